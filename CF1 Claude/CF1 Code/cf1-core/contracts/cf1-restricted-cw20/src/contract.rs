@@ -0,0 +1,91 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult, SubMsg,
+    Uint128, WasmMsg,
+};
+use cw2::set_contract_version;
+use cw20_base::ContractError;
+use cw_storage_plus::Item;
+
+use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg, TransferHookMsg};
+
+const CONTRACT_NAME: &str = "crates.io:cf1-restricted-cw20";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+// Storage keys
+pub const TRANSFER_HOOK: Item<Addr> = Item::new("transfer_hook");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    let hook = deps.api.addr_validate(&msg.transfer_hook)?;
+    let response = cw20_base::contract::instantiate(deps.branch(), env, info, msg.token)?;
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    TRANSFER_HOOK.save(deps.storage, &hook)?;
+
+    Ok(response.add_attribute("transfer_hook", hook))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    let hook = TRANSFER_HOOK.load(deps.storage)?;
+    let approval = transfer_approval(&info.sender, &msg).filter(|_| info.sender != hook);
+
+    let mut response = cw20_base::contract::execute(deps, env, info, msg)?;
+    if let Some((owner, recipient, amount)) = approval {
+        // Ask first, so a rejected move never reaches a Send receiver
+        let approve = WasmMsg::Execute {
+            contract_addr: hook.to_string(),
+            msg: to_json_binary(&TransferHookMsg::ApproveTransfer {
+                owner,
+                recipient,
+                amount,
+            })?,
+            funds: vec![],
+        };
+        response.messages.insert(0, SubMsg::new(approve));
+    }
+    Ok(response)
+}
+
+/// Owner, recipient and amount of a balance move that needs the hook's approval.
+///
+/// Moves made by the hook itself are already approved, and mints and burns are controlled by
+/// the minter, so neither is sent back.
+fn transfer_approval(sender: &Addr, msg: &ExecuteMsg) -> Option<(String, String, Uint128)> {
+    match msg {
+        ExecuteMsg::Transfer { recipient, amount } => {
+            Some((sender.to_string(), recipient.clone(), *amount))
+        }
+        ExecuteMsg::Send {
+            contract, amount, ..
+        } => Some((sender.to_string(), contract.clone(), *amount)),
+        ExecuteMsg::TransferFrom {
+            owner,
+            recipient,
+            amount,
+        } => Some((owner.clone(), recipient.clone(), *amount)),
+        ExecuteMsg::SendFrom {
+            owner,
+            contract,
+            amount,
+            ..
+        } => Some((owner.clone(), contract.clone(), *amount)),
+        _ => None,
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    cw20_base::contract::query(deps, env, msg)
+}
@@ -0,0 +1,8 @@
+//! CW20 share token for CF1 raises.
+//!
+//! A thin wrapper around cw20-base that asks a hook contract (the launchpad) to approve every
+//! holder-initiated `Transfer`, `Send`, `TransferFrom` and `SendFrom`. The hook can reject a move
+//! that breaks the raise's lockup or compliance rules, which reverts the whole transfer.
+
+pub mod contract;
+pub mod msg;
@@ -0,0 +1,10 @@
+use cosmwasm_schema::cw_serde;
+
+pub use cf1_types::TransferHookMsg;
+pub use cw20_base::msg::{ExecuteMsg, QueryMsg};
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub token: cw20_base::msg::InstantiateMsg,
+    pub transfer_hook: String, // Contract that approves holder transfers
+}
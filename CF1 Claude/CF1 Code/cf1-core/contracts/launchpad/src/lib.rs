@@ -0,0 +1,5 @@
+//! The launchpad contract lives in the `cf1-core` package at the workspace root; this package only
+//! re-exports it so code depending on the `contracts/launchpad` path keeps building. Make changes
+//! in `cf1-core`.
+
+pub use cf1_core::*;
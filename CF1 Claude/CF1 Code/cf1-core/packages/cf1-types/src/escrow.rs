@@ -0,0 +1,18 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Uint128;
+
+/// Messages the launchpad sends to an external escrow contract holding investor funds
+#[cw_serde]
+pub enum EscrowExecuteMsg {
+    /// Hold the attached funds for an investment in `proposal_id`
+    EscrowDeposit {
+        proposal_id: String,
+        investor: String,
+    },
+    /// Pay `amount` of the proposal's held funds to `recipient`
+    EscrowRelease {
+        proposal_id: String,
+        recipient: String,
+        amount: Uint128,
+    },
+}
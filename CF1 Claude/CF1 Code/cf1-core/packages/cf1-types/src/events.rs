@@ -0,0 +1,13 @@
+/// Bumped whenever the attributes of an event change incompatibly
+pub const EVENT_VERSION: u32 = 1;
+/// Prefix of every CF1 event type
+pub const EVENT_NAMESPACE: &str = "cf1";
+
+/// Event type for an action, e.g. "cf1.invest.v1" (indexed on chain as "wasm-cf1.invest.v1")
+pub fn event_type(action: &str) -> String {
+    format!("{}.{}.v{}", EVENT_NAMESPACE, action, EVENT_VERSION)
+}
+
+/// Version of the attribute schema carried by notification events (funding complete, tokens
+/// distributed, lockup expired, refund issued) in their "schema_version" attribute
+pub const NOTIFICATION_SCHEMA_VERSION: u32 = 1;
@@ -0,0 +1,47 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Uint128;
+
+/// Notifications the launchpad sends to the registered governance contract.
+///
+/// `idempotency_key` is the same on every delivery of one notification, so the receiver can
+/// ignore a retry of a notification it already processed.
+#[cw_serde]
+pub enum GovernanceHookMsg {
+    /// The raise closed successfully and its lockup started
+    ProposalFunded {
+        idempotency_key: String,
+        proposal_id: String,
+        creator: String,
+        raised_amount: Uint128,
+        lockup_end: u64,
+    },
+    /// Share tokens reached every investor, who can now vote
+    TokensDistributed {
+        idempotency_key: String,
+        proposal_id: String,
+        token_contract: String,
+        total_supply: Uint128,
+    },
+    /// The lockup ended and shares can move freely
+    LockupExpired {
+        idempotency_key: String,
+        proposal_id: String,
+        lockup_end: u64,
+    },
+}
+
+impl GovernanceHookMsg {
+    pub fn idempotency_key(&self) -> &str {
+        match self {
+            Self::ProposalFunded {
+                idempotency_key, ..
+            }
+            | Self::TokensDistributed {
+                idempotency_key, ..
+            }
+            | Self::LockupExpired {
+                idempotency_key, ..
+            } => idempotency_key,
+        }
+    }
+}
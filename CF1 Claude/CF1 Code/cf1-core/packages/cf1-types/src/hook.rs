@@ -0,0 +1,15 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Uint128;
+
+/// Message sent to the hook contract before a transfer settles; an error aborts the transfer.
+///
+/// The launchpad accepts it as its own `ExecuteMsg::ApproveTransfer`, which must keep the same
+/// shape.
+#[cw_serde]
+pub enum TransferHookMsg {
+    ApproveTransfer {
+        owner: String,
+        recipient: String,
+        amount: Uint128,
+    },
+}
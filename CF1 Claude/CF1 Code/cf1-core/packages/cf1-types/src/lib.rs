@@ -0,0 +1,13 @@
+//! Types shared across the CF1 contracts.
+//!
+//! Anything that crosses a contract boundary or is read by indexers lives here, so the launchpad,
+//! the restricted share token, escrow contracts and off-chain tooling agree on one definition.
+
+pub mod escrow;
+pub mod events;
+pub mod governance;
+pub mod hook;
+
+pub use escrow::EscrowExecuteMsg;
+pub use governance::GovernanceHookMsg;
+pub use hook::TransferHookMsg;
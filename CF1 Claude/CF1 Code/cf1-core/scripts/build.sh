@@ -39,9 +39,14 @@ else
     RUSTFLAGS='-C link-arg=-s' cargo build --release --target wasm32-unknown-unknown
 fi
 
+# Share token wasm, built on its own so it keeps its entry points
+echo "Building restricted CW20 share token..."
+RUSTFLAGS='-C link-arg=-s' cargo build --release --target wasm32-unknown-unknown -p cf1-restricted-cw20
+
 # Check wasm size
 echo "Checking WASM size..."
 ls -lh target/wasm32-unknown-unknown/release/cf1_core.wasm
+ls -lh target/wasm32-unknown-unknown/release/cf1_restricted_cw20.wasm
 
 # Optional: Use cosmwasm/optimizer if available
 if command -v docker &> /dev/null; then
@@ -61,6 +66,7 @@ echo "✅ Build completed successfully!"
 echo ""
 echo "📁 Generated files:"
 echo "  - WASM binary: target/wasm32-unknown-unknown/release/cf1_core.wasm"
+echo "  - Share token: target/wasm32-unknown-unknown/release/cf1_restricted_cw20.wasm"
 echo "  - Schema files: schema/"
 if [ -f "artifacts/cf1_core.wasm" ]; then
     echo "  - Optimized WASM: artifacts/cf1_core.wasm"
@@ -111,12 +111,13 @@ fi
 
 echo -e "${GREEN}✅ Contract stored with code ID: $CODE_ID${NC}"
 
-# Deploy CW20 base contract first (if not already deployed)
-echo -e "${BLUE}📤 Deploying CW20 base contract...${NC}"
-echo -e "${YELLOW}Note: In production, use an already deployed CW20 contract code ID${NC}"
+# Deploy the share token contract first (if not already deployed)
+echo -e "${BLUE}📤 Deploying CF1 restricted CW20 contract...${NC}"
+echo -e "${YELLOW}Note: In production, use an already deployed cf1-restricted-cw20 code ID${NC}"
 
 # For testnet, we'll use a placeholder CW20 code ID
-# In production, this should be the actual CW20 base contract code ID on Neutron
+# This must be cf1-restricted-cw20, not plain cw20-base: the launchpad instantiates share tokens
+# with itself as the transfer hook
 CW20_CODE_ID=${CW20_CODE_ID:-1}  # Override with environment variable if available
 
 echo -e "${GREEN}Using CW20 code ID: $CW20_CODE_ID${NC}"
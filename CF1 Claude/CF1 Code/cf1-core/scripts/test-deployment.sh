@@ -129,7 +129,7 @@ SAMPLE_PROPOSAL='{
       "token_price": "1000000000",
       "total_shares": 1000,
       "minimum_investment": "1000000000",
-      "expected_apy": "10%",
+      "expected_apy_bps": 1000,
       "funding_deadline": '$(date -d "+30 days" +%s)'
     },
     "documents": [
@@ -0,0 +1,114 @@
+//! Per-user activity feed.
+//!
+//! Investments, refunds, claimed distributions and governance votes are appended to the acting
+//! user's own log as they happen. Entries are never changed or removed, so the frontend's recent
+//! activity view reads one contiguous range instead of needing an indexer.
+
+use cosmwasm_std::{Addr, Deps, Order, StdError, StdResult, Storage, Uint128};
+use cw_storage_plus::{Bound, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::gas_optimization::{DEFAULT_LIMIT, MAX_LIMIT};
+use crate::ledger::{LedgerEntry, LedgerEntryKind};
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivityKind {
+    Investment,        // Funds moved into a proposal's escrow
+    Refund,            // Escrowed funds returned
+    DistributionClaim, // Retirement proceeds claimed for burned tokens
+    GovernanceVote,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ActivityEntry {
+    pub activity_id: u64, // Increasing per user
+    pub kind: ActivityKind,
+    pub proposal_id: String,
+    pub amount: Option<Uint128>,    // None for votes
+    pub governance_id: Option<u64>, // Set for votes only
+    pub block_height: u64,
+    pub timestamp: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ActivityResponse {
+    pub activities: Vec<ActivityEntry>, // Newest first
+    pub next_key: Option<String>, // Pass as start_after for the next page; None on the last page
+}
+
+// Storage keys
+pub const USER_ACTIVITY: Map<(&Addr, u64), ActivityEntry> = Map::new("user_activity"); // (user, activity_id)
+const USER_ACTIVITY_COUNT: Map<&Addr, u64> = Map::new("user_activity_count");
+
+/// Append an entry to the user's feed, assigning its id
+pub fn record(storage: &mut dyn Storage, user: &Addr, mut entry: ActivityEntry) -> StdResult<()> {
+    entry.activity_id = USER_ACTIVITY_COUNT
+        .may_load(storage, user)?
+        .unwrap_or_default()
+        + 1;
+    USER_ACTIVITY_COUNT.save(storage, user, &entry.activity_id)?;
+    USER_ACTIVITY.save(storage, (user, entry.activity_id), &entry)
+}
+
+/// Mirror an investor's money movement from the proposal ledger into their feed
+pub fn record_ledger_entry(
+    storage: &mut dyn Storage,
+    proposal_id: &str,
+    entry: &LedgerEntry,
+) -> StdResult<()> {
+    let kind = match entry.kind {
+        LedgerEntryKind::Investment => ActivityKind::Investment,
+        LedgerEntryKind::Refund => ActivityKind::Refund,
+        LedgerEntryKind::Redemption => ActivityKind::DistributionClaim,
+        _ => return Ok(()),
+    };
+    record(
+        storage,
+        &entry.counterparty,
+        ActivityEntry {
+            activity_id: 0,
+            kind,
+            proposal_id: proposal_id.to_string(),
+            amount: Some(entry.amount),
+            governance_id: None,
+            block_height: entry.block_height,
+            timestamp: entry.timestamp,
+        },
+    )
+}
+
+// Query functions
+/// The user's activity across all proposals, newest first
+pub fn query_activity_by_user(
+    deps: Deps,
+    user: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<ActivityResponse> {
+    let user = deps.api.addr_validate(&user)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let max = start_after
+        .map(|s| {
+            s.parse::<u64>()
+                .map_err(|_| StdError::generic_err("start_after must be an activity id"))
+        })
+        .transpose()?
+        .map(Bound::exclusive);
+
+    let mut activities = USER_ACTIVITY
+        .prefix(&user)
+        .range(deps.storage, None, max, Order::Descending)
+        .take(limit + 1)
+        .map(|item| item.map(|(_, entry)| entry))
+        .collect::<StdResult<Vec<_>>>()?;
+    let next_key = crate::next_page_key(&mut activities, limit, |entry| {
+        entry.activity_id.to_string()
+    });
+
+    Ok(ActivityResponse {
+        activities,
+        next_key,
+    })
+}
@@ -0,0 +1,348 @@
+use cosmwasm_std::{Addr, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Storage, Uint128};
+use cw_storage_plus::Map;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+use crate::governance::{self, VoteOption, VoteTally, GOVERNANCE_CONFIG};
+use crate::state::{
+    save_proposal, InvestmentStatus, Proposal, ProposalStatus, CONFIG, INVESTMENTS, PROPOSALS,
+    PROPOSAL_INVESTMENTS,
+};
+
+/// Changes a creator may make to an active raise's financial terms
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct TermsAmendment {
+    pub funding_deadline: Option<u64>,  // Can only be extended
+    pub target_amount: Option<Uint128>, // Can only be lowered
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub enum AmendmentStatus {
+    Open,
+    Applied,
+    Rejected,
+}
+
+/// Amendment put to a share-weighted vote of the proposal's existing investors
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AmendmentProposal {
+    pub proposal_id: String,
+    pub round: u32,
+    pub terms: TermsAmendment,
+    pub reason: String,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub total_voting_power: u64, // Shares held by investors when the amendment opened
+    pub tally: VoteTally,
+    pub status: AmendmentStatus,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AmendmentResponse {
+    pub amendment: Option<AmendmentProposal>, // Latest amendment put to a vote, if any
+    pub voting_power: Option<u64>,            // Set when the query names a voter
+    pub vote: Option<VoteOption>,
+}
+
+// Storage keys
+pub const AMENDMENTS: Map<(String, u32), AmendmentProposal> = Map::new("amendments");
+pub const AMENDMENT_ROUNDS: Map<String, u32> = Map::new("amendment_rounds"); // proposal_id -> latest round
+pub const AMENDMENT_SNAPSHOTS: Map<(String, u32, &Addr), u64> = Map::new("amendment_snapshots");
+pub const AMENDMENT_VOTES: Map<(String, u32, &Addr), VoteOption> = Map::new("amendment_votes");
+
+fn latest_amendment(
+    storage: &dyn Storage,
+    proposal_id: &str,
+) -> StdResult<Option<AmendmentProposal>> {
+    match AMENDMENT_ROUNDS.may_load(storage, proposal_id.to_string())? {
+        Some(round) => AMENDMENTS.may_load(storage, (proposal_id.to_string(), round)),
+        None => Ok(None),
+    }
+}
+
+/// Check an amendment against the proposal as it stands now
+fn validate_terms(
+    storage: &dyn Storage,
+    env: &Env,
+    proposal: &Proposal,
+    terms: &TermsAmendment,
+) -> Result<(), ContractError> {
+    if terms.funding_deadline.is_none() && terms.target_amount.is_none() {
+        return Err(ContractError::InvalidInput {
+            field: "terms".to_string(),
+            message: "Amendment must change the deadline or the target".to_string(),
+        });
+    }
+    if proposal.status != ProposalStatus::Active || proposal.funding_status.is_funded {
+        return Err(ContractError::ProposalNotActive {});
+    }
+
+    if let Some(deadline) = terms.funding_deadline {
+        let config = CONFIG.load(storage)?;
+        let max_deadline =
+            proposal.timestamps.created_at + config.max_funding_period_days * 24 * 60 * 60;
+        if deadline <= proposal.financial_terms.funding_deadline
+            || deadline <= env.block.time.seconds()
+            || deadline > max_deadline
+        {
+            return Err(ContractError::InvalidInput {
+                field: "funding_deadline".to_string(),
+                message: format!(
+                    "Deadline can only be extended, up to {} days after creation",
+                    config.max_funding_period_days
+                ),
+            });
+        }
+    }
+    if let Some(target) = terms.target_amount {
+        if target >= proposal.financial_terms.target_amount
+            || target <= proposal.funding_status.raised_amount
+            || proposal
+                .financial_terms
+                .soft_cap
+                .is_some_and(|soft_cap| target < soft_cap)
+        {
+            return Err(ContractError::InvalidInput {
+                field: "target_amount".to_string(),
+                message: "Target can only be lowered, and must stay above the amount raised \
+                          and at or above the soft cap"
+                    .to_string(),
+            });
+        }
+    }
+    Ok(())
+}
+
+fn apply_terms(
+    storage: &mut dyn Storage,
+    env: &Env,
+    mut proposal: Proposal,
+    terms: &TermsAmendment,
+) -> Result<(), ContractError> {
+    if let Some(deadline) = terms.funding_deadline {
+        proposal.financial_terms.funding_deadline = deadline;
+        proposal.timestamps.funding_deadline = deadline;
+    }
+    if let Some(target) = terms.target_amount {
+        proposal.financial_terms.target_amount = target;
+    }
+    proposal.timestamps.updated_at = env.block.time.seconds();
+    save_proposal(storage, &proposal)?;
+    Ok(())
+}
+
+/// Amend an active raise's financial terms (creator only).
+///
+/// Before anyone has invested the change applies immediately. Once investments exist it opens an
+/// amendment that investors approve by a share-weighted vote under the governance quorum and
+/// threshold; the emitted event lists how many investors were asked to vote.
+pub fn amend_financial_terms(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: String,
+    terms: TermsAmendment,
+    reason: String,
+) -> Result<Response, ContractError> {
+    let proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+    if proposal.creator != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    validate_terms(deps.storage, &env, &proposal, &terms)?;
+
+    let investors = PROPOSAL_INVESTMENTS
+        .may_load(deps.storage, proposal_id.clone())?
+        .unwrap_or_default();
+    if investors.is_empty() {
+        apply_terms(deps.storage, &env, proposal, &terms)?;
+        return Ok(Response::new()
+            .add_attribute("action", "amend_financial_terms")
+            .add_attribute("proposal_id", &proposal_id)
+            .add_attribute("applied", "true"));
+    }
+
+    if let Some(amendment) = latest_amendment(deps.storage, &proposal_id)? {
+        if amendment.status == AmendmentStatus::Open {
+            return Err(ContractError::AmendmentPending { proposal_id });
+        }
+    }
+
+    let round = AMENDMENT_ROUNDS
+        .may_load(deps.storage, proposal_id.clone())?
+        .map_or(1, |round| round + 1);
+    AMENDMENT_ROUNDS.save(deps.storage, proposal_id.clone(), &round)?;
+
+    // Only investors at the time the amendment opens get a say
+    let mut total_voting_power = 0u64;
+    let mut notified = 0u64;
+    for investor in &investors {
+        let Some(investment) =
+            INVESTMENTS.may_load(deps.storage, (proposal_id.clone(), investor))?
+        else {
+            continue;
+        };
+        if investment.status == InvestmentStatus::Pending && investment.shares > 0 {
+            AMENDMENT_SNAPSHOTS.save(
+                deps.storage,
+                (proposal_id.clone(), round, investor),
+                &investment.shares,
+            )?;
+            total_voting_power = total_voting_power.saturating_add(investment.shares);
+            notified += 1;
+        }
+    }
+
+    let config = GOVERNANCE_CONFIG
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    let start_time = env.block.time.seconds();
+    let amendment = AmendmentProposal {
+        proposal_id: proposal_id.clone(),
+        round,
+        terms,
+        reason,
+        start_time,
+        end_time: start_time + config.voting_period_seconds,
+        total_voting_power,
+        tally: VoteTally::default(),
+        status: AmendmentStatus::Open,
+    };
+    AMENDMENTS.save(deps.storage, (proposal_id.clone(), round), &amendment)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "propose_amendment")
+        .add_attribute("proposal_id", &proposal_id)
+        .add_attribute("round", round.to_string())
+        .add_attribute("investors_notified", notified.to_string())
+        .add_attribute("voting_ends", amendment.end_time.to_string())
+        .add_attribute("applied", "false"))
+}
+
+/// Vote on the open amendment, weighted by shares held when it opened
+pub fn vote_on_amendment(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: String,
+    vote: VoteOption,
+) -> Result<Response, ContractError> {
+    let mut amendment = latest_amendment(deps.storage, &proposal_id)?
+        .filter(|amendment| {
+            amendment.status == AmendmentStatus::Open
+                && env.block.time.seconds() < amendment.end_time
+        })
+        .ok_or(ContractError::NoOpenAmendment {
+            proposal_id: proposal_id.clone(),
+        })?;
+    let key = (proposal_id.clone(), amendment.round, &info.sender);
+    if AMENDMENT_VOTES.has(deps.storage, key.clone()) {
+        return Err(ContractError::AlreadyVotedOnAmendment { proposal_id });
+    }
+    let power = AMENDMENT_SNAPSHOTS
+        .may_load(deps.storage, key.clone())?
+        .unwrap_or(0);
+    if power == 0 {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    match vote {
+        VoteOption::Yes => amendment.tally.yes = amendment.tally.yes.saturating_add(power),
+        VoteOption::No => amendment.tally.no = amendment.tally.no.saturating_add(power),
+        VoteOption::Abstain => {
+            amendment.tally.abstain = amendment.tally.abstain.saturating_add(power)
+        }
+    }
+    AMENDMENT_VOTES.save(deps.storage, key, &vote)?;
+    AMENDMENTS.save(
+        deps.storage,
+        (proposal_id.clone(), amendment.round),
+        &amendment,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "vote_on_amendment")
+        .add_attribute("proposal_id", &proposal_id)
+        .add_attribute("round", amendment.round.to_string())
+        .add_attribute("voter", info.sender)
+        .add_attribute("power", power.to_string()))
+}
+
+/// Close the vote once it ends and apply the amendment if it passed (anyone may call).
+///
+/// Terms are re-validated first, so an amendment the raise has outgrown (e.g. it funded or
+/// expired during the vote) is rejected rather than applied.
+pub fn resolve_amendment(
+    deps: DepsMut,
+    env: Env,
+    proposal_id: String,
+) -> Result<Response, ContractError> {
+    let mut amendment = latest_amendment(deps.storage, &proposal_id)?
+        .filter(|amendment| amendment.status == AmendmentStatus::Open)
+        .ok_or(ContractError::NoOpenAmendment {
+            proposal_id: proposal_id.clone(),
+        })?;
+    if env.block.time.seconds() < amendment.end_time {
+        return Err(ContractError::AmendmentVotingOpen { proposal_id });
+    }
+
+    let config = GOVERNANCE_CONFIG
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    let proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+    let passed = governance::tally_passes(
+        config.quorum_bps,
+        config.threshold_bps,
+        &amendment.tally,
+        amendment.total_voting_power,
+    ) && validate_terms(deps.storage, &env, &proposal, &amendment.terms).is_ok();
+    if passed {
+        apply_terms(deps.storage, &env, proposal, &amendment.terms)?;
+        amendment.status = AmendmentStatus::Applied;
+    } else {
+        amendment.status = AmendmentStatus::Rejected;
+    }
+    AMENDMENTS.save(
+        deps.storage,
+        (proposal_id.clone(), amendment.round),
+        &amendment,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "resolve_amendment")
+        .add_attribute("proposal_id", &proposal_id)
+        .add_attribute("round", amendment.round.to_string())
+        .add_attribute("applied", passed.to_string())
+        .add_attribute("yes", amendment.tally.yes.to_string())
+        .add_attribute("no", amendment.tally.no.to_string()))
+}
+
+// Query functions
+pub fn query_amendment(
+    deps: Deps,
+    proposal_id: String,
+    voter: Option<String>,
+) -> StdResult<AmendmentResponse> {
+    let amendment = latest_amendment(deps.storage, &proposal_id)?;
+    let (voting_power, vote) = match (&amendment, voter) {
+        (Some(amendment), Some(voter)) => {
+            let voter = deps.api.addr_validate(&voter)?;
+            let key = (proposal_id.clone(), amendment.round, &voter);
+            (
+                Some(
+                    AMENDMENT_SNAPSHOTS
+                        .may_load(deps.storage, key.clone())?
+                        .unwrap_or(0),
+                ),
+                AMENDMENT_VOTES.may_load(deps.storage, key)?,
+            )
+        }
+        _ => (None, None),
+    };
+
+    Ok(AmendmentResponse {
+        amendment,
+        voting_power,
+        vote,
+    })
+}
@@ -0,0 +1,198 @@
+//! Append-only audit log for Reg CF recordkeeping.
+//!
+//! Every proposal status transition, config change and admin action is written to
+//! `PROPOSAL_EVENTS` with the block, the actor and, where the handler gives one, the reason.
+//! Entries are recorded at the entry points rather than in each handler: `save_proposal` notes
+//! status changes as they happen, and once the handler has succeeded the entry point files them
+//! together with whatever config change or admin action the response describes. Entries are
+//! never updated or removed.
+
+use cosmwasm_std::{Addr, Deps, Env, Order, Response, StdResult, Storage};
+use cw_storage_plus::{Bound, Item, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::state::{Config, ProposalStatus, CONFIG};
+
+/// Scope of entries not tied to one proposal
+pub const PLATFORM_SCOPE: &str = "platform";
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditKind {
+    StatusTransition,
+    ConfigChange,
+    AdminAction,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AuditEntry {
+    pub sequence: u64, // Platform-wide, increasing
+    pub proposal_id: Option<String>,
+    pub kind: AuditKind,
+    pub action: String, // Handler that caused the entry, e.g. "cancel" or "update_config"
+    pub actor: Addr,    // Sender, or the contract itself for sudo and replies
+    pub from_status: Option<ProposalStatus>, // None when the proposal was created
+    pub to_status: Option<ProposalStatus>,
+    pub reason: Option<String>,
+    pub block_height: u64,
+    pub timestamp: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AuditTrailResponse {
+    pub entries: Vec<AuditEntry>,
+    pub next_key: Option<String>, // Pass as start_after for the next page; None on the last page
+}
+
+/// A status change noted by `save_proposal`, waiting for the entry point to file it
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+struct PendingTransition {
+    proposal_id: String,
+    from: Option<ProposalStatus>,
+    to: ProposalStatus,
+}
+
+// Storage keys
+pub const PROPOSAL_EVENTS: Map<(&str, u64), AuditEntry> = Map::new("proposal_events"); // (proposal_id or PLATFORM_SCOPE, sequence)
+const AUDIT_SEQUENCE: Item<u64> = Item::new("audit_sequence");
+const PENDING_TRANSITIONS: Item<Vec<PendingTransition>> = Item::new("audit_pending"); // Empty between calls
+
+/// Note a status change for the current call; `save_proposal` calls this
+pub fn note_transition(
+    storage: &mut dyn Storage,
+    proposal_id: &str,
+    from: Option<ProposalStatus>,
+    to: ProposalStatus,
+) -> StdResult<()> {
+    let mut pending = PENDING_TRANSITIONS.may_load(storage)?.unwrap_or_default();
+    pending.push(PendingTransition {
+        proposal_id: proposal_id.to_string(),
+        from,
+        to,
+    });
+    PENDING_TRANSITIONS.save(storage, &pending)
+}
+
+/// Config as it was before the handler ran, to tell whether the call changed it
+pub struct AuditScope {
+    config_before: Option<Config>,
+}
+
+impl AuditScope {
+    pub fn begin(storage: &dyn Storage) -> StdResult<Self> {
+        Ok(Self {
+            config_before: CONFIG.may_load(storage)?,
+        })
+    }
+
+    /// File everything the call did once its handler has succeeded
+    pub fn finish(
+        self,
+        storage: &mut dyn Storage,
+        env: &Env,
+        actor: &Addr,
+        response: &Response,
+    ) -> StdResult<()> {
+        let attribute = |key: &str| {
+            response
+                .attributes
+                .iter()
+                .find(|attr| attr.key == key)
+                .map(|attr| attr.value.clone())
+        };
+        let method = attribute("method");
+        let action = method
+            .clone()
+            .or_else(|| attribute("action"))
+            .unwrap_or_else(|| "unknown".to_string());
+        let reason = attribute("reason");
+        let entry = |storage: &mut dyn Storage,
+                     proposal_id: Option<String>,
+                     kind: AuditKind,
+                     from_status: Option<ProposalStatus>,
+                     to_status: Option<ProposalStatus>|
+         -> StdResult<()> {
+            let sequence = AUDIT_SEQUENCE.may_load(storage)?.unwrap_or_default() + 1;
+            AUDIT_SEQUENCE.save(storage, &sequence)?;
+            let scope = proposal_id
+                .clone()
+                .unwrap_or_else(|| PLATFORM_SCOPE.to_string());
+            PROPOSAL_EVENTS.save(
+                storage,
+                (&scope, sequence),
+                &AuditEntry {
+                    sequence,
+                    proposal_id,
+                    kind,
+                    action: action.clone(),
+                    actor: actor.clone(),
+                    from_status,
+                    to_status,
+                    reason: reason.clone(),
+                    block_height: env.block.height,
+                    timestamp: env.block.time.seconds(),
+                },
+            )
+        };
+
+        let transitions = PENDING_TRANSITIONS.may_load(storage)?.unwrap_or_default();
+        let transitioned = !transitions.is_empty();
+        for transition in transitions {
+            entry(
+                storage,
+                Some(transition.proposal_id),
+                AuditKind::StatusTransition,
+                transition.from,
+                Some(transition.to),
+            )?;
+        }
+        PENDING_TRANSITIONS.remove(storage);
+
+        if CONFIG.may_load(storage)? != self.config_before {
+            entry(storage, None, AuditKind::ConfigChange, None, None)?;
+        } else if method.is_some() && !transitioned {
+            // Admin actions that moved a proposal are already on record as its transition
+            entry(
+                storage,
+                attribute("proposal_id"),
+                AuditKind::AdminAction,
+                None,
+                None,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+// Query functions
+/// Oldest first; platform-wide entries when no proposal is given
+pub fn query_audit_trail(
+    deps: Deps,
+    proposal_id: Option<String>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<AuditTrailResponse> {
+    let limit = limit
+        .unwrap_or(crate::gas_optimization::DEFAULT_LIMIT)
+        .min(crate::gas_optimization::MAX_LIMIT) as usize;
+    let scope = proposal_id.unwrap_or_else(|| PLATFORM_SCOPE.to_string());
+    let min = start_after
+        .map(|s| {
+            s.parse::<u64>().map_err(|_| {
+                cosmwasm_std::StdError::generic_err("start_after must be an audit sequence number")
+            })
+        })
+        .transpose()?
+        .map(Bound::exclusive);
+
+    let mut entries = PROPOSAL_EVENTS
+        .prefix(&scope)
+        .range(deps.storage, min, None, Order::Ascending)
+        .take(limit + 1)
+        .map(|item| item.map(|(_, entry)| entry))
+        .collect::<StdResult<Vec<_>>>()?;
+    let next_key = crate::next_page_key(&mut entries, limit, |entry| entry.sequence.to_string());
+
+    Ok(AuditTrailResponse { entries, next_key })
+}
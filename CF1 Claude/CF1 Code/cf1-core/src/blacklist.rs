@@ -0,0 +1,210 @@
+//! Creator blacklist.
+//!
+//! The owner can blacklist a creator caught in fraud. A blacklisted address cannot create
+//! proposals, and everything still owed to it as a creator is frozen: distributions cannot be
+//! finalized, milestone tranches and vested tokens cannot be released, and a creation bond that
+//! would have been refunded on cancellation or a failed raise is slashed instead. Investor refunds
+//! are not affected.
+//! The creator can file one appeal per blacklisting; the owner either lifts the blacklist, which
+//! unfreezes the payouts, or dismisses the appeal.
+
+use cosmwasm_std::{Addr, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult, Storage};
+use cw_storage_plus::{Bound, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+use crate::gas_optimization::{DEFAULT_LIMIT, MAX_LIMIT};
+use crate::review::MAX_REASON_LENGTH;
+use crate::roles::{self, Role};
+use crate::security::InputValidator;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BlacklistAppeal {
+    pub statement: String,
+    pub filed_at: u64,
+    pub dismissed_at: Option<u64>, // None while the appeal is pending
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BlacklistEntry {
+    pub creator: Addr,
+    pub reason: String,
+    pub blacklisted_by: Addr,
+    pub blacklisted_at: u64,
+    pub appeal: Option<BlacklistAppeal>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BlacklistedCreatorsResponse {
+    pub creators: Vec<BlacklistEntry>,
+    pub next_key: Option<String>, // Pass as start_after for the next page; None on the last page
+}
+
+// Storage keys
+pub const BLACKLISTED_CREATORS: Map<&Addr, BlacklistEntry> = Map::new("blacklisted_creators");
+
+pub fn is_blacklisted(storage: &dyn Storage, creator: &Addr) -> bool {
+    BLACKLISTED_CREATORS.has(storage, creator)
+}
+
+/// Fail if `creator` is blacklisted; guards proposal creation and every creator payout
+pub fn ensure_not_blacklisted(storage: &dyn Storage, creator: &Addr) -> Result<(), ContractError> {
+    if is_blacklisted(storage, creator) {
+        return Err(ContractError::CreatorBlacklisted {
+            creator: creator.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Blacklist a creator (owner only)
+pub fn blacklist_creator(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    creator: String,
+    reason: String,
+) -> Result<Response, ContractError> {
+    roles::ensure_role(deps.storage, &info.sender, Role::Owner)?;
+    InputValidator::validate_length("reason", &reason, 1, MAX_REASON_LENGTH)?;
+
+    let creator = deps.api.addr_validate(&creator)?;
+    if is_blacklisted(deps.storage, &creator) {
+        return Err(ContractError::InvalidInput {
+            field: "creator".to_string(),
+            message: "Creator is already blacklisted".to_string(),
+        });
+    }
+    BLACKLISTED_CREATORS.save(
+        deps.storage,
+        &creator,
+        &BlacklistEntry {
+            creator: creator.clone(),
+            reason: reason.clone(),
+            blacklisted_by: info.sender,
+            blacklisted_at: env.block.time.seconds(),
+            appeal: None,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "blacklist_creator")
+        .add_attribute("creator", creator)
+        .add_attribute("reason", reason))
+}
+
+/// Lift a blacklist, unfreezing the creator's payouts (owner only)
+pub fn unblacklist_creator(
+    deps: DepsMut,
+    info: MessageInfo,
+    creator: String,
+) -> Result<Response, ContractError> {
+    roles::ensure_role(deps.storage, &info.sender, Role::Owner)?;
+
+    let creator = deps.api.addr_validate(&creator)?;
+    let entry = load_entry(deps.storage, &creator)?;
+    BLACKLISTED_CREATORS.remove(deps.storage, &creator);
+
+    Ok(Response::new()
+        .add_attribute("action", "unblacklist_creator")
+        .add_attribute("creator", creator)
+        .add_attribute("had_appeal", entry.appeal.is_some().to_string()))
+}
+
+/// Appeal the sender's own blacklisting; one appeal per blacklisting
+pub fn appeal_blacklist(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    statement: String,
+) -> Result<Response, ContractError> {
+    InputValidator::validate_length("statement", &statement, 1, MAX_REASON_LENGTH)?;
+
+    let mut entry = load_entry(deps.storage, &info.sender)?;
+    if entry.appeal.is_some() {
+        return Err(ContractError::InvalidInput {
+            field: "statement".to_string(),
+            message: "An appeal has already been filed".to_string(),
+        });
+    }
+    entry.appeal = Some(BlacklistAppeal {
+        statement,
+        filed_at: env.block.time.seconds(),
+        dismissed_at: None,
+    });
+    BLACKLISTED_CREATORS.save(deps.storage, &info.sender, &entry)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "appeal_blacklist")
+        .add_attribute("creator", info.sender))
+}
+
+/// Turn down a pending appeal, leaving the blacklist in place (owner only)
+pub fn dismiss_blacklist_appeal(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    creator: String,
+) -> Result<Response, ContractError> {
+    roles::ensure_role(deps.storage, &info.sender, Role::Owner)?;
+
+    let creator = deps.api.addr_validate(&creator)?;
+    let mut entry = load_entry(deps.storage, &creator)?;
+    let Some(appeal) = entry
+        .appeal
+        .as_mut()
+        .filter(|appeal| appeal.dismissed_at.is_none())
+    else {
+        return Err(ContractError::InvalidInput {
+            field: "creator".to_string(),
+            message: "Creator has no pending appeal".to_string(),
+        });
+    };
+    appeal.dismissed_at = Some(env.block.time.seconds());
+    BLACKLISTED_CREATORS.save(deps.storage, &creator, &entry)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "dismiss_blacklist_appeal")
+        .add_attribute("creator", creator))
+}
+
+fn load_entry(storage: &dyn Storage, creator: &Addr) -> Result<BlacklistEntry, ContractError> {
+    BLACKLISTED_CREATORS
+        .may_load(storage, creator)?
+        .ok_or_else(|| ContractError::InvalidInput {
+            field: "creator".to_string(),
+            message: "Creator is not blacklisted".to_string(),
+        })
+}
+
+// Query functions
+pub fn query_blacklisted_creator(deps: Deps, creator: String) -> StdResult<Option<BlacklistEntry>> {
+    let creator = deps.api.addr_validate(&creator)?;
+    BLACKLISTED_CREATORS.may_load(deps.storage, &creator)
+}
+
+pub fn query_blacklisted_creators(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<BlacklistedCreatorsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_after = start_after
+        .map(|creator| deps.api.addr_validate(&creator))
+        .transpose()?;
+
+    let mut creators = BLACKLISTED_CREATORS
+        .range(
+            deps.storage,
+            start_after.as_ref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit + 1)
+        .map(|item| item.map(|(_, entry)| entry))
+        .collect::<StdResult<Vec<_>>>()?;
+    let next_key = crate::next_page_key(&mut creators, limit, |entry| entry.creator.to_string());
+
+    Ok(BlacklistedCreatorsResponse { creators, next_key })
+}
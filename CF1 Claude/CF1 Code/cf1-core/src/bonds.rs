@@ -0,0 +1,145 @@
+//! Creation bonds.
+//!
+//! When the owner sets a bond, every `CreateProposal` has to carry exactly that much untrn. The
+//! bond sits in the treasury's bonds bucket while the proposal runs. It goes back to the creator
+//! when the raise is finalized, when it fails to reach its soft cap, or when the creator cancels
+//! before anyone has invested. A proposal rejected for fraud has its bond slashed to the
+//! treasury's fees bucket, as does one cancelled after taking investments or whose creator is
+//! blacklisted.
+
+use cosmwasm_std::{
+    coins, BankMsg, Coin, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Storage, Uint128,
+};
+use cw_storage_plus::Map;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::blacklist;
+use crate::error::ContractError;
+use crate::roles::{self, Role};
+use crate::state::{Config, Proposal, CONFIG};
+use crate::treasury::{self, TreasuryAccount};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CreationBond {
+    pub amount: Uint128, // untrn
+    pub posted_at: u64,
+}
+
+// Storage keys
+pub const CREATION_BONDS: Map<String, CreationBond> = Map::new("creation_bonds"); // proposal_id -> bond still held
+
+/// Set the bond new proposals must carry, or drop the requirement with None (owner only).
+/// Bonds already posted are settled at the amount they were posted with.
+pub fn set_creation_bond(
+    deps: DepsMut,
+    info: MessageInfo,
+    amount: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    roles::ensure_role(deps.storage, &info.sender, Role::Owner)?;
+
+    let amount = amount.filter(|amount| !amount.is_zero());
+    let mut config = CONFIG.load(deps.storage)?;
+    config.creation_bond = amount;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "set_creation_bond")
+        .add_attribute(
+            "amount",
+            amount.map_or_else(|| "none".to_string(), |amount| amount.to_string()),
+        ))
+}
+
+/// Take the bond from the funds sent with `CreateProposal`. Returns the amount held, if any.
+pub fn post_bond(
+    storage: &mut dyn Storage,
+    env: &Env,
+    config: &Config,
+    proposal_id: &str,
+    funds: &[Coin],
+) -> Result<Option<Uint128>, ContractError> {
+    let Some(required) = config.creation_bond else {
+        return Ok(None);
+    };
+    let attached: Uint128 = funds
+        .iter()
+        .filter(|coin| coin.denom == "untrn")
+        .map(|coin| coin.amount)
+        .sum();
+    if attached != required {
+        return Err(ContractError::InvalidInput {
+            field: "funds".to_string(),
+            message: format!(
+                "Proposals require a creation bond of exactly {}untrn",
+                required
+            ),
+        });
+    }
+
+    treasury::credit(storage, TreasuryAccount::Bonds, required)?;
+    CREATION_BONDS.save(
+        storage,
+        proposal_id.to_string(),
+        &CreationBond {
+            amount: required,
+            posted_at: env.block.time.seconds(),
+        },
+    )?;
+    Ok(Some(required))
+}
+
+/// Return the proposal's bond to its creator, if one is held
+pub fn refund_bond(storage: &mut dyn Storage, proposal: &Proposal) -> StdResult<Option<BankMsg>> {
+    let Some(bond) = CREATION_BONDS.may_load(storage, proposal.id.clone())? else {
+        return Ok(None);
+    };
+    CREATION_BONDS.remove(storage, proposal.id.clone());
+    treasury::debit(storage, TreasuryAccount::Bonds, bond.amount)?;
+
+    Ok(Some(BankMsg::Send {
+        to_address: proposal.creator.to_string(),
+        amount: coins(bond.amount.u128(), "untrn"),
+    }))
+}
+
+/// Settle the bond of a raise that failed: it goes back to the creator unless they are blacklisted
+pub fn settle_failed_raise(
+    storage: &mut dyn Storage,
+    env: &Env,
+    proposal: &Proposal,
+) -> StdResult<Option<BankMsg>> {
+    if blacklist::is_blacklisted(storage, &proposal.creator) {
+        slash_bond(storage, env, &proposal.id, "creator blacklisted")?;
+        return Ok(None);
+    }
+    refund_bond(storage, proposal)
+}
+
+/// Move the proposal's bond to the treasury's fees bucket, if one is held. Returns the amount
+/// slashed.
+pub fn slash_bond(
+    storage: &mut dyn Storage,
+    env: &Env,
+    proposal_id: &str,
+    reason: &str,
+) -> StdResult<Uint128> {
+    let Some(bond) = CREATION_BONDS.may_load(storage, proposal_id.to_string())? else {
+        return Ok(Uint128::zero());
+    };
+    CREATION_BONDS.remove(storage, proposal_id.to_string());
+    treasury::transfer(
+        storage,
+        env,
+        TreasuryAccount::Bonds,
+        TreasuryAccount::Fees,
+        bond.amount,
+        &format!("creation bond for {} slashed: {}", proposal_id, reason),
+    )?;
+    Ok(bond.amount)
+}
+
+// Query functions
+pub fn query_creation_bond(deps: Deps, proposal_id: String) -> StdResult<Option<CreationBond>> {
+    CREATION_BONDS.may_load(deps.storage, proposal_id)
+}
@@ -1,7 +1,9 @@
-use cosmwasm_std::{Addr, Deps, DepsMut, StdResult, Uint128};
+use cosmwasm_std::{Addr, Deps, Env, Order, StdResult, Storage, Uint128};
+use cw_storage_plus::{Bound, Map};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::error::ContractError;
 use crate::state::{Investment, Proposal, ProposalStatus, INVESTMENTS, PROPOSALS};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -55,6 +57,174 @@ pub struct ComplianceSummary {
     pub common_issues: Vec<String>,
 }
 
+/// Encoding of a compliance report page
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportFormat {
+    #[default]
+    Json, // Structured rows
+    Csv, // One comma-separated line per row, with the column names in `header`
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ProposalComplianceRow {
+    pub proposal_id: String,
+    pub proposal_title: String,
+    pub creator: Addr,
+    pub status: ProposalStatus,
+    pub total_raised: Uint128,
+    pub investor_count: u64,
+    pub compliance_status: ComplianceStatus,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ProposalCompliancePage {
+    pub rows: Vec<ProposalComplianceRow>, // Empty in CSV format
+    pub header: Option<String>,           // CSV format only
+    pub lines: Vec<String>,               // CSV format only
+    pub next_key: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct InvestmentCompliancePage {
+    pub proposal_id: String,
+    pub rows: Vec<InvestmentSummary>, // Empty in CSV format
+    pub header: Option<String>,       // CSV format only
+    pub lines: Vec<String>,           // CSV format only
+    pub next_key: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RegCfCapacityResponse {
+    pub creator: Addr,
+    pub limit: Uint128,
+    pub raised_in_window: Uint128, // Completed raises funded since window_start, plus open ones' caps
+    pub remaining: Uint128,
+    pub window_start: u64,
+}
+
+/// Reg CF caps what one issuer may raise in any 12 months ($5M in micro units)
+pub const REG_CF_ISSUER_LIMIT: Uint128 = Uint128::new(5_000_000_000_000);
+const REG_CF_WINDOW_SECONDS: u64 = 365 * 24 * 60 * 60;
+const RAISE_BUCKET_SECONDS: u64 = 24 * 60 * 60;
+
+// Storage keys
+pub const ISSUER_RAISES: Map<(&Addr, u64), Uint128> = Map::new("issuer_raises"); // (creator, day) -> completed or reserved that day
+pub const COUNTED_RAISES: Map<&str, (u64, Uint128)> = Map::new("counted_raises"); // proposal_id -> (day, amount) it added
+
+/// Day bucket a timestamp falls in
+pub(crate) fn bucket_day(timestamp: u64) -> u64 {
+    timestamp / RAISE_BUCKET_SECONDS
+}
+
+/// First day bucket of the rolling 12-month window
+pub(crate) fn window_start_day(now: u64) -> u64 {
+    bucket_day(now.saturating_sub(REG_CF_WINDOW_SECONDS))
+}
+
+/// Start of the rolling 12-month window, rounded down to its day bucket
+pub(crate) fn window_start(now: u64) -> u64 {
+    window_start_day(now) * RAISE_BUCKET_SECONDS
+}
+
+/// What the creator's raises within the last 12 months brought in or still hold while open
+pub fn raised_in_window(storage: &dyn Storage, creator: &Addr, now: u64) -> StdResult<Uint128> {
+    ISSUER_RAISES
+        .prefix(creator)
+        .range(
+            storage,
+            Some(Bound::inclusive(window_start_day(now))),
+            None,
+            Order::Ascending,
+        )
+        .map(|item| item.map(|(_, raised)| raised))
+        .sum()
+}
+
+/// Reject a raise that would take the creator past the Reg CF limit for the last 12 months
+pub fn enforce_issuer_limit(
+    storage: &dyn Storage,
+    creator: &Addr,
+    now: u64,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    let raised = raised_in_window(storage, creator, now)?;
+    let total = raised
+        .checked_add(amount)
+        .map_err(|_| ContractError::Overflow {
+            operation: "issuer limit check".to_string(),
+        })?;
+    if total > REG_CF_ISSUER_LIMIT {
+        return Err(ContractError::IssuerLimitExceeded {
+            limit: REG_CF_ISSUER_LIMIT,
+            raised,
+            requested: amount,
+        });
+    }
+    Ok(())
+}
+
+/// Hold at least `amount` of the creator's Reg CF allowance for a raise that is still open.
+///
+/// Capacity is reserved when the proposal is created, so completing the raise never has to check
+/// the limit again. Only growth past what the raise already holds is checked, which happens when
+/// USD-denominated terms are re-priced upwards.
+pub fn reserve_issuer_capacity(
+    storage: &mut dyn Storage,
+    creator: &Addr,
+    proposal_id: &str,
+    now: u64,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    let counted = COUNTED_RAISES.may_load(storage, proposal_id)?;
+    let held = counted.map_or(Uint128::zero(), |(_, held)| held);
+    if amount <= held {
+        return Ok(());
+    }
+    enforce_issuer_limit(storage, creator, now, amount - held)?;
+    let reserved_at = counted.map_or(now, |(day, _)| day * RAISE_BUCKET_SECONDS);
+    record_issuer_raise(storage, creator, proposal_id, reserved_at, amount)?;
+    Ok(())
+}
+
+/// Count a raise towards the creator's rolling total, replacing what it counted before
+pub fn record_issuer_raise(
+    storage: &mut dyn Storage,
+    creator: &Addr,
+    proposal_id: &str,
+    funded_at: u64,
+    amount: Uint128,
+) -> StdResult<()> {
+    release_issuer_raise(storage, creator, proposal_id)?;
+    let day = bucket_day(funded_at);
+    ISSUER_RAISES.update(storage, (creator, day), |raised| -> StdResult<_> {
+        Ok(raised.unwrap_or_default().checked_add(amount)?)
+    })?;
+    COUNTED_RAISES.save(storage, proposal_id, &(day, amount))
+}
+
+/// Take a raise that failed or was cancelled back out of the creator's rolling total
+pub fn release_issuer_raise(
+    storage: &mut dyn Storage,
+    creator: &Addr,
+    proposal_id: &str,
+) -> StdResult<()> {
+    let Some((day, amount)) = COUNTED_RAISES.may_load(storage, proposal_id)? else {
+        return Ok(());
+    };
+    COUNTED_RAISES.remove(storage, proposal_id);
+    let raised = ISSUER_RAISES
+        .may_load(storage, (creator, day))?
+        .unwrap_or_default()
+        .saturating_sub(amount);
+    if raised.is_zero() {
+        ISSUER_RAISES.remove(storage, (creator, day));
+    } else {
+        ISSUER_RAISES.save(storage, (creator, day), &raised)?;
+    }
+    Ok(())
+}
+
 /// Generate compliance report for a specific proposal
 pub fn generate_proposal_compliance_report(
     deps: Deps,
@@ -73,31 +243,12 @@ pub fn generate_proposal_compliance_report(
         cosmwasm_std::Order::Ascending,
     ) {
         let (investor_addr, investment) = item?;
-
-        let ownership_percentage = if proposal.financial_terms.total_shares > 0 {
-            format!(
-                "{:.2}%",
-                (investment.shares as f64 / proposal.financial_terms.total_shares as f64) * 100.0
-            )
-        } else {
-            "0.00%".to_string()
-        };
-
-        let summary = InvestmentSummary {
-            investor: investor_addr,
-            total_invested: investment.amount,
-            shares_owned: investment.shares,
-            ownership_percentage,
-            investment_date: investment.timestamp,
-            status: format!("{:?}", investment.status),
-        };
-
-        investment_summaries.push(summary);
         total_shares_distributed += investment.shares;
+        investment_summaries.push(summarize_investment(&proposal, investor_addr, investment));
     }
 
     // Check compliance status
-    let compliance_status = check_proposal_compliance(&proposal, &investment_summaries);
+    let compliance_status = check_proposal_compliance(&proposal, investment_summaries.len() as u64);
 
     Ok(ComplianceReport {
         proposal_id: proposal_id.to_string(),
@@ -111,17 +262,31 @@ pub fn generate_proposal_compliance_report(
     })
 }
 
-/// Check compliance status for a proposal
-fn check_proposal_compliance(
+fn summarize_investment(
     proposal: &Proposal,
-    investments: &[InvestmentSummary],
-) -> ComplianceStatus {
+    investor: Addr,
+    investment: Investment,
+) -> InvestmentSummary {
+    InvestmentSummary {
+        investor,
+        total_invested: investment.amount,
+        shares_owned: investment.shares,
+        ownership_percentage: crate::math::format_percentage(
+            investment.shares.into(),
+            proposal.financial_terms.total_shares.into(),
+        ),
+        investment_date: investment.timestamp,
+        status: format!("{:?}", investment.status),
+    }
+}
+
+/// Check compliance status for a proposal
+fn check_proposal_compliance(proposal: &Proposal, investor_count: u64) -> ComplianceStatus {
     let mut issues = Vec::new();
     let mut is_compliant = true;
 
     // Check Regulation CF compliance (max $5M per 12 months)
-    let reg_cf_limit = Uint128::from(5_000_000_000_000u128); // $5M in micro units
-    let reg_cf_compliant = proposal.funding_status.raised_amount <= reg_cf_limit;
+    let reg_cf_compliant = proposal.funding_status.raised_amount <= REG_CF_ISSUER_LIMIT;
     if !reg_cf_compliant {
         issues.push("Exceeds Regulation CF $5M limit".to_string());
         is_compliant = false;
@@ -129,7 +294,7 @@ fn check_proposal_compliance(
 
     // Check maximum investors (if specified)
     let max_investors_check = if let Some(max_investors) = proposal.compliance.max_investors {
-        let check_passed = investments.len() as u64 <= max_investors;
+        let check_passed = investor_count <= max_investors;
         if !check_passed {
             issues.push(format!(
                 "Exceeds maximum investor limit of {}",
@@ -202,14 +367,8 @@ pub fn generate_platform_compliance_report(deps: Deps) -> StdResult<PlatformComp
     }
 
     // Calculate compliance rate
-    let compliance_rate = if total_proposals > 0 {
-        format!(
-            "{:.2}%",
-            (compliant_count as f64 / total_proposals as f64) * 100.0
-        )
-    } else {
-        "0.00%".to_string()
-    };
+    let compliance_rate =
+        crate::math::format_percentage(compliant_count.into(), total_proposals.into());
 
     // Find common issues
     let mut issue_counts = std::collections::HashMap::new();
@@ -243,41 +402,201 @@ pub fn generate_platform_compliance_report(deps: Deps) -> StdResult<PlatformComp
     })
 }
 
+/// Quote a CSV field when it holds a separator, quote or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn csv_line(fields: &[String]) -> String {
+    fields
+        .iter()
+        .map(|field| csv_field(field))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn report_limit(limit: Option<u32>) -> usize {
+    limit
+        .unwrap_or(crate::gas_optimization::DEFAULT_LIMIT)
+        .min(crate::gas_optimization::MAX_LIMIT) as usize
+}
+
+/// One page of per-proposal compliance status, without the investment lists
+pub fn query_proposal_compliance_page(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+    format: Option<ReportFormat>,
+) -> StdResult<ProposalCompliancePage> {
+    let limit = report_limit(limit);
+    let start = start_after.map(Bound::exclusive);
+    let mut rows = PROPOSALS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit + 1)
+        .map(|item| {
+            item.map(|(proposal_id, proposal)| ProposalComplianceRow {
+                compliance_status: check_proposal_compliance(
+                    &proposal,
+                    proposal.funding_status.investor_count,
+                ),
+                proposal_id,
+                proposal_title: proposal.asset_details.name,
+                creator: proposal.creator,
+                status: proposal.status,
+                total_raised: proposal.funding_status.raised_amount,
+                investor_count: proposal.funding_status.investor_count,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    let next_key = crate::next_page_key(&mut rows, limit, |row| row.proposal_id.clone());
+
+    if format.unwrap_or_default() == ReportFormat::Json {
+        return Ok(ProposalCompliancePage {
+            rows,
+            header: None,
+            lines: vec![],
+            next_key,
+        });
+    }
+    let lines = rows
+        .into_iter()
+        .map(|row| {
+            csv_line(&[
+                row.proposal_id,
+                row.proposal_title,
+                row.creator.to_string(),
+                format!("{:?}", row.status),
+                row.total_raised.to_string(),
+                row.investor_count.to_string(),
+                row.compliance_status.is_compliant.to_string(),
+                row.compliance_status.issues.join("; "),
+            ])
+        })
+        .collect();
+    Ok(ProposalCompliancePage {
+        rows: vec![],
+        header: Some(
+            "proposal_id,proposal_title,creator,status,total_raised,investor_count,is_compliant,issues"
+                .to_string(),
+        ),
+        lines,
+        next_key,
+    })
+}
+
+/// One page of a proposal's investment summaries, by investor address
+pub fn query_investment_compliance_page(
+    deps: Deps,
+    proposal_id: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+    format: Option<ReportFormat>,
+) -> StdResult<InvestmentCompliancePage> {
+    let proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+    let limit = report_limit(limit);
+    let start_addr = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    let start = start_addr.as_ref().map(Bound::exclusive);
+    let mut rows = INVESTMENTS
+        .prefix(proposal_id.clone())
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit + 1)
+        .map(|item| {
+            item.map(|(investor, investment)| summarize_investment(&proposal, investor, investment))
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    let next_key = crate::next_page_key(&mut rows, limit, |row| row.investor.to_string());
+
+    if format.unwrap_or_default() == ReportFormat::Json {
+        return Ok(InvestmentCompliancePage {
+            proposal_id,
+            rows,
+            header: None,
+            lines: vec![],
+            next_key,
+        });
+    }
+    let lines = rows
+        .into_iter()
+        .map(|row| {
+            csv_line(&[
+                row.investor.to_string(),
+                row.total_invested.to_string(),
+                row.shares_owned.to_string(),
+                row.ownership_percentage,
+                row.investment_date.to_string(),
+                row.status,
+            ])
+        })
+        .collect();
+    Ok(InvestmentCompliancePage {
+        proposal_id,
+        rows: vec![],
+        header: Some(
+            "investor,total_invested,shares_owned,ownership_percentage,investment_date,status"
+                .to_string(),
+        ),
+        lines,
+        next_key,
+    })
+}
+
+/// Reject an investment that would push the investor's cumulative total past the proposal cap
+pub fn enforce_investor_cap(
+    proposal: &Proposal,
+    already_invested: Uint128,
+    investment_amount: Uint128,
+) -> Result<(), ContractError> {
+    if let Some(cap) = proposal.compliance.max_investment_per_user {
+        let new_total = already_invested
+            .checked_add(investment_amount)
+            .map_err(|_| ContractError::Overflow {
+                operation: "investor cap check".to_string(),
+            })?;
+        if new_total > cap {
+            return Err(ContractError::InvestorCapExceeded {
+                cap,
+                current: already_invested,
+            });
+        }
+    }
+    Ok(())
+}
+
 /// Validate investment against compliance rules
 pub fn validate_investment_compliance(
     deps: Deps,
+    env: &Env,
     proposal: &Proposal,
     investor: &Addr,
     investment_amount: Uint128,
 ) -> Result<(), Vec<String>> {
     let mut violations = Vec::new();
 
-    // Check if KYC is required
-    if proposal.compliance.kyc_required {
-        // In production, this would check against a KYC database
-        // For now, we'll assume compliance
-    }
-
-    // Check if accredited investor only
-    if proposal.compliance.accredited_only {
-        // In production, this would verify accredited investor status
-        violations.push("Investment requires accredited investor verification".to_string());
+    // Check KYC and accredited investor status against the registry
+    if let Err(err) = crate::kyc::enforce_verification(deps.storage, env, proposal, investor) {
+        violations.push(err.to_string());
     }
 
     // Check maximum investors limit
-    if let Some(max_investors) = proposal.compliance.max_investors {
-        if proposal.funding_status.investor_count >= max_investors {
-            violations.push(format!(
-                "Maximum investor limit of {} reached",
-                max_investors
-            ));
-        }
+    let max_investors = proposal.compliance.investor_cap();
+    if proposal.funding_status.investor_count >= max_investors {
+        violations.push(format!(
+            "Maximum investor limit of {} reached",
+            max_investors
+        ));
     }
 
-    // Check individual investment limits (placeholder for SEC rules)
-    let individual_limit = Uint128::from(10_000_000_000u128); // $10K limit example
-    if investment_amount > individual_limit {
-        violations.push("Investment exceeds individual investor limit".to_string());
+    // Check individual investment limits
+    if let Some(cap) = proposal.compliance.max_investment_per_user {
+        if investment_amount > cap {
+            violations.push("Investment exceeds individual investor limit".to_string());
+        }
     }
 
     if violations.is_empty() {
@@ -286,3 +605,22 @@ pub fn validate_investment_compliance(
         Err(violations)
     }
 }
+
+// Query functions
+pub fn query_creator_reg_cf_capacity(
+    deps: Deps,
+    env: Env,
+    creator: String,
+) -> StdResult<RegCfCapacityResponse> {
+    let creator = deps.api.addr_validate(&creator)?;
+    let now = env.block.time.seconds();
+    let raised = raised_in_window(deps.storage, &creator, now)?;
+
+    Ok(RegCfCapacityResponse {
+        creator,
+        limit: REG_CF_ISSUER_LIMIT,
+        raised_in_window: raised,
+        remaining: REG_CF_ISSUER_LIMIT.saturating_sub(raised),
+        window_start: window_start(now),
+    })
+}
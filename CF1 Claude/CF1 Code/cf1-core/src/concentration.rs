@@ -0,0 +1,153 @@
+use cosmwasm_std::{Deps, Env, StdResult, Storage};
+use cw_storage_plus::Map;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::state::{InvestmentStatus, INVESTMENTS, PROPOSAL_INVESTMENTS};
+
+/// Number of largest holders summed for the top-holder share
+pub const TOP_HOLDERS: usize = 10;
+
+/// Ownership concentration of an asset's cap table
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct HolderMetrics {
+    pub proposal_id: String,
+    pub unique_holders: u64,
+    pub total_shares: u64,
+    pub largest_holder_bps: u64, // Share of the largest single holder
+    pub top_holders_bps: u64,    // Combined share of the top 10 holders
+    pub gini_bps: u64, // 0 = perfectly even, approaches 10000 as one holder owns everything
+    pub updated_at: u64,
+}
+
+// Storage keys
+pub const HOLDER_METRICS: Map<String, HolderMetrics> = Map::new("holder_metrics");
+
+/// Compute metrics from the current cap table
+fn compute(storage: &dyn Storage, proposal_id: &str, updated_at: u64) -> StdResult<HolderMetrics> {
+    let investors = PROPOSAL_INVESTMENTS
+        .may_load(storage, proposal_id.to_string())?
+        .unwrap_or_default();
+
+    let mut holdings = Vec::with_capacity(investors.len());
+    for investor in &investors {
+        if let Some(investment) =
+            INVESTMENTS.may_load(storage, (proposal_id.to_string(), investor))?
+        {
+            let holds = matches!(
+                investment.status,
+                InvestmentStatus::Pending | InvestmentStatus::Completed
+            );
+            if holds && investment.shares > 0 {
+                holdings.push(investment.shares as u128);
+            }
+        }
+    }
+    holdings.sort_unstable();
+
+    let n = holdings.len() as u128;
+    let total: u128 = holdings.iter().sum();
+    if total == 0 {
+        return Ok(HolderMetrics {
+            proposal_id: proposal_id.to_string(),
+            updated_at,
+            ..HolderMetrics::default()
+        });
+    }
+
+    let bps = |part: u128| (part * 10_000 / total) as u64;
+    let top: u128 = holdings.iter().rev().take(TOP_HOLDERS).sum();
+
+    // Gini over holdings sorted ascending: (2 * sum(i * x_i)) / (n * total) - (n + 1) / n
+    let weighted: u128 = holdings
+        .iter()
+        .enumerate()
+        .map(|(i, shares)| (i as u128 + 1) * shares)
+        .sum();
+    let gini_bps = (2 * weighted * 10_000 / (n * total)).saturating_sub((n + 1) * 10_000 / n);
+
+    Ok(HolderMetrics {
+        proposal_id: proposal_id.to_string(),
+        unique_holders: n as u64,
+        total_shares: total as u64,
+        largest_holder_bps: bps(*holdings.last().unwrap_or(&0)),
+        top_holders_bps: bps(top),
+        gini_bps: gini_bps as u64,
+        updated_at,
+    })
+}
+
+/// Recompute and store metrics after the cap table changed
+pub fn refresh(
+    storage: &mut dyn Storage,
+    env: &Env,
+    proposal_id: &str,
+) -> StdResult<HolderMetrics> {
+    let metrics = compute(storage, proposal_id, env.block.time.seconds())?;
+    HOLDER_METRICS.save(storage, proposal_id.to_string(), &metrics)?;
+    Ok(metrics)
+}
+
+// Query functions
+pub fn query_holder_metrics(deps: Deps, env: Env, proposal_id: String) -> StdResult<HolderMetrics> {
+    // Before distribution nothing is stored yet; report the live cap table instead
+    match HOLDER_METRICS.may_load(deps.storage, proposal_id.clone())? {
+        Some(metrics) => Ok(metrics),
+        None => compute(deps.storage, &proposal_id, env.block.time.seconds()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::Investment;
+    use cosmwasm_std::testing::{mock_env, MockApi, MockStorage};
+    use cosmwasm_std::Uint128;
+
+    fn seed(storage: &mut MockStorage, shares: &[u64]) {
+        let api = MockApi::default();
+        let mut investors = vec![];
+        for (i, shares) in shares.iter().enumerate() {
+            let investor = api.addr_make(&format!("holder{}", i));
+            INVESTMENTS
+                .save(
+                    storage,
+                    ("p1".to_string(), &investor),
+                    &Investment {
+                        investor: investor.clone(),
+                        proposal_id: "p1".to_string(),
+                        amount: Uint128::new(*shares as u128),
+                        shares: *shares,
+                        timestamp: 0,
+                        status: InvestmentStatus::Completed,
+                    },
+                )
+                .unwrap();
+            investors.push(investor);
+        }
+        PROPOSAL_INVESTMENTS
+            .save(storage, "p1".to_string(), &investors)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_even_and_concentrated_cap_tables() {
+        let env = mock_env();
+
+        let mut even = MockStorage::new();
+        seed(&mut even, &[100; 20]);
+        let metrics = refresh(&mut even, &env, "p1").unwrap();
+        assert_eq!(metrics.unique_holders, 20);
+        assert_eq!(metrics.largest_holder_bps, 500);
+        assert_eq!(metrics.top_holders_bps, 5_000);
+        assert_eq!(metrics.gini_bps, 0);
+
+        let mut concentrated = MockStorage::new();
+        seed(&mut concentrated, &[1, 1, 1, 997]);
+        let metrics = refresh(&mut concentrated, &env, "p1").unwrap();
+        assert_eq!(metrics.largest_holder_bps, 9_970);
+        assert_eq!(metrics.top_holders_bps, 10_000);
+        // Maximum possible Gini for 4 holders is (n - 1) / n = 7500
+        assert_eq!(metrics.gini_bps, 7_470);
+    }
+}
@@ -0,0 +1,301 @@
+use cosmwasm_std::{
+    to_json_binary, Addr, BankMsg, Coin, Deps, DepsMut, Env, MessageInfo, Response, StdResult,
+    Storage, Uint128,
+};
+use cw_storage_plus::{Item, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+use crate::roles::{self, Role};
+use crate::signatures::SignedAuthorization;
+
+/// Co-signature policy for large investment tickets
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CosignConfig {
+    /// Investments at or above this amount need a second approval (None = disabled)
+    pub threshold: Option<Uint128>,
+    /// How long a pending ticket can wait for approval before the investor may reclaim it
+    pub approval_window_seconds: u64,
+}
+
+impl Default for CosignConfig {
+    fn default() -> Self {
+        Self {
+            threshold: None,
+            approval_window_seconds: 3 * 24 * 60 * 60, // 3 days
+        }
+    }
+}
+
+/// Investment held by the contract until it is co-signed
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PendingInvestment {
+    pub ticket_id: u64,
+    pub investor: Addr,
+    pub proposal_id: String,
+    pub amount: Uint128,
+    pub created_at: u64,
+    pub expires_at: u64,
+}
+
+// Storage keys
+pub const COSIGN_CONFIG: Item<CosignConfig> = Item::new("cosign_config");
+pub const PENDING_INVESTMENTS: Map<u64, PendingInvestment> = Map::new("pending_inv");
+pub const PENDING_INVESTMENT_COUNT: Item<u64> = Item::new("pending_inv_count");
+pub const SECONDARY_KEYS: Map<&Addr, Addr> = Map::new("secondary_keys"); // investor -> co-signing key
+
+/// Initialize co-signing with default configuration (disabled)
+pub fn initialize(storage: &mut dyn Storage) -> StdResult<()> {
+    COSIGN_CONFIG.save(storage, &CosignConfig::default())?;
+    PENDING_INVESTMENT_COUNT.save(storage, &0u64)
+}
+
+/// Check whether an investment of this size must be co-signed
+pub fn requires_cosign(storage: &dyn Storage, amount: Uint128) -> StdResult<bool> {
+    let config = COSIGN_CONFIG.may_load(storage)?.unwrap_or_default();
+    Ok(matches!(config.threshold, Some(threshold) if amount >= threshold))
+}
+
+/// Phase one: park the investor's funds in a pending ticket awaiting approval
+pub fn propose_investment(
+    deps: DepsMut,
+    env: &Env,
+    investor: &Addr,
+    proposal_id: String,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    let config = COSIGN_CONFIG.may_load(deps.storage)?.unwrap_or_default();
+    let current_time = env.block.time.seconds();
+
+    let ticket_id = PENDING_INVESTMENT_COUNT
+        .may_load(deps.storage)?
+        .unwrap_or(0)
+        + 1;
+    PENDING_INVESTMENT_COUNT.save(deps.storage, &ticket_id)?;
+
+    let pending = PendingInvestment {
+        ticket_id,
+        investor: investor.clone(),
+        proposal_id: proposal_id.clone(),
+        amount,
+        created_at: current_time,
+        expires_at: current_time + config.approval_window_seconds,
+    };
+    PENDING_INVESTMENTS.save(deps.storage, ticket_id, &pending)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "invest_pending_cosign")
+        .add_attribute("proposal_id", &proposal_id)
+        .add_attribute("investor", investor)
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("ticket_id", ticket_id.to_string())
+        .add_attribute("expires_at", pending.expires_at.to_string()))
+}
+
+/// Phase two: a compliance officer or the investor's secondary key approves the ticket
+pub fn confirm_investment(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    ticket_id: u64,
+) -> Result<Response, ContractError> {
+    confirm_as(deps, env, info.sender, ticket_id)
+}
+
+/// Phase two via an off-chain signature of the investor's secondary key, relayed by anyone
+pub fn confirm_investment_signed(
+    mut deps: DepsMut,
+    env: Env,
+    ticket_id: u64,
+    authorization: SignedAuthorization,
+) -> Result<Response, ContractError> {
+    let cosigner = crate::signatures::verify_and_consume(
+        deps.branch(),
+        &env,
+        &authorization,
+        "confirm_investment",
+        to_json_binary(&ticket_id)?,
+    )?;
+    confirm_as(deps, env, cosigner, ticket_id)
+}
+
+fn confirm_as(
+    deps: DepsMut,
+    env: Env,
+    cosigner: Addr,
+    ticket_id: u64,
+) -> Result<Response, ContractError> {
+    let pending = PENDING_INVESTMENTS
+        .may_load(deps.storage, ticket_id)?
+        .ok_or(ContractError::PendingInvestmentNotFound { ticket_id })?;
+
+    let secondary_key = SECONDARY_KEYS.may_load(deps.storage, &pending.investor)?;
+    let is_cosigner = secondary_key.as_ref() == Some(&cosigner)
+        || roles::has_role(deps.storage, &cosigner, Role::ComplianceOfficer)?;
+    if !is_cosigner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if env.block.time.seconds() > pending.expires_at {
+        return Err(ContractError::CosignExpired { ticket_id });
+    }
+
+    PENDING_INVESTMENTS.remove(deps.storage, ticket_id);
+
+    let response = crate::record_investment(
+        deps,
+        &env,
+        &pending.investor,
+        pending.proposal_id,
+        pending.amount,
+    )?;
+
+    Ok(response
+        .add_attribute("ticket_id", ticket_id.to_string())
+        .add_attribute("cosigner", cosigner))
+}
+
+/// Return the funds of a ticket that expired or can no longer be confirmed
+pub fn reclaim_pending_investment(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    ticket_id: u64,
+    operation_id: Option<String>,
+) -> Result<Response, ContractError> {
+    crate::idempotency::claim(
+        deps.storage,
+        &env,
+        &info.sender,
+        "reclaim_pending_investment",
+        operation_id.as_deref(),
+    )?;
+
+    let pending = PENDING_INVESTMENTS
+        .may_load(deps.storage, ticket_id)?
+        .ok_or(ContractError::PendingInvestmentNotFound { ticket_id })?;
+
+    if pending.investor != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Before expiry the ticket can still be co-signed, unless the proposal stopped accepting funds
+    let proposal = crate::state::PROPOSALS.load(deps.storage, pending.proposal_id.clone())?;
+    let still_investable = proposal.status == crate::state::ProposalStatus::Active
+        && !proposal.funding_status.is_funded;
+    if env.block.time.seconds() <= pending.expires_at && still_investable {
+        return Err(ContractError::CosignPending { ticket_id });
+    }
+
+    PENDING_INVESTMENTS.remove(deps.storage, ticket_id);
+    crate::treasury::debit(
+        deps.storage,
+        crate::treasury::TreasuryAccount::Escrow,
+        pending.amount,
+    )?;
+
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: pending.investor.to_string(),
+            amount: vec![Coin {
+                denom: "untrn".to_string(),
+                amount: pending.amount,
+            }],
+        })
+        .add_attribute("action", "reclaim_pending_investment")
+        .add_attribute("ticket_id", ticket_id.to_string())
+        .add_attribute("amount", pending.amount.to_string()))
+}
+
+/// Register (or replace) the caller's secondary co-signing key
+pub fn register_secondary_key(
+    deps: DepsMut,
+    info: MessageInfo,
+    key: String,
+) -> Result<Response, ContractError> {
+    let key_addr = deps.api.addr_validate(&key)?;
+    if key_addr == info.sender {
+        return Err(ContractError::InvalidInput {
+            field: "key".to_string(),
+            message: "Secondary key must differ from the investor address".to_string(),
+        });
+    }
+
+    SECONDARY_KEYS.save(deps.storage, &info.sender, &key_addr)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "register_secondary_key")
+        .add_attribute("investor", info.sender)
+        .add_attribute("key", key_addr))
+}
+
+/// Update co-signing policy (compliance officer only); a zero threshold disables co-signing
+pub fn update_cosign_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    threshold: Option<Uint128>,
+    approval_window_seconds: Option<u64>,
+) -> Result<Response, ContractError> {
+    roles::ensure_role(deps.storage, &info.sender, Role::ComplianceOfficer)?;
+
+    let mut cosign_config = COSIGN_CONFIG.may_load(deps.storage)?.unwrap_or_default();
+
+    if let Some(threshold) = threshold {
+        cosign_config.threshold = if threshold.is_zero() {
+            None
+        } else {
+            Some(threshold)
+        };
+    }
+    if let Some(window) = approval_window_seconds {
+        if window == 0 {
+            return Err(ContractError::InvalidInput {
+                field: "approval_window_seconds".to_string(),
+                message: "Approval window must be greater than zero".to_string(),
+            });
+        }
+        cosign_config.approval_window_seconds = window;
+    }
+
+    COSIGN_CONFIG.save(deps.storage, &cosign_config)?;
+
+    Ok(Response::new().add_attribute("method", "update_cosign_config"))
+}
+
+// Query functions
+pub fn query_pending_investment(deps: Deps, ticket_id: u64) -> StdResult<PendingInvestment> {
+    PENDING_INVESTMENTS.load(deps.storage, ticket_id)
+}
+
+pub fn query_cosign_config(deps: Deps) -> StdResult<CosignConfig> {
+    Ok(COSIGN_CONFIG.may_load(deps.storage)?.unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::MockStorage;
+
+    #[test]
+    fn test_requires_cosign_threshold() {
+        let mut storage = MockStorage::new();
+        initialize(&mut storage).unwrap();
+
+        // Disabled by default
+        assert!(!requires_cosign(&storage, Uint128::new(u128::MAX)).unwrap());
+
+        COSIGN_CONFIG
+            .save(
+                &mut storage,
+                &CosignConfig {
+                    threshold: Some(Uint128::new(1_000)),
+                    approval_window_seconds: 60,
+                },
+            )
+            .unwrap();
+
+        assert!(!requires_cosign(&storage, Uint128::new(999)).unwrap());
+        assert!(requires_cosign(&storage, Uint128::new(1_000)).unwrap());
+    }
+}
@@ -0,0 +1,116 @@
+use cosmwasm_std::{Addr, Deps, DepsMut, Empty, Env, MessageInfo, Order, Response, StdResult};
+use cw_storage_plus::{Bound, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+use crate::gas_optimization::{DEFAULT_LIMIT, MAX_LIMIT};
+use crate::roles::{self, Role};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CustodiansResponse {
+    pub custodians: Vec<Addr>,
+}
+
+// Storage keys
+pub const CUSTODIANS: Map<&Addr, Empty> = Map::new("custodians");
+
+/// Invest the attached funds on behalf of a customer (allowlisted custodians only).
+///
+/// The investment runs exactly as if the beneficiary had sent it: KYC, whitelist and caps are
+/// checked against the beneficiary, who owns the escrow record, any partial-fill change, refunds
+/// and the eventual tokens.
+pub fn invest_for(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: String,
+    beneficiary: String,
+) -> Result<Response, ContractError> {
+    if !CUSTODIANS.has(deps.storage, &info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+    let beneficiary = deps.api.addr_validate(&beneficiary)?;
+    if beneficiary == info.sender {
+        return Err(ContractError::InvalidInput {
+            field: "beneficiary".to_string(),
+            message: "Custodians invest for themselves with Invest".to_string(),
+        });
+    }
+
+    let custodian = info.sender;
+    let beneficiary_info = MessageInfo {
+        sender: beneficiary.clone(),
+        funds: info.funds,
+    };
+    let response = crate::execute_invest(deps, env, beneficiary_info, proposal_id, None, None)?;
+
+    Ok(response
+        .add_attribute("custodian", custodian)
+        .add_attribute("beneficiary", beneficiary))
+}
+
+/// Allow an address to invest for its customers (compliance officer only)
+pub fn add_custodian(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    roles::ensure_role(deps.storage, &info.sender, Role::ComplianceOfficer)?;
+    let address = deps.api.addr_validate(&address)?;
+    if CUSTODIANS.has(deps.storage, &address) {
+        return Err(ContractError::InvalidInput {
+            field: "address".to_string(),
+            message: "Address is already a custodian".to_string(),
+        });
+    }
+
+    CUSTODIANS.save(deps.storage, &address, &Empty {})?;
+
+    Ok(Response::new()
+        .add_attribute("method", "add_custodian")
+        .add_attribute("address", address))
+}
+
+/// Stop an address from investing for others (compliance officer only). Existing positions stay
+/// with their beneficiaries.
+pub fn remove_custodian(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    roles::ensure_role(deps.storage, &info.sender, Role::ComplianceOfficer)?;
+    let address = deps.api.addr_validate(&address)?;
+    if !CUSTODIANS.has(deps.storage, &address) {
+        return Err(ContractError::InvalidInput {
+            field: "address".to_string(),
+            message: "Address is not a custodian".to_string(),
+        });
+    }
+
+    CUSTODIANS.remove(deps.storage, &address);
+
+    Ok(Response::new()
+        .add_attribute("method", "remove_custodian")
+        .add_attribute("address", address))
+}
+
+// Query functions
+pub fn query_custodians(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<CustodiansResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_addr = start_after
+        .map(|s| deps.api.addr_validate(&s))
+        .transpose()?;
+    let start = start_addr.as_ref().map(Bound::exclusive);
+
+    let custodians = CUSTODIANS
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(CustodiansResponse { custodians })
+}
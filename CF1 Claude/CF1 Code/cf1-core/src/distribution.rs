@@ -0,0 +1,258 @@
+//! Per-investor share mints during token distribution.
+//!
+//! Each mint runs as its own submessage, so one investor's failed mint no longer reverts the
+//! whole batch. A failed investor goes back to `Pending` with a record of the error, the
+//! distribution cursor gives their shares back, and `RetryFailedMints` sends the mint again. The
+//! raise cannot be finalized while any record remains.
+
+use cosmwasm_std::{
+    from_json, to_json_binary, Addr, Deps, DepsMut, Env, MessageInfo, Order, Reply, Response,
+    StdResult, Storage, SubMsg, SubMsgResult, Uint128, WasmMsg,
+};
+use cw20::Cw20ExecuteMsg;
+use cw_storage_plus::{Bound, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+use crate::gas_optimization::{DEFAULT_LIMIT, MAX_LIMIT};
+use crate::roles::{self, Role};
+use crate::security::ReentrancyGuard;
+use crate::state::{
+    InvestmentStatus, DEFAULT_DISTRIBUTION_BATCH, DISTRIBUTION_PROGRESS, INVESTMENTS,
+    MAX_DISTRIBUTION_BATCH, PROPOSALS, TOKEN_CONTRACTS,
+};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FailedMint {
+    pub investor: Addr,
+    pub shares: u64,
+    pub error: String,
+    pub attempts: u32, // Failed mints so far
+    pub failed_at: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FailedMintsResponse {
+    pub failed_mints: Vec<FailedMint>,
+    pub next_key: Option<String>, // Pass as start_after for the next page; None on the last page
+}
+
+/// Carried through the mint submessage so the reply knows whose mint it was
+#[derive(Serialize, Deserialize)]
+struct MintReceipt {
+    proposal_id: String,
+    investor: Addr,
+    shares: u64,
+    release_lock: bool, // Set on the last mint of the call, which releases the reentrancy lock
+}
+
+// Storage keys
+pub const FAILED_MINTS: Map<(&str, &Addr), FailedMint> = Map::new("failed_mints"); // (proposal_id, investor), until a mint succeeds
+
+pub fn has_failed_mint(storage: &dyn Storage, proposal_id: &str, investor: &Addr) -> bool {
+    FAILED_MINTS.has(storage, (proposal_id, investor))
+}
+
+pub fn has_failed_mints(storage: &dyn Storage, proposal_id: &str) -> bool {
+    FAILED_MINTS
+        .prefix(proposal_id)
+        .keys(storage, None, None, Order::Ascending)
+        .next()
+        .is_some()
+}
+
+/// One submessage per investor, each reporting back whether its mint went through.
+///
+/// The caller holds the reentrancy lock; the reply to the last mint releases it.
+pub fn mint_submessages(
+    token_address: &Addr,
+    proposal_id: &str,
+    mints: &[(Addr, u64)],
+) -> StdResult<Vec<SubMsg>> {
+    mints
+        .iter()
+        .enumerate()
+        .map(|(index, (investor, shares))| {
+            let mint = WasmMsg::Execute {
+                contract_addr: token_address.to_string(),
+                msg: to_json_binary(&Cw20ExecuteMsg::Mint {
+                    recipient: investor.to_string(),
+                    amount: Uint128::from(*shares),
+                })?,
+                funds: vec![],
+            };
+            let receipt = MintReceipt {
+                proposal_id: proposal_id.to_string(),
+                investor: investor.clone(),
+                shares: *shares,
+                release_lock: index + 1 == mints.len(),
+            };
+            Ok(SubMsg::reply_always(mint, crate::REPLY_DISTRIBUTION_MINT)
+                .with_payload(to_json_binary(&receipt)?))
+        })
+        .collect()
+}
+
+pub fn handle_mint_reply(deps: DepsMut, env: &Env, msg: Reply) -> Result<Response, ContractError> {
+    let receipt: MintReceipt = from_json(&msg.payload)?;
+    if receipt.release_lock {
+        ReentrancyGuard::exit(deps.storage);
+    }
+    let key = (receipt.proposal_id.as_str(), &receipt.investor);
+
+    let error = match msg.result {
+        SubMsgResult::Ok(_) => {
+            FAILED_MINTS.remove(deps.storage, key);
+            return Ok(Response::new());
+        }
+        SubMsgResult::Err(error) => error,
+    };
+
+    // The investor was marked as paid when the mint was sent; undo that until a retry succeeds
+    let mut investment = INVESTMENTS.load(
+        deps.storage,
+        (receipt.proposal_id.clone(), &receipt.investor),
+    )?;
+    investment.status = InvestmentStatus::Pending;
+    INVESTMENTS.save(
+        deps.storage,
+        (receipt.proposal_id.clone(), &receipt.investor),
+        &investment,
+    )?;
+    if let Some(mut progress) =
+        DISTRIBUTION_PROGRESS.may_load(deps.storage, receipt.proposal_id.clone())?
+    {
+        progress.distributed_count = progress.distributed_count.saturating_sub(1);
+        progress.total_distributed = progress.total_distributed.saturating_sub(receipt.shares);
+        DISTRIBUTION_PROGRESS.save(deps.storage, receipt.proposal_id.clone(), &progress)?;
+    }
+
+    let attempts = FAILED_MINTS
+        .may_load(deps.storage, key)?
+        .map_or(0, |failed| failed.attempts)
+        + 1;
+    FAILED_MINTS.save(
+        deps.storage,
+        key,
+        &FailedMint {
+            investor: receipt.investor.clone(),
+            shares: receipt.shares,
+            error: error.clone(),
+            attempts,
+            failed_at: env.block.time.seconds(),
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "investor_mint_failed")
+        .add_attribute("proposal_id", receipt.proposal_id)
+        .add_attribute("investor", receipt.investor)
+        .add_attribute("attempts", attempts.to_string())
+        .add_attribute("error", error))
+}
+
+/// Send the mint again for up to `limit` investors whose mint failed (creator or operator)
+pub fn retry_failed_mints(
+    deps: DepsMut,
+    info: MessageInfo,
+    proposal_id: String,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    ReentrancyGuard::enter(deps.storage, "retry_failed_mints")?;
+
+    let proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+    if info.sender != proposal.creator
+        && !roles::has_role(deps.storage, &info.sender, Role::Operator)?
+    {
+        return Err(ContractError::Unauthorized {});
+    }
+    crate::freeze::ensure_not_frozen(deps.storage, &proposal_id)?;
+    let token_address = TOKEN_CONTRACTS.load(deps.storage, proposal_id.clone())?;
+    let limit = limit
+        .unwrap_or(DEFAULT_DISTRIBUTION_BATCH)
+        .clamp(1, MAX_DISTRIBUTION_BATCH) as usize;
+
+    let failed = FAILED_MINTS
+        .prefix(&proposal_id)
+        .keys(deps.storage, None, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+    if failed.is_empty() {
+        return Err(ContractError::InvalidInput {
+            field: "proposal_id".to_string(),
+            message: "No failed mints to retry".to_string(),
+        });
+    }
+    let mut progress = DISTRIBUTION_PROGRESS.may_load(deps.storage, proposal_id.clone())?;
+    let mut mints = Vec::new();
+    for investor in failed {
+        // A position that has since been paid or moved away has nothing left to retry
+        let Some(mut investment) =
+            INVESTMENTS.may_load(deps.storage, (proposal_id.clone(), &investor))?
+        else {
+            FAILED_MINTS.remove(deps.storage, (&proposal_id, &investor));
+            continue;
+        };
+        if investment.status != InvestmentStatus::Pending {
+            FAILED_MINTS.remove(deps.storage, (&proposal_id, &investor));
+            continue;
+        }
+
+        investment.status = InvestmentStatus::Completed;
+        INVESTMENTS.save(deps.storage, (proposal_id.clone(), &investor), &investment)?;
+        if let Some(progress) = progress.as_mut() {
+            progress.distributed_count = progress.distributed_count.saturating_add(1);
+            progress.total_distributed =
+                progress.total_distributed.saturating_add(investment.shares);
+        }
+        mints.push((investor, investment.shares));
+    }
+    if let Some(progress) = progress {
+        DISTRIBUTION_PROGRESS.save(deps.storage, proposal_id.clone(), &progress)?;
+    }
+
+    if mints.is_empty() {
+        ReentrancyGuard::exit(deps.storage);
+    }
+
+    Ok(Response::new()
+        .add_submessages(mint_submessages(&token_address, &proposal_id, &mints)?)
+        .add_attribute("action", "retry_failed_mints")
+        .add_attribute("proposal_id", &proposal_id)
+        .add_attribute("retried", mints.len().to_string()))
+}
+
+// Query functions
+/// Investors whose mint failed and has not yet been retried successfully
+pub fn query_failed_mints(
+    deps: Deps,
+    proposal_id: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<FailedMintsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_after = start_after
+        .map(|investor| deps.api.addr_validate(&investor))
+        .transpose()?;
+
+    let mut failed_mints = FAILED_MINTS
+        .prefix(&proposal_id)
+        .range(
+            deps.storage,
+            start_after.as_ref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit + 1)
+        .map(|item| item.map(|(_, failed)| failed))
+        .collect::<StdResult<Vec<_>>>()?;
+    let next_key = crate::next_page_key(&mut failed_mints, limit, |failed| {
+        failed.investor.to_string()
+    });
+
+    Ok(FailedMintsResponse {
+        failed_mints,
+        next_key,
+    })
+}
@@ -0,0 +1,379 @@
+//! Versioned offering documents.
+//!
+//! Documents are content-addressed: each must carry the IPFS CID of its file. Once listed, a
+//! document is never overwritten or dropped. Sending a document under an existing name publishes
+//! a new version of it, and every version stays in the proposal's document history so investors
+//! can see exactly what they relied on.
+//!
+//! The creator, and an auditor the creator names, can attest the current version of a document
+//! by signing its CID with their registered signing key. Attestations are kept with the version
+//! they endorse, so an investor can show which documents were endorsed when they invested.
+
+use cosmwasm_std::{
+    to_json_vec, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult, Storage,
+};
+use cw_storage_plus::{Bound, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::error::ContractError;
+use crate::signatures::SIGNING_KEYS;
+use crate::state::{Document, PROPOSALS};
+
+/// Base58btc alphabet used by CIDv0
+const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const CID_V0_LENGTH: usize = 46;
+/// Shortest base32 CIDv1 (a 32-byte digest) and a generous ceiling for longer digests
+const MIN_CID_V1_LENGTH: usize = 59;
+const MAX_CID_V1_LENGTH: usize = 128;
+const MAX_DOCUMENT_NAME_LENGTH: usize = 100;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DocumentVersion {
+    pub sequence: u64, // Order of publication within the proposal
+    pub version: u32,  // Per document name, starting at 1
+    pub document: Document,
+    pub published_at: u64,
+    pub published_by: Addr,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DocumentHistoryResponse {
+    pub proposal_id: String,
+    pub versions: Vec<DocumentVersion>,
+    pub next_key: Option<String>, // Pass as start_after for the next page; None on the last page
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AttestorRole {
+    Creator,
+    Auditor,
+}
+
+/// What an attestor signs: sha256 of this document's JSON.
+///
+/// Chain id and contract address come from the executing environment, so an attestation only
+/// verifies against the deployment and proposal it was made for.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AttestationDoc {
+    pub chain_id: String,
+    pub contract_address: String,
+    pub proposal_id: String,
+    pub document_name: String,
+    pub version: u32,
+    pub hash: String, // IPFS CID of the attested version
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DocumentAttestation {
+    pub attestor: Addr,
+    pub role: AttestorRole,
+    pub hash: String,
+    pub signature: Binary, // 64-byte secp256k1 signature over the AttestationDoc digest
+    pub attested_at: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DocumentAttestationsResponse {
+    pub proposal_id: String,
+    pub version: DocumentVersion,
+    pub auditor: Option<Addr>,
+    pub attestations: Vec<DocumentAttestation>,
+}
+
+// Storage keys
+pub const DOCUMENT_VERSIONS: Map<(&str, u64), DocumentVersion> = Map::new("document_versions");
+const DOCUMENT_SEQUENCE: Map<&str, u64> = Map::new("document_sequence"); // proposal_id -> last sequence
+const LATEST_VERSIONS: Map<(&str, &str), u32> = Map::new("document_latest"); // (proposal_id, name) -> version
+pub const DOCUMENT_AUDITORS: Map<&str, Addr> = Map::new("document_auditors"); // proposal_id -> auditor
+pub const DOCUMENT_ATTESTATIONS: Map<(&str, u64, &Addr), DocumentAttestation> =
+    Map::new("document_attestations"); // (proposal_id, sequence, attestor)
+
+impl AttestationDoc {
+    pub fn new(env: &Env, proposal_id: &str, version: &DocumentVersion) -> Self {
+        Self {
+            chain_id: env.block.chain_id.clone(),
+            contract_address: env.contract.address.to_string(),
+            proposal_id: proposal_id.to_string(),
+            document_name: version.document.name.clone(),
+            version: version.version,
+            hash: version.document.hash.clone().unwrap_or_default(),
+        }
+    }
+
+    /// Hash that is actually signed
+    pub fn digest(&self) -> StdResult<Vec<u8>> {
+        Ok(Sha256::digest(to_json_vec(self)?).to_vec())
+    }
+}
+
+/// CIDv0 (`Qm...`, base58btc) or base32 CIDv1 (`b...`)
+fn is_cid(hash: &str) -> bool {
+    if let Some(rest) = hash.strip_prefix("Qm") {
+        return hash.len() == CID_V0_LENGTH && rest.chars().all(|c| BASE58_ALPHABET.contains(c));
+    }
+    if let Some(rest) = hash.strip_prefix('b') {
+        return (MIN_CID_V1_LENGTH..=MAX_CID_V1_LENGTH).contains(&hash.len())
+            && rest
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || ('2'..='7').contains(&c));
+    }
+    false
+}
+
+/// Every document needs a name, unique within the batch, and the CID of its content
+pub fn validate(documents: &[Document]) -> Result<(), ContractError> {
+    for (index, document) in documents.iter().enumerate() {
+        if document.name.trim().is_empty() || document.name.len() > MAX_DOCUMENT_NAME_LENGTH {
+            return Err(ContractError::InvalidInput {
+                field: "documents.name".to_string(),
+                message: format!(
+                    "Document names must be 1-{} characters",
+                    MAX_DOCUMENT_NAME_LENGTH
+                ),
+            });
+        }
+        if documents[..index]
+            .iter()
+            .any(|other| other.name == document.name)
+        {
+            return Err(ContractError::InvalidInput {
+                field: "documents.name".to_string(),
+                message: format!("Duplicate document: {}", document.name),
+            });
+        }
+        if !document.hash.as_deref().is_some_and(is_cid) {
+            return Err(ContractError::InvalidInput {
+                field: "documents.hash".to_string(),
+                message: format!(
+                    "{} needs the IPFS CID (v0 or v1) of its file",
+                    document.name
+                ),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Merge `incoming` into the proposal's `current` documents, publishing a version for each one
+/// that is new or changed. Documents left out of `incoming` stay as they are. Returns the names
+/// of the documents that changed.
+pub fn publish(
+    storage: &mut dyn Storage,
+    env: &Env,
+    publisher: &Addr,
+    proposal_id: &str,
+    current: &mut Vec<Document>,
+    incoming: Vec<Document>,
+) -> Result<Vec<String>, ContractError> {
+    validate(&incoming)?;
+
+    let mut changed = vec![];
+    for document in incoming {
+        match current
+            .iter_mut()
+            .find(|existing| existing.name == document.name)
+        {
+            Some(existing) if *existing == document => continue,
+            Some(existing) => *existing = document.clone(),
+            None => current.push(document.clone()),
+        }
+
+        let version = LATEST_VERSIONS
+            .may_load(storage, (proposal_id, &document.name))?
+            .unwrap_or_default()
+            + 1;
+        LATEST_VERSIONS.save(storage, (proposal_id, &document.name), &version)?;
+        let sequence = DOCUMENT_SEQUENCE
+            .may_load(storage, proposal_id)?
+            .unwrap_or_default()
+            + 1;
+        DOCUMENT_SEQUENCE.save(storage, proposal_id, &sequence)?;
+
+        changed.push(document.name.clone());
+        DOCUMENT_VERSIONS.save(
+            storage,
+            (proposal_id, sequence),
+            &DocumentVersion {
+                sequence,
+                version,
+                document,
+                published_at: env.block.time.seconds(),
+                published_by: publisher.clone(),
+            },
+        )?;
+    }
+    Ok(changed)
+}
+
+/// Latest published version of the named document
+fn current_version(
+    storage: &dyn Storage,
+    proposal_id: &str,
+    name: &str,
+) -> Result<DocumentVersion, ContractError> {
+    for item in DOCUMENT_VERSIONS
+        .prefix(proposal_id)
+        .range(storage, None, None, Order::Descending)
+    {
+        let (_, version) = item?;
+        if version.document.name == name {
+            return Ok(version);
+        }
+    }
+    Err(ContractError::InvalidInput {
+        field: "document_name".to_string(),
+        message: format!("No document named {} on proposal {}", name, proposal_id),
+    })
+}
+
+/// Name (or clear) the auditor allowed to attest the proposal's documents (creator only)
+pub fn set_document_auditor(
+    deps: DepsMut,
+    info: MessageInfo,
+    proposal_id: String,
+    auditor: Option<String>,
+) -> Result<Response, ContractError> {
+    let proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+    if proposal.creator != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let auditor = auditor
+        .map(|auditor| deps.api.addr_validate(&auditor))
+        .transpose()?;
+    match &auditor {
+        Some(auditor) if *auditor == proposal.creator => {
+            return Err(ContractError::InvalidInput {
+                field: "auditor".to_string(),
+                message: "The creator cannot be their own auditor".to_string(),
+            });
+        }
+        Some(auditor) => DOCUMENT_AUDITORS.save(deps.storage, &proposal_id, auditor)?,
+        None => DOCUMENT_AUDITORS.remove(deps.storage, &proposal_id),
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "set_document_auditor")
+        .add_attribute("proposal_id", proposal_id)
+        .add_attribute(
+            "auditor",
+            auditor
+                .map(|auditor| auditor.to_string())
+                .unwrap_or_default(),
+        ))
+}
+
+/// Creator or auditor endorses the current version of a document with a signature over its CID
+pub fn attest_document(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: String,
+    document_name: String,
+    signature: Binary,
+) -> Result<Response, ContractError> {
+    let proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+    let role = if proposal.creator == info.sender {
+        AttestorRole::Creator
+    } else if DOCUMENT_AUDITORS.may_load(deps.storage, &proposal_id)? == Some(info.sender.clone()) {
+        AttestorRole::Auditor
+    } else {
+        return Err(ContractError::Unauthorized {});
+    };
+
+    let pubkey = SIGNING_KEYS.may_load(deps.storage, &info.sender)?.ok_or(
+        ContractError::SigningKeyNotRegistered {
+            signer: info.sender.to_string(),
+        },
+    )?;
+    let version = current_version(deps.storage, &proposal_id, &document_name)?;
+    let doc = AttestationDoc::new(&env, &proposal_id, &version);
+    let valid = deps
+        .api
+        .secp256k1_verify(&doc.digest()?, &signature, &pubkey)
+        .unwrap_or(false);
+    if !valid {
+        return Err(ContractError::InvalidSignature {});
+    }
+
+    DOCUMENT_ATTESTATIONS.save(
+        deps.storage,
+        (&proposal_id, version.sequence, &info.sender),
+        &DocumentAttestation {
+            attestor: info.sender.clone(),
+            role,
+            hash: doc.hash,
+            signature,
+            attested_at: env.block.time.seconds(),
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "attest_document")
+        .add_attribute("proposal_id", proposal_id)
+        .add_attribute("document", document_name)
+        .add_attribute("version", version.version.to_string())
+        .add_attribute("attestor", info.sender))
+}
+
+// Query functions
+/// Every version published for the proposal, oldest first
+pub fn query_document_history(
+    deps: Deps,
+    proposal_id: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<DocumentHistoryResponse> {
+    let limit = limit
+        .unwrap_or(crate::gas_optimization::DEFAULT_LIMIT)
+        .min(crate::gas_optimization::MAX_LIMIT) as usize;
+    let min = start_after
+        .map(|s| {
+            s.parse::<u64>().map_err(|_| {
+                cosmwasm_std::StdError::generic_err(
+                    "start_after must be a document sequence number",
+                )
+            })
+        })
+        .transpose()?
+        .map(Bound::exclusive);
+
+    let mut versions = DOCUMENT_VERSIONS
+        .prefix(&proposal_id)
+        .range(deps.storage, min, None, Order::Ascending)
+        .take(limit + 1)
+        .map(|item| item.map(|(_, version)| version))
+        .collect::<StdResult<Vec<_>>>()?;
+    let next_key =
+        crate::next_page_key(&mut versions, limit, |version| version.sequence.to_string());
+
+    Ok(DocumentHistoryResponse {
+        proposal_id,
+        versions,
+        next_key,
+    })
+}
+
+/// Attestations on one published version, identified by its history sequence number
+pub fn query_document_attestations(
+    deps: Deps,
+    proposal_id: String,
+    sequence: u64,
+) -> StdResult<DocumentAttestationsResponse> {
+    let version = DOCUMENT_VERSIONS.load(deps.storage, (&proposal_id, sequence))?;
+    let attestations = DOCUMENT_ATTESTATIONS
+        .prefix((&proposal_id, sequence))
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, attestation)| attestation))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(DocumentAttestationsResponse {
+        auditor: DOCUMENT_AUDITORS.may_load(deps.storage, &proposal_id)?,
+        proposal_id,
+        version,
+        attestations,
+    })
+}
@@ -75,6 +75,9 @@ pub enum ContractError {
     #[error("No investments to refund")]
     NoInvestmentsToRefund {},
 
+    #[error("Refund already claimed for proposal: {proposal_id}")]
+    RefundAlreadyClaimed { proposal_id: String },
+
     #[error("Refund failed")]
     RefundFailed {},
 
@@ -138,4 +141,152 @@ pub enum ContractError {
 
     #[error("Reply parsing failed: {message}")]
     ReplyParseFailed { message: String },
+
+    #[error("Investment exceeds per-investor cap: cap={cap}, already invested={current}")]
+    InvestorCapExceeded { cap: Uint128, current: Uint128 },
+
+    #[error("Reg CF annual investor limit exceeded: limit={limit}, invested={invested}, requested={requested}")]
+    InvestorAnnualLimitExceeded {
+        limit: Uint128,
+        invested: Uint128,
+        requested: Uint128,
+    },
+
+    #[error("Reg CF 12-month issuer limit exceeded: limit={limit}, raised={raised}, requested={requested}")]
+    IssuerLimitExceeded {
+        limit: Uint128,
+        raised: Uint128,
+        requested: Uint128,
+    },
+
+    #[error("Pending investment not found: {ticket_id}")]
+    PendingInvestmentNotFound { ticket_id: u64 },
+
+    #[error("Co-signature window expired for pending investment: {ticket_id}")]
+    CosignExpired { ticket_id: u64 },
+
+    #[error("Pending investment {ticket_id} is still awaiting co-signature")]
+    CosignPending { ticket_id: u64 },
+
+    #[error("Investor is not KYC verified: {investor}")]
+    InvestorNotVerified { investor: String },
+
+    #[error("Investor {investor} is not on the whitelist for proposal {proposal_id}")]
+    NotWhitelisted {
+        proposal_id: String,
+        investor: String,
+    },
+
+    #[error("KYC verification expired for investor: {investor}")]
+    VerificationExpired { investor: String },
+
+    #[error("Proposal is limited to accredited investors: {investor}")]
+    AccreditationRequired { investor: String },
+
+    #[error("No signing key registered for: {signer}")]
+    SigningKeyNotRegistered { signer: String },
+
+    #[error("Invalid signature")]
+    InvalidSignature {},
+
+    #[error("Invalid nonce: expected {expected}, got {got}")]
+    InvalidNonce { expected: u64, got: u64 },
+
+    #[error("Milestone not found: {index}")]
+    MilestoneNotFound { index: u32 },
+
+    #[error("Milestone already released: {index}")]
+    MilestoneAlreadyReleased { index: u32 },
+
+    #[error("Milestone {index} has not been approved by the admin or a majority of holders")]
+    MilestoneNotApproved { index: u32 },
+
+    #[error("Governance proposal not found: {governance_id}")]
+    GovernanceProposalNotFound { governance_id: u64 },
+
+    #[error("Voting is closed for governance proposal: {governance_id}")]
+    VotingClosed { governance_id: u64 },
+
+    #[error("Voting is still open for governance proposal: {governance_id}")]
+    VotingStillOpen { governance_id: u64 },
+
+    #[error("Already voted on governance proposal: {governance_id}")]
+    AlreadyVoted { governance_id: u64 },
+
+    #[error("Holder snapshot is still being recorded for governance proposal: {governance_id}")]
+    GovernanceSnapshotPending { governance_id: u64 },
+
+    #[error("Reconfirmation window is still open for proposal: {proposal_id}")]
+    ReconfirmationPending { proposal_id: String },
+
+    #[error("No open reconfirmation window for proposal: {proposal_id}")]
+    NoReconfirmationWindow { proposal_id: String },
+
+    #[error("Reconfirmation window has closed for proposal: {proposal_id}")]
+    ReconfirmationWindowClosed { proposal_id: String },
+
+    #[error("Operation already processed: {operation_id}")]
+    DuplicateOperation { operation_id: String },
+
+    #[error("Asset retirement has not been approved by a holder vote: {proposal_id}")]
+    RetirementNotApproved { proposal_id: String },
+
+    #[error("Asset has been retired: {proposal_id}")]
+    AssetRetired { proposal_id: String },
+
+    #[error("No NTRN/USD price feed is configured")]
+    PriceFeedNotConfigured {},
+
+    #[error(
+        "Price feed is stale: last update {age_seconds}s ago, maximum {max_staleness_seconds}s"
+    )]
+    StalePriceFeed {
+        age_seconds: u64,
+        max_staleness_seconds: u64,
+    },
+
+    #[error("Price feed returned an invalid price")]
+    InvalidOraclePrice {},
+
+    #[error("An amendment is already open for proposal: {proposal_id}")]
+    AmendmentPending { proposal_id: String },
+
+    #[error("No open amendment for proposal: {proposal_id}")]
+    NoOpenAmendment { proposal_id: String },
+
+    #[error("Amendment voting is still open for proposal: {proposal_id}")]
+    AmendmentVotingOpen { proposal_id: String },
+
+    #[error("Already voted on the amendment for proposal: {proposal_id}")]
+    AlreadyVotedOnAmendment { proposal_id: String },
+
+    #[error("Funding deadline has already been extended for proposal: {proposal_id}")]
+    DeadlineAlreadyExtended { proposal_id: String },
+
+    #[error("Soft cap not reached: raised {raised}, soft cap {soft_cap}")]
+    SoftCapNotReached { raised: Uint128, soft_cap: Uint128 },
+
+    #[error("No referral rewards to claim")]
+    NoReferralRewards {},
+
+    #[error("Contract is paused: {reason}")]
+    ContractPaused { reason: String },
+
+    #[error("No vested tokens to claim")]
+    NoVestedTokens {},
+
+    #[error("Transfer exceeds the unlocked part of the position: {available} shares available")]
+    TransferExceedsUnlocked { available: u64 },
+
+    #[error("Investment cannot be withdrawn before {available_at}")]
+    WithdrawalCooldown { available_at: u64 },
+
+    #[error("Raises above {threshold} require a verified creator")]
+    CreatorNotVerified { threshold: Uint128 },
+
+    #[error("Creator is blacklisted: {creator}")]
+    CreatorBlacklisted { creator: String },
+
+    #[error("Proposal is frozen pending investigation: {proposal_id}")]
+    ProposalFrozen { proposal_id: String },
 }
@@ -0,0 +1,210 @@
+//! Optional external custody of investor funds.
+//!
+//! When the config names an escrow contract, each investment is forwarded there with an
+//! `EscrowDeposit` and the launchpad keeps only the accounting. Refunds and payouts for the
+//! proposal then ask the escrow contract to release the funds. A proposal's funds stay with the
+//! escrow contract that took its first deposit, even if the config later changes.
+//!
+//! The treasury's Escrow bucket only counts funds the launchpad holds itself, so forwarded funds
+//! are tracked per proposal in `EXTERNAL_ESCROWS` instead.
+
+use cf1_types::EscrowExecuteMsg;
+use cosmwasm_std::{
+    coins, from_json, to_json_binary, Addr, CosmosMsg, DepsMut, Env, MessageInfo, Reply, Response,
+    StdResult, Storage, SubMsg, Uint128, WasmMsg,
+};
+use cw_storage_plus::Map;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+use crate::roles::{self, Role};
+use crate::state::CONFIG;
+use crate::treasury::{self, TreasuryAccount};
+
+/// Funds a proposal has placed with an external escrow contract
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ExternalEscrow {
+    pub contract: Addr,
+    pub amount: Uint128,
+}
+
+/// Carried to the deposit reply, which books the funds once the escrow contract accepted them
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+struct DepositReceipt {
+    proposal_id: String,
+    contract: Addr,
+    amount: Uint128,
+}
+
+// Storage keys
+pub const EXTERNAL_ESCROWS: Map<String, ExternalEscrow> = Map::new("external_escrows");
+
+/// Name the contract new investments are deposited with, or keep funds here with None (owner only)
+pub fn set_escrow_contract(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: Option<String>,
+) -> Result<Response, ContractError> {
+    roles::ensure_role(deps.storage, &info.sender, Role::Owner)?;
+    let address = address
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+
+    let mut config = CONFIG.load(deps.storage)?;
+    config.escrow_contract = address.clone();
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "set_escrow_contract")
+        .add_attribute(
+            "escrow_contract",
+            address.map_or_else(|| "none".to_string(), |address| address.to_string()),
+        ))
+}
+
+/// Forward an investment to the proposal's escrow contract, if it uses one
+pub fn deposit_message(
+    storage: &dyn Storage,
+    proposal_id: &str,
+    investor: &Addr,
+    amount: Uint128,
+) -> StdResult<Option<SubMsg>> {
+    let contract = match EXTERNAL_ESCROWS.may_load(storage, proposal_id.to_string())? {
+        Some(escrow) => Some(escrow.contract),
+        None => CONFIG.load(storage)?.escrow_contract,
+    };
+    let Some(contract) = contract else {
+        return Ok(None);
+    };
+
+    let deposit = WasmMsg::Execute {
+        contract_addr: contract.to_string(),
+        msg: to_json_binary(&EscrowExecuteMsg::EscrowDeposit {
+            proposal_id: proposal_id.to_string(),
+            investor: investor.to_string(),
+        })?,
+        funds: coins(amount.u128(), "untrn"),
+    };
+    let receipt = DepositReceipt {
+        proposal_id: proposal_id.to_string(),
+        contract,
+        amount,
+    };
+    Ok(Some(
+        SubMsg::reply_on_success(deposit, crate::REPLY_ESCROW_DEPOSIT)
+            .with_payload(to_json_binary(&receipt)?),
+    ))
+}
+
+pub fn handle_deposit_reply(deps: DepsMut, msg: Reply) -> Result<Response, ContractError> {
+    let receipt: DepositReceipt = from_json(&msg.payload)?;
+
+    EXTERNAL_ESCROWS.update(
+        deps.storage,
+        receipt.proposal_id.clone(),
+        |escrow| -> StdResult<_> {
+            let amount = escrow.map(|escrow| escrow.amount).unwrap_or_default();
+            Ok(ExternalEscrow {
+                contract: receipt.contract.clone(),
+                amount: amount.checked_add(receipt.amount)?,
+            })
+        },
+    )?;
+
+    Ok(Response::new())
+}
+
+/// Take up to `amount` out of the proposal's externally held funds.
+///
+/// What is taken is booked back into the Escrow bucket, so callers debit the whole payout once
+/// its messages are built, wherever the funds were held.
+fn take(
+    storage: &mut dyn Storage,
+    proposal_id: &str,
+    amount: Uint128,
+) -> StdResult<Option<(Addr, Uint128)>> {
+    let Some(mut escrow) = EXTERNAL_ESCROWS.may_load(storage, proposal_id.to_string())? else {
+        return Ok(None);
+    };
+    let taken = escrow.amount.min(amount);
+    if taken.is_zero() {
+        return Ok(None);
+    }
+    escrow.amount -= taken;
+    EXTERNAL_ESCROWS.save(storage, proposal_id.to_string(), &escrow)?;
+    treasury::credit(storage, TreasuryAccount::Escrow, taken)?;
+    Ok(Some((escrow.contract, taken)))
+}
+
+fn release_message(
+    contract: &Addr,
+    proposal_id: &str,
+    recipient: &Addr,
+    amount: Uint128,
+) -> StdResult<CosmosMsg> {
+    Ok(WasmMsg::Execute {
+        contract_addr: contract.to_string(),
+        msg: to_json_binary(&EscrowExecuteMsg::EscrowRelease {
+            proposal_id: proposal_id.to_string(),
+            recipient: recipient.to_string(),
+            amount,
+        })?,
+        funds: vec![],
+    }
+    .into())
+}
+
+/// Pay a proposal's escrowed funds to `recipient`.
+///
+/// Funds held externally are released straight to the recipient, unless the payout has to
+/// travel on over IBC, in which case they come back here first. Anything held locally is paid as
+/// before.
+pub fn payout_messages(
+    storage: &mut dyn Storage,
+    env: &Env,
+    proposal_id: &str,
+    recipient: &Addr,
+    amount: Uint128,
+) -> StdResult<Vec<CosmosMsg>> {
+    let mut messages = vec![];
+    let mut local = amount;
+    if let Some((contract, released)) = take(storage, proposal_id, amount)? {
+        if crate::interchain::routes_over_ibc(storage, recipient)? {
+            messages.push(release_message(
+                &contract,
+                proposal_id,
+                &env.contract.address,
+                released,
+            )?);
+        } else {
+            messages.push(release_message(
+                &contract,
+                proposal_id,
+                recipient,
+                released,
+            )?);
+            local -= released;
+        }
+    }
+    if !local.is_zero() {
+        messages.push(crate::interchain::payout_message(
+            storage, env, recipient, local,
+        )?);
+    }
+    Ok(messages)
+}
+
+/// Bring a proposal's escrowed funds back into the launchpad, e.g. for fees it keeps
+pub fn reclaim_message(
+    storage: &mut dyn Storage,
+    env: &Env,
+    proposal_id: &str,
+    amount: Uint128,
+) -> StdResult<Option<CosmosMsg>> {
+    take(storage, proposal_id, amount)?
+        .map(|(contract, released)| {
+            release_message(&contract, proposal_id, &env.contract.address, released)
+        })
+        .transpose()
+}
@@ -0,0 +1,111 @@
+use cosmwasm_std::{Addr, Event, Response, Uint128};
+
+pub use cf1_types::events::{event_type, EVENT_VERSION, NOTIFICATION_SCHEMA_VERSION};
+
+/// Versioned event for an action, carrying the action itself as its first attribute
+pub fn event(action: &str) -> Event {
+    Event::new(event_type(action)).add_attribute("action", action)
+}
+
+/// Fold a handler's loose attributes into one versioned event named after its action.
+///
+/// User actions tag themselves with "action" and admin actions with "method"; both come out as
+/// "action". Responses without either, such as those already built from a typed constructor
+/// below, pass through untouched.
+pub fn standardize(mut response: Response) -> Response {
+    let Some(action) = response
+        .attributes
+        .iter()
+        .find(|attr| attr.key == "action" || attr.key == "method")
+        .map(|attr| attr.value.clone())
+    else {
+        return response;
+    };
+
+    let attributes = std::mem::take(&mut response.attributes)
+        .into_iter()
+        .filter(|attr| attr.key != "action" && attr.key != "method");
+    response
+        .events
+        .insert(0, event(&action).add_attributes(attributes));
+    response
+}
+
+/// A position (or part of one) changed hands through TransferShares
+pub fn transfer_shares(
+    proposal_id: &str,
+    from: &Addr,
+    to: &Addr,
+    shares: u64,
+    amount: Uint128,
+    tokens_moved: bool,
+) -> Event {
+    event("transfer_shares")
+        .add_attribute("proposal_id", proposal_id)
+        .add_attribute("from", from)
+        .add_attribute("to", to)
+        .add_attribute("shares", shares.to_string())
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("tokens_moved", tokens_moved.to_string())
+}
+
+/// The creator used their one deadline extension
+pub fn extend_funding_deadline(
+    proposal_id: &str,
+    previous_deadline: u64,
+    new_deadline: u64,
+) -> Event {
+    event("extend_funding_deadline")
+        .add_attribute("proposal_id", proposal_id)
+        .add_attribute("previous_deadline", previous_deadline.to_string())
+        .add_attribute("new_deadline", new_deadline.to_string())
+}
+
+/// Notification event for off-chain services, carrying its attribute schema version
+fn notification(action: &str, proposal_id: &str) -> Event {
+    event(action)
+        .add_attribute("schema_version", NOTIFICATION_SCHEMA_VERSION.to_string())
+        .add_attribute("proposal_id", proposal_id)
+}
+
+/// A raise reached its target and closed as funded
+pub fn funding_complete(
+    proposal_id: &str,
+    creator: &Addr,
+    raised_amount: Uint128,
+    investor_count: u64,
+    lockup_end: u64,
+) -> Event {
+    notification("funding_complete", proposal_id)
+        .add_attribute("creator", creator)
+        .add_attribute("raised_amount", raised_amount.to_string())
+        .add_attribute("investor_count", investor_count.to_string())
+        .add_attribute("lockup_end", lockup_end.to_string())
+}
+
+/// Every investor received their share tokens
+pub fn tokens_distributed(
+    proposal_id: &str,
+    token_contract: &Addr,
+    total_shares: u64,
+    investor_count: u64,
+) -> Event {
+    notification("tokens_distributed", proposal_id)
+        .add_attribute("token_contract", token_contract)
+        .add_attribute("total_shares", total_shares.to_string())
+        .add_attribute("investor_count", investor_count.to_string())
+}
+
+/// A lockup ended and the shares became transferable
+pub fn lockup_expired(proposal_id: &str, lockup_end: u64) -> Event {
+    notification("lockup_expired", proposal_id).add_attribute("lockup_end", lockup_end.to_string())
+}
+
+/// Escrowed funds went back to an investor; `reason` is one of "proposal_cancelled",
+/// "proposal_failed", "withdrawn" or "not_reconfirmed"
+pub fn refund_issued(proposal_id: &str, investor: &Addr, amount: Uint128, reason: &str) -> Event {
+    notification("refund_issued", proposal_id)
+        .add_attribute("investor", investor)
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("reason", reason)
+}
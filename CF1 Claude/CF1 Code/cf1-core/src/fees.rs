@@ -0,0 +1,131 @@
+use cosmwasm_std::{Deps, DepsMut, MessageInfo, Response, StdResult, Storage, Uint128};
+use cw_storage_plus::Map;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+use crate::roles::{self, Role};
+use crate::security::MathGuard;
+use crate::state::{Config, Proposal, ProposalStatus, CONFIG, PROPOSALS};
+
+/// Most brackets a fee schedule can hold
+pub const MAX_FEE_BRACKETS: usize = 10;
+
+/// Fee rate for raises up to `up_to`; the whole raise is charged at its bracket's rate
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FeeBracket {
+    pub up_to: Uint128,
+    pub fee_bps: u16,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FeeQuoteResponse {
+    pub proposal_id: String,
+    pub raised_amount: Uint128,
+    pub fee_bps: u16,
+    pub platform_fee: Uint128,
+    pub creator_amount: Uint128, // Before any milestone escrow
+}
+
+// Storage keys
+pub const PROPOSAL_FEE_OVERRIDES: Map<String, u16> = Map::new("proposal_fee_overrides"); // Negotiated rate replacing the schedule
+
+/// Fee rate for a raise of this size; past the last bracket `platform_fee_bps` applies
+pub fn fee_bps_for(config: &Config, raised: Uint128) -> u16 {
+    config
+        .fee_schedule
+        .iter()
+        .find(|bracket| raised <= bracket.up_to)
+        .map_or(config.platform_fee_bps, |bracket| bracket.fee_bps)
+}
+
+/// Fee rate charged on a proposal: its negotiated override, else the schedule rate for its raise
+pub fn proposal_fee_bps(
+    storage: &dyn Storage,
+    config: &Config,
+    proposal: &Proposal,
+) -> StdResult<u16> {
+    Ok(PROPOSAL_FEE_OVERRIDES
+        .may_load(storage, proposal.id.clone())?
+        .unwrap_or_else(|| fee_bps_for(config, proposal.funding_status.raised_amount)))
+}
+
+/// Fix a custom fee for one raise, e.g. a large institutional deal (owner only). Only while the
+/// raise is still open, so investors never see the terms change after funding.
+pub fn set_proposal_fee_override(
+    deps: DepsMut,
+    info: MessageInfo,
+    proposal_id: String,
+    fee_bps: u16,
+) -> Result<Response, ContractError> {
+    roles::ensure_role(deps.storage, &info.sender, Role::Owner)?;
+    if fee_bps > 10_000 {
+        return Err(ContractError::InvalidPlatformFee {});
+    }
+    let proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+    if proposal.status != ProposalStatus::Active || proposal.funding_status.is_funded {
+        return Err(ContractError::ProposalNotActive {});
+    }
+
+    PROPOSAL_FEE_OVERRIDES.save(deps.storage, proposal_id.clone(), &fee_bps)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "set_proposal_fee_override")
+        .add_attribute("proposal_id", proposal_id)
+        .add_attribute("fee_bps", fee_bps.to_string()))
+}
+
+/// Replace the raise-size fee schedule (fee manager only). An empty schedule charges the flat fee.
+pub fn set_fee_schedule(
+    deps: DepsMut,
+    info: MessageInfo,
+    brackets: Vec<FeeBracket>,
+) -> Result<Response, ContractError> {
+    roles::ensure_role(deps.storage, &info.sender, Role::FeeManager)?;
+    if brackets.len() > MAX_FEE_BRACKETS {
+        return Err(ContractError::InvalidInput {
+            field: "brackets".to_string(),
+            message: format!("At most {} fee brackets", MAX_FEE_BRACKETS),
+        });
+    }
+    let mut previous: Option<Uint128> = None;
+    for bracket in &brackets {
+        if bracket.fee_bps > 10_000 {
+            return Err(ContractError::InvalidPlatformFee {});
+        }
+        if previous.is_some_and(|previous| bracket.up_to <= previous) {
+            return Err(ContractError::InvalidInput {
+                field: "brackets".to_string(),
+                message: "Bracket limits must be strictly increasing".to_string(),
+            });
+        }
+        previous = Some(bracket.up_to);
+    }
+
+    let mut config = CONFIG.load(deps.storage)?;
+    config.fee_schedule = brackets;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "set_fee_schedule")
+        .add_attribute("brackets", config.fee_schedule.len().to_string()))
+}
+
+// Query functions
+pub fn query_fee_quote(deps: Deps, proposal_id: String) -> StdResult<FeeQuoteResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+
+    let raised_amount = proposal.funding_status.raised_amount;
+    let fee_bps = proposal_fee_bps(deps.storage, &config, &proposal)?;
+    let platform_fee = MathGuard::calculate_percentage(raised_amount, fee_bps)
+        .map_err(|err| cosmwasm_std::StdError::generic_err(err.to_string()))?;
+
+    Ok(FeeQuoteResponse {
+        proposal_id,
+        raised_amount,
+        fee_bps,
+        platform_fee,
+        creator_amount: raised_amount - platform_fee,
+    })
+}
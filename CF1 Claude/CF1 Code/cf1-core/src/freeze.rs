@@ -0,0 +1,98 @@
+//! Proposal freezes.
+//!
+//! A compliance officer can freeze a proposal while it is investigated. The freeze is kept apart
+//! from `ProposalStatus`, so the proposal's place in its lifecycle is unchanged and picks up where
+//! it left off once unfrozen. While frozen, the proposal takes no new investments, pending
+//! co-signed tickets cannot be confirmed, and nothing from the raise is distributed: no share
+//! mints, no payout, no milestone tranches and no vested creator tokens. Refunds, cancellation and
+//! reclaiming pending tickets keep working.
+
+use cosmwasm_std::{Addr, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Storage};
+use cw_storage_plus::Map;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+use crate::review::MAX_REASON_LENGTH;
+use crate::roles::{self, Role};
+use crate::security::InputValidator;
+use crate::state::PROPOSALS;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ProposalFreeze {
+    pub reason: String,
+    pub frozen_by: Addr,
+    pub frozen_at: u64,
+}
+
+// Storage keys
+pub const PROPOSAL_FREEZES: Map<String, ProposalFreeze> = Map::new("proposal_freezes"); // proposal_id -> freeze
+
+pub fn is_frozen(storage: &dyn Storage, proposal_id: &str) -> bool {
+    PROPOSAL_FREEZES.has(storage, proposal_id.to_string())
+}
+
+/// Fail if the proposal is frozen; guards investments and distribution
+pub fn ensure_not_frozen(storage: &dyn Storage, proposal_id: &str) -> Result<(), ContractError> {
+    if is_frozen(storage, proposal_id) {
+        return Err(ContractError::ProposalFrozen {
+            proposal_id: proposal_id.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Freeze a proposal pending investigation (compliance officer only)
+pub fn freeze_proposal(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: String,
+    reason: String,
+) -> Result<Response, ContractError> {
+    roles::ensure_role(deps.storage, &info.sender, Role::ComplianceOfficer)?;
+    InputValidator::validate_length("reason", &reason, 1, MAX_REASON_LENGTH)?;
+
+    PROPOSALS.load(deps.storage, proposal_id.clone())?;
+    ensure_not_frozen(deps.storage, &proposal_id)?;
+    PROPOSAL_FREEZES.save(
+        deps.storage,
+        proposal_id.clone(),
+        &ProposalFreeze {
+            reason: reason.clone(),
+            frozen_by: info.sender,
+            frozen_at: env.block.time.seconds(),
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "freeze_proposal")
+        .add_attribute("proposal_id", &proposal_id)
+        .add_attribute("reason", reason))
+}
+
+/// Lift a freeze; the proposal carries on from the status it was in (compliance officer only)
+pub fn unfreeze_proposal(
+    deps: DepsMut,
+    info: MessageInfo,
+    proposal_id: String,
+) -> Result<Response, ContractError> {
+    roles::ensure_role(deps.storage, &info.sender, Role::ComplianceOfficer)?;
+
+    if !is_frozen(deps.storage, &proposal_id) {
+        return Err(ContractError::InvalidInput {
+            field: "proposal_id".to_string(),
+            message: "Proposal is not frozen".to_string(),
+        });
+    }
+    PROPOSAL_FREEZES.remove(deps.storage, proposal_id.clone());
+
+    Ok(Response::new()
+        .add_attribute("action", "unfreeze_proposal")
+        .add_attribute("proposal_id", &proposal_id))
+}
+
+// Query functions
+pub fn query_proposal_freeze(deps: Deps, proposal_id: String) -> StdResult<Option<ProposalFreeze>> {
+    PROPOSAL_FREEZES.may_load(deps.storage, proposal_id)
+}
@@ -2,7 +2,10 @@
 // Implements gas-efficient patterns and storage optimizations
 
 use crate::error::ContractError;
-use crate::state::{Investment, Proposal, ProposalStatus, INVESTMENTS, PROPOSALS};
+use crate::state::{
+    Proposal, ProposalStatus, INVESTMENTS, PROPOSALS, PROPOSAL_COUNT, STATUS_COUNTS,
+    TOTAL_VALUE_LOCKED,
+};
 use cosmwasm_std::{Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult, Storage, Uint128};
 use cw_storage_plus::{Bound, Item, Map};
 
@@ -10,6 +13,12 @@ use cw_storage_plus::{Bound, Item, Map};
 pub const DEFAULT_LIMIT: u32 = 20; // Increased for better UX while maintaining gas efficiency
 pub const MAX_LIMIT: u32 = 50; // Reduced from 100 to prevent gas limit issues
 pub const BATCH_SIZE: u32 = 25; // Optimal batch size for bulk operations
+pub const MAX_COUNT: usize = 1_000; // Most entries a filtered query scans to report its total
+
+/// Gas budget assumed for a single maintenance transaction
+pub const MAINTENANCE_GAS_LIMIT: u64 = 5_000_000;
+/// Typical serialized proposal size used when estimating maintenance costs
+pub const AVERAGE_PROPOSAL_SIZE: usize = 2_048;
 
 /// Storage access patterns optimized for gas efficiency
 pub struct GasOptimizedStorage;
@@ -32,6 +41,29 @@ impl GasOptimizedStorage {
             .collect()
     }
 
+    /// Load one batch of proposals (with keys) for paginated maintenance handlers
+    pub fn load_batch(
+        storage: &dyn Storage,
+        start_after: Option<String>,
+        limit: usize,
+    ) -> StdResult<Vec<(String, Proposal)>> {
+        let start = start_after.map(Bound::exclusive);
+
+        PROPOSALS
+            .range(storage, start, None, Order::Ascending)
+            .take(limit)
+            .collect()
+    }
+
+    /// Cursor for the next batch, or None when the scan reached the end
+    pub fn next_batch_start(batch: &[(String, Proposal)], limit: usize) -> Option<String> {
+        if batch.len() == limit {
+            batch.last().map(|(id, _)| id.clone())
+        } else {
+            None
+        }
+    }
+
     /// Load only essential proposal data for listings (gas optimization)
     pub fn load_proposal_summary(
         storage: &dyn Storage,
@@ -143,10 +175,24 @@ impl GasOptimizer {
                 // Read operations are cheaper
                 10_000u64 + (data_size as u64 * 2)
             }
+            "process_expired_proposal" => {
+                // Proposal rewrite plus investment status updates
+                40_000u64 + (data_size as u64 * 10)
+            }
+            "process_expired_lockup" => {
+                // Single proposal rewrite
+                20_000u64 + (data_size as u64 * 10)
+            }
             _ => 50_000u64, // Default estimate
         }
     }
 
+    /// Default batch size for a maintenance operation when the caller omits `limit`
+    pub fn maintenance_batch_size(operation: &str) -> u32 {
+        let operation_gas_cost = Self::estimate_proposal_gas_cost(operation, AVERAGE_PROPOSAL_SIZE);
+        Self::calculate_optimal_batch_size(operation_gas_cost, MAINTENANCE_GAS_LIMIT)
+    }
+
     /// Optimize storage layout for frequently accessed data
     pub fn should_use_indexed_storage(access_frequency: AccessFrequency, data_size: usize) -> bool {
         match access_frequency {
@@ -207,32 +253,21 @@ impl MemoryOptimizedQueries {
         Ok(results)
     }
 
-    /// Aggregated statistics without loading full data
+    /// Aggregated statistics read from the running counters, without touching any proposal
     pub fn calculate_platform_stats_efficient(deps: Deps) -> StdResult<PlatformStatsEfficient> {
-        let mut total_proposals = 0u64;
-        let mut total_raised = Uint128::zero();
-        let mut active_proposals = 0u64;
-        let mut funded_proposals = 0u64;
-
-        // Iterate through all proposals but only load essential data
-        for item in PROPOSALS.range(deps.storage, None, None, Order::Ascending) {
-            let (_, proposal) = item?;
-
-            total_proposals += 1;
-            total_raised = total_raised.checked_add(proposal.funding_status.raised_amount)?;
-
-            match proposal.status {
-                ProposalStatus::Active => active_proposals += 1,
-                ProposalStatus::Funded => funded_proposals += 1,
-                _ => {}
-            }
-        }
+        let status_count = |status: ProposalStatus| -> StdResult<u64> {
+            Ok(STATUS_COUNTS
+                .may_load(deps.storage, status.as_str())?
+                .unwrap_or_default())
+        };
 
         Ok(PlatformStatsEfficient {
-            total_proposals,
-            total_raised,
-            active_proposals,
-            funded_proposals,
+            total_proposals: PROPOSAL_COUNT.may_load(deps.storage)?.unwrap_or_default(),
+            total_raised: TOTAL_VALUE_LOCKED
+                .may_load(deps.storage)?
+                .unwrap_or_default(),
+            active_proposals: status_count(ProposalStatus::Active)?,
+            funded_proposals: status_count(ProposalStatus::Funded)?,
         })
     }
 }
@@ -329,7 +364,7 @@ impl EfficientEvents {
     pub fn emit_proposal_created(proposal_id: &str, creator: &str) -> Vec<cosmwasm_std::Attribute> {
         vec![
             cosmwasm_std::attr("action", "create"),
-            cosmwasm_std::attr("id", proposal_id),
+            cosmwasm_std::attr("proposal_id", proposal_id),
             cosmwasm_std::attr("creator", creator),
         ]
     }
@@ -395,6 +430,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_maintenance_batch_size() {
+        // (5M * 0.8) / (40k + 2048 * 10) = 66
+        assert_eq!(
+            GasOptimizer::maintenance_batch_size("process_expired_proposal"),
+            66
+        );
+        // (5M * 0.8) / (20k + 2048 * 10) = 98
+        assert_eq!(
+            GasOptimizer::maintenance_batch_size("process_expired_lockup"),
+            98
+        );
+    }
+
     #[test]
     fn test_storage_optimization_decisions() {
         assert!(GasOptimizer::should_use_indexed_storage(
@@ -1,8 +1,18 @@
-use cosmwasm_std::{Addr, Deps, DepsMut, StdResult, Uint128};
+use cosmwasm_std::{
+    from_json, to_json_binary, Addr, Deps, DepsMut, Env, MessageInfo, Order, Reply, Response,
+    StdResult, Storage, SubMsg, SubMsgResult, WasmMsg,
+};
+use cw_storage_plus::{Bound, Item, Map};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::state::{ProposalStatus, PROPOSALS, TOKEN_CONTRACTS};
+use crate::error::ContractError;
+use crate::gas_optimization::{DEFAULT_LIMIT, MAX_LIMIT};
+use crate::roles::{self, Role};
+use crate::state::{InvestmentStatus, ProposalStatus, INVESTMENTS, PROPOSALS, TOKEN_CONTRACTS};
+
+/// Holders recorded per transaction while snapshotting an asset's holdings
+pub const SNAPSHOT_BATCH_SIZE: usize = 100;
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct GovernanceInfo {
@@ -141,23 +151,6 @@ pub fn get_user_governance_proposals(deps: Deps, user: &Addr) -> StdResult<Vec<G
     Ok(governance_proposals)
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-pub struct GovernanceIntegrationHooks {
-    pub on_proposal_funded: String,
-    pub on_tokens_distributed: String,
-    pub on_lockup_expired: String,
-}
-
-/// Integration hooks for governance module
-/// These would be called by external governance contracts
-pub fn get_governance_hooks() -> GovernanceIntegrationHooks {
-    GovernanceIntegrationHooks {
-        on_proposal_funded: "notify_governance_proposal_funded".to_string(),
-        on_tokens_distributed: "notify_governance_tokens_distributed".to_string(),
-        on_lockup_expired: "notify_governance_lockup_expired".to_string(),
-    }
-}
-
 /// Prepare governance setup data for external governance contract
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct GovernanceSetupData {
@@ -195,14 +188,8 @@ pub fn generate_governance_setup_data(
         let (shareholder_addr, investment) = item?;
 
         if matches!(investment.status, crate::state::InvestmentStatus::Completed) {
-            let percentage = if total_supply > 0 {
-                format!(
-                    "{:.2}%",
-                    (investment.shares as f64 / total_supply as f64) * 100.0
-                )
-            } else {
-                "0.00%".to_string()
-            };
+            let percentage =
+                crate::math::format_percentage(investment.shares.into(), total_supply.into());
 
             shareholders.push(ShareholderInfo {
                 address: shareholder_addr,
@@ -221,3 +208,699 @@ pub fn generate_governance_setup_data(
         shareholders,
     })
 }
+
+/// Quorum, pass threshold and voting period for asset-level governance
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct GovernanceConfig {
+    pub quorum_bps: u16,    // Minimum turnout as a share of total voting power
+    pub threshold_bps: u16, // Yes votes must exceed this share of yes + no votes
+    pub voting_period_seconds: u64,
+}
+
+impl Default for GovernanceConfig {
+    fn default() -> Self {
+        Self {
+            quorum_bps: 2_000,    // 20%
+            threshold_bps: 5_000, // Simple majority
+            voting_period_seconds: 7 * 24 * 60 * 60,
+        }
+    }
+}
+
+/// What happens when a governance proposal passes. Every action applies to the asset whose
+/// holders voted, and only through a passed vote.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum GovernanceAction {
+    Signal {},      // Records the holders' decision without on-chain side effects
+    RetireAsset {}, // Authorizes the creator or an operator to wind the asset down
+    ReleaseMilestone { milestone_index: u32 }, // Pays the creator a tranche held in milestone escrow
+    ExtendLockup { additional_seconds: u64 },  // Locks transfers again if the lockup has ended
+    ReplacePropertyManager { manager: String }, // Records who manages the underlying property
+}
+
+/// Who manages the underlying property for an asset's holders
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PropertyManager {
+    pub manager: Addr,
+    pub appointed_at: u64,
+    pub governance_id: Option<u64>, // Vote that appointed the manager; None when named by the creator
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum VoteOption {
+    Yes,
+    No,
+    Abstain,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub enum GovernanceProposalStatus {
+    Open,
+    Rejected,
+    Executed,
+    Failed, // Passed, but its action could not be applied
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct VoteTally {
+    pub yes: u64,
+    pub no: u64,
+    pub abstain: u64,
+}
+
+impl VoteTally {
+    pub fn turnout(&self) -> u64 {
+        self.yes
+            .saturating_add(self.no)
+            .saturating_add(self.abstain)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct GovernanceProposal {
+    pub id: u64,
+    pub asset_id: String, // Launchpad proposal whose token holders vote
+    pub proposer: Addr,
+    pub title: String,
+    pub description: String,
+    pub action: GovernanceAction,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub snapshot_height: u64, // Holdings at this block determine voting power
+    pub total_voting_power: u64, // Sum of snapshotted holdings
+    #[serde(default)]
+    pub snapshot_cursor: Option<Addr>, // Last holder recorded while the snapshot is incomplete
+    #[serde(default)]
+    pub quorum_bps: u16, // Governance config when the proposal opened
+    #[serde(default)]
+    pub threshold_bps: u16,
+    pub tally: VoteTally,
+    pub status: GovernanceProposalStatus,
+    #[serde(default)]
+    pub execution_error: Option<String>, // Why the action failed, for Failed proposals
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct GovernanceVote {
+    pub voter: Addr,
+    pub option: VoteOption,
+    pub power: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct GovernanceProposalsResponse {
+    pub proposals: Vec<GovernanceProposal>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SnapshotVotingPowerResponse {
+    pub governance_id: u64,
+    pub voter: Addr,
+    pub snapshot_height: u64,
+    pub power: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct GovernanceVotesResponse {
+    pub governance_id: u64,
+    pub tally: VoteTally,
+    pub votes: Vec<GovernanceVote>,
+}
+
+// Storage keys
+pub const GOVERNANCE_CONFIG: Item<GovernanceConfig> = Item::new("governance_config");
+pub const GOVERNANCE_PROPOSALS: Map<u64, GovernanceProposal> = Map::new("gov_proposals");
+pub const GOVERNANCE_PROPOSAL_COUNT: Item<u64> = Item::new("gov_proposal_count");
+pub const GOVERNANCE_VOTES: Map<(u64, &Addr), GovernanceVote> = Map::new("gov_votes");
+pub const GOVERNANCE_SNAPSHOTS: Map<(u64, &Addr), u64> = Map::new("gov_snapshots"); // (governance_id, holder) -> shares
+pub const PENDING_SNAPSHOTS: Map<String, u64> = Map::new("gov_pending_snapshots"); // asset_id -> governance_id still snapshotting
+pub const PROPERTY_MANAGERS: Map<String, PropertyManager> = Map::new("property_managers"); // asset_id -> manager
+
+/// Record the next batch of holders' shares for a governance proposal.
+///
+/// Votes are weighted by this snapshot, so shares bought or transferred after the proposal
+/// opens carry no weight on it. Assets with more holders than one batch finish the snapshot
+/// through `ContinueGovernanceSnapshot`; until then the asset's shares cannot move and votes
+/// are not accepted.
+fn snapshot_holdings(
+    storage: &mut dyn Storage,
+    proposal: &mut GovernanceProposal,
+) -> StdResult<()> {
+    let cursor = proposal.snapshot_cursor.take();
+    let mut holders = INVESTMENTS
+        .prefix(proposal.asset_id.clone())
+        .range(
+            storage,
+            cursor.as_ref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(SNAPSHOT_BATCH_SIZE + 1)
+        .collect::<StdResult<Vec<_>>>()?;
+    if holders.len() > SNAPSHOT_BATCH_SIZE {
+        holders.truncate(SNAPSHOT_BATCH_SIZE);
+        proposal.snapshot_cursor = holders.last().map(|(holder, _)| holder.clone());
+    }
+
+    for (holder, investment) in holders {
+        if investment.status == InvestmentStatus::Completed && investment.shares > 0 {
+            GOVERNANCE_SNAPSHOTS.save(storage, (proposal.id, &holder), &investment.shares)?;
+            proposal.total_voting_power = proposal
+                .total_voting_power
+                .saturating_add(investment.shares);
+        }
+    }
+
+    if proposal.snapshot_cursor.is_some() {
+        PENDING_SNAPSHOTS.save(storage, proposal.asset_id.clone(), &proposal.id)?;
+    } else {
+        PENDING_SNAPSHOTS.remove(storage, proposal.asset_id.clone());
+    }
+    Ok(())
+}
+
+/// Fail while a governance snapshot of the asset is incomplete; guards every cap table change
+pub fn ensure_snapshot_settled(storage: &dyn Storage, asset_id: &str) -> Result<(), ContractError> {
+    if let Some(governance_id) = PENDING_SNAPSHOTS.may_load(storage, asset_id.to_string())? {
+        return Err(ContractError::GovernanceSnapshotPending { governance_id });
+    }
+    Ok(())
+}
+
+/// Initialize governance with the default quorum and threshold
+pub fn initialize(storage: &mut dyn Storage) -> StdResult<()> {
+    GOVERNANCE_CONFIG.save(storage, &GovernanceConfig::default())
+}
+
+/// Open a vote among an asset's token holders (holders with at least 1% of supply)
+pub fn create_governance_proposal(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    asset_id: String,
+    title: String,
+    description: String,
+    action: GovernanceAction,
+) -> Result<Response, ContractError> {
+    if title.trim().is_empty() {
+        return Err(ContractError::InvalidInput {
+            field: "title".to_string(),
+            message: "Title cannot be empty".to_string(),
+        });
+    }
+    if !can_create_governance_proposal(deps.as_ref(), &asset_id, &info.sender)? {
+        return Err(ContractError::Unauthorized {});
+    }
+    validate_action(deps.as_ref(), &asset_id, &action)?;
+    // One snapshot at a time per asset, since shares are locked while it runs
+    ensure_snapshot_settled(deps.storage, &asset_id)?;
+
+    let config = GOVERNANCE_CONFIG
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    let id = GOVERNANCE_PROPOSAL_COUNT
+        .may_load(deps.storage)?
+        .unwrap_or(0)
+        + 1;
+    GOVERNANCE_PROPOSAL_COUNT.save(deps.storage, &id)?;
+
+    let start_time = env.block.time.seconds();
+    let mut proposal = GovernanceProposal {
+        id,
+        asset_id: asset_id.clone(),
+        proposer: info.sender.clone(),
+        title,
+        description,
+        action,
+        start_time,
+        end_time: start_time + config.voting_period_seconds,
+        snapshot_height: env.block.height,
+        total_voting_power: 0,
+        snapshot_cursor: None,
+        quorum_bps: config.quorum_bps,
+        threshold_bps: config.threshold_bps,
+        tally: VoteTally::default(),
+        status: GovernanceProposalStatus::Open,
+        execution_error: None,
+    };
+    snapshot_holdings(deps.storage, &mut proposal)?;
+    GOVERNANCE_PROPOSALS.save(deps.storage, id, &proposal)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "create_governance_proposal")
+        .add_attribute("proposal_id", &asset_id)
+        .add_attribute("governance_id", id.to_string())
+        .add_attribute("proposer", info.sender)
+        .add_attribute("end_time", proposal.end_time.to_string())
+        .add_attribute("snapshot_height", proposal.snapshot_height.to_string())
+        .add_attribute(
+            "snapshot_complete",
+            proposal.snapshot_cursor.is_none().to_string(),
+        ))
+}
+
+/// Record the next batch of holders for a proposal whose snapshot is incomplete (anyone may call)
+pub fn continue_governance_snapshot(
+    deps: DepsMut,
+    governance_id: u64,
+) -> Result<Response, ContractError> {
+    let mut proposal = GOVERNANCE_PROPOSALS
+        .may_load(deps.storage, governance_id)?
+        .ok_or(ContractError::GovernanceProposalNotFound { governance_id })?;
+    if proposal.snapshot_cursor.is_none() {
+        return Err(ContractError::InvalidInput {
+            field: "governance_id".to_string(),
+            message: "Snapshot is already complete".to_string(),
+        });
+    }
+    snapshot_holdings(deps.storage, &mut proposal)?;
+    GOVERNANCE_PROPOSALS.save(deps.storage, governance_id, &proposal)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "continue_governance_snapshot")
+        .add_attribute("proposal_id", &proposal.asset_id)
+        .add_attribute("governance_id", governance_id.to_string())
+        .add_attribute(
+            "snapshot_complete",
+            proposal.snapshot_cursor.is_none().to_string(),
+        ))
+}
+
+/// Reject actions that could not apply to the asset, so holders only vote on ones that can
+fn validate_action(
+    deps: Deps,
+    asset_id: &str,
+    action: &GovernanceAction,
+) -> Result<(), ContractError> {
+    match action {
+        GovernanceAction::Signal {} | GovernanceAction::RetireAsset {} => Ok(()),
+        GovernanceAction::ReleaseMilestone { milestone_index } => {
+            let proposal = PROPOSALS.load(deps.storage, asset_id.to_string())?;
+            crate::milestones::ensure_releasable(deps.storage, &proposal, *milestone_index)
+        }
+        GovernanceAction::ExtendLockup { additional_seconds } => {
+            if *additional_seconds == 0
+                || *additional_seconds > crate::lockup::MAX_LOCKUP_EXTENSION_SECONDS
+            {
+                return Err(ContractError::InvalidInput {
+                    field: "additional_seconds".to_string(),
+                    message: format!(
+                        "Lockup extensions must be between 1 and {} seconds",
+                        crate::lockup::MAX_LOCKUP_EXTENSION_SECONDS
+                    ),
+                });
+            }
+            Ok(())
+        }
+        GovernanceAction::ReplacePropertyManager { manager } => {
+            let manager = deps.api.addr_validate(manager)?;
+            let current = PROPERTY_MANAGERS.may_load(deps.storage, asset_id.to_string())?;
+            if current.is_some_and(|current| current.manager == manager) {
+                return Err(ContractError::InvalidInput {
+                    field: "manager".to_string(),
+                    message: "Already the property manager".to_string(),
+                });
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Apply a just-passed proposal's action; only sent by the launchpad to itself
+pub fn apply_governance_action(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    governance_id: u64,
+) -> Result<Response, ContractError> {
+    if info.sender != env.contract.address {
+        return Err(ContractError::Unauthorized {});
+    }
+    let proposal = GOVERNANCE_PROPOSALS
+        .may_load(deps.storage, governance_id)?
+        .ok_or(ContractError::GovernanceProposalNotFound { governance_id })?;
+    if proposal.status != GovernanceProposalStatus::Executed {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    Ok(apply_action(deps, &env, &proposal)?
+        .add_attribute("action", "apply_governance_action")
+        .add_attribute("proposal_id", &proposal.asset_id)
+        .add_attribute("governance_id", governance_id.to_string()))
+}
+
+/// Close a proposal whose action failed; its changes were rolled back with the submessage
+pub fn handle_action_reply(deps: DepsMut, msg: Reply) -> Result<Response, ContractError> {
+    let governance_id: u64 = from_json(&msg.payload)?;
+    let SubMsgResult::Err(error) = msg.result else {
+        return Ok(Response::new());
+    };
+    let mut proposal = GOVERNANCE_PROPOSALS.load(deps.storage, governance_id)?;
+    proposal.status = GovernanceProposalStatus::Failed;
+    proposal.execution_error = Some(error.clone());
+    GOVERNANCE_PROPOSALS.save(deps.storage, governance_id, &proposal)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "governance_action_failed")
+        .add_attribute("proposal_id", &proposal.asset_id)
+        .add_attribute("governance_id", governance_id.to_string())
+        .add_attribute("error", error))
+}
+
+/// Carry out a passed proposal's action on its asset
+fn apply_action(
+    deps: DepsMut,
+    env: &Env,
+    proposal: &GovernanceProposal,
+) -> Result<Response, ContractError> {
+    let asset_id = &proposal.asset_id;
+    match &proposal.action {
+        GovernanceAction::Signal {} => Ok(Response::new()),
+        GovernanceAction::RetireAsset {} => {
+            crate::retirement::record_approval(deps.storage, asset_id, proposal.id)?;
+            Ok(Response::new())
+        }
+        GovernanceAction::ReleaseMilestone { milestone_index } => {
+            crate::milestones::release_by_governance(deps.storage, env, asset_id, *milestone_index)
+        }
+        GovernanceAction::ExtendLockup { additional_seconds } => {
+            let lockup_end = crate::lockup::extend(
+                deps.storage,
+                asset_id,
+                env.block.time.seconds(),
+                *additional_seconds,
+            )?;
+            Ok(Response::new().add_attribute("lockup_end", lockup_end.to_string()))
+        }
+        GovernanceAction::ReplacePropertyManager { manager } => {
+            let manager = deps.api.addr_validate(manager)?;
+            PROPERTY_MANAGERS.save(
+                deps.storage,
+                asset_id.clone(),
+                &PropertyManager {
+                    manager: manager.clone(),
+                    appointed_at: env.block.time.seconds(),
+                    governance_id: Some(proposal.id),
+                },
+            )?;
+            Ok(Response::new().add_attribute("manager", manager))
+        }
+    }
+}
+
+/// Creator names the first property manager; replacing one takes a holder vote
+pub fn set_property_manager(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    asset_id: String,
+    manager: String,
+) -> Result<Response, ContractError> {
+    let proposal = PROPOSALS.load(deps.storage, asset_id.clone())?;
+    if info.sender != proposal.creator {
+        return Err(ContractError::Unauthorized {});
+    }
+    if PROPERTY_MANAGERS.has(deps.storage, asset_id.clone()) {
+        return Err(ContractError::InvalidInput {
+            field: "manager".to_string(),
+            message: "A property manager is already recorded; replacing it takes a holder vote"
+                .to_string(),
+        });
+    }
+    let manager = deps.api.addr_validate(&manager)?;
+    PROPERTY_MANAGERS.save(
+        deps.storage,
+        asset_id.clone(),
+        &PropertyManager {
+            manager: manager.clone(),
+            appointed_at: env.block.time.seconds(),
+            governance_id: None,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_property_manager")
+        .add_attribute("proposal_id", asset_id)
+        .add_attribute("manager", manager))
+}
+
+/// Cast a vote weighted by the holder's snapshotted shares; each holder votes once
+pub fn cast_vote(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    governance_id: u64,
+    option: VoteOption,
+) -> Result<Response, ContractError> {
+    let mut proposal = GOVERNANCE_PROPOSALS
+        .may_load(deps.storage, governance_id)?
+        .ok_or(ContractError::GovernanceProposalNotFound { governance_id })?;
+    if proposal.status != GovernanceProposalStatus::Open
+        || env.block.time.seconds() >= proposal.end_time
+    {
+        return Err(ContractError::VotingClosed { governance_id });
+    }
+    if proposal.snapshot_cursor.is_some() {
+        return Err(ContractError::GovernanceSnapshotPending { governance_id });
+    }
+    if GOVERNANCE_VOTES.has(deps.storage, (governance_id, &info.sender)) {
+        return Err(ContractError::AlreadyVoted { governance_id });
+    }
+
+    let power = GOVERNANCE_SNAPSHOTS
+        .may_load(deps.storage, (governance_id, &info.sender))?
+        .unwrap_or(0);
+    if power == 0 {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    match option {
+        VoteOption::Yes => proposal.tally.yes = proposal.tally.yes.saturating_add(power),
+        VoteOption::No => proposal.tally.no = proposal.tally.no.saturating_add(power),
+        VoteOption::Abstain => {
+            proposal.tally.abstain = proposal.tally.abstain.saturating_add(power)
+        }
+    }
+    GOVERNANCE_PROPOSALS.save(deps.storage, governance_id, &proposal)?;
+    GOVERNANCE_VOTES.save(
+        deps.storage,
+        (governance_id, &info.sender),
+        &GovernanceVote {
+            voter: info.sender.clone(),
+            option,
+            power,
+        },
+    )?;
+    crate::activity::record(
+        deps.storage,
+        &info.sender,
+        crate::activity::ActivityEntry {
+            activity_id: 0,
+            kind: crate::activity::ActivityKind::GovernanceVote,
+            proposal_id: proposal.asset_id.clone(),
+            amount: None,
+            governance_id: Some(governance_id),
+            block_height: env.block.height,
+            timestamp: env.block.time.seconds(),
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "cast_vote")
+        .add_attribute("governance_id", governance_id.to_string())
+        .add_attribute("voter", info.sender)
+        .add_attribute("option", format!("{:?}", option))
+        .add_attribute("power", power.to_string()))
+}
+
+/// Whether a closed vote met the quorum and pass threshold it opened with
+pub fn is_passed(proposal: &GovernanceProposal) -> bool {
+    tally_passes(
+        proposal.quorum_bps,
+        proposal.threshold_bps,
+        &proposal.tally,
+        proposal.total_voting_power,
+    )
+}
+
+/// Quorum and threshold check shared by every share-weighted vote
+pub fn tally_passes(
+    quorum_bps: u16,
+    threshold_bps: u16,
+    tally: &VoteTally,
+    total_voting_power: u64,
+) -> bool {
+    let quorum_met =
+        tally.turnout() as u128 * 10_000 >= quorum_bps as u128 * total_voting_power as u128;
+    let decisive = tally.yes as u128 + tally.no as u128;
+    let threshold_met =
+        decisive > 0 && tally.yes as u128 * 10_000 > threshold_bps as u128 * decisive;
+    quorum_met && threshold_met
+}
+
+/// Close a vote after its period ends and apply the outcome (anyone may call).
+///
+/// A passed proposal's action runs as a submessage to the launchpad itself, so an action that
+/// fails is rolled back on its own: the proposal is closed as Failed with the error recorded,
+/// rather than staying open.
+pub fn execute_governance_proposal(
+    deps: DepsMut,
+    env: Env,
+    governance_id: u64,
+) -> Result<Response, ContractError> {
+    let mut proposal = GOVERNANCE_PROPOSALS
+        .may_load(deps.storage, governance_id)?
+        .ok_or(ContractError::GovernanceProposalNotFound { governance_id })?;
+    if proposal.status != GovernanceProposalStatus::Open {
+        return Err(ContractError::VotingClosed { governance_id });
+    }
+    if proposal.snapshot_cursor.is_some() {
+        return Err(ContractError::GovernanceSnapshotPending { governance_id });
+    }
+    if env.block.time.seconds() < proposal.end_time {
+        return Err(ContractError::VotingStillOpen { governance_id });
+    }
+
+    let passed = is_passed(&proposal);
+    let mut response = Response::new();
+    if passed {
+        proposal.status = GovernanceProposalStatus::Executed;
+        let apply = WasmMsg::Execute {
+            contract_addr: env.contract.address.to_string(),
+            msg: to_json_binary(&crate::msg::ExecuteMsg::ApplyGovernanceAction { governance_id })?,
+            funds: vec![],
+        };
+        response = response.add_submessage(
+            SubMsg::reply_on_error(apply, crate::REPLY_GOVERNANCE_ACTION)
+                .with_payload(to_json_binary(&governance_id)?),
+        );
+    } else {
+        proposal.status = GovernanceProposalStatus::Rejected;
+    }
+    GOVERNANCE_PROPOSALS.save(deps.storage, governance_id, &proposal)?;
+
+    Ok(response
+        .add_attribute("action", "execute_governance_proposal")
+        .add_attribute("proposal_id", &proposal.asset_id)
+        .add_attribute("governance_id", governance_id.to_string())
+        .add_attribute("passed", passed.to_string())
+        .add_attribute("yes", proposal.tally.yes.to_string())
+        .add_attribute("no", proposal.tally.no.to_string())
+        .add_attribute("abstain", proposal.tally.abstain.to_string()))
+}
+
+/// Update quorum, threshold or voting period (operator only)
+pub fn update_governance_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    quorum_bps: Option<u16>,
+    threshold_bps: Option<u16>,
+    voting_period_seconds: Option<u64>,
+) -> Result<Response, ContractError> {
+    roles::ensure_role(deps.storage, &info.sender, Role::Operator)?;
+
+    let mut governance_config = GOVERNANCE_CONFIG
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    if let Some(quorum_bps) = quorum_bps {
+        governance_config.quorum_bps = quorum_bps;
+    }
+    if let Some(threshold_bps) = threshold_bps {
+        governance_config.threshold_bps = threshold_bps;
+    }
+    if let Some(voting_period_seconds) = voting_period_seconds {
+        governance_config.voting_period_seconds = voting_period_seconds;
+    }
+    if governance_config.quorum_bps > 10_000
+        || governance_config.threshold_bps >= 10_000
+        || governance_config.voting_period_seconds == 0
+    {
+        return Err(ContractError::InvalidInput {
+            field: "governance_config".to_string(),
+            message: "Quorum must be at most 10000 bps, threshold below 10000 bps and the voting period non-zero".to_string(),
+        });
+    }
+    GOVERNANCE_CONFIG.save(deps.storage, &governance_config)?;
+
+    Ok(Response::new().add_attribute("method", "update_governance_config"))
+}
+
+// Query functions
+pub fn query_property_manager(deps: Deps, asset_id: String) -> StdResult<Option<PropertyManager>> {
+    PROPERTY_MANAGERS.may_load(deps.storage, asset_id)
+}
+
+pub fn query_governance_proposal(deps: Deps, governance_id: u64) -> StdResult<GovernanceProposal> {
+    GOVERNANCE_PROPOSALS.load(deps.storage, governance_id)
+}
+
+pub fn query_open_governance_proposals(
+    deps: Deps,
+    asset_id: Option<String>,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<GovernanceProposalsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let proposals = GOVERNANCE_PROPOSALS
+        .range(deps.storage, start, None, Order::Ascending)
+        .filter(|item| {
+            item.as_ref().map_or(true, |(_, proposal)| {
+                proposal.status == GovernanceProposalStatus::Open
+                    && asset_id.as_ref().is_none_or(|id| *id == proposal.asset_id)
+            })
+        })
+        .take(limit)
+        .map(|item| item.map(|(_, proposal)| proposal))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(GovernanceProposalsResponse { proposals })
+}
+
+pub fn query_snapshot_voting_power(
+    deps: Deps,
+    governance_id: u64,
+    voter: String,
+) -> StdResult<SnapshotVotingPowerResponse> {
+    let voter = deps.api.addr_validate(&voter)?;
+    let proposal = GOVERNANCE_PROPOSALS.load(deps.storage, governance_id)?;
+
+    Ok(SnapshotVotingPowerResponse {
+        governance_id,
+        power: GOVERNANCE_SNAPSHOTS
+            .may_load(deps.storage, (governance_id, &voter))?
+            .unwrap_or(0),
+        snapshot_height: proposal.snapshot_height,
+        voter,
+    })
+}
+
+pub fn query_governance_votes(
+    deps: Deps,
+    governance_id: u64,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<GovernanceVotesResponse> {
+    let proposal = GOVERNANCE_PROPOSALS.load(deps.storage, governance_id)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_addr = start_after
+        .map(|s| deps.api.addr_validate(&s))
+        .transpose()?;
+    let start = start_addr.as_ref().map(Bound::exclusive);
+
+    let votes = GOVERNANCE_VOTES
+        .prefix(governance_id)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, vote)| vote))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(GovernanceVotesResponse {
+        governance_id,
+        tally: proposal.tally,
+        votes,
+    })
+}
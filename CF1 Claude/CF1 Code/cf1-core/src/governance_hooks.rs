@@ -0,0 +1,234 @@
+//! Notifications to an external governance contract.
+//!
+//! When the config names a governance contract, the launchpad tells it when a raise is funded,
+//! when share tokens are distributed and when a lockup expires. Handlers queue notifications and
+//! the entry point sends them once the handler succeeds. Each goes out as a submessage that
+//! reports only failures, so a failing governance contract never blocks the launchpad. Failed
+//! notifications are kept until someone retries them, with the same idempotency key.
+
+use cf1_types::GovernanceHookMsg;
+use cosmwasm_std::{
+    from_json, to_json_binary, Addr, Deps, DepsMut, Env, MessageInfo, Order, Reply, Response,
+    StdResult, Storage, SubMsg, SubMsgResult, Uint128, WasmMsg,
+};
+use cw_storage_plus::{Bound, Item, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+use crate::gas_optimization::{DEFAULT_LIMIT, MAX_LIMIT};
+use crate::roles::{self, Role};
+use crate::state::{Proposal, CONFIG};
+
+/// Carried to the reply, which keeps the notification if delivery failed
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+struct Delivery {
+    hook: GovernanceHookMsg,
+    contract: Addr,
+    attempts: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FailedGovernanceHook {
+    pub hook: GovernanceHookMsg,
+    pub contract: Addr,
+    pub attempts: u32,
+    pub error: String,
+    pub failed_at: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FailedGovernanceHooksResponse {
+    pub hooks: Vec<FailedGovernanceHook>,
+    pub next_key: Option<String>, // Pass as start_after for the next page; None on the last page
+}
+
+// Storage keys
+const OUTBOX: Item<Vec<(GovernanceHookMsg, u32)>> = Item::new("gov_hook_outbox"); // Queued this transaction, with prior attempts
+pub const FAILED_GOVERNANCE_HOOKS: Map<&str, FailedGovernanceHook> = Map::new("gov_hooks_failed"); // idempotency_key -> hook
+
+fn queue(storage: &mut dyn Storage, hook: GovernanceHookMsg, attempts: u32) -> StdResult<()> {
+    if CONFIG.load(storage)?.governance_contract.is_none() {
+        return Ok(());
+    }
+    let mut outbox = OUTBOX.may_load(storage)?.unwrap_or_default();
+    outbox.push((hook, attempts));
+    OUTBOX.save(storage, &outbox)
+}
+
+/// Announce a raise that just closed as funded
+pub fn proposal_funded(storage: &mut dyn Storage, env: &Env, proposal: &Proposal) -> StdResult<()> {
+    queue(
+        storage,
+        GovernanceHookMsg::ProposalFunded {
+            idempotency_key: format!("{}:funded:{}", proposal.id, env.block.height),
+            proposal_id: proposal.id.clone(),
+            creator: proposal.creator.to_string(),
+            raised_amount: proposal.funding_status.raised_amount,
+            lockup_end: proposal.timestamps.lockup_end.unwrap_or_default(),
+        },
+        0,
+    )
+}
+
+/// Announce that every investor received their shares
+pub fn tokens_distributed(
+    storage: &mut dyn Storage,
+    env: &Env,
+    proposal: &Proposal,
+    token_contract: &Addr,
+) -> StdResult<()> {
+    queue(
+        storage,
+        GovernanceHookMsg::TokensDistributed {
+            idempotency_key: format!("{}:distributed:{}", proposal.id, env.block.height),
+            proposal_id: proposal.id.clone(),
+            token_contract: token_contract.to_string(),
+            total_supply: Uint128::from(proposal.financial_terms.total_shares),
+        },
+        0,
+    )
+}
+
+/// Announce a lockup that has ended (once per lockup end, see `notifications::lockup_expired`)
+pub fn lockup_expired(
+    storage: &mut dyn Storage,
+    proposal_id: &str,
+    lockup_end: u64,
+) -> StdResult<()> {
+    queue(
+        storage,
+        GovernanceHookMsg::LockupExpired {
+            idempotency_key: format!("{}:lockup_expired:{}", proposal_id, lockup_end),
+            proposal_id: proposal_id.to_string(),
+            lockup_end,
+        },
+        0,
+    )
+}
+
+/// Send everything queued during the transaction (called by `notifications::flush`)
+pub fn flush(storage: &mut dyn Storage, response: Response) -> StdResult<Response> {
+    let Some(outbox) = OUTBOX.may_load(storage)? else {
+        return Ok(response);
+    };
+    OUTBOX.remove(storage);
+    let Some(contract) = CONFIG.load(storage)?.governance_contract else {
+        return Ok(response);
+    };
+
+    let mut messages = vec![];
+    for (hook, attempts) in outbox {
+        let notify = WasmMsg::Execute {
+            contract_addr: contract.to_string(),
+            msg: to_json_binary(&hook)?,
+            funds: vec![],
+        };
+        let delivery = Delivery {
+            hook,
+            contract: contract.clone(),
+            attempts: attempts + 1,
+        };
+        messages.push(
+            SubMsg::reply_on_error(notify, crate::REPLY_GOVERNANCE_HOOK)
+                .with_payload(to_json_binary(&delivery)?),
+        );
+    }
+    Ok(response.add_submessages(messages))
+}
+
+/// Keep a notification the governance contract rejected, for a later retry
+pub fn handle_reply(deps: DepsMut, env: &Env, msg: Reply) -> Result<Response, ContractError> {
+    let delivery: Delivery = from_json(&msg.payload)?;
+    let SubMsgResult::Err(error) = msg.result else {
+        return Ok(Response::new());
+    };
+    let key = delivery.hook.idempotency_key().to_string();
+    FAILED_GOVERNANCE_HOOKS.save(
+        deps.storage,
+        &key,
+        &FailedGovernanceHook {
+            hook: delivery.hook,
+            contract: delivery.contract,
+            attempts: delivery.attempts,
+            error: error.clone(),
+            failed_at: env.block.time.seconds(),
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "governance_hook_failed")
+        .add_attribute("idempotency_key", key)
+        .add_attribute("error", error))
+}
+
+/// Name the contract notified of funding, distribution and lockup expiry, or stop notifying with
+/// None (owner only)
+pub fn set_governance_contract(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: Option<String>,
+) -> Result<Response, ContractError> {
+    roles::ensure_role(deps.storage, &info.sender, Role::Owner)?;
+    let address = address
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+
+    let mut config = CONFIG.load(deps.storage)?;
+    config.governance_contract = address.clone();
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "set_governance_contract")
+        .add_attribute(
+            "governance_contract",
+            address.map_or_else(|| "none".to_string(), |address| address.to_string()),
+        ))
+}
+
+/// Send a failed notification again, to the governance contract in the config now (anyone)
+pub fn retry_governance_hook(
+    deps: DepsMut,
+    idempotency_key: String,
+) -> Result<Response, ContractError> {
+    let failed = FAILED_GOVERNANCE_HOOKS
+        .may_load(deps.storage, &idempotency_key)?
+        .ok_or_else(|| ContractError::InvalidInput {
+            field: "idempotency_key".to_string(),
+            message: "No failed governance notification with this key".to_string(),
+        })?;
+    if CONFIG.load(deps.storage)?.governance_contract.is_none() {
+        return Err(ContractError::InvalidInput {
+            field: "governance_contract".to_string(),
+            message: "No governance contract is registered".to_string(),
+        });
+    }
+    FAILED_GOVERNANCE_HOOKS.remove(deps.storage, &idempotency_key);
+    queue(deps.storage, failed.hook, failed.attempts)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "retry_governance_hook")
+        .add_attribute("idempotency_key", idempotency_key)
+        .add_attribute("attempt", (failed.attempts + 1).to_string()))
+}
+
+// Query functions
+pub fn query_failed_governance_hooks(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<FailedGovernanceHooksResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.as_deref().map(Bound::exclusive);
+
+    let mut hooks = FAILED_GOVERNANCE_HOOKS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit + 1)
+        .map(|item| item.map(|(_, hook)| hook))
+        .collect::<StdResult<Vec<_>>>()?;
+    let next_key = crate::next_page_key(&mut hooks, limit, |hook| {
+        hook.hook.idempotency_key().to_string()
+    });
+
+    Ok(FailedGovernanceHooksResponse { hooks, next_key })
+}
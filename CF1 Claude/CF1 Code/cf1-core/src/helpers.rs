@@ -1,8 +1,21 @@
 use crate::msg::{InvestmentResponse, InvestmentsResponse};
-use crate::state::{Investment, Proposal, INVESTMENTS, PROPOSALS};
-use cosmwasm_std::{Addr, Deps, StdResult, Uint128};
+use crate::state::{
+    Investment, InvestmentStatus, Proposal, ProposalStatus, INVESTMENTS, PROPOSALS,
+    USER_INVESTMENTS,
+};
+use cosmwasm_std::{Addr, Deps, Int128, StdResult, Uint128};
 
-/// Get all investments for a specific user across all proposals
+/// Proposals the user holds a position in, ordered by id. Reads the user's own index entry, so the
+/// cost tracks the size of their portfolio rather than the platform's.
+fn user_proposal_ids(deps: Deps, user: &Addr) -> StdResult<Vec<String>> {
+    let mut proposal_ids = USER_INVESTMENTS
+        .may_load(deps.storage, user)?
+        .unwrap_or_default();
+    proposal_ids.sort();
+    Ok(proposal_ids)
+}
+
+/// Get all investments for a specific user across all proposals, ordered by proposal id
 pub fn get_user_portfolio(
     deps: Deps,
     user: &Addr,
@@ -11,32 +24,25 @@ pub fn get_user_portfolio(
 ) -> StdResult<InvestmentsResponse> {
     let limit = limit.unwrap_or(30).min(100) as usize;
 
-    let user_investments: Vec<_> = INVESTMENTS
-        .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
-        .filter(|item| {
-            if let Ok(((_, addr), _)) = item {
-                addr == user
-            } else {
-                false
-            }
-        })
-        .skip_while(|item| {
-            if let Some(start_after) = &start_after {
-                if let Ok(((proposal_id, _), _)) = item {
-                    proposal_id <= start_after
-                } else {
-                    false
-                }
-            } else {
-                false
-            }
-        })
-        .take(limit)
-        .map(|item| {
-            let ((proposal_id, _), investment) = item?;
+    let proposal_ids = user_proposal_ids(deps, user)?;
+    let start = match &start_after {
+        Some(start_after) => proposal_ids.partition_point(|id| id <= start_after),
+        None => 0,
+    };
+    let mut page: Vec<_> = proposal_ids
+        .into_iter()
+        .skip(start)
+        .take(limit + 1)
+        .collect();
+    let next_key = crate::next_page_key(&mut page, limit, String::clone);
+
+    let user_investments = page
+        .into_iter()
+        .map(|proposal_id| {
+            let investment = INVESTMENTS.load(deps.storage, (proposal_id.clone(), user))?;
             let proposal = PROPOSALS.load(deps.storage, proposal_id)?;
             Ok(InvestmentResponse {
-                current_value: calculate_current_investment_value(&investment, &proposal),
+                current_value: crate::oracle::mark_to_market(deps.storage, &investment, &proposal)?,
                 proposal_title: proposal.asset_details.name,
                 investment,
             })
@@ -51,50 +57,71 @@ pub fn get_user_portfolio(
     Ok(InvestmentsResponse {
         investments: user_investments,
         total_invested,
+        next_key,
     })
 }
 
-/// Calculate portfolio performance metrics for a user
-pub fn get_portfolio_performance(deps: Deps, user: &Addr) -> StdResult<PortfolioPerformance> {
-    let user_investments: Vec<_> = INVESTMENTS
-        .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
-        .filter(|item| {
-            if let Ok(((_, addr), _)) = item {
-                addr == user
-            } else {
-                false
-            }
-        })
-        .map(|item| {
-            let ((proposal_id, _), investment) = item?;
-            let proposal = PROPOSALS.load(deps.storage, proposal_id)?;
-            Ok((investment, proposal))
-        })
-        .collect::<StdResult<Vec<_>>>()?;
+/// Calculate portfolio performance metrics for a user. Totals cover every position; the
+/// per-proposal breakdown is paginated by proposal id.
+pub fn get_portfolio_performance(
+    deps: Deps,
+    user: &Addr,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<PortfolioPerformance> {
+    let limit = limit.unwrap_or(30).min(100) as usize;
+
+    let proposal_ids = user_proposal_ids(deps, user)?;
+    let start = match &start_after {
+        Some(start_after) => proposal_ids.partition_point(|id| id <= start_after),
+        None => 0,
+    };
 
     let mut total_invested = Uint128::zero();
     let mut total_current_value = Uint128::zero();
+    let mut total_unrealized_gain_loss = Int128::zero();
+    let mut total_refunded = Uint128::zero();
+    let mut total_realized_distributions = Uint128::zero();
     let mut active_investments = 0u32;
     let mut completed_investments = 0u32;
+    let mut positions = Vec::new();
+
+    for (index, proposal_id) in proposal_ids.into_iter().enumerate() {
+        let investment = INVESTMENTS.load(deps.storage, (proposal_id.clone(), user))?;
+        let proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+        let position = position_performance(deps, user, investment, proposal)?;
 
-    for (investment, proposal) in user_investments {
-        total_invested += investment.amount;
-        total_current_value += calculate_current_investment_value(&investment, &proposal);
+        total_invested += position.invested;
+        total_current_value += position.current_value;
+        total_unrealized_gain_loss += position.unrealized_gain_loss;
+        total_refunded += position.refunded;
+        total_realized_distributions += position.realized_distributions;
 
-        match proposal.status {
-            crate::state::ProposalStatus::Active | crate::state::ProposalStatus::Funded => {
+        match position.proposal_status {
+            ProposalStatus::Active | ProposalStatus::Funded => {
                 active_investments += 1;
             }
-            crate::state::ProposalStatus::Completed => {
+            ProposalStatus::Completed => {
                 completed_investments += 1;
             }
             _ => {}
         }
+
+        if index >= start && positions.len() <= limit {
+            positions.push(position);
+        }
     }
+    let next_key = crate::next_page_key(&mut positions, limit, |position| {
+        position.proposal_id.clone()
+    });
 
     let total_return = if !total_invested.is_zero() {
-        let return_amount = total_current_value.saturating_sub(total_invested);
-        ((return_amount.u128() * 10000) / total_invested.u128()) as i32 // Basis points
+        // Marked-to-market positions can be worth less than cost, so the return is signed. Cash
+        // already paid back out counts towards it, so a refund is not a loss.
+        let returned = total_current_value + total_refunded + total_realized_distributions;
+        let current = returned.u128() as i128;
+        let invested = total_invested.u128() as i128;
+        ((current - invested) * 10000 / invested) as i32 // Basis points
     } else {
         0i32
     };
@@ -102,30 +129,53 @@ pub fn get_portfolio_performance(deps: Deps, user: &Addr) -> StdResult<Portfolio
     Ok(PortfolioPerformance {
         total_invested,
         total_current_value,
+        total_unrealized_gain_loss,
+        total_refunded,
+        total_realized_distributions,
         total_return_bps: total_return,
         active_investments,
         completed_investments,
+        positions,
+        next_key,
     })
 }
 
-/// Helper function to calculate current investment value
-fn calculate_current_investment_value(investment: &Investment, proposal: &Proposal) -> Uint128 {
-    // For now, return the original investment amount
-    // In production, this would calculate current market value based on:
-    // - Asset performance
-    // - Market conditions
-    // - Time elapsed
-    // - Dividend distributions
-    match proposal.status {
-        crate::state::ProposalStatus::Completed => {
-            // Could apply appreciation/depreciation here
-            investment.amount
-        }
-        crate::state::ProposalStatus::Failed | crate::state::ProposalStatus::Cancelled => {
-            Uint128::zero()
-        }
-        _ => investment.amount,
-    }
+/// Value one position: open positions are marked to market against cost, closed ones only carry
+/// the cash they returned
+fn position_performance(
+    deps: Deps,
+    user: &Addr,
+    investment: Investment,
+    proposal: Proposal,
+) -> StdResult<PositionPerformance> {
+    let current_value = crate::oracle::mark_to_market(deps.storage, &investment, &proposal)?;
+    let is_open = !matches!(
+        investment.status,
+        InvestmentStatus::Refunded | InvestmentStatus::Redeemed
+    ) && !matches!(
+        proposal.status,
+        ProposalStatus::Failed | ProposalStatus::Cancelled
+    );
+    let unrealized_gain_loss = if is_open {
+        Int128::try_from(current_value)? - Int128::try_from(investment.amount)?
+    } else {
+        Int128::zero()
+    };
+    let realized = crate::ledger::INVESTOR_REALIZED
+        .may_load(deps.storage, (user, proposal.id.as_str()))?
+        .unwrap_or_default();
+
+    Ok(PositionPerformance {
+        proposal_id: proposal.id,
+        proposal_title: proposal.asset_details.name,
+        proposal_status: proposal.status,
+        investment_status: investment.status,
+        invested: investment.amount,
+        current_value,
+        unrealized_gain_loss,
+        refunded: realized.refunded,
+        realized_distributions: realized.distributions,
+    })
 }
 
 use schemars::JsonSchema;
@@ -134,8 +184,26 @@ use serde::{Deserialize, Serialize};
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct PortfolioPerformance {
     pub total_invested: Uint128,
-    pub total_current_value: Uint128,
+    pub total_current_value: Uint128, // Marked to the latest oracle valuations
+    pub total_unrealized_gain_loss: Int128, // Current value less cost across open positions
+    pub total_refunded: Uint128,
+    pub total_realized_distributions: Uint128,
     pub total_return_bps: i32, // Return in basis points (100 bps = 1%)
     pub active_investments: u32,
     pub completed_investments: u32,
+    pub positions: Vec<PositionPerformance>,
+    pub next_key: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PositionPerformance {
+    pub proposal_id: String,
+    pub proposal_title: String,
+    pub proposal_status: ProposalStatus,
+    pub investment_status: InvestmentStatus,
+    pub invested: Uint128,
+    pub current_value: Uint128,
+    pub unrealized_gain_loss: Int128,
+    pub refunded: Uint128,
+    pub realized_distributions: Uint128,
 }
@@ -0,0 +1,96 @@
+//! Cross-chain investment through Neutron's ibc-hooks middleware.
+//!
+//! An investor on another chain sends NTRN back to Neutron with an ICS-20 transfer whose
+//! `receiver` is the launchpad contract and whose memo is an ibc-hooks wasm call to
+//! `InvestFromIbc` (see `invest_memo`). The middleware credits the transfer to an intermediate
+//! address derived from the channel and the original sender, then runs the call with those funds
+//! attached, so only coins that actually arrived are ever invested. If the call fails the
+//! middleware acknowledges the packet with an error and the sending chain refunds the transfer.
+//! The launchpad opens no IBC port of its own.
+
+use cosmwasm_std::{
+    to_json_string, Addr, Binary, DepsMut, Env, MessageInfo, Response, StdResult, Uint128,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+use crate::msg::ExecuteMsg;
+
+const NATIVE_DENOM: &str = "untrn";
+
+/// Packet data of an ICS-20 fungible token transfer
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Ics20Packet {
+    pub denom: String,
+    pub amount: Uint128,
+    pub sender: String,
+    pub receiver: String,
+    #[serde(default)]
+    pub memo: String,
+}
+
+/// ICS-20 acknowledgement; an `error` ack makes the sending chain refund the transfer
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Ics20Ack {
+    Result(Binary),
+    Error(String),
+}
+
+/// ibc-hooks memo: the middleware executes `msg` on `contract` with the transferred funds
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct IbcHooksMemo {
+    pub wasm: WasmHook,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct WasmHook {
+    pub contract: String,
+    pub msg: ExecuteMsg,
+}
+
+/// Memo for an ICS-20 transfer to `contract` that invests the transferred NTRN in `proposal_id`
+/// for `beneficiary`
+pub fn invest_memo(contract: &Addr, proposal_id: &str, beneficiary: &str) -> StdResult<String> {
+    to_json_string(&IbcHooksMemo {
+        wasm: WasmHook {
+            contract: contract.to_string(),
+            msg: ExecuteMsg::InvestFromIbc {
+                proposal_id: proposal_id.to_string(),
+                beneficiary: beneficiary.to_string(),
+            },
+        },
+    })
+}
+
+/// Invest funds delivered by the ibc-hooks middleware for their beneficiary.
+///
+/// The sender is the middleware's intermediate address for the transfer. The beneficiary is
+/// checked and recorded exactly as if it had sent the funds itself, and owns the escrow record,
+/// any partial-fill change, refunds and the eventual tokens.
+pub fn invest_from_ibc(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: String,
+    beneficiary: String,
+) -> Result<Response, ContractError> {
+    if info.funds.iter().any(|coin| coin.denom != NATIVE_DENOM) {
+        return Err(ContractError::InvalidInput {
+            field: "funds".to_string(),
+            message: format!("Only {} can be invested over IBC", NATIVE_DENOM),
+        });
+    }
+    let beneficiary = deps.api.addr_validate(&beneficiary)?;
+    let funded_by = info.sender;
+    let beneficiary_info = MessageInfo {
+        sender: beneficiary,
+        funds: info.funds,
+    };
+    let response = crate::execute_invest(deps, env, beneficiary_info, proposal_id, None, None)?;
+
+    Ok(response
+        .add_attribute("source", "ibc")
+        .add_attribute("funded_by", funded_by))
+}
@@ -0,0 +1,151 @@
+use cosmwasm_std::{Addr, Deps, Empty, Env, Order, StdResult, Storage};
+use cw_storage_plus::Map;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+
+/// How long a processed operation id is remembered and rejected on replay
+pub const OPERATION_ID_RETENTION_SECONDS: u64 = 7 * 24 * 60 * 60;
+
+/// Longest accepted client-supplied id (fits a UUID or a tx-hash-derived key)
+pub const MAX_OPERATION_ID_LENGTH: usize = 64;
+
+/// Expired ids pruned per recorded operation, keeping cleanup cost bounded
+const PRUNE_BATCH: usize = 10;
+
+/// Client-supplied operation id the contract has already executed
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ProcessedOperation {
+    pub action: String,
+    pub processed_at: u64,
+    pub block_height: u64,
+    pub expires_at: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct OperationStatusResponse {
+    pub sender: Addr,
+    pub operation_id: String,
+    pub processed: Option<ProcessedOperation>, // None if unknown or past retention
+}
+
+// Storage keys
+pub const PROCESSED_OPERATIONS: Map<(&Addr, &str), ProcessedOperation> =
+    Map::new("processed_operations");
+pub const OPERATION_EXPIRIES: Map<(u64, &Addr, &str), Empty> = Map::new("operation_expiries"); // (expires_at, sender, id) -> ()
+
+/// Reject a replayed operation id and remember a new one.
+///
+/// Execution failures revert this write along with the rest of the transaction, so a failed
+/// attempt can be retried under the same id. Without an id the call is not deduplicated.
+pub fn claim(
+    storage: &mut dyn Storage,
+    env: &Env,
+    sender: &Addr,
+    action: &str,
+    operation_id: Option<&str>,
+) -> Result<(), ContractError> {
+    let Some(operation_id) = operation_id else {
+        return Ok(());
+    };
+    if operation_id.is_empty() || operation_id.len() > MAX_OPERATION_ID_LENGTH {
+        return Err(ContractError::InvalidInput {
+            field: "operation_id".to_string(),
+            message: format!("Must be 1 to {} bytes", MAX_OPERATION_ID_LENGTH),
+        });
+    }
+
+    let now = env.block.time.seconds();
+    prune_expired(storage, now)?;
+
+    if let Some(existing) = PROCESSED_OPERATIONS.may_load(storage, (sender, operation_id))? {
+        if existing.expires_at > now {
+            return Err(ContractError::DuplicateOperation {
+                operation_id: operation_id.to_string(),
+            });
+        }
+        OPERATION_EXPIRIES.remove(storage, (existing.expires_at, sender, operation_id));
+    }
+
+    let expires_at = now + OPERATION_ID_RETENTION_SECONDS;
+    PROCESSED_OPERATIONS.save(
+        storage,
+        (sender, operation_id),
+        &ProcessedOperation {
+            action: action.to_string(),
+            processed_at: now,
+            block_height: env.block.height,
+            expires_at,
+        },
+    )?;
+    OPERATION_EXPIRIES.save(storage, (expires_at, sender, operation_id), &Empty {})?;
+    Ok(())
+}
+
+/// Drop a bounded batch of ids whose retention window has passed
+fn prune_expired(storage: &mut dyn Storage, now: u64) -> StdResult<()> {
+    let expired = OPERATION_EXPIRIES
+        .keys(storage, None, None, Order::Ascending)
+        .take_while(|key| {
+            key.as_ref()
+                .map_or(true, |(expires_at, _, _)| *expires_at <= now)
+        })
+        .take(PRUNE_BATCH)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    for (expires_at, sender, operation_id) in expired {
+        OPERATION_EXPIRIES.remove(storage, (expires_at, &sender, &operation_id));
+        PROCESSED_OPERATIONS.remove(storage, (&sender, &operation_id));
+    }
+    Ok(())
+}
+
+// Query functions
+pub fn query_operation_status(
+    deps: Deps,
+    env: Env,
+    sender: String,
+    operation_id: String,
+) -> StdResult<OperationStatusResponse> {
+    let sender = deps.api.addr_validate(&sender)?;
+    let processed = PROCESSED_OPERATIONS
+        .may_load(deps.storage, (&sender, &operation_id))?
+        .filter(|operation| operation.expires_at > env.block.time.seconds());
+
+    Ok(OperationStatusResponse {
+        sender,
+        operation_id,
+        processed,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_env, MockApi, MockStorage};
+
+    #[test]
+    fn test_duplicate_rejected_until_retention_expires() {
+        let mut storage = MockStorage::new();
+        let mut env = mock_env();
+        let alice = MockApi::default().addr_make("alice");
+        let bob = MockApi::default().addr_make("bob");
+
+        claim(&mut storage, &env, &alice, "invest", Some("op-1")).unwrap();
+        assert!(matches!(
+            claim(&mut storage, &env, &alice, "invest", Some("op-1")),
+            Err(ContractError::DuplicateOperation { .. })
+        ));
+        // Ids are scoped per sender, and calls without an id are never deduplicated
+        claim(&mut storage, &env, &bob, "invest", Some("op-1")).unwrap();
+        claim(&mut storage, &env, &alice, "invest", None).unwrap();
+        claim(&mut storage, &env, &alice, "invest", None).unwrap();
+
+        env.block.time = env.block.time.plus_seconds(OPERATION_ID_RETENTION_SECONDS);
+        claim(&mut storage, &env, &alice, "invest", Some("op-2")).unwrap();
+        // Both op-1 entries were pruned once their window passed
+        assert!(!PROCESSED_OPERATIONS.has(&storage, (&bob, "op-1")));
+        claim(&mut storage, &env, &alice, "invest", Some("op-1")).unwrap();
+    }
+}
@@ -1,72 +1,239 @@
 #[cfg(test)]
 mod integration_tests {
-    use super::*;
-    use crate::{execute, instantiate, query, reply};
     use cosmwasm_std::{coins, Addr, Uint128};
+    use cw20::{BalanceResponse, Cw20QueryMsg, MinterResponse};
     use cw_multi_test::{App, AppBuilder, Contract, ContractWrapper, Executor};
 
     use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+    use crate::roles::Role;
     use crate::state::{AssetDetails, ComplianceInfo, Document, FinancialTerms, ProposalStatus};
+    use crate::treasury::TreasuryAccount;
 
     fn contract_launchpad() -> Box<dyn Contract<cosmwasm_std::Empty>> {
         let contract = ContractWrapper::new(crate::execute, crate::instantiate, crate::query)
-            .with_reply(crate::reply);
+            .with_reply(crate::reply)
+            .with_sudo(crate::sudo)
+            .with_migrate(crate::migrate);
         Box::new(contract)
     }
 
+    fn contract_cw20() -> Box<dyn Contract<cosmwasm_std::Empty>> {
+        let contract = ContractWrapper::new(
+            cf1_restricted_cw20::contract::execute,
+            cf1_restricted_cw20::contract::instantiate,
+            cf1_restricted_cw20::contract::query,
+        );
+        Box::new(contract)
+    }
+
+    /// Price posted to the mock Pyth contract; execute overwrites it
+    #[derive(Debug, serde::Serialize, serde::Deserialize)]
+    struct MockPythPrice {
+        price: String,
+        conf: String,
+        expo: i32,
+        publish_time: i64,
+    }
+
+    fn contract_mock_pyth() -> Box<dyn Contract<cosmwasm_std::Empty>> {
+        use cosmwasm_std::{
+            from_json, to_json_binary, to_json_vec, Binary, Deps, DepsMut, Env, MessageInfo,
+            Response, StdResult,
+        };
+
+        fn post(deps: DepsMut, _: Env, _: MessageInfo, msg: MockPythPrice) -> StdResult<Response> {
+            deps.storage.set(b"price", &to_json_vec(&msg)?);
+            Ok(Response::new())
+        }
+        fn query(deps: Deps, _: Env, _: cosmwasm_std::Empty) -> StdResult<Binary> {
+            let price: MockPythPrice = from_json(deps.storage.get(b"price").unwrap())?;
+            #[derive(serde::Serialize)]
+            struct Feed {
+                id: String,
+                price: MockPythPrice,
+            }
+            #[derive(serde::Serialize)]
+            struct FeedResponse {
+                price_feed: Feed,
+            }
+            to_json_binary(&FeedResponse {
+                price_feed: Feed {
+                    id: "ntrn-usd".to_string(),
+                    price,
+                },
+            })
+        }
+        Box::new(ContractWrapper::new(post, post, query))
+    }
+
+    /// CW20 look-alike that tries to call back into the launchpad while minting
+    fn contract_malicious_token() -> Box<dyn Contract<cosmwasm_std::Empty>> {
+        use cosmwasm_std::{
+            to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult, WasmMsg,
+        };
+
+        fn instantiate(
+            _: DepsMut,
+            _: Env,
+            _: MessageInfo,
+            _: cf1_restricted_cw20::msg::InstantiateMsg,
+        ) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+        fn execute(
+            _: DepsMut,
+            _: Env,
+            info: MessageInfo,
+            _: cw20::Cw20ExecuteMsg,
+        ) -> StdResult<Response> {
+            Ok(Response::new().add_message(WasmMsg::Execute {
+                contract_addr: info.sender.to_string(),
+                msg: to_json_binary(&ExecuteMsg::Invest {
+                    proposal_id: "p1".to_string(),
+                    operation_id: None,
+                    referrer: None,
+                })?,
+                funds: vec![],
+            }))
+        }
+        fn query(_: Deps, _: Env, _: cosmwasm_std::Empty) -> StdResult<Binary> {
+            Ok(Binary::default())
+        }
+        Box::new(ContractWrapper::new(execute, instantiate, query))
+    }
+
+    /// CW20 look-alike that refuses to mint to investor2 until it has been sent any `Burn`
+    fn contract_flaky_token() -> Box<dyn Contract<cosmwasm_std::Empty>> {
+        use cosmwasm_std::{
+            Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult,
+        };
+
+        fn instantiate(
+            _: DepsMut,
+            _: Env,
+            _: MessageInfo,
+            _: cf1_restricted_cw20::msg::InstantiateMsg,
+        ) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+        fn execute(
+            deps: DepsMut,
+            _: Env,
+            _: MessageInfo,
+            msg: cw20::Cw20ExecuteMsg,
+        ) -> StdResult<Response> {
+            match msg {
+                cw20::Cw20ExecuteMsg::Burn { .. } => deps.storage.set(b"open", b"1"),
+                cw20::Cw20ExecuteMsg::Mint { recipient, .. }
+                    if recipient == addr("investor2").as_str()
+                        && deps.storage.get(b"open").is_none() =>
+                {
+                    return Err(StdError::generic_err("mint rejected"));
+                }
+                _ => {}
+            }
+            Ok(Response::new())
+        }
+        fn query(_: Deps, _: Env, _: cosmwasm_std::Empty) -> StdResult<Binary> {
+            Ok(Binary::default())
+        }
+        Box::new(ContractWrapper::new(execute, instantiate, query))
+    }
+
+    /// External escrow that holds whatever it is sent and pays out on request
+    fn contract_escrow() -> Box<dyn Contract<cosmwasm_std::Empty>> {
+        use cf1_types::EscrowExecuteMsg;
+        use cosmwasm_std::{
+            BankMsg, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult,
+        };
+
+        fn instantiate(_: DepsMut, _: Env, _: MessageInfo, _: Empty) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+        fn execute(
+            _: DepsMut,
+            _: Env,
+            _: MessageInfo,
+            msg: EscrowExecuteMsg,
+        ) -> StdResult<Response> {
+            match msg {
+                EscrowExecuteMsg::EscrowDeposit { .. } => Ok(Response::new()),
+                EscrowExecuteMsg::EscrowRelease {
+                    recipient, amount, ..
+                } => Ok(Response::new().add_message(BankMsg::Send {
+                    to_address: recipient,
+                    amount: coins(amount.u128(), "untrn"),
+                })),
+            }
+        }
+        fn query(_: Deps, _: Env, _: Empty) -> StdResult<Binary> {
+            Ok(Binary::default())
+        }
+        Box::new(ContractWrapper::new(execute, instantiate, query))
+    }
+
+    /// Governance contract that records every notification, or rejects them all when `rejects`
+    fn contract_governance(rejects: bool) -> Box<dyn Contract<cosmwasm_std::Empty>> {
+        use cf1_types::GovernanceHookMsg;
+        use cosmwasm_std::{
+            to_json_binary, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdError,
+            StdResult,
+        };
+        use cw_storage_plus::Item;
+
+        const RECEIVED: Item<Vec<GovernanceHookMsg>> = Item::new("received");
+
+        fn instantiate(_: DepsMut, _: Env, _: MessageInfo, _: Empty) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+        fn record(
+            deps: DepsMut,
+            _: Env,
+            _: MessageInfo,
+            msg: GovernanceHookMsg,
+        ) -> StdResult<Response> {
+            let mut received = RECEIVED.may_load(deps.storage)?.unwrap_or_default();
+            received.push(msg);
+            RECEIVED.save(deps.storage, &received)?;
+            Ok(Response::new())
+        }
+        fn reject(_: DepsMut, _: Env, _: MessageInfo, _: GovernanceHookMsg) -> StdResult<Response> {
+            Err(StdError::generic_err("governance contract unavailable"))
+        }
+        fn query(deps: Deps, _: Env, _: Empty) -> StdResult<Binary> {
+            to_json_binary(&RECEIVED.may_load(deps.storage)?.unwrap_or_default())
+        }
+        if rejects {
+            Box::new(ContractWrapper::new(reject, instantiate, query))
+        } else {
+            Box::new(ContractWrapper::new(record, instantiate, query))
+        }
+    }
+
+    fn addr(name: &str) -> Addr {
+        // Same derivation as App::api(), so these match multitest senders
+        cosmwasm_std::testing::MockApi::default().addr_make(name)
+    }
+
     fn mock_app() -> App {
         AppBuilder::new().build(|router, _, storage| {
             // Fund multiple users for testing
-            router
-                .bank
-                .init_balance(
-                    storage,
-                    &Addr::unchecked("creator"),
-                    coins(10_000_000_000_000, "untrn"),
-                )
-                .unwrap();
-            router
-                .bank
-                .init_balance(
-                    storage,
-                    &Addr::unchecked("investor1"),
-                    coins(10_000_000_000_000, "untrn"),
-                )
-                .unwrap();
-            router
-                .bank
-                .init_balance(
-                    storage,
-                    &Addr::unchecked("investor2"),
-                    coins(10_000_000_000_000, "untrn"),
-                )
-                .unwrap();
-            router
-                .bank
-                .init_balance(
-                    storage,
-                    &Addr::unchecked("investor3"),
-                    coins(10_000_000_000_000, "untrn"),
-                )
-                .unwrap();
-            router
-                .bank
-                .init_balance(
-                    storage,
-                    &Addr::unchecked("admin"),
-                    coins(10_000_000_000_000, "untrn"),
-                )
-                .unwrap();
+            for user in ["creator", "investor1", "investor2", "investor3", "admin"] {
+                router
+                    .bank
+                    .init_balance(storage, &addr(user), coins(10_000_000_000_000, "untrn"))
+                    .unwrap();
+            }
         })
     }
 
     fn setup_contract() -> (App, Addr) {
         let mut app = mock_app();
-        let cw20_id = app.store_code(contract_launchpad()); // Using same for simplicity in tests
+        let cw20_id = app.store_code(contract_cw20());
         let launchpad_id = app.store_code(contract_launchpad());
 
         let msg = InstantiateMsg {
-            admin: Some("admin".to_string()),
+            admin: Some(addr("admin").to_string()),
             platform_fee_bps: Some(250), // 2.5%
             cw20_code_id: cw20_id,
         };
@@ -74,14 +241,29 @@ mod integration_tests {
         let launchpad_addr = app
             .instantiate_contract(
                 launchpad_id,
-                Addr::unchecked("admin"),
+                addr("admin"),
                 &msg,
                 &[],
                 "CF1-Launchpad",
-                Some("admin".to_string()),
+                Some(addr("admin").to_string()),
             )
             .unwrap();
 
+        // Sample proposals require KYC
+        for investor in ["investor1", "investor2", "investor3"] {
+            app.execute_contract(
+                addr("admin"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::AddVerifiedInvestor {
+                    investor: addr(investor).to_string(),
+                    tier: crate::kyc::VerificationTier::Retail,
+                    expires_at: None,
+                },
+                &[],
+            )
+            .unwrap();
+        }
+
         (app, launchpad_addr)
     }
 
@@ -93,6 +275,7 @@ mod integration_tests {
             location: "Seattle, WA".to_string(),
             description: "Premium Class A office building".to_string(),
             full_description: "A premium Class A office building located in the heart of downtown Seattle's business district.".to_string(),
+            token_metadata: None,
             risk_factors: vec![
                 "Market volatility".to_string(),
                 "Interest rate changes".to_string(),
@@ -105,14 +288,25 @@ mod integration_tests {
             ],
         };
 
-        let current_time = 1640995200u64;
+        let current_time = cosmwasm_std::testing::mock_env().block.time.seconds(); // Multitest genesis time
         let financial_terms = FinancialTerms {
             target_amount: Uint128::from(5_000_000_000_000u128), // $5M target
             token_price: Uint128::from(1_000_000_000u128),       // $1000 per token
             total_shares: 5_000u64,
             minimum_investment: Uint128::from(1_000_000_000u128), // $1000 minimum
-            expected_apy: "12.5%".to_string(),
+            expected_apy_bps: 1_250,
             funding_deadline: current_time + (60 * 24 * 60 * 60), // 60 days
+            allow_partial_fill: false,
+            milestones: vec![],
+            usd_terms: None,
+            soft_cap: None,
+            max_raise: None,
+            pricing_tiers: vec![],
+            creator_vesting: None,
+            lockup_tranches: vec![],
+            token_name: None,
+            token_symbol: None,
+            withdrawal_terms: Default::default(),
         };
 
         let documents = vec![
@@ -120,19 +314,19 @@ mod integration_tests {
                 name: "Business Plan".to_string(),
                 doc_type: "PDF".to_string(),
                 size: "3.2MB".to_string(),
-                hash: Some("QmBusinessPlan123".to_string()),
+                hash: Some("QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_string()),
             },
             Document {
                 name: "Financial Projections".to_string(),
                 doc_type: "Excel".to_string(),
                 size: "1.1MB".to_string(),
-                hash: Some("QmFinancials456".to_string()),
+                hash: Some("QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o".to_string()),
             },
             Document {
                 name: "Property Appraisal".to_string(),
                 doc_type: "PDF".to_string(),
                 size: "5.8MB".to_string(),
-                hash: Some("QmAppraisal789".to_string()),
+                hash: Some("QmSgvgwxZGaBLqkGyWemEDqikCqU52XxsYLKtdy3vGZ8uq".to_string()),
             },
         ];
 
@@ -144,11 +338,110 @@ mod integration_tests {
                 "SEC Regulation CF compliant".to_string(),
                 "State securities law compliant".to_string(),
             ],
+            max_investment_per_user: None,
         };
 
         (asset_details, financial_terms, documents, compliance)
     }
 
+    /// The sample proposal at a $1M target, so one creator can run several within the Reg CF
+    /// issuer limit
+    fn create_small_sample_proposal(
+    ) -> (AssetDetails, FinancialTerms, Vec<Document>, ComplianceInfo) {
+        let (asset_details, mut financial_terms, documents, compliance) = create_sample_proposal();
+        financial_terms.target_amount = Uint128::from(1_000_000_000_000u128);
+        financial_terms.total_shares = 1_000;
+        (asset_details, financial_terms, documents, compliance)
+    }
+
+    /// Create the sample proposal and invest the given amounts
+    fn setup_funded_proposal(app: &mut App, launchpad_addr: &Addr, investments: &[(&str, u128)]) {
+        let (asset_details, financial_terms, documents, compliance) = create_sample_proposal();
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CreateProposal {
+                asset_details,
+                financial_terms,
+                documents,
+                compliance,
+            },
+            &[],
+        )
+        .unwrap();
+        for (investor, amount) in investments {
+            app.execute_contract(
+                addr(investor),
+                launchpad_addr.clone(),
+                &ExecuteMsg::Invest {
+                    proposal_id: "p1".to_string(),
+                    operation_id: None,
+                    referrer: None,
+                },
+                &coins(*amount, "untrn"),
+            )
+            .unwrap();
+        }
+    }
+
+    fn treasury_balance(app: &App, launchpad_addr: &Addr, account: TreasuryAccount) -> Uint128 {
+        let res: crate::treasury::TreasuryBalancesResponse = app
+            .wrap()
+            .query_wasm_smart(launchpad_addr.clone(), &QueryMsg::TreasuryBalances {})
+            .unwrap();
+        res.balances
+            .iter()
+            .find(|b| b.account == account)
+            .unwrap()
+            .amount
+    }
+
+    /// Create the sample proposal, fund it fully, mint and distribute; returns the token address
+    fn setup_distributed_proposal(
+        app: &mut App,
+        launchpad_addr: &Addr,
+        investments: &[(&str, u128)],
+    ) -> Addr {
+        setup_funded_proposal(app, launchpad_addr, investments);
+
+        let mint_res = app
+            .execute_contract(
+                addr("creator"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::MintTokens {
+                    proposal_id: "p1".to_string(),
+                },
+                &[],
+            )
+            .unwrap();
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::DistributeTokens {
+                proposal_id: "p1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::FinalizeDistribution {
+                proposal_id: "p1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        mint_res
+            .events
+            .iter()
+            .flat_map(|e| e.attributes.iter())
+            .find(|a| a.key == "token_address")
+            .map(|a| Addr::unchecked(&a.value))
+            .unwrap()
+    }
+
     #[test]
     fn test_complete_investment_lifecycle() {
         let (mut app, launchpad_addr) = setup_contract();
@@ -163,19 +456,14 @@ mod integration_tests {
         };
 
         let create_res = app
-            .execute_contract(
-                Addr::unchecked("creator"),
-                launchpad_addr.clone(),
-                &create_msg,
-                &[],
-            )
+            .execute_contract(addr("creator"), launchpad_addr.clone(), &create_msg, &[])
             .unwrap();
 
         // Verify proposal creation
         assert!(create_res.events.iter().any(|e| e
             .attributes
             .iter()
-            .any(|a| a.key == "proposal_id" && a.value == "proposal_1")));
+            .any(|a| a.key == "proposal_id" && a.value == "p1")));
 
         // Step 2: Multiple investors invest
         let investments = vec![
@@ -186,12 +474,14 @@ mod integration_tests {
 
         for (investor, amount) in investments {
             let invest_msg = ExecuteMsg::Invest {
-                proposal_id: "proposal_1".to_string(),
+                proposal_id: "p1".to_string(),
+                operation_id: None,
+                referrer: None,
             };
 
             let invest_res = app
                 .execute_contract(
-                    Addr::unchecked(investor),
+                    addr(investor),
                     launchpad_addr.clone(),
                     &invest_msg,
                     &coins(amount, "untrn"),
@@ -201,12 +491,12 @@ mod integration_tests {
             assert!(invest_res.events.iter().any(|e| e
                 .attributes
                 .iter()
-                .any(|a| a.key == "method" && a.value == "invest")));
+                .any(|a| a.key == "action" && a.value == "invest")));
         }
 
         // Step 3: Check proposal status (should still be active, not fully funded)
         let query_msg = QueryMsg::Proposal {
-            proposal_id: "proposal_1".to_string(),
+            proposal_id: "p1".to_string(),
         };
 
         let proposal_res: crate::msg::ProposalResponse = app
@@ -225,12 +515,14 @@ mod integration_tests {
         // Step 4: Complete funding with final investor
         let final_investment = 500_000_000_000u128; // $500 to reach $5M target
         let invest_msg = ExecuteMsg::Invest {
-            proposal_id: "proposal_1".to_string(),
+            proposal_id: "p1".to_string(),
+            operation_id: None,
+            referrer: None,
         };
 
         let final_invest_res = app
             .execute_contract(
-                Addr::unchecked("investor1"), // investor1 invests again
+                addr("investor1"), // investor1 invests again
                 launchpad_addr.clone(),
                 &invest_msg,
                 &coins(final_investment, "untrn"),
@@ -259,7 +551,7 @@ mod integration_tests {
 
         // Step 6: Query user investments
         let user_investment_query = QueryMsg::InvestmentsByUser {
-            user: "investor1".to_string(),
+            user: addr("investor1").to_string(),
             start_after: None,
             limit: None,
         };
@@ -289,7 +581,7 @@ mod integration_tests {
             Uint128::from(5_000_000_000_000u128)
         );
         assert_eq!(platform_stats.total_investors, 3); // Unique investors
-        assert_eq!(platform_stats.successful_proposals, 0); // Not completed yet, just funded
+        assert_eq!(platform_stats.successful_proposals, 1); // Funded proposals count as successful
     }
 
     #[test]
@@ -298,7 +590,7 @@ mod integration_tests {
 
         // Create a proposal with short deadline
         let (asset_details, mut financial_terms, documents, compliance) = create_sample_proposal();
-        financial_terms.funding_deadline = 1640995200u64 + (10 * 24 * 60 * 60); // 10 days
+        financial_terms.funding_deadline = app.block_info().time.seconds() + (10 * 24 * 60 * 60); // 10 days
 
         let create_msg = ExecuteMsg::CreateProposal {
             asset_details,
@@ -307,21 +599,18 @@ mod integration_tests {
             compliance,
         };
 
-        app.execute_contract(
-            Addr::unchecked("creator"),
-            launchpad_addr.clone(),
-            &create_msg,
-            &[],
-        )
-        .unwrap();
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &create_msg, &[])
+            .unwrap();
 
         // Partial investments (not reaching goal)
         let invest_msg = ExecuteMsg::Invest {
-            proposal_id: "proposal_1".to_string(),
+            proposal_id: "p1".to_string(),
+            operation_id: None,
+            referrer: None,
         };
 
         app.execute_contract(
-            Addr::unchecked("investor1"),
+            addr("investor1"),
             launchpad_addr.clone(),
             &invest_msg,
             &coins(1_000_000_000_000, "untrn"), // Only $1000 of $5M target
@@ -329,18 +618,22 @@ mod integration_tests {
         .unwrap();
 
         app.execute_contract(
-            Addr::unchecked("investor2"),
+            addr("investor2"),
             launchpad_addr.clone(),
             &invest_msg,
             &coins(500_000_000_000, "untrn"), // $500 more
         )
         .unwrap();
 
-        // Simulate time passing beyond deadline by processing expired proposals
-        let process_expired_msg = ExecuteMsg::ProcessExpiredProposals {};
+        // Move past the deadline, then process expired proposals
+        app.update_block(|block| block.time = block.time.plus_days(11));
+        let process_expired_msg = ExecuteMsg::ProcessExpiredProposals {
+            start_after: None,
+            limit: None,
+        };
 
         app.execute_contract(
-            Addr::unchecked("admin"),
+            addr("admin"),
             launchpad_addr.clone(),
             &process_expired_msg,
             &[],
@@ -349,23 +642,18 @@ mod integration_tests {
 
         // Manually trigger refunds
         let refund_msg = ExecuteMsg::RefundInvestors {
-            proposal_id: "proposal_1".to_string(),
+            proposal_id: "p1".to_string(),
         };
 
         let refund_res = app
-            .execute_contract(
-                Addr::unchecked("admin"),
-                launchpad_addr.clone(),
-                &refund_msg,
-                &[],
-            )
+            .execute_contract(addr("admin"), launchpad_addr.clone(), &refund_msg, &[])
             .unwrap();
 
         // Verify refund attributes
-        assert!(refund_res.events.iter().any(|e| e
-            .attributes
+        assert!(refund_res
+            .events
             .iter()
-            .any(|a| a.key == "method" && a.value == "refund_investors")));
+            .any(|e| e.ty == format!("wasm-{}", crate::events::event_type("refund_investors"))));
         assert!(refund_res.events.iter().any(|e| e
             .attributes
             .iter()
@@ -373,7 +661,7 @@ mod integration_tests {
 
         // Check proposal status
         let query_msg = QueryMsg::Proposal {
-            proposal_id: "proposal_1".to_string(),
+            proposal_id: "p1".to_string(),
         };
 
         let proposal_res: crate::msg::ProposalResponse = app
@@ -401,13 +689,8 @@ mod integration_tests {
             compliance,
         };
 
-        app.execute_contract(
-            Addr::unchecked("creator"),
-            launchpad_addr.clone(),
-            &create_msg,
-            &[],
-        )
-        .unwrap();
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &create_msg, &[])
+            .unwrap();
 
         // Test successful update by creator
         let mut updated_asset_details = create_sample_proposal().0;
@@ -417,28 +700,23 @@ mod integration_tests {
             .push("New HVAC system".to_string());
 
         let update_msg = ExecuteMsg::UpdateProposal {
-            proposal_id: "proposal_1".to_string(),
+            proposal_id: "p1".to_string(),
             asset_details: Some(updated_asset_details.clone()),
             documents: None,
         };
 
         let update_res = app
-            .execute_contract(
-                Addr::unchecked("creator"),
-                launchpad_addr.clone(),
-                &update_msg,
-                &[],
-            )
+            .execute_contract(addr("creator"), launchpad_addr.clone(), &update_msg, &[])
             .unwrap();
 
         assert!(update_res.events.iter().any(|e| e
             .attributes
             .iter()
-            .any(|a| a.key == "method" && a.value == "update_proposal")));
+            .any(|a| a.key == "action" && a.value == "update")));
 
         // Verify update was applied
         let query_msg = QueryMsg::Proposal {
-            proposal_id: "proposal_1".to_string(),
+            proposal_id: "p1".to_string(),
         };
 
         let updated_proposal: crate::msg::ProposalResponse = app
@@ -454,40 +732,38 @@ mod integration_tests {
 
         // Test unauthorized update attempt
         let unauthorized_update = ExecuteMsg::UpdateProposal {
-            proposal_id: "proposal_1".to_string(),
+            proposal_id: "p1".to_string(),
             asset_details: Some(updated_asset_details),
             documents: None,
         };
 
         let unauthorized_err = app
             .execute_contract(
-                Addr::unchecked("investor1"),
+                addr("investor1"),
                 launchpad_addr.clone(),
                 &unauthorized_update,
                 &[],
             )
             .unwrap_err();
 
-        assert!(unauthorized_err.to_string().contains("Unauthorized"));
+        assert!(unauthorized_err
+            .root_cause()
+            .to_string()
+            .contains("Unauthorized"));
 
         // Test proposal cancellation by creator
         let cancel_msg = ExecuteMsg::CancelProposal {
-            proposal_id: "proposal_1".to_string(),
+            proposal_id: "p1".to_string(),
         };
 
         let cancel_res = app
-            .execute_contract(
-                Addr::unchecked("creator"),
-                launchpad_addr.clone(),
-                &cancel_msg,
-                &[],
-            )
+            .execute_contract(addr("creator"), launchpad_addr.clone(), &cancel_msg, &[])
             .unwrap();
 
         assert!(cancel_res.events.iter().any(|e| e
             .attributes
             .iter()
-            .any(|a| a.key == "method" && a.value == "cancel_proposal")));
+            .any(|a| a.key == "action" && a.value == "cancel")));
 
         // Verify cancellation
         let cancelled_proposal: crate::msg::ProposalResponse = app
@@ -508,7 +784,7 @@ mod integration_tests {
         // Create multiple proposals by same creator
         for i in 0..3 {
             let (mut asset_details, financial_terms, documents, compliance) =
-                create_sample_proposal();
+                create_small_sample_proposal();
             asset_details.name = format!("Property {}", i + 1);
 
             let create_msg = ExecuteMsg::CreateProposal {
@@ -518,31 +794,28 @@ mod integration_tests {
                 compliance,
             };
 
-            app.execute_contract(
-                Addr::unchecked("creator"),
-                launchpad_addr.clone(),
-                &create_msg,
-                &[],
-            )
-            .unwrap();
+            app.execute_contract(addr("creator"), launchpad_addr.clone(), &create_msg, &[])
+                .unwrap();
         }
 
         // Fund one proposal completely
         let invest_msg = ExecuteMsg::Invest {
-            proposal_id: "proposal_1".to_string(),
+            proposal_id: "p1".to_string(),
+            operation_id: None,
+            referrer: None,
         };
 
         app.execute_contract(
-            Addr::unchecked("investor1"),
+            addr("investor1"),
             launchpad_addr.clone(),
             &invest_msg,
-            &coins(5_000_000_000_000, "untrn"), // Full $5M
+            &coins(1_000_000_000_000, "untrn"), // Full $1M
         )
         .unwrap();
 
         // Query creator stats
         let creator_query = QueryMsg::Creator {
-            creator: "creator".to_string(),
+            creator: addr("creator").to_string(),
         };
 
         let creator_res: crate::msg::CreatorResponse = app
@@ -554,12 +827,12 @@ mod integration_tests {
         assert_eq!(creator_res.creator.successful_proposals, 1);
         assert_eq!(
             creator_res.creator.total_raised,
-            Uint128::from(5_000_000_000_000u128)
+            Uint128::from(1_000_000_000_000u128)
         );
 
         // Query proposals by creator
         let creator_proposals_query = QueryMsg::ProposalsByCreator {
-            creator: "creator".to_string(),
+            creator: addr("creator").to_string(),
             start_after: None,
             limit: None,
         };
@@ -600,34 +873,34 @@ mod integration_tests {
             compliance,
         };
 
-        app.execute_contract(
-            Addr::unchecked("creator"),
-            launchpad_addr.clone(),
-            &create_msg,
-            &[],
-        )
-        .unwrap();
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &create_msg, &[])
+            .unwrap();
 
         // Test investment with no funds
         let invest_msg = ExecuteMsg::Invest {
-            proposal_id: "proposal_1".to_string(),
+            proposal_id: "p1".to_string(),
+            operation_id: None,
+            referrer: None,
         };
 
         let no_funds_err = app
             .execute_contract(
-                Addr::unchecked("investor1"),
+                addr("investor1"),
                 launchpad_addr.clone(),
                 &invest_msg,
                 &[], // No funds
             )
             .unwrap_err();
 
-        assert!(no_funds_err.to_string().contains("Insufficient funds"));
+        assert!(no_funds_err
+            .root_cause()
+            .to_string()
+            .contains("Insufficient funds"));
 
         // Test investment below minimum
         let below_min_err = app
             .execute_contract(
-                Addr::unchecked("investor1"),
+                addr("investor1"),
                 launchpad_addr.clone(),
                 &invest_msg,
                 &coins(500_000_000, "untrn"), // $500, below $1000 minimum
@@ -635,12 +908,13 @@ mod integration_tests {
             .unwrap_err();
 
         assert!(below_min_err
+            .root_cause()
             .to_string()
             .contains("Investment amount below minimum"));
 
         // Test multiple investments by same user (should accumulate)
         app.execute_contract(
-            Addr::unchecked("investor1"),
+            addr("investor1"),
             launchpad_addr.clone(),
             &invest_msg,
             &coins(1_000_000_000_000, "untrn"), // $1000
@@ -648,7 +922,7 @@ mod integration_tests {
         .unwrap();
 
         app.execute_contract(
-            Addr::unchecked("investor1"),
+            addr("investor1"),
             launchpad_addr.clone(),
             &invest_msg,
             &coins(2_000_000_000_000, "untrn"), // Another $2000
@@ -657,8 +931,8 @@ mod integration_tests {
 
         // Check accumulated investment
         let investment_query = QueryMsg::Investment {
-            proposal_id: "proposal_1".to_string(),
-            investor: "investor1".to_string(),
+            proposal_id: "p1".to_string(),
+            investor: addr("investor1").to_string(),
         };
 
         let investment_res: crate::msg::InvestmentResponse = app
@@ -670,20 +944,8998 @@ mod integration_tests {
             investment_res.investment.amount,
             Uint128::from(3_000_000_000_000u128)
         );
-        assert_eq!(investment_res.investment.shares, 3); // $3000 / $1000 per share
+        assert_eq!(investment_res.investment.shares, 3_000); // 3M untrn / 1K untrn per share
 
         // Test investment in non-existent proposal
         let invalid_proposal_err = app
             .execute_contract(
-                Addr::unchecked("investor1"),
+                addr("investor1"),
                 launchpad_addr.clone(),
                 &ExecuteMsg::Invest {
-                    proposal_id: "proposal_999".to_string(),
+                    proposal_id: "p999".to_string(),
+                    operation_id: None,
+                    referrer: None,
                 },
                 &coins(1_000_000_000_000, "untrn"),
             )
             .unwrap_err();
 
-        assert!(invalid_proposal_err.to_string().contains("not found"));
+        assert!(invalid_proposal_err
+            .root_cause()
+            .to_string()
+            .contains("not found"));
+    }
+
+    #[test]
+    fn test_token_lifecycle_with_real_cw20() {
+        let (mut app, launchpad_addr) = setup_contract();
+
+        let (asset_details, financial_terms, documents, compliance) = create_sample_proposal();
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CreateProposal {
+                asset_details,
+                financial_terms,
+                documents,
+                compliance,
+            },
+            &[],
+        )
+        .unwrap();
+
+        // Fully fund the $5M target
+        let investments = [
+            ("investor1", 2_000_000_000_000u128), // 2,000 shares
+            ("investor2", 3_000_000_000_000u128), // 3,000 shares
+        ];
+        for (investor, amount) in investments {
+            app.execute_contract(
+                addr(investor),
+                launchpad_addr.clone(),
+                &ExecuteMsg::Invest {
+                    proposal_id: "p1".to_string(),
+                    operation_id: None,
+                    referrer: None,
+                },
+                &coins(amount, "untrn"),
+            )
+            .unwrap();
+        }
+
+        // MintTokens instantiates cw20_base; the reply records its address
+        let mint_res = app
+            .execute_contract(
+                addr("creator"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::MintTokens {
+                    proposal_id: "p1".to_string(),
+                },
+                &[],
+            )
+            .unwrap();
+
+        let token_addr = mint_res
+            .events
+            .iter()
+            .flat_map(|e| e.attributes.iter())
+            .find(|a| a.key == "token_address")
+            .map(|a| Addr::unchecked(&a.value))
+            .expect("reply should record the token address");
+
+        // The launchpad is the minter, capped at the proposal's total shares
+        let minter: MinterResponse = app
+            .wrap()
+            .query_wasm_smart(token_addr.clone(), &Cw20QueryMsg::Minter {})
+            .unwrap();
+        assert_eq!(minter.minter, launchpad_addr.to_string());
+        assert_eq!(minter.cap, Some(Uint128::new(5_000)));
+
+        let creator_before = app
+            .wrap()
+            .query_balance(addr("creator"), "untrn")
+            .unwrap()
+            .amount;
+        let admin_before = app
+            .wrap()
+            .query_balance(addr("admin"), "untrn")
+            .unwrap()
+            .amount;
+
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::DistributeTokens {
+                proposal_id: "p1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::FinalizeDistribution {
+                proposal_id: "p1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        // Investors hold one token per share
+        for (investor, shares) in [("investor1", 2_000u128), ("investor2", 3_000u128)] {
+            let balance: BalanceResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    token_addr.clone(),
+                    &Cw20QueryMsg::Balance {
+                        address: addr(investor).to_string(),
+                    },
+                )
+                .unwrap();
+            assert_eq!(balance.balance, Uint128::new(shares));
+        }
+
+        // Supply is exhausted, so the cap holds against further minting
+        let token_info: cw20::TokenInfoResponse = app
+            .wrap()
+            .query_wasm_smart(token_addr.clone(), &Cw20QueryMsg::TokenInfo {})
+            .unwrap();
+        assert_eq!(token_info.total_supply, Uint128::new(5_000));
+        assert_eq!(token_info.symbol, "CFB");
+
+        // Creator receives the raise minus the 2.5% platform fee
+        let creator_after = app
+            .wrap()
+            .query_balance(addr("creator"), "untrn")
+            .unwrap()
+            .amount;
+        let admin_after = app
+            .wrap()
+            .query_balance(addr("admin"), "untrn")
+            .unwrap()
+            .amount;
+        assert_eq!(
+            creator_after - creator_before,
+            Uint128::new(4_875_000_000_000)
+        );
+        // The fee accrues in the treasury rather than going straight to the admin
+        assert_eq!(admin_after, admin_before);
+
+        let proposal_res: crate::msg::ProposalResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::Proposal {
+                    proposal_id: "p1".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(proposal_res.proposal.status, ProposalStatus::Completed);
+
+        // Distribution cannot be replayed
+        let replay_err = app
+            .execute_contract(
+                addr("creator"),
+                launchpad_addr,
+                &ExecuteMsg::DistributeTokens {
+                    proposal_id: "p1".to_string(),
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert!(replay_err
+            .root_cause()
+            .to_string()
+            .contains("Proposal is not funded"));
+    }
+
+    #[test]
+    fn test_secondary_share_transfer_after_lockup() {
+        let (mut app, launchpad_addr) = setup_contract();
+
+        let (asset_details, financial_terms, documents, compliance) = create_sample_proposal();
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CreateProposal {
+                asset_details,
+                financial_terms,
+                documents,
+                compliance,
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::Invest {
+                proposal_id: "p1".to_string(),
+                operation_id: None,
+                referrer: None,
+            },
+            &coins(5_000_000_000_000, "untrn"),
+        )
+        .unwrap();
+
+        let mint_res = app
+            .execute_contract(
+                addr("creator"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::MintTokens {
+                    proposal_id: "p1".to_string(),
+                },
+                &[],
+            )
+            .unwrap();
+        let token_addr = mint_res
+            .events
+            .iter()
+            .flat_map(|e| e.attributes.iter())
+            .find(|a| a.key == "token_address")
+            .map(|a| Addr::unchecked(&a.value))
+            .unwrap();
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::DistributeTokens {
+                proposal_id: "p1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::FinalizeDistribution {
+                proposal_id: "p1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        let transfer_msg = |recipient: &str, shares: u64| ExecuteMsg::TransferShares {
+            proposal_id: "p1".to_string(),
+            recipient: addr(recipient).to_string(),
+            shares,
+        };
+
+        // Still locked up
+        let locked_err = app
+            .execute_contract(
+                addr("investor1"),
+                launchpad_addr.clone(),
+                &transfer_msg("investor2", 1_000),
+                &[],
+            )
+            .unwrap_err();
+        assert!(locked_err.root_cause().to_string().contains("lockup"));
+
+        app.update_block(|block| block.time = block.time.plus_days(366));
+
+        // Recipients must be KYC verified
+        let kyc_err = app
+            .execute_contract(
+                addr("investor1"),
+                launchpad_addr.clone(),
+                &transfer_msg("outsider", 1_000),
+                &[],
+            )
+            .unwrap_err();
+        assert!(kyc_err
+            .root_cause()
+            .to_string()
+            .contains("not KYC verified"));
+
+        // The launchpad moves the CW20 balance under an allowance
+        app.execute_contract(
+            addr("investor1"),
+            token_addr.clone(),
+            &cw20::Cw20ExecuteMsg::IncreaseAllowance {
+                spender: launchpad_addr.to_string(),
+                amount: Uint128::new(1_000),
+                expires: None,
+            },
+            &[],
+        )
+        .unwrap();
+        let transfer_res = app
+            .execute_contract(
+                addr("investor1"),
+                launchpad_addr.clone(),
+                &transfer_msg("investor2", 1_000),
+                &[],
+            )
+            .unwrap();
+        assert!(transfer_res.events.iter().any(|e| e.ty
+            == format!("wasm-{}", crate::events::event_type("transfer_shares"))
+            && e.attributes
+                .iter()
+                .any(|a| a.key == "shares" && a.value == "1000")));
+
+        for (investor, shares) in [("investor1", 4_000u128), ("investor2", 1_000u128)] {
+            let balance: BalanceResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    token_addr.clone(),
+                    &Cw20QueryMsg::Balance {
+                        address: addr(investor).to_string(),
+                    },
+                )
+                .unwrap();
+            assert_eq!(balance.balance, Uint128::new(shares));
+        }
+
+        let received: crate::msg::InvestmentResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::Investment {
+                    proposal_id: "p1".to_string(),
+                    investor: addr("investor2").to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(received.investment.shares, 1_000);
+        assert_eq!(received.investment.amount, Uint128::new(1_000_000_000_000));
+
+        let proposal_res: crate::msg::ProposalResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::Proposal {
+                    proposal_id: "p1".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(proposal_res.proposal.funding_status.investor_count, 2);
+
+        // Concentration metrics follow the transfer
+        let metrics: crate::concentration::HolderMetrics = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::HolderMetrics {
+                    proposal_id: "p1".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(metrics.unique_holders, 2);
+        assert_eq!(metrics.total_shares, 5_000);
+        assert_eq!(metrics.largest_holder_bps, 8_000);
+        assert_eq!(metrics.gini_bps, 3_000);
+
+        // Cannot transfer more than the remaining position
+        let excess_err = app
+            .execute_contract(
+                addr("investor1"),
+                launchpad_addr,
+                &transfer_msg("investor2", 4_001),
+                &[],
+            )
+            .unwrap_err();
+        assert!(excess_err
+            .root_cause()
+            .to_string()
+            .contains("only owns 4000 shares"));
+    }
+
+    #[test]
+    fn test_treasury_buckets_reconcile_with_bank_balance() {
+        use crate::treasury::{
+            TreasuryAccount, TreasuryBalancesResponse, TreasuryTransfersResponse,
+        };
+
+        let (mut app, launchpad_addr) = setup_contract();
+        let balances = |app: &App| -> TreasuryBalancesResponse {
+            app.wrap()
+                .query_wasm_smart(launchpad_addr.clone(), &QueryMsg::TreasuryBalances {})
+                .unwrap()
+        };
+        let bucket = |res: &TreasuryBalancesResponse, account: TreasuryAccount| {
+            res.balances
+                .iter()
+                .find(|b| b.account == account)
+                .unwrap()
+                .amount
+        };
+
+        let (asset_details, financial_terms, documents, compliance) = create_sample_proposal();
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CreateProposal {
+                asset_details,
+                financial_terms,
+                documents,
+                compliance,
+            },
+            &[],
+        )
+        .unwrap();
+        for investor in ["investor1", "investor2"] {
+            app.execute_contract(
+                addr(investor),
+                launchpad_addr.clone(),
+                &ExecuteMsg::Invest {
+                    proposal_id: "p1".to_string(),
+                    operation_id: None,
+                    referrer: None,
+                },
+                &coins(1_000_000_000_000, "untrn"),
+            )
+            .unwrap();
+        }
+
+        // Anyone can seed the insurance pool; escrow is off limits
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::FundTreasury {
+                account: TreasuryAccount::InsurancePool,
+            },
+            &coins(50_000_000, "untrn"),
+        )
+        .unwrap();
+        let escrow_err = app
+            .execute_contract(
+                addr("admin"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::FundTreasury {
+                    account: TreasuryAccount::Escrow,
+                },
+                &coins(1, "untrn"),
+            )
+            .unwrap_err();
+        assert!(escrow_err
+            .root_cause()
+            .to_string()
+            .contains("Escrow can only move"));
+
+        let res = balances(&app);
+        assert_eq!(
+            bucket(&res, TreasuryAccount::Escrow),
+            Uint128::new(2_000_000_000_000)
+        );
+        assert_eq!(
+            bucket(&res, TreasuryAccount::InsurancePool),
+            Uint128::new(50_000_000)
+        );
+        assert_eq!(res.total, res.bank_balance);
+        assert!(res.unallocated.is_zero());
+
+        // Internal reallocation is admin only and recorded
+        let transfer = ExecuteMsg::TreasuryTransfer {
+            from: TreasuryAccount::InsurancePool,
+            to: TreasuryAccount::Escheatment,
+            amount: Uint128::new(20_000_000),
+            memo: "unclaimed payout".to_string(),
+        };
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &transfer, &[])
+            .unwrap_err();
+        app.execute_contract(addr("admin"), launchpad_addr.clone(), &transfer, &[])
+            .unwrap();
+
+        let transfers: TreasuryTransfersResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::TreasuryTransfers {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(transfers.transfers.len(), 1);
+        assert_eq!(transfers.transfers[0].memo, "unclaimed payout");
+
+        // Refunds drain escrow and the decomposition still matches the bank
+        app.update_block(|block| block.time = block.time.plus_days(61));
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::RefundInvestors {
+                proposal_id: "p1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        let res = balances(&app);
+        assert!(bucket(&res, TreasuryAccount::Escrow).is_zero());
+        assert_eq!(
+            bucket(&res, TreasuryAccount::InsurancePool),
+            Uint128::new(30_000_000)
+        );
+        assert_eq!(
+            bucket(&res, TreasuryAccount::Escheatment),
+            Uint128::new(20_000_000)
+        );
+        assert_eq!(res.total, res.bank_balance);
+    }
+
+    #[test]
+    fn test_milestone_payouts_released_in_tranches() {
+        use crate::milestones::{Milestone, MilestoneEscrowResponse};
+
+        let (mut app, launchpad_addr) = setup_contract();
+
+        let (asset_details, mut financial_terms, documents, compliance) = create_sample_proposal();
+        financial_terms.milestones = vec![
+            Milestone {
+                description: "Acquisition closes".to_string(),
+                payout_bps: 6_000,
+            },
+            Milestone {
+                description: "Renovation complete".to_string(),
+                payout_bps: 4_000,
+            },
+        ];
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CreateProposal {
+                asset_details,
+                financial_terms,
+                documents,
+                compliance,
+            },
+            &[],
+        )
+        .unwrap();
+        for (investor, amount) in [
+            ("investor1", 3_000_000_000_000u128),
+            ("investor2", 2_000_000_000_000u128),
+        ] {
+            app.execute_contract(
+                addr(investor),
+                launchpad_addr.clone(),
+                &ExecuteMsg::Invest {
+                    proposal_id: "p1".to_string(),
+                    operation_id: None,
+                    referrer: None,
+                },
+                &coins(amount, "untrn"),
+            )
+            .unwrap();
+        }
+
+        let creator_balance = |app: &App| {
+            app.wrap()
+                .query_balance(addr("creator"), "untrn")
+                .unwrap()
+                .amount
+        };
+        let creator_start = creator_balance(&app);
+
+        for msg in [
+            ExecuteMsg::MintTokens {
+                proposal_id: "p1".to_string(),
+            },
+            ExecuteMsg::DistributeTokens {
+                proposal_id: "p1".to_string(),
+            },
+            ExecuteMsg::FinalizeDistribution {
+                proposal_id: "p1".to_string(),
+            },
+        ] {
+            app.execute_contract(addr("creator"), launchpad_addr.clone(), &msg, &[])
+                .unwrap();
+        }
+
+        // Nothing is paid out on distribution; the net raise sits in escrow
+        assert_eq!(creator_balance(&app), creator_start);
+        let escrow: MilestoneEscrowResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::MilestoneEscrow {
+                    proposal_id: "p1".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(escrow.total_escrowed, Uint128::new(4_875_000_000_000));
+        assert_eq!(escrow.remaining, escrow.total_escrowed);
+
+        let release = |index: u32| ExecuteMsg::ReleaseMilestone {
+            proposal_id: "p1".to_string(),
+            milestone_index: index,
+        };
+
+        // Without admin action or a holder majority the creator cannot release
+        let err = app
+            .execute_contract(addr("creator"), launchpad_addr.clone(), &release(0), &[])
+            .unwrap_err();
+        assert!(err.root_cause().to_string().contains("not been approved"));
+
+        // investor1 holds 60% of the shares, which is a majority
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::ApproveMilestone {
+                proposal_id: "p1".to_string(),
+                milestone_index: 0,
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &release(0), &[])
+            .unwrap();
+        assert_eq!(
+            creator_balance(&app) - creator_start,
+            Uint128::new(2_925_000_000_000)
+        );
+
+        // Each tranche is paid once
+        let err = app
+            .execute_contract(addr("admin"), launchpad_addr.clone(), &release(0), &[])
+            .unwrap_err();
+        assert!(err.root_cause().to_string().contains("already released"));
+
+        // The admin can release directly
+        app.execute_contract(addr("admin"), launchpad_addr.clone(), &release(1), &[])
+            .unwrap();
+        assert_eq!(
+            creator_balance(&app) - creator_start,
+            Uint128::new(4_875_000_000_000)
+        );
+
+        let escrow: MilestoneEscrowResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr,
+                &QueryMsg::MilestoneEscrow {
+                    proposal_id: "p1".to_string(),
+                },
+            )
+            .unwrap();
+        assert!(escrow.remaining.is_zero());
+        assert!(escrow.milestones.iter().all(|m| m.released));
+    }
+
+    #[test]
+    fn test_governance_vote_lifecycle() {
+        use crate::governance::{
+            GovernanceAction, GovernanceProposal, GovernanceProposalStatus,
+            GovernanceProposalsResponse, GovernanceVotesResponse, VoteOption,
+        };
+
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_distributed_proposal(
+            &mut app,
+            &launchpad_addr,
+            &[
+                ("investor1", 3_000_000_000_000),
+                ("investor2", 2_000_000_000_000),
+            ],
+        );
+
+        let create = |title: &str| ExecuteMsg::CreateGovernanceProposal {
+            proposal_id: "p1".to_string(),
+            title: title.to_string(),
+            description: "Hold the property for another year".to_string(),
+            action: GovernanceAction::Signal {},
+        };
+        let vote = |governance_id: u64, vote: VoteOption| ExecuteMsg::CastVote {
+            governance_id,
+            vote,
+        };
+
+        // Only holders with at least 1% of supply can open a vote
+        app.execute_contract(
+            addr("investor3"),
+            launchpad_addr.clone(),
+            &create("Extend"),
+            &[],
+        )
+        .unwrap_err();
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &create("Extend"),
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("investor2"),
+            launchpad_addr.clone(),
+            &create("Sell"),
+            &[],
+        )
+        .unwrap();
+
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &vote(1, VoteOption::Yes),
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("investor2"),
+            launchpad_addr.clone(),
+            &vote(1, VoteOption::No),
+            &[],
+        )
+        .unwrap();
+        let err = app
+            .execute_contract(
+                addr("investor2"),
+                launchpad_addr.clone(),
+                &vote(1, VoteOption::Yes),
+                &[],
+            )
+            .unwrap_err();
+        assert!(err.root_cause().to_string().contains("Already voted"));
+
+        let votes: GovernanceVotesResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::GovernanceVotes {
+                    governance_id: 1,
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(votes.tally.yes, 3_000);
+        assert_eq!(votes.tally.no, 2_000);
+        assert_eq!(votes.votes.len(), 2);
+
+        // Tallying waits for the voting period to end
+        let execute = |governance_id: u64| ExecuteMsg::ExecuteGovernanceProposal { governance_id };
+        let err = app
+            .execute_contract(addr("creator"), launchpad_addr.clone(), &execute(1), &[])
+            .unwrap_err();
+        assert!(err.root_cause().to_string().contains("still open"));
+
+        app.update_block(|block| block.time = block.time.plus_days(8));
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &execute(1), &[])
+            .unwrap();
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &execute(2), &[])
+            .unwrap();
+
+        let passed: GovernanceProposal = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::GovernanceProposal { governance_id: 1 },
+            )
+            .unwrap();
+        assert_eq!(passed.status, GovernanceProposalStatus::Executed);
+
+        // Nobody voted on the second proposal, so it missed quorum
+        let rejected: GovernanceProposal = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::GovernanceProposal { governance_id: 2 },
+            )
+            .unwrap();
+        assert_eq!(rejected.status, GovernanceProposalStatus::Rejected);
+
+        let open: GovernanceProposalsResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr,
+                &QueryMsg::OpenGovernanceProposals {
+                    proposal_id: None,
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert!(open.proposals.is_empty());
+    }
+
+    #[test]
+    fn test_material_change_reopens_funding() {
+        use crate::reconfirmation::ReconfirmationStatusResponse;
+
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_funded_proposal(
+            &mut app,
+            &launchpad_addr,
+            &[
+                ("investor1", 3_000_000_000_000),
+                ("investor2", 2_000_000_000_000),
+            ],
+        );
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::MintTokens {
+                proposal_id: "p1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        // Only the creator can amend the offering
+        let material_change = ExecuteMsg::PostMaterialChange {
+            proposal_id: "p1".to_string(),
+            description: "Corrected rent roll in the offering memorandum".to_string(),
+            asset_details: None,
+            documents: None,
+        };
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &material_change,
+            &[],
+        )
+        .unwrap_err();
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &material_change,
+            &[],
+        )
+        .unwrap();
+
+        // Distribution is blocked while investors decide
+        let err = app
+            .execute_contract(
+                addr("creator"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::DistributeTokens {
+                    proposal_id: "p1".to_string(),
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert!(err
+            .root_cause()
+            .to_string()
+            .contains("Reconfirmation window is still open"));
+
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::ReconfirmInvestment {
+                proposal_id: "p1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("investor2"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::WithdrawInvestment {
+                proposal_id: "p1".to_string(),
+                operation_id: None,
+            },
+            &[],
+        )
+        .unwrap();
+        let balance = app
+            .wrap()
+            .query_balance(addr("investor2"), "untrn")
+            .unwrap();
+        assert_eq!(balance.amount, Uint128::new(10_000_000_000_000));
+
+        let status: ReconfirmationStatusResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::ReconfirmationStatus {
+                    proposal_id: "p1".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(status.reconfirmed_amount, Uint128::new(3_000_000_000_000));
+        assert_eq!(status.unconfirmed_amount, Uint128::zero());
+
+        let resolve = ExecuteMsg::ResolveReconfirmation {
+            proposal_id: "p1".to_string(),
+        };
+        app.execute_contract(addr("investor3"), launchpad_addr.clone(), &resolve, &[])
+            .unwrap_err();
+
+        // Below target with the deadline still ahead, so funding reopens
+        app.update_block(|block| block.time = block.time.plus_days(8));
+        let res = app
+            .execute_contract(addr("investor3"), launchpad_addr.clone(), &resolve, &[])
+            .unwrap();
+        assert!(res
+            .events
+            .iter()
+            .flat_map(|e| e.attributes.iter())
+            .any(|a| a.key == "outcome" && a.value == "reopened"));
+
+        let proposal: crate::msg::ProposalResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::Proposal {
+                    proposal_id: "p1".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(proposal.proposal.status, ProposalStatus::Active);
+        assert_eq!(
+            proposal.proposal.funding_status.raised_amount,
+            Uint128::new(3_000_000_000_000)
+        );
+        assert_eq!(proposal.proposal.funding_status.investor_count, 1);
+
+        // A new investor fills the gap and the already minted token is distributed
+        app.execute_contract(
+            addr("investor3"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::Invest {
+                proposal_id: "p1".to_string(),
+                operation_id: None,
+                referrer: None,
+            },
+            &coins(2_000_000_000_000, "untrn"),
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::DistributeTokens {
+                proposal_id: "p1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::FinalizeDistribution {
+                proposal_id: "p1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_unconfirmed_material_change_fails_deal_after_deadline() {
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_funded_proposal(
+            &mut app,
+            &launchpad_addr,
+            &[
+                ("investor1", 3_000_000_000_000),
+                ("investor2", 2_000_000_000_000),
+            ],
+        );
+
+        // Posted late in the raise; the window closes after the funding deadline
+        app.update_block(|block| block.time = block.time.plus_days(55));
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::PostMaterialChange {
+                proposal_id: "p1".to_string(),
+                description: "Sponsor replaced".to_string(),
+                asset_details: None,
+                documents: None,
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::ReconfirmInvestment {
+                proposal_id: "p1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        app.update_block(|block| block.time = block.time.plus_days(8));
+        let err = app
+            .execute_contract(
+                addr("investor2"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::ReconfirmInvestment {
+                    proposal_id: "p1".to_string(),
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert!(err.root_cause().to_string().contains("has closed"));
+
+        app.execute_contract(
+            addr("investor3"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::ResolveReconfirmation {
+                proposal_id: "p1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        // Everyone is made whole, including the investor that reconfirmed
+        for investor in ["investor1", "investor2"] {
+            let balance = app.wrap().query_balance(addr(investor), "untrn").unwrap();
+            assert_eq!(balance.amount, Uint128::new(10_000_000_000_000));
+        }
+        let proposal: crate::msg::ProposalResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::Proposal {
+                    proposal_id: "p1".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(proposal.proposal.status, ProposalStatus::Failed);
+        assert!(proposal.proposal.funding_status.raised_amount.is_zero());
+
+        let treasury: crate::treasury::TreasuryBalancesResponse = app
+            .wrap()
+            .query_wasm_smart(launchpad_addr, &QueryMsg::TreasuryBalances {})
+            .unwrap();
+        assert!(treasury.total.is_zero());
+        assert!(treasury.bank_balance.is_zero());
+    }
+
+    #[test]
+    fn test_invest_retry_with_operation_id_is_idempotent() {
+        use crate::idempotency::OperationStatusResponse;
+
+        let (mut app, launchpad_addr) = setup_contract();
+        let (asset_details, financial_terms, documents, compliance) = create_sample_proposal();
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CreateProposal {
+                asset_details,
+                financial_terms,
+                documents,
+                compliance,
+            },
+            &[],
+        )
+        .unwrap();
+
+        let invest = ExecuteMsg::Invest {
+            proposal_id: "p1".to_string(),
+            operation_id: Some("order-42".to_string()),
+            referrer: None,
+        };
+
+        // A failed attempt does not consume the id
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &invest,
+            &coins(1, "untrn"),
+        )
+        .unwrap_err();
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &invest,
+            &coins(1_000_000_000_000, "untrn"),
+        )
+        .unwrap();
+
+        // The rebroadcast is rejected and nothing is charged twice
+        let err = app
+            .execute_contract(
+                addr("investor1"),
+                launchpad_addr.clone(),
+                &invest,
+                &coins(1_000_000_000_000, "untrn"),
+            )
+            .unwrap_err();
+        assert!(err
+            .root_cause()
+            .to_string()
+            .contains("Operation already processed: order-42"));
+        let balance = app
+            .wrap()
+            .query_balance(addr("investor1"), "untrn")
+            .unwrap();
+        assert_eq!(balance.amount, Uint128::new(9_000_000_000_000));
+
+        // Another investor may use the same id
+        app.execute_contract(
+            addr("investor2"),
+            launchpad_addr.clone(),
+            &invest,
+            &coins(1_000_000_000_000, "untrn"),
+        )
+        .unwrap();
+
+        let status: OperationStatusResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr,
+                &QueryMsg::OperationStatus {
+                    sender: addr("investor1").to_string(),
+                    operation_id: "order-42".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(status.processed.unwrap().action, "invest");
+    }
+
+    #[test]
+    fn test_governance_voting_power_uses_creation_snapshot() {
+        use crate::governance::{
+            GovernanceAction, GovernanceVotesResponse, SnapshotVotingPowerResponse, VoteOption,
+        };
+
+        let (mut app, launchpad_addr) = setup_contract();
+        let token_addr = setup_distributed_proposal(
+            &mut app,
+            &launchpad_addr,
+            &[
+                ("investor1", 3_000_000_000_000),
+                ("investor2", 2_000_000_000_000),
+            ],
+        );
+        // Past the lockup so shares can change hands while the vote is open
+        app.update_block(|block| block.time = block.time.plus_days(366));
+
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CreateGovernanceProposal {
+                proposal_id: "p1".to_string(),
+                title: "Refinance the mortgage".to_string(),
+                description: "Lock in a lower rate".to_string(),
+                action: GovernanceAction::Signal {},
+            },
+            &[],
+        )
+        .unwrap();
+
+        // investor3 buys investor2's whole position after the proposal opened
+        app.execute_contract(
+            addr("investor2"),
+            token_addr,
+            &cw20::Cw20ExecuteMsg::IncreaseAllowance {
+                spender: launchpad_addr.to_string(),
+                amount: Uint128::new(2_000),
+                expires: None,
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("investor2"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::TransferShares {
+                proposal_id: "p1".to_string(),
+                recipient: addr("investor3").to_string(),
+                shares: 2_000,
+            },
+            &[],
+        )
+        .unwrap();
+
+        let power = |app: &App, voter: &str| -> u64 {
+            let res: SnapshotVotingPowerResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::GovernanceVotingPower {
+                        governance_id: 1,
+                        voter: addr(voter).to_string(),
+                    },
+                )
+                .unwrap();
+            res.power
+        };
+        assert_eq!(power(&app, "investor2"), 2_000);
+        assert_eq!(power(&app, "investor3"), 0);
+
+        // The bought shares carry no weight on this proposal; the seller keeps their snapshot
+        let vote = |vote: VoteOption| ExecuteMsg::CastVote {
+            governance_id: 1,
+            vote,
+        };
+        app.execute_contract(
+            addr("investor3"),
+            launchpad_addr.clone(),
+            &vote(VoteOption::Yes),
+            &[],
+        )
+        .unwrap_err();
+        app.execute_contract(
+            addr("investor2"),
+            launchpad_addr.clone(),
+            &vote(VoteOption::No),
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &vote(VoteOption::Yes),
+            &[],
+        )
+        .unwrap();
+
+        let votes: GovernanceVotesResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr,
+                &QueryMsg::GovernanceVotes {
+                    governance_id: 1,
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(votes.tally.yes, 3_000);
+        assert_eq!(votes.tally.no, 2_000);
+    }
+
+    #[test]
+    fn test_governance_snapshot_spans_batches_and_pins_voting_rules() {
+        use crate::governance::{
+            GovernanceAction, GovernanceProposal, GovernanceProposalStatus, VoteOption,
+            SNAPSHOT_BATCH_SIZE,
+        };
+        use crate::state::{Investment, InvestmentStatus, INVESTMENTS};
+
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_distributed_proposal(
+            &mut app,
+            &launchpad_addr,
+            &[
+                ("investor1", 3_000_000_000_000),
+                ("investor2", 2_000_000_000_000),
+            ],
+        );
+        // More holders than fit in one snapshot batch
+        {
+            let mut storage = app.contract_storage_mut(&launchpad_addr);
+            for i in 0..SNAPSHOT_BATCH_SIZE {
+                let holder = addr(&format!("holder{}", i));
+                INVESTMENTS
+                    .save(
+                        storage.as_mut(),
+                        ("p1".to_string(), &holder),
+                        &Investment {
+                            investor: holder.clone(),
+                            proposal_id: "p1".to_string(),
+                            amount: Uint128::new(10_000_000_000),
+                            shares: 10,
+                            timestamp: 0,
+                            status: InvestmentStatus::Completed,
+                        },
+                    )
+                    .unwrap();
+            }
+        }
+
+        let create = ExecuteMsg::CreateGovernanceProposal {
+            proposal_id: "p1".to_string(),
+            title: "Refinance the mortgage".to_string(),
+            description: "Lock in a lower rate".to_string(),
+            action: GovernanceAction::Signal {},
+        };
+        app.execute_contract(addr("investor1"), launchpad_addr.clone(), &create, &[])
+            .unwrap();
+        let governance = |app: &App| -> GovernanceProposal {
+            app.wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::GovernanceProposal { governance_id: 1 },
+                )
+                .unwrap()
+        };
+        assert!(governance(&app).snapshot_cursor.is_some());
+
+        // Until every holder is recorded nobody votes, shares stay put and no other vote opens
+        let vote = ExecuteMsg::CastVote {
+            governance_id: 1,
+            vote: VoteOption::Yes,
+        };
+        let err = app
+            .execute_contract(addr("investor1"), launchpad_addr.clone(), &vote, &[])
+            .unwrap_err();
+        assert!(err
+            .root_cause()
+            .to_string()
+            .contains("still being recorded"));
+        let err = app
+            .execute_contract(
+                addr("investor2"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::TransferShares {
+                    proposal_id: "p1".to_string(),
+                    recipient: addr("investor3").to_string(),
+                    shares: 100,
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert!(err
+            .root_cause()
+            .to_string()
+            .contains("still being recorded"));
+        app.execute_contract(addr("investor2"), launchpad_addr.clone(), &create, &[])
+            .unwrap_err();
+
+        let resume = ExecuteMsg::ContinueGovernanceSnapshot { governance_id: 1 };
+        app.execute_contract(addr("investor3"), launchpad_addr.clone(), &resume, &[])
+            .unwrap();
+        app.execute_contract(addr("investor3"), launchpad_addr.clone(), &resume, &[])
+            .unwrap_err();
+        let snapshot = governance(&app);
+        assert!(snapshot.snapshot_cursor.is_none());
+        assert_eq!(
+            snapshot.total_voting_power,
+            5_000 + 10 * SNAPSHOT_BATCH_SIZE as u64
+        );
+
+        // Quorum and threshold were fixed when the vote opened
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::UpdateGovernanceConfig {
+                quorum_bps: Some(10_000),
+                threshold_bps: None,
+                voting_period_seconds: None,
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(addr("investor1"), launchpad_addr.clone(), &vote, &[])
+            .unwrap();
+        app.update_block(|block| block.time = block.time.plus_days(8));
+        app.execute_contract(
+            addr("investor3"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::ExecuteGovernanceProposal { governance_id: 1 },
+            &[],
+        )
+        .unwrap();
+        let executed = governance(&app);
+        assert_eq!(executed.quorum_bps, 2_000);
+        assert_eq!(executed.status, GovernanceProposalStatus::Executed);
+    }
+
+    #[test]
+    fn test_asset_retirement_and_redemption() {
+        use crate::governance::{GovernanceAction, VoteOption};
+        use crate::retirement::RetirementReportResponse;
+
+        let (mut app, launchpad_addr) = setup_contract();
+        let token_addr = setup_distributed_proposal(
+            &mut app,
+            &launchpad_addr,
+            &[
+                ("investor1", 3_000_000_000_000),
+                ("investor2", 2_000_000_000_000),
+            ],
+        );
+        let retire = ExecuteMsg::RetireAsset {
+            proposal_id: "p1".to_string(),
+        };
+
+        // Holders have to approve the wind-down first
+        let err = app
+            .execute_contract(
+                addr("creator"),
+                launchpad_addr.clone(),
+                &retire,
+                &coins(1_000_000_000_000, "untrn"),
+            )
+            .unwrap_err();
+        assert!(err.root_cause().to_string().contains("not been approved"));
+
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CreateGovernanceProposal {
+                proposal_id: "p1".to_string(),
+                title: "Sell the building".to_string(),
+                description: "Accept the offer and wind the asset down".to_string(),
+                action: GovernanceAction::RetireAsset {},
+            },
+            &[],
+        )
+        .unwrap();
+        for investor in ["investor1", "investor2"] {
+            app.execute_contract(
+                addr(investor),
+                launchpad_addr.clone(),
+                &ExecuteMsg::CastVote {
+                    governance_id: 1,
+                    vote: VoteOption::Yes,
+                },
+                &[],
+            )
+            .unwrap();
+        }
+        app.update_block(|block| block.time = block.time.plus_days(8));
+        app.execute_contract(
+            addr("investor3"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::ExecuteGovernanceProposal { governance_id: 1 },
+            &[],
+        )
+        .unwrap();
+
+        // Sale proceeds are paid in with the retirement
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &retire,
+            &coins(1_000_000_000_000, "untrn"),
+        )
+        .unwrap();
+
+        // The cap table is frozen
+        app.update_block(|block| block.time = block.time.plus_days(366));
+        let err = app
+            .execute_contract(
+                addr("investor1"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::TransferShares {
+                    proposal_id: "p1".to_string(),
+                    recipient: addr("investor3").to_string(),
+                    shares: 100,
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert!(err
+            .root_cause()
+            .to_string()
+            .contains("Asset has been retired"));
+
+        let redeem = ExecuteMsg::RedeemShares {
+            proposal_id: "p1".to_string(),
+        };
+        // Without an allowance the burn fails and nothing is paid
+        app.execute_contract(addr("investor1"), launchpad_addr.clone(), &redeem, &[])
+            .unwrap_err();
+
+        for (investor, shares) in [("investor1", 3_000u128), ("investor2", 2_000)] {
+            app.execute_contract(
+                addr(investor),
+                token_addr.clone(),
+                &cw20::Cw20ExecuteMsg::IncreaseAllowance {
+                    spender: launchpad_addr.to_string(),
+                    amount: Uint128::new(shares),
+                    expires: None,
+                },
+                &[],
+            )
+            .unwrap();
+        }
+        app.execute_contract(addr("investor1"), launchpad_addr.clone(), &redeem, &[])
+            .unwrap();
+        let balance = app
+            .wrap()
+            .query_balance(addr("investor1"), "untrn")
+            .unwrap();
+        assert_eq!(balance.amount, Uint128::new(7_600_000_000_000));
+
+        // A redeemed position cannot be redeemed twice
+        app.execute_contract(addr("investor1"), launchpad_addr.clone(), &redeem, &[])
+            .unwrap_err();
+
+        let report = |app: &App| -> RetirementReportResponse {
+            app.wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::RetirementReport {
+                        proposal_id: "p1".to_string(),
+                    },
+                )
+                .unwrap()
+        };
+        let partial = report(&app);
+        assert_eq!(partial.unredeemed_shares, 2_000);
+        assert_eq!(partial.unclaimed_proceeds, Uint128::new(400_000_000_000));
+        assert!(!partial.fully_redeemed);
+
+        app.execute_contract(addr("investor2"), launchpad_addr.clone(), &redeem, &[])
+            .unwrap();
+        let final_report = report(&app);
+        assert!(final_report.fully_redeemed);
+        assert!(final_report.unclaimed_proceeds.is_zero());
+        assert_eq!(final_report.retirement.holders_redeemed, 2);
+        assert_eq!(final_report.total_raised, Uint128::new(5_000_000_000_000));
+
+        // Every token has been burned
+        let token_info: cw20::TokenInfoResponse = app
+            .wrap()
+            .query_wasm_smart(token_addr, &Cw20QueryMsg::TokenInfo {})
+            .unwrap();
+        assert!(token_info.total_supply.is_zero());
+    }
+
+    #[test]
+    fn test_asset_valuation_marks_positions_to_market() {
+        use crate::helpers::PortfolioPerformance;
+        use crate::oracle::{AssetValuationResponse, ValuationHistoryResponse};
+
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_distributed_proposal(
+            &mut app,
+            &launchpad_addr,
+            &[
+                ("investor1", 3_000_000_000_000),
+                ("investor2", 2_000_000_000_000),
+            ],
+        );
+        let report = |valuation: u128| ExecuteMsg::UpdateAssetValuation {
+            proposal_id: "p1".to_string(),
+            valuation: Uint128::new(valuation),
+            source: "appraisal".to_string(),
+        };
+        let investor1_value = |app: &App| -> Uint128 {
+            let res: crate::msg::InvestmentResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::Investment {
+                        proposal_id: "p1".to_string(),
+                        investor: addr("investor1").to_string(),
+                    },
+                )
+                .unwrap();
+            res.current_value
+        };
+
+        // Until an oracle reports, positions are carried at cost
+        assert_eq!(investor1_value(&app), Uint128::new(3_000_000_000_000));
+
+        // Only whitelisted oracles can report
+        app.execute_contract(
+            addr("appraiser"),
+            launchpad_addr.clone(),
+            &report(6_000_000_000_000),
+            &[],
+        )
+        .unwrap_err();
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::AddOracle {
+                oracle: addr("appraiser").to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        app.execute_contract(
+            addr("appraiser"),
+            launchpad_addr.clone(),
+            &report(6_000_000_000_000),
+            &[],
+        )
+        .unwrap();
+        assert_eq!(investor1_value(&app), Uint128::new(3_600_000_000_000));
+        let performance: PortfolioPerformance = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::PortfolioPerformance {
+                    user: addr("investor1").to_string(),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(performance.total_return_bps, 2_000);
+
+        // A markdown shows up as a negative return
+        app.execute_contract(
+            addr("appraiser"),
+            launchpad_addr.clone(),
+            &report(4_000_000_000_000),
+            &[],
+        )
+        .unwrap();
+        assert_eq!(investor1_value(&app), Uint128::new(2_400_000_000_000));
+        let performance: PortfolioPerformance = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::PortfolioPerformance {
+                    user: addr("investor1").to_string(),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(performance.total_return_bps, -2_000);
+
+        let valuation: AssetValuationResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::AssetValuation {
+                    proposal_id: "p1".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(valuation.value_per_share, Some(Uint128::new(800_000_000)));
+        assert_eq!(valuation.latest.unwrap().sequence, 1);
+
+        let history: ValuationHistoryResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::ValuationHistory {
+                    proposal_id: "p1".to_string(),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(history.valuations.len(), 2);
+        assert_eq!(
+            history.valuations[0].valuation,
+            Uint128::new(6_000_000_000_000)
+        );
+    }
+
+    #[test]
+    fn test_usd_denominated_proposal_converts_at_invest_time() {
+        use crate::oracle::{PriceFeedProvider, UsdTerms};
+
+        let (mut app, launchpad_addr) = setup_contract();
+        let pyth_id = app.store_code(contract_mock_pyth());
+        let post_price = |app: &App, price: &str| MockPythPrice {
+            price: price.to_string(),
+            conf: "10000".to_string(),
+            expo: -8,
+            publish_time: app.block_info().time.seconds() as i64,
+        };
+        // NTRN at $0.50
+        let pyth_addr = app
+            .instantiate_contract(
+                pyth_id,
+                addr("admin"),
+                &post_price(&app, "50000000"),
+                &[],
+                "mock-pyth",
+                None,
+            )
+            .unwrap();
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::SetPriceFeed {
+                contract: pyth_addr.to_string(),
+                provider: PriceFeedProvider::Pyth {
+                    price_feed_id: "ntrn-usd".to_string(),
+                },
+                max_staleness_seconds: 300,
+            },
+            &[],
+        )
+        .unwrap();
+
+        // $2.5M target with a $500 minimum across 5000 shares
+        let (asset_details, mut financial_terms, documents, compliance) = create_sample_proposal();
+        financial_terms.usd_terms = Some(UsdTerms {
+            target_amount_usd: Uint128::new(2_500_000_000_000),
+            minimum_investment_usd: Uint128::new(500_000_000),
+        });
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CreateProposal {
+                asset_details,
+                financial_terms,
+                documents,
+                compliance,
+            },
+            &[],
+        )
+        .unwrap();
+        let proposal_query = QueryMsg::Proposal {
+            proposal_id: "p1".to_string(),
+        };
+        let proposal: crate::msg::ProposalResponse = app
+            .wrap()
+            .query_wasm_smart(launchpad_addr.clone(), &proposal_query)
+            .unwrap();
+        let terms = proposal.proposal.financial_terms;
+        assert_eq!(terms.target_amount, Uint128::new(5_000_000_000_000));
+        assert_eq!(terms.minimum_investment, Uint128::new(1_000_000_000));
+        assert_eq!(terms.token_price, Uint128::new(1_000_000_000));
+
+        let invest = ExecuteMsg::Invest {
+            proposal_id: "p1".to_string(),
+            operation_id: None,
+            referrer: None,
+        };
+
+        // A feed that has not updated within the window is rejected
+        app.update_block(|block| block.time = block.time.plus_seconds(600));
+        let err = app
+            .execute_contract(
+                addr("investor1"),
+                launchpad_addr.clone(),
+                &invest,
+                &coins(2_500_000_000_000, "untrn"),
+            )
+            .unwrap_err();
+        assert!(err.root_cause().to_string().contains("stale"));
+
+        // NTRN doubles to $1.00, halving the untrn target and minimum
+        app.execute_contract(
+            addr("admin"),
+            pyth_addr.clone(),
+            &post_price(&app, "100000000"),
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &invest,
+            &coins(400_000_000, "untrn"),
+        )
+        .unwrap_err();
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &invest,
+            &coins(2_500_000_000_000, "untrn"),
+        )
+        .unwrap();
+
+        let proposal: crate::msg::ProposalResponse = app
+            .wrap()
+            .query_wasm_smart(launchpad_addr.clone(), &proposal_query)
+            .unwrap();
+        assert_eq!(proposal.proposal.status, ProposalStatus::Funded);
+        assert_eq!(
+            proposal.proposal.financial_terms.target_amount,
+            Uint128::new(2_500_000_000_000)
+        );
+    }
+
+    #[test]
+    fn test_expired_proposal_investments_stay_claimable() {
+        use crate::state::InvestmentStatus;
+
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_funded_proposal(
+            &mut app,
+            &launchpad_addr,
+            &[("investor1", 1_000_000_000_000)],
+        );
+        app.update_block(|block| block.time = block.time.plus_days(61));
+        app.execute_contract(
+            addr("investor3"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::ProcessExpiredProposals {
+                start_after: None,
+                limit: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+        // Failing the proposal pays nothing out, so the position must not look refunded yet
+        let position: crate::msg::InvestmentResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::Investment {
+                    proposal_id: "p1".to_string(),
+                    investor: addr("investor1").to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(position.investment.status, InvestmentStatus::Pending);
+
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::ClaimRefund {
+                proposal_id: "p1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        let balance = app
+            .wrap()
+            .query_balance(addr("investor1"), "untrn")
+            .unwrap()
+            .amount;
+        assert_eq!(balance, Uint128::new(10_000_000_000_000));
+    }
+
+    #[test]
+    fn test_keeper_processes_expired_proposals_for_reward() {
+        use crate::keeper::KeeperStats;
+        use crate::treasury::TreasuryAccount;
+
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_funded_proposal(&mut app, &launchpad_addr, &[("investor1", 1_000_000_000)]);
+
+        // Only the admin sets the reward
+        let set_reward = ExecuteMsg::UpdateKeeperConfig {
+            reward_per_proposal: Uint128::new(1_000_000),
+        };
+        app.execute_contract(addr("investor3"), launchpad_addr.clone(), &set_reward, &[])
+            .unwrap_err();
+        app.execute_contract(addr("admin"), launchpad_addr.clone(), &set_reward, &[])
+            .unwrap();
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::FundTreasury {
+                account: TreasuryAccount::Fees,
+            },
+            &coins(1_500_000, "untrn"),
+        )
+        .unwrap();
+
+        // Any address can process expired proposals once the deadline passes
+        app.update_block(|block| block.time = block.time.plus_days(61));
+        let process = ExecuteMsg::ProcessExpiredProposals {
+            start_after: None,
+            limit: Some(10),
+        };
+        let balance_before = app
+            .wrap()
+            .query_balance(addr("investor3"), "untrn")
+            .unwrap()
+            .amount;
+        let res = app
+            .execute_contract(addr("investor3"), launchpad_addr.clone(), &process, &[])
+            .unwrap();
+        assert!(res.events.iter().any(|e| e
+            .attributes
+            .iter()
+            .any(|a| a.key == "keeper_reward" && a.value == "1000000")));
+        let balance_after = app
+            .wrap()
+            .query_balance(addr("investor3"), "untrn")
+            .unwrap()
+            .amount;
+        assert_eq!(balance_after - balance_before, Uint128::new(1_000_000));
+
+        let proposal: crate::msg::ProposalResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::Proposal {
+                    proposal_id: "p1".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(proposal.proposal.status, ProposalStatus::Failed);
+
+        // Nothing left to process, so a repeat call earns nothing
+        let res = app
+            .execute_contract(addr("investor3"), launchpad_addr.clone(), &process, &[])
+            .unwrap();
+        assert!(res.events.iter().any(|e| e
+            .attributes
+            .iter()
+            .any(|a| a.key == "keeper_reward" && a.value == "0")));
+
+        let stats: KeeperStats = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr,
+                &QueryMsg::KeeperStats {
+                    keeper: addr("investor3").to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(stats.proposals_processed, 1);
+        assert_eq!(stats.rewards_paid, Uint128::new(1_000_000));
+    }
+
+    #[test]
+    fn test_scheduled_maintenance_resumes_from_cursor() {
+        use crate::msg::SudoMsg;
+        use crate::scheduler::SchedulerState;
+
+        let (mut app, launchpad_addr) = setup_contract();
+        // p1 is funded and locked up; p2 and p3, from another creator, never raise anything
+        setup_funded_proposal(
+            &mut app,
+            &launchpad_addr,
+            &[("investor1", 5_000_000_000_000)],
+        );
+        for _ in 0..2 {
+            let (asset_details, financial_terms, documents, compliance) =
+                create_small_sample_proposal();
+            app.execute_contract(
+                addr("creator2"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::CreateProposal {
+                    asset_details,
+                    financial_terms,
+                    documents,
+                    compliance,
+                },
+                &[],
+            )
+            .unwrap();
+        }
+        let status = |app: &App, proposal_id: &str| {
+            let res: crate::msg::ProposalResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::Proposal {
+                        proposal_id: proposal_id.to_string(),
+                    },
+                )
+                .unwrap();
+            res.proposal.status
+        };
+        let scheduler_state = |app: &App| -> SchedulerState {
+            app.wrap()
+                .query_wasm_smart(launchpad_addr.clone(), &QueryMsg::SchedulerState {})
+                .unwrap()
+        };
+        let run = SudoMsg::ScheduledMaintenance { limit: Some(2) };
+
+        app.update_block(|block| block.time = block.time.plus_days(61));
+
+        // The first run covers p1 and p2 and leaves the cursor after p2
+        app.wasm_sudo(launchpad_addr.clone(), &run).unwrap();
+        assert_eq!(status(&app, "p2"), ProposalStatus::Failed);
+        assert_eq!(status(&app, "p3"), ProposalStatus::Active);
+        assert_eq!(scheduler_state(&app).expiry_cursor, Some("p2".to_string()));
+
+        // The next run picks up p3 and wraps back to the start
+        app.wasm_sudo(launchpad_addr.clone(), &run).unwrap();
+        assert_eq!(status(&app, "p3"), ProposalStatus::Failed);
+        let state = scheduler_state(&app);
+        assert_eq!(state.expiry_cursor, None);
+        assert_eq!(state.runs, 2);
+
+        // CronCat tasks use the permissionless execute entry, which shares the cursors
+        app.update_block(|block| block.time = block.time.plus_days(366));
+        assert_eq!(status(&app, "p1"), ProposalStatus::Funded);
+        app.execute_contract(
+            addr("investor3"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::RunScheduledMaintenance { limit: Some(2) },
+            &[],
+        )
+        .unwrap();
+        assert_eq!(status(&app, "p1"), ProposalStatus::Completed);
+    }
+
+    #[test]
+    fn test_investor_claims_own_refund_once() {
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_funded_proposal(
+            &mut app,
+            &launchpad_addr,
+            &[
+                ("investor1", 1_000_000_000_000),
+                ("investor2", 500_000_000_000),
+            ],
+        );
+        let claim = ExecuteMsg::ClaimRefund {
+            proposal_id: "p1".to_string(),
+        };
+
+        // Nothing to claim while the raise is still open
+        app.execute_contract(addr("investor1"), launchpad_addr.clone(), &claim, &[])
+            .unwrap_err();
+
+        // Past the deadline the claim fails the proposal itself, no keeper needed
+        app.update_block(|block| block.time = block.time.plus_days(61));
+        app.execute_contract(addr("investor1"), launchpad_addr.clone(), &claim, &[])
+            .unwrap();
+        let balance = app
+            .wrap()
+            .query_balance(addr("investor1"), "untrn")
+            .unwrap()
+            .amount;
+        assert_eq!(balance, Uint128::new(10_000_000_000_000));
+
+        let err = app
+            .execute_contract(addr("investor1"), launchpad_addr.clone(), &claim, &[])
+            .unwrap_err();
+        assert!(err.root_cause().to_string().contains("already claimed"));
+        app.execute_contract(addr("investor3"), launchpad_addr.clone(), &claim, &[])
+            .unwrap_err();
+
+        let proposal: crate::msg::ProposalResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::Proposal {
+                    proposal_id: "p1".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(proposal.proposal.status, ProposalStatus::Failed);
+        assert_eq!(
+            proposal.proposal.funding_status.raised_amount,
+            Uint128::new(500_000_000_000)
+        );
+
+        // The bulk refund only pays investors who have not claimed
+        let res = app
+            .execute_contract(
+                addr("admin"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::RefundInvestors {
+                    proposal_id: "p1".to_string(),
+                },
+                &[],
+            )
+            .unwrap();
+        assert!(res.events.iter().any(|e| e
+            .attributes
+            .iter()
+            .any(|a| a.key == "total_refunded" && a.value == "500000000000")));
+    }
+
+    #[test]
+    fn test_cancel_partially_funded_proposal_refunds_investors() {
+        use crate::state::InvestmentStatus;
+        use crate::treasury::TreasuryBalancesResponse;
+
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_funded_proposal(
+            &mut app,
+            &launchpad_addr,
+            &[
+                ("investor1", 1_000_000_000_000),
+                ("investor2", 2_000_000_000_000),
+            ],
+        );
+
+        let res = app
+            .execute_contract(
+                addr("creator"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::CancelProposal {
+                    proposal_id: "p1".to_string(),
+                },
+                &[],
+            )
+            .unwrap();
+        assert!(res.events.iter().any(|e| e
+            .attributes
+            .iter()
+            .any(|a| a.key == "total_refunded" && a.value == "3000000000000")));
+
+        // Both investors are made whole and nothing is left in escrow
+        for investor in ["investor1", "investor2"] {
+            let balance = app
+                .wrap()
+                .query_balance(addr(investor), "untrn")
+                .unwrap()
+                .amount;
+            assert_eq!(balance, Uint128::new(10_000_000_000_000));
+
+            let investment: crate::msg::InvestmentResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::Investment {
+                        proposal_id: "p1".to_string(),
+                        investor: addr(investor).to_string(),
+                    },
+                )
+                .unwrap();
+            assert_eq!(investment.investment.status, InvestmentStatus::Refunded);
+        }
+        let treasury: TreasuryBalancesResponse = app
+            .wrap()
+            .query_wasm_smart(launchpad_addr.clone(), &QueryMsg::TreasuryBalances {})
+            .unwrap();
+        assert!(treasury.total.is_zero());
+        assert!(treasury.bank_balance.is_zero());
+
+        let proposal: crate::msg::ProposalResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::Proposal {
+                    proposal_id: "p1".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(proposal.proposal.status, ProposalStatus::Cancelled);
+        assert!(proposal.proposal.funding_status.raised_amount.is_zero());
+
+        // Refunds cannot be claimed a second time
+        let err = app
+            .execute_contract(
+                addr("investor1"),
+                launchpad_addr,
+                &ExecuteMsg::ClaimRefund {
+                    proposal_id: "p1".to_string(),
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert!(err.root_cause().to_string().contains("already claimed"));
+    }
+
+    #[test]
+    fn test_amendments_apply_directly_until_investors_vote() {
+        use crate::amendments::{AmendmentResponse, AmendmentStatus, TermsAmendment};
+        use crate::governance::VoteOption;
+
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_funded_proposal(&mut app, &launchpad_addr, &[]);
+        let created_at = app.block_info().time.seconds();
+        let proposal = |app: &App| -> crate::msg::ProposalResponse {
+            app.wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::Proposal {
+                        proposal_id: "p1".to_string(),
+                    },
+                )
+                .unwrap()
+        };
+        let amend = |terms: TermsAmendment| ExecuteMsg::AmendFinancialTerms {
+            proposal_id: "p1".to_string(),
+            terms,
+            reason: "Longer marketing period".to_string(),
+        };
+        let extend = amend(TermsAmendment {
+            funding_deadline: Some(created_at + 80 * 24 * 60 * 60),
+            target_amount: None,
+        });
+
+        // Without investors the creator's change applies straight away
+        app.execute_contract(addr("investor1"), launchpad_addr.clone(), &extend, &[])
+            .unwrap_err();
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &extend, &[])
+            .unwrap();
+        assert_eq!(
+            proposal(&app).proposal.financial_terms.funding_deadline,
+            created_at + 80 * 24 * 60 * 60
+        );
+
+        for (investor, amount) in [
+            ("investor1", 3_000_000_000_000),
+            ("investor2", 1_000_000_000_000),
+        ] {
+            app.execute_contract(
+                addr(investor),
+                launchpad_addr.clone(),
+                &ExecuteMsg::Invest {
+                    proposal_id: "p1".to_string(),
+                    operation_id: None,
+                    referrer: None,
+                },
+                &coins(amount, "untrn"),
+            )
+            .unwrap();
+        }
+
+        // Now lowering the target needs the investors' approval
+        let lower = amend(TermsAmendment {
+            funding_deadline: None,
+            target_amount: Some(Uint128::new(4_500_000_000_000)),
+        });
+        let res = app
+            .execute_contract(addr("creator"), launchpad_addr.clone(), &lower, &[])
+            .unwrap();
+        assert!(res.events.iter().any(|e| e
+            .attributes
+            .iter()
+            .any(|a| a.key == "investors_notified" && a.value == "2")));
+        assert_eq!(
+            proposal(&app).proposal.financial_terms.target_amount,
+            Uint128::new(5_000_000_000_000)
+        );
+        let err = app
+            .execute_contract(addr("creator"), launchpad_addr.clone(), &lower, &[])
+            .unwrap_err();
+        assert!(err.root_cause().to_string().contains("already open"));
+
+        let vote = |vote: VoteOption| ExecuteMsg::VoteOnAmendment {
+            proposal_id: "p1".to_string(),
+            vote,
+        };
+        app.execute_contract(
+            addr("investor3"),
+            launchpad_addr.clone(),
+            &vote(VoteOption::Yes),
+            &[],
+        )
+        .unwrap_err();
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &vote(VoteOption::Yes),
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("investor2"),
+            launchpad_addr.clone(),
+            &vote(VoteOption::No),
+            &[],
+        )
+        .unwrap();
+
+        let resolve = ExecuteMsg::ResolveAmendment {
+            proposal_id: "p1".to_string(),
+        };
+        app.execute_contract(addr("investor3"), launchpad_addr.clone(), &resolve, &[])
+            .unwrap_err();
+        app.update_block(|block| block.time = block.time.plus_days(7));
+        app.execute_contract(addr("investor3"), launchpad_addr.clone(), &resolve, &[])
+            .unwrap();
+
+        let amendment: AmendmentResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::Amendment {
+                    proposal_id: "p1".to_string(),
+                    voter: Some(addr("investor1").to_string()),
+                },
+            )
+            .unwrap();
+        let latest = amendment.amendment.unwrap();
+        assert_eq!(latest.status, AmendmentStatus::Applied);
+        assert_eq!(latest.tally.yes, 3_000);
+        assert_eq!(amendment.voting_power, Some(3_000));
+        assert_eq!(amendment.vote, Some(VoteOption::Yes));
+        assert_eq!(
+            proposal(&app).proposal.financial_terms.target_amount,
+            Uint128::new(4_500_000_000_000)
+        );
+    }
+
+    #[test]
+    fn test_creator_extends_deadline_once() {
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_funded_proposal(
+            &mut app,
+            &launchpad_addr,
+            &[("investor1", 4_000_000_000_000)],
+        );
+        let created_at = app.block_info().time.seconds();
+        let extend = |days: u64| ExecuteMsg::ExtendFundingDeadline {
+            proposal_id: "p1".to_string(),
+            new_deadline: created_at + days * 24 * 60 * 60,
+        };
+
+        app.execute_contract(addr("investor1"), launchpad_addr.clone(), &extend(90), &[])
+            .unwrap_err();
+        // Bounded by the 120-day maximum funding period from creation
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &extend(121), &[])
+            .unwrap_err();
+
+        let res = app
+            .execute_contract(addr("creator"), launchpad_addr.clone(), &extend(90), &[])
+            .unwrap();
+        assert!(res
+            .events
+            .iter()
+            .any(|e| e.ty == "wasm-cf1.extend_funding_deadline.v1"));
+        let err = app
+            .execute_contract(addr("creator"), launchpad_addr.clone(), &extend(100), &[])
+            .unwrap_err();
+        assert!(err
+            .root_cause()
+            .to_string()
+            .contains("already been extended"));
+
+        // The original deadline passes without the raise failing
+        app.update_block(|block| block.time = block.time.plus_days(61));
+        app.execute_contract(
+            addr("investor3"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::ProcessExpiredProposals {
+                start_after: None,
+                limit: None,
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("investor2"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::Invest {
+                proposal_id: "p1".to_string(),
+                operation_id: None,
+                referrer: None,
+            },
+            &coins(1_000_000_000_000, "untrn"),
+        )
+        .unwrap();
+
+        let proposal: crate::msg::ProposalResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr,
+                &QueryMsg::Proposal {
+                    proposal_id: "p1".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(proposal.proposal.status, ProposalStatus::Funded);
+    }
+
+    #[test]
+    fn test_soft_cap_and_over_subscription() {
+        let (mut app, launchpad_addr) = setup_contract();
+        let create = |app: &mut App, creator: &str, soft_cap: u128, max_raise: Option<u128>| {
+            let (asset_details, mut financial_terms, documents, compliance) =
+                create_sample_proposal();
+            financial_terms.target_amount = Uint128::new(3_000_000_000_000);
+            financial_terms.total_shares = 6_000;
+            financial_terms.soft_cap = Some(Uint128::new(soft_cap));
+            financial_terms.max_raise = max_raise.map(Uint128::new);
+            app.execute_contract(
+                addr(creator),
+                launchpad_addr.clone(),
+                &ExecuteMsg::CreateProposal {
+                    asset_details,
+                    financial_terms,
+                    documents,
+                    compliance,
+                },
+                &[],
+            )
+        };
+        let invest = |app: &mut App, investor: &str, proposal_id: &str, amount: u128| {
+            app.execute_contract(
+                addr(investor),
+                launchpad_addr.clone(),
+                &ExecuteMsg::Invest {
+                    proposal_id: proposal_id.to_string(),
+                    operation_id: None,
+                    referrer: None,
+                },
+                &coins(amount, "untrn"),
+            )
+            .unwrap();
+        };
+        let status = |app: &App, proposal_id: &str| {
+            let proposal: crate::msg::ProposalResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::Proposal {
+                        proposal_id: proposal_id.to_string(),
+                    },
+                )
+                .unwrap();
+            proposal.proposal.status
+        };
+
+        // Soft cap above target, and a cap the share supply cannot cover, are rejected
+        create(&mut app, "creator", 4_000_000_000_000, None).unwrap_err();
+        create(
+            &mut app,
+            "creator",
+            2_000_000_000_000,
+            Some(7_000_000_000_000),
+        )
+        .unwrap_err();
+
+        // p1 keeps accepting past its target until the max raise is reached
+        create(
+            &mut app,
+            "creator",
+            2_000_000_000_000,
+            Some(4_000_000_000_000),
+        )
+        .unwrap();
+        invest(&mut app, "investor1", "p1", 3_000_000_000_000);
+        assert_eq!(status(&app, "p1"), ProposalStatus::Active);
+        invest(&mut app, "investor2", "p1", 1_000_000_000_000);
+        assert_eq!(status(&app, "p1"), ProposalStatus::Funded);
+
+        // p2 meets only its soft cap and p3 falls short of it; each from another creator, as
+        // every raise holds its target against its creator's Reg CF allowance
+        create(&mut app, "creator2", 2_000_000_000_000, None).unwrap();
+        invest(&mut app, "investor2", "p2", 2_500_000_000_000);
+        create(&mut app, "creator3", 2_000_000_000_000, None).unwrap();
+        invest(&mut app, "investor3", "p3", 1_000_000_000_000);
+
+        app.update_block(|block| block.time = block.time.plus_days(61));
+        let res = app
+            .execute_contract(
+                addr("investor1"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::ProcessExpiredProposals {
+                    start_after: None,
+                    limit: None,
+                },
+                &[],
+            )
+            .unwrap();
+        let processed = res
+            .events
+            .iter()
+            .find(|e| e.ty == "wasm-cf1.process_expired_proposals.v1")
+            .unwrap();
+        assert!(processed
+            .attributes
+            .iter()
+            .any(|a| a.key == "funded_proposals" && a.value == "p2"));
+        assert_eq!(status(&app, "p2"), ProposalStatus::Funded);
+        assert_eq!(status(&app, "p3"), ProposalStatus::Failed);
+    }
+
+    #[test]
+    fn test_creator_finalizes_funding_at_soft_cap() {
+        let (mut app, launchpad_addr) = setup_contract();
+        let (asset_details, mut financial_terms, documents, compliance) = create_sample_proposal();
+        financial_terms.soft_cap = Some(Uint128::new(2_000_000_000_000));
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CreateProposal {
+                asset_details,
+                financial_terms,
+                documents,
+                compliance,
+            },
+            &[],
+        )
+        .unwrap();
+        let invest = |app: &mut App, amount: u128| {
+            app.execute_contract(
+                addr("investor1"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::Invest {
+                    proposal_id: "p1".to_string(),
+                    operation_id: None,
+                    referrer: None,
+                },
+                &coins(amount, "untrn"),
+            )
+            .unwrap();
+        };
+        let finalize = ExecuteMsg::FinalizeFunding {
+            proposal_id: "p1".to_string(),
+        };
+
+        invest(&mut app, 1_000_000_000_000);
+        let err = app
+            .execute_contract(addr("creator"), launchpad_addr.clone(), &finalize, &[])
+            .unwrap_err();
+        assert!(err
+            .root_cause()
+            .to_string()
+            .contains("Soft cap not reached"));
+
+        invest(&mut app, 1_500_000_000_000);
+        app.execute_contract(addr("investor1"), launchpad_addr.clone(), &finalize, &[])
+            .unwrap_err();
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &finalize, &[])
+            .unwrap();
+
+        let proposal: crate::msg::ProposalResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::Proposal {
+                    proposal_id: "p1".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(proposal.proposal.status, ProposalStatus::Funded);
+        assert_eq!(
+            proposal.proposal.timestamps.lockup_end,
+            Some(app.block_info().time.seconds() + 365 * 24 * 60 * 60)
+        );
+
+        // Closed raises take no further investment and cannot be finalized again
+        app.execute_contract(
+            addr("investor2"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::Invest {
+                proposal_id: "p1".to_string(),
+                operation_id: None,
+                referrer: None,
+            },
+            &coins(1_000_000_000_000, "untrn"),
+        )
+        .unwrap_err();
+        app.execute_contract(addr("creator"), launchpad_addr, &finalize, &[])
+            .unwrap_err();
+    }
+
+    #[test]
+    fn test_private_round_whitelist() {
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_funded_proposal(&mut app, &launchpad_addr, &[]);
+        let set_whitelist = |investors: &[&str]| ExecuteMsg::SetProposalWhitelist {
+            proposal_id: "p1".to_string(),
+            investors: investors
+                .iter()
+                .map(|name| addr(name).to_string())
+                .collect(),
+        };
+        let invest = |app: &mut App, investor: &str| {
+            app.execute_contract(
+                addr(investor),
+                launchpad_addr.clone(),
+                &ExecuteMsg::Invest {
+                    proposal_id: "p1".to_string(),
+                    operation_id: None,
+                    referrer: None,
+                },
+                &coins(1_000_000_000_000, "untrn"),
+            )
+        };
+
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &set_whitelist(&["investor1"]),
+            &[],
+        )
+        .unwrap_err();
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &set_whitelist(&["investor1", "investor3", "investor1"]),
+            &[],
+        )
+        .unwrap();
+
+        invest(&mut app, "investor1").unwrap();
+        let err = invest(&mut app, "investor2").unwrap_err();
+        assert!(err
+            .root_cause()
+            .to_string()
+            .contains("not on the whitelist"));
+
+        let status: crate::whitelist::WhitelistStatusResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::WhitelistStatus {
+                    proposal_id: "p1".to_string(),
+                    investor: addr("investor2").to_string(),
+                },
+            )
+            .unwrap();
+        assert!(status.private_round && !status.eligible);
+        let eligibility: crate::kyc::InvestorEligibilityResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::InvestorEligibility {
+                    proposal_id: "p1".to_string(),
+                    investor: addr("investor2").to_string(),
+                },
+            )
+            .unwrap();
+        assert!(!eligibility.eligible);
+
+        // Duplicates are collapsed, and the list pages in address order
+        let page: crate::whitelist::ProposalWhitelistResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::ProposalWhitelist {
+                    proposal_id: "p1".to_string(),
+                    start_after: None,
+                    limit: Some(1),
+                },
+            )
+            .unwrap();
+        assert_eq!(page.size, 2);
+        assert_eq!(page.investors.len(), 1);
+        let rest: crate::whitelist::ProposalWhitelistResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::ProposalWhitelist {
+                    proposal_id: "p1".to_string(),
+                    start_after: Some(page.investors[0].to_string()),
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(rest.investors.len(), 1);
+        assert_ne!(rest.investors[0], page.investors[0]);
+
+        // An empty list reopens the round
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &set_whitelist(&[]),
+            &[],
+        )
+        .unwrap();
+        invest(&mut app, "investor2").unwrap();
+    }
+
+    #[test]
+    fn test_early_bird_tier_pricing() {
+        let (mut app, launchpad_addr) = setup_contract();
+        let (asset_details, mut financial_terms, documents, compliance) = create_sample_proposal();
+        // The first 1M untrn sells at half price, so 2000 extra shares are needed for the target
+        financial_terms.total_shares = 6_000;
+        financial_terms.pricing_tiers = vec![crate::pricing::PricingTier {
+            up_to: Uint128::new(1_000_000_000_000),
+            token_price: Uint128::new(500_000_000),
+        }];
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CreateProposal {
+                asset_details,
+                financial_terms,
+                documents,
+                compliance,
+            },
+            &[],
+        )
+        .unwrap();
+        let progress = |app: &App| {
+            let proposal: crate::msg::ProposalResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::Proposal {
+                        proposal_id: "p1".to_string(),
+                    },
+                )
+                .unwrap();
+            proposal.funding_progress
+        };
+
+        let before = progress(&app);
+        assert_eq!(before.current_tier, Some(0));
+        assert_eq!(before.current_price, Uint128::new(500_000_000));
+        assert_eq!(before.tier_remaining, Some(Uint128::new(1_000_000_000_000)));
+
+        // Spans the tier boundary: 2000 shares at the early price, 500 at the base price
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::Invest {
+                proposal_id: "p1".to_string(),
+                operation_id: None,
+                referrer: None,
+            },
+            &coins(1_500_000_000_000, "untrn"),
+        )
+        .unwrap();
+        let investment: crate::msg::InvestmentResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::Investment {
+                    proposal_id: "p1".to_string(),
+                    investor: addr("investor1").to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(investment.investment.shares, 2_500);
+
+        let after = progress(&app);
+        assert_eq!(after.current_tier, None);
+        assert_eq!(after.current_price, Uint128::new(1_000_000_000));
+        assert_eq!(after.shares_sold, 2_500);
+        assert_eq!(after.shares_remaining, 3_500);
+    }
+
+    #[test]
+    fn test_referrer_earns_share_of_platform_fee() {
+        let (mut app, launchpad_addr) = setup_contract();
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::UpdateReferralConfig { fee_bps: 2_000 },
+            &[],
+        )
+        .unwrap();
+        setup_funded_proposal(&mut app, &launchpad_addr, &[]);
+        let invest = |app: &mut App, investor: &str, referrer: &str, amount: u128| {
+            app.execute_contract(
+                addr(investor),
+                launchpad_addr.clone(),
+                &ExecuteMsg::Invest {
+                    proposal_id: "p1".to_string(),
+                    operation_id: None,
+                    referrer: Some(addr(referrer).to_string()),
+                },
+                &coins(amount, "untrn"),
+            )
+        };
+
+        let err = invest(&mut app, "investor2", "investor2", 1_000_000_000_000).unwrap_err();
+        assert!(err.root_cause().to_string().contains("refer themselves"));
+        invest(&mut app, "investor1", "referrer", 3_000_000_000_000).unwrap();
+        invest(&mut app, "investor2", "referrer", 2_000_000_000_000).unwrap();
+
+        for msg in [
+            ExecuteMsg::MintTokens {
+                proposal_id: "p1".to_string(),
+            },
+            ExecuteMsg::DistributeTokens {
+                proposal_id: "p1".to_string(),
+            },
+            ExecuteMsg::FinalizeDistribution {
+                proposal_id: "p1".to_string(),
+            },
+        ] {
+            app.execute_contract(addr("creator"), launchpad_addr.clone(), &msg, &[])
+                .unwrap();
+        }
+
+        // 20% of the 125_000_000_000 platform fee on the referred 5M goes to the referrer
+        let rewards: crate::referral::ReferrerRewardsResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::ReferrerRewards {
+                    referrer: addr("referrer").to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(rewards.rewards.referred_investments, 2);
+        assert_eq!(rewards.claimable, Uint128::new(25_000_000_000));
+        let fees = treasury_balance(&app, &launchpad_addr, TreasuryAccount::Fees);
+        assert_eq!(fees, Uint128::new(100_000_000_000));
+
+        let claim = ExecuteMsg::ClaimReferralRewards {};
+        app.execute_contract(addr("referrer"), launchpad_addr.clone(), &claim, &[])
+            .unwrap();
+        let balance = app.wrap().query_balance(addr("referrer"), "untrn").unwrap();
+        assert_eq!(balance.amount, Uint128::new(25_000_000_000));
+        app.execute_contract(addr("referrer"), launchpad_addr, &claim, &[])
+            .unwrap_err();
+    }
+
+    #[test]
+    fn test_fee_schedule_brackets_by_raise_size() {
+        let (mut app, launchpad_addr) = setup_contract();
+        let bracket = |up_to: u128, fee_bps: u16| crate::fees::FeeBracket {
+            up_to: Uint128::new(up_to),
+            fee_bps,
+        };
+        // 2.5% up to 1M, 2% up to 5M, 1.5% above
+        let schedule = ExecuteMsg::SetFeeSchedule {
+            brackets: vec![
+                bracket(1_000_000_000_000, 250),
+                bracket(5_000_000_000_000, 200),
+            ],
+        };
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &schedule, &[])
+            .unwrap_err();
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::SetFeeSchedule {
+                brackets: vec![
+                    bracket(5_000_000_000_000, 200),
+                    bracket(1_000_000_000_000, 250),
+                ],
+            },
+            &[],
+        )
+        .unwrap_err();
+        app.execute_contract(addr("admin"), launchpad_addr.clone(), &schedule, &[])
+            .unwrap();
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::UpdateConfig {
+                admin: None,
+                platform_fee_bps: Some(150),
+                min_funding_period_days: None,
+                max_funding_period_days: None,
+                cw20_code_id: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+        setup_funded_proposal(
+            &mut app,
+            &launchpad_addr,
+            &[("investor1", 5_000_000_000_000)],
+        );
+        let quote: crate::fees::FeeQuoteResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::FeeQuote {
+                    proposal_id: "p1".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(quote.fee_bps, 200);
+        assert_eq!(quote.platform_fee, Uint128::new(100_000_000_000));
+        assert_eq!(quote.creator_amount, Uint128::new(4_900_000_000_000));
+
+        for msg in [
+            ExecuteMsg::MintTokens {
+                proposal_id: "p1".to_string(),
+            },
+            ExecuteMsg::DistributeTokens {
+                proposal_id: "p1".to_string(),
+            },
+            ExecuteMsg::FinalizeDistribution {
+                proposal_id: "p1".to_string(),
+            },
+        ] {
+            app.execute_contract(addr("creator"), launchpad_addr.clone(), &msg, &[])
+                .unwrap();
+        }
+        let fees = treasury_balance(&app, &launchpad_addr, TreasuryAccount::Fees);
+        assert_eq!(fees, Uint128::new(100_000_000_000));
+    }
+
+    #[test]
+    fn test_admin_withdraws_accrued_fees_with_audit_trail() {
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_distributed_proposal(
+            &mut app,
+            &launchpad_addr,
+            &[("investor1", 5_000_000_000_000)],
+        );
+        assert_eq!(
+            treasury_balance(&app, &launchpad_addr, TreasuryAccount::Fees),
+            Uint128::new(125_000_000_000)
+        );
+
+        let withdraw = |amount: u128| ExecuteMsg::WithdrawTreasury {
+            amount: Uint128::new(amount),
+            recipient: addr("ops").to_string(),
+        };
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &withdraw(1_000),
+            &[],
+        )
+        .unwrap_err();
+        // Only accrued fees can leave, never more than the bucket holds
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &withdraw(125_000_000_001),
+            &[],
+        )
+        .unwrap_err();
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &withdraw(100_000_000_000),
+            &[],
+        )
+        .unwrap();
+
+        let ops = app.wrap().query_balance(addr("ops"), "untrn").unwrap();
+        assert_eq!(ops.amount, Uint128::new(100_000_000_000));
+        assert_eq!(
+            treasury_balance(&app, &launchpad_addr, TreasuryAccount::Fees),
+            Uint128::new(25_000_000_000)
+        );
+
+        let audit: crate::treasury::TreasuryWithdrawalsResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr,
+                &QueryMsg::TreasuryWithdrawals {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(audit.withdrawals.len(), 1);
+        assert_eq!(audit.withdrawals[0].recipient, addr("ops"));
+        assert_eq!(audit.withdrawals[0].withdrawn_by, addr("admin"));
+    }
+
+    #[test]
+    fn test_roles_split_admin_powers() {
+        let (mut app, launchpad_addr) = setup_contract();
+        let grant = |address: &str, role: Role| ExecuteMsg::GrantRole {
+            address: addr(address).to_string(),
+            role,
+        };
+        let set_fees = ExecuteMsg::SetFeeSchedule { brackets: vec![] };
+        let set_keeper = ExecuteMsg::UpdateKeeperConfig {
+            reward_per_proposal: Uint128::new(1_000),
+        };
+
+        // Only owners hand out roles
+        app.execute_contract(
+            addr("treasurer"),
+            launchpad_addr.clone(),
+            &grant("treasurer", Role::Owner),
+            &[],
+        )
+        .unwrap_err();
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &grant("treasurer", Role::FeeManager),
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &grant("ops", Role::Operator),
+            &[],
+        )
+        .unwrap();
+
+        // Each role covers its own area and nothing else
+        app.execute_contract(addr("treasurer"), launchpad_addr.clone(), &set_fees, &[])
+            .unwrap();
+        app.execute_contract(addr("treasurer"), launchpad_addr.clone(), &set_keeper, &[])
+            .unwrap_err();
+        app.execute_contract(addr("ops"), launchpad_addr.clone(), &set_keeper, &[])
+            .unwrap();
+        app.execute_contract(addr("ops"), launchpad_addr.clone(), &set_fees, &[])
+            .unwrap_err();
+
+        // A second owner can act without the original admin
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &grant("backup", Role::Owner),
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("backup"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::RevokeRole {
+                address: addr("treasurer").to_string(),
+                role: Role::FeeManager,
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(addr("treasurer"), launchpad_addr.clone(), &set_fees, &[])
+            .unwrap_err();
+
+        let listing: crate::roles::RoleAssignmentsResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr,
+                &QueryMsg::RoleAssignments {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(listing.admin, addr("admin"));
+        assert_eq!(listing.assignments.len(), 2);
+        assert!(listing
+            .assignments
+            .iter()
+            .any(|a| a.address == addr("ops") && a.roles == vec![Role::Operator]));
+        assert!(listing
+            .assignments
+            .iter()
+            .any(|a| a.address == addr("backup") && a.roles == vec![Role::Owner]));
+    }
+
+    #[test]
+    fn test_pause_blocks_new_money_but_not_refunds() {
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_funded_proposal(
+            &mut app,
+            &launchpad_addr,
+            &[("investor1", 1_000_000_000_000)],
+        );
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::GrantRole {
+                address: addr("guardian").to_string(),
+                role: Role::Guardian,
+            },
+            &[],
+        )
+        .unwrap();
+
+        let pause = ExecuteMsg::Pause {
+            reason: "oracle incident".to_string(),
+        };
+        app.execute_contract(addr("investor1"), launchpad_addr.clone(), &pause, &[])
+            .unwrap_err();
+        app.execute_contract(addr("guardian"), launchpad_addr.clone(), &pause, &[])
+            .unwrap();
+
+        let status: crate::pause::PauseStatusResponse = app
+            .wrap()
+            .query_wasm_smart(launchpad_addr.clone(), &QueryMsg::PauseStatus {})
+            .unwrap();
+        assert!(status.paused);
+        assert_eq!(status.state.unwrap().reason, "oracle incident");
+
+        let invest = ExecuteMsg::Invest {
+            proposal_id: "p1".to_string(),
+            operation_id: None,
+            referrer: None,
+        };
+        let err = app
+            .execute_contract(
+                addr("investor2"),
+                launchpad_addr.clone(),
+                &invest,
+                &coins(1_000_000_000_000, "untrn"),
+            )
+            .unwrap_err();
+        assert!(err.root_cause().to_string().contains("paused"));
+        let (asset_details, financial_terms, documents, compliance) = create_sample_proposal();
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CreateProposal {
+                asset_details,
+                financial_terms,
+                documents,
+                compliance,
+            },
+            &[],
+        )
+        .unwrap_err();
+
+        // Investors can still get their money back
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CancelProposal {
+                proposal_id: "p1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        let balance = app
+            .wrap()
+            .query_balance(addr("investor1"), "untrn")
+            .unwrap();
+        assert_eq!(balance.amount, Uint128::new(10_000_000_000_000));
+
+        app.execute_contract(
+            addr("guardian"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::Unpause {},
+            &[],
+        )
+        .unwrap();
+        let status: crate::pause::PauseStatusResponse = app
+            .wrap()
+            .query_wasm_smart(launchpad_addr, &QueryMsg::PauseStatus {})
+            .unwrap();
+        assert!(!status.paused);
+    }
+
+    #[test]
+    fn test_pause_blocks_confirming_a_cosigned_ticket() {
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_funded_proposal(&mut app, &launchpad_addr, &[]);
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::UpdateCosignConfig {
+                threshold: Some(Uint128::new(1_000_000_000_000)),
+                approval_window_seconds: None,
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::Invest {
+                proposal_id: "p1".to_string(),
+                operation_id: None,
+                referrer: None,
+            },
+            &coins(2_000_000_000_000, "untrn"),
+        )
+        .unwrap();
+
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::Pause {
+                reason: "oracle incident".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        let confirm = ExecuteMsg::ConfirmInvestment { ticket_id: 1 };
+        let err = app
+            .execute_contract(addr("admin"), launchpad_addr.clone(), &confirm, &[])
+            .unwrap_err();
+        assert!(err.root_cause().to_string().contains("paused"));
+
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::Unpause {},
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(addr("admin"), launchpad_addr.clone(), &confirm, &[])
+            .unwrap();
+        let investment: crate::msg::InvestmentResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr,
+                &QueryMsg::Investment {
+                    proposal_id: "p1".to_string(),
+                    investor: addr("investor1").to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            investment.investment.amount,
+            Uint128::new(2_000_000_000_000)
+        );
+    }
+
+    #[test]
+    fn test_token_callbacks_cannot_reenter_distribution() {
+        let (mut app, launchpad_addr) = setup_contract();
+        let malicious_id = app.store_code(contract_malicious_token());
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::UpdateConfig {
+                admin: None,
+                platform_fee_bps: None,
+                min_funding_period_days: None,
+                max_funding_period_days: None,
+                cw20_code_id: Some(malicious_id),
+            },
+            &[],
+        )
+        .unwrap();
+        setup_funded_proposal(
+            &mut app,
+            &launchpad_addr,
+            &[("investor1", 5_000_000_000_000)],
+        );
+        let proposal_msg = ExecuteMsg::MintTokens {
+            proposal_id: "p1".to_string(),
+        };
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &proposal_msg, &[])
+            .unwrap();
+
+        // The token's callback during minting is refused, which fails only that investor's mint
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::DistributeTokens {
+                proposal_id: "p1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        let failed: crate::distribution::FailedMintsResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::FailedMints {
+                    proposal_id: "p1".to_string(),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(failed.failed_mints.len(), 1);
+        assert!(failed.failed_mints[0].error.contains("Reentrancy"));
+
+        // No payout while the investor is still owed their tokens
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::FinalizeDistribution {
+                proposal_id: "p1".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+
+        // The lock does not linger for later calls
+        let (asset_details, financial_terms, documents, compliance) = create_sample_proposal();
+        app.execute_contract(
+            addr("creator2"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CreateProposal {
+                asset_details,
+                financial_terms,
+                documents,
+                compliance,
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("investor2"),
+            launchpad_addr,
+            &ExecuteMsg::Invest {
+                proposal_id: "p2".to_string(),
+                operation_id: None,
+                referrer: None,
+            },
+            &coins(1_000_000_000_000, "untrn"),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_shares_sold_follows_escrowed_shares() {
+        let (mut app, launchpad_addr) = setup_contract();
+        // Each 1.5 share investment buys one whole share; the half shares are never sold
+        setup_funded_proposal(
+            &mut app,
+            &launchpad_addr,
+            &[("investor1", 1_500_000_000), ("investor1", 1_500_000_000)],
+        );
+
+        let investment: crate::msg::InvestmentResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::Investment {
+                    proposal_id: "p1".to_string(),
+                    investor: addr("investor1").to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(investment.investment.shares, 2);
+
+        let proposal: crate::msg::ProposalResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr,
+                &QueryMsg::Proposal {
+                    proposal_id: "p1".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            proposal.proposal.funding_status.raised_amount,
+            Uint128::new(3_000_000_000)
+        );
+        assert_eq!(proposal.funding_progress.shares_sold, 2);
+        assert_eq!(proposal.funding_progress.shares_remaining, 4_998);
+    }
+
+    #[test]
+    fn test_creator_allocation_vests_linearly() {
+        use crate::vesting::{CreatorVesting, VestingSchedule, VestingStatusResponse};
+
+        let (mut app, launchpad_addr) = setup_contract();
+        let (asset_details, mut financial_terms, documents, compliance) = create_sample_proposal();
+        // 10% of the 5000 shares is held back for the creator, leaving 4500 for investors
+        financial_terms.target_amount = Uint128::new(4_500_000_000_000);
+        financial_terms.creator_vesting = Some(CreatorVesting {
+            allocation_bps: 1_000,
+            schedule: VestingSchedule::Linear {
+                cliff_seconds: 30 * 86_400,
+                duration_seconds: 360 * 86_400,
+            },
+        });
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CreateProposal {
+                asset_details,
+                financial_terms,
+                documents,
+                compliance,
+            },
+            &[],
+        )
+        .unwrap();
+        let invest = ExecuteMsg::Invest {
+            proposal_id: "p1".to_string(),
+            operation_id: None,
+            referrer: None,
+        };
+        // The creator's slice cannot be sold to investors
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &invest,
+            &coins(5_000_000_000_000, "untrn"),
+        )
+        .unwrap_err();
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &invest,
+            &coins(4_500_000_000_000, "untrn"),
+        )
+        .unwrap();
+
+        let mint_res = app
+            .execute_contract(
+                addr("creator"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::MintTokens {
+                    proposal_id: "p1".to_string(),
+                },
+                &[],
+            )
+            .unwrap();
+        let token_addr = mint_res
+            .events
+            .iter()
+            .flat_map(|e| e.attributes.iter())
+            .find(|a| a.key == "token_address")
+            .map(|a| Addr::unchecked(&a.value))
+            .unwrap();
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::DistributeTokens {
+                proposal_id: "p1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::FinalizeDistribution {
+                proposal_id: "p1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        let claim = ExecuteMsg::ClaimVestedTokens {
+            proposal_id: "p1".to_string(),
+        };
+        let status = |app: &App| -> VestingStatusResponse {
+            app.wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::VestingStatus {
+                        proposal_id: "p1".to_string(),
+                    },
+                )
+                .unwrap()
+        };
+        let token_balance = |app: &App, holder: &str| -> Uint128 {
+            let res: BalanceResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    token_addr.clone(),
+                    &Cw20QueryMsg::Balance {
+                        address: addr(holder).to_string(),
+                    },
+                )
+                .unwrap();
+            res.balance
+        };
+
+        assert_eq!(status(&app).total, 500);
+        assert_eq!(status(&app).unvested, 500);
+        // Nothing before the cliff
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &claim, &[])
+            .unwrap_err();
+
+        app.update_block(|block| block.time = block.time.plus_days(90));
+        let quarter = status(&app);
+        assert_eq!(quarter.vested, 125);
+        assert_eq!(quarter.unvested, 375);
+        app.execute_contract(addr("investor1"), launchpad_addr.clone(), &claim, &[])
+            .unwrap_err();
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &claim, &[])
+            .unwrap();
+        assert_eq!(token_balance(&app, "creator"), Uint128::new(125));
+
+        app.update_block(|block| block.time = block.time.plus_days(300));
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &claim, &[])
+            .unwrap();
+        assert_eq!(token_balance(&app, "creator"), Uint128::new(500));
+        assert_eq!(token_balance(&app, "investor1"), Uint128::new(4_500));
+        let done = status(&app);
+        assert_eq!(done.claimed, 500);
+        assert_eq!(done.claimable, 0);
+    }
+
+    #[test]
+    fn test_staged_lockup_releases_quarterly_tranches() {
+        use crate::lockup::{LockupStatus, LockupTranche};
+
+        let (mut app, launchpad_addr) = setup_contract();
+        let (asset_details, mut financial_terms, documents, compliance) = create_sample_proposal();
+        // 25% per quarter for the first three quarters, the rest when the lockup ends
+        financial_terms.lockup_tranches = [90u64, 180, 270]
+            .iter()
+            .map(|days| LockupTranche {
+                after_seconds: days * 86_400,
+                release_bps: 2_500,
+            })
+            .collect();
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CreateProposal {
+                asset_details,
+                financial_terms,
+                documents,
+                compliance,
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::Invest {
+                proposal_id: "p1".to_string(),
+                operation_id: None,
+                referrer: None,
+            },
+            &coins(5_000_000_000_000, "untrn"),
+        )
+        .unwrap();
+        for msg in [
+            ExecuteMsg::MintTokens {
+                proposal_id: "p1".to_string(),
+            },
+            ExecuteMsg::DistributeTokens {
+                proposal_id: "p1".to_string(),
+            },
+            ExecuteMsg::FinalizeDistribution {
+                proposal_id: "p1".to_string(),
+            },
+        ] {
+            app.execute_contract(addr("creator"), launchpad_addr.clone(), &msg, &[])
+                .unwrap();
+        }
+        let token_addr: Addr = app
+            .wrap()
+            .query_wasm_smart::<crate::lockup::LockupInfo>(
+                launchpad_addr.clone(),
+                &QueryMsg::LockupInfo {
+                    proposal_id: "p1".to_string(),
+                },
+            )
+            .unwrap()
+            .token_address;
+        app.execute_contract(
+            addr("investor1"),
+            token_addr,
+            &cw20::Cw20ExecuteMsg::IncreaseAllowance {
+                spender: launchpad_addr.to_string(),
+                amount: Uint128::new(5_000),
+                expires: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+        let transfer = |shares: u64| ExecuteMsg::TransferShares {
+            proposal_id: "p1".to_string(),
+            recipient: addr("investor2").to_string(),
+            shares,
+        };
+        let status = |app: &App| -> LockupStatus {
+            app.wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::LockupStatus {
+                        proposal_id: "p1".to_string(),
+                    },
+                )
+                .unwrap()
+        };
+        let err = app
+            .execute_contract(addr("investor1"), launchpad_addr.clone(), &transfer(1), &[])
+            .unwrap_err();
+        assert!(err.root_cause().to_string().contains("lockup"));
+        assert_eq!(status(&app).unlocked_percentage, 0);
+
+        // First quarter: a quarter of the position can move, no more
+        app.update_block(|block| block.time = block.time.plus_days(91));
+        assert_eq!(status(&app).unlocked_percentage, 25);
+        let res = app
+            .execute_contract(
+                addr("keeper"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::ProcessExpiredLockups {
+                    start_after: None,
+                    limit: None,
+                },
+                &[],
+            )
+            .unwrap();
+        assert!(res.events.iter().any(|e| e
+            .attributes
+            .iter()
+            .any(|a| a.key == "tranches_released" && a.value == "p1")));
+        assert_eq!(status(&app).tranches_released, 1);
+
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &transfer(1_250),
+            &[],
+        )
+        .unwrap();
+        let err = app
+            .execute_contract(addr("investor1"), launchpad_addr.clone(), &transfer(1), &[])
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<crate::error::ContractError>(),
+            Some(crate::error::ContractError::TransferExceedsUnlocked { available: 0 })
+        ));
+
+        // Second quarter releases another quarter of the original position
+        app.update_block(|block| block.time = block.time.plus_days(90));
+        assert_eq!(status(&app).unlocked_percentage, 50);
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &transfer(1_250),
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(addr("investor1"), launchpad_addr.clone(), &transfer(1), &[])
+            .unwrap_err();
+
+        // Everything is free once the lockup ends
+        app.update_block(|block| block.time = block.time.plus_days(200));
+        assert_eq!(status(&app).unlocked_percentage, 100);
+        app.execute_contract(addr("investor1"), launchpad_addr, &transfer(2_500), &[])
+            .unwrap();
+    }
+
+    #[test]
+    fn test_direct_token_transfers_need_launchpad_approval() {
+        let (mut app, launchpad_addr) = setup_contract();
+        let token_addr = setup_distributed_proposal(
+            &mut app,
+            &launchpad_addr,
+            &[("investor1", 5_000_000_000_000)],
+        );
+        let transfer = |recipient: &str, amount: u128| cw20::Cw20ExecuteMsg::Transfer {
+            recipient: addr(recipient).to_string(),
+            amount: Uint128::new(amount),
+        };
+
+        // The token asks the launchpad, which still enforces the lockup
+        let err = app
+            .execute_contract(
+                addr("investor1"),
+                token_addr.clone(),
+                &transfer("investor2", 500),
+                &[],
+            )
+            .unwrap_err();
+        assert!(err.root_cause().to_string().contains("lockup"));
+
+        // Only the share token may approve its own transfers
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::ApproveTransfer {
+                owner: addr("investor1").to_string(),
+                recipient: addr("investor2").to_string(),
+                amount: Uint128::new(500),
+            },
+            &[],
+        )
+        .unwrap_err();
+
+        app.update_block(|block| block.time = block.time.plus_days(366));
+
+        // Recipients still need to pass the deal's KYC checks
+        let err = app
+            .execute_contract(
+                addr("investor1"),
+                token_addr.clone(),
+                &transfer("outsider", 500),
+                &[],
+            )
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<crate::error::ContractError>(),
+            Some(crate::error::ContractError::InvestorNotVerified { .. })
+        ));
+
+        app.execute_contract(
+            addr("investor1"),
+            token_addr.clone(),
+            &transfer("investor2", 500),
+            &[],
+        )
+        .unwrap();
+
+        // The cap table follows the token balances
+        let position = |investor: &str| -> crate::msg::InvestmentResponse {
+            app.wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::Investment {
+                        proposal_id: "p1".to_string(),
+                        investor: addr(investor).to_string(),
+                    },
+                )
+                .unwrap()
+        };
+        assert_eq!(position("investor1").investment.shares, 4_500);
+        assert_eq!(position("investor2").investment.shares, 500);
+        let balance: BalanceResponse = app
+            .wrap()
+            .query_wasm_smart(
+                token_addr,
+                &Cw20QueryMsg::Balance {
+                    address: addr("investor2").to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(balance.balance, Uint128::new(500));
+    }
+
+    #[test]
+    fn test_share_token_carries_marketing_metadata() {
+        use crate::token_metadata::TokenMetadata;
+
+        let (mut app, launchpad_addr) = setup_contract();
+        let (mut asset_details, financial_terms, documents, compliance) = create_sample_proposal();
+        let create = |asset_details: AssetDetails| ExecuteMsg::CreateProposal {
+            asset_details,
+            financial_terms: financial_terms.clone(),
+            documents: documents.clone(),
+            compliance: compliance.clone(),
+        };
+
+        // Logos have to be fetchable by wallets
+        asset_details.token_metadata = Some(TokenMetadata {
+            logo_url: Some("http://example.com/logo.png".to_string()),
+            ..TokenMetadata::default()
+        });
+        let err = app
+            .execute_contract(
+                addr("creator"),
+                launchpad_addr.clone(),
+                &create(asset_details.clone()),
+                &[],
+            )
+            .unwrap_err();
+        assert!(err.root_cause().to_string().contains("logo_url"));
+
+        // Long asset names are shortened to fit the CW20 name limit
+        asset_details.name =
+            "Downtown Seattle Class A Office Building and Parking Garage".to_string();
+        asset_details.token_metadata = Some(TokenMetadata {
+            logo_url: Some("https://example.com/logo.png".to_string()),
+            project_url: Some("https://example.com/seattle-office".to_string()),
+            description: Some("Fractional shares of a Seattle office building".to_string()),
+        });
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &create(asset_details),
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::Invest {
+                proposal_id: "p1".to_string(),
+                operation_id: None,
+                referrer: None,
+            },
+            &coins(5_000_000_000_000, "untrn"),
+        )
+        .unwrap();
+        let token_addr = app
+            .execute_contract(
+                addr("creator"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::MintTokens {
+                    proposal_id: "p1".to_string(),
+                },
+                &[],
+            )
+            .unwrap()
+            .events
+            .iter()
+            .flat_map(|e| e.attributes.iter())
+            .find(|a| a.key == "token_address")
+            .map(|a| Addr::unchecked(&a.value))
+            .unwrap();
+
+        let info: cw20::TokenInfoResponse = app
+            .wrap()
+            .query_wasm_smart(token_addr.clone(), &Cw20QueryMsg::TokenInfo {})
+            .unwrap();
+        assert_eq!(
+            info.name,
+            "Downtown Seattle Class A Office Building and Token"
+        );
+        assert_eq!(info.symbol, "CFB");
+
+        let marketing: cw20::MarketingInfoResponse = app
+            .wrap()
+            .query_wasm_smart(token_addr, &Cw20QueryMsg::MarketingInfo {})
+            .unwrap();
+        assert_eq!(
+            marketing.project.as_deref(),
+            Some("https://example.com/seattle-office")
+        );
+        assert_eq!(
+            marketing.description.as_deref(),
+            Some("Fractional shares of a Seattle office building")
+        );
+        assert_eq!(
+            marketing.logo,
+            Some(cw20::LogoInfo::Url(
+                "https://example.com/logo.png".to_string()
+            ))
+        );
+        assert_eq!(marketing.marketing, Some(addr("creator")));
+    }
+
+    #[test]
+    fn test_custom_token_symbols_are_unique() {
+        let (mut app, launchpad_addr) = setup_contract();
+        let (asset_details, financial_terms, documents, compliance) = create_sample_proposal();
+        let create = |name: Option<&str>, symbol: &str| ExecuteMsg::CreateProposal {
+            asset_details: asset_details.clone(),
+            financial_terms: FinancialTerms {
+                token_name: name.map(str::to_string),
+                token_symbol: Some(symbol.to_string()),
+                ..financial_terms.clone()
+            },
+            documents: documents.clone(),
+            compliance: compliance.clone(),
+        };
+
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &create(Some("Seattle Office Shares"), "SEAOF"),
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::Invest {
+                proposal_id: "p1".to_string(),
+                operation_id: None,
+                referrer: None,
+            },
+            &coins(5_000_000_000_000, "untrn"),
+        )
+        .unwrap();
+        let token_addr = app
+            .execute_contract(
+                addr("creator"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::MintTokens {
+                    proposal_id: "p1".to_string(),
+                },
+                &[],
+            )
+            .unwrap()
+            .events
+            .iter()
+            .flat_map(|e| e.attributes.iter())
+            .find(|a| a.key == "token_address")
+            .map(|a| Addr::unchecked(&a.value))
+            .unwrap();
+        let info: cw20::TokenInfoResponse = app
+            .wrap()
+            .query_wasm_smart(token_addr, &Cw20QueryMsg::TokenInfo {})
+            .unwrap();
+        assert_eq!(info.name, "Seattle Office Shares");
+        assert_eq!(info.symbol, "SEAOF");
+
+        // Taken (case-insensitively), malformed, or in the generated namespace. Later raises come
+        // from another creator, as p1 used this creator's whole Reg CF allowance
+        for (symbol, expected) in [
+            ("seaof", "already used by proposal p1"),
+            ("SE", "token_symbol"),
+            ("SEA0F", "token_symbol"),
+            ("CFTOWER", "reserved"),
+        ] {
+            let err = app
+                .execute_contract(
+                    addr("creator2"),
+                    launchpad_addr.clone(),
+                    &create(None, symbol),
+                    &[],
+                )
+                .unwrap_err();
+            assert!(
+                err.root_cause().to_string().contains(expected),
+                "{}: {}",
+                symbol,
+                err.root_cause()
+            );
+        }
+
+        // A cancelled raise never minted, so its symbol can be reused
+        app.execute_contract(
+            addr("creator2"),
+            launchpad_addr.clone(),
+            &create(None, "TOWER"),
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("creator2"),
+            launchpad_addr.clone(),
+            &create(None, "TOWER"),
+            &[],
+        )
+        .unwrap_err();
+        app.execute_contract(
+            addr("creator2"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CancelProposal {
+                proposal_id: "p2".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("creator2"),
+            launchpad_addr,
+            &create(None, "TOWER"),
+            &[],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_custodian_invests_for_beneficiary() {
+        let (mut app, launchpad_addr) = setup_contract();
+        let (asset_details, financial_terms, documents, compliance) = create_sample_proposal();
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CreateProposal {
+                asset_details,
+                financial_terms,
+                documents,
+                compliance,
+            },
+            &[],
+        )
+        .unwrap();
+        app.send_tokens(
+            addr("admin"),
+            addr("broker"),
+            &coins(3_000_000_000_000, "untrn"),
+        )
+        .unwrap();
+        let invest_for = |beneficiary: &str| ExecuteMsg::InvestFor {
+            proposal_id: "p1".to_string(),
+            beneficiary: addr(beneficiary).to_string(),
+        };
+
+        // Only allowlisted custodians, managed by compliance
+        app.execute_contract(
+            addr("broker"),
+            launchpad_addr.clone(),
+            &invest_for("investor2"),
+            &coins(1_000_000_000_000, "untrn"),
+        )
+        .unwrap_err();
+        let add_broker = ExecuteMsg::AddCustodian {
+            address: addr("broker").to_string(),
+        };
+        app.execute_contract(addr("investor1"), launchpad_addr.clone(), &add_broker, &[])
+            .unwrap_err();
+        app.execute_contract(addr("admin"), launchpad_addr.clone(), &add_broker, &[])
+            .unwrap();
+        let custodians: crate::custody::CustodiansResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::Custodians {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(custodians.custodians, vec![addr("broker")]);
+
+        // The position is the customer's, not the broker's
+        app.execute_contract(
+            addr("broker"),
+            launchpad_addr.clone(),
+            &invest_for("investor2"),
+            &coins(2_000_000_000_000, "untrn"),
+        )
+        .unwrap();
+        let position = |investor: &str| {
+            app.wrap()
+                .query_wasm_smart::<crate::msg::InvestmentResponse>(
+                    launchpad_addr.clone(),
+                    &QueryMsg::Investment {
+                        proposal_id: "p1".to_string(),
+                        investor: addr(investor).to_string(),
+                    },
+                )
+                .map(|res| res.investment)
+        };
+        let investment = position("investor2").unwrap();
+        assert_eq!(investment.amount, Uint128::new(2_000_000_000_000));
+        assert_eq!(investment.shares, 2_000);
+        assert!(position("broker").is_err());
+
+        // Beneficiaries go through the same KYC checks as direct investors
+        let err = app
+            .execute_contract(
+                addr("broker"),
+                launchpad_addr.clone(),
+                &invest_for("outsider"),
+                &coins(1_000_000_000, "untrn"),
+            )
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<crate::error::ContractError>(),
+            Some(crate::error::ContractError::InvestorNotVerified { .. })
+        ));
+
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::RemoveCustodian {
+                address: addr("broker").to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("broker"),
+            launchpad_addr,
+            &invest_for("investor3"),
+            &coins(1_000_000_000, "untrn"),
+        )
+        .unwrap_err();
+    }
+
+    #[test]
+    fn test_investor_withdraws_from_active_raise() {
+        let (mut app, launchpad_addr) = setup_contract();
+        let (asset_details, mut financial_terms, documents, compliance) = create_sample_proposal();
+        financial_terms.withdrawal_terms = crate::reconfirmation::WithdrawalTerms {
+            fee_bps: 100, // 1%
+            cooldown_seconds: 86_400,
+        };
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CreateProposal {
+                asset_details,
+                financial_terms,
+                documents,
+                compliance,
+            },
+            &[],
+        )
+        .unwrap();
+        let invest = ExecuteMsg::Invest {
+            proposal_id: "p1".to_string(),
+            operation_id: None,
+            referrer: None,
+        };
+        let withdraw = ExecuteMsg::WithdrawInvestment {
+            proposal_id: "p1".to_string(),
+            operation_id: None,
+        };
+        for (investor, amount) in [
+            ("investor1", 2_000_000_000_000),
+            ("investor2", 1_000_000_000_000),
+        ] {
+            app.execute_contract(
+                addr(investor),
+                launchpad_addr.clone(),
+                &invest,
+                &coins(amount, "untrn"),
+            )
+            .unwrap();
+        }
+
+        let err = app
+            .execute_contract(addr("investor1"), launchpad_addr.clone(), &withdraw, &[])
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<crate::error::ContractError>(),
+            Some(crate::error::ContractError::WithdrawalCooldown { .. })
+        ));
+
+        // After the cooldown the position is unwound, less the 1% fee
+        app.update_block(|block| block.time = block.time.plus_seconds(86_400));
+        app.execute_contract(addr("investor1"), launchpad_addr.clone(), &withdraw, &[])
+            .unwrap();
+        let balance = app
+            .wrap()
+            .query_balance(addr("investor1"), "untrn")
+            .unwrap();
+        assert_eq!(balance.amount, Uint128::new(9_980_000_000_000));
+        assert_eq!(
+            treasury_balance(&app, &launchpad_addr, TreasuryAccount::Fees),
+            Uint128::new(20_000_000_000)
+        );
+        assert_eq!(
+            treasury_balance(&app, &launchpad_addr, TreasuryAccount::Escrow),
+            Uint128::new(1_000_000_000_000)
+        );
+        let proposal: crate::msg::ProposalResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::Proposal {
+                    proposal_id: "p1".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            proposal.proposal.funding_status.raised_amount,
+            Uint128::new(1_000_000_000_000)
+        );
+        assert_eq!(proposal.proposal.funding_status.investor_count, 1);
+        app.execute_contract(addr("investor1"), launchpad_addr.clone(), &withdraw, &[])
+            .unwrap_err();
+
+        // The withdrawn shares are back on sale, so the rest of the raise fills exactly
+        let res = app
+            .execute_contract(
+                addr("investor3"),
+                launchpad_addr.clone(),
+                &invest,
+                &coins(4_000_000_000_000, "untrn"),
+            )
+            .unwrap();
+        assert!(res
+            .events
+            .iter()
+            .flat_map(|e| e.attributes.iter())
+            .any(|a| a.key == "funding_completed" && a.value == "true"));
+
+        // Funded raises only release investors through reconfirmation
+        let err = app
+            .execute_contract(addr("investor2"), launchpad_addr, &withdraw, &[])
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<crate::error::ContractError>(),
+            Some(crate::error::ContractError::ProposalNotActive {})
+        ));
+    }
+
+    #[test]
+    fn test_proposal_summary_tracks_investments_and_migration_backfills() {
+        let (mut app, launchpad_addr) = setup_contract();
+        let (asset_details, financial_terms, documents, compliance) = create_sample_proposal();
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CreateProposal {
+                asset_details,
+                financial_terms,
+                documents,
+                compliance,
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::Invest {
+                proposal_id: "p1".to_string(),
+                operation_id: None,
+                referrer: None,
+            },
+            &coins(1_000_000_000_000, "untrn"),
+        )
+        .unwrap();
+
+        let summary_query = QueryMsg::ProposalSummary {
+            proposal_id: "p1".to_string(),
+        };
+        let summary: crate::state::ProposalHotData = app
+            .wrap()
+            .query_wasm_smart(&launchpad_addr, &summary_query)
+            .unwrap();
+        assert_eq!(summary.raised_amount, Uint128::new(1_000_000_000_000));
+        assert_eq!(summary.investor_count, 1);
+        assert_eq!(summary.status, ProposalStatus::Active);
+
+        // Simulate a summary left stale by an older version, then repair it by migrating
+        {
+            let mut storage = app.contract_storage_mut(&launchpad_addr);
+            crate::state::PROPOSAL_HOT_DATA
+                .save(
+                    storage.as_mut(),
+                    "p1".to_string(),
+                    &crate::state::ProposalHotData {
+                        raised_amount: Uint128::zero(),
+                        investor_count: 0,
+                        ..summary.clone()
+                    },
+                )
+                .unwrap();
+        }
+        let code_id = app
+            .wrap()
+            .query_wasm_contract_info(&launchpad_addr)
+            .unwrap()
+            .code_id;
+        app.migrate_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &crate::msg::MigrateMsg {},
+            code_id,
+        )
+        .unwrap();
+
+        let backfilled: crate::state::ProposalHotData = app
+            .wrap()
+            .query_wasm_smart(&launchpad_addr, &summary_query)
+            .unwrap();
+        assert_eq!(backfilled, summary);
+    }
+
+    #[test]
+    fn test_proposals_by_status_pages_through_the_status_index() {
+        let (mut app, launchpad_addr) = setup_contract();
+        for _ in 0..3 {
+            let (asset_details, financial_terms, documents, compliance) =
+                create_small_sample_proposal();
+            app.execute_contract(
+                addr("creator"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::CreateProposal {
+                    asset_details,
+                    financial_terms,
+                    documents,
+                    compliance,
+                },
+                &[],
+            )
+            .unwrap();
+            app.update_block(|block| block.time = block.time.plus_seconds(3_600));
+        }
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CancelProposal {
+                proposal_id: "p2".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        let page = |app: &App, status: ProposalStatus, start_after: Option<&str>| {
+            let response: crate::msg::ProposalsResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    &launchpad_addr,
+                    &QueryMsg::ProposalsByStatus {
+                        status,
+                        start_after: start_after.map(str::to_string),
+                        limit: Some(1),
+                    },
+                )
+                .unwrap();
+            response
+                .proposals
+                .into_iter()
+                .map(|p| p.proposal.id)
+                .collect::<Vec<_>>()
+        };
+
+        // Newest first, one per page, with the cancelled proposal dropped from the active index
+        assert_eq!(page(&app, ProposalStatus::Active, None), vec!["p3"]);
+        assert_eq!(page(&app, ProposalStatus::Active, Some("p3")), vec!["p1"]);
+        assert!(page(&app, ProposalStatus::Active, Some("p1")).is_empty());
+        assert_eq!(page(&app, ProposalStatus::Cancelled, None), vec!["p2"]);
+    }
+
+    #[test]
+    fn test_list_queries_return_next_key_cursors() {
+        let (mut app, launchpad_addr) = setup_contract();
+        for _ in 0..3 {
+            let (asset_details, financial_terms, documents, compliance) =
+                create_small_sample_proposal();
+            app.execute_contract(
+                addr("creator"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::CreateProposal {
+                    asset_details,
+                    financial_terms,
+                    documents,
+                    compliance,
+                },
+                &[],
+            )
+            .unwrap();
+        }
+        for proposal_id in ["p1", "p2", "p3"] {
+            app.execute_contract(
+                addr("investor1"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::Invest {
+                    proposal_id: proposal_id.to_string(),
+                    operation_id: None,
+                    referrer: None,
+                },
+                &coins(100_000_000_000, "untrn"),
+            )
+            .unwrap();
+        }
+
+        // Follow the cursor until it runs out, two proposals per page
+        let mut seen = vec![];
+        let mut start_after = None;
+        loop {
+            let page: crate::msg::ProposalsResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    &launchpad_addr,
+                    &QueryMsg::AllProposals {
+                        start_after: start_after.clone(),
+                        limit: Some(2),
+                    },
+                )
+                .unwrap();
+            assert_eq!(page.total_count, 3);
+            seen.extend(page.proposals.into_iter().map(|p| p.proposal.id));
+            start_after = page.next_key;
+            if start_after.is_none() {
+                break;
+            }
+        }
+        assert_eq!(seen, vec!["p3", "p2", "p1"]);
+
+        let first: crate::msg::InvestmentsResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &launchpad_addr,
+                &QueryMsg::InvestmentsByUser {
+                    user: addr("investor1").to_string(),
+                    start_after: None,
+                    limit: Some(2),
+                },
+            )
+            .unwrap();
+        assert_eq!(first.investments.len(), 2);
+        assert_eq!(first.total_invested, Uint128::new(200_000_000_000));
+        assert_eq!(first.next_key, Some("p2".to_string()));
+        let last: crate::msg::InvestmentsResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &launchpad_addr,
+                &QueryMsg::InvestmentsByUser {
+                    user: addr("investor1").to_string(),
+                    start_after: first.next_key,
+                    limit: Some(2),
+                },
+            )
+            .unwrap();
+        assert_eq!(last.investments.len(), 1);
+        assert_eq!(last.investments[0].investment.proposal_id, "p3");
+        assert_eq!(last.next_key, None);
+
+        let by_proposal: crate::msg::InvestmentsResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &launchpad_addr,
+                &QueryMsg::InvestmentsByProposal {
+                    proposal_id: "p1".to_string(),
+                    start_after: None,
+                    limit: Some(1),
+                },
+            )
+            .unwrap();
+        assert_eq!(by_proposal.investments.len(), 1);
+        assert_eq!(by_proposal.next_key, None);
+    }
+
+    #[test]
+    fn test_list_queries_report_totals_across_pages() {
+        let (mut app, launchpad_addr) = setup_contract();
+        for _ in 0..3 {
+            let (asset_details, financial_terms, documents, compliance) =
+                create_small_sample_proposal();
+            app.execute_contract(
+                addr("creator"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::CreateProposal {
+                    asset_details,
+                    financial_terms,
+                    documents,
+                    compliance,
+                },
+                &[],
+            )
+            .unwrap();
+        }
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CancelProposal {
+                proposal_id: "p1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        let total = |query: QueryMsg| {
+            let response: crate::msg::ProposalsResponse = app
+                .wrap()
+                .query_wasm_smart(&launchpad_addr, &query)
+                .unwrap();
+            assert_eq!(response.proposals.len(), 1);
+            response.total_count
+        };
+        let by_status = |status| QueryMsg::ProposalsByStatus {
+            status,
+            start_after: None,
+            limit: Some(1),
+        };
+
+        assert_eq!(total(by_status(ProposalStatus::Active)), 2);
+        assert_eq!(total(by_status(ProposalStatus::Cancelled)), 1);
+        assert_eq!(
+            total(QueryMsg::ProposalsByCreator {
+                creator: addr("creator").to_string(),
+                start_after: None,
+                limit: Some(1),
+            }),
+            3
+        );
+        assert_eq!(
+            total(QueryMsg::AllProposals {
+                start_after: None,
+                limit: Some(1),
+            }),
+            3
+        );
+    }
+
+    #[test]
+    fn test_portfolio_reads_follow_the_user_investment_index() {
+        let (mut app, launchpad_addr) = setup_contract();
+        for _ in 0..2 {
+            let (asset_details, financial_terms, documents, compliance) =
+                create_small_sample_proposal();
+            app.execute_contract(
+                addr("creator"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::CreateProposal {
+                    asset_details,
+                    financial_terms,
+                    documents,
+                    compliance,
+                },
+                &[],
+            )
+            .unwrap();
+        }
+        for (investor, proposal_id) in [
+            ("investor1", "p1"),
+            ("investor1", "p2"),
+            ("investor2", "p1"),
+        ] {
+            app.execute_contract(
+                addr(investor),
+                launchpad_addr.clone(),
+                &ExecuteMsg::Invest {
+                    proposal_id: proposal_id.to_string(),
+                    operation_id: None,
+                    referrer: None,
+                },
+                &coins(100_000_000_000, "untrn"),
+            )
+            .unwrap();
+        }
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::WithdrawInvestment {
+                proposal_id: "p1".to_string(),
+                operation_id: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+        // The withdrawn position drops out, and other investors' positions never show up
+        let portfolio: crate::msg::InvestmentsResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &launchpad_addr,
+                &QueryMsg::UserPortfolio {
+                    user: addr("investor1").to_string(),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(portfolio.investments.len(), 1);
+        assert_eq!(portfolio.investments[0].investment.proposal_id, "p2");
+
+        let performance: crate::helpers::PortfolioPerformance = app
+            .wrap()
+            .query_wasm_smart(
+                &launchpad_addr,
+                &QueryMsg::PortfolioPerformance {
+                    user: addr("investor1").to_string(),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(performance.total_invested, Uint128::new(100_000_000_000));
+        assert_eq!(performance.active_investments, 1);
+    }
+
+    #[test]
+    fn test_platform_aggregates_follow_withdrawals_and_rebuild_on_migrate() {
+        let (mut app, launchpad_addr) = setup_contract();
+        let (asset_details, financial_terms, documents, compliance) = create_sample_proposal();
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CreateProposal {
+                asset_details,
+                financial_terms,
+                documents,
+                compliance,
+            },
+            &[],
+        )
+        .unwrap();
+        for investor in ["investor1", "investor2"] {
+            app.execute_contract(
+                addr(investor),
+                launchpad_addr.clone(),
+                &ExecuteMsg::Invest {
+                    proposal_id: "p1".to_string(),
+                    operation_id: None,
+                    referrer: None,
+                },
+                &coins(1_000_000_000_000, "untrn"),
+            )
+            .unwrap();
+        }
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::WithdrawInvestment {
+                proposal_id: "p1".to_string(),
+                operation_id: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+        let check = |app: &App| {
+            let tvl: Uint128 = app
+                .wrap()
+                .query_wasm_smart(&launchpad_addr, &QueryMsg::TotalValueLocked {})
+                .unwrap();
+            assert_eq!(tvl, Uint128::new(1_000_000_000_000));
+            let stats: crate::msg::PlatformStats = app
+                .wrap()
+                .query_wasm_smart(&launchpad_addr, &QueryMsg::PlatformStats {})
+                .unwrap();
+            assert_eq!(stats.total_raised, tvl);
+            assert_eq!(stats.total_investors, 2); // Exiting a raise does not uncount an investor
+            assert_eq!(stats.active_proposals, 1);
+        };
+        check(&app);
+
+        // A contract from before the running aggregates existed gets them rebuilt on migrate
+        {
+            let mut storage = app.contract_storage_mut(&launchpad_addr);
+            crate::state::TOTAL_VALUE_LOCKED.remove(storage.as_mut());
+            crate::state::AGGREGATE_PLATFORM_STATS.remove(storage.as_mut());
+            crate::state::STATUS_COUNTS.clear(storage.as_mut());
+        }
+        let code_id = app
+            .wrap()
+            .query_wasm_contract_info(&launchpad_addr)
+            .unwrap()
+            .code_id;
+        app.migrate_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &crate::msg::MigrateMsg {},
+            code_id,
+        )
+        .unwrap();
+        check(&app);
+    }
+
+    #[test]
+    fn test_migrate_rekeys_the_creator_index_by_sequence() {
+        let (mut app, launchpad_addr) = setup_contract();
+        for _ in 0..3 {
+            let (asset_details, financial_terms, documents, compliance) =
+                create_small_sample_proposal();
+            app.execute_contract(
+                addr("creator"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::CreateProposal {
+                    asset_details,
+                    financial_terms,
+                    documents,
+                    compliance,
+                },
+                &[],
+            )
+            .unwrap();
+            app.update_block(|block| block.time = block.time.plus_seconds(60));
+        }
+
+        // An upgraded contract still has the first two proposals keyed by creation time
+        {
+            let mut storage = app.contract_storage_mut(&launchpad_addr);
+            for (sequence, proposal_id) in [(1u64, "p1"), (2, "p2")] {
+                let proposal = crate::state::PROPOSALS
+                    .load(storage.as_ref(), proposal_id.to_string())
+                    .unwrap();
+                let index = crate::state::CREATOR_PROPOSAL_INDEX;
+                index.remove(storage.as_mut(), (&proposal.creator, sequence));
+                index
+                    .save(
+                        storage.as_mut(),
+                        (&proposal.creator, proposal.timestamps.created_at),
+                        &proposal.id,
+                    )
+                    .unwrap();
+            }
+        }
+        let code_id = app
+            .wrap()
+            .query_wasm_contract_info(&launchpad_addr)
+            .unwrap()
+            .code_id;
+        app.migrate_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &crate::msg::MigrateMsg {},
+            code_id,
+        )
+        .unwrap();
+
+        // Newest first, paging by sequence across what used to be both kinds of key
+        let page = |app: &App, start_after: Option<String>| -> crate::msg::ProposalsResponse {
+            app.wrap()
+                .query_wasm_smart(
+                    &launchpad_addr,
+                    &QueryMsg::ProposalsByCreator {
+                        creator: addr("creator").to_string(),
+                        start_after,
+                        limit: Some(2),
+                    },
+                )
+                .unwrap()
+        };
+        let ids = |res: &crate::msg::ProposalsResponse| {
+            res.proposals
+                .iter()
+                .map(|p| p.proposal.id.clone())
+                .collect::<Vec<_>>()
+        };
+        let first = page(&app, None);
+        assert_eq!(ids(&first), vec!["p3", "p2"]);
+        assert_eq!(first.next_key.as_deref(), Some("2"));
+        let second = page(&app, first.next_key);
+        assert_eq!(ids(&second), vec!["p1"]);
+        assert_eq!(second.next_key, None);
+    }
+
+    #[test]
+    fn test_platform_stats_count_each_investor_once() {
+        let (mut app, launchpad_addr) = setup_contract();
+        for _ in 0..2 {
+            let (asset_details, financial_terms, documents, compliance) =
+                create_small_sample_proposal();
+            app.execute_contract(
+                addr("creator"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::CreateProposal {
+                    asset_details,
+                    financial_terms,
+                    documents,
+                    compliance,
+                },
+                &[],
+            )
+            .unwrap();
+        }
+        for (investor, proposal_id) in [
+            ("investor1", "p1"),
+            ("investor1", "p2"),
+            ("investor2", "p2"),
+            ("investor1", "p1"), // Top-up of an existing position
+        ] {
+            app.execute_contract(
+                addr(investor),
+                launchpad_addr.clone(),
+                &ExecuteMsg::Invest {
+                    proposal_id: proposal_id.to_string(),
+                    operation_id: None,
+                    referrer: None,
+                },
+                &coins(100_000_000_000, "untrn"),
+            )
+            .unwrap();
+        }
+
+        let stats: crate::msg::PlatformStats = app
+            .wrap()
+            .query_wasm_smart(&launchpad_addr, &QueryMsg::PlatformStats {})
+            .unwrap();
+        assert_eq!(stats.total_investors, 2);
+    }
+
+    #[test]
+    fn test_funding_progress_counts_down_from_block_time() {
+        let (mut app, launchpad_addr) = setup_contract();
+        let (asset_details, financial_terms, documents, compliance) = create_sample_proposal();
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CreateProposal {
+                asset_details,
+                financial_terms,
+                documents,
+                compliance,
+            },
+            &[],
+        )
+        .unwrap();
+        let progress = |app: &App| {
+            let response: crate::msg::ProposalResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    &launchpad_addr,
+                    &QueryMsg::Proposal {
+                        proposal_id: "p1".to_string(),
+                    },
+                )
+                .unwrap();
+            response.funding_progress
+        };
+
+        let fresh = progress(&app);
+        assert_eq!(fresh.days_remaining, 60);
+        assert_eq!(fresh.hours_remaining, 60 * 24);
+        assert_eq!(fresh.funding_deadline_iso, "2019-12-22T02:23:39Z");
+
+        // Half a day before the deadline only the hour count is left
+        app.update_block(|block| block.time = block.time.plus_seconds(59 * 86_400 + 43_200));
+        let closing = progress(&app);
+        assert_eq!(closing.days_remaining, 0);
+        assert_eq!(closing.hours_remaining, 12);
+        assert_eq!(closing.funding_deadline_iso, fresh.funding_deadline_iso);
+    }
+
+    #[test]
+    fn test_ibc_hooks_transfer_invests_for_the_beneficiary() {
+        use crate::ibc::{self, IbcHooksMemo};
+        use cw_multi_test::BankSudo;
+
+        let (mut app, launchpad_addr) = setup_contract();
+        let (asset_details, financial_terms, documents, compliance) = create_sample_proposal();
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CreateProposal {
+                asset_details,
+                financial_terms,
+                documents,
+                compliance,
+            },
+            &[],
+        )
+        .unwrap();
+
+        // What the ibc-hooks middleware does with a received transfer: credit it to an
+        // intermediate address, then run the memo's wasm call with the funds attached
+        let intermediary = addr("ibc-hooks-intermediary");
+        let receive_transfer = |app: &mut App, amount: u128, denom: &str, memo: &str| {
+            app.sudo(
+                BankSudo::Mint {
+                    to_address: intermediary.to_string(),
+                    amount: coins(amount, denom),
+                }
+                .into(),
+            )
+            .unwrap();
+            let hook: IbcHooksMemo = cosmwasm_std::from_json(memo.as_bytes()).unwrap();
+            app.execute_contract(
+                intermediary.clone(),
+                Addr::unchecked(hook.wasm.contract),
+                &hook.wasm.msg,
+                &coins(amount, denom),
+            )
+        };
+        let memo = ibc::invest_memo(&launchpad_addr, "p1", addr("investor2").as_str()).unwrap();
+
+        // Only NTRN can be invested, and only a proposal that exists; the middleware turns the
+        // error into an error ack and the sending chain refunds the transfer
+        receive_transfer(&mut app, 1_000_000_000_000, "ibc/27394FB092D2ECCD", &memo).unwrap_err();
+        let missing = ibc::invest_memo(&launchpad_addr, "p9", addr("investor2").as_str()).unwrap();
+        receive_transfer(&mut app, 1_000_000_000_000, "untrn", &missing).unwrap_err();
+
+        // The call has to carry the funds; the launchpad's own balance is never invested
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::InvestFromIbc {
+                proposal_id: "p1".to_string(),
+                beneficiary: addr("investor2").to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+
+        let res = receive_transfer(&mut app, 1_000_000_000_000, "untrn", &memo).unwrap();
+        assert!(res
+            .events
+            .iter()
+            .flat_map(|e| e.attributes.iter())
+            .any(|a| a.key == "funded_by" && a.value == intermediary.as_str()));
+        let position: crate::msg::InvestmentResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::Investment {
+                    proposal_id: "p1".to_string(),
+                    investor: addr("investor2").to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            position.investment.amount,
+            Uint128::from(1_000_000_000_000u128)
+        );
+        assert_eq!(
+            app.wrap()
+                .query_balance(&launchpad_addr, "untrn")
+                .unwrap()
+                .amount,
+            treasury_balance(&app, &launchpad_addr, TreasuryAccount::Escrow)
+        );
+    }
+
+    #[test]
+    fn test_external_escrow_holds_investments_and_pays_refunds() {
+        let (mut app, launchpad_addr) = setup_contract();
+        let escrow_code = app.store_code(contract_escrow());
+        let escrow_addr = app
+            .instantiate_contract(
+                escrow_code,
+                addr("admin"),
+                &cosmwasm_std::Empty {},
+                &[],
+                "escrow",
+                None,
+            )
+            .unwrap();
+
+        let set_escrow = ExecuteMsg::SetEscrowContract {
+            address: Some(escrow_addr.to_string()),
+        };
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &set_escrow, &[])
+            .unwrap_err();
+        app.execute_contract(addr("admin"), launchpad_addr.clone(), &set_escrow, &[])
+            .unwrap();
+
+        let (asset_details, financial_terms, documents, compliance) = create_sample_proposal();
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CreateProposal {
+                asset_details,
+                financial_terms,
+                documents,
+                compliance,
+            },
+            &[],
+        )
+        .unwrap();
+        let balance =
+            |app: &App, who: &Addr| app.wrap().query_balance(who, "untrn").unwrap().amount;
+        let investor_start = balance(&app, &addr("investor1"));
+
+        // The investment lands with the escrow contract, not the launchpad
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::Invest {
+                proposal_id: "p1".to_string(),
+                operation_id: None,
+                referrer: None,
+            },
+            &coins(1_000_000_000_000, "untrn"),
+        )
+        .unwrap();
+        assert_eq!(balance(&app, &launchpad_addr), Uint128::zero());
+        assert_eq!(
+            balance(&app, &escrow_addr),
+            Uint128::from(1_000_000_000_000u128)
+        );
+        assert_eq!(
+            treasury_balance(&app, &launchpad_addr, TreasuryAccount::Escrow),
+            Uint128::zero()
+        );
+
+        // Cancelling has the escrow contract pay the investor back
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CancelProposal {
+                proposal_id: "p1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        assert_eq!(balance(&app, &escrow_addr), Uint128::zero());
+        assert_eq!(balance(&app, &addr("investor1")), investor_start);
+    }
+
+    #[test]
+    fn test_external_escrow_keeps_the_treasury_reconciled_through_payout() {
+        let (mut app, launchpad_addr) = setup_contract();
+        let escrow_code = app.store_code(contract_escrow());
+        let escrow_addr = app
+            .instantiate_contract(
+                escrow_code,
+                addr("admin"),
+                &cosmwasm_std::Empty {},
+                &[],
+                "escrow",
+                None,
+            )
+            .unwrap();
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::SetEscrowContract {
+                address: Some(escrow_addr.to_string()),
+            },
+            &[],
+        )
+        .unwrap();
+        let balances = |app: &App| -> crate::treasury::TreasuryBalancesResponse {
+            app.wrap()
+                .query_wasm_smart(launchpad_addr.clone(), &QueryMsg::TreasuryBalances {})
+                .unwrap()
+        };
+
+        // Forwarded investments are not counted as escrow the launchpad holds
+        setup_funded_proposal(
+            &mut app,
+            &launchpad_addr,
+            &[("investor1", 5_000_000_000_000)],
+        );
+        let funded = balances(&app);
+        assert_eq!(funded.bank_balance, Uint128::zero());
+        assert_eq!(funded.total, Uint128::zero());
+
+        // Finalizing pays the creator from the escrow contract and brings back only the fee
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::MintTokens {
+                proposal_id: "p1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::DistributeTokens {
+                proposal_id: "p1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::FinalizeDistribution {
+                proposal_id: "p1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        let paid_out = balances(&app);
+        assert!(!paid_out.bank_balance.is_zero());
+        assert_eq!(paid_out.total, paid_out.bank_balance);
+        assert_eq!(paid_out.unallocated, Uint128::zero());
+        assert_eq!(
+            treasury_balance(&app, &launchpad_addr, TreasuryAccount::Escrow),
+            Uint128::zero()
+        );
+        assert_eq!(
+            app.wrap()
+                .query_balance(&escrow_addr, "untrn")
+                .unwrap()
+                .amount,
+            Uint128::zero()
+        );
+    }
+
+    #[test]
+    fn test_proposal_fee_override_replaces_the_schedule() {
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_funded_proposal(&mut app, &launchpad_addr, &[]);
+        let set_override = |fee_bps: u16| ExecuteMsg::SetProposalFeeOverride {
+            proposal_id: "p1".to_string(),
+            fee_bps,
+        };
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &set_override(100),
+            &[],
+        )
+        .unwrap_err();
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &set_override(10_001),
+            &[],
+        )
+        .unwrap_err();
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &set_override(100),
+            &[],
+        )
+        .unwrap();
+
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::Invest {
+                proposal_id: "p1".to_string(),
+                operation_id: None,
+                referrer: None,
+            },
+            &coins(5_000_000_000_000, "untrn"),
+        )
+        .unwrap();
+        // Terms are fixed once the raise is funded
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &set_override(50),
+            &[],
+        )
+        .unwrap_err();
+
+        let quote: crate::fees::FeeQuoteResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::FeeQuote {
+                    proposal_id: "p1".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(quote.fee_bps, 100);
+        assert_eq!(quote.platform_fee, Uint128::new(50_000_000_000));
+
+        for msg in [
+            ExecuteMsg::MintTokens {
+                proposal_id: "p1".to_string(),
+            },
+            ExecuteMsg::DistributeTokens {
+                proposal_id: "p1".to_string(),
+            },
+            ExecuteMsg::FinalizeDistribution {
+                proposal_id: "p1".to_string(),
+            },
+        ] {
+            app.execute_contract(addr("creator"), launchpad_addr.clone(), &msg, &[])
+                .unwrap();
+        }
+        let fees = treasury_balance(&app, &launchpad_addr, TreasuryAccount::Fees);
+        assert_eq!(fees, Uint128::new(50_000_000_000));
+    }
+
+    #[test]
+    fn test_creator_profile_is_returned_with_the_creator() {
+        let (mut app, launchpad_addr) = setup_contract();
+        let update = |name: &str, bio: Option<String>| ExecuteMsg::UpdateCreatorProfile {
+            name: name.to_string(),
+            bio,
+            website: Some("https://example.com".to_string()),
+            logo_hash: Some(
+                "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".to_string(),
+            ),
+        };
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &update(" ", None),
+            &[],
+        )
+        .unwrap_err();
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &update("Acme Capital", Some("x".repeat(1_001))),
+            &[],
+        )
+        .unwrap_err();
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &update("Acme Capital", Some("Commercial real estate".to_string())),
+            &[],
+        )
+        .unwrap();
+
+        setup_funded_proposal(&mut app, &launchpad_addr, &[]);
+        let res: crate::msg::CreatorResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::Creator {
+                    creator: addr("creator").to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(res.creator.name, "Acme Capital");
+        let profile = res.profile.unwrap();
+        assert_eq!(profile.bio.as_deref(), Some("Commercial real estate"));
+        assert_eq!(profile.website.as_deref(), Some("https://example.com"));
+
+        // Renaming later also updates the existing creator record
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &update("Acme Partners", None),
+            &[],
+        )
+        .unwrap();
+        let res: crate::msg::CreatorResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr,
+                &QueryMsg::Creator {
+                    creator: addr("creator").to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(res.creator.name, "Acme Partners");
+        assert_eq!(res.profile.unwrap().bio, None);
+    }
+
+    #[test]
+    fn test_large_raises_require_a_verified_creator() {
+        let (mut app, launchpad_addr) = setup_contract();
+        let (asset_details, financial_terms, documents, compliance) = create_sample_proposal();
+        let create = ExecuteMsg::CreateProposal {
+            asset_details,
+            financial_terms,
+            documents,
+            compliance,
+        };
+        let threshold = ExecuteMsg::SetVerificationThreshold {
+            threshold: Some(Uint128::new(1_000_000_000_000)),
+        };
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &threshold, &[])
+            .unwrap_err();
+        app.execute_contract(addr("admin"), launchpad_addr.clone(), &threshold, &[])
+            .unwrap();
+
+        let err = app
+            .execute_contract(addr("creator"), launchpad_addr.clone(), &create, &[])
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<crate::error::ContractError>(),
+            Some(crate::error::ContractError::CreatorNotVerified { .. })
+        ));
+
+        let verify = ExecuteMsg::SetCreatorVerification {
+            creator: addr("creator").to_string(),
+            tier: crate::verification::VerificationTier::Verified,
+        };
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &verify, &[])
+            .unwrap_err();
+        app.execute_contract(addr("admin"), launchpad_addr.clone(), &verify, &[])
+            .unwrap();
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &create, &[])
+            .unwrap();
+
+        let res: crate::msg::ProposalResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::Proposal {
+                    proposal_id: "p1".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            res.creator_verification,
+            crate::verification::VerificationTier::Verified
+        );
+        let creator: crate::msg::CreatorResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr,
+                &QueryMsg::Creator {
+                    creator: addr("creator").to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(creator.creator.total_proposals, 1);
+    }
+
+    #[test]
+    fn test_proposal_review_gates_listing() {
+        let (mut app, launchpad_addr) = setup_contract();
+        let required = ExecuteMsg::SetProposalReviewRequired { required: true };
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &required, &[])
+            .unwrap_err();
+        app.execute_contract(addr("admin"), launchpad_addr.clone(), &required, &[])
+            .unwrap();
+
+        setup_funded_proposal(&mut app, &launchpad_addr, &[]);
+        let review = |app: &App| -> crate::review::ProposalReviewResponse {
+            app.wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::ProposalReview {
+                        proposal_id: "p1".to_string(),
+                    },
+                )
+                .unwrap()
+        };
+        assert_eq!(review(&app).status, ProposalStatus::Draft);
+        let invest = ExecuteMsg::Invest {
+            proposal_id: "p1".to_string(),
+            operation_id: None,
+            referrer: None,
+        };
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &invest,
+            &coins(1_000_000_000, "untrn"),
+        )
+        .unwrap_err();
+
+        let submit = ExecuteMsg::SubmitProposal {
+            proposal_id: "p1".to_string(),
+        };
+        let approve = ExecuteMsg::ApproveProposal {
+            proposal_id: "p1".to_string(),
+        };
+        // Only drafts under review can be decided on
+        app.execute_contract(addr("admin"), launchpad_addr.clone(), &approve, &[])
+            .unwrap_err();
+        app.execute_contract(addr("investor1"), launchpad_addr.clone(), &submit, &[])
+            .unwrap_err();
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &submit, &[])
+            .unwrap();
+        assert_eq!(review(&app).status, ProposalStatus::UnderReview);
+
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &approve, &[])
+            .unwrap_err();
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::RejectProposal {
+                proposal_id: "p1".to_string(),
+                reason: "Appraisal is out of date".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        let res = review(&app);
+        assert_eq!(res.status, ProposalStatus::Draft);
+        let decision = res.last_decision.unwrap();
+        assert!(!decision.approved);
+        assert_eq!(decision.reason.as_deref(), Some("Appraisal is out of date"));
+
+        // The creator revises the draft and resubmits
+        let (asset_details, ..) = create_sample_proposal();
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::ResubmitProposal {
+                proposal_id: "p1".to_string(),
+                asset_details: Some(asset_details),
+                documents: None,
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(addr("admin"), launchpad_addr.clone(), &approve, &[])
+            .unwrap();
+        let res = review(&app);
+        assert_eq!(res.status, ProposalStatus::Active);
+        assert!(res.last_decision.unwrap().approved);
+
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &invest,
+            &coins(1_000_000_000, "untrn"),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_rejected_proposals_resubmit_a_bounded_number_of_times() {
+        let (mut app, launchpad_addr) = setup_contract();
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::SetProposalReviewRequired { required: true },
+            &[],
+        )
+        .unwrap();
+        setup_funded_proposal(&mut app, &launchpad_addr, &[]);
+
+        let resubmit = ExecuteMsg::ResubmitProposal {
+            proposal_id: "p1".to_string(),
+            asset_details: None,
+            documents: Some(vec![Document {
+                name: "Risk Factors".to_string(),
+                doc_type: "PDF".to_string(),
+                size: "0.4MB".to_string(),
+                hash: Some("QmZ4tDuvesekSs4qM5ZBKpXiZGun7S2CYtEZRB3DYXkjGx".to_string()),
+            }]),
+        };
+        let reject = |reason: &str| ExecuteMsg::RejectProposal {
+            proposal_id: "p1".to_string(),
+            reason: reason.to_string(),
+        };
+        // Nothing to resubmit before a rejection
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &resubmit, &[])
+            .unwrap_err();
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::SubmitProposal {
+                proposal_id: "p1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &resubmit, &[])
+            .unwrap_err();
+
+        for round in 0..=crate::review::MAX_RESUBMISSIONS {
+            app.execute_contract(
+                addr("admin"),
+                launchpad_addr.clone(),
+                &reject(&format!("Round {}", round)),
+                &[],
+            )
+            .unwrap();
+            let result =
+                app.execute_contract(addr("creator"), launchpad_addr.clone(), &resubmit, &[]);
+            assert_eq!(result.is_ok(), round < crate::review::MAX_RESUBMISSIONS);
+        }
+
+        let history: crate::review::ProposalHistoryResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::ProposalHistory {
+                    proposal_id: "p1".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(history.resubmissions_left, 0);
+        assert_eq!(
+            history.revisions.len() as u32,
+            crate::review::MAX_RESUBMISSIONS + 1
+        );
+        assert!(history.revisions[0].changed.is_empty());
+        assert_eq!(history.revisions[1].changed, vec!["documents".to_string()]);
+        let reasons: Vec<_> = history
+            .revisions
+            .iter()
+            .map(|revision| revision.decision.as_ref().unwrap().reason.clone().unwrap())
+            .collect();
+        assert_eq!(reasons, vec!["Round 0", "Round 1", "Round 2", "Round 3"]);
+
+        // Out of resubmissions, the creator can still withdraw the proposal
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr,
+            &ExecuteMsg::CancelProposal {
+                proposal_id: "p1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_audit_trail_records_transitions_and_admin_actions() {
+        use crate::audit::{AuditKind, AuditTrailResponse};
+
+        let (mut app, launchpad_addr) = setup_contract();
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::SetProposalReviewRequired { required: true },
+            &[],
+        )
+        .unwrap();
+        setup_funded_proposal(&mut app, &launchpad_addr, &[]);
+        let steps = [
+            (
+                "creator",
+                ExecuteMsg::SubmitProposal {
+                    proposal_id: "p1".to_string(),
+                },
+            ),
+            (
+                "admin",
+                ExecuteMsg::RejectProposal {
+                    proposal_id: "p1".to_string(),
+                    reason: "Missing appraisal".to_string(),
+                },
+            ),
+            (
+                "creator",
+                ExecuteMsg::ResubmitProposal {
+                    proposal_id: "p1".to_string(),
+                    asset_details: None,
+                    documents: None,
+                },
+            ),
+            (
+                "admin",
+                ExecuteMsg::ApproveProposal {
+                    proposal_id: "p1".to_string(),
+                },
+            ),
+            (
+                "admin",
+                ExecuteMsg::SetProposalFeeOverride {
+                    proposal_id: "p1".to_string(),
+                    fee_bps: 100,
+                },
+            ),
+        ];
+        for (sender, msg) in steps {
+            app.execute_contract(addr(sender), launchpad_addr.clone(), &msg, &[])
+                .unwrap();
+        }
+
+        let trail =
+            |proposal_id: Option<&str>, start_after: Option<String>| -> AuditTrailResponse {
+                app.wrap()
+                    .query_wasm_smart(
+                        launchpad_addr.clone(),
+                        &QueryMsg::ProposalAuditTrail {
+                            proposal_id: proposal_id.map(str::to_string),
+                            start_after,
+                            limit: Some(4),
+                        },
+                    )
+                    .unwrap()
+            };
+        let first = trail(Some("p1"), None);
+        assert_eq!(first.entries.len(), 4);
+        let second = trail(Some("p1"), first.next_key.clone());
+        assert_eq!(second.next_key, None);
+        let entries: Vec<_> = first.entries.into_iter().chain(second.entries).collect();
+        let summary: Vec<_> = entries
+            .iter()
+            .map(|entry| (entry.kind.clone(), entry.action.as_str(), entry.to_status))
+            .collect();
+        let transition = |action, status| (AuditKind::StatusTransition, action, Some(status));
+        assert_eq!(
+            summary,
+            vec![
+                transition("create", ProposalStatus::Draft),
+                transition("submit_proposal", ProposalStatus::UnderReview),
+                transition("reject_proposal", ProposalStatus::Draft),
+                transition("resubmit_proposal", ProposalStatus::UnderReview),
+                transition("approve_proposal", ProposalStatus::Active),
+                (AuditKind::AdminAction, "set_proposal_fee_override", None),
+            ]
+        );
+        let rejection = &entries[2];
+        assert_eq!(rejection.actor, addr("admin"));
+        assert_eq!(rejection.from_status, Some(ProposalStatus::UnderReview));
+        assert_eq!(rejection.reason.as_deref(), Some("Missing appraisal"));
+        assert_eq!(rejection.block_height, app.block_info().height);
+
+        let platform = trail(None, None);
+        assert_eq!(platform.entries.len(), 1);
+        assert_eq!(platform.entries[0].kind, AuditKind::ConfigChange);
+        assert_eq!(platform.entries[0].action, "set_review_required");
+    }
+
+    #[test]
+    fn test_documents_are_versioned_instead_of_replaced() {
+        use crate::documents::DocumentHistoryResponse;
+
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_funded_proposal(&mut app, &launchpad_addr, &[]);
+
+        let document = |name: &str, hash: &str| Document {
+            name: name.to_string(),
+            doc_type: "PDF".to_string(),
+            size: "1.0MB".to_string(),
+            hash: Some(hash.to_string()),
+        };
+        let update = |documents: Vec<Document>| ExecuteMsg::UpdateProposal {
+            proposal_id: "p1".to_string(),
+            asset_details: None,
+            documents: Some(documents),
+        };
+
+        // Missing and malformed hashes are rejected
+        for hash in [None, Some("QmBusinessPlan123"), Some("bafy-not-a-cid")] {
+            let mut bad = document("Business Plan", "");
+            bad.hash = hash.map(str::to_string);
+            let err = app
+                .execute_contract(
+                    addr("creator"),
+                    launchpad_addr.clone(),
+                    &update(vec![bad]),
+                    &[],
+                )
+                .unwrap_err();
+            assert!(err.root_cause().to_string().contains("IPFS CID"));
+        }
+
+        // A revised plan becomes version 2; documents left out are kept
+        let revised_plan = document(
+            "Business Plan",
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        );
+        let res = app
+            .execute_contract(
+                addr("creator"),
+                launchpad_addr.clone(),
+                &update(vec![revised_plan.clone()]),
+                &[],
+            )
+            .unwrap();
+        assert!(res.events.iter().any(|e| e
+            .attributes
+            .iter()
+            .any(|a| a.key == "documents_changed" && a.value == "Business Plan")));
+
+        let proposal: crate::msg::ProposalResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::Proposal {
+                    proposal_id: "p1".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(proposal.proposal.documents.len(), 3);
+        assert_eq!(proposal.proposal.documents[0], revised_plan);
+
+        let history = |app: &App, start_after: Option<String>| -> DocumentHistoryResponse {
+            app.wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::DocumentHistory {
+                        proposal_id: "p1".to_string(),
+                        start_after,
+                        limit: Some(2),
+                    },
+                )
+                .unwrap()
+        };
+        let first = history(&app, None);
+        let second = history(&app, first.next_key.clone());
+        assert_eq!(second.next_key, None);
+        let versions: Vec<_> = first.versions.into_iter().chain(second.versions).collect();
+        let summary: Vec<_> = versions
+            .iter()
+            .map(|v| (v.sequence, v.document.name.as_str(), v.version))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (1, "Business Plan", 1),
+                (2, "Financial Projections", 1),
+                (3, "Property Appraisal", 1),
+                (4, "Business Plan", 2),
+            ]
+        );
+        assert_eq!(
+            versions[0].document.hash.as_deref(),
+            Some("QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG")
+        );
+        assert_eq!(versions[3].published_by, addr("creator"));
+
+        // Resending an unchanged document publishes nothing
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &update(vec![revised_plan]),
+            &[],
+        )
+        .unwrap();
+        assert_eq!(history(&app, Some("3".to_string())).versions.len(), 1);
+    }
+
+    #[test]
+    fn test_creator_and_auditor_attest_the_current_document_version() {
+        use crate::documents::{AttestationDoc, AttestorRole, DocumentAttestationsResponse};
+        use k256::ecdsa::{signature::hazmat::PrehashSigner, Signature, SigningKey};
+
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_funded_proposal(&mut app, &launchpad_addr, &[]);
+
+        let keys = [("creator", [3u8; 32]), ("auditor", [5u8; 32])];
+        for (signer, secret) in &keys {
+            let key = SigningKey::from_slice(secret).unwrap();
+            app.execute_contract(
+                addr(signer),
+                launchpad_addr.clone(),
+                &ExecuteMsg::RegisterSigningKey {
+                    pubkey: cosmwasm_std::Binary::from(
+                        key.verifying_key()
+                            .to_encoded_point(true)
+                            .as_bytes()
+                            .to_vec(),
+                    ),
+                },
+                &[],
+            )
+            .unwrap();
+        }
+        let sign = |app: &App, secret: &[u8; 32], sequence: u64| {
+            let version: crate::documents::DocumentHistoryResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::DocumentHistory {
+                        proposal_id: "p1".to_string(),
+                        start_after: Some((sequence - 1).to_string()),
+                        limit: Some(1),
+                    },
+                )
+                .unwrap();
+            let doc = AttestationDoc {
+                chain_id: app.block_info().chain_id,
+                contract_address: launchpad_addr.to_string(),
+                proposal_id: "p1".to_string(),
+                document_name: version.versions[0].document.name.clone(),
+                version: version.versions[0].version,
+                hash: version.versions[0].document.hash.clone().unwrap(),
+            };
+            let signature: Signature = SigningKey::from_slice(secret)
+                .unwrap()
+                .sign_prehash(&doc.digest().unwrap())
+                .unwrap();
+            cosmwasm_std::Binary::from(signature.to_bytes().to_vec())
+        };
+        let attest = |signature| ExecuteMsg::AttestDocument {
+            proposal_id: "p1".to_string(),
+            document_name: "Business Plan".to_string(),
+            signature,
+        };
+
+        // The auditor may only attest once the creator names them
+        let auditor_signature = sign(&app, &keys[1].1, 1);
+        app.execute_contract(
+            addr("auditor"),
+            launchpad_addr.clone(),
+            &attest(auditor_signature.clone()),
+            &[],
+        )
+        .unwrap_err();
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::SetDocumentAuditor {
+                proposal_id: "p1".to_string(),
+                auditor: Some(addr("auditor").to_string()),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("auditor"),
+            launchpad_addr.clone(),
+            &attest(auditor_signature),
+            &[],
+        )
+        .unwrap();
+
+        // A signature from the wrong key, or over another document, does not verify
+        for signature in [sign(&app, &keys[1].1, 1), sign(&app, &keys[0].1, 2)] {
+            let err = app
+                .execute_contract(
+                    addr("creator"),
+                    launchpad_addr.clone(),
+                    &attest(signature),
+                    &[],
+                )
+                .unwrap_err();
+            assert!(err.root_cause().to_string().contains("Invalid signature"));
+        }
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &attest(sign(&app, &keys[0].1, 1)),
+            &[],
+        )
+        .unwrap();
+
+        let attestations = |app: &App, sequence| -> DocumentAttestationsResponse {
+            app.wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::DocumentAttestations {
+                        proposal_id: "p1".to_string(),
+                        sequence,
+                    },
+                )
+                .unwrap()
+        };
+        let endorsed = attestations(&app, 1);
+        assert_eq!(endorsed.auditor, Some(addr("auditor")));
+        assert_eq!(endorsed.version.document.name, "Business Plan");
+        let mut roles: Vec<_> = endorsed
+            .attestations
+            .iter()
+            .map(|attestation| (attestation.attestor.clone(), attestation.role.clone()))
+            .collect();
+        roles.sort_by_key(|(attestor, _)| attestor.to_string());
+        let mut expected = vec![
+            (addr("auditor"), AttestorRole::Auditor),
+            (addr("creator"), AttestorRole::Creator),
+        ];
+        expected.sort_by_key(|(attestor, _)| attestor.to_string());
+        assert_eq!(roles, expected);
+
+        // A new version starts unattested; the old attestations stay with the old version
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::UpdateProposal {
+                proposal_id: "p1".to_string(),
+                asset_details: None,
+                documents: Some(vec![Document {
+                    name: "Business Plan".to_string(),
+                    doc_type: "PDF".to_string(),
+                    size: "3.4MB".to_string(),
+                    hash: Some(
+                        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".to_string(),
+                    ),
+                }]),
+            },
+            &[],
+        )
+        .unwrap();
+        assert!(attestations(&app, 4).attestations.is_empty());
+        assert_eq!(attestations(&app, 1).attestations.len(), 2);
+        let stale = sign(&app, &keys[0].1, 1);
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &attest(stale), &[])
+            .unwrap_err();
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &attest(sign(&app, &keys[0].1, 4)),
+            &[],
+        )
+        .unwrap();
+        assert_eq!(attestations(&app, 4).attestations.len(), 1);
+    }
+
+    #[test]
+    fn test_each_investment_keeps_its_own_receipt() {
+        use crate::receipts::ReceiptsResponse;
+
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_funded_proposal(&mut app, &launchpad_addr, &[("investor1", 1_000_000_000)]);
+        app.update_block(|block| {
+            block.height += 1;
+            block.time = block.time.plus_seconds(60);
+        });
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::Invest {
+                proposal_id: "p1".to_string(),
+                operation_id: None,
+                referrer: None,
+            },
+            &coins(3_000_000_000, "untrn"),
+        )
+        .unwrap();
+        let (asset_details, financial_terms, documents, compliance) =
+            create_small_sample_proposal();
+        app.execute_contract(
+            addr("creator2"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CreateProposal {
+                asset_details,
+                financial_terms,
+                documents,
+                compliance,
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("investor2"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::Invest {
+                proposal_id: "p2".to_string(),
+                operation_id: None,
+                referrer: None,
+            },
+            &coins(2_000_000_000, "untrn"),
+        )
+        .unwrap();
+
+        let receipts = |user: &str, start_after: Option<String>| -> ReceiptsResponse {
+            app.wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::ReceiptsByUser {
+                        user: addr(user).to_string(),
+                        start_after,
+                        limit: Some(1),
+                    },
+                )
+                .unwrap()
+        };
+        let first = receipts("investor1", None);
+        let second = receipts("investor1", first.next_key.clone());
+        assert_eq!(second.next_key, None);
+        let summary: Vec<_> = first
+            .receipts
+            .iter()
+            .chain(&second.receipts)
+            .map(|r| {
+                (
+                    r.receipt_id,
+                    r.amount.u128(),
+                    r.shares,
+                    r.token_price.u128(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (1, 1_000_000_000, 1, 1_000_000_000),
+                (2, 3_000_000_000, 3, 1_000_000_000),
+            ]
+        );
+        let later = &second.receipts[0];
+        assert_eq!(later.proposal_id, "p1");
+        assert_eq!(later.denom, "untrn");
+        assert_eq!(later.block_height, app.block_info().height);
+        assert_eq!(later.timestamp, app.block_info().time.seconds());
+
+        // The position accumulates; the receipts do not
+        let position: crate::msg::InvestmentResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::Investment {
+                    proposal_id: "p1".to_string(),
+                    investor: addr("investor1").to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(position.investment.amount.u128(), 4_000_000_000);
+
+        let other = receipts("investor2", None);
+        assert_eq!(other.receipts.len(), 1);
+        assert_eq!(other.receipts[0].receipt_id, 3);
+        assert_eq!(other.receipts[0].proposal_id, "p2");
+    }
+
+    #[test]
+    fn test_repeat_investments_keep_separate_tax_lots() {
+        use crate::tax_lots::CostBasisResponse;
+
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_funded_proposal(&mut app, &launchpad_addr, &[("investor1", 2_000_000_000)]);
+        let first_purchase = app.block_info().time.seconds();
+        app.update_block(|block| {
+            block.height += 1;
+            block.time = block.time.plus_days(1);
+        });
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::Invest {
+                proposal_id: "p1".to_string(),
+                operation_id: None,
+                referrer: None,
+            },
+            &coins(5_000_000_000, "untrn"),
+        )
+        .unwrap();
+
+        let cost_basis = |app: &App, user: &str| -> CostBasisResponse {
+            app.wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::CostBasis {
+                        proposal_id: "p1".to_string(),
+                        user: addr(user).to_string(),
+                    },
+                )
+                .unwrap()
+        };
+        let basis = cost_basis(&app, "investor1");
+        assert_eq!(basis.status, Some(crate::state::InvestmentStatus::Pending));
+        let lots: Vec<_> = basis
+            .lots
+            .iter()
+            .map(|lot| (lot.receipt_id, lot.acquired_at, lot.shares, lot.cost.u128()))
+            .collect();
+        assert_eq!(
+            lots,
+            vec![
+                (Some(1), first_purchase, 2, 2_000_000_000),
+                (Some(2), first_purchase + 86_400, 5, 5_000_000_000),
+            ]
+        );
+        assert_eq!(basis.total_shares, 7);
+        assert_eq!(basis.total_cost.u128(), 7_000_000_000);
+
+        // Withdrawing the position closes its lots
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::WithdrawInvestment {
+                proposal_id: "p1".to_string(),
+                operation_id: None,
+            },
+            &[],
+        )
+        .unwrap();
+        let basis = cost_basis(&app, "investor1");
+        assert_eq!(basis.status, None);
+        assert!(basis.lots.is_empty());
+    }
+
+    #[test]
+    fn test_reg_cf_issuer_limit_rolls_over_twelve_months() {
+        use crate::compliance::RegCfCapacityResponse;
+
+        let (mut app, launchpad_addr) = setup_contract();
+        let create = |app: &mut App, target: u128| {
+            let (asset_details, mut financial_terms, documents, compliance) =
+                create_sample_proposal();
+            financial_terms.target_amount = Uint128::new(target);
+            financial_terms.funding_deadline = app.block_info().time.seconds() + 60 * 86_400;
+            app.execute_contract(
+                addr("creator"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::CreateProposal {
+                    asset_details,
+                    financial_terms,
+                    documents,
+                    compliance,
+                },
+                &[],
+            )
+        };
+        let capacity = |app: &App| -> RegCfCapacityResponse {
+            app.wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::CreatorRegCfCapacity {
+                        creator: addr("creator").to_string(),
+                    },
+                )
+                .unwrap()
+        };
+
+        // An open raise holds its max raise against the allowance until it settles
+        create(&mut app, 3_000_000_000_000).unwrap();
+        assert_eq!(capacity(&app).remaining.u128(), 2_000_000_000_000);
+        let err = create(&mut app, 3_000_000_000_000).unwrap_err();
+        assert!(err.root_cause().to_string().contains("issuer limit"));
+        create(&mut app, 2_000_000_000_000).unwrap();
+        assert_eq!(capacity(&app).remaining.u128(), 0);
+
+        // Cancelling p2 frees what it held; completing p1 keeps its amount in the window
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CancelProposal {
+                proposal_id: "p2".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::Invest {
+                proposal_id: "p1".to_string(),
+                operation_id: None,
+                referrer: None,
+            },
+            &coins(3_000_000_000_000, "untrn"),
+        )
+        .unwrap();
+        let used = capacity(&app);
+        assert_eq!(used.raised_in_window.u128(), 3_000_000_000_000);
+        assert_eq!(used.remaining.u128(), 2_000_000_000_000);
+
+        // A year after p1 funded its amount drops out of the window
+        app.update_block(|block| block.time = block.time.plus_days(366));
+        assert_eq!(capacity(&app).remaining.u128(), 5_000_000_000_000);
+        create(&mut app, 5_000_000_000_000).unwrap();
+    }
+
+    #[test]
+    fn test_concurrent_raises_settle_within_the_issuer_limit() {
+        use crate::compliance::RegCfCapacityResponse;
+
+        let (mut app, launchpad_addr) = setup_contract();
+        let create = |app: &mut App, target: u128| {
+            let (asset_details, mut financial_terms, documents, compliance) =
+                create_sample_proposal();
+            financial_terms.target_amount = Uint128::new(target);
+            financial_terms.soft_cap = Some(Uint128::new(1_000_000_000_000));
+            app.execute_contract(
+                addr("creator"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::CreateProposal {
+                    asset_details,
+                    financial_terms,
+                    documents,
+                    compliance,
+                },
+                &[],
+            )
+        };
+        let invest = |app: &mut App, proposal_id: &str, amount: u128| {
+            app.execute_contract(
+                addr("investor1"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::Invest {
+                    proposal_id: proposal_id.to_string(),
+                    operation_id: None,
+                    referrer: None,
+                },
+                &coins(amount, "untrn"),
+            )
+            .unwrap();
+        };
+        let status = |app: &App, proposal_id: &str| {
+            let proposal: crate::msg::ProposalResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::Proposal {
+                        proposal_id: proposal_id.to_string(),
+                    },
+                )
+                .unwrap();
+            proposal.proposal.status
+        };
+
+        // Two raises whose targets together pass the limit cannot both be open
+        create(&mut app, 3_000_000_000_000).unwrap();
+        let err = create(&mut app, 3_000_000_000_000).unwrap_err();
+        assert!(err.root_cause().to_string().contains("issuer limit"));
+        create(&mut app, 2_000_000_000_000).unwrap();
+        invest(&mut app, "p1", 1_500_000_000_000);
+        invest(&mut app, "p2", 500_000_000_000);
+
+        // At the deadline p1 funds with what it raised and p2, short of its soft cap, fails;
+        // the batch settles both and p2 hands back what it held
+        app.update_block(|block| block.time = block.time.plus_days(61));
+        app.execute_contract(
+            addr("investor2"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::ProcessExpiredProposals {
+                start_after: None,
+                limit: None,
+            },
+            &[],
+        )
+        .unwrap();
+        assert_eq!(status(&app, "p1"), ProposalStatus::Funded);
+        assert_eq!(status(&app, "p2"), ProposalStatus::Failed);
+
+        let capacity: RegCfCapacityResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::CreatorRegCfCapacity {
+                    creator: addr("creator").to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(capacity.raised_in_window.u128(), 1_500_000_000_000);
+        assert_eq!(capacity.remaining.u128(), 3_500_000_000_000);
+    }
+
+    #[test]
+    fn test_investor_annual_limit_spans_proposals() {
+        use crate::investor_limits::{InvestorLimitResponse, LimitSource};
+
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_funded_proposal(&mut app, &launchpad_addr, &[]);
+        let invest = |app: &mut App, investor: &str, amount: u128| {
+            app.execute_contract(
+                addr(investor),
+                launchpad_addr.clone(),
+                &ExecuteMsg::Invest {
+                    proposal_id: "p1".to_string(),
+                    operation_id: None,
+                    referrer: None,
+                },
+                &coins(amount, "untrn"),
+            )
+        };
+        let limit = |app: &App, investor: &str| -> InvestorLimitResponse {
+            app.wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::InvestorRegCfLimit {
+                        investor: addr(investor).to_string(),
+                    },
+                )
+                .unwrap()
+        };
+
+        // No profile, no cap
+        assert_eq!(limit(&app, "investor1").annual_limit, None);
+
+        // Income $30k and net worth $20k allow $2,500 a year
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::DeclareInvestorLimits {
+                annual_income: Uint128::new(30_000_000_000),
+                net_worth: Uint128::new(20_000_000_000),
+            },
+            &[],
+        )
+        .unwrap();
+        invest(&mut app, "investor1", 2_000_000_000).unwrap();
+        let err = invest(&mut app, "investor1", 1_000_000_000).unwrap_err();
+        assert!(err
+            .root_cause()
+            .to_string()
+            .contains("annual investor limit"));
+        let status = limit(&app, "investor1");
+        assert_eq!(status.annual_limit, Some(Uint128::new(2_500_000_000)));
+        assert_eq!(status.invested_in_window.u128(), 2_000_000_000);
+        assert_eq!(status.remaining, Some(Uint128::new(500_000_000)));
+
+        // Verified records from the registry replace the declaration and lock it
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::SetInvestorLimits {
+                investor: addr("investor1").to_string(),
+                annual_income: Uint128::new(200_000_000_000),
+                net_worth: Uint128::new(900_000_000_000),
+            },
+            &[],
+        )
+        .unwrap();
+        let err = app
+            .execute_contract(
+                addr("investor1"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::DeclareInvestorLimits {
+                    annual_income: Uint128::new(30_000_000_000),
+                    net_worth: Uint128::new(20_000_000_000),
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert!(err.root_cause().to_string().contains("registry"));
+        let status = limit(&app, "investor1");
+        assert!(matches!(
+            status.profile.unwrap().source,
+            LimitSource::Registry { .. }
+        ));
+        assert_eq!(status.annual_limit, Some(Uint128::new(90_000_000_000)));
+        invest(&mut app, "investor1", 1_000_000_000).unwrap();
+
+        // Accredited investors are not capped whatever they declared
+        app.execute_contract(
+            addr("investor2"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::DeclareInvestorLimits {
+                annual_income: Uint128::new(30_000_000_000),
+                net_worth: Uint128::new(20_000_000_000),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::AddVerifiedInvestor {
+                investor: addr("investor2").to_string(),
+                tier: crate::kyc::VerificationTier::Accredited,
+                expires_at: None,
+            },
+            &[],
+        )
+        .unwrap();
+        invest(&mut app, "investor2", 10_000_000_000).unwrap();
+        let status = limit(&app, "investor2");
+        assert!(status.accredited);
+        assert_eq!(status.annual_limit, None);
+        assert_eq!(status.invested_in_window.u128(), 10_000_000_000);
+    }
+
+    #[test]
+    fn test_withdrawal_frees_the_investor_annual_limit() {
+        use crate::investor_limits::InvestorLimitResponse;
+
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_funded_proposal(&mut app, &launchpad_addr, &[]);
+        let invest = |app: &mut App, amount: u128| {
+            app.execute_contract(
+                addr("investor1"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::Invest {
+                    proposal_id: "p1".to_string(),
+                    operation_id: None,
+                    referrer: None,
+                },
+                &coins(amount, "untrn"),
+            )
+        };
+        let limit = |app: &App| -> InvestorLimitResponse {
+            app.wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::InvestorRegCfLimit {
+                        investor: addr("investor1").to_string(),
+                    },
+                )
+                .unwrap()
+        };
+
+        // $2,500 a year, invested over two days
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::DeclareInvestorLimits {
+                annual_income: Uint128::new(30_000_000_000),
+                net_worth: Uint128::new(20_000_000_000),
+            },
+            &[],
+        )
+        .unwrap();
+        invest(&mut app, 1_000_000_000).unwrap();
+        app.update_block(|block| block.time = block.time.plus_days(1));
+        invest(&mut app, 1_000_000_000).unwrap();
+        assert_eq!(limit(&app).remaining, Some(Uint128::new(500_000_000)));
+
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::WithdrawInvestment {
+                proposal_id: "p1".to_string(),
+                operation_id: None,
+            },
+            &[],
+        )
+        .unwrap();
+        let status = limit(&app);
+        assert_eq!(status.invested_in_window, Uint128::zero());
+        assert_eq!(status.remaining, Some(Uint128::new(2_500_000_000)));
+        invest(&mut app, 2_500_000_000).unwrap();
+    }
+
+    #[test]
+    fn test_compliance_report_pages_and_csv_export() {
+        use crate::compliance::{InvestmentCompliancePage, ProposalCompliancePage, ReportFormat};
+
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_funded_proposal(
+            &mut app,
+            &launchpad_addr,
+            &[
+                ("investor1", 1_000_000_000),
+                ("investor2", 2_000_000_000),
+                ("investor3", 3_000_000_000),
+            ],
+        );
+        let (mut asset_details, financial_terms, documents, compliance) = create_sample_proposal();
+        asset_details.name = "Tacoma Warehouse, Unit \"B\"".to_string();
+        app.execute_contract(
+            addr("creator2"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CreateProposal {
+                asset_details,
+                financial_terms,
+                documents,
+                compliance,
+            },
+            &[],
+        )
+        .unwrap();
+
+        // Proposal rows leave out the investment lists
+        let page: ProposalCompliancePage = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::ComplianceReportPage {
+                    start_after: None,
+                    limit: Some(1),
+                    format: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(page.rows.len(), 1);
+        assert_eq!(page.rows[0].proposal_id, "p1");
+        assert_eq!(page.rows[0].investor_count, 3);
+        assert!(page.rows[0].compliance_status.is_compliant);
+        assert_eq!(page.next_key, Some("p1".to_string()));
+
+        let page: ProposalCompliancePage = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::ComplianceReportPage {
+                    start_after: page.next_key,
+                    limit: Some(1),
+                    format: Some(ReportFormat::Csv),
+                },
+            )
+            .unwrap();
+        assert!(page.rows.is_empty());
+        assert!(page
+            .header
+            .unwrap()
+            .starts_with("proposal_id,proposal_title,"));
+        assert_eq!(page.lines.len(), 1);
+        assert!(page.lines[0].starts_with("p2,\"Tacoma Warehouse, Unit \"\"B\"\"\","));
+        assert_eq!(page.next_key, None);
+
+        // Investments come in chunks keyed by investor address
+        let investments = |app: &App, start_after: Option<String>| -> InvestmentCompliancePage {
+            app.wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::ComplianceInvestmentsPage {
+                        proposal_id: "p1".to_string(),
+                        start_after,
+                        limit: Some(2),
+                        format: Some(ReportFormat::Csv),
+                    },
+                )
+                .unwrap()
+        };
+        let first = investments(&app, None);
+        assert_eq!(first.lines.len(), 2);
+        let second = investments(&app, first.next_key.clone());
+        assert_eq!(second.lines.len(), 1);
+        assert_eq!(second.next_key, None);
+        let total: u128 = first
+            .lines
+            .iter()
+            .chain(&second.lines)
+            .map(|line| line.split(',').nth(1).unwrap().parse::<u128>().unwrap())
+            .sum();
+        assert_eq!(total, 6_000_000_000);
+    }
+
+    #[test]
+    fn test_governance_votes_trigger_launchpad_actions() {
+        use crate::governance::{
+            GovernanceAction, GovernanceProposal, GovernanceProposalStatus, PropertyManager,
+            VoteOption,
+        };
+        use crate::milestones::Milestone;
+
+        let (mut app, launchpad_addr) = setup_contract();
+        let (asset_details, mut financial_terms, documents, compliance) = create_sample_proposal();
+        financial_terms.milestones = vec![
+            Milestone {
+                description: "Acquisition closes".to_string(),
+                payout_bps: 6_000,
+            },
+            Milestone {
+                description: "Renovation complete".to_string(),
+                payout_bps: 4_000,
+            },
+        ];
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CreateProposal {
+                asset_details,
+                financial_terms,
+                documents,
+                compliance,
+            },
+            &[],
+        )
+        .unwrap();
+        for (investor, amount) in [
+            ("investor1", 3_000_000_000_000u128),
+            ("investor2", 2_000_000_000_000u128),
+        ] {
+            app.execute_contract(
+                addr(investor),
+                launchpad_addr.clone(),
+                &ExecuteMsg::Invest {
+                    proposal_id: "p1".to_string(),
+                    operation_id: None,
+                    referrer: None,
+                },
+                &coins(amount, "untrn"),
+            )
+            .unwrap();
+        }
+        for msg in [
+            ExecuteMsg::MintTokens {
+                proposal_id: "p1".to_string(),
+            },
+            ExecuteMsg::DistributeTokens {
+                proposal_id: "p1".to_string(),
+            },
+            ExecuteMsg::FinalizeDistribution {
+                proposal_id: "p1".to_string(),
+            },
+            ExecuteMsg::SetPropertyManager {
+                proposal_id: "p1".to_string(),
+                manager: addr("manager1").to_string(),
+            },
+        ] {
+            app.execute_contract(addr("creator"), launchpad_addr.clone(), &msg, &[])
+                .unwrap();
+        }
+
+        // Only a vote can replace the manager the creator named
+        let err = app
+            .execute_contract(
+                addr("creator"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::SetPropertyManager {
+                    proposal_id: "p1".to_string(),
+                    manager: addr("manager2").to_string(),
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert!(err.root_cause().to_string().contains("holder vote"));
+
+        let propose = |app: &mut App, action: GovernanceAction| {
+            app.execute_contract(
+                addr("investor1"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::CreateGovernanceProposal {
+                    proposal_id: "p1".to_string(),
+                    title: "Asset decision".to_string(),
+                    description: "Holder vote".to_string(),
+                    action,
+                },
+                &[],
+            )
+        };
+
+        // Actions that cannot apply to the asset are refused before anyone votes
+        let err = propose(
+            &mut app,
+            GovernanceAction::ReleaseMilestone { milestone_index: 2 },
+        )
+        .unwrap_err();
+        assert!(err.root_cause().to_string().contains("Milestone not found"));
+        propose(
+            &mut app,
+            GovernanceAction::ExtendLockup {
+                additional_seconds: 0,
+            },
+        )
+        .unwrap_err();
+
+        propose(
+            &mut app,
+            GovernanceAction::ReleaseMilestone { milestone_index: 0 },
+        )
+        .unwrap();
+        propose(
+            &mut app,
+            GovernanceAction::ExtendLockup {
+                additional_seconds: 30 * 24 * 60 * 60,
+            },
+        )
+        .unwrap();
+        propose(
+            &mut app,
+            GovernanceAction::ReplacePropertyManager {
+                manager: addr("manager2").to_string(),
+            },
+        )
+        .unwrap();
+
+        // investor1 holds 60% of the shares
+        for governance_id in 1..=3 {
+            app.execute_contract(
+                addr("investor1"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::CastVote {
+                    governance_id,
+                    vote: VoteOption::Yes,
+                },
+                &[],
+            )
+            .unwrap();
+        }
+
+        let lockup_end = |app: &App| {
+            let res: crate::msg::ProposalResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::Proposal {
+                        proposal_id: "p1".to_string(),
+                    },
+                )
+                .unwrap();
+            res.proposal.timestamps.lockup_end.unwrap()
+        };
+        let lockup_before = lockup_end(&app);
+        let creator_start = app
+            .wrap()
+            .query_balance(addr("creator"), "untrn")
+            .unwrap()
+            .amount;
+
+        app.update_block(|block| block.time = block.time.plus_days(8));
+        for governance_id in 1..=3 {
+            app.execute_contract(
+                addr("investor3"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::ExecuteGovernanceProposal { governance_id },
+                &[],
+            )
+            .unwrap();
+        }
+
+        let creator_balance = app
+            .wrap()
+            .query_balance(addr("creator"), "untrn")
+            .unwrap()
+            .amount;
+        assert_eq!(
+            creator_balance - creator_start,
+            Uint128::new(2_925_000_000_000)
+        );
+        assert_eq!(lockup_end(&app), lockup_before + 30 * 24 * 60 * 60);
+        let manager: Option<PropertyManager> = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::PropertyManager {
+                    proposal_id: "p1".to_string(),
+                },
+            )
+            .unwrap();
+        let manager = manager.unwrap();
+        assert_eq!(manager.manager, addr("manager2"));
+        assert_eq!(manager.governance_id, Some(3));
+
+        // The released tranche cannot be voted on again
+        let err = propose(
+            &mut app,
+            GovernanceAction::ReleaseMilestone { milestone_index: 0 },
+        )
+        .unwrap_err();
+        assert!(err.root_cause().to_string().contains("already released"));
+
+        // A passed action that cannot run closes the vote as failed instead of leaving it open
+        propose(
+            &mut app,
+            GovernanceAction::ReleaseMilestone { milestone_index: 1 },
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CastVote {
+                governance_id: 4,
+                vote: VoteOption::Yes,
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::FreezeProposal {
+                proposal_id: "p1".to_string(),
+                reason: "Inspection pending".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        app.update_block(|block| block.time = block.time.plus_days(8));
+        let execute = ExecuteMsg::ExecuteGovernanceProposal { governance_id: 4 };
+        app.execute_contract(addr("investor3"), launchpad_addr.clone(), &execute, &[])
+            .unwrap();
+        let failed: GovernanceProposal = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::GovernanceProposal { governance_id: 4 },
+            )
+            .unwrap();
+        assert_eq!(failed.status, GovernanceProposalStatus::Failed);
+        assert!(failed.execution_error.unwrap().contains("frozen"));
+        assert_eq!(
+            app.wrap()
+                .query_balance(addr("creator"), "untrn")
+                .unwrap()
+                .amount,
+            creator_balance
+        );
+        app.execute_contract(addr("investor3"), launchpad_addr.clone(), &execute, &[])
+            .unwrap_err();
+
+        // The tranche was not touched, so holders can vote on it again
+        propose(
+            &mut app,
+            GovernanceAction::ReleaseMilestone { milestone_index: 1 },
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_governance_contract_notified_with_retryable_hooks() {
+        use crate::governance_hooks::FailedGovernanceHooksResponse;
+        use cf1_types::GovernanceHookMsg;
+
+        let (mut app, launchpad_addr) = setup_contract();
+        let mut instantiate_governance = |reject: bool| {
+            let code_id = app.store_code(contract_governance(reject));
+            app.instantiate_contract(
+                code_id,
+                addr("admin"),
+                &cosmwasm_std::Empty {},
+                &[],
+                "governance",
+                None,
+            )
+            .unwrap()
+        };
+        let broken = instantiate_governance(true);
+        let governance = instantiate_governance(false);
+        let register = |app: &mut App, contract: &Addr| {
+            app.execute_contract(
+                addr("admin"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::SetGovernanceContract {
+                    address: Some(contract.to_string()),
+                },
+                &[],
+            )
+            .unwrap();
+        };
+        let received = |app: &App| -> Vec<GovernanceHookMsg> {
+            app.wrap()
+                .query_wasm_smart(governance.clone(), &cosmwasm_std::Empty {})
+                .unwrap()
+        };
+        let failed = |app: &App| -> FailedGovernanceHooksResponse {
+            app.wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::FailedGovernanceHooks {
+                        start_after: None,
+                        limit: None,
+                    },
+                )
+                .unwrap()
+        };
+
+        // A failing governance contract does not stop the raise from closing
+        register(&mut app, &broken);
+        setup_funded_proposal(
+            &mut app,
+            &launchpad_addr,
+            &[("investor1", 5_000_000_000_000)],
+        );
+        let pending = failed(&app).hooks;
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].attempts, 1);
+        assert!(pending[0].error.contains("unavailable"));
+        let key = pending[0].hook.idempotency_key().to_string();
+        assert!(matches!(
+            pending[0].hook,
+            GovernanceHookMsg::ProposalFunded { .. }
+        ));
+
+        // Retrying after the registration is fixed delivers it with the same key
+        register(&mut app, &governance);
+        app.execute_contract(
+            addr("investor3"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::RetryGovernanceHook {
+                idempotency_key: key.clone(),
+            },
+            &[],
+        )
+        .unwrap();
+        assert!(failed(&app).hooks.is_empty());
+        assert_eq!(received(&app)[0].idempotency_key(), key);
+
+        for msg in [
+            ExecuteMsg::MintTokens {
+                proposal_id: "p1".to_string(),
+            },
+            ExecuteMsg::DistributeTokens {
+                proposal_id: "p1".to_string(),
+            },
+            ExecuteMsg::FinalizeDistribution {
+                proposal_id: "p1".to_string(),
+            },
+        ] {
+            app.execute_contract(addr("creator"), launchpad_addr.clone(), &msg, &[])
+                .unwrap();
+        }
+        assert!(matches!(
+            &received(&app)[1],
+            GovernanceHookMsg::TokensDistributed { proposal_id, total_supply, .. }
+                if proposal_id == "p1" && total_supply.u128() == 5_000
+        ));
+
+        // Lockup expiry is announced once, however often the scan runs
+        app.update_block(|block| block.time = block.time.plus_days(366));
+        for _ in 0..2 {
+            app.execute_contract(
+                addr("investor3"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::ProcessExpiredLockups {
+                    start_after: None,
+                    limit: None,
+                },
+                &[],
+            )
+            .unwrap();
+        }
+        let received = received(&app);
+        assert_eq!(received.len(), 3);
+        assert!(matches!(
+            &received[2],
+            GovernanceHookMsg::LockupExpired { proposal_id, .. } if proposal_id == "p1"
+        ));
+    }
+
+    #[test]
+    fn test_notification_events_carry_versioned_schema() {
+        use cw_multi_test::AppResponse;
+
+        // Attributes of the one notification event of this type, without the contract address
+        fn notification(res: &AppResponse, ty: &str) -> Vec<(String, String)> {
+            let events: Vec<_> = res
+                .events
+                .iter()
+                .filter(|e| e.ty == format!("wasm-cf1.{}.v1", ty))
+                .collect();
+            assert_eq!(events.len(), 1, "{}", ty);
+            events[0]
+                .attributes
+                .iter()
+                .filter(|attr| attr.key != "_contract_address")
+                .map(|attr| (attr.key.clone(), attr.value.clone()))
+                .collect()
+        }
+        fn expected(fields: &[(&str, &str)]) -> Vec<(String, String)> {
+            fields
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect()
+        }
+
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_funded_proposal(
+            &mut app,
+            &launchpad_addr,
+            &[("investor1", 4_000_000_000_000)],
+        );
+        let invest = |proposal_id: &str| ExecuteMsg::Invest {
+            proposal_id: proposal_id.to_string(),
+            operation_id: None,
+            referrer: None,
+        };
+
+        // The investment that completes the raise announces it
+        let res = app
+            .execute_contract(
+                addr("investor2"),
+                launchpad_addr.clone(),
+                &invest("p1"),
+                &coins(1_000_000_000_000, "untrn"),
+            )
+            .unwrap();
+        let lockup_end = (app.block_info().time.seconds() + 365 * 24 * 60 * 60).to_string();
+        assert_eq!(
+            notification(&res, "funding_complete"),
+            expected(&[
+                ("action", "funding_complete"),
+                ("schema_version", "1"),
+                ("proposal_id", "p1"),
+                ("creator", addr("creator").as_str()),
+                ("raised_amount", "5000000000000"),
+                ("investor_count", "2"),
+                ("lockup_end", &lockup_end),
+            ])
+        );
+
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::MintTokens {
+                proposal_id: "p1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        let res = app
+            .execute_contract(
+                addr("creator"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::DistributeTokens {
+                    proposal_id: "p1".to_string(),
+                },
+                &[],
+            )
+            .unwrap();
+        let attributes = notification(&res, "tokens_distributed");
+        assert_eq!(attributes[2], ("proposal_id".to_string(), "p1".to_string()));
+        assert_eq!(attributes[3].0, "token_contract");
+        assert_eq!(
+            attributes[4..],
+            expected(&[("total_shares", "5000"), ("investor_count", "2")])[..]
+        );
+
+        // Cancelling a raise announces a refund to each investor
+        let (asset_details, financial_terms, documents, compliance) = create_sample_proposal();
+        app.execute_contract(
+            addr("creator2"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CreateProposal {
+                asset_details,
+                financial_terms,
+                documents,
+                compliance,
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("investor3"),
+            launchpad_addr.clone(),
+            &invest("p2"),
+            &coins(1_000_000_000, "untrn"),
+        )
+        .unwrap();
+        let res = app
+            .execute_contract(
+                addr("creator2"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::CancelProposal {
+                    proposal_id: "p2".to_string(),
+                },
+                &[],
+            )
+            .unwrap();
+        assert_eq!(
+            notification(&res, "refund_issued"),
+            expected(&[
+                ("action", "refund_issued"),
+                ("schema_version", "1"),
+                ("proposal_id", "p2"),
+                ("investor", addr("investor3").as_str()),
+                ("amount", "1000000000"),
+                ("reason", "proposal_cancelled"),
+            ])
+        );
+
+        // Lockup expiry is announced by the first scan after it passes, and only once
+        app.update_block(|block| block.time = block.time.plus_days(366));
+        let scan = ExecuteMsg::ProcessExpiredLockups {
+            start_after: None,
+            limit: None,
+        };
+        let res = app
+            .execute_contract(addr("investor3"), launchpad_addr.clone(), &scan, &[])
+            .unwrap();
+        assert_eq!(
+            notification(&res, "lockup_expired"),
+            expected(&[
+                ("action", "lockup_expired"),
+                ("schema_version", "1"),
+                ("proposal_id", "p1"),
+                ("lockup_end", &lockup_end),
+            ])
+        );
+        let res = app
+            .execute_contract(addr("investor3"), launchpad_addr.clone(), &scan, &[])
+            .unwrap();
+        assert!(!res
+            .events
+            .iter()
+            .any(|e| e.ty == "wasm-cf1.lockup_expired.v1"));
+    }
+
+    #[test]
+    fn test_proposals_expiring_within_follow_deadline_index() {
+        const DAY: u64 = 24 * 60 * 60;
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_funded_proposal(&mut app, &launchpad_addr, &[]);
+        let now = app.block_info().time.seconds();
+        let (asset_details, mut financial_terms, documents, compliance) = create_sample_proposal();
+        financial_terms.funding_deadline = now + 30 * DAY;
+        app.execute_contract(
+            addr("creator2"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CreateProposal {
+                asset_details,
+                financial_terms,
+                documents,
+                compliance,
+            },
+            &[],
+        )
+        .unwrap();
+        let expiring = |app: &App, days: u64, limit: Option<u32>| {
+            let res: crate::msg::ProposalsResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::ProposalsExpiringWithin {
+                        seconds: days * DAY,
+                        start_after: None,
+                        limit,
+                    },
+                )
+                .unwrap();
+            let ids: Vec<_> = res.proposals.into_iter().map(|p| p.proposal.id).collect();
+            (ids, res.total_count)
+        };
+
+        // Soonest deadline first, counted past the limit
+        assert_eq!(expiring(&app, 29, None), (vec![], 0));
+        assert_eq!(expiring(&app, 30, None), (vec!["p2".to_string()], 1));
+        assert_eq!(
+            expiring(&app, 60, None),
+            (vec!["p2".to_string(), "p1".to_string()], 2)
+        );
+        assert_eq!(expiring(&app, 60, Some(1)), (vec!["p2".to_string()], 2));
+
+        // The last proposal on a page is the cursor for the next
+        let page = |app: &App, start_after: Option<String>| -> crate::msg::ProposalsResponse {
+            app.wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::ProposalsExpiringWithin {
+                        seconds: 60 * DAY,
+                        start_after,
+                        limit: Some(1),
+                    },
+                )
+                .unwrap()
+        };
+        let first = page(&app, None);
+        assert_eq!(first.next_key.as_deref(), Some("p2"));
+        let second = page(&app, first.next_key);
+        assert_eq!(second.proposals[0].proposal.id, "p1");
+        assert_eq!(second.total_count, 2);
+        assert_eq!(second.next_key, None);
+
+        // An extended deadline moves the proposal in the index
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::ExtendFundingDeadline {
+                proposal_id: "p1".to_string(),
+                new_deadline: now + 90 * DAY,
+            },
+            &[],
+        )
+        .unwrap();
+        assert_eq!(expiring(&app, 60, None), (vec!["p2".to_string()], 1));
+
+        // A proposal that is no longer active leaves it
+        app.execute_contract(
+            addr("creator2"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CancelProposal {
+                proposal_id: "p2".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        assert_eq!(expiring(&app, 120, None), (vec!["p1".to_string()], 1));
+
+        // Deadlines already passed are not "expiring"
+        app.update_block(|block| block.time = block.time.plus_seconds(91 * DAY));
+        assert_eq!(expiring(&app, 120, None), (vec![], 0));
+    }
+
+    #[test]
+    fn test_trending_proposals_rank_recent_investment() {
+        use crate::trending::TrendingProposalsResponse;
+
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_funded_proposal(&mut app, &launchpad_addr, &[("investor1", 3_000_000_000)]);
+        let (asset_details, financial_terms, documents, compliance) = create_sample_proposal();
+        app.execute_contract(
+            addr("creator2"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CreateProposal {
+                asset_details,
+                financial_terms,
+                documents,
+                compliance,
+            },
+            &[],
+        )
+        .unwrap();
+        let invest = |app: &mut App, investor: &str, proposal_id: &str, amount: u128| {
+            app.execute_contract(
+                addr(investor),
+                launchpad_addr.clone(),
+                &ExecuteMsg::Invest {
+                    proposal_id: proposal_id.to_string(),
+                    operation_id: None,
+                    referrer: None,
+                },
+                &coins(amount, "untrn"),
+            )
+            .unwrap();
+        };
+        let trending = |app: &App, limit: Option<u32>| -> Vec<(String, u32, u128)> {
+            let res: TrendingProposalsResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::TrendingProposals { limit },
+                )
+                .unwrap();
+            res.proposals
+                .into_iter()
+                .map(|p| (p.proposal_id, p.investment_count, p.amount.u128()))
+                .collect()
+        };
+
+        invest(&mut app, "investor2", "p2", 1_000_000_000);
+        invest(&mut app, "investor3", "p2", 1_000_000_000);
+        assert_eq!(
+            trending(&app, None),
+            vec![
+                ("p1".to_string(), 1, 3_000_000_000),
+                ("p2".to_string(), 2, 2_000_000_000),
+            ]
+        );
+        assert_eq!(trending(&app, Some(1)).len(), 1);
+
+        // Later investments overtake
+        app.update_block(|block| block.time = block.time.plus_hours(12));
+        invest(&mut app, "investor1", "p2", 2_000_000_000);
+        assert_eq!(
+            trending(&app, None)[0],
+            ("p2".to_string(), 3, 4_000_000_000)
+        );
+
+        // Investments older than a day drop out
+        app.update_block(|block| block.time = block.time.plus_hours(13));
+        assert_eq!(
+            trending(&app, None),
+            vec![("p2".to_string(), 1, 2_000_000_000)]
+        );
+
+        // A raise that closes is no longer trending, however fast it filled
+        invest(&mut app, "investor2", "p1", 4_997_000_000_000);
+        assert_eq!(
+            trending(&app, None),
+            vec![("p2".to_string(), 1, 2_000_000_000)]
+        );
+    }
+
+    #[test]
+    fn test_browse_proposals_by_category_and_asset_type() {
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_funded_proposal(&mut app, &launchpad_addr, &[]);
+        for (category, asset_type) in [("Art", "Painting"), (" real estate ", "Residential")] {
+            app.update_block(|block| block.time = block.time.plus_seconds(60));
+            let (mut asset_details, financial_terms, documents, compliance) =
+                create_small_sample_proposal();
+            asset_details.category = category.to_string();
+            asset_details.asset_type = asset_type.to_string();
+            app.execute_contract(
+                addr("creator2"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::CreateProposal {
+                    asset_details,
+                    financial_terms,
+                    documents,
+                    compliance,
+                },
+                &[],
+            )
+            .unwrap();
+        }
+        let browse = |app: &App, msg: QueryMsg| {
+            let res: crate::msg::ProposalsResponse = app
+                .wrap()
+                .query_wasm_smart(launchpad_addr.clone(), &msg)
+                .unwrap();
+            let ids: Vec<_> = res.proposals.into_iter().map(|p| p.proposal.id).collect();
+            (ids, res.total_count, res.next_key)
+        };
+        let by_category = |category: &str, start_after: Option<&str>, limit: Option<u32>| {
+            QueryMsg::ProposalsByCategory {
+                category: category.to_string(),
+                start_after: start_after.map(str::to_string),
+                limit,
+            }
+        };
+
+        // Newest first, ignoring case and surrounding spaces, one page at a time
+        assert_eq!(
+            browse(&app, by_category("REAL ESTATE", None, Some(1))),
+            (vec!["p3".to_string()], 2, Some("p3".to_string()))
+        );
+        assert_eq!(
+            browse(&app, by_category("Real Estate", Some("p3"), Some(1))),
+            (vec!["p1".to_string()], 2, None)
+        );
+        assert_eq!(
+            browse(
+                &app,
+                QueryMsg::ProposalsByAssetType {
+                    asset_type: "commercial real estate".to_string(),
+                    start_after: None,
+                    limit: None,
+                },
+            ),
+            (vec!["p1".to_string()], 1, None)
+        );
+
+        // Changing the category moves the proposal to the new listing
+        let (mut asset_details, ..) = create_sample_proposal();
+        asset_details.category = "art".to_string();
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::UpdateProposal {
+                proposal_id: "p1".to_string(),
+                asset_details: Some(asset_details),
+                documents: None,
+            },
+            &[],
+        )
+        .unwrap();
+        assert_eq!(
+            browse(&app, by_category("Art", None, None)),
+            (vec!["p2".to_string(), "p1".to_string()], 2, None)
+        );
+        assert_eq!(
+            browse(&app, by_category("Real Estate", None, None)),
+            (vec!["p3".to_string()], 1, None)
+        );
+    }
+
+    #[test]
+    fn test_proposals_by_target_and_apy_range() {
+        const MILLION: u128 = 1_000_000_000_000;
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_funded_proposal(&mut app, &launchpad_addr, &[]);
+        // Split across creators so each stays within the Reg CF issuer limit
+        for (creator, millions, apy_bps) in [
+            ("creator2", 2, 800),
+            ("creator2", 1, 750),
+            ("creator3", 3, 0),
+        ] {
+            let (mut asset_details, mut financial_terms, documents, compliance) =
+                create_sample_proposal();
+            asset_details.asset_type = "Income Property".to_string();
+            financial_terms.target_amount = Uint128::new(millions * MILLION);
+            financial_terms.total_shares = millions as u64 * 1_000;
+            financial_terms.expected_apy_bps = apy_bps;
+            app.execute_contract(
+                addr(creator),
+                launchpad_addr.clone(),
+                &ExecuteMsg::CreateProposal {
+                    asset_details,
+                    financial_terms,
+                    documents,
+                    compliance,
+                },
+                &[],
+            )
+            .unwrap();
+        }
+        let query = |app: &App,
+                     target: (Option<u128>, Option<u128>),
+                     min_apy_bps: Option<u16>,
+                     asset_type: Option<&str>,
+                     start_after: Option<&str>,
+                     limit: Option<u32>| {
+            let res: crate::msg::ProposalsResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::ProposalsByTerms {
+                        min_target: target.0.map(Uint128::new),
+                        max_target: target.1.map(Uint128::new),
+                        min_apy_bps,
+                        asset_type: asset_type.map(str::to_string),
+                        start_after: start_after.map(str::to_string),
+                        limit,
+                    },
+                )
+                .unwrap();
+            let ids: Vec<_> = res.proposals.into_iter().map(|p| p.proposal.id).collect();
+            (ids, res.total_count, res.next_key)
+        };
+        let ids = |ids: &[&str]| ids.iter().map(|id| id.to_string()).collect::<Vec<_>>();
+
+        // Income assets between $1M and $10M yielding at least 8%
+        assert_eq!(
+            query(
+                &app,
+                (Some(MILLION), Some(10 * MILLION)),
+                Some(800),
+                Some("income property"),
+                None,
+                None
+            ),
+            (ids(&["p2"]), 1, None)
+        );
+        // Target bounds are inclusive and order by target
+        assert_eq!(
+            query(&app, (Some(2 * MILLION), None), None, None, None, None),
+            (ids(&["p2", "p4", "p1"]), 3, None)
+        );
+        assert_eq!(
+            query(&app, (None, Some(2 * MILLION)), None, None, None, None),
+            (ids(&["p3", "p2"]), 2, None)
+        );
+        // With only an APY floor the results are ordered by APY, a page at a time
+        assert_eq!(
+            query(&app, (None, None), Some(750), None, None, Some(2)),
+            (ids(&["p3", "p2"]), 3, Some("p2".to_string()))
+        );
+        assert_eq!(
+            query(&app, (None, None), Some(750), None, Some("p2"), Some(2)),
+            (ids(&["p1"]), 3, None)
+        );
+        // Asset type is matched from the index, page by page
+        let income = Some("Income Property");
+        assert_eq!(
+            query(&app, (Some(MILLION), None), None, income, None, Some(2)),
+            (ids(&["p3", "p2"]), 3, Some("p2".to_string()))
+        );
+        assert_eq!(
+            query(
+                &app,
+                (Some(MILLION), None),
+                None,
+                income,
+                Some("p2"),
+                Some(2)
+            ),
+            (ids(&["p4"]), 3, None)
+        );
+
+        // Changing a proposal's asset type moves it out of the filter
+        let (mut asset_details, ..) = create_sample_proposal();
+        asset_details.asset_type = "Office".to_string();
+        app.execute_contract(
+            addr("creator3"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::UpdateProposal {
+                proposal_id: "p4".to_string(),
+                asset_details: Some(asset_details),
+                documents: None,
+            },
+            &[],
+        )
+        .unwrap();
+        assert_eq!(
+            query(&app, (Some(MILLION), None), None, income, None, None),
+            (ids(&["p3", "p2"]), 2, None)
+        );
+    }
+
+    #[test]
+    fn test_dashboard_data_in_one_query() {
+        use crate::msg::DashboardResponse;
+
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_funded_proposal(
+            &mut app,
+            &launchpad_addr,
+            &[("investor1", 2_000_000_000), ("investor1", 1_000_000_000)],
+        );
+        let dashboard = |user: Option<&str>| -> DashboardResponse {
+            app.wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::DashboardData {
+                        user: user.map(|user| addr(user).to_string()),
+                    },
+                )
+                .unwrap()
+        };
+
+        let res = dashboard(Some("investor1"));
+        assert_eq!(res.config.admin, addr("admin"));
+        assert_eq!(res.platform_stats.total_proposals, 1);
+        assert_eq!(res.platform_stats.total_investors, 1);
+        assert_eq!(
+            res.portfolio.unwrap().total_invested,
+            Uint128::new(3_000_000_000)
+        );
+        let invest = res
+            .rate_limits
+            .iter()
+            .find(|status| status.operation == "invest")
+            .unwrap();
+        assert_eq!((invest.used, invest.remaining), (2, 48));
+
+        // Without a user only the platform-wide parts are filled in
+        let res = dashboard(None);
+        assert_eq!(res.platform_stats.total_raised, Uint128::new(3_000_000_000));
+        assert!(res.portfolio.is_none());
+        assert!(res.rate_limits.is_empty());
+    }
+
+    #[test]
+    fn test_portfolio_performance_breaks_down_realized_and_unrealized() {
+        use crate::helpers::PortfolioPerformance;
+        use crate::state::InvestmentStatus;
+        use cosmwasm_std::Int128;
+
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_distributed_proposal(
+            &mut app,
+            &launchpad_addr,
+            &[
+                ("investor1", 3_000_000_000_000),
+                ("investor2", 2_000_000_000_000),
+            ],
+        );
+        let (asset_details, financial_terms, documents, compliance) = create_sample_proposal();
+        app.execute_contract(
+            addr("creator2"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CreateProposal {
+                asset_details,
+                financial_terms,
+                documents,
+                compliance,
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::Invest {
+                proposal_id: "p2".to_string(),
+                operation_id: None,
+                referrer: None,
+            },
+            &coins(1_000_000_000_000, "untrn"),
+        )
+        .unwrap();
+
+        // The funded asset is marked up 20% and the second raise fails and is refunded
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::AddOracle {
+                oracle: addr("appraiser").to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("appraiser"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::UpdateAssetValuation {
+                proposal_id: "p1".to_string(),
+                valuation: Uint128::new(6_000_000_000_000),
+                source: "appraisal".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        app.update_block(|block| block.time = block.time.plus_days(61));
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::ClaimRefund {
+                proposal_id: "p2".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        let performance = |app: &App, start_after: Option<&str>| -> PortfolioPerformance {
+            app.wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::PortfolioPerformance {
+                        user: addr("investor1").to_string(),
+                        start_after: start_after.map(str::to_string),
+                        limit: Some(1),
+                    },
+                )
+                .unwrap()
+        };
+
+        // Totals cover the whole portfolio whichever page is requested
+        let first = performance(&app, None);
+        assert_eq!(first.total_invested, Uint128::new(4_000_000_000_000));
+        assert_eq!(first.total_current_value, Uint128::new(3_600_000_000_000));
+        assert_eq!(
+            first.total_unrealized_gain_loss,
+            Int128::new(600_000_000_000)
+        );
+        assert_eq!(first.total_refunded, Uint128::new(1_000_000_000_000));
+        assert!(first.total_realized_distributions.is_zero());
+        // The refund is returned capital rather than a loss
+        assert_eq!(first.total_return_bps, 1_500);
+
+        assert_eq!(first.positions.len(), 1);
+        assert_eq!(first.next_key, Some("p1".to_string()));
+        let open = &first.positions[0];
+        assert_eq!(open.proposal_id, "p1");
+        assert_eq!(open.current_value, Uint128::new(3_600_000_000_000));
+        assert_eq!(open.unrealized_gain_loss, Int128::new(600_000_000_000));
+        assert!(open.refunded.is_zero());
+
+        let second = performance(&app, Some("p1"));
+        assert_eq!(second.total_invested, first.total_invested);
+        assert_eq!(second.next_key, None);
+        let closed = &second.positions[0];
+        assert_eq!(closed.proposal_id, "p2");
+        assert_eq!(closed.investment_status, InvestmentStatus::Refunded);
+        assert!(closed.current_value.is_zero());
+        assert_eq!(closed.unrealized_gain_loss, Int128::zero());
+        assert_eq!(closed.refunded, Uint128::new(1_000_000_000_000));
+    }
+
+    #[test]
+    fn test_activity_feed_lists_user_actions_newest_first() {
+        use crate::activity::{ActivityKind, ActivityResponse};
+        use crate::governance::{GovernanceAction, VoteOption};
+
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_distributed_proposal(
+            &mut app,
+            &launchpad_addr,
+            &[
+                ("investor1", 3_000_000_000_000),
+                ("investor2", 2_000_000_000_000),
+            ],
+        );
+        app.execute_contract(
+            addr("investor2"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CreateGovernanceProposal {
+                proposal_id: "p1".to_string(),
+                title: "Sell the building".to_string(),
+                description: "Accept the offer and wind the asset down".to_string(),
+                action: GovernanceAction::RetireAsset {},
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CastVote {
+                governance_id: 1,
+                vote: VoteOption::No,
+            },
+            &[],
+        )
+        .unwrap();
+
+        let (asset_details, financial_terms, documents, compliance) = create_sample_proposal();
+        app.execute_contract(
+            addr("creator2"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CreateProposal {
+                asset_details,
+                financial_terms,
+                documents,
+                compliance,
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::Invest {
+                proposal_id: "p2".to_string(),
+                operation_id: None,
+                referrer: None,
+            },
+            &coins(1_000_000_000_000, "untrn"),
+        )
+        .unwrap();
+        app.update_block(|block| block.time = block.time.plus_days(61));
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::ClaimRefund {
+                proposal_id: "p2".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        let activity = |app: &App, start_after: Option<String>| -> ActivityResponse {
+            app.wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::ActivityByUser {
+                        user: addr("investor1").to_string(),
+                        start_after,
+                        limit: Some(2),
+                    },
+                )
+                .unwrap()
+        };
+
+        let first = activity(&app, None);
+        assert_eq!(first.activities.len(), 2);
+        assert_eq!(first.activities[0].kind, ActivityKind::Refund);
+        assert_eq!(first.activities[0].proposal_id, "p2");
+        assert_eq!(
+            first.activities[0].amount,
+            Some(Uint128::new(1_000_000_000_000))
+        );
+        assert_eq!(first.activities[1].kind, ActivityKind::Investment);
+        assert_eq!(first.activities[1].proposal_id, "p2");
+
+        let second = activity(&app, first.next_key);
+        assert_eq!(second.activities.len(), 2);
+        assert_eq!(second.activities[0].kind, ActivityKind::GovernanceVote);
+        assert_eq!(second.activities[0].proposal_id, "p1");
+        assert_eq!(second.activities[0].governance_id, Some(1));
+        assert_eq!(second.activities[0].amount, None);
+        assert_eq!(second.activities[1].kind, ActivityKind::Investment);
+        assert_eq!(
+            second.activities[1].amount,
+            Some(Uint128::new(3_000_000_000_000))
+        );
+        assert_eq!(second.next_key, None);
+
+        // Each user only sees their own actions
+        let other: ActivityResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::ActivityByUser {
+                    user: addr("investor2").to_string(),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(other.activities.len(), 1);
+        assert_eq!(other.activities[0].kind, ActivityKind::Investment);
+    }
+
+    #[test]
+    fn test_investor_count_enforces_caps_and_follows_refunds() {
+        let (mut app, launchpad_addr) = setup_contract();
+        let (asset_details, financial_terms, documents, mut compliance) = create_sample_proposal();
+        compliance.max_investors = Some(2);
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CreateProposal {
+                asset_details,
+                financial_terms,
+                documents,
+                compliance,
+            },
+            &[],
+        )
+        .unwrap();
+        let invest = |app: &mut App, investor: &str| {
+            app.execute_contract(
+                addr(investor),
+                launchpad_addr.clone(),
+                &ExecuteMsg::Invest {
+                    proposal_id: "p1".to_string(),
+                    operation_id: None,
+                    referrer: None,
+                },
+                &coins(500_000_000_000, "untrn"),
+            )
+        };
+        let investor_count = |app: &App| -> u64 {
+            let res: crate::msg::ProposalResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::Proposal {
+                        proposal_id: "p1".to_string(),
+                    },
+                )
+                .unwrap();
+            res.proposal.funding_status.investor_count
+        };
+
+        invest(&mut app, "investor1").unwrap();
+        invest(&mut app, "investor2").unwrap();
+        let err = invest(&mut app, "investor3").unwrap_err();
+        assert!(err
+            .root_cause()
+            .to_string()
+            .contains("Maximum investors per proposal exceeded: 2"));
+        // Existing investors can still add to their position
+        invest(&mut app, "investor1").unwrap();
+        assert_eq!(investor_count(&app), 2);
+
+        // A refunded investor no longer counts
+        app.update_block(|block| block.time = block.time.plus_days(61));
+        app.execute_contract(
+            addr("investor1"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::ClaimRefund {
+                proposal_id: "p1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        assert_eq!(investor_count(&app), 1);
+
+        // Migration recounts from the positions themselves
+        {
+            let mut storage = app.contract_storage_mut(&launchpad_addr);
+            let mut proposal = crate::state::PROPOSALS
+                .load(storage.as_ref(), "p1".to_string())
+                .unwrap();
+            proposal.funding_status.investor_count = 7;
+            crate::state::PROPOSALS
+                .save(storage.as_mut(), "p1".to_string(), &proposal)
+                .unwrap();
+        }
+        let code_id = app
+            .wrap()
+            .query_wasm_contract_info(&launchpad_addr)
+            .unwrap()
+            .code_id;
+        app.migrate_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &crate::msg::MigrateMsg {},
+            code_id,
+        )
+        .unwrap();
+        assert_eq!(investor_count(&app), 1);
+    }
+
+    #[test]
+    fn test_funding_progress_reports_investor_slots() {
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_funded_proposal(
+            &mut app,
+            &launchpad_addr,
+            &[
+                ("investor1", 1_000_000_000_000),
+                ("investor2", 1_000_000_000_000),
+            ],
+        );
+        let (asset_details, financial_terms, documents, mut compliance) = create_sample_proposal();
+        compliance.max_investors = Some(1);
+        app.execute_contract(
+            addr("creator2"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CreateProposal {
+                asset_details,
+                financial_terms,
+                documents,
+                compliance,
+            },
+            &[],
+        )
+        .unwrap();
+        let invest = |app: &mut App, investor: &str| {
+            app.execute_contract(
+                addr(investor),
+                launchpad_addr.clone(),
+                &ExecuteMsg::Invest {
+                    proposal_id: "p2".to_string(),
+                    operation_id: None,
+                    referrer: None,
+                },
+                &coins(1_000_000_000_000, "untrn"),
+            )
+        };
+        invest(&mut app, "investor1").unwrap();
+        let progress = |app: &App, proposal_id: &str| {
+            let res: crate::msg::ProposalResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::Proposal {
+                        proposal_id: proposal_id.to_string(),
+                    },
+                )
+                .unwrap();
+            res.funding_progress
+        };
+
+        // A compliance limit above the platform ceiling is held to 500
+        let open = progress(&app, "p1");
+        assert_eq!(open.investor_cap, 500);
+        assert_eq!(open.investor_slots_remaining, 498);
+
+        let full = progress(&app, "p2");
+        assert_eq!(full.investor_cap, 1);
+        assert_eq!(full.investor_slots_remaining, 0);
+        let err = invest(&mut app, "investor2").unwrap_err();
+        assert!(err
+            .root_cause()
+            .to_string()
+            .contains("Maximum investors per proposal exceeded: 1"));
+    }
+
+    #[test]
+    fn test_platform_investment_bounds() {
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_funded_proposal(&mut app, &launchpad_addr, &[]);
+        let set_bounds = |min: Option<u128>, max: Option<u128>| ExecuteMsg::SetInvestmentBounds {
+            min_investment_amount: min.map(Uint128::new),
+            max_investment_amount: max.map(Uint128::new),
+        };
+        let invest = |app: &mut App, amount: u128| {
+            app.execute_contract(
+                addr("investor1"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::Invest {
+                    proposal_id: "p1".to_string(),
+                    operation_id: None,
+                    referrer: None,
+                },
+                &coins(amount, "untrn"),
+            )
+        };
+
+        let config: crate::state::Config = app
+            .wrap()
+            .query_wasm_smart(launchpad_addr.clone(), &QueryMsg::Config {})
+            .unwrap();
+        assert_eq!(config.min_investment_amount, Some(Uint128::new(1_000_000)));
+        assert_eq!(config.max_investment_amount, None);
+
+        // Only the owner sets them, and the minimum cannot pass the maximum
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &set_bounds(Some(2_000_000_000), Some(1_000_000_000_000)),
+            &[],
+        )
+        .unwrap_err();
+        let err = app
+            .execute_contract(
+                addr("admin"),
+                launchpad_addr.clone(),
+                &set_bounds(Some(2_000_000_000), Some(1_000_000_000)),
+                &[],
+            )
+            .unwrap_err();
+        assert!(err.root_cause().to_string().contains("cannot exceed"));
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &set_bounds(Some(2_000_000_000), Some(1_000_000_000_000)),
+            &[],
+        )
+        .unwrap();
+
+        // Above the proposal minimum but below the platform floor
+        let err = invest(&mut app, 1_500_000_000).unwrap_err();
+        assert!(err
+            .root_cause()
+            .to_string()
+            .contains("Investment too small, minimum: 2000000000"));
+        let err = invest(&mut app, 1_500_000_000_000).unwrap_err();
+        assert!(err
+            .root_cause()
+            .to_string()
+            .contains("Investment too large, maximum: 1000000000000"));
+        invest(&mut app, 1_000_000_000_000).unwrap();
+
+        // Lifting the ceiling lets larger tickets through again
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &set_bounds(Some(2_000_000_000), None),
+            &[],
+        )
+        .unwrap();
+        invest(&mut app, 1_500_000_000_000).unwrap();
+    }
+
+    #[test]
+    fn test_token_instantiation_replies_carry_their_proposal() {
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_funded_proposal(
+            &mut app,
+            &launchpad_addr,
+            &[
+                ("investor1", 3_000_000_000_000),
+                ("investor2", 2_000_000_000_000),
+            ],
+        );
+        let (asset_details, financial_terms, documents, compliance) = create_sample_proposal();
+        app.execute_contract(
+            addr("creator2"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CreateProposal {
+                asset_details,
+                financial_terms,
+                documents,
+                compliance,
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("investor3"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::Invest {
+                proposal_id: "p2".to_string(),
+                operation_id: None,
+                referrer: None,
+            },
+            &coins(5_000_000_000_000, "untrn"),
+        )
+        .unwrap();
+        let set_code_id = |app: &mut App, code_id: u64| {
+            app.execute_contract(
+                addr("admin"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::UpdateConfig {
+                    admin: None,
+                    platform_fee_bps: None,
+                    min_funding_period_days: None,
+                    max_funding_period_days: None,
+                    cw20_code_id: Some(code_id),
+                },
+                &[],
+            )
+            .unwrap();
+        };
+        let mint = |app: &mut App, sender: &str, proposal_id: &str| {
+            app.execute_contract(
+                addr(sender),
+                launchpad_addr.clone(),
+                &ExecuteMsg::MintTokens {
+                    proposal_id: proposal_id.to_string(),
+                },
+                &[],
+            )
+            .unwrap()
+        };
+        let tokens_minted = |app: &App, proposal_id: &str| {
+            let res: crate::msg::ProposalResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::Proposal {
+                        proposal_id: proposal_id.to_string(),
+                    },
+                )
+                .unwrap();
+            res.proposal.funding_status.tokens_minted
+        };
+
+        // A failed instantiation rolls the flag back and releases the mint lock
+        let config: crate::state::Config = app
+            .wrap()
+            .query_wasm_smart(launchpad_addr.clone(), &QueryMsg::Config {})
+            .unwrap();
+        set_code_id(&mut app, 999);
+        let res = mint(&mut app, "creator", "p1");
+        assert!(res
+            .events
+            .iter()
+            .any(|e| e.ty == "wasm-cf1.token_instantiate_failed.v1"));
+        assert!(!tokens_minted(&app, "p1"));
+
+        // Each reply records the token against the proposal that asked for it
+        set_code_id(&mut app, config.cw20_code_id);
+        mint(&mut app, "creator2", "p2");
+        mint(&mut app, "creator", "p1");
+        assert!(tokens_minted(&app, "p1"));
+        assert!(tokens_minted(&app, "p2"));
+        let storage = app.contract_storage(&launchpad_addr);
+        let p1_token = crate::state::TOKEN_CONTRACTS
+            .load(storage.as_ref(), "p1".to_string())
+            .unwrap();
+        let p2_token = crate::state::TOKEN_CONTRACTS
+            .load(storage.as_ref(), "p2".to_string())
+            .unwrap();
+        assert_ne!(p1_token, p2_token);
+        assert_eq!(
+            crate::state::TOKEN_PROPOSALS
+                .load(storage.as_ref(), &p2_token)
+                .unwrap(),
+            "p2"
+        );
+    }
+
+    #[test]
+    fn test_minting_keeps_the_lockup_set_at_funding() {
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_funded_proposal(
+            &mut app,
+            &launchpad_addr,
+            &[("investor1", 5_000_000_000_000)],
+        );
+        let lockup_end = |app: &App| {
+            let res: crate::msg::ProposalResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::Proposal {
+                        proposal_id: "p1".to_string(),
+                    },
+                )
+                .unwrap();
+            res.proposal.timestamps.lockup_end
+        };
+        let funded_lockup_end = lockup_end(&app);
+        assert_eq!(
+            funded_lockup_end,
+            Some(app.block_info().time.seconds() + 365 * 24 * 60 * 60)
+        );
+
+        // Minting days later does not push the lockup back
+        app.update_block(|block| block.time = block.time.plus_days(3));
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::MintTokens {
+                proposal_id: "p1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        assert_eq!(lockup_end(&app), funded_lockup_end);
+    }
+
+    #[test]
+    fn test_retry_mint_after_failed_token_instantiation() {
+        use crate::state::MintFailure;
+
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_funded_proposal(
+            &mut app,
+            &launchpad_addr,
+            &[
+                ("investor1", 3_000_000_000_000),
+                ("investor2", 2_000_000_000_000),
+            ],
+        );
+        let config: crate::state::Config = app
+            .wrap()
+            .query_wasm_smart(launchpad_addr.clone(), &QueryMsg::Config {})
+            .unwrap();
+        let set_code_id = |app: &mut App, code_id: u64| {
+            app.execute_contract(
+                addr("admin"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::UpdateConfig {
+                    admin: None,
+                    platform_fee_bps: None,
+                    min_funding_period_days: None,
+                    max_funding_period_days: None,
+                    cw20_code_id: Some(code_id),
+                },
+                &[],
+            )
+            .unwrap();
+        };
+        let retry = ExecuteMsg::RetryMintTokens {
+            proposal_id: "p1".to_string(),
+        };
+        let failure = |app: &App| -> Option<MintFailure> {
+            app.wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::MintFailure {
+                        proposal_id: "p1".to_string(),
+                    },
+                )
+                .unwrap()
+        };
+
+        // There is nothing to retry before a mint has failed
+        let err = app
+            .execute_contract(addr("creator"), launchpad_addr.clone(), &retry, &[])
+            .unwrap_err();
+        assert!(err
+            .root_cause()
+            .to_string()
+            .contains("No failed token mint to retry"));
+
+        // Instantiation fails while the configured code id does not exist
+        set_code_id(&mut app, 999);
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::MintTokens {
+                proposal_id: "p1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &retry, &[])
+            .unwrap();
+        let recorded = failure(&app).unwrap();
+        assert_eq!(recorded.attempts, 2);
+        assert!(!recorded.error.is_empty());
+
+        // Only the creator or an operator may retry
+        set_code_id(&mut app, config.cw20_code_id);
+        app.execute_contract(addr("investor1"), launchpad_addr.clone(), &retry, &[])
+            .unwrap_err();
+        let res = app
+            .execute_contract(addr("creator"), launchpad_addr.clone(), &retry, &[])
+            .unwrap();
+        assert!(res.events.iter().any(|e| e
+            .attributes
+            .iter()
+            .any(|a| a.key == "retry_attempt" && a.value == "3")));
+        assert!(res
+            .events
+            .iter()
+            .any(|e| e.ty == "wasm-cf1.token_instantiated.v1"));
+        assert_eq!(failure(&app), None);
+
+        // The recovered proposal carries on to distribution
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::DistributeTokens {
+                proposal_id: "p1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::FinalizeDistribution {
+                proposal_id: "p1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &retry, &[])
+            .unwrap_err();
+    }
+
+    #[test]
+    fn test_distribute_tokens_in_batches() {
+        use crate::state::DistributionProgress;
+
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_funded_proposal(
+            &mut app,
+            &launchpad_addr,
+            &[
+                ("investor1", 1_000_000_000_000),
+                ("investor2", 2_000_000_000_000),
+                ("investor3", 2_000_000_000_000),
+            ],
+        );
+        let mint_res = app
+            .execute_contract(
+                addr("creator"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::MintTokens {
+                    proposal_id: "p1".to_string(),
+                },
+                &[],
+            )
+            .unwrap();
+        let token_addr = mint_res
+            .events
+            .iter()
+            .flat_map(|e| e.attributes.iter())
+            .find(|a| a.key == "token_address")
+            .map(|a| Addr::unchecked(&a.value))
+            .unwrap();
+        let batch = ExecuteMsg::DistributeTokensBatch {
+            proposal_id: "p1".to_string(),
+            limit: Some(2),
+        };
+        let proposal_status = |app: &App| {
+            let res: crate::msg::ProposalResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::Proposal {
+                        proposal_id: "p1".to_string(),
+                    },
+                )
+                .unwrap();
+            res.proposal.status
+        };
+        let balance_of = |app: &App, investor: &str| {
+            let res: BalanceResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    token_addr.clone(),
+                    &Cw20QueryMsg::Balance {
+                        address: addr(investor).to_string(),
+                    },
+                )
+                .unwrap();
+            res.balance.u128()
+        };
+
+        // The first batch mints for two investors and leaves the proposal funded
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &batch, &[])
+            .unwrap();
+        let progress: Option<DistributionProgress> = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::DistributionProgress {
+                    proposal_id: "p1".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            progress,
+            Some(DistributionProgress {
+                next_index: 2,
+                distributed_count: 2,
+                total_distributed: 3_000,
+                complete: false,
+            })
+        );
+        assert_eq!(proposal_status(&app), ProposalStatus::Funded);
+        assert_eq!(balance_of(&app, "investor1"), 1_000);
+        assert_eq!(balance_of(&app, "investor2"), 2_000);
+        assert_eq!(balance_of(&app, "investor3"), 0);
+        let creator_balance = app.wrap().query_balance(addr("creator"), "untrn").unwrap();
+
+        // The batch reaching the end of the list completes the proposal but pays nothing out yet
+        let res = app
+            .execute_contract(addr("creator"), launchpad_addr.clone(), &batch, &[])
+            .unwrap();
+        assert!(res
+            .events
+            .iter()
+            .flat_map(|e| e.attributes.iter())
+            .any(|a| a.key == "total_distributed" && a.value == "5000"));
+        assert_eq!(proposal_status(&app), ProposalStatus::Completed);
+        assert_eq!(balance_of(&app, "investor3"), 2_000);
+        let progress: Option<DistributionProgress> = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::DistributionProgress {
+                    proposal_id: "p1".to_string(),
+                },
+            )
+            .unwrap();
+        assert!(progress.unwrap().complete);
+        assert_eq!(
+            app.wrap().query_balance(addr("creator"), "untrn").unwrap(),
+            creator_balance
+        );
+
+        // Nothing is left to distribute
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &batch, &[])
+            .unwrap_err();
+
+        // Finalizing releases the raise less the 2.5% platform fee, once
+        let finalize = ExecuteMsg::FinalizeDistribution {
+            proposal_id: "p1".to_string(),
+        };
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &finalize, &[])
+            .unwrap();
+        assert_eq!(
+            app.wrap()
+                .query_balance(addr("creator"), "untrn")
+                .unwrap()
+                .amount
+                .u128(),
+            creator_balance.amount.u128() + 4_875_000_000_000
+        );
+        let progress: Option<DistributionProgress> = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::DistributionProgress {
+                    proposal_id: "p1".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(progress, None);
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &finalize, &[])
+            .unwrap_err();
+    }
+
+    #[test]
+    fn test_failed_investor_mints_are_recorded_and_retried() {
+        use crate::distribution::FailedMintsResponse;
+        use crate::state::DistributionProgress;
+
+        let (mut app, launchpad_addr) = setup_contract();
+        let flaky_id = app.store_code(contract_flaky_token());
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::UpdateConfig {
+                admin: None,
+                platform_fee_bps: None,
+                min_funding_period_days: None,
+                max_funding_period_days: None,
+                cw20_code_id: Some(flaky_id),
+            },
+            &[],
+        )
+        .unwrap();
+        setup_funded_proposal(
+            &mut app,
+            &launchpad_addr,
+            &[
+                ("investor1", 2_000_000_000_000),
+                ("investor2", 3_000_000_000_000),
+            ],
+        );
+        let mint_res = app
+            .execute_contract(
+                addr("creator"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::MintTokens {
+                    proposal_id: "p1".to_string(),
+                },
+                &[],
+            )
+            .unwrap();
+        let token_addr = mint_res
+            .events
+            .iter()
+            .flat_map(|e| e.attributes.iter())
+            .find(|a| a.key == "token_address")
+            .map(|a| Addr::unchecked(&a.value))
+            .unwrap();
+        let failed_mints = |app: &App| {
+            let res: FailedMintsResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::FailedMints {
+                        proposal_id: "p1".to_string(),
+                        start_after: None,
+                        limit: None,
+                    },
+                )
+                .unwrap();
+            res.failed_mints
+        };
+        let progress = |app: &App| {
+            let res: Option<DistributionProgress> = app
+                .wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::DistributionProgress {
+                        proposal_id: "p1".to_string(),
+                    },
+                )
+                .unwrap();
+            res
+        };
+        let retry = ExecuteMsg::RetryFailedMints {
+            proposal_id: "p1".to_string(),
+            limit: None,
+        };
+        let finalize = ExecuteMsg::FinalizeDistribution {
+            proposal_id: "p1".to_string(),
+        };
+
+        // investor2's mint fails without taking investor1's down with it
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::DistributeTokens {
+                proposal_id: "p1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        let failed = failed_mints(&app);
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].investor, addr("investor2"));
+        assert_eq!(failed[0].shares, 3_000);
+        assert_eq!(failed[0].attempts, 1);
+        assert!(failed[0].error.contains("mint rejected"));
+        assert_eq!(
+            progress(&app),
+            Some(DistributionProgress {
+                next_index: 2,
+                distributed_count: 1,
+                total_distributed: 2_000,
+                complete: true,
+            })
+        );
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &finalize, &[])
+            .unwrap_err();
+
+        // A retry that fails again only bumps the attempt count
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &retry, &[])
+            .unwrap();
+        assert_eq!(failed_mints(&app)[0].attempts, 2);
+        app.execute_contract(addr("investor1"), launchpad_addr.clone(), &retry, &[])
+            .unwrap_err();
+
+        // Once the token accepts the mint the record clears and the raise can be paid out
+        app.execute_contract(
+            addr("creator"),
+            token_addr,
+            &cw20::Cw20ExecuteMsg::Burn {
+                amount: Uint128::zero(),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &retry, &[])
+            .unwrap();
+        assert!(failed_mints(&app).is_empty());
+        assert_eq!(progress(&app).unwrap().total_distributed, 5_000);
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &retry, &[])
+            .unwrap_err();
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &finalize, &[])
+            .unwrap();
+    }
+
+    #[test]
+    fn test_simulate_investment_previews_without_recording() {
+        use crate::simulation::SimulateInvestmentResponse;
+
+        let (mut app, launchpad_addr) = setup_contract();
+        let (asset_details, financial_terms, documents, compliance) = create_sample_proposal();
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CreateProposal {
+                asset_details,
+                financial_terms,
+                documents,
+                compliance,
+            },
+            &[],
+        )
+        .unwrap();
+        let simulate = |app: &App, amount: u128, denom: &str, investor: Option<&str>| {
+            let res: SimulateInvestmentResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::SimulateInvestment {
+                        proposal_id: "p1".to_string(),
+                        amount: Uint128::new(amount),
+                        denom: denom.to_string(),
+                        investor: investor.map(|investor| addr(investor).to_string()),
+                    },
+                )
+                .unwrap();
+            res
+        };
+
+        let preview = simulate(&app, 2_000_000_000_000, "untrn", Some("investor1"));
+        assert_eq!(preview.shares, 2_000);
+        assert_eq!(preview.accepted_amount, Uint128::new(2_000_000_000_000));
+        assert_eq!(
+            preview.effective_price,
+            Some(cosmwasm_std::Decimal::from_ratio(1_000_000_000u128, 1u128))
+        );
+        assert!(!preview.completes_funding);
+        assert_eq!(preview.remaining_capacity, Uint128::new(5_000_000_000_000));
+        assert_eq!(preview.remaining_shares, 5_000);
+        assert!(preview.rejections.is_empty());
+
+        // Nothing was recorded, and the real investment matches the preview
+        let res = app
+            .execute_contract(
+                addr("investor1"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::Invest {
+                    proposal_id: "p1".to_string(),
+                    operation_id: None,
+                    referrer: None,
+                },
+                &coins(2_000_000_000_000, "untrn"),
+            )
+            .unwrap();
+        assert!(res
+            .events
+            .iter()
+            .flat_map(|e| e.attributes.iter())
+            .any(|a| a.key == "shares" && a.value == "2000"));
+
+        // The investment that fills the raise is flagged
+        let preview = simulate(&app, 3_000_000_000_000, "untrn", Some("investor2"));
+        assert!(preview.completes_funding);
+        assert_eq!(preview.remaining_capacity, Uint128::new(3_000_000_000_000));
+
+        // Every failed check is reported, not just the first
+        let preview = simulate(&app, 500_000_000, "uatom", Some("stranger"));
+        assert!(!preview.completes_funding);
+        assert_eq!(preview.rejections.len(), 4);
+        assert!(preview.rejections[0].contains("untrn"));
+        assert!(preview.rejections[1].contains("below minimum"));
+        assert!(preview.rejections[3].contains("KYC"));
+
+        // Without an investor only the proposal-level checks run
+        let preview = simulate(&app, 4_000_000_000_000, "untrn", None);
+        assert_eq!(preview.rejections.len(), 1);
+    }
+
+    #[test]
+    fn test_gas_estimates_are_tallied_per_operation() {
+        use crate::gas_monitor::{GasClass, GasStatsResponse};
+
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_funded_proposal(
+            &mut app,
+            &launchpad_addr,
+            &[
+                ("investor1", 2_000_000_000_000),
+                ("investor2", 3_000_000_000_000),
+            ],
+        );
+        let gas_stats = |app: &App| {
+            let res: GasStatsResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::GasStats {
+                        start_after: None,
+                        limit: None,
+                    },
+                )
+                .unwrap();
+            res
+        };
+
+        let stats = gas_stats(&app);
+        assert!(!stats.emit_gas_class);
+        let invest = stats
+            .operations
+            .iter()
+            .find(|stats| stats.operation == "invest")
+            .unwrap();
+        assert_eq!(invest.count, 2);
+        assert_eq!(invest.average_class, GasClass::Low);
+        assert!(stats
+            .operations
+            .iter()
+            .any(|stats| stats.operation == "create_proposal" && stats.count == 1));
+
+        // Only the owner can turn on the response attribute
+        let enable = ExecuteMsg::SetEmitGasClass { enabled: true };
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &enable, &[])
+            .unwrap_err();
+        app.execute_contract(addr("admin"), launchpad_addr.clone(), &enable, &[])
+            .unwrap();
+        assert!(gas_stats(&app).emit_gas_class);
+
+        let res = app
+            .execute_contract(
+                addr("creator"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::MintTokens {
+                    proposal_id: "p1".to_string(),
+                },
+                &[],
+            )
+            .unwrap();
+        assert!(res
+            .events
+            .iter()
+            .flat_map(|e| e.attributes.iter())
+            .any(|a| a.key == "estimated_gas_class" && a.value == "low"));
+    }
+
+    #[test]
+    fn test_rate_limit_queries_return_typed_responses() {
+        use crate::rate_limit::{RateLimitConfig, RateLimitStatusResponse};
+
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_funded_proposal(
+            &mut app,
+            &launchpad_addr,
+            &[("investor1", 2_000_000_000), ("investor1", 1_000_000_000)],
+        );
+        let status = |operation: &str| -> RateLimitStatusResponse {
+            app.wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::RateLimitStatus {
+                        user: addr("investor1").to_string(),
+                        operation: operation.to_string(),
+                    },
+                )
+                .unwrap()
+        };
+
+        let invest = status("invest");
+        assert_eq!(invest.operation, "invest");
+        assert_eq!((invest.used, invest.remaining), (2, 48));
+        assert!(invest.window_end > app.block_info().time.seconds());
+
+        // Operations without their own limit fall back to the global one
+        let config: RateLimitConfig = app
+            .wrap()
+            .query_wasm_smart(launchpad_addr.clone(), &QueryMsg::RateLimitConfig {})
+            .unwrap();
+        let vote = status("vote");
+        assert_eq!((vote.used, vote.remaining), (0, config.max_operations));
+    }
+
+    #[test]
+    fn test_prune_rate_limit_data_removes_expired_counters() {
+        use crate::rate_limit::{RateLimitStatusResponse, USER_OPERATIONS};
+        use cosmwasm_std::Order;
+
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_funded_proposal(
+            &mut app,
+            &launchpad_addr,
+            &[("investor1", 2_000_000_000), ("investor2", 1_000_000_000)],
+        );
+        let counters = |app: &App| {
+            let storage = app.contract_storage(&launchpad_addr);
+            USER_OPERATIONS
+                .keys(storage.as_ref(), None, None, Order::Ascending)
+                .count()
+        };
+        assert_eq!(counters(&app), 3);
+
+        // Once the windows have ended, anyone can sweep them, a batch at a time
+        app.update_block(|block| block.time = block.time.plus_seconds(86_400));
+        let prune = ExecuteMsg::PruneRateLimitData { limit: Some(2) };
+        let res = app
+            .execute_contract(addr("keeper"), launchpad_addr.clone(), &prune, &[])
+            .unwrap();
+        assert!(res
+            .events
+            .iter()
+            .flat_map(|e| e.attributes.iter())
+            .any(|a| a.key == "pruned_count" && a.value == "2"));
+        assert_eq!(counters(&app), 1);
+        app.execute_contract(addr("keeper"), launchpad_addr.clone(), &prune, &[])
+            .unwrap();
+        assert_eq!(counters(&app), 0);
+
+        // A pruned counter reads as a fresh window
+        let status: RateLimitStatusResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::RateLimitStatus {
+                    user: addr("investor1").to_string(),
+                    operation: "invest".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!((status.used, status.remaining), (0, 50));
+    }
+
+    #[test]
+    fn test_rate_limit_exemptions_raise_limits_for_listed_addresses() {
+        use crate::rate_limit::{RateLimitExemptionsResponse, RateLimitStatusResponse};
+
+        let (mut app, launchpad_addr) = setup_contract();
+        let status = |app: &App, user: &str| -> RateLimitStatusResponse {
+            app.wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::RateLimitStatus {
+                        user: addr(user).to_string(),
+                        operation: "create_proposal".to_string(),
+                    },
+                )
+                .unwrap()
+        };
+        let exempt = |user: &str, multiplier: Option<u32>| ExecuteMsg::AddRateLimitExemption {
+            address: addr(user).to_string(),
+            multiplier,
+        };
+
+        // Only an operator can grant exemptions
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &exempt("creator", None),
+            &[],
+        )
+        .unwrap_err();
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &exempt("market_maker", Some(2)),
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &exempt("custodian", None),
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(status(&app, "creator").remaining, 5);
+        assert_eq!(status(&app, "market_maker").remaining, 10);
+        assert_eq!(status(&app, "custodian").remaining, u32::MAX);
+
+        let res: RateLimitExemptionsResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::RateLimitExemptions {
+                    start_after: None,
+                    limit: Some(1),
+                },
+            )
+            .unwrap();
+        assert_eq!(res.exemptions.len(), 1);
+        assert!(res.next_key.is_some());
+
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::RemoveRateLimitExemption {
+                address: addr("market_maker").to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        assert_eq!(status(&app, "market_maker").remaining, 5);
+    }
+
+    #[test]
+    fn test_creation_bond_is_refunded_or_slashed() {
+        use crate::bonds::CreationBond;
+
+        let (mut app, launchpad_addr) = setup_contract();
+        let bond = 1_000_000u128;
+        let set_bond = ExecuteMsg::SetCreationBond {
+            amount: Some(Uint128::new(bond)),
+        };
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &set_bond, &[])
+            .unwrap_err();
+        app.execute_contract(addr("admin"), launchpad_addr.clone(), &set_bond, &[])
+            .unwrap();
+
+        let create_as = |app: &mut App, creator: &str, funds: &[cosmwasm_std::Coin]| {
+            let (asset_details, financial_terms, documents, compliance) = create_sample_proposal();
+            app.execute_contract(
+                addr(creator),
+                launchpad_addr.clone(),
+                &ExecuteMsg::CreateProposal {
+                    asset_details,
+                    financial_terms,
+                    documents,
+                    compliance,
+                },
+                funds,
+            )
+        };
+        let creation_bond = |app: &App, proposal_id: &str| -> Option<CreationBond> {
+            app.wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::CreationBond {
+                        proposal_id: proposal_id.to_string(),
+                    },
+                )
+                .unwrap()
+        };
+        let create = |app: &mut App, funds: &[cosmwasm_std::Coin]| create_as(app, "creator", funds);
+        let balance = |app: &App, owner: &str| {
+            app.wrap()
+                .query_balance(addr(owner), "untrn")
+                .unwrap()
+                .amount
+        };
+        let creator_balance = |app: &App| balance(app, "creator");
+        let invest = |app: &mut App, proposal_id: &str, investor: &str, amount: u128| {
+            app.execute_contract(
+                addr(investor),
+                launchpad_addr.clone(),
+                &ExecuteMsg::Invest {
+                    proposal_id: proposal_id.to_string(),
+                    operation_id: None,
+                    referrer: None,
+                },
+                &coins(amount, "untrn"),
+            )
+            .unwrap();
+        };
+
+        // The bond has to be attached in full
+        create(&mut app, &[]).unwrap_err();
+        create(&mut app, &coins(bond - 1, "untrn")).unwrap_err();
+        let starting_balance = creator_balance(&app);
+        create(&mut app, &coins(bond, "untrn")).unwrap();
+        assert_eq!(
+            creation_bond(&app, "p1").unwrap().amount,
+            Uint128::new(bond)
+        );
+        assert_eq!(
+            treasury_balance(&app, &launchpad_addr, TreasuryAccount::Bonds),
+            Uint128::new(bond)
+        );
+
+        // Cancelling before anyone invested returns it
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::CancelProposal {
+                proposal_id: "p1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        assert_eq!(creator_balance(&app), starting_balance);
+        assert!(creation_bond(&app, "p1").is_none());
+
+        // A fraudulent raise refunds its investors and loses the bond to the treasury
+        create(&mut app, &coins(bond, "untrn")).unwrap();
+        invest(&mut app, "p2", "investor1", 2_000_000_000);
+        let reject = ExecuteMsg::RejectProposalForFraud {
+            proposal_id: "p2".to_string(),
+            reason: "Forged property deed".to_string(),
+        };
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &reject, &[])
+            .unwrap_err();
+        app.execute_contract(addr("admin"), launchpad_addr.clone(), &reject, &[])
+            .unwrap();
+        let proposal: crate::msg::ProposalResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::Proposal {
+                    proposal_id: "p2".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(proposal.proposal.status, ProposalStatus::Cancelled);
+        assert_eq!(
+            app.wrap()
+                .query_balance(addr("investor1"), "untrn")
+                .unwrap()
+                .amount,
+            Uint128::new(10_000_000_000_000)
+        );
+        assert!(creation_bond(&app, "p2").is_none());
+        assert_eq!(
+            treasury_balance(&app, &launchpad_addr, TreasuryAccount::Fees),
+            Uint128::new(bond)
+        );
+
+        // A completed raise pays the bond back with the creator's payout
+        create(&mut app, &coins(bond, "untrn")).unwrap();
+        invest(&mut app, "p3", "investor1", 2_000_000_000_000);
+        invest(&mut app, "p3", "investor2", 3_000_000_000_000);
+        for msg in [
+            ExecuteMsg::MintTokens {
+                proposal_id: "p3".to_string(),
+            },
+            ExecuteMsg::DistributeTokens {
+                proposal_id: "p3".to_string(),
+            },
+        ] {
+            app.execute_contract(addr("creator"), launchpad_addr.clone(), &msg, &[])
+                .unwrap();
+        }
+        let before_finalize = creator_balance(&app);
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::FinalizeDistribution {
+                proposal_id: "p3".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        assert_eq!(
+            creator_balance(&app),
+            before_finalize + Uint128::new(4_875_000_000_000 + bond)
+        );
+        assert!(creation_bond(&app, "p3").is_none());
+        assert!(treasury_balance(&app, &launchpad_addr, TreasuryAccount::Bonds).is_zero());
+
+        // A raise that just misses its soft cap hands the bond back when it expires
+        app.send_tokens(addr("admin"), addr("creator2"), &coins(bond, "untrn"))
+            .unwrap();
+        let creator2_balance = balance(&app, "creator2");
+        create_as(&mut app, "creator2", &coins(bond, "untrn")).unwrap();
+        invest(&mut app, "p4", "investor1", 2_000_000_000);
+        let fees = treasury_balance(&app, &launchpad_addr, TreasuryAccount::Fees);
+        app.update_block(|block| block.time = block.time.plus_days(61));
+        app.execute_contract(
+            addr("investor2"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::ProcessExpiredProposals {
+                start_after: None,
+                limit: None,
+            },
+            &[],
+        )
+        .unwrap();
+        assert_eq!(balance(&app, "creator2"), creator2_balance);
+        assert!(creation_bond(&app, "p4").is_none());
+        assert_eq!(
+            treasury_balance(&app, &launchpad_addr, TreasuryAccount::Fees),
+            fees
+        );
+        assert!(treasury_balance(&app, &launchpad_addr, TreasuryAccount::Bonds).is_zero());
+    }
+
+    #[test]
+    fn test_blacklisted_creator_is_blocked_and_payouts_frozen() {
+        use crate::blacklist::BlacklistEntry;
+
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_funded_proposal(
+            &mut app,
+            &launchpad_addr,
+            &[("investor1", 5_000_000_000_000)],
+        );
+        for msg in [
+            ExecuteMsg::MintTokens {
+                proposal_id: "p1".to_string(),
+            },
+            ExecuteMsg::DistributeTokens {
+                proposal_id: "p1".to_string(),
+            },
+        ] {
+            app.execute_contract(addr("creator"), launchpad_addr.clone(), &msg, &[])
+                .unwrap();
+        }
+
+        let blacklist = ExecuteMsg::BlacklistCreator {
+            creator: addr("creator").to_string(),
+            reason: "Misrepresented the asset".to_string(),
+        };
+        app.execute_contract(addr("investor1"), launchpad_addr.clone(), &blacklist, &[])
+            .unwrap_err();
+        let res = app
+            .execute_contract(addr("admin"), launchpad_addr.clone(), &blacklist, &[])
+            .unwrap();
+        assert!(res
+            .events
+            .iter()
+            .any(|e| e.ty == "wasm-cf1.blacklist_creator.v1"));
+
+        // No new proposals, and the raise stays in escrow
+        let (asset_details, financial_terms, documents, compliance) = create_sample_proposal();
+        let err = app
+            .execute_contract(
+                addr("creator"),
+                launchpad_addr.clone(),
+                &ExecuteMsg::CreateProposal {
+                    asset_details,
+                    financial_terms,
+                    documents,
+                    compliance,
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert!(err.root_cause().to_string().contains("blacklisted"));
+        let finalize = ExecuteMsg::FinalizeDistribution {
+            proposal_id: "p1".to_string(),
+        };
+        app.execute_contract(addr("admin"), launchpad_addr.clone(), &finalize, &[])
+            .unwrap_err();
+
+        // The creator gets one appeal
+        let appeal = ExecuteMsg::AppealBlacklist {
+            statement: "The valuation was independently audited".to_string(),
+        };
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &appeal, &[])
+            .unwrap();
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &appeal, &[])
+            .unwrap_err();
+        let entry: Option<BlacklistEntry> = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::BlacklistedCreator {
+                    creator: addr("creator").to_string(),
+                },
+            )
+            .unwrap();
+        let appeal = entry.unwrap().appeal.unwrap();
+        assert!(appeal.dismissed_at.is_none());
+
+        // Lifting the blacklist releases the frozen payout
+        let balance_before = app
+            .wrap()
+            .query_balance(addr("creator"), "untrn")
+            .unwrap()
+            .amount;
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::UnblacklistCreator {
+                creator: addr("creator").to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &finalize, &[])
+            .unwrap();
+        let balance_after = app
+            .wrap()
+            .query_balance(addr("creator"), "untrn")
+            .unwrap()
+            .amount;
+        assert!(balance_after > balance_before);
+
+        let listed: crate::blacklist::BlacklistedCreatorsResponse = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::BlacklistedCreators {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert!(listed.creators.is_empty());
+    }
+
+    #[test]
+    fn test_frozen_proposal_takes_no_investments_or_distribution() {
+        let (mut app, launchpad_addr) = setup_contract();
+        setup_funded_proposal(
+            &mut app,
+            &launchpad_addr,
+            &[("investor1", 2_000_000_000_000)],
+        );
+
+        let freeze = ExecuteMsg::FreezeProposal {
+            proposal_id: "p1".to_string(),
+            reason: "Title dispute under review".to_string(),
+        };
+        let unfreeze = ExecuteMsg::UnfreezeProposal {
+            proposal_id: "p1".to_string(),
+        };
+        let invest = ExecuteMsg::Invest {
+            proposal_id: "p1".to_string(),
+            operation_id: None,
+            referrer: None,
+        };
+        let proposal = |app: &App| -> crate::msg::ProposalResponse {
+            app.wrap()
+                .query_wasm_smart(
+                    launchpad_addr.clone(),
+                    &QueryMsg::Proposal {
+                        proposal_id: "p1".to_string(),
+                    },
+                )
+                .unwrap()
+        };
+
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &freeze, &[])
+            .unwrap_err();
+        let res = app
+            .execute_contract(addr("admin"), launchpad_addr.clone(), &freeze, &[])
+            .unwrap();
+        assert!(res
+            .events
+            .iter()
+            .any(|e| e.ty == "wasm-cf1.freeze_proposal.v1"));
+
+        // Investing is blocked, but the lifecycle status is untouched
+        let err = app
+            .execute_contract(
+                addr("investor2"),
+                launchpad_addr.clone(),
+                &invest,
+                &coins(3_000_000_000_000, "untrn"),
+            )
+            .unwrap_err();
+        assert!(err.root_cause().to_string().contains("frozen"));
+        let frozen = proposal(&app);
+        assert!(frozen.frozen);
+        assert_eq!(frozen.proposal.status, ProposalStatus::Active);
+
+        app.execute_contract(addr("admin"), launchpad_addr.clone(), &unfreeze, &[])
+            .unwrap();
+        app.execute_contract(
+            addr("investor2"),
+            launchpad_addr.clone(),
+            &invest,
+            &coins(3_000_000_000_000, "untrn"),
+        )
+        .unwrap();
+        app.execute_contract(
+            addr("creator"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::MintTokens {
+                proposal_id: "p1".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        // Distribution waits for the freeze to be lifted
+        let distribute = ExecuteMsg::DistributeTokens {
+            proposal_id: "p1".to_string(),
+        };
+        app.execute_contract(addr("admin"), launchpad_addr.clone(), &freeze, &[])
+            .unwrap();
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &distribute, &[])
+            .unwrap_err();
+        let record: Option<crate::freeze::ProposalFreeze> = app
+            .wrap()
+            .query_wasm_smart(
+                launchpad_addr.clone(),
+                &QueryMsg::ProposalFreeze {
+                    proposal_id: "p1".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(record.unwrap().frozen_by, addr("admin"));
+        assert_eq!(proposal(&app).proposal.status, ProposalStatus::Funded);
+
+        app.execute_contract(addr("admin"), launchpad_addr.clone(), &unfreeze, &[])
+            .unwrap();
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &distribute, &[])
+            .unwrap();
+        assert!(!proposal(&app).frozen);
     }
 }
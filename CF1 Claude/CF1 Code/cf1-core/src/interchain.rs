@@ -0,0 +1,248 @@
+//! Creators (and investors) that act through a Neutron Interchain Account.
+//!
+//! An ICA host account is an ordinary Neutron address driven by an owner on a controller chain.
+//! The account registers its controller connection, owner and the ICS-20 channel back to the
+//! controller chain; once a compliance officer has verified the registration against the
+//! controller chain, refunds and payouts owed to the account travel over that channel to the
+//! owner instead of sitting on Neutron. A transfer that fails or times out is paid to the host
+//! account on Neutron instead, so funds never strand in the launchpad.
+//!
+//! The contract does not check the registration on chain. Verification is the compliance
+//! officer's attestation that they checked it off chain, and the registered details are trusted
+//! as attested.
+
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    coins, from_json, to_json_string, Addr, BankMsg, CosmosMsg, Deps, DepsMut, Env,
+    IbcBasicResponse, IbcCallbackRequest, IbcMsg, IbcPacket, IbcSourceCallbackMsg, IbcSrcCallback,
+    IbcTimeout, MessageInfo, Response, StdResult, Storage, Uint128,
+};
+use cw_storage_plus::Map;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+use crate::ibc::{Ics20Ack, Ics20Packet};
+use crate::roles::{self, Role};
+
+/// How long a payout transfer may take to reach the controller chain
+pub const PAYOUT_TIMEOUT_SECONDS: u64 = 24 * 60 * 60;
+const NATIVE_DENOM: &str = "untrn";
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct InterchainAccount {
+    pub controller_connection_id: String, // Neutron's connection to the controller chain
+    pub controller_owner: String,         // Owner of the account on the controller chain
+    pub transfer_channel_id: String,      // ICS-20 channel on Neutron that payouts leave through
+    pub verified: bool, // Attested by a compliance officer; not checked on chain
+    pub registered_at: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct InterchainAccountResponse {
+    pub address: Addr,
+    pub account: Option<InterchainAccount>,
+}
+
+// Storage keys
+pub const INTERCHAIN_ACCOUNTS: Map<&Addr, InterchainAccount> = Map::new("interchain_accounts");
+// (transfer channel, controller owner) -> host account, to return failed payouts. Kept after
+// an account is removed so transfers already in flight can still fall back.
+pub const INTERCHAIN_OWNERS: Map<(&str, &str), Addr> = Map::new("interchain_owners");
+
+fn require_non_empty(field: &str, value: &str) -> Result<(), ContractError> {
+    if value.trim().is_empty() {
+        return Err(ContractError::InvalidInput {
+            field: field.to_string(),
+            message: "Cannot be empty".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Register the sender as an interchain account. Takes effect once verified; registering again
+/// replaces the details and needs a fresh verification.
+pub fn register_interchain_account(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    controller_connection_id: String,
+    controller_owner: String,
+    transfer_channel_id: String,
+) -> Result<Response, ContractError> {
+    require_non_empty("controller_connection_id", &controller_connection_id)?;
+    require_non_empty("controller_owner", &controller_owner)?;
+    require_non_empty("transfer_channel_id", &transfer_channel_id)?;
+
+    INTERCHAIN_ACCOUNTS.save(
+        deps.storage,
+        &info.sender,
+        &InterchainAccount {
+            controller_connection_id: controller_connection_id.clone(),
+            controller_owner: controller_owner.clone(),
+            transfer_channel_id,
+            verified: false,
+            registered_at: env.block.time.seconds(),
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "register_interchain_account")
+        .add_attribute("address", info.sender)
+        .add_attribute("controller_connection_id", controller_connection_id)
+        .add_attribute("controller_owner", controller_owner))
+}
+
+/// Attest that a registration matches the controller chain's record (compliance officer only).
+///
+/// Nothing is checked on chain: the officer's word is what turns on IBC payouts, so the response
+/// records the attested details and marks the verification as an unverified attestation.
+pub fn verify_interchain_account(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    roles::ensure_role(deps.storage, &info.sender, Role::ComplianceOfficer)?;
+    let address = deps.api.addr_validate(&address)?;
+    let mut account = INTERCHAIN_ACCOUNTS
+        .may_load(deps.storage, &address)?
+        .ok_or_else(|| ContractError::InvalidInput {
+            field: "address".to_string(),
+            message: "Address has not registered as an interchain account".to_string(),
+        })?;
+
+    account.verified = true;
+    INTERCHAIN_ACCOUNTS.save(deps.storage, &address, &account)?;
+    INTERCHAIN_OWNERS.save(
+        deps.storage,
+        (&account.transfer_channel_id, &account.controller_owner),
+        &address,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "verify_interchain_account")
+        .add_attribute("address", address)
+        .add_attribute("verified_by", info.sender)
+        .add_attribute("verification", "unverified_attestation")
+        .add_attribute("controller_connection_id", account.controller_connection_id)
+        .add_attribute("controller_owner", account.controller_owner)
+        .add_attribute("transfer_channel_id", account.transfer_channel_id))
+}
+
+/// Stop routing payouts over IBC; the account itself or a compliance officer can remove it
+pub fn remove_interchain_account(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    let address = deps.api.addr_validate(&address)?;
+    if info.sender != address {
+        roles::ensure_role(deps.storage, &info.sender, Role::ComplianceOfficer)?;
+    }
+    if !INTERCHAIN_ACCOUNTS.has(deps.storage, &address) {
+        return Err(ContractError::InvalidInput {
+            field: "address".to_string(),
+            message: "Address has not registered as an interchain account".to_string(),
+        });
+    }
+
+    INTERCHAIN_ACCOUNTS.remove(deps.storage, &address);
+
+    Ok(Response::new()
+        .add_attribute("method", "remove_interchain_account")
+        .add_attribute("address", address))
+}
+
+fn verified_account(storage: &dyn Storage, address: &Addr) -> StdResult<Option<InterchainAccount>> {
+    Ok(INTERCHAIN_ACCOUNTS
+        .may_load(storage, address)?
+        .filter(|account| account.verified))
+}
+
+/// Whether payouts to `address` leave Neutron over IBC
+pub fn routes_over_ibc(storage: &dyn Storage, address: &Addr) -> StdResult<bool> {
+    Ok(verified_account(storage, address)?.is_some())
+}
+
+/// Pay `amount` untrn to `recipient`, over IBC to the controller owner when the recipient is a
+/// verified interchain account
+pub fn payout_message(
+    storage: &dyn Storage,
+    env: &Env,
+    recipient: &Addr,
+    amount: Uint128,
+) -> StdResult<CosmosMsg> {
+    let Some(account) = verified_account(storage, recipient)? else {
+        return Ok(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: coins(amount.u128(), NATIVE_DENOM),
+        }
+        .into());
+    };
+
+    let callback = IbcCallbackRequest::source(IbcSrcCallback {
+        address: env.contract.address.clone(),
+        gas_limit: None,
+    });
+    Ok(IbcMsg::Transfer {
+        channel_id: account.transfer_channel_id,
+        to_address: account.controller_owner,
+        amount: cosmwasm_std::Coin::new(amount, NATIVE_DENOM),
+        timeout: IbcTimeout::with_timestamp(env.block.time.plus_seconds(PAYOUT_TIMEOUT_SECONDS)),
+        memo: Some(to_json_string(&callback)?),
+    }
+    .into())
+}
+
+/// Send a payout that never arrived to the host account on Neutron
+fn return_payout(storage: &dyn Storage, packet: &IbcPacket) -> StdResult<IbcBasicResponse> {
+    let data: Ics20Packet = from_json(&packet.data)?;
+    let event = crate::events::event("interchain_payout_returned")
+        .add_attribute("channel_id", &packet.src.channel_id)
+        .add_attribute("receiver", &data.receiver)
+        .add_attribute("amount", data.amount.to_string());
+    let host = INTERCHAIN_OWNERS.may_load(storage, (&packet.src.channel_id, &data.receiver))?;
+    let Some(host) = host else {
+        return Ok(IbcBasicResponse::new().add_event(event));
+    };
+
+    Ok(IbcBasicResponse::new()
+        .add_message(BankMsg::Send {
+            to_address: host.to_string(),
+            amount: coins(data.amount.u128(), NATIVE_DENOM),
+        })
+        .add_event(event.add_attribute("host", host)))
+}
+
+/// Acknowledgement or timeout of a payout transfer sent by `payout_message`
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_source_callback(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcSourceCallbackMsg,
+) -> StdResult<IbcBasicResponse> {
+    match msg {
+        IbcSourceCallbackMsg::Acknowledgement(ack) => {
+            let delivered = matches!(
+                from_json(&ack.acknowledgement.data),
+                Ok(Ics20Ack::Result(_))
+            );
+            if delivered {
+                return Ok(IbcBasicResponse::new());
+            }
+            return_payout(deps.storage, &ack.original_packet)
+        }
+        IbcSourceCallbackMsg::Timeout(timeout) => return_payout(deps.storage, &timeout.packet),
+    }
+}
+
+// Query functions
+pub fn query_interchain_account(
+    deps: Deps,
+    address: String,
+) -> StdResult<InterchainAccountResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let account = INTERCHAIN_ACCOUNTS.may_load(deps.storage, &address)?;
+    Ok(InterchainAccountResponse { address, account })
+}
@@ -0,0 +1,275 @@
+//! Reg CF per-investor annual limits.
+//!
+//! Every accepted investment counts towards the investor's platform-wide total for the last 12
+//! months. Investors with a limit profile, declared by themselves or set by a registry manager,
+//! cannot go past the Reg CF cap that their income and net worth allow. Accredited investors
+//! have no cap. Without a profile nothing is enforced.
+
+use cosmwasm_std::{
+    Addr, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult, Storage, Uint128,
+};
+use cw_storage_plus::{Bound, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+
+/// Income or net worth below this keeps the investor on the lower 5% tier ($124,000)
+const REG_CF_THRESHOLD: Uint128 = Uint128::new(124_000_000_000);
+/// Least any investor may put in over 12 months ($2,500)
+const REG_CF_FLOOR: Uint128 = Uint128::new(2_500_000_000);
+/// Most any non-accredited investor may put in over 12 months ($124,000)
+const REG_CF_CEILING: Uint128 = Uint128::new(124_000_000_000);
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LimitSource {
+    SelfDeclared,
+    Registry { set_by: Addr },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct InvestorLimitProfile {
+    pub annual_income: Uint128,
+    pub net_worth: Uint128,
+    pub source: LimitSource,
+    pub updated_at: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct InvestorLimitResponse {
+    pub investor: Addr,
+    pub profile: Option<InvestorLimitProfile>,
+    pub accredited: bool,
+    pub annual_limit: Option<Uint128>, // None when no cap applies
+    pub invested_in_window: Uint128,
+    pub remaining: Option<Uint128>,
+    pub window_start: u64,
+}
+
+// Storage keys
+pub const INVESTOR_LIMIT_PROFILES: Map<&Addr, InvestorLimitProfile> = Map::new("investor_limits");
+pub const INVESTOR_SPEND: Map<(&Addr, u64), Uint128> = Map::new("investor_spend"); // (investor, day) -> invested that day
+
+impl InvestorLimitProfile {
+    /// Most the investor may put into Reg CF offerings over 12 months
+    pub fn annual_limit(&self) -> Uint128 {
+        let greater = self.annual_income.max(self.net_worth);
+        if self.annual_income < REG_CF_THRESHOLD || self.net_worth < REG_CF_THRESHOLD {
+            greater.multiply_ratio(5u128, 100u128).max(REG_CF_FLOOR)
+        } else {
+            greater.multiply_ratio(10u128, 100u128).min(REG_CF_CEILING)
+        }
+    }
+}
+
+/// What the investor put in over the last 12 months, across every proposal
+pub fn invested_in_window(storage: &dyn Storage, investor: &Addr, now: u64) -> StdResult<Uint128> {
+    INVESTOR_SPEND
+        .prefix(investor)
+        .range(
+            storage,
+            Some(Bound::inclusive(crate::compliance::window_start_day(now))),
+            None,
+            Order::Ascending,
+        )
+        .map(|item| item.map(|(_, invested)| invested))
+        .sum()
+}
+
+/// The cap that applies to the investor right now, if any
+fn annual_limit(storage: &dyn Storage, env: &Env, investor: &Addr) -> StdResult<Option<Uint128>> {
+    if crate::kyc::is_accredited(storage, env, investor)? {
+        return Ok(None);
+    }
+    Ok(INVESTOR_LIMIT_PROFILES
+        .may_load(storage, investor)?
+        .map(|profile| profile.annual_limit()))
+}
+
+/// Reject an investment that would take the investor past their Reg CF annual limit
+pub fn enforce_annual_limit(
+    storage: &dyn Storage,
+    env: &Env,
+    investor: &Addr,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    let Some(limit) = annual_limit(storage, env, investor)? else {
+        return Ok(());
+    };
+    let invested = invested_in_window(storage, investor, env.block.time.seconds())?;
+    let total = invested
+        .checked_add(amount)
+        .map_err(|_| ContractError::Overflow {
+            operation: "investor annual limit check".to_string(),
+        })?;
+    if total > limit {
+        return Err(ContractError::InvestorAnnualLimitExceeded {
+            limit,
+            invested,
+            requested: amount,
+        });
+    }
+    Ok(())
+}
+
+/// Count an accepted investment towards the investor's rolling total
+pub fn record_spend(
+    storage: &mut dyn Storage,
+    investor: &Addr,
+    now: u64,
+    amount: Uint128,
+) -> StdResult<()> {
+    INVESTOR_SPEND.update(
+        storage,
+        (investor, crate::compliance::bucket_day(now)),
+        |invested| -> StdResult<_> { Ok(invested.unwrap_or_default().checked_add(amount)?) },
+    )?;
+    Ok(())
+}
+
+/// Take a withdrawn investment, made at `invested_at`, back off the investor's rolling total
+pub fn release_spend(
+    storage: &mut dyn Storage,
+    investor: &Addr,
+    invested_at: u64,
+    amount: Uint128,
+) -> StdResult<()> {
+    let key = (investor, crate::compliance::bucket_day(invested_at));
+    let remaining = INVESTOR_SPEND
+        .may_load(storage, key)?
+        .unwrap_or_default()
+        .saturating_sub(amount);
+    if remaining.is_zero() {
+        INVESTOR_SPEND.remove(storage, key);
+    } else {
+        INVESTOR_SPEND.save(storage, key, &remaining)?;
+    }
+    Ok(())
+}
+
+/// Investor states their own income and net worth; a registry-set profile cannot be replaced
+pub fn declare_investor_limits(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    annual_income: Uint128,
+    net_worth: Uint128,
+) -> Result<Response, ContractError> {
+    let existing = INVESTOR_LIMIT_PROFILES.may_load(deps.storage, &info.sender)?;
+    if matches!(
+        existing,
+        Some(InvestorLimitProfile {
+            source: LimitSource::Registry { .. },
+            ..
+        })
+    ) {
+        return Err(ContractError::InvalidInput {
+            field: "investor".to_string(),
+            message: "Limits set by the registry can only be changed by the registry".to_string(),
+        });
+    }
+
+    let profile = InvestorLimitProfile {
+        annual_income,
+        net_worth,
+        source: LimitSource::SelfDeclared,
+        updated_at: env.block.time.seconds(),
+    };
+    INVESTOR_LIMIT_PROFILES.save(deps.storage, &info.sender, &profile)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "declare_investor_limits")
+        .add_attribute("investor", info.sender)
+        .add_attribute("annual_limit", profile.annual_limit().to_string()))
+}
+
+/// Set an investor's limit profile from verified records (registry manager)
+pub fn set_investor_limits(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    investor: String,
+    annual_income: Uint128,
+    net_worth: Uint128,
+) -> Result<Response, ContractError> {
+    crate::kyc::ensure_registry_manager(deps.storage, &info.sender)?;
+    let investor = deps.api.addr_validate(&investor)?;
+
+    let profile = InvestorLimitProfile {
+        annual_income,
+        net_worth,
+        source: LimitSource::Registry {
+            set_by: info.sender.clone(),
+        },
+        updated_at: env.block.time.seconds(),
+    };
+    INVESTOR_LIMIT_PROFILES.save(deps.storage, &investor, &profile)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "set_investor_limits")
+        .add_attribute("investor", investor)
+        .add_attribute("annual_limit", profile.annual_limit().to_string()))
+}
+
+/// Drop an investor's limit profile, whoever set it (registry manager)
+pub fn clear_investor_limits(
+    deps: DepsMut,
+    info: MessageInfo,
+    investor: String,
+) -> Result<Response, ContractError> {
+    crate::kyc::ensure_registry_manager(deps.storage, &info.sender)?;
+    let investor = deps.api.addr_validate(&investor)?;
+    INVESTOR_LIMIT_PROFILES.remove(deps.storage, &investor);
+
+    Ok(Response::new()
+        .add_attribute("method", "clear_investor_limits")
+        .add_attribute("investor", investor))
+}
+
+// Query functions
+pub fn query_investor_limit(
+    deps: Deps,
+    env: Env,
+    investor: String,
+) -> StdResult<InvestorLimitResponse> {
+    let investor = deps.api.addr_validate(&investor)?;
+    let now = env.block.time.seconds();
+    let invested = invested_in_window(deps.storage, &investor, now)?;
+    let annual_limit = annual_limit(deps.storage, &env, &investor)?;
+
+    Ok(InvestorLimitResponse {
+        profile: INVESTOR_LIMIT_PROFILES.may_load(deps.storage, &investor)?,
+        accredited: crate::kyc::is_accredited(deps.storage, &env, &investor)?,
+        remaining: annual_limit.map(|limit| limit.saturating_sub(invested)),
+        annual_limit,
+        invested_in_window: invested,
+        window_start: crate::compliance::window_start(now),
+        investor,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(income: u128, net_worth: u128) -> InvestorLimitProfile {
+        InvestorLimitProfile {
+            annual_income: Uint128::new(income * 1_000_000),
+            net_worth: Uint128::new(net_worth * 1_000_000),
+            source: LimitSource::SelfDeclared,
+            updated_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_annual_limit_follows_reg_cf_tiers() {
+        let dollars = |limit: Uint128| limit.u128() / 1_000_000;
+        // Either figure below $124k: greater of $2,500 or 5% of the larger figure
+        assert_eq!(dollars(profile(30_000, 20_000).annual_limit()), 2_500);
+        assert_eq!(dollars(profile(80_000, 150_000).annual_limit()), 7_500);
+        // Both at or above $124k: 10% of the larger figure, at most $124k
+        assert_eq!(dollars(profile(200_000, 900_000).annual_limit()), 90_000);
+        assert_eq!(dollars(profile(500_000, 3_000_000).annual_limit()), 124_000);
+    }
+}
@@ -0,0 +1,86 @@
+use cosmwasm_std::{Addr, Deps, DepsMut, MessageInfo, Response, StdResult, Storage, Uint128};
+use cw_storage_plus::{Item, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+use crate::roles::{self, Role};
+use crate::treasury::{self, TreasuryAccount};
+
+/// Incentive paid to whoever runs permissionless maintenance
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct KeeperConfig {
+    pub reward_per_proposal: Uint128, // untrn per expired proposal processed; zero disables rewards
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct KeeperStats {
+    pub proposals_processed: u64,
+    pub rewards_paid: Uint128,
+    pub last_run: u64,
+}
+
+// Storage keys
+pub const KEEPER_CONFIG: Item<KeeperConfig> = Item::new("keeper_config");
+pub const KEEPER_STATS: Map<&Addr, KeeperStats> = Map::new("keeper_stats");
+
+/// Set the per-proposal keeper reward (operator only)
+pub fn update_keeper_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    reward_per_proposal: Uint128,
+) -> Result<Response, ContractError> {
+    roles::ensure_role(deps.storage, &info.sender, Role::Operator)?;
+
+    KEEPER_CONFIG.save(
+        deps.storage,
+        &KeeperConfig {
+            reward_per_proposal,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "update_keeper_config")
+        .add_attribute("reward_per_proposal", reward_per_proposal.to_string()))
+}
+
+/// Debit the keeper's reward for `processed` proposals from the fee bucket.
+///
+/// The reward is capped at what the fee bucket holds, so maintenance never fails because
+/// rewards ran dry; the caller sends whatever amount is returned.
+pub fn reward_keeper(
+    storage: &mut dyn Storage,
+    keeper: &Addr,
+    processed: u64,
+    now: u64,
+) -> StdResult<Uint128> {
+    let config = KEEPER_CONFIG.may_load(storage)?.unwrap_or_default();
+    let earned = config
+        .reward_per_proposal
+        .checked_mul(Uint128::from(processed))?;
+    let reward = earned.min(treasury::balance(storage, TreasuryAccount::Fees)?);
+    treasury::debit(storage, TreasuryAccount::Fees, reward)?;
+
+    if processed > 0 {
+        KEEPER_STATS.update(storage, keeper, |stats| -> StdResult<_> {
+            let mut stats = stats.unwrap_or_default();
+            stats.proposals_processed += processed;
+            stats.rewards_paid = stats.rewards_paid.checked_add(reward)?;
+            stats.last_run = now;
+            Ok(stats)
+        })?;
+    }
+    Ok(reward)
+}
+
+// Query functions
+pub fn query_keeper_config(deps: Deps) -> StdResult<KeeperConfig> {
+    Ok(KEEPER_CONFIG.may_load(deps.storage)?.unwrap_or_default())
+}
+
+pub fn query_keeper_stats(deps: Deps, keeper: String) -> StdResult<KeeperStats> {
+    let keeper = deps.api.addr_validate(&keeper)?;
+    Ok(KEEPER_STATS
+        .may_load(deps.storage, &keeper)?
+        .unwrap_or_default())
+}
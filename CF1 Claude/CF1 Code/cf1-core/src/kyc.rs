@@ -0,0 +1,279 @@
+use cosmwasm_std::{Addr, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult, Storage};
+use cw_storage_plus::{Bound, Item, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+use crate::gas_optimization::{DEFAULT_LIMIT, MAX_LIMIT};
+use crate::roles::{self, Role};
+use crate::state::Proposal;
+
+/// Verification level granted to an investor, ordered from least to most privileged
+#[derive(
+    Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum VerificationTier {
+    Retail,
+    Accredited,
+    Institutional,
+}
+
+impl VerificationTier {
+    /// Accredited and institutional investors may join accredited_only offerings
+    pub fn is_accredited(&self) -> bool {
+        *self >= VerificationTier::Accredited
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct VerifiedInvestor {
+    pub investor: Addr,
+    pub tier: VerificationTier,
+    pub verified_by: Addr,
+    pub verified_at: u64,
+    pub expires_at: Option<u64>, // None = never expires
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct KycConfig {
+    /// Address allowed to manage the registry alongside compliance officer role holders
+    pub compliance_officer: Option<Addr>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct VerificationStatusResponse {
+    pub investor: Addr,
+    pub is_verified: bool, // Present and not expired
+    pub record: Option<VerifiedInvestor>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct InvestorEligibilityResponse {
+    pub proposal_id: String,
+    pub investor: Addr,
+    pub eligible: bool,
+    pub is_accredited: bool,
+    pub reason: Option<String>, // Why the investor is currently blocked
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct VerifiedInvestorsResponse {
+    pub investors: Vec<VerifiedInvestor>,
+}
+
+// Storage keys
+pub const KYC_CONFIG: Item<KycConfig> = Item::new("kyc_config");
+pub const VERIFIED_INVESTORS: Map<&Addr, VerifiedInvestor> = Map::new("verified_investors");
+
+/// Initialize the registry with no compliance officer
+pub fn initialize(storage: &mut dyn Storage) -> StdResult<()> {
+    KYC_CONFIG.save(storage, &KycConfig::default())
+}
+
+/// Only compliance officers (by role or by designation) may perform compliance actions
+pub fn ensure_registry_manager(storage: &dyn Storage, sender: &Addr) -> Result<(), ContractError> {
+    let kyc_config = KYC_CONFIG.may_load(storage)?.unwrap_or_default();
+    if kyc_config.compliance_officer.as_ref() != Some(sender)
+        && !roles::has_role(storage, sender, Role::ComplianceOfficer)?
+    {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(())
+}
+
+fn is_current(record: &VerifiedInvestor, env: &Env) -> bool {
+    record
+        .expires_at
+        .is_none_or(|expires_at| env.block.time.seconds() < expires_at)
+}
+
+/// Whether the investor holds a current accredited (or higher) verification
+pub fn is_accredited(storage: &dyn Storage, env: &Env, investor: &Addr) -> StdResult<bool> {
+    Ok(VERIFIED_INVESTORS
+        .may_load(storage, investor)?
+        .is_some_and(|record| is_current(&record, env) && record.tier.is_accredited()))
+}
+
+/// Reject investors that do not meet the proposal's KYC / accreditation requirements
+pub fn enforce_verification(
+    storage: &dyn Storage,
+    env: &Env,
+    proposal: &Proposal,
+    investor: &Addr,
+) -> Result<(), ContractError> {
+    if !proposal.compliance.kyc_required && !proposal.compliance.accredited_only {
+        return Ok(());
+    }
+
+    let record = VERIFIED_INVESTORS.may_load(storage, investor)?.ok_or(
+        ContractError::InvestorNotVerified {
+            investor: investor.to_string(),
+        },
+    )?;
+
+    if !is_current(&record, env) {
+        return Err(ContractError::VerificationExpired {
+            investor: investor.to_string(),
+        });
+    }
+
+    if proposal.compliance.accredited_only && !record.tier.is_accredited() {
+        return Err(ContractError::AccreditationRequired {
+            investor: investor.to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Add or refresh an investor's verification (compliance officer)
+pub fn add_verified_investor(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    investor: String,
+    tier: VerificationTier,
+    expires_at: Option<u64>,
+) -> Result<Response, ContractError> {
+    ensure_registry_manager(deps.storage, &info.sender)?;
+
+    let investor_addr = deps.api.addr_validate(&investor)?;
+    let current_time = env.block.time.seconds();
+    if matches!(expires_at, Some(expires_at) if expires_at <= current_time) {
+        return Err(ContractError::InvalidInput {
+            field: "expires_at".to_string(),
+            message: "Verification expiry must be in the future".to_string(),
+        });
+    }
+
+    let record = VerifiedInvestor {
+        investor: investor_addr.clone(),
+        tier,
+        verified_by: info.sender.clone(),
+        verified_at: current_time,
+        expires_at,
+    };
+    VERIFIED_INVESTORS.save(deps.storage, &investor_addr, &record)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "add_verified_investor")
+        .add_attribute("investor", investor_addr)
+        .add_attribute("tier", format!("{:?}", tier))
+        .add_attribute("verified_by", info.sender))
+}
+
+/// Revoke an investor's verification (compliance officer)
+pub fn remove_verified_investor(
+    deps: DepsMut,
+    info: MessageInfo,
+    investor: String,
+) -> Result<Response, ContractError> {
+    ensure_registry_manager(deps.storage, &info.sender)?;
+
+    let investor_addr = deps.api.addr_validate(&investor)?;
+    if !VERIFIED_INVESTORS.has(deps.storage, &investor_addr) {
+        return Err(ContractError::InvestorNotVerified {
+            investor: investor_addr.to_string(),
+        });
+    }
+    VERIFIED_INVESTORS.remove(deps.storage, &investor_addr);
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_verified_investor")
+        .add_attribute("investor", investor_addr)
+        .add_attribute("removed_by", info.sender))
+}
+
+/// Designate (or clear) the compliance officer (owner only)
+pub fn set_compliance_officer(
+    deps: DepsMut,
+    info: MessageInfo,
+    officer: Option<String>,
+) -> Result<Response, ContractError> {
+    roles::ensure_role(deps.storage, &info.sender, Role::Owner)?;
+
+    let officer = officer.map(|o| deps.api.addr_validate(&o)).transpose()?;
+    KYC_CONFIG.save(
+        deps.storage,
+        &KycConfig {
+            compliance_officer: officer.clone(),
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "set_compliance_officer")
+        .add_attribute(
+            "officer",
+            officer.map_or_else(|| "none".to_string(), |o| o.to_string()),
+        ))
+}
+
+// Query functions
+pub fn query_verification_status(
+    deps: Deps,
+    env: Env,
+    investor: String,
+) -> StdResult<VerificationStatusResponse> {
+    let investor_addr = deps.api.addr_validate(&investor)?;
+    let record = VERIFIED_INVESTORS.may_load(deps.storage, &investor_addr)?;
+
+    Ok(VerificationStatusResponse {
+        investor: investor_addr,
+        is_verified: record.as_ref().is_some_and(|r| is_current(r, &env)),
+        record,
+    })
+}
+
+/// Check whether an investor currently meets a proposal's KYC, accreditation and whitelist requirements
+pub fn query_investor_eligibility(
+    deps: Deps,
+    env: Env,
+    proposal_id: String,
+    investor: String,
+) -> StdResult<InvestorEligibilityResponse> {
+    let investor_addr = deps.api.addr_validate(&investor)?;
+    let proposal = crate::state::PROPOSALS.load(deps.storage, proposal_id.clone())?;
+    let record = VERIFIED_INVESTORS.may_load(deps.storage, &investor_addr)?;
+
+    let reason = enforce_verification(deps.storage, &env, &proposal, &investor_addr)
+        .and_then(|_| {
+            crate::whitelist::enforce_whitelist(deps.storage, &proposal_id, &investor_addr)
+        })
+        .err()
+        .map(|err| err.to_string());
+
+    Ok(InvestorEligibilityResponse {
+        proposal_id,
+        investor: investor_addr,
+        eligible: reason.is_none(),
+        is_accredited: record
+            .as_ref()
+            .is_some_and(|r| is_current(r, &env) && r.tier.is_accredited()),
+        reason,
+    })
+}
+
+pub fn query_verified_investors(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<VerifiedInvestorsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_addr = start_after
+        .map(|s| deps.api.addr_validate(&s))
+        .transpose()?;
+    let start = start_addr.as_ref().map(Bound::exclusive);
+
+    let investors = VERIFIED_INVESTORS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, record)| record))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(VerifiedInvestorsResponse { investors })
+}
+
+pub fn query_kyc_config(deps: Deps) -> StdResult<KycConfig> {
+    Ok(KYC_CONFIG.may_load(deps.storage)?.unwrap_or_default())
+}
@@ -0,0 +1,248 @@
+use cosmwasm_std::{Addr, Deps, Env, Order, StdResult, Storage, Uint128};
+use cw_storage_plus::{Bound, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::gas_optimization::{DEFAULT_LIMIT, MAX_LIMIT};
+
+/// Kind of money movement recorded against a proposal
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LedgerEntryKind {
+    Investment,         // Investor funds moved into escrow
+    Refund,             // Escrowed funds returned to an investor
+    PlatformFee,        // Fee accrued to the platform out of escrow
+    CreatorPayout,      // Raised funds released to the creator
+    RetirementProceeds, // Final proceeds paid into escrow when the asset is retired
+    Redemption,         // Retirement proceeds paid to a holder for burned tokens
+}
+
+/// Side of a double-entry record
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LedgerAccount {
+    Investor,
+    Escrow,
+    Creator,
+    Platform,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LedgerEntry {
+    pub sequence: u64,
+    pub kind: LedgerEntryKind,
+    pub debit: LedgerAccount,
+    pub credit: LedgerAccount,
+    pub counterparty: Addr,
+    pub amount: Uint128,
+    pub denom: String,
+    pub block_height: u64,
+    pub timestamp: u64,
+    pub escrow_balance: Uint128, // Escrow balance after this entry
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct LedgerHead {
+    pub next_sequence: u64,
+    pub escrow_balance: Uint128,
+}
+
+/// Cash an investor has taken back out of one proposal's escrow
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct RealizedTotals {
+    pub refunded: Uint128,      // Principal returned by refunds and withdrawals
+    pub distributions: Uint128, // Retirement proceeds claimed for burned tokens
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ProposalLedgerResponse {
+    pub proposal_id: String,
+    pub entries: Vec<LedgerEntry>,
+    pub escrow_balance: Uint128,
+}
+
+// Storage keys
+pub const PROPOSAL_LEDGER: Map<(String, u64), LedgerEntry> = Map::new("ledger"); // (proposal_id, seq) -> entry
+pub const PROPOSAL_LEDGER_HEAD: Map<String, LedgerHead> = Map::new("ledger_head");
+pub const INVESTOR_REALIZED: Map<(&Addr, &str), RealizedTotals> = Map::new("ledger_realized"); // (investor, proposal_id)
+
+/// Append a money movement to the proposal's ledger
+pub fn record_entry(
+    storage: &mut dyn Storage,
+    env: &Env,
+    proposal_id: &str,
+    kind: LedgerEntryKind,
+    counterparty: &Addr,
+    amount: Uint128,
+) -> StdResult<()> {
+    if amount.is_zero() {
+        return Ok(());
+    }
+
+    let mut head = PROPOSAL_LEDGER_HEAD
+        .may_load(storage, proposal_id.to_string())?
+        .unwrap_or_default();
+
+    let (debit, credit) = match kind {
+        LedgerEntryKind::Investment => (LedgerAccount::Escrow, LedgerAccount::Investor),
+        LedgerEntryKind::Refund => (LedgerAccount::Investor, LedgerAccount::Escrow),
+        LedgerEntryKind::PlatformFee => (LedgerAccount::Platform, LedgerAccount::Escrow),
+        LedgerEntryKind::CreatorPayout => (LedgerAccount::Creator, LedgerAccount::Escrow),
+        LedgerEntryKind::RetirementProceeds => (LedgerAccount::Escrow, LedgerAccount::Creator),
+        LedgerEntryKind::Redemption => (LedgerAccount::Investor, LedgerAccount::Escrow),
+    };
+
+    head.escrow_balance = if debit == LedgerAccount::Escrow {
+        head.escrow_balance.checked_add(amount)?
+    } else {
+        head.escrow_balance.checked_sub(amount)?
+    };
+
+    let entry = LedgerEntry {
+        sequence: head.next_sequence,
+        kind,
+        debit,
+        credit,
+        counterparty: counterparty.clone(),
+        amount,
+        denom: "untrn".to_string(),
+        block_height: env.block.height,
+        timestamp: env.block.time.seconds(),
+        escrow_balance: head.escrow_balance,
+    };
+
+    PROPOSAL_LEDGER.save(storage, (proposal_id.to_string(), entry.sequence), &entry)?;
+    head.next_sequence += 1;
+    PROPOSAL_LEDGER_HEAD.save(storage, proposal_id.to_string(), &head)?;
+    realize(storage, proposal_id, &entry)?;
+    crate::activity::record_ledger_entry(storage, proposal_id, &entry)
+}
+
+/// Credit an investor's running realized totals for a payout out of escrow
+fn realize(storage: &mut dyn Storage, proposal_id: &str, entry: &LedgerEntry) -> StdResult<()> {
+    if !matches!(
+        entry.kind,
+        LedgerEntryKind::Refund | LedgerEntryKind::Redemption
+    ) {
+        return Ok(());
+    }
+    INVESTOR_REALIZED.update(
+        storage,
+        (&entry.counterparty, proposal_id),
+        |totals| -> StdResult<_> {
+            let mut totals = totals.unwrap_or_default();
+            if entry.kind == LedgerEntryKind::Refund {
+                totals.refunded = totals.refunded.checked_add(entry.amount)?;
+            } else {
+                totals.distributions = totals.distributions.checked_add(entry.amount)?;
+            }
+            Ok(totals)
+        },
+    )?;
+    Ok(())
+}
+
+/// Rebuild every investor's realized totals by replaying the recorded ledgers
+pub fn rebuild_realized(storage: &mut dyn Storage) -> StdResult<()> {
+    INVESTOR_REALIZED.clear(storage);
+    let entries = PROPOSAL_LEDGER
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for ((proposal_id, _), entry) in entries {
+        realize(storage, &proposal_id, &entry)?;
+    }
+    Ok(())
+}
+
+/// Paginated, ordered ledger for a proposal
+pub fn query_proposal_ledger(
+    deps: Deps,
+    proposal_id: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<ProposalLedgerResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let entries = PROPOSAL_LEDGER
+        .prefix(proposal_id.clone())
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, entry)| entry))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let escrow_balance = PROPOSAL_LEDGER_HEAD
+        .may_load(deps.storage, proposal_id.clone())?
+        .unwrap_or_default()
+        .escrow_balance;
+
+    Ok(ProposalLedgerResponse {
+        proposal_id,
+        entries,
+        escrow_balance,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_env, MockStorage};
+
+    #[test]
+    fn test_ledger_tracks_escrow_balance() {
+        let mut storage = MockStorage::new();
+        let env = mock_env();
+        let investor = Addr::unchecked("investor");
+        let creator = Addr::unchecked("creator");
+
+        record_entry(
+            &mut storage,
+            &env,
+            "p1",
+            LedgerEntryKind::Investment,
+            &investor,
+            Uint128::new(1_000),
+        )
+        .unwrap();
+        record_entry(
+            &mut storage,
+            &env,
+            "p1",
+            LedgerEntryKind::PlatformFee,
+            &creator,
+            Uint128::new(25),
+        )
+        .unwrap();
+        record_entry(
+            &mut storage,
+            &env,
+            "p1",
+            LedgerEntryKind::CreatorPayout,
+            &creator,
+            Uint128::new(975),
+        )
+        .unwrap();
+
+        let entries: Vec<LedgerEntry> = PROPOSAL_LEDGER
+            .prefix("p1".to_string())
+            .range(&storage, None, None, Order::Ascending)
+            .map(|item| item.unwrap().1)
+            .collect();
+
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].debit, LedgerAccount::Escrow);
+        assert_eq!(entries[1].escrow_balance, Uint128::new(975));
+        assert_eq!(entries[2].escrow_balance, Uint128::zero());
+
+        // Escrow can never go negative
+        assert!(record_entry(
+            &mut storage,
+            &env,
+            "p1",
+            LedgerEntryKind::Refund,
+            &investor,
+            Uint128::new(1)
+        )
+        .is_err());
+    }
+}
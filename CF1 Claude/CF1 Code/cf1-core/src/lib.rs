@@ -1,34 +1,83 @@
 use cosmwasm_std::{
-    entry_point, to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env,
-    MessageInfo, ReplyOn, Response, StdResult, SubMsg, SubMsgResult, Timestamp, Uint128, WasmMsg,
+    entry_point, to_json_binary, to_json_vec, Addr, BankMsg, Binary, Coin, CosmosMsg, Deps,
+    DepsMut, Env, MessageInfo, ReplyOn, Response, StdResult, SubMsg, SubMsgResult, Uint128,
+    WasmMsg,
 };
 use cw2::set_contract_version;
-use cw20::{Cw20ExecuteMsg, MinterResponse};
+use cw20::MinterResponse;
 use cw20_base::msg::InstantiateMsg as Cw20InstantiateMsg;
 
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
-use crate::security::{MathGuard, ReentrancyGuard};
+use crate::gas_optimization::{GasOptimizedStorage, GasOptimizer};
+use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg, SudoMsg};
+use crate::roles::Role;
+use crate::security::{InputValidator, MathGuard, ReentrancyGuard};
 use crate::state::{
-    generate_proposal_id, Config, Creator, Investment, InvestmentStatus, Proposal, ProposalStatus,
-    Timestamps, CONFIG, CREATORS, CREATOR_PROPOSAL_COUNT, DEFAULT_PLATFORM_FEE_BPS, INVESTMENTS,
-    MAX_FUNDING_PERIOD_DAYS, MIN_FUNDING_PERIOD_DAYS, PENDING_TOKEN_REPLY, PROPOSALS,
-    PROPOSAL_COUNT, PROPOSAL_INVESTMENTS, PROPOSAL_INVESTOR_COUNT, TOKEN_CONTRACTS,
-    USER_INVESTMENTS,
+    generate_proposal_id, save_proposal, Config, Creator, Investment, InvestmentStatus, Proposal,
+    ProposalStatus, Timestamps, CONFIG, CREATORS, CREATOR_PROPOSAL_COUNT, DEFAULT_PLATFORM_FEE_BPS,
+    DISTRIBUTION_PROGRESS, ESCROWED_SHARES, INVESTMENTS, MAX_FUNDING_PERIOD_DAYS,
+    MIN_FUNDING_PERIOD_DAYS, PROPOSALS, PROPOSAL_COUNT, PROPOSAL_INVESTMENTS, TOKEN_CONTRACTS,
+    TOKEN_PROPOSALS, USER_INVESTMENTS,
 };
 
+mod activity;
+mod amendments;
+mod audit;
+mod blacklist;
+mod bonds;
 mod compliance;
+mod concentration;
+mod cosign;
+mod custody;
+mod distribution;
+mod documents;
 pub mod error;
+mod escrow;
+pub mod events;
+mod fees;
+mod freeze;
 mod gas_optimization;
 mod gas_monitor;
 mod governance;
+mod governance_hooks;
 mod helpers;
+pub mod ibc;
+mod idempotency;
+pub mod interchain;
+mod investor_limits;
+mod keeper;
+mod kyc;
+mod ledger;
 mod lockup;
+mod math;
+mod milestones;
 pub mod msg;
+mod notifications;
 mod oracle;
+mod pause;
+mod pricing;
+mod profiles;
 mod rate_limit;
+mod receipts;
+mod reconfirmation;
+mod referral;
+mod retirement;
+mod review;
+mod revocation;
+mod roles;
+mod scheduler;
 mod security;
+mod signatures;
+mod simulation;
 pub mod state;
+mod tax_lots;
+mod token_metadata;
+mod transfer;
+mod treasury;
+mod trending;
+mod verification;
+mod vesting;
+mod whitelist;
 
 #[cfg(test)]
 mod tests;
@@ -41,6 +90,11 @@ const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 // Reply IDs for submessages
 const REPLY_INSTANTIATE_TOKEN: u64 = 1;
+const REPLY_RELEASE_LOCK: u64 = 2;
+const REPLY_ESCROW_DEPOSIT: u64 = 4;
+const REPLY_GOVERNANCE_HOOK: u64 = 5;
+const REPLY_DISTRIBUTION_MINT: u64 = 6;
+const REPLY_GOVERNANCE_ACTION: u64 = 7;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -62,6 +116,14 @@ pub fn instantiate(
         max_funding_period_days: MAX_FUNDING_PERIOD_DAYS,
         lockup_period_seconds: crate::state::LOCKUP_PERIOD_SECONDS,
         cw20_code_id: msg.cw20_code_id,
+        fee_schedule: vec![],
+        escrow_contract: None,
+        verification_required_above: None,
+        require_proposal_review: false,
+        governance_contract: None,
+        min_investment_amount: Some(crate::state::DEFAULT_MIN_INVESTMENT_AMOUNT),
+        max_investment_amount: None,
+        creation_bond: None,
     };
 
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
@@ -71,13 +133,48 @@ pub fn instantiate(
     // Initialize rate limiting
     rate_limit::RateLimiter::initialize(deps.storage)?;
 
-    Ok(Response::new()
-        .add_attribute("action", "init")
-        .add_attribute("admin", config.admin))
+    // Co-signing for large tickets starts disabled
+    cosign::initialize(deps.storage)?;
+
+    // KYC registry starts empty, managed by the admin until an officer is designated
+    kyc::initialize(deps.storage)?;
+
+    // Asset-level governance with default quorum and threshold
+    governance::initialize(deps.storage)?;
+
+    Ok(events::standardize(
+        Response::new()
+            .add_attribute("action", "init")
+            .add_attribute("admin", config.admin),
+    ))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    pause::ensure_not_paused(deps.storage, &msg)?;
+    let audit = audit::AuditScope::begin(deps.storage)?;
+    let actor = info.sender.clone();
+    let msg_json = to_json_vec(&msg)?;
+
+    let response = dispatch_execute(deps.branch(), env.clone(), info, msg)?;
+    let response = notifications::flush(deps.storage, response)?;
+    let response = gas_monitor::record(
+        deps.storage,
+        &gas_monitor::operation_name(&msg_json),
+        msg_json.len(),
+        response,
+    )?;
+
+    audit.finish(deps.storage, &env, &actor, &response)?;
+    Ok(events::standardize(response))
+}
+
+fn dispatch_execute(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
@@ -106,13 +203,115 @@ pub fn execute(
         ExecuteMsg::CancelProposal { proposal_id } => {
             execute_cancel_proposal(deps, env, info, proposal_id)
         }
-        ExecuteMsg::Invest { proposal_id } => execute_invest(deps, env, info, proposal_id),
+        ExecuteMsg::SubmitProposal { proposal_id } => {
+            review::submit_proposal(deps, env, info, proposal_id)
+        }
+        ExecuteMsg::ApproveProposal { proposal_id } => {
+            review::approve_proposal(deps, env, info, proposal_id)
+        }
+        ExecuteMsg::RejectProposal {
+            proposal_id,
+            reason,
+        } => review::reject_proposal(deps, env, info, proposal_id, reason),
+        ExecuteMsg::RejectProposalForFraud {
+            proposal_id,
+            reason,
+        } => review::reject_proposal_for_fraud(deps, env, info, proposal_id, reason),
+        ExecuteMsg::FreezeProposal {
+            proposal_id,
+            reason,
+        } => freeze::freeze_proposal(deps, env, info, proposal_id, reason),
+        ExecuteMsg::UnfreezeProposal { proposal_id } => {
+            freeze::unfreeze_proposal(deps, info, proposal_id)
+        }
+        ExecuteMsg::BlacklistCreator { creator, reason } => {
+            blacklist::blacklist_creator(deps, env, info, creator, reason)
+        }
+        ExecuteMsg::UnblacklistCreator { creator } => {
+            blacklist::unblacklist_creator(deps, info, creator)
+        }
+        ExecuteMsg::AppealBlacklist { statement } => {
+            blacklist::appeal_blacklist(deps, env, info, statement)
+        }
+        ExecuteMsg::DismissBlacklistAppeal { creator } => {
+            blacklist::dismiss_blacklist_appeal(deps, env, info, creator)
+        }
+        ExecuteMsg::ResubmitProposal {
+            proposal_id,
+            asset_details,
+            documents,
+        } => review::resubmit_proposal(deps, env, info, proposal_id, asset_details, documents),
+        ExecuteMsg::ExtendFundingDeadline {
+            proposal_id,
+            new_deadline,
+        } => execute_extend_funding_deadline(deps, env, info, proposal_id, new_deadline),
+        ExecuteMsg::FinalizeFunding { proposal_id } => {
+            execute_finalize_funding(deps, env, info, proposal_id)
+        }
+        ExecuteMsg::Invest {
+            proposal_id,
+            operation_id,
+            referrer,
+        } => execute_invest(deps, env, info, proposal_id, operation_id, referrer),
+        ExecuteMsg::InvestFor {
+            proposal_id,
+            beneficiary,
+        } => custody::invest_for(deps, env, info, proposal_id, beneficiary),
+        ExecuteMsg::InvestFromIbc {
+            proposal_id,
+            beneficiary,
+        } => ibc::invest_from_ibc(deps, env, info, proposal_id, beneficiary),
+        ExecuteMsg::ConfirmInvestment { ticket_id } => {
+            cosign::confirm_investment(deps, env, info, ticket_id)
+        }
+        ExecuteMsg::ConfirmInvestmentSigned {
+            ticket_id,
+            authorization,
+        } => cosign::confirm_investment_signed(deps, env, ticket_id, authorization),
+        ExecuteMsg::ReclaimPendingInvestment {
+            ticket_id,
+            operation_id,
+        } => cosign::reclaim_pending_investment(deps, env, info, ticket_id, operation_id),
+        ExecuteMsg::RegisterSecondaryKey { key } => cosign::register_secondary_key(deps, info, key),
+        ExecuteMsg::RegisterSigningKey { pubkey } => {
+            signatures::register_signing_key(deps, info, pubkey)
+        }
+        ExecuteMsg::SetDocumentAuditor {
+            proposal_id,
+            auditor,
+        } => documents::set_document_auditor(deps, info, proposal_id, auditor),
+        ExecuteMsg::AttestDocument {
+            proposal_id,
+            document_name,
+            signature,
+        } => documents::attest_document(deps, env, info, proposal_id, document_name, signature),
         ExecuteMsg::RefundInvestors { proposal_id } => {
             execute_refund_investors(deps, env, info, proposal_id)
         }
+        ExecuteMsg::ClaimRefund { proposal_id } => {
+            execute_claim_refund(deps, env, info, proposal_id)
+        }
         ExecuteMsg::MintTokens { proposal_id } => execute_mint_tokens(deps, env, info, proposal_id),
+        ExecuteMsg::RetryMintTokens { proposal_id } => {
+            execute_retry_mint_tokens(deps, env, info, proposal_id)
+        }
         ExecuteMsg::DistributeTokens { proposal_id } => {
-            execute_distribute_tokens(deps, env, info, proposal_id)
+            execute_distribute_tokens(deps, env, info, proposal_id, usize::MAX)
+        }
+        ExecuteMsg::DistributeTokensBatch { proposal_id, limit } => {
+            let batch_size = limit
+                .unwrap_or(state::DEFAULT_DISTRIBUTION_BATCH)
+                .clamp(1, state::MAX_DISTRIBUTION_BATCH) as usize;
+            execute_distribute_tokens(deps, env, info, proposal_id, batch_size)
+        }
+        ExecuteMsg::FinalizeDistribution { proposal_id } => {
+            execute_finalize_distribution(deps, env, info, proposal_id)
+        }
+        ExecuteMsg::RetryFailedMints { proposal_id, limit } => {
+            distribution::retry_failed_mints(deps, info, proposal_id, limit)
+        }
+        ExecuteMsg::ClaimVestedTokens { proposal_id } => {
+            vesting::claim_vested_tokens(deps, env, info, proposal_id)
         }
         ExecuteMsg::UpdateConfig {
             admin,
@@ -129,10 +328,54 @@ pub fn execute(
             max_funding_period_days,
             cw20_code_id,
         ),
-        ExecuteMsg::ProcessExpiredProposals {} => {
-            execute_process_expired_proposals(deps, env, info)
+        ExecuteMsg::SetInvestmentBounds {
+            min_investment_amount,
+            max_investment_amount,
+        } => {
+            execute_set_investment_bounds(deps, info, min_investment_amount, max_investment_amount)
+        }
+        ExecuteMsg::SetCreationBond { amount } => bonds::set_creation_bond(deps, info, amount),
+        ExecuteMsg::SetEmitGasClass { enabled } => {
+            gas_monitor::set_emit_gas_class(deps, info, enabled)
+        }
+        ExecuteMsg::Pause { reason } => pause::pause(deps, env, info, reason),
+        ExecuteMsg::Unpause {} => pause::unpause(deps, info),
+        ExecuteMsg::GrantRole { address, role } => roles::grant_role(deps, info, address, role),
+        ExecuteMsg::RevokeRole { address, role } => roles::revoke_role(deps, info, address, role),
+        ExecuteMsg::SetFeeSchedule { brackets } => fees::set_fee_schedule(deps, info, brackets),
+        ExecuteMsg::SetProposalFeeOverride {
+            proposal_id,
+            fee_bps,
+        } => fees::set_proposal_fee_override(deps, info, proposal_id, fee_bps),
+        ExecuteMsg::SetProposalReviewRequired { required } => {
+            review::set_review_required(deps, info, required)
+        }
+        ExecuteMsg::SetEscrowContract { address } => {
+            escrow::set_escrow_contract(deps, info, address)
+        }
+        ExecuteMsg::SetGovernanceContract { address } => {
+            governance_hooks::set_governance_contract(deps, info, address)
+        }
+        ExecuteMsg::RetryGovernanceHook { idempotency_key } => {
+            governance_hooks::retry_governance_hook(deps, idempotency_key)
+        }
+        ExecuteMsg::ProcessExpiredProposals { start_after, limit } => {
+            execute_process_expired_proposals(deps, env, info, start_after, limit)
         }
-        ExecuteMsg::ProcessExpiredLockups {} => lockup::process_expired_lockups(deps, &env, info),
+        ExecuteMsg::ProcessExpiredLockups { start_after, limit } => {
+            lockup::process_expired_lockups(deps, &env, info, start_after, limit)
+        }
+        ExecuteMsg::UpdateKeeperConfig {
+            reward_per_proposal,
+        } => keeper::update_keeper_config(deps, info, reward_per_proposal),
+        ExecuteMsg::UpdateReferralConfig { fee_bps } => {
+            referral::update_referral_config(deps, info, fee_bps)
+        }
+        ExecuteMsg::ClaimReferralRewards {} => referral::claim_referral_rewards(deps, info),
+        ExecuteMsg::RunScheduledMaintenance { limit } => {
+            scheduler::run_scheduled_maintenance(deps, &env, limit)
+        }
+        ExecuteMsg::PruneRateLimitData { limit } => execute_prune_rate_limit_data(deps, env, limit),
         ExecuteMsg::UpdateRateLimitConfig {
             window_seconds,
             max_operations,
@@ -143,6 +386,220 @@ pub fn execute(
             max_per_window,
             window_seconds,
         } => execute_update_operation_limit(deps, info, operation, max_per_window, window_seconds),
+        ExecuteMsg::AddRateLimitExemption {
+            address,
+            multiplier,
+        } => execute_add_rate_limit_exemption(deps, info, address, multiplier),
+        ExecuteMsg::RemoveRateLimitExemption { address } => {
+            execute_remove_rate_limit_exemption(deps, info, address)
+        }
+        ExecuteMsg::UpdateCosignConfig {
+            threshold,
+            approval_window_seconds,
+        } => cosign::update_cosign_config(deps, info, threshold, approval_window_seconds),
+        ExecuteMsg::AddVerifiedInvestor {
+            investor,
+            tier,
+            expires_at,
+        } => kyc::add_verified_investor(deps, env, info, investor, tier, expires_at),
+        ExecuteMsg::RemoveVerifiedInvestor { investor } => {
+            kyc::remove_verified_investor(deps, info, investor)
+        }
+        ExecuteMsg::SetComplianceOfficer { officer } => {
+            kyc::set_compliance_officer(deps, info, officer)
+        }
+        ExecuteMsg::DeclareInvestorLimits {
+            annual_income,
+            net_worth,
+        } => investor_limits::declare_investor_limits(deps, env, info, annual_income, net_worth),
+        ExecuteMsg::SetInvestorLimits {
+            investor,
+            annual_income,
+            net_worth,
+        } => investor_limits::set_investor_limits(
+            deps,
+            env,
+            info,
+            investor,
+            annual_income,
+            net_worth,
+        ),
+        ExecuteMsg::ClearInvestorLimits { investor } => {
+            investor_limits::clear_investor_limits(deps, info, investor)
+        }
+        ExecuteMsg::AddCustodian { address } => custody::add_custodian(deps, info, address),
+        ExecuteMsg::RemoveCustodian { address } => custody::remove_custodian(deps, info, address),
+        ExecuteMsg::RegisterInterchainAccount {
+            controller_connection_id,
+            controller_owner,
+            transfer_channel_id,
+        } => interchain::register_interchain_account(
+            deps,
+            env,
+            info,
+            controller_connection_id,
+            controller_owner,
+            transfer_channel_id,
+        ),
+        ExecuteMsg::VerifyInterchainAccount { address } => {
+            interchain::verify_interchain_account(deps, info, address)
+        }
+        ExecuteMsg::RemoveInterchainAccount { address } => {
+            interchain::remove_interchain_account(deps, info, address)
+        }
+        ExecuteMsg::SetCreatorVerification { creator, tier } => {
+            verification::set_creator_verification(deps, env, info, creator, tier)
+        }
+        ExecuteMsg::SetVerificationThreshold { threshold } => {
+            verification::set_verification_threshold(deps, info, threshold)
+        }
+        ExecuteMsg::UpdateCreatorProfile {
+            name,
+            bio,
+            website,
+            logo_hash,
+        } => profiles::update_creator_profile(deps, env, info, name, bio, website, logo_hash),
+        ExecuteMsg::SetProposalWhitelist {
+            proposal_id,
+            investors,
+        } => whitelist::set_proposal_whitelist(deps, info, proposal_id, investors),
+        ExecuteMsg::RevokeAndReissueShares {
+            proposal_id,
+            holder,
+            reissue_to,
+            reason,
+        } => revocation::revoke_and_reissue_shares(
+            deps,
+            env,
+            info,
+            proposal_id,
+            holder,
+            reissue_to,
+            reason,
+        ),
+        ExecuteMsg::TransferShares {
+            proposal_id,
+            recipient,
+            shares,
+        } => transfer::transfer_shares(deps, env, info, proposal_id, recipient, shares),
+        ExecuteMsg::ApproveTransfer {
+            owner,
+            recipient,
+            amount,
+        } => transfer::approve_token_transfer(deps, env, info, owner, recipient, amount),
+        ExecuteMsg::RetireAsset { proposal_id } => {
+            retirement::retire_asset(deps, env, info, proposal_id)
+        }
+        ExecuteMsg::RedeemShares { proposal_id } => {
+            retirement::redeem_shares(deps, env, info, proposal_id)
+        }
+        ExecuteMsg::AmendFinancialTerms {
+            proposal_id,
+            terms,
+            reason,
+        } => amendments::amend_financial_terms(deps, env, info, proposal_id, terms, reason),
+        ExecuteMsg::VoteOnAmendment { proposal_id, vote } => {
+            amendments::vote_on_amendment(deps, env, info, proposal_id, vote)
+        }
+        ExecuteMsg::ResolveAmendment { proposal_id } => {
+            amendments::resolve_amendment(deps, env, proposal_id)
+        }
+        ExecuteMsg::CreateGovernanceProposal {
+            proposal_id,
+            title,
+            description,
+            action,
+        } => governance::create_governance_proposal(
+            deps,
+            env,
+            info,
+            proposal_id,
+            title,
+            description,
+            action,
+        ),
+        ExecuteMsg::CastVote {
+            governance_id,
+            vote,
+        } => governance::cast_vote(deps, env, info, governance_id, vote),
+        ExecuteMsg::ExecuteGovernanceProposal { governance_id } => {
+            governance::execute_governance_proposal(deps, env, governance_id)
+        }
+        ExecuteMsg::ContinueGovernanceSnapshot { governance_id } => {
+            governance::continue_governance_snapshot(deps, governance_id)
+        }
+        ExecuteMsg::ApplyGovernanceAction { governance_id } => {
+            governance::apply_governance_action(deps, env, info, governance_id)
+        }
+        ExecuteMsg::UpdateGovernanceConfig {
+            quorum_bps,
+            threshold_bps,
+            voting_period_seconds,
+        } => governance::update_governance_config(
+            deps,
+            info,
+            quorum_bps,
+            threshold_bps,
+            voting_period_seconds,
+        ),
+        ExecuteMsg::SetPropertyManager {
+            proposal_id,
+            manager,
+        } => governance::set_property_manager(deps, env, info, proposal_id, manager),
+        ExecuteMsg::AddOracle { oracle } => oracle::add_oracle(deps, env, info, oracle),
+        ExecuteMsg::RemoveOracle { oracle } => oracle::remove_oracle(deps, info, oracle),
+        ExecuteMsg::UpdateAssetValuation {
+            proposal_id,
+            valuation,
+            source,
+        } => oracle::update_asset_valuation(deps, env, info, proposal_id, valuation, source),
+        ExecuteMsg::SetPriceFeed {
+            contract,
+            provider,
+            max_staleness_seconds,
+        } => oracle::set_price_feed(deps, info, contract, provider, max_staleness_seconds),
+        ExecuteMsg::TreasuryTransfer {
+            from,
+            to,
+            amount,
+            memo,
+        } => treasury::execute_internal_transfer(deps, env, info, from, to, amount, memo),
+        ExecuteMsg::FundTreasury { account } => treasury::execute_fund_account(deps, info, account),
+        ExecuteMsg::WithdrawTreasury { amount, recipient } => {
+            treasury::execute_withdraw(deps, env, info, amount, recipient)
+        }
+        ExecuteMsg::PostMaterialChange {
+            proposal_id,
+            description,
+            asset_details,
+            documents,
+        } => reconfirmation::post_material_change(
+            deps,
+            env,
+            info,
+            proposal_id,
+            description,
+            asset_details,
+            documents,
+        ),
+        ExecuteMsg::ReconfirmInvestment { proposal_id } => {
+            reconfirmation::reconfirm_investment(deps, env, info, proposal_id)
+        }
+        ExecuteMsg::WithdrawInvestment {
+            proposal_id,
+            operation_id,
+        } => reconfirmation::withdraw_investment(deps, env, info, proposal_id, operation_id),
+        ExecuteMsg::ResolveReconfirmation { proposal_id } => {
+            reconfirmation::resolve_reconfirmation(deps, env, proposal_id)
+        }
+        ExecuteMsg::ApproveMilestone {
+            proposal_id,
+            milestone_index,
+        } => milestones::approve_milestone(deps, env, info, proposal_id, milestone_index),
+        ExecuteMsg::ReleaseMilestone {
+            proposal_id,
+            milestone_index,
+        } => milestones::release_milestone(deps, env, info, proposal_id, milestone_index),
     }
 }
 
@@ -151,12 +608,18 @@ fn execute_create_proposal(
     env: Env,
     info: MessageInfo,
     asset_details: crate::state::AssetDetails,
-    financial_terms: crate::state::FinancialTerms,
+    mut financial_terms: crate::state::FinancialTerms,
     documents: Vec<crate::state::Document>,
     compliance: crate::state::ComplianceInfo,
 ) -> Result<Response, ContractError> {
-    // Check rate limit
-    rate_limit::RateLimiter::record_operation(deps.storage, &info.sender, "create_proposal", &env)?;
+    // Check rate limit; the operation is only counted once it succeeds
+    rate_limit::RateLimiter::ensure_within_limit(
+        deps.storage,
+        &info.sender,
+        "create_proposal",
+        &env,
+    )?;
+    blacklist::ensure_not_blacklisted(deps.storage, &info.sender)?;
 
     let config = CONFIG.load(deps.storage)?;
 
@@ -182,6 +645,20 @@ fn execute_create_proposal(
         return Err(ContractError::FundingPeriodTooLong {});
     }
 
+    // USD-denominated terms are converted to untrn at the current NTRN/USD rate
+    if let Some(usd_terms) = &financial_terms.usd_terms {
+        if usd_terms.target_amount_usd.is_zero() {
+            return Err(ContractError::InvalidTargetAmount {});
+        }
+        if usd_terms.minimum_investment_usd > usd_terms.target_amount_usd {
+            return Err(ContractError::InvalidInput {
+                field: "minimum_investment_usd".to_string(),
+                message: "Minimum investment cannot exceed the target".to_string(),
+            });
+        }
+    }
+    oracle::apply_usd_terms(deps.as_ref(), &env, &mut financial_terms, Uint128::zero())?;
+
     // Validate financial terms
     if financial_terms.target_amount.is_zero() {
         return Err(ContractError::InvalidTargetAmount {});
@@ -192,12 +669,73 @@ fn execute_create_proposal(
     if financial_terms.total_shares == 0 {
         return Err(ContractError::InvalidTotalShares {});
     }
+    if financial_terms.expected_apy_bps > crate::state::MAX_EXPECTED_APY_BPS {
+        return Err(ContractError::InvalidInput {
+            field: "expected_apy_bps".to_string(),
+            message: format!(
+                "Expected APY cannot exceed {}",
+                math::format_percentage(crate::state::MAX_EXPECTED_APY_BPS as u128, 10_000)
+            ),
+        });
+    }
+    if financial_terms.minimum_raise().is_zero()
+        || financial_terms.minimum_raise() > financial_terms.target_amount
+    {
+        return Err(ContractError::InvalidInput {
+            field: "soft_cap".to_string(),
+            message: "Soft cap must be non-zero and at most the target".to_string(),
+        });
+    }
+    vesting::validate(&financial_terms)?;
+    verification::ensure_verified_for(
+        deps.as_ref(),
+        &config,
+        &info.sender,
+        financial_terms.target_amount,
+    )?;
+    compliance::enforce_issuer_limit(
+        deps.storage,
+        &info.sender,
+        current_time,
+        financial_terms.funding_cap(),
+    )?;
+    // Shares sold past target have to exist, so the cap is bounded by the share supply
+    let share_supply_value = MathGuard::safe_mul(
+        Uint128::from(financial_terms.sellable_shares()),
+        financial_terms.token_price,
+    )?;
+    if financial_terms.funding_cap() < financial_terms.target_amount
+        || (financial_terms.max_raise.is_some()
+            && financial_terms.funding_cap() > share_supply_value)
+    {
+        return Err(ContractError::InvalidInput {
+            field: "max_raise".to_string(),
+            message: "Max raise must be at least the target and covered by total shares"
+                .to_string(),
+        });
+    }
+    pricing::validate_tiers(&financial_terms)?;
+    milestones::validate_schedule(&financial_terms.milestones)?;
+    lockup::validate_tranches(&financial_terms.lockup_tranches)?;
+    reconfirmation::validate_withdrawal_terms(&financial_terms.withdrawal_terms)?;
 
     // Generate proposal ID
     let mut count = PROPOSAL_COUNT.load(deps.storage)?;
     count += 1;
     let proposal_id = generate_proposal_id(count);
     let funding_deadline = financial_terms.funding_deadline;
+    token_metadata::validate(&asset_details.token_metadata)?;
+    token_metadata::reserve_symbol(deps.storage, &financial_terms, &proposal_id)?;
+    // Every listed document starts its history at version 1
+    let mut listed_documents = vec![];
+    documents::publish(
+        deps.storage,
+        &env,
+        &info.sender,
+        &proposal_id,
+        &mut listed_documents,
+        documents,
+    )?;
 
     // Create proposal
     let proposal = Proposal {
@@ -211,7 +749,7 @@ fn execute_create_proposal(
             is_funded: false,
             tokens_minted: false,
         },
-        documents,
+        documents: listed_documents,
         compliance,
         timestamps: Timestamps {
             created_at: current_time,
@@ -219,11 +757,11 @@ fn execute_create_proposal(
             funding_deadline,
             lockup_end: None,
         },
-        status: ProposalStatus::Active,
+        status: review::initial_status(&config),
     };
 
-    // Save proposal and update count
-    PROPOSALS.save(deps.storage, proposal_id.clone(), &proposal)?;
+    // Save proposal (with its hot data) and update count
+    save_proposal(deps.storage, &proposal)?;
     PROPOSAL_COUNT.save(deps.storage, &count)?;
 
     // Update creator proposal count
@@ -235,11 +773,12 @@ fn execute_create_proposal(
     if !CREATORS.has(deps.storage, &info.sender) {
         let creator = Creator {
             addr: info.sender.clone(),
-            name: "".to_string(), // Can be updated later
+            name: profiles::display_name(deps.storage, &info.sender)?,
             total_raised: Uint128::zero(),
             successful_proposals: 0,
             total_proposals: 1,
             established: current_time,
+            verification: verification::VerificationTier::Unverified,
         };
         CREATORS.save(deps.storage, &info.sender, &creator)?;
     } else {
@@ -250,27 +789,29 @@ fn execute_create_proposal(
         })?;
     }
 
-    // Save hot data for efficient access
-    let hot_data = crate::state::ProposalHotData {
-        status: ProposalStatus::Active,
-        target_amount: proposal.financial_terms.target_amount,
-        raised_amount: Uint128::zero(),
-        investor_count: 0,
-        funding_deadline,
-        creator: info.sender.clone(),
-    };
-    crate::state::PROPOSAL_HOT_DATA.save(deps.storage, proposal_id.clone(), &hot_data)?;
+    // Add to creator index (keyed by sequence so proposals from the same block don't collide)
+    crate::state::CREATOR_PROPOSAL_INDEX.save(deps.storage, (&info.sender, count), &proposal_id)?;
 
-    // Add to active proposals index
-    crate::state::ACTIVE_PROPOSALS.save(deps.storage, current_time, &proposal_id)?;
+    // The max raise is held against the creator's Reg CF allowance until the raise settles
+    compliance::reserve_issuer_capacity(
+        deps.storage,
+        &info.sender,
+        &proposal_id,
+        current_time,
+        proposal.financial_terms.funding_cap(),
+    )?;
 
-    // Add to creator index
-    crate::state::CREATOR_PROPOSAL_INDEX.save(deps.storage, (&info.sender, current_time), &proposal_id)?;
+    // Held until the proposal settles
+    let bond = bonds::post_bond(deps.storage, &env, &config, &proposal_id, &info.funds)?;
+
+    rate_limit::RateLimiter::record_operation(deps.storage, &info.sender, "create_proposal", &env)?;
 
     Ok(Response::new()
         .add_attribute("action", "create")
-        .add_attribute("id", &proposal_id)
-        .add_attribute("creator", info.sender))
+        .add_attribute("proposal_id", &proposal_id)
+        .add_attribute("creator", info.sender)
+        .add_attribute("status", proposal.status.as_str())
+        .add_attribute("creation_bond", bond.unwrap_or_default().to_string()))
 }
 
 fn execute_update_proposal(
@@ -288,26 +829,38 @@ fn execute_update_proposal(
         return Err(ContractError::Unauthorized {});
     }
 
-    // Can only update active proposals
-    if proposal.status != ProposalStatus::Active {
+    // Can only update active proposals, or drafts being prepared for review
+    if !matches!(
+        proposal.status,
+        ProposalStatus::Active | ProposalStatus::Draft
+    ) {
         return Err(ContractError::ProposalNotActive {});
     }
 
-    // Update fields if provided
+    // Update fields if provided; documents are versioned, never replaced
     if let Some(details) = asset_details {
         proposal.asset_details = details;
     }
-    if let Some(docs) = documents {
-        proposal.documents = docs;
-    }
+    let changed_documents = match documents {
+        Some(docs) => documents::publish(
+            deps.storage,
+            &env,
+            &info.sender,
+            &proposal_id,
+            &mut proposal.documents,
+            docs,
+        )?,
+        None => vec![],
+    };
 
     proposal.timestamps.updated_at = env.block.time.seconds();
 
-    PROPOSALS.save(deps.storage, proposal_id.clone(), &proposal)?;
+    save_proposal(deps.storage, &proposal)?;
 
     Ok(Response::new()
         .add_attribute("action", "update")
-        .add_attribute("id", &proposal_id))
+        .add_attribute("proposal_id", &proposal_id)
+        .add_attribute("documents_changed", changed_documents.join(",")))
 }
 
 fn execute_cancel_proposal(
@@ -318,110 +871,376 @@ fn execute_cancel_proposal(
 ) -> Result<Response, ContractError> {
     let mut proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
 
-    // Only creator or admin can cancel
-    let config = CONFIG.load(deps.storage)?;
-    if proposal.creator != info.sender && config.admin != info.sender {
+    // Only creator or an operator can cancel
+    if proposal.creator != info.sender
+        && !roles::has_role(deps.storage, &info.sender, Role::Operator)?
+    {
         return Err(ContractError::Unauthorized {});
     }
 
-    // Can only cancel active proposals
-    if proposal.status != ProposalStatus::Active {
+    let received_investments = PROPOSAL_INVESTMENTS
+        .may_load(deps.storage, proposal_id.clone())?
+        .is_some_and(|investors| !investors.is_empty());
+    let (mut messages, total_refunded, refunded_count) =
+        cancel_proposal(deps.storage, &env, &mut proposal)?;
+
+    // The creation bond only comes back if nobody had invested yet and the creator is in good
+    // standing
+    let mut response = Response::new();
+    if received_investments || blacklist::is_blacklisted(deps.storage, &proposal.creator) {
+        let reason = if received_investments {
+            "cancelled after investments"
+        } else {
+            "creator blacklisted"
+        };
+        let slashed = bonds::slash_bond(deps.storage, &env, &proposal_id, reason)?;
+        response = response.add_attribute("bond_slashed", slashed.to_string());
+    } else if let Some(refund) = bonds::refund_bond(deps.storage, &proposal)? {
+        messages.push(refund.into());
+        response = response.add_attribute("bond_refunded", "true");
+    }
+
+    Ok(response
+        .add_messages(messages)
+        .add_attribute("action", "cancel")
+        .add_attribute("proposal_id", &proposal_id)
+        .add_attribute("total_refunded", total_refunded.to_string())
+        .add_attribute("investors_refunded", refunded_count.to_string()))
+}
+
+/// Close a proposal that is still open or in review and refund everyone who has invested so far
+pub(crate) fn cancel_proposal(
+    storage: &mut dyn cosmwasm_std::Storage,
+    env: &Env,
+    proposal: &mut Proposal,
+) -> Result<(Vec<CosmosMsg>, Uint128, u64), ContractError> {
+    // Can only cancel proposals that have not closed, including those still in review
+    if !matches!(
+        proposal.status,
+        ProposalStatus::Active | ProposalStatus::Draft | ProposalStatus::UnderReview
+    ) {
         return Err(ContractError::ProposalNotActive {});
     }
 
     // Validate state transition before updating
-    validate_proposal_state_transition(proposal.status, ProposalStatus::Cancelled, &proposal, &env)?;
+    validate_proposal_state_transition(proposal.status, ProposalStatus::Cancelled, proposal, env)?;
     proposal.status = ProposalStatus::Cancelled;
-    PROPOSALS.save(deps.storage, proposal_id.clone(), &proposal)?;
-
-    // TODO: Refund any existing investments
+    compliance::release_issuer_raise(storage, &proposal.creator, &proposal.id)?;
 
-    // Update hot data
-    crate::state::PROPOSAL_HOT_DATA.update(deps.storage, proposal_id.clone(), |hot_data| -> Result<_, ContractError> {
-        let mut data = hot_data.unwrap();
-        data.status = ProposalStatus::Cancelled;
-        Ok(data)
-    })?;
+    // Return escrowed investments to everyone who has invested so far
+    let investors = PROPOSAL_INVESTMENTS
+        .may_load(storage, proposal.id.clone())?
+        .unwrap_or_default();
+    let (refund_messages, total_refunded, refunded_count) = refund_pending_investments(
+        storage,
+        env,
+        &proposal.id,
+        investors,
+        notifications::RefundReason::ProposalCancelled,
+    )?;
+    proposal.funding_status.raised_amount = Uint128::zero();
+    proposal.funding_status.investor_count = proposal
+        .funding_status
+        .investor_count
+        .saturating_sub(refunded_count);
+    ESCROWED_SHARES.save(storage, proposal.id.clone(), &0)?;
+    proposal.timestamps.updated_at = env.block.time.seconds();
+    save_proposal(storage, proposal)?;
 
-    Ok(Response::new()
-        .add_attribute("action", "cancel")
-        .add_attribute("id", &proposal_id))
+    Ok((refund_messages, total_refunded, refunded_count))
 }
 
-// Placeholder functions for remaining execute functions
-fn execute_invest(
+fn execute_extend_funding_deadline(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     proposal_id: String,
+    new_deadline: u64,
 ) -> Result<Response, ContractError> {
-    // Reentrancy protection
-    ReentrancyGuard::check_reentrancy(&deps, "invest")?;
-
-    // Check rate limit
-    rate_limit::RateLimiter::record_operation(deps.storage, &info.sender, "invest", &env)?;
-
     let mut proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+    if proposal.creator != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
 
-    // Validate proposal is active
-    if proposal.status != ProposalStatus::Active {
+    // Only a live raise can be extended; once the deadline passes it is left to fail
+    if proposal.status != ProposalStatus::Active || proposal.funding_status.is_funded {
         return Err(ContractError::ProposalNotActive {});
     }
-
-    // Check if funding deadline has passed
     if env.block.time.seconds() > proposal.financial_terms.funding_deadline {
         return Err(ContractError::FundingDeadlinePassed {});
     }
+    if crate::state::DEADLINE_EXTENSIONS.has(deps.storage, proposal_id.clone()) {
+        return Err(ContractError::DeadlineAlreadyExtended { proposal_id });
+    }
 
-    // Check if proposal is already funded
-    if proposal.funding_status.is_funded {
-        return Err(ContractError::ProposalAlreadyFunded {});
+    let config = CONFIG.load(deps.storage)?;
+    let max_deadline =
+        proposal.timestamps.created_at + config.max_funding_period_days * 24 * 60 * 60;
+    if new_deadline <= proposal.financial_terms.funding_deadline {
+        return Err(ContractError::InvalidInput {
+            field: "new_deadline".to_string(),
+            message: "New deadline must be later than the current one".to_string(),
+        });
+    }
+    if new_deadline > max_deadline {
+        return Err(ContractError::FundingPeriodTooLong {});
+    }
+
+    let previous_deadline = proposal.financial_terms.funding_deadline;
+    crate::state::DEADLINE_EXTENSIONS.save(
+        deps.storage,
+        proposal_id.clone(),
+        &previous_deadline,
+    )?;
+    proposal.financial_terms.funding_deadline = new_deadline;
+    proposal.timestamps.funding_deadline = new_deadline;
+    proposal.timestamps.updated_at = env.block.time.seconds();
+    save_proposal(deps.storage, &proposal)?;
+
+    Ok(Response::new().add_event(events::extend_funding_deadline(
+        &proposal_id,
+        previous_deadline,
+        new_deadline,
+    )))
+}
+
+fn execute_finalize_funding(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: String,
+) -> Result<Response, ContractError> {
+    let mut proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+    if proposal.creator != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    if proposal.status != ProposalStatus::Active || proposal.funding_status.is_funded {
+        return Err(ContractError::ProposalNotActive {});
+    }
+
+    let soft_cap = proposal.financial_terms.minimum_raise();
+    if proposal.funding_status.raised_amount < soft_cap {
+        return Err(ContractError::SoftCapNotReached {
+            raised: proposal.funding_status.raised_amount,
+            soft_cap,
+        });
     }
 
+    // Closes the raise with what it has; the lockup starts now
+    mark_funded(deps.storage, &env, &mut proposal)?;
+    proposal.timestamps.updated_at = env.block.time.seconds();
+    save_proposal(deps.storage, &proposal)?;
+
+    let raised_amount = proposal.funding_status.raised_amount;
+    let lockup_end = proposal.timestamps.lockup_end.unwrap_or_default();
+    Ok(Response::new()
+        .add_attribute("action", "finalize_funding")
+        .add_attribute("proposal_id", &proposal_id)
+        .add_attribute("raised_amount", raised_amount.to_string())
+        .add_attribute("lockup_end", lockup_end.to_string()))
+}
+
+pub(crate) fn execute_invest(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: String,
+    operation_id: Option<String>,
+    referrer: Option<String>,
+) -> Result<Response, ContractError> {
+    // Reentrancy protection
+    ReentrancyGuard::check_reentrancy(&deps, "invest")?;
+
+    // A retried broadcast of an already processed investment is rejected
+    idempotency::claim(
+        deps.storage,
+        &env,
+        &info.sender,
+        "invest",
+        operation_id.as_deref(),
+    )?;
+
+    // Check rate limit; the operation is only counted once it succeeds
+    rate_limit::RateLimiter::ensure_within_limit(deps.storage, &info.sender, "invest", &env)?;
+
     // Validate investment amount
     let investment_amount = info
         .funds
         .iter()
         .find(|coin| coin.denom == "untrn") // Neutron native token
         .map(|coin| coin.amount)
-        .unwrap_or_else(|| Uint128::zero());
+        .unwrap_or_default();
 
     if investment_amount.is_zero() {
         return Err(ContractError::InsufficientFunds {});
     }
 
-    if investment_amount < proposal.financial_terms.minimum_investment {
-        return Err(ContractError::InvestmentBelowMinimum {});
-    }
-
-    // Calculate shares
-    let shares = calculate_shares(&proposal, investment_amount)?;
+    // USD-denominated proposals are converted at the current NTRN/USD rate
+    oracle::refresh_usd_terms(deps.branch(), &env, &proposal_id)?;
 
-    // Check if investment exceeds available shares - use safe addition
-    let current_shares_sold = calculate_current_shares_sold(&proposal);
-    let total_shares_after_investment = current_shares_sold.saturating_add(shares);
-    if total_shares_after_investment > proposal.financial_terms.total_shares {
-        return Err(ContractError::InvestmentExceedsAvailable {});
-    }
+    // Referrers earn their share of the fee only if the raise completes
+    referral::record_referral(deps.branch(), &proposal_id, &info.sender, referrer)?;
 
-    // Check investor limit
-    const MAX_INVESTORS_PER_PROPOSAL: u32 = 500;
-    let investor_count = PROPOSAL_INVESTOR_COUNT
-        .may_load(deps.storage, proposal_id.clone())?
-        .unwrap_or(proposal.funding_status.investor_count as u32);
+    // Received funds are held in escrow until refunded or paid out
+    treasury::credit(
+        deps.storage,
+        treasury::TreasuryAccount::Escrow,
+        investment_amount,
+    )?;
 
-    // Only check if this is a new investor
-    let investment_key = (proposal_id.clone(), &info.sender);
-    if !INVESTMENTS.has(deps.storage, investment_key)
-        && investor_count >= MAX_INVESTORS_PER_PROPOSAL
-    {
-        return Err(ContractError::MaxInvestorsExceeded {
-            max: MAX_INVESTORS_PER_PROPOSAL,
-        });
+    // Large tickets are parked until a second approval arrives
+    if cosign::requires_cosign(deps.storage, investment_amount)? {
+        let proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+        let config = CONFIG.load(deps.storage)?;
+        validate_investment(&config, &proposal, &env, investment_amount)?;
+        freeze::ensure_not_frozen(deps.storage, &proposal_id)?;
+        kyc::enforce_verification(deps.storage, &env, &proposal, &info.sender)?;
+        whitelist::enforce_whitelist(deps.storage, &proposal_id, &info.sender)?;
+        let response = cosign::propose_investment(
+            deps.branch(),
+            &env,
+            &info.sender,
+            proposal_id,
+            investment_amount,
+        )?;
+        rate_limit::RateLimiter::record_operation(deps.storage, &info.sender, "invest", &env)?;
+        return Ok(response);
     }
 
+    let response = record_investment(
+        deps.branch(),
+        &env,
+        &info.sender,
+        proposal_id,
+        investment_amount,
+    )?;
+    rate_limit::RateLimiter::record_operation(deps.storage, &info.sender, "invest", &env)?;
+    Ok(response)
+}
+
+/// Checks that a proposal can accept the given investment right now
+fn validate_investment(
+    config: &Config,
+    proposal: &Proposal,
+    env: &Env,
+    investment_amount: Uint128,
+) -> Result<(), ContractError> {
+    // Validate proposal is active
+    if proposal.status != ProposalStatus::Active {
+        return Err(ContractError::ProposalNotActive {});
+    }
+
+    // Check if funding deadline has passed
+    if env.block.time.seconds() > proposal.financial_terms.funding_deadline {
+        return Err(ContractError::FundingDeadlinePassed {});
+    }
+
+    // Check if proposal is already funded
+    if proposal.funding_status.is_funded {
+        return Err(ContractError::ProposalAlreadyFunded {});
+    }
+
+    if investment_amount < proposal.financial_terms.minimum_investment {
+        return Err(ContractError::InvestmentBelowMinimum {});
+    }
+    InputValidator::validate_investment_bounds(investment_amount, config)?;
+
+    Ok(())
+}
+
+/// Closes a raise as successful: marks it Funded, starts the lockup and credits the creator
+fn mark_funded(
+    storage: &mut dyn cosmwasm_std::Storage,
+    env: &Env,
+    proposal: &mut Proposal,
+) -> Result<(), ContractError> {
+    let current_time = env.block.time.seconds();
+    proposal.funding_status.is_funded = true;
+
+    // Validate state transition before updating
+    validate_proposal_state_transition(proposal.status, ProposalStatus::Funded, proposal, env)?;
+    proposal.status = ProposalStatus::Funded;
+
+    // The capacity held since creation shrinks to what the raise brought in
+    compliance::record_issuer_raise(
+        storage,
+        &proposal.creator,
+        &proposal.id,
+        current_time,
+        proposal.funding_status.raised_amount,
+    )?;
+
+    // Set lockup end time (12 months after funding completion)
+    let config = CONFIG.load(storage)?;
+    proposal.timestamps.lockup_end = Some(current_time + config.lockup_period_seconds);
+    lockup::start_schedule(storage, proposal, current_time)?;
+
+    // Update creator stats efficiently with hot data
+    crate::state::CREATOR_STATS.update(storage, &proposal.creator, |stats| -> Result<_, ContractError> {
+        let mut stats = stats.unwrap_or(crate::state::CreatorCompactStats {
+            total_raised: Uint128::zero(),
+            success_count: 0,
+            total_count: 0,
+            last_active: current_time,
+        });
+        stats.total_raised = MathGuard::safe_add(stats.total_raised, proposal.funding_status.raised_amount)?;
+        stats.success_count += 1;
+        stats.last_active = current_time;
+        Ok(stats)
+    })?;
+
+    // Also update legacy creator data
+    CREATORS.update(storage, &proposal.creator, |creator| -> Result<_, ContractError> {
+        let mut creator = creator.unwrap();
+        creator.total_raised = MathGuard::safe_add(creator.total_raised, proposal.funding_status.raised_amount)?;
+        creator.successful_proposals = creator.successful_proposals.saturating_add(1);
+        Ok(creator)
+    })?;
+
+    notifications::proposal_funded(storage, env, proposal)?;
+    Ok(())
+}
+
+/// Moves an approved investment into escrow and updates proposal funding state
+fn record_investment(
+    deps: DepsMut,
+    env: &Env,
+    investor: &Addr,
+    proposal_id: String,
+    investment_amount: Uint128,
+) -> Result<Response, ContractError> {
+    let mut proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+    let config = CONFIG.load(deps.storage)?;
+    validate_investment(&config, &proposal, env, investment_amount)?;
+    freeze::ensure_not_frozen(deps.storage, &proposal_id)?;
+    kyc::enforce_verification(deps.storage, env, &proposal, investor)?;
+    whitelist::enforce_whitelist(deps.storage, &proposal_id, investor)?;
+
+    // Calculate shares, filling only what is left when partial fills are allowed
+    let current_shares_sold = shares_sold(deps.storage, &proposal)?;
+    let (shares, investment_amount, change) =
+        fill_investment(&proposal, current_shares_sold, investment_amount)?;
+
+    // Check the investor cap; only a new investor adds to the count
+    let investor_cap = proposal.compliance.investor_cap();
+    if !INVESTMENTS.has(deps.storage, (proposal_id.clone(), investor))
+        && proposal.funding_status.investor_count >= investor_cap
+    {
+        return Err(ContractError::MaxInvestorsExceeded {
+            max: investor_cap as u32,
+        });
+    }
+
+    // Enforce the per-investor cap across repeated investments
+    let already_invested = INVESTMENTS
+        .may_load(deps.storage, (proposal_id.clone(), investor))?
+        .map(|investment| investment.amount)
+        .unwrap_or_default();
+    compliance::enforce_investor_cap(&proposal, already_invested, investment_amount)?;
+    investor_limits::enforce_annual_limit(deps.storage, env, investor, investment_amount)?;
+
     // Create or update investment
-    let investment_key = (proposal_id.clone(), &info.sender);
+    let investment_key = (proposal_id.clone(), investor);
     let current_time = env.block.time.seconds();
 
     if INVESTMENTS.has(deps.storage, investment_key.clone()) {
@@ -441,7 +1260,7 @@ fn execute_invest(
     } else {
         // Create new investment
         let investment = Investment {
-            investor: info.sender.clone(),
+            investor: investor.clone(),
             proposal_id: proposal_id.clone(),
             amount: investment_amount,
             shares,
@@ -450,6 +1269,7 @@ fn execute_invest(
         };
 
         INVESTMENTS.save(deps.storage, investment_key.clone(), &investment)?;
+        proposal.funding_status.investor_count += 1;
 
         // Add to proposal investors list
         PROPOSAL_INVESTMENTS.update(
@@ -457,78 +1277,118 @@ fn execute_invest(
             proposal_id.clone(),
             |investors| -> StdResult<_> {
                 let mut investors = investors.unwrap_or_default();
-                if !investors.contains(&info.sender) {
-                    investors.push(info.sender.clone());
-                    proposal.funding_status.investor_count += 1;
+                if !investors.contains(investor) {
+                    investors.push(investor.clone());
                 }
                 Ok(investors)
             },
         )?;
 
         // Add to user investments list
-        USER_INVESTMENTS.update(deps.storage, &info.sender, |proposals| -> StdResult<_> {
-            let mut proposals = proposals.unwrap_or_default();
-            if !proposals.contains(&proposal_id) {
-                proposals.push(proposal_id.clone());
-            }
-            Ok(proposals)
-        })?;
+        crate::state::add_user_investment(deps.storage, investor, &proposal_id)?;
     }
 
+    // Each purchase keeps its own receipt, whatever happens to the accumulated position
+    let token_price =
+        pricing::active_tier(&proposal.financial_terms, proposal.funding_status.raised_amount)
+            .token_price;
+    let receipt_id = receipts::issue(
+        deps.storage,
+        env,
+        investor,
+        &proposal_id,
+        investment_amount,
+        shares,
+        token_price,
+    )?;
+    investor_limits::record_spend(deps.storage, investor, current_time, investment_amount)?;
+    trending::record(deps.storage, &proposal_id, current_time, investment_amount)?;
+    tax_lots::open(
+        deps.storage,
+        &proposal_id,
+        investor,
+        tax_lots::TaxLot {
+            receipt_id: Some(receipt_id),
+            acquired_at: current_time,
+            shares,
+            cost: investment_amount,
+            token_price,
+        },
+    )?;
+
     // Update proposal funding status - use safe addition
     proposal.funding_status.raised_amount = MathGuard::safe_add(proposal.funding_status.raised_amount, investment_amount)?;
+    ESCROWED_SHARES.save(
+        deps.storage,
+        proposal_id.clone(),
+        &current_shares_sold.saturating_add(shares),
+    )?;
 
-    // Check if funding goal is reached
-    if proposal.funding_status.raised_amount >= proposal.financial_terms.target_amount {
-        proposal.funding_status.is_funded = true;
-
-        // Validate state transition before updating
-        validate_proposal_state_transition(proposal.status, ProposalStatus::Funded, &proposal, &env)?;
-        proposal.status = ProposalStatus::Funded;
-
-        // Set lockup end time (12 months after funding completion)
-        let config = CONFIG.load(deps.storage)?;
-        proposal.timestamps.lockup_end = Some(current_time + config.lockup_period_seconds);
-
-        // Update creator stats efficiently with hot data
-        crate::state::CREATOR_STATS.update(deps.storage, &proposal.creator, |stats| -> Result<_, ContractError> {
-            let mut stats = stats.unwrap_or(crate::state::CreatorCompactStats {
-                total_raised: Uint128::zero(),
-                success_count: 0,
-                total_count: 0,
-                last_active: current_time,
-            });
-            stats.total_raised = MathGuard::safe_add(stats.total_raised, proposal.funding_status.raised_amount)?;
-            stats.success_count += 1;
-            stats.last_active = current_time;
-            Ok(stats)
-        })?;
+    // Re-priced USD terms can take a raise past the capacity it reserved
+    compliance::reserve_issuer_capacity(
+        deps.storage,
+        &proposal.creator,
+        &proposal_id,
+        current_time,
+        proposal.funding_status.raised_amount,
+    )?;
 
-        // Also update legacy creator data
-        CREATORS.update(deps.storage, &proposal.creator, |creator| -> Result<_, ContractError> {
-            let mut creator = creator.unwrap();
-            creator.total_raised = MathGuard::safe_add(creator.total_raised, proposal.funding_status.raised_amount)?;
-            creator.successful_proposals = creator.successful_proposals.saturating_add(1);
-            Ok(creator)
-        })?;
+    // Close as soon as the cap is reached; past target but below the cap it stays open
+    if proposal.funding_status.raised_amount >= proposal.financial_terms.funding_cap() {
+        mark_funded(deps.storage, env, &mut proposal)?;
     }
 
     proposal.timestamps.updated_at = current_time;
-    PROPOSALS.save(deps.storage, proposal_id.clone(), &proposal)?;
+    save_proposal(deps.storage, &proposal)?;
+
+    ledger::record_entry(
+        deps.storage,
+        env,
+        &proposal_id,
+        ledger::LedgerEntryKind::Investment,
+        investor,
+        investment_amount,
+    )?;
 
     let mut response = Response::new()
         .add_attributes(crate::gas_optimization::EfficientEvents::emit_investment(
             &proposal_id,
-            info.sender.as_str(),
+            investor.as_str(),
             investment_amount,
         ))
         .add_attribute("amount", investment_amount.to_string())
-        .add_attribute("shares", shares.to_string());
+        .add_attribute("shares", shares.to_string())
+        .add_attribute("receipt_id", receipt_id.to_string());
 
     if proposal.funding_status.is_funded {
         response = response.add_attribute("funding_completed", "true");
     }
 
+    if !change.is_zero() {
+        treasury::debit(deps.storage, treasury::TreasuryAccount::Escrow, change)?;
+        response = response
+            .add_message(BankMsg::Send {
+                to_address: investor.to_string(),
+                amount: vec![Coin {
+                    denom: "untrn".to_string(),
+                    amount: change,
+                }],
+            })
+            .add_attribute("change_refunded", change.to_string());
+    }
+
+    // With external custody configured the invested funds move on to the escrow contract
+    if let Some(deposit) =
+        escrow::deposit_message(deps.storage, &proposal_id, investor, investment_amount)?
+    {
+        treasury::debit(
+            deps.storage,
+            treasury::TreasuryAccount::Escrow,
+            investment_amount,
+        )?;
+        response = response.add_submessage(deposit);
+    }
+
     Ok(response)
 }
 
@@ -542,14 +1402,16 @@ fn execute_refund_investors(
     ReentrancyGuard::check_reentrancy(&deps, "refund_investors")?;
 
     let mut proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
-    let config = CONFIG.load(deps.storage)?;
 
-    // Only admin or creator can trigger refunds
-    if info.sender != proposal.creator && info.sender != config.admin {
+    // Only an operator or creator can trigger refunds
+    if info.sender != proposal.creator
+        && !roles::has_role(deps.storage, &info.sender, Role::Operator)?
+    {
         return Err(ContractError::Unauthorized {});
     }
 
     // Can only refund if proposal failed or was cancelled
+    let mut bond_refund = None;
     match proposal.status {
         ProposalStatus::Failed | ProposalStatus::Cancelled => {}
         ProposalStatus::Active => {
@@ -561,6 +1423,8 @@ fn execute_refund_investors(
             validate_proposal_state_transition(proposal.status, ProposalStatus::Failed, &proposal, &env)?;
             // Mark as failed since deadline passed without funding
             proposal.status = ProposalStatus::Failed;
+            compliance::release_issuer_raise(deps.storage, &proposal.creator, &proposal_id)?;
+            bond_refund = bonds::settle_failed_raise(deps.storage, &env, &proposal)?;
         }
         _ => return Err(ContractError::ProposalNotActive {}),
     }
@@ -574,32 +1438,94 @@ fn execute_refund_investors(
         return Err(ContractError::NoInvestmentsToRefund {});
     }
 
+    let (refund_messages, total_refunded, refunded_count) = refund_pending_investments(
+        deps.storage,
+        &env,
+        &proposal_id,
+        investors,
+        refund_reason(&proposal),
+    )?;
+
+    if refund_messages.is_empty() {
+        return Err(ContractError::NoInvestmentsToRefund {});
+    }
+
+    // Update proposal funding status
+    proposal.funding_status.raised_amount = Uint128::zero();
+    proposal.funding_status.investor_count = proposal
+        .funding_status
+        .investor_count
+        .saturating_sub(refunded_count);
+    ESCROWED_SHARES.save(deps.storage, proposal_id.clone(), &0)?;
+    proposal.timestamps.updated_at = env.block.time.seconds();
+    save_proposal(deps.storage, &proposal)?;
+
+    Ok(Response::new()
+        .add_messages(refund_messages)
+        .add_messages(bond_refund)
+        .add_attribute("method", "refund_investors")
+        .add_attribute("proposal_id", &proposal_id)
+        .add_attribute("total_refunded", total_refunded.to_string())
+        .add_attribute("investors_refunded", refunded_count.to_string()))
+}
+
+/// Why investors of a failed or cancelled proposal are being refunded
+fn refund_reason(proposal: &Proposal) -> notifications::RefundReason {
+    if proposal.status == ProposalStatus::Cancelled {
+        notifications::RefundReason::ProposalCancelled
+    } else {
+        notifications::RefundReason::ProposalFailed
+    }
+}
+
+/// Refunds every still-pending investment out of escrow; returns the bank messages, the total
+/// refunded and the number of investors paid
+fn refund_pending_investments(
+    storage: &mut dyn cosmwasm_std::Storage,
+    env: &Env,
+    proposal_id: &str,
+    investors: Vec<Addr>,
+    reason: notifications::RefundReason,
+) -> Result<(Vec<CosmosMsg>, Uint128, u64), ContractError> {
     let mut refund_messages = Vec::new();
     let mut total_refunded = Uint128::zero();
     let mut refunded_count = 0u64;
 
     // Process refunds for each investor
     for investor in investors {
-        if let Ok(mut investment) = INVESTMENTS.load(deps.storage, (proposal_id.clone(), &investor))
+        if let Ok(mut investment) = INVESTMENTS.load(storage, (proposal_id.to_string(), &investor))
         {
             // Only refund pending investments
             if investment.status == InvestmentStatus::Pending {
                 let refund_amount = investment.amount;
 
-                // Create bank message to refund investor
-                let refund_msg = CosmosMsg::Bank(BankMsg::Send {
-                    to_address: investor.to_string(),
-                    amount: vec![Coin {
-                        denom: "untrn".to_string(),
-                        amount: refund_amount,
-                    }],
-                });
-
-                refund_messages.push(refund_msg);
+                // Refund the investor, from external escrow or over IBC where that applies
+                refund_messages.extend(escrow::payout_messages(
+                    storage,
+                    env,
+                    proposal_id,
+                    &investor,
+                    refund_amount,
+                )?);
 
                 // Update investment status
                 investment.status = InvestmentStatus::Refunded;
-                INVESTMENTS.save(deps.storage, (proposal_id.clone(), &investor), &investment)?;
+                INVESTMENTS.save(storage, (proposal_id.to_string(), &investor), &investment)?;
+                ledger::record_entry(
+                    storage,
+                    env,
+                    proposal_id,
+                    ledger::LedgerEntryKind::Refund,
+                    &investor,
+                    refund_amount,
+                )?;
+                notifications::refund_issued(
+                    storage,
+                    proposal_id,
+                    &investor,
+                    refund_amount,
+                    reason,
+                )?;
 
                 total_refunded = MathGuard::safe_add(total_refunded, refund_amount)?;
                 refunded_count = refunded_count.saturating_add(1);
@@ -607,21 +1533,105 @@ fn execute_refund_investors(
         }
     }
 
-    if refund_messages.is_empty() {
-        return Err(ContractError::NoInvestmentsToRefund {});
+    treasury::debit(storage, treasury::TreasuryAccount::Escrow, total_refunded)?;
+
+    Ok((refund_messages, total_refunded, refunded_count))
+}
+
+/// Lets an investor pull their own refund from a failed or cancelled proposal
+fn execute_claim_refund(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: String,
+) -> Result<Response, ContractError> {
+    // Reentrancy protection
+    ReentrancyGuard::check_reentrancy(&deps, "claim_refund")?;
+
+    let mut proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+
+    let mut bond_refund = None;
+    match proposal.status {
+        ProposalStatus::Failed | ProposalStatus::Cancelled => {}
+        ProposalStatus::Active => {
+            // An unfunded proposal past its deadline fails here without waiting for a keeper
+            if env.block.time.seconds() <= proposal.financial_terms.funding_deadline
+                || proposal.funding_status.is_funded
+            {
+                return Err(ContractError::ProposalNotActive {});
+            }
+            validate_proposal_state_transition(
+                proposal.status,
+                ProposalStatus::Failed,
+                &proposal,
+                &env,
+            )?;
+            proposal.status = ProposalStatus::Failed;
+            compliance::release_issuer_raise(deps.storage, &proposal.creator, &proposal_id)?;
+            bond_refund = bonds::settle_failed_raise(deps.storage, &env, &proposal)?;
+        }
+        _ => return Err(ContractError::ProposalNotActive {}),
     }
 
-    // Update proposal funding status
-    proposal.funding_status.raised_amount = Uint128::zero();
+    let mut investment = INVESTMENTS
+        .may_load(deps.storage, (proposal_id.clone(), &info.sender))?
+        .ok_or(ContractError::InvestmentNotFound {})?;
+
+    // Each investor is refunded once, whether they claimed or were included in RefundInvestors
+    if investment.status != InvestmentStatus::Pending {
+        return Err(ContractError::RefundAlreadyClaimed { proposal_id });
+    }
+
+    let refund_amount = investment.amount;
+    investment.status = InvestmentStatus::Refunded;
+    INVESTMENTS.save(
+        deps.storage,
+        (proposal_id.clone(), &info.sender),
+        &investment,
+    )?;
+    ledger::record_entry(
+        deps.storage,
+        &env,
+        &proposal_id,
+        ledger::LedgerEntryKind::Refund,
+        &info.sender,
+        refund_amount,
+    )?;
+    notifications::refund_issued(
+        deps.storage,
+        &proposal_id,
+        &info.sender,
+        refund_amount,
+        refund_reason(&proposal),
+    )?;
+
+    proposal.funding_status.raised_amount =
+        MathGuard::safe_sub(proposal.funding_status.raised_amount, refund_amount)?;
+    proposal.funding_status.investor_count =
+        proposal.funding_status.investor_count.saturating_sub(1);
     proposal.timestamps.updated_at = env.block.time.seconds();
-    PROPOSALS.save(deps.storage, proposal_id.clone(), &proposal)?;
+    save_proposal(deps.storage, &proposal)?;
+
+    let refund_messages = escrow::payout_messages(
+        deps.storage,
+        &env,
+        &proposal_id,
+        &info.sender,
+        refund_amount,
+    )?;
+    treasury::debit(
+        deps.storage,
+        treasury::TreasuryAccount::Escrow,
+        refund_amount,
+    )?;
 
     Ok(Response::new()
         .add_messages(refund_messages)
-        .add_attribute("method", "refund_investors")
+        .add_messages(bond_refund)
+        .add_attribute("action", "claim_refund")
         .add_attribute("proposal_id", &proposal_id)
-        .add_attribute("total_refunded", total_refunded.to_string())
-        .add_attribute("investors_refunded", refunded_count.to_string()))
+        .add_attribute("investor", info.sender)
+        .add_attribute("amount", refund_amount.to_string()))
 }
 
 fn execute_mint_tokens(
@@ -630,17 +1640,18 @@ fn execute_mint_tokens(
     info: MessageInfo,
     proposal_id: String,
 ) -> Result<Response, ContractError> {
-    // Reentrancy protection
-    ReentrancyGuard::check_reentrancy(&deps, "mint_tokens")?;
+    // Reentrancy protection, held until the token instantiation replies
+    ReentrancyGuard::enter(deps.storage, "mint_tokens")?;
 
-    // Check rate limit
-    rate_limit::RateLimiter::record_operation(deps.storage, &info.sender, "mint_tokens", &env)?;
+    // Check rate limit; the operation is only counted once it succeeds
+    rate_limit::RateLimiter::ensure_within_limit(deps.storage, &info.sender, "mint_tokens", &env)?;
 
     let mut proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
-    let config = CONFIG.load(deps.storage)?;
 
-    // Only admin or creator can mint tokens
-    if info.sender != proposal.creator && info.sender != config.admin {
+    // Only an operator or creator can mint tokens
+    if info.sender != proposal.creator
+        && !roles::has_role(deps.storage, &info.sender, Role::Operator)?
+    {
         return Err(ContractError::Unauthorized {});
     }
 
@@ -660,8 +1671,9 @@ fn execute_mint_tokens(
     }
 
     // Create token name and symbol based on proposal
-    let token_name = format!("{} Token", proposal.asset_details.name);
-    let token_symbol = format!("{}T", proposal.id.to_uppercase().replace("_", ""));
+    let token_name = token_metadata::token_name(&proposal);
+    let token_symbol = token_metadata::token_symbol(&proposal);
+    token_metadata::validate_symbol(&token_symbol)?;
 
     // Create CW20 token instantiate message
     let token_instantiate_msg = Cw20InstantiateMsg {
@@ -673,35 +1685,41 @@ fn execute_mint_tokens(
             minter: env.contract.address.to_string(),
             cap: Some(Uint128::from(proposal.financial_terms.total_shares)),
         }),
-        marketing: None,
+        marketing: token_metadata::marketing_info(&proposal),
+    };
+
+    // Share tokens ask this contract to approve every holder transfer
+    let restricted_instantiate_msg = cf1_restricted_cw20::msg::InstantiateMsg {
+        token: token_instantiate_msg,
+        transfer_hook: env.contract.address.to_string(),
     };
 
     // Get CW20 code ID from config
     let config = CONFIG.load(deps.storage)?;
 
-    // Store the proposal ID for the reply handler
-    PENDING_TOKEN_REPLY.save(deps.storage, &proposal_id)?;
-
-    // Create submessage to instantiate CW20 token
+    // Create submessage to instantiate CW20 token. The reply reads the proposal from the payload,
+    // so instantiations for different proposals never share state, and comes back on failure too
     let instantiate_submsg = SubMsg {
         id: REPLY_INSTANTIATE_TOKEN,
         msg: WasmMsg::Instantiate {
             code_id: config.cw20_code_id,
-            msg: to_json_binary(&token_instantiate_msg)?,
+            msg: to_json_binary(&restricted_instantiate_msg)?,
             funds: vec![],
             label: format!("CF1 Token for {}", proposal.id),
             admin: Some(env.contract.address.to_string()),
         }
         .into(),
         gas_limit: None,
-        reply_on: ReplyOn::Success,
-        payload: cosmwasm_std::Binary::default(),
+        reply_on: ReplyOn::Always,
+        payload: to_json_binary(&proposal_id)?,
     };
 
     // Mark tokens as minted in proposal
     proposal.funding_status.tokens_minted = true;
     proposal.timestamps.updated_at = env.block.time.seconds();
-    PROPOSALS.save(deps.storage, proposal_id.clone(), &proposal)?;
+    save_proposal(deps.storage, &proposal)?;
+
+    rate_limit::RateLimiter::record_operation(deps.storage, &info.sender, "mint_tokens", &env)?;
 
     Ok(Response::new()
         .add_submessage(instantiate_submsg)
@@ -713,20 +1731,43 @@ fn execute_mint_tokens(
         ))
 }
 
+/// Mint again after the share token failed to instantiate, under the same rules as MintTokens
+fn execute_retry_mint_tokens(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: String,
+) -> Result<Response, ContractError> {
+    let failure = crate::state::MINT_FAILURES
+        .may_load(deps.storage, proposal_id.clone())?
+        .ok_or_else(|| ContractError::InvalidInput {
+            field: "proposal_id".to_string(),
+            message: "No failed token mint to retry".to_string(),
+        })?;
+
+    Ok(execute_mint_tokens(deps, env, info, proposal_id)?
+        .add_attribute("retry_attempt", (failure.attempts + 1).to_string()))
+}
+
+/// Mint shares for up to `batch_size` pending investors, resuming from the proposal's distribution
+/// cursor. The batch that reaches the end of the investor list completes the proposal; the raise
+/// is only paid out by `FinalizeDistribution` afterwards.
 fn execute_distribute_tokens(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     proposal_id: String,
+    batch_size: usize,
 ) -> Result<Response, ContractError> {
-    // Reentrancy protection
-    ReentrancyGuard::check_reentrancy(&deps, "distribute_tokens")?;
+    // Reentrancy protection, held until the reply to the last mint
+    ReentrancyGuard::enter(deps.storage, "distribute_tokens")?;
 
     let mut proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
-    let config = CONFIG.load(deps.storage)?;
 
-    // Enhanced access control - only admin or creator can distribute tokens
-    if info.sender != proposal.creator && info.sender != config.admin {
+    // Enhanced access control - only an operator or creator can distribute tokens
+    if info.sender != proposal.creator
+        && !roles::has_role(deps.storage, &info.sender, Role::Operator)?
+    {
         return Err(ContractError::Unauthorized {});
     }
 
@@ -741,6 +1782,12 @@ fn execute_distribute_tokens(
         return Err(ContractError::TokensNotMinted {});
     }
 
+    // Investors must have had the chance to withdraw after a material change
+    reconfirmation::ensure_no_open_window(deps.storage, &proposal_id)?;
+
+    // Nothing is distributed while the proposal is frozen
+    freeze::ensure_not_frozen(deps.storage, &proposal_id)?;
+
     // Check that proposal is funded
     if !proposal.funding_status.is_funded {
         return Err(ContractError::ProposalNotFunded {});
@@ -774,16 +1821,22 @@ fn execute_distribute_tokens(
         return Err(ContractError::NoInvestmentsToRefund {});
     }
 
-    // Phase 1: Validate all investments and prepare operations (atomic preparation)
+    // Phase 1: Validate the batch's investments and prepare operations (atomic preparation)
+    let mut progress = DISTRIBUTION_PROGRESS
+        .may_load(deps.storage, proposal_id.clone())?
+        .unwrap_or_default();
     let mut pending_distributions = Vec::new();
-    let mut total_distributed = 0u64;
-    let mut distributed_count = 0u64;
+    let mut index = progress.next_index as usize;
 
-    // Collect all valid investments that need token distribution
-    for investor in &investors {
+    // Collect the next valid investments that need token distribution
+    while index < investors.len() && pending_distributions.len() < batch_size {
+        let investor = &investors[index];
+        index += 1;
         if let Ok(investment) = INVESTMENTS.load(deps.storage, (proposal_id.clone(), investor)) {
-            // Only distribute to pending investments
-            if investment.status == InvestmentStatus::Pending {
+            // Only distribute to pending investments; failed mints wait for RetryFailedMints
+            if investment.status == InvestmentStatus::Pending
+                && !distribution::has_failed_mint(deps.storage, &proposal_id, investor)
+            {
                 let shares_to_mint = investment.shares;
 
                 // Validate shares are non-zero
@@ -795,34 +1848,25 @@ fn execute_distribute_tokens(
                 }
 
                 pending_distributions.push((investor.clone(), investment, shares_to_mint));
-                total_distributed = total_distributed.saturating_add(shares_to_mint);
-                distributed_count = distributed_count.saturating_add(1);
+                progress.total_distributed =
+                    progress.total_distributed.saturating_add(shares_to_mint);
+                progress.distributed_count = progress.distributed_count.saturating_add(1);
             }
         }
     }
 
-    if pending_distributions.is_empty() {
+    if pending_distributions.is_empty() && progress.distributed_count == 0 {
         return Err(ContractError::NoInvestmentsToRefund {});
     }
 
-    // Phase 2: Create all messages (atomic message preparation)
-    let mut mint_messages: Vec<CosmosMsg> = Vec::new();
-
-    for (investor, _, shares_to_mint) in &pending_distributions {
-        // Create mint message for investor
-        let mint_msg = Cw20ExecuteMsg::Mint {
-            recipient: investor.to_string(),
-            amount: Uint128::from(*shares_to_mint),
-        };
-
-        let cosmos_msg = cosmwasm_std::WasmMsg::Execute {
-            contract_addr: token_address.to_string(),
-            msg: to_json_binary(&mint_msg)?,
-            funds: vec![],
-        };
-
-        mint_messages.push(cosmos_msg.into());
-    }
+    // Phase 2: Create one mint submessage per investor, so a failed mint is recorded rather than
+    // reverting the batch
+    let mints: Vec<(Addr, u64)> = pending_distributions
+        .iter()
+        .map(|(investor, _, shares_to_mint)| (investor.clone(), *shares_to_mint))
+        .collect();
+    let mint_messages = distribution::mint_submessages(&token_address, &proposal_id, &mints)?;
+    let batch_count = pending_distributions.len();
 
     // Phase 3: Update all investment statuses atomically
     for (investor, mut investment, _) in pending_distributions {
@@ -830,82 +1874,239 @@ fn execute_distribute_tokens(
         INVESTMENTS.save(deps.storage, (proposal_id.clone(), &investor), &investment)?;
     }
 
-    // Update proposal status to completed (tokens distributed)
-    // Validate state transition before updating
-    validate_proposal_state_transition(proposal.status, ProposalStatus::Completed, &proposal, &env)?;
-    proposal.status = ProposalStatus::Completed;
-    proposal.timestamps.updated_at = env.block.time.seconds();
-    PROPOSALS.save(deps.storage, proposal_id.clone(), &proposal)?;
-
-    // Release funds to creator (minus platform fee) - use safe arithmetic
-    let platform_fee = MathGuard::calculate_percentage(proposal.funding_status.raised_amount, config.platform_fee_bps)?;
-    let creator_amount = MathGuard::safe_sub(proposal.funding_status.raised_amount, platform_fee)?;
-
-    let mut response_messages = mint_messages;
-
-    // Send funds to creator
-    if !creator_amount.is_zero() {
-        let creator_payout = cosmwasm_std::BankMsg::Send {
-            to_address: proposal.creator.to_string(),
-            amount: vec![Coin {
-                denom: "untrn".to_string(),
-                amount: creator_amount,
-            }],
-        };
-        response_messages.push(creator_payout.into());
+    // The cap table can change between batches, so the end of the list only completes the
+    // distribution once nobody is left pending; otherwise the next batch starts over
+    let mut complete = index >= investors.len();
+    if complete {
+        for investor in &investors {
+            if INVESTMENTS
+                .may_load(deps.storage, (proposal_id.clone(), investor))?
+                .is_some_and(|investment| investment.status == InvestmentStatus::Pending)
+                && !distribution::has_failed_mint(deps.storage, &proposal_id, investor)
+            {
+                complete = false;
+                index = 0;
+                break;
+            }
+        }
     }
+    progress.next_index = index as u64;
+    progress.complete = complete;
+    DISTRIBUTION_PROGRESS.save(deps.storage, proposal_id.clone(), &progress)?;
 
-    // Send platform fee to admin
-    if !platform_fee.is_zero() {
-        let admin_fee = cosmwasm_std::BankMsg::Send {
-            to_address: config.admin.to_string(),
-            amount: vec![Coin {
-                denom: "untrn".to_string(),
-                amount: platform_fee,
-            }],
-        };
-        response_messages.push(admin_fee.into());
+    if complete {
+        // Update proposal status to completed (tokens distributed)
+        // Validate state transition before updating
+        validate_proposal_state_transition(
+            proposal.status,
+            ProposalStatus::Completed,
+            &proposal,
+            &env,
+        )?;
+        proposal.status = ProposalStatus::Completed;
+        proposal.timestamps.updated_at = env.block.time.seconds();
+        save_proposal(deps.storage, &proposal)?;
+        concentration::refresh(deps.storage, &env, &proposal_id)?;
+        vesting::start_vesting(deps.storage, &env, &proposal)?;
+        notifications::tokens_distributed(deps.storage, &env, &proposal, &token_address)?;
     }
 
+    if mint_messages.is_empty() {
+        ReentrancyGuard::exit(deps.storage);
+    }
     Ok(Response::new()
-        .add_messages(response_messages)
+        .add_submessages(mint_messages)
         .add_attribute("method", "distribute_tokens")
         .add_attribute("proposal_id", &proposal_id)
-        .add_attribute("total_distributed", total_distributed.to_string())
-        .add_attribute("investors_count", distributed_count.to_string())
-        .add_attribute("creator_payout", creator_amount.to_string())
-        .add_attribute("platform_fee", platform_fee.to_string()))
+        .add_attribute("batch_count", batch_count.to_string())
+        .add_attribute("next_index", progress.next_index.to_string())
+        .add_attribute("distribution_complete", complete.to_string())
+        .add_attribute("total_distributed", progress.total_distributed.to_string())
+        .add_attribute("investors_count", progress.distributed_count.to_string()))
 }
 
-fn execute_update_config(
+/// Release the raise once every investor's tokens are minted: the platform fee goes to the fee
+/// bucket and the rest to the creator, or into milestone escrow (creator or operator)
+fn execute_finalize_distribution(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
-    admin: Option<String>,
-    platform_fee_bps: Option<u16>,
-    min_funding_period_days: Option<u64>,
-    max_funding_period_days: Option<u64>,
-    cw20_code_id: Option<u64>,
+    proposal_id: String,
 ) -> Result<Response, ContractError> {
-    let mut config = CONFIG.load(deps.storage)?;
+    // Reentrancy protection, held until the payout has run
+    ReentrancyGuard::enter(deps.storage, "finalize_distribution")?;
 
-    // Only admin can update config
-    if config.admin != info.sender {
+    let proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != proposal.creator
+        && !roles::has_role(deps.storage, &info.sender, Role::Operator)?
+    {
         return Err(ContractError::Unauthorized {});
     }
+    // Payouts to a blacklisted creator stay frozen until the blacklist is lifted
+    blacklist::ensure_not_blacklisted(deps.storage, &proposal.creator)?;
+    freeze::ensure_not_frozen(deps.storage, &proposal_id)?;
 
-    if let Some(admin) = admin {
-        config.admin = deps.api.addr_validate(&admin)?;
-    }
-    if let Some(fee) = platform_fee_bps {
-        if fee > 10000 {
-            return Err(ContractError::InvalidPlatformFee {});
-        }
-        config.platform_fee_bps = fee;
-    }
-    if let Some(min_days) = min_funding_period_days {
-        config.min_funding_period_days = min_days;
+    // Only a distribution that has minted for everyone can be finalized, and only once
+    let progress = DISTRIBUTION_PROGRESS
+        .may_load(deps.storage, proposal_id.clone())?
+        .filter(|progress| progress.complete)
+        .ok_or_else(|| ContractError::InvalidInput {
+            field: "proposal_id".to_string(),
+            message: "Token distribution is not complete or already finalized".to_string(),
+        })?;
+    if distribution::has_failed_mints(deps.storage, &proposal_id) {
+        return Err(ContractError::InvalidInput {
+            field: "proposal_id".to_string(),
+            message: "Some investor mints failed; retry them before finalizing".to_string(),
+        });
     }
-    if let Some(max_days) = max_funding_period_days {
+    DISTRIBUTION_PROGRESS.remove(deps.storage, proposal_id.clone());
+
+    let distributed_amounts = PROPOSAL_INVESTMENTS
+        .may_load(deps.storage, proposal_id.clone())?
+        .unwrap_or_default()
+        .into_iter()
+        .map(|investor| {
+            let investment =
+                INVESTMENTS.may_load(deps.storage, (proposal_id.clone(), &investor))?;
+            Ok(investment
+                .filter(|investment| investment.status == InvestmentStatus::Completed)
+                .map(|investment| (investor, investment.amount)))
+        })
+        .filter_map(|item| item.transpose())
+        .collect::<StdResult<Vec<(Addr, Uint128)>>>()?;
+
+    // Release funds to creator (minus platform fee) - use safe arithmetic
+    let fee_bps = fees::proposal_fee_bps(deps.storage, &config, &proposal)?;
+    let platform_fee =
+        MathGuard::calculate_percentage(proposal.funding_status.raised_amount, fee_bps)?;
+    let creator_amount = MathGuard::safe_sub(proposal.funding_status.raised_amount, platform_fee)?;
+
+    ledger::record_entry(
+        deps.storage,
+        &env,
+        &proposal_id,
+        ledger::LedgerEntryKind::PlatformFee,
+        &config.admin,
+        platform_fee,
+    )?;
+
+    let mut response_messages = Vec::new();
+    // The fee stays with the launchpad, so it comes back from external custody
+    if let Some(reclaim) = escrow::reclaim_message(deps.storage, &env, &proposal_id, platform_fee)?
+    {
+        response_messages.push(reclaim);
+    }
+
+    // The fee accrues in the fee bucket until the admin withdraws it
+    treasury::transfer(
+        deps.storage,
+        &env,
+        treasury::TreasuryAccount::Escrow,
+        treasury::TreasuryAccount::Fees,
+        platform_fee,
+        &format!("platform fee for {}", proposal_id),
+    )?;
+    // Referrers' share of the fee is set aside until they claim it
+    let referral_rewards =
+        referral::accrue_rewards(deps.storage, &proposal_id, &distributed_amounts, fee_bps)?;
+    if !referral_rewards.is_zero() {
+        treasury::transfer(
+            deps.storage,
+            &env,
+            treasury::TreasuryAccount::Fees,
+            treasury::TreasuryAccount::Referrals,
+            referral_rewards,
+            &format!("referral rewards for {}", proposal_id),
+        )?;
+    }
+
+    // With a milestone schedule the creator's share stays in escrow until tranches are released
+    let hold_in_escrow = !proposal.financial_terms.milestones.is_empty();
+    if hold_in_escrow {
+        milestones::open_escrow(
+            deps.storage,
+            &proposal_id,
+            creator_amount,
+            progress.total_distributed,
+        )?;
+    } else {
+        ledger::record_entry(
+            deps.storage,
+            &env,
+            &proposal_id,
+            ledger::LedgerEntryKind::CreatorPayout,
+            &proposal.creator,
+            creator_amount,
+        )?;
+
+        // Send funds to creator
+        if !creator_amount.is_zero() {
+            response_messages.extend(escrow::payout_messages(
+                deps.storage,
+                &env,
+                &proposal_id,
+                &proposal.creator,
+                creator_amount,
+            )?);
+        }
+        treasury::debit(
+            deps.storage,
+            treasury::TreasuryAccount::Escrow,
+            creator_amount,
+        )?;
+    }
+
+    // The raise completed, so the creation bond goes back with the payout
+    if let Some(refund) = bonds::refund_bond(deps.storage, &proposal)? {
+        response_messages.push(refund.into());
+    }
+
+    if response_messages.is_empty() {
+        ReentrancyGuard::exit(deps.storage);
+    }
+    Ok(Response::new()
+        .add_submessages(ReentrancyGuard::guarded_messages(
+            response_messages,
+            REPLY_RELEASE_LOCK,
+        ))
+        .add_attribute("method", "finalize_distribution")
+        .add_attribute("proposal_id", &proposal_id)
+        .add_attribute("creator_payout", creator_amount.to_string())
+        .add_attribute("milestone_escrow", hold_in_escrow.to_string())
+        .add_attribute("platform_fee", platform_fee.to_string())
+        .add_attribute("fee_bps", fee_bps.to_string())
+        .add_attribute("referral_rewards", referral_rewards.to_string()))
+}
+
+fn execute_update_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    admin: Option<String>,
+    platform_fee_bps: Option<u16>,
+    min_funding_period_days: Option<u64>,
+    max_funding_period_days: Option<u64>,
+    cw20_code_id: Option<u64>,
+) -> Result<Response, ContractError> {
+    // Only an owner can update config
+    roles::ensure_role(deps.storage, &info.sender, Role::Owner)?;
+    let mut config = CONFIG.load(deps.storage)?;
+
+    if let Some(admin) = admin {
+        config.admin = deps.api.addr_validate(&admin)?;
+    }
+    if let Some(fee) = platform_fee_bps {
+        if fee > 10000 {
+            return Err(ContractError::InvalidPlatformFee {});
+        }
+        config.platform_fee_bps = fee;
+    }
+    if let Some(min_days) = min_funding_period_days {
+        config.min_funding_period_days = min_days;
+    }
+    if let Some(max_days) = max_funding_period_days {
         config.max_funding_period_days = max_days;
     }
     if let Some(code_id) = cw20_code_id {
@@ -917,91 +2118,192 @@ fn execute_update_config(
     Ok(Response::new().add_attribute("method", "update_config"))
 }
 
-fn execute_process_expired_proposals(
+/// Bounds every investment must fall within, alongside each proposal's own minimum (owner only)
+fn execute_set_investment_bounds(
     deps: DepsMut,
-    env: Env,
     info: MessageInfo,
+    min_investment_amount: Option<Uint128>,
+    max_investment_amount: Option<Uint128>,
 ) -> Result<Response, ContractError> {
-    let config = CONFIG.load(deps.storage)?;
-
-    // Only admin can process expired proposals (could be automated via cron)
-    if info.sender != config.admin {
-        return Err(ContractError::Unauthorized {});
+    roles::ensure_role(deps.storage, &info.sender, Role::Owner)?;
+    if let (Some(min), Some(max)) = (min_investment_amount, max_investment_amount) {
+        if min > max {
+            return Err(ContractError::InvalidInput {
+                field: "min_investment_amount".to_string(),
+                message: "Minimum investment cannot exceed the maximum".to_string(),
+            });
+        }
     }
 
+    let mut config = CONFIG.load(deps.storage)?;
+    config.min_investment_amount = min_investment_amount;
+    config.max_investment_amount = max_investment_amount;
+    CONFIG.save(deps.storage, &config)?;
+
+    let bound =
+        |amount: Option<Uint128>| amount.map_or_else(|| "none".to_string(), |a| a.to_string());
+    Ok(Response::new()
+        .add_attribute("method", "set_investment_bounds")
+        .add_attribute("min_investment_amount", bound(min_investment_amount))
+        .add_attribute("max_investment_amount", bound(max_investment_amount)))
+}
+
+/// Proposals settled by one expiry scan
+struct ExpiryBatch {
+    failed: Vec<String>,
+    funded: Vec<String>,        // Past the deadline with the soft cap met
+    bond_refunds: Vec<BankMsg>, // Creation bonds going back to the creators of failed raises
+    next_start_after: Option<String>,
+}
+
+/// Settle proposals past their deadline within one batch: funded if the soft cap was met,
+/// failed otherwise. Also returns the cursor for the next batch
+fn expire_proposals(
+    storage: &mut dyn cosmwasm_std::Storage,
+    env: &Env,
+    start_after: Option<String>,
+    limit: usize,
+) -> Result<ExpiryBatch, ContractError> {
     let current_time = env.block.time.seconds();
-    let mut processed_count = 0u64;
     let mut failed_proposals = Vec::new();
+    let mut funded_proposals = Vec::new();
+    let mut bond_refunds = Vec::new();
+
+    let batch = GasOptimizedStorage::load_batch(storage, start_after, limit)?;
+    let next_start_after = GasOptimizedStorage::next_batch_start(&batch, limit);
 
     // First, collect all proposals that need processing
-    let proposals_to_process: Vec<(String, Proposal)> = PROPOSALS
-        .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
-        .filter_map(|item| {
-            if let Ok((proposal_id, proposal)) = item {
-                if proposal.status == ProposalStatus::Active
-                    && current_time > proposal.financial_terms.funding_deadline
-                    && !proposal.funding_status.is_funded
-                {
-                    return Some((proposal_id, proposal));
-                }
-            }
-            None
+    let proposals_to_process: Vec<(String, Proposal)> = batch
+        .into_iter()
+        .filter(|(_, proposal)| {
+            proposal.status == ProposalStatus::Active
+                && current_time > proposal.financial_terms.funding_deadline
+                && !proposal.funding_status.is_funded
         })
         .collect();
 
     // Now process the collected proposals
     for (proposal_id, mut proposal) in proposals_to_process {
+        proposal.timestamps.updated_at = current_time;
+
+        // A raise that met its soft cap succeeds at the deadline with what it collected
+        if proposal.funding_status.raised_amount >= proposal.financial_terms.minimum_raise() {
+            mark_funded(storage, env, &mut proposal)?;
+            save_proposal(storage, &proposal)?;
+            funded_proposals.push(proposal_id);
+            continue;
+        }
+
         // Validate state transition before updating
-        validate_proposal_state_transition(proposal.status, ProposalStatus::Failed, &proposal, &env)?;
+        validate_proposal_state_transition(proposal.status, ProposalStatus::Failed, &proposal, env)?;
 
         // Mark proposal as failed
         proposal.status = ProposalStatus::Failed;
-        proposal.timestamps.updated_at = current_time;
-        PROPOSALS.save(deps.storage, proposal_id.clone(), &proposal)?;
+        save_proposal(storage, &proposal)?;
+        compliance::release_issuer_raise(storage, &proposal.creator, &proposal_id)?;
+        bond_refunds.extend(bonds::settle_failed_raise(storage, env, &proposal)?);
+
+        // Investments stay Pending so ClaimRefund and RefundInvestors can still pay them out
+        failed_proposals.push(proposal_id);
+    }
 
-        failed_proposals.push(proposal_id.clone());
-        processed_count += 1;
+    Ok(ExpiryBatch {
+        failed: failed_proposals,
+        funded: funded_proposals,
+        bond_refunds,
+        next_start_after,
+    })
+}
 
-        // Auto-trigger refunds for failed proposals
-        let investors = PROPOSAL_INVESTMENTS
-            .may_load(deps.storage, proposal_id.clone())?
-            .unwrap_or_default();
+fn execute_process_expired_proposals(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    // Permissionless so expiry does not depend on the admin; keepers earn a reward per proposal
+    let current_time = env.block.time.seconds();
 
-        for investor in investors {
-            if let Ok(mut investment) =
-                INVESTMENTS.load(deps.storage, (proposal_id.clone(), &investor))
-            {
-                if investment.status == InvestmentStatus::Pending {
-                    investment.status = InvestmentStatus::Refunded;
-                    INVESTMENTS.save(
-                        deps.storage,
-                        (proposal_id.clone(), &investor),
-                        &investment,
-                    )?;
-                }
-            }
-        }
-    }
+    // Scan one batch of proposals, sized from gas estimates unless the caller overrides it
+    let limit = limit
+        .unwrap_or_else(|| GasOptimizer::maintenance_batch_size("process_expired_proposal"))
+        .max(1) as usize;
+    let expired = expire_proposals(deps.storage, &env, start_after, limit)?;
+    let processed_count = (expired.failed.len() + expired.funded.len()) as u64;
+
+    let reward = keeper::reward_keeper(deps.storage, &info.sender, processed_count, current_time)?;
 
     let mut response = Response::new()
+        .add_messages(expired.bond_refunds)
         .add_attribute("method", "process_expired_proposals")
-        .add_attribute("processed_count", processed_count.to_string());
+        .add_attribute("processed_count", processed_count.to_string())
+        .add_attribute("batch_size", limit.to_string())
+        .add_attribute("keeper", info.sender.to_string())
+        .add_attribute("keeper_reward", reward.to_string());
+
+    if !reward.is_zero() {
+        response = response.add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![Coin {
+                denom: "untrn".to_string(),
+                amount: reward,
+            }],
+        });
+    }
 
-    if !failed_proposals.is_empty() {
-        response = response.add_attribute("failed_proposals", failed_proposals.join(","));
+    if !expired.failed.is_empty() {
+        response = response.add_attribute("failed_proposals", expired.failed.join(","));
+    }
+    if !expired.funded.is_empty() {
+        response = response.add_attribute("funded_proposals", expired.funded.join(","));
+    }
+    if let Some(next) = expired.next_start_after {
+        response = response.add_attribute("next_start_after", next);
     }
 
     Ok(response)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn reply(deps: DepsMut, env: Env, msg: cosmwasm_std::Reply) -> Result<Response, ContractError> {
-    match msg.id {
-        REPLY_INSTANTIATE_TOKEN => handle_token_instantiate_reply(deps, env, msg),
+pub fn sudo(mut deps: DepsMut, env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
+    let audit = audit::AuditScope::begin(deps.storage)?;
+    let response = match msg {
+        SudoMsg::ScheduledMaintenance { limit } => {
+            scheduler::run_scheduled_maintenance(deps.branch(), &env, limit)
+        }
+    }?;
+    let response = notifications::flush(deps.storage, response)?;
+
+    audit.finish(deps.storage, &env, &env.contract.address, &response)?;
+    Ok(events::standardize(response))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(
+    mut deps: DepsMut,
+    env: Env,
+    msg: cosmwasm_std::Reply,
+) -> Result<Response, ContractError> {
+    let audit = audit::AuditScope::begin(deps.storage)?;
+    let response = match msg.id {
+        REPLY_INSTANTIATE_TOKEN => handle_token_instantiate_reply(deps.branch(), env.clone(), msg),
+        REPLY_RELEASE_LOCK => {
+            ReentrancyGuard::exit(deps.storage);
+            Ok(Response::new())
+        }
+        REPLY_ESCROW_DEPOSIT => escrow::handle_deposit_reply(deps.branch(), msg),
+        REPLY_GOVERNANCE_HOOK => governance_hooks::handle_reply(deps.branch(), &env, msg),
+        REPLY_DISTRIBUTION_MINT => distribution::handle_mint_reply(deps.branch(), &env, msg),
+        REPLY_GOVERNANCE_ACTION => governance::handle_action_reply(deps.branch(), msg),
         _ => Err(ContractError::Std(cosmwasm_std::StdError::generic_err(
             "Unknown reply ID",
         ))),
-    }
+    }?;
+    let response = notifications::flush(deps.storage, response)?;
+
+    audit.finish(deps.storage, &env, &env.contract.address, &response)?;
+    Ok(events::standardize(response))
 }
 
 fn handle_token_instantiate_reply(
@@ -1009,6 +2311,8 @@ fn handle_token_instantiate_reply(
     env: Env,
     msg: cosmwasm_std::Reply,
 ) -> Result<Response, ContractError> {
+    let proposal_id: String = cosmwasm_std::from_json(&msg.payload)?;
+
     // Parse the instantiate result to get the token contract address
     let contract_address = match msg.result {
         SubMsgResult::Ok(res) => {
@@ -1023,32 +2327,45 @@ fn handle_token_instantiate_reply(
                 })?
         }
         SubMsgResult::Err(err) => {
-            return Err(ContractError::ReplyParseFailed {
-                message: format!("Submessage failed: {}", err),
-            });
+            // Nothing was created, so the proposal can be minted again
+            let mut proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+            proposal.funding_status.tokens_minted = false;
+            proposal.timestamps.updated_at = env.block.time.seconds();
+            save_proposal(deps.storage, &proposal)?;
+            crate::state::MINT_FAILURES.update(
+                deps.storage,
+                proposal_id.clone(),
+                |failure| -> StdResult<_> {
+                    Ok(crate::state::MintFailure {
+                        error: err.clone(),
+                        attempts: failure.map_or(0, |failure| failure.attempts) + 1,
+                        failed_at: env.block.time.seconds(),
+                    })
+                },
+            )?;
+            ReentrancyGuard::exit(deps.storage);
+
+            return Ok(Response::new()
+                .add_attribute("method", "token_instantiate_failed")
+                .add_attribute("proposal_id", &proposal_id)
+                .add_attribute("error", err));
         }
     };
     let token_addr = deps.api.addr_validate(&contract_address)?;
 
-    // Retrieve the proposal ID we stored before the submessage
-    let proposal_id = PENDING_TOKEN_REPLY.load(deps.storage)?;
-
     // Store the token contract address for this proposal
     TOKEN_CONTRACTS.save(deps.storage, proposal_id.clone(), &token_addr)?;
+    TOKEN_PROPOSALS.save(deps.storage, &token_addr, &proposal_id)?;
+    crate::state::MINT_FAILURES.remove(deps.storage, proposal_id.clone());
 
-    // Load the proposal to update its status
+    // Load the proposal to update its status; the lockup already started when it was funded
     let mut proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
-
-    // Set the lockup end timestamp (12 months from now)
-    proposal.timestamps.lockup_end =
-        Some(env.block.time.seconds() + crate::state::LOCKUP_PERIOD_SECONDS);
     proposal.timestamps.updated_at = env.block.time.seconds();
 
     // Update proposal status to indicate tokens are ready for distribution
-    PROPOSALS.save(deps.storage, proposal_id.clone(), &proposal)?;
+    save_proposal(deps.storage, &proposal)?;
 
-    // Clean up the temporary storage
-    PENDING_TOKEN_REPLY.remove(deps.storage);
+    ReentrancyGuard::exit(deps.storage);
 
     Ok(Response::new()
         .add_attribute("method", "token_instantiated")
@@ -1056,17 +2373,268 @@ fn handle_token_instantiate_reply(
         .add_attribute("token_address", token_addr.to_string()))
 }
 
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(
+    deps: DepsMut,
+    _env: Env,
+    _msg: crate::msg::MigrateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    // Earlier versions only wrote hot data at creation and kept no status index, counts or running
+    // aggregates, so rebuild all of them from the stored proposals and positions
+    let mut proposals = PROPOSALS
+        .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        .map(|item| item.map(|(_, proposal)| proposal))
+        .collect::<StdResult<Vec<_>>>()?;
+    // Older versions counted withdrawn and refunded investors forever, so recount open positions
+    for proposal in &mut proposals {
+        let mut investor_count = 0u64;
+        for item in INVESTMENTS.prefix(proposal.id.clone()).range(
+            deps.storage,
+            None,
+            None,
+            cosmwasm_std::Order::Ascending,
+        ) {
+            if item?.1.status != InvestmentStatus::Refunded {
+                investor_count += 1;
+            }
+        }
+        proposal.funding_status.investor_count = investor_count;
+    }
+    crate::state::STATUS_INDEX.clear(deps.storage);
+    crate::state::STATUS_COUNTS.clear(deps.storage);
+    crate::state::ACTIVE_PROPOSALS.clear(deps.storage);
+    crate::state::CATEGORY_INDEX.clear(deps.storage);
+    crate::state::CATEGORY_COUNTS.clear(deps.storage);
+    crate::state::ASSET_TYPE_INDEX.clear(deps.storage);
+    crate::state::ASSET_TYPE_COUNTS.clear(deps.storage);
+    crate::state::TARGET_INDEX.clear(deps.storage);
+    crate::state::APY_INDEX.clear(deps.storage);
+    // Earlier versions keyed the creator index by creation time rather than by sequence
+    crate::state::CREATOR_PROPOSAL_INDEX.clear(deps.storage);
+    let total_value_locked: Uint128 = proposals
+        .iter()
+        .map(|proposal| proposal.funding_status.raised_amount)
+        .sum();
+    crate::state::TOTAL_VALUE_LOCKED.save(deps.storage, &total_value_locked)?;
+    // Older versions kept an entry for every address that ever invested, even after it exited
+    let investors = USER_INVESTMENTS
+        .keys(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for investor in &investors {
+        crate::state::UNIQUE_INVESTORS.save(deps.storage, investor, &cosmwasm_std::Empty {})?;
+    }
+    let total_investors = crate::state::UNIQUE_INVESTORS
+        .keys(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        .count() as u64;
+    crate::state::AGGREGATE_PLATFORM_STATS.save(
+        deps.storage,
+        &crate::state::AggregatePlatformStats { total_investors },
+    )?;
+    for proposal in &proposals {
+        // Rewrites an expected_apy string from before basis points in the current format
+        PROPOSALS.save(deps.storage, proposal.id.clone(), proposal)?;
+        crate::state::PROPOSAL_HOT_DATA.save(
+            deps.storage,
+            proposal.id.clone(),
+            &crate::state::ProposalHotData::from(proposal),
+        )?;
+        crate::state::STATUS_INDEX.save(
+            deps.storage,
+            crate::state::status_index_key(proposal.status, proposal),
+            &proposal.id,
+        )?;
+        crate::state::STATUS_COUNTS.update(
+            deps.storage,
+            proposal.status.as_str(),
+            |count| -> StdResult<_> { Ok(count.unwrap_or_default() + 1) },
+        )?;
+        if let Some(deadline) = crate::state::active_index_deadline(proposal) {
+            crate::state::ACTIVE_PROPOSALS.save(
+                deps.storage,
+                (deadline, proposal.id.as_str()),
+                &proposal.id,
+            )?;
+        }
+        if let Some(sequence) = crate::state::proposal_sequence(&proposal.id) {
+            crate::state::CREATOR_PROPOSAL_INDEX.save(
+                deps.storage,
+                (&proposal.creator, sequence),
+                &proposal.id,
+            )?;
+        }
+        crate::state::move_listing(
+            deps.storage,
+            crate::state::CATEGORY_INDEX,
+            crate::state::CATEGORY_COUNTS,
+            None,
+            &proposal.asset_details.category,
+            proposal,
+        )?;
+        crate::state::move_listing(
+            deps.storage,
+            crate::state::ASSET_TYPE_INDEX,
+            crate::state::ASSET_TYPE_COUNTS,
+            None,
+            &proposal.asset_details.asset_type,
+            proposal,
+        )?;
+        crate::state::index_terms(deps.storage, None, proposal)?;
+    }
+    // Completed raises count towards their creator's rolling Reg CF total from when they funded;
+    // open ones hold their max raise from when they were created
+    let config = CONFIG.load(deps.storage)?;
+    compliance::ISSUER_RAISES.clear(deps.storage);
+    compliance::COUNTED_RAISES.clear(deps.storage);
+    for proposal in &proposals {
+        let counted = match proposal.timestamps.lockup_end {
+            Some(lockup_end) => Some((
+                lockup_end.saturating_sub(config.lockup_period_seconds),
+                proposal.funding_status.raised_amount,
+            )),
+            None if matches!(
+                proposal.status,
+                ProposalStatus::Draft | ProposalStatus::UnderReview | ProposalStatus::Active
+            ) =>
+            {
+                Some((
+                    proposal.timestamps.created_at,
+                    proposal
+                        .financial_terms
+                        .funding_cap()
+                        .max(proposal.funding_status.raised_amount),
+                ))
+            }
+            None => None,
+        };
+        if let Some((counted_at, amount)) = counted {
+            compliance::record_issuer_raise(
+                deps.storage,
+                &proposal.creator,
+                &proposal.id,
+                counted_at,
+                amount,
+            )?;
+        }
+    }
+    // Positions opened before lot tracking get a single lot at their average price
+    let positions = INVESTMENTS
+        .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        .map(|item| item.map(|(_, investment)| investment))
+        .collect::<StdResult<Vec<_>>>()?;
+    for investment in &positions {
+        let key = (investment.proposal_id.clone(), &investment.investor);
+        if !tax_lots::TAX_LOTS.has(deps.storage, key.clone()) {
+            tax_lots::TAX_LOTS.save(deps.storage, key, &vec![tax_lots::legacy_lot(investment)])?;
+        }
+    }
+    // Realized totals were only introduced with portfolio performance breakdowns
+    ledger::rebuild_realized(deps.storage)?;
+    // Open governance votes from before quorum and threshold were pinned take the current config
+    let governance_config = governance::GOVERNANCE_CONFIG
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    let governance_proposals = governance::GOVERNANCE_PROPOSALS
+        .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        .map(|item| item.map(|(_, proposal)| proposal))
+        .collect::<StdResult<Vec<_>>>()?;
+    for mut proposal in governance_proposals {
+        let unpinned = proposal.quorum_bps == 0 && proposal.threshold_bps == 0;
+        if proposal.status != governance::GovernanceProposalStatus::Open || !unpinned {
+            continue;
+        }
+        proposal.quorum_bps = governance_config.quorum_bps;
+        proposal.threshold_bps = governance_config.threshold_bps;
+        governance::GOVERNANCE_PROPOSALS.save(deps.storage, proposal.id, &proposal)?;
+    }
+
+    Ok(events::standardize(
+        Response::new()
+            .add_attribute("method", "migrate")
+            .add_attribute("proposals_backfilled", proposals.len().to_string()),
+    ))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => to_json_binary(&query_config(deps)?),
-        QueryMsg::Proposal { proposal_id } => to_json_binary(&query_proposal(deps, proposal_id)?),
+        QueryMsg::FeeQuote { proposal_id } => {
+            to_json_binary(&fees::query_fee_quote(deps, proposal_id)?)
+        }
+        QueryMsg::PauseStatus {} => to_json_binary(&pause::query_pause_status(deps)?),
+        QueryMsg::GasStats { start_after, limit } => {
+            to_json_binary(&gas_monitor::query_gas_stats(deps, start_after, limit)?)
+        }
+        QueryMsg::RoleAssignments { start_after, limit } => {
+            to_json_binary(&roles::query_role_assignments(deps, start_after, limit)?)
+        }
+        QueryMsg::Custodians { start_after, limit } => {
+            to_json_binary(&custody::query_custodians(deps, start_after, limit)?)
+        }
+        QueryMsg::InterchainAccount { address } => {
+            to_json_binary(&interchain::query_interchain_account(deps, address)?)
+        }
+        QueryMsg::Proposal { proposal_id } => {
+            to_json_binary(&query_proposal(deps, &env, proposal_id)?)
+        }
+        QueryMsg::ProposalReview { proposal_id } => {
+            to_json_binary(&review::query_proposal_review(deps, proposal_id)?)
+        }
+        QueryMsg::ProposalHistory { proposal_id } => {
+            to_json_binary(&review::query_proposal_history(deps, proposal_id)?)
+        }
+        QueryMsg::CreationBond { proposal_id } => {
+            to_json_binary(&bonds::query_creation_bond(deps, proposal_id)?)
+        }
+        QueryMsg::ProposalFreeze { proposal_id } => {
+            to_json_binary(&freeze::query_proposal_freeze(deps, proposal_id)?)
+        }
+        QueryMsg::BlacklistedCreator { creator } => {
+            to_json_binary(&blacklist::query_blacklisted_creator(deps, creator)?)
+        }
+        QueryMsg::BlacklistedCreators { start_after, limit } => to_json_binary(
+            &blacklist::query_blacklisted_creators(deps, start_after, limit)?,
+        ),
+        QueryMsg::DocumentHistory {
+            proposal_id,
+            start_after,
+            limit,
+        } => to_json_binary(&documents::query_document_history(
+            deps,
+            proposal_id,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::DocumentAttestations {
+            proposal_id,
+            sequence,
+        } => to_json_binary(&documents::query_document_attestations(
+            deps,
+            proposal_id,
+            sequence,
+        )?),
+        QueryMsg::ProposalAuditTrail {
+            proposal_id,
+            start_after,
+            limit,
+        } => to_json_binary(&audit::query_audit_trail(
+            deps,
+            proposal_id,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::ProposalSummary { proposal_id } => to_json_binary(
+            &crate::state::get_proposal_hot_data(deps.storage, &proposal_id)?,
+        ),
         QueryMsg::ProposalsByCreator {
             creator,
             start_after,
             limit,
         } => to_json_binary(&query_proposals_by_creator(
             deps,
+            &env,
             creator,
             start_after,
             limit,
@@ -1077,12 +2645,69 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             limit,
         } => to_json_binary(&query_proposals_by_status(
             deps,
+            &env,
             status,
             start_after,
             limit,
         )?),
+        QueryMsg::ProposalsByCategory {
+            category,
+            start_after,
+            limit,
+        } => to_json_binary(&query_proposals_by_listing(
+            deps,
+            &env,
+            crate::state::CATEGORY_INDEX,
+            crate::state::CATEGORY_COUNTS,
+            &category,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::ProposalsByAssetType {
+            asset_type,
+            start_after,
+            limit,
+        } => to_json_binary(&query_proposals_by_listing(
+            deps,
+            &env,
+            crate::state::ASSET_TYPE_INDEX,
+            crate::state::ASSET_TYPE_COUNTS,
+            &asset_type,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::ProposalsByTerms {
+            min_target,
+            max_target,
+            min_apy_bps,
+            asset_type,
+            start_after,
+            limit,
+        } => to_json_binary(&query_proposals_by_terms(
+            deps,
+            &env,
+            (min_target, max_target),
+            min_apy_bps,
+            asset_type,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::ProposalsExpiringWithin {
+            seconds,
+            start_after,
+            limit,
+        } => to_json_binary(&query_proposals_expiring_within(
+            deps,
+            &env,
+            seconds,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::TrendingProposals { limit } => {
+            to_json_binary(&trending::query_trending_proposals(deps, env, limit)?)
+        }
         QueryMsg::AllProposals { start_after, limit } => {
-            to_json_binary(&query_all_proposals(deps, start_after, limit)?)
+            to_json_binary(&query_all_proposals(deps, &env, start_after, limit)?)
         }
         QueryMsg::Investment {
             proposal_id,
@@ -1103,18 +2728,83 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             start_after,
             limit,
         } => to_json_binary(&query_investments_by_user(deps, user, start_after, limit)?),
+        QueryMsg::SimulateInvestment {
+            proposal_id,
+            amount,
+            denom,
+            investor,
+        } => to_json_binary(&simulation::query_simulate_investment(
+            deps,
+            env,
+            proposal_id,
+            amount,
+            denom,
+            investor,
+        )?),
         QueryMsg::Creator { creator } => to_json_binary(&query_creator(deps, creator)?),
         QueryMsg::CreatorStats { creator } => to_json_binary(&query_creator_stats(deps, creator)?),
         QueryMsg::TotalValueLocked {} => to_json_binary(&query_total_value_locked(deps)?),
         QueryMsg::PlatformStats {} => to_json_binary(&query_platform_stats(deps)?),
+        QueryMsg::DashboardData { user } => {
+            to_json_binary(&query_dashboard_data(deps, &env, user)?)
+        }
         QueryMsg::UserPortfolio {
             user,
             start_after,
             limit,
         } => to_json_binary(&query_user_portfolio(deps, user, start_after, limit)?),
-        QueryMsg::PortfolioPerformance { user } => {
-            to_json_binary(&query_portfolio_performance(deps, user)?)
+        QueryMsg::CreatorRegCfCapacity { creator } => to_json_binary(
+            &compliance::query_creator_reg_cf_capacity(deps, env, creator)?,
+        ),
+        QueryMsg::CostBasis { proposal_id, user } => {
+            to_json_binary(&tax_lots::query_cost_basis(deps, proposal_id, user)?)
+        }
+        QueryMsg::ReceiptsByUser {
+            user,
+            start_after,
+            limit,
+        } => to_json_binary(&receipts::query_receipts_by_user(
+            deps,
+            user,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::ActivityByUser {
+            user,
+            start_after,
+            limit,
+        } => to_json_binary(&activity::query_activity_by_user(
+            deps,
+            user,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::PortfolioPerformance {
+            user,
+            start_after,
+            limit,
+        } => to_json_binary(&query_portfolio_performance(
+            deps,
+            user,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::MintFailure { proposal_id } => {
+            to_json_binary(&crate::state::MINT_FAILURES.may_load(deps.storage, proposal_id)?)
+        }
+        QueryMsg::DistributionProgress { proposal_id } => {
+            to_json_binary(&DISTRIBUTION_PROGRESS.may_load(deps.storage, proposal_id)?)
         }
+        QueryMsg::FailedMints {
+            proposal_id,
+            start_after,
+            limit,
+        } => to_json_binary(&distribution::query_failed_mints(
+            deps,
+            proposal_id,
+            start_after,
+            limit,
+        )?),
         QueryMsg::LockupInfo { proposal_id } => {
             to_json_binary(&query_lockup_info(deps, env, proposal_id)?)
         }
@@ -1127,6 +2817,31 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::PlatformComplianceReport {} => {
             to_json_binary(&query_platform_compliance_report(deps)?)
         }
+        QueryMsg::ComplianceReportPage {
+            start_after,
+            limit,
+            format,
+        } => to_json_binary(&compliance::query_proposal_compliance_page(
+            deps,
+            start_after,
+            limit,
+            format,
+        )?),
+        QueryMsg::ComplianceInvestmentsPage {
+            proposal_id,
+            start_after,
+            limit,
+            format,
+        } => to_json_binary(&compliance::query_investment_compliance_page(
+            deps,
+            proposal_id,
+            start_after,
+            limit,
+            format,
+        )?),
+        QueryMsg::Amendment { proposal_id, voter } => {
+            to_json_binary(&amendments::query_amendment(deps, proposal_id, voter)?)
+        }
         QueryMsg::GovernanceInfo { proposal_id } => {
             to_json_binary(&query_governance_info(deps, proposal_id)?)
         }
@@ -1139,12 +2854,179 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::GovernanceSetupData { proposal_id } => {
             to_json_binary(&query_governance_setup_data(deps, proposal_id)?)
         }
+        QueryMsg::FailedGovernanceHooks { start_after, limit } => to_json_binary(
+            &governance_hooks::query_failed_governance_hooks(deps, start_after, limit)?,
+        ),
+        QueryMsg::GovernanceConfig {} => to_json_binary(
+            &governance::GOVERNANCE_CONFIG
+                .may_load(deps.storage)?
+                .unwrap_or_default(),
+        ),
+        QueryMsg::GovernanceProposal { governance_id } => {
+            to_json_binary(&governance::query_governance_proposal(deps, governance_id)?)
+        }
+        QueryMsg::PropertyManager { proposal_id } => {
+            to_json_binary(&governance::query_property_manager(deps, proposal_id)?)
+        }
+        QueryMsg::OpenGovernanceProposals {
+            proposal_id,
+            start_after,
+            limit,
+        } => to_json_binary(&governance::query_open_governance_proposals(
+            deps,
+            proposal_id,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::GovernanceVotingPower {
+            governance_id,
+            voter,
+        } => to_json_binary(&governance::query_snapshot_voting_power(
+            deps,
+            governance_id,
+            voter,
+        )?),
+        QueryMsg::GovernanceVotes {
+            governance_id,
+            start_after,
+            limit,
+        } => to_json_binary(&governance::query_governance_votes(
+            deps,
+            governance_id,
+            start_after,
+            limit,
+        )?),
         QueryMsg::RateLimitStatus { user, operation } => to_json_binary(
-            &crate::rate_limit::query_rate_limit_status(deps, user, operation)?,
+            &crate::rate_limit::query_rate_limit_status(deps, env, user, operation)?,
         ),
         QueryMsg::RateLimitConfig {} => {
             to_json_binary(&crate::rate_limit::query_rate_limit_config(deps)?)
         }
+        QueryMsg::RateLimitExemptions { start_after, limit } => to_json_binary(
+            &crate::rate_limit::query_rate_limit_exemptions(deps, start_after, limit)?,
+        ),
+        QueryMsg::KeeperConfig {} => to_json_binary(&keeper::query_keeper_config(deps)?),
+        QueryMsg::KeeperStats { keeper } => {
+            to_json_binary(&keeper::query_keeper_stats(deps, keeper)?)
+        }
+        QueryMsg::ReferralConfig {} => to_json_binary(&referral::query_referral_config(deps)?),
+        QueryMsg::ReferrerRewards { referrer } => {
+            to_json_binary(&referral::query_referrer_rewards(deps, referrer)?)
+        }
+        QueryMsg::SchedulerState {} => to_json_binary(&scheduler::query_scheduler_state(deps)?),
+        QueryMsg::VestingStatus { proposal_id } => {
+            to_json_binary(&vesting::query_vesting_status(deps, env, proposal_id)?)
+        }
+        QueryMsg::PendingInvestment { ticket_id } => {
+            to_json_binary(&cosign::query_pending_investment(deps, ticket_id)?)
+        }
+        QueryMsg::CosignConfig {} => to_json_binary(&cosign::query_cosign_config(deps)?),
+        QueryMsg::SignerInfo { signer } => {
+            to_json_binary(&signatures::query_signer_info(deps, signer)?)
+        }
+        QueryMsg::VerificationStatus { investor } => {
+            to_json_binary(&kyc::query_verification_status(deps, env, investor)?)
+        }
+        QueryMsg::VerifiedInvestors { start_after, limit } => {
+            to_json_binary(&kyc::query_verified_investors(deps, start_after, limit)?)
+        }
+        QueryMsg::KycConfig {} => to_json_binary(&kyc::query_kyc_config(deps)?),
+        QueryMsg::InvestorRegCfLimit { investor } => to_json_binary(
+            &investor_limits::query_investor_limit(deps, env, investor)?,
+        ),
+        QueryMsg::InvestorEligibility {
+            proposal_id,
+            investor,
+        } => to_json_binary(&kyc::query_investor_eligibility(
+            deps,
+            env,
+            proposal_id,
+            investor,
+        )?),
+        QueryMsg::WhitelistStatus {
+            proposal_id,
+            investor,
+        } => to_json_binary(&whitelist::query_whitelist_status(
+            deps,
+            proposal_id,
+            investor,
+        )?),
+        QueryMsg::ProposalWhitelist {
+            proposal_id,
+            start_after,
+            limit,
+        } => to_json_binary(&whitelist::query_proposal_whitelist(
+            deps,
+            proposal_id,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::ShareRevocations {
+            proposal_id,
+            start_after,
+            limit,
+        } => to_json_binary(&revocation::query_share_revocations(
+            deps,
+            proposal_id,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::HolderMetrics { proposal_id } => to_json_binary(
+            &concentration::query_holder_metrics(deps, env, proposal_id)?,
+        ),
+        QueryMsg::MilestoneEscrow { proposal_id } => {
+            to_json_binary(&milestones::query_milestone_escrow(deps, proposal_id)?)
+        }
+        QueryMsg::ReconfirmationStatus { proposal_id } => to_json_binary(
+            &reconfirmation::query_reconfirmation_status(deps, proposal_id)?,
+        ),
+        QueryMsg::AssetValuation { proposal_id } => {
+            to_json_binary(&oracle::query_asset_valuation(deps, proposal_id)?)
+        }
+        QueryMsg::ValuationHistory {
+            proposal_id,
+            start_after,
+            limit,
+        } => to_json_binary(&oracle::query_valuation_history(
+            deps,
+            proposal_id,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::Oracles {} => to_json_binary(&oracle::query_oracles(deps)?),
+        QueryMsg::PriceFeed {} => to_json_binary(&oracle::query_price_feed(deps)?),
+        QueryMsg::NtrnUsdPrice {} => to_json_binary(&oracle::query_ntrn_usd_price(deps, env)?),
+        QueryMsg::RetirementReport { proposal_id } => {
+            to_json_binary(&retirement::query_retirement_report(deps, proposal_id)?)
+        }
+        QueryMsg::OperationStatus {
+            sender,
+            operation_id,
+        } => to_json_binary(&idempotency::query_operation_status(
+            deps,
+            env,
+            sender,
+            operation_id,
+        )?),
+        QueryMsg::TreasuryBalances {} => {
+            to_json_binary(&treasury::query_treasury_balances(deps, env)?)
+        }
+        QueryMsg::TreasuryTransfers { start_after, limit } => to_json_binary(
+            &treasury::query_treasury_transfers(deps, start_after, limit)?,
+        ),
+        QueryMsg::TreasuryWithdrawals { start_after, limit } => to_json_binary(
+            &treasury::query_treasury_withdrawals(deps, start_after, limit)?,
+        ),
+        QueryMsg::ProposalLedger {
+            proposal_id,
+            start_after,
+            limit,
+        } => to_json_binary(&ledger::query_proposal_ledger(
+            deps,
+            proposal_id,
+            start_after,
+            limit,
+        )?),
     }
 }
 
@@ -1153,24 +3035,18 @@ fn query_config(deps: Deps) -> StdResult<Config> {
     CONFIG.load(deps.storage)
 }
 
-fn query_proposal(deps: Deps, proposal_id: String) -> StdResult<crate::msg::ProposalResponse> {
-    // Load hot data first for gas efficiency
-    let hot_data = crate::state::get_proposal_hot_data(deps.storage, &proposal_id)?;
-
-    // If we only need basic info, we can return early with hot data
-    // For full proposal details, load the complete proposal
-    let proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
-
-    let funding_progress = calculate_funding_progress(&proposal);
-
-    Ok(crate::msg::ProposalResponse {
-        proposal,
-        funding_progress,
-    })
+fn query_proposal(
+    deps: Deps,
+    env: &Env,
+    proposal_id: String,
+) -> StdResult<crate::msg::ProposalResponse> {
+    // Callers that only need the headline numbers should use ProposalSummary instead
+    proposal_response(deps, env, proposal_id)
 }
 
 fn query_proposals_by_creator(
     deps: Deps,
+    env: &Env,
     creator: String,
     start_after: Option<String>,
     limit: Option<u32>,
@@ -1178,136 +3054,354 @@ fn query_proposals_by_creator(
     let creator_addr = deps.api.addr_validate(&creator)?;
     let limit = limit.unwrap_or(crate::gas_optimization::DEFAULT_LIMIT).min(crate::gas_optimization::MAX_LIMIT) as usize;
 
-    // Use indexed storage for gas efficiency
-    let start_bound = start_after.map(|s| {
-        s.parse::<u64>().unwrap_or(0)
+    // Newest first; the cursor is the proposal sequence number
+    let max = start_after
+        .map(|s| {
+            s.parse::<u64>().map_err(|_| {
+                cosmwasm_std::StdError::generic_err(
+                    "start_after must be a proposal sequence number",
+                )
+            })
+        })
+        .transpose()?
+        .map(cw_storage_plus::Bound::exclusive);
+
+    let mut entries = crate::state::CREATOR_PROPOSAL_INDEX
+        .prefix(&creator_addr)
+        .range(deps.storage, None, max, cosmwasm_std::Order::Descending)
+        .take(limit + 1)
+        .collect::<StdResult<Vec<_>>>()?;
+    let next_key = next_page_key(&mut entries, limit, |(sequence, _)| sequence.to_string());
+
+    let proposals = entries
+        .into_iter()
+        .map(|(_, proposal_id)| proposal_response(deps, env, proposal_id))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(crate::msg::ProposalsResponse {
+        proposals,
+        total_count: CREATOR_PROPOSAL_COUNT
+            .may_load(deps.storage, &creator_addr)?
+            .unwrap_or_default() as u64,
+        next_key,
+    })
+}
+
+fn query_proposals_by_status(
+    deps: Deps,
+    env: &Env,
+    status: ProposalStatus,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<crate::msg::ProposalsResponse> {
+    let limit = limit.unwrap_or(30).min(100) as usize;
+
+    // Newest first; the cursor proposal only supplies its position, so it may have changed status
+    let cursor = start_after
+        .map(|id| PROPOSALS.load(deps.storage, id))
+        .transpose()?;
+    let max = cursor.as_ref().map(|proposal| {
+        cw_storage_plus::Bound::exclusive((proposal.timestamps.created_at, proposal.id.as_str()))
     });
 
-    let mut proposals = Vec::with_capacity(limit);
-    let mut count = 0;
+    let mut proposal_ids = crate::state::STATUS_INDEX
+        .sub_prefix(status.as_str())
+        .range(deps.storage, None, max, cosmwasm_std::Order::Descending)
+        .map(|item| item.map(|(_, proposal_id)| proposal_id))
+        .take(limit + 1)
+        .collect::<StdResult<Vec<_>>>()?;
+    let next_key = next_page_key(&mut proposal_ids, limit, String::clone);
 
-    // Iterate through creator index (gas efficient)
-    for item in crate::state::CREATOR_PROPOSAL_INDEX
-        .prefix(&creator_addr)
-        .range(deps.storage, None, None, cosmwasm_std::Order::Descending)
-    {
-        if count >= limit {
-            break;
-        }
+    let proposals = proposal_ids
+        .into_iter()
+        .map(|proposal_id| proposal_response(deps, env, proposal_id))
+        .collect::<StdResult<Vec<_>>>()?;
 
-        let (timestamp, proposal_id) = item?;
+    Ok(crate::msg::ProposalsResponse {
+        proposals,
+        total_count: crate::state::STATUS_COUNTS
+            .may_load(deps.storage, status.as_str())?
+            .unwrap_or_default(),
+        next_key,
+    })
+}
 
-        // Skip if before start_after
-        if let Some(start) = start_bound {
-            if timestamp <= start {
-                continue;
-            }
-        }
+/// Proposals in one category or of one asset type, newest first
+fn query_proposals_by_listing(
+    deps: Deps,
+    env: &Env,
+    index: cw_storage_plus::Map<(&str, u64, &str), String>,
+    counts: cw_storage_plus::Map<&str, u64>,
+    value: &str,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<crate::msg::ProposalsResponse> {
+    let limit = limit.unwrap_or(30).min(100) as usize;
+    let value = crate::state::listing_key(value);
+
+    // The cursor proposal only supplies its position, as in query_proposals_by_status
+    let cursor = start_after
+        .map(|id| PROPOSALS.load(deps.storage, id))
+        .transpose()?;
+    let max = cursor.as_ref().map(|proposal| {
+        cw_storage_plus::Bound::exclusive((proposal.timestamps.created_at, proposal.id.as_str()))
+    });
 
-        // Load hot data first
-        if let Ok(hot_data) = crate::state::get_proposal_hot_data(deps.storage, &proposal_id) {
-            // Only load full proposal if needed
-            if let Ok(proposal) = PROPOSALS.load(deps.storage, proposal_id) {
-                let funding_progress = calculate_funding_progress(&proposal);
-                proposals.push(crate::msg::ProposalResponse {
-                    proposal,
-                    funding_progress,
-                });
-                count += 1;
-            }
+    let mut proposal_ids = index
+        .sub_prefix(&value)
+        .range(deps.storage, None, max, cosmwasm_std::Order::Descending)
+        .map(|item| item.map(|(_, proposal_id)| proposal_id))
+        .take(limit + 1)
+        .collect::<StdResult<Vec<_>>>()?;
+    let next_key = next_page_key(&mut proposal_ids, limit, String::clone);
+
+    let proposals = proposal_ids
+        .into_iter()
+        .map(|proposal_id| proposal_response(deps, env, proposal_id))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(crate::msg::ProposalsResponse {
+        proposals,
+        total_count: counts.may_load(deps.storage, &value)?.unwrap_or_default(),
+        next_key,
+    })
+}
+
+fn query_proposals_by_terms(
+    deps: Deps,
+    env: &Env,
+    (min_target, max_target): (Option<Uint128>, Option<Uint128>),
+    min_apy_bps: Option<u16>,
+    asset_type: Option<String>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<crate::msg::ProposalsResponse> {
+    use cw_storage_plus::Bound;
+
+    let limit = limit.unwrap_or(30).min(100) as usize;
+    let asset_type = asset_type.as_deref().map(crate::state::listing_key);
+
+    // The index entries carry every term filtered on, so no proposal is loaded to match it
+    let matches = |target: u128, apy_bps: u16, listed_as: &String| {
+        min_target.is_none_or(|min| target >= min.u128())
+            && max_target.is_none_or(|max| target <= max.u128())
+            && min_apy_bps.is_none_or(|min| apy_bps >= min)
+            && asset_type
+                .as_ref()
+                .is_none_or(|asset_type| asset_type == listed_as)
+    };
+    let matching_id = |item: StdResult<(String, u128, u16, String)>| match item {
+        Ok((proposal_id, target, apy_bps, listed_as)) => {
+            matches(target, apy_bps, &listed_as).then_some(Ok(proposal_id))
         }
-    }
+        Err(err) => Some(Err(err)),
+    };
+
+    // The cursor proposal only supplies its position, so it may have changed terms since
+    let cursor = start_after
+        .map(|id| PROPOSALS.load(deps.storage, id))
+        .transpose()?;
+    let cursor = cursor.as_ref().map(|proposal| {
+        (
+            crate::state::terms_index_key(proposal),
+            proposal.id.as_str(),
+        )
+    });
+
+    // Walk whichever index the bounds narrow: by APY when only min_apy_bps is given
+    let (total_count, mut proposal_ids) = if min_target.is_none() && max_target.is_none() {
+        let min = Bound::inclusive((min_apy_bps.unwrap_or_default(), ""));
+        let start = cursor.map(|((_, apy_bps, _), id)| Bound::exclusive((apy_bps, id)));
+        let entries = |start: Bound<(u16, &str)>| {
+            crate::state::APY_INDEX
+                .range(
+                    deps.storage,
+                    Some(start),
+                    None,
+                    cosmwasm_std::Order::Ascending,
+                )
+                .map(|item| {
+                    item.map(|((apy_bps, proposal_id), (target, listed_as))| {
+                        (proposal_id, target, apy_bps, listed_as)
+                    })
+                })
+        };
+        (
+            entries(min.clone())
+                .take(crate::gas_optimization::MAX_COUNT)
+                .filter_map(matching_id)
+                .count(),
+            entries(start.unwrap_or(min))
+                .filter_map(matching_id)
+                .take(limit + 1)
+                .collect::<StdResult<Vec<_>>>()?,
+        )
+    } else {
+        let min = Bound::inclusive((min_target.unwrap_or_default().u128(), ""));
+        let max = max_target
+            .and_then(|max| max.u128().checked_add(1))
+            .map(|end| Bound::exclusive((end, "")));
+        let start = cursor.map(|((target, _, _), id)| Bound::exclusive((target, id)));
+        let entries = |start: Bound<(u128, &str)>| {
+            crate::state::TARGET_INDEX
+                .range(
+                    deps.storage,
+                    Some(start),
+                    max.clone(),
+                    cosmwasm_std::Order::Ascending,
+                )
+                .map(|item| {
+                    item.map(|((target, proposal_id), (apy_bps, listed_as))| {
+                        (proposal_id, target, apy_bps, listed_as)
+                    })
+                })
+        };
+        (
+            entries(min.clone())
+                .take(crate::gas_optimization::MAX_COUNT)
+                .filter_map(matching_id)
+                .count(),
+            entries(start.unwrap_or(min))
+                .filter_map(matching_id)
+                .take(limit + 1)
+                .collect::<StdResult<Vec<_>>>()?,
+        )
+    };
+    let next_key = next_page_key(&mut proposal_ids, limit, String::clone);
+
+    let proposals = proposal_ids
+        .into_iter()
+        .map(|proposal_id| proposal_response(deps, env, proposal_id))
+        .collect::<StdResult<Vec<_>>>()?;
 
     Ok(crate::msg::ProposalsResponse {
         proposals,
-        total_count: count as u64,
+        total_count: total_count as u64,
+        next_key,
     })
 }
 
-fn query_proposals_by_status(
+fn query_proposals_expiring_within(
     deps: Deps,
-    status: ProposalStatus,
+    env: &Env,
+    seconds: u64,
     start_after: Option<String>,
     limit: Option<u32>,
 ) -> StdResult<crate::msg::ProposalsResponse> {
+    use cw_storage_plus::Bound;
+
     let limit = limit.unwrap_or(30).min(100) as usize;
 
-    let proposals: Vec<_> = PROPOSALS
-        .range(deps.storage, None, None, cosmwasm_std::Order::Descending)
-        .filter(|item| {
-            if let Ok((_, proposal)) = item {
-                proposal.status == status
-            } else {
-                false
-            }
-        })
-        .skip_while(|item| {
-            if let Some(start_after) = &start_after {
-                if let Ok((key, _)) = item {
-                    key <= start_after
-                } else {
-                    false
-                }
-            } else {
-                false
-            }
-        })
-        .take(limit)
-        .map(|item| {
-            let (_, proposal) = item?;
-            let funding_progress = calculate_funding_progress(&proposal);
-            Ok(crate::msg::ProposalResponse {
-                proposal,
-                funding_progress,
-            })
-        })
+    // Still open now, closing no later than `seconds` from now
+    let now = env.block.time.seconds();
+    let min = Bound::inclusive((now, ""));
+    let max = Bound::exclusive((now.saturating_add(seconds).saturating_add(1), ""));
+    let total_count = crate::state::ACTIVE_PROPOSALS
+        .keys_raw(
+            deps.storage,
+            Some(min.clone()),
+            Some(max.clone()),
+            cosmwasm_std::Order::Ascending,
+        )
+        .take(crate::gas_optimization::MAX_COUNT)
+        .count() as u64;
+
+    // The cursor proposal only supplies its position, as in query_proposals_by_status
+    let cursor = start_after
+        .map(|id| PROPOSALS.load(deps.storage, id))
+        .transpose()?;
+    let start = cursor
+        .as_ref()
+        .map(|cursor| (cursor.financial_terms.funding_deadline, cursor.id.as_str()))
+        .filter(|(deadline, _)| *deadline >= now)
+        .map_or(min, Bound::exclusive);
+
+    let mut proposal_ids = crate::state::ACTIVE_PROPOSALS
+        .range(
+            deps.storage,
+            Some(start),
+            Some(max),
+            cosmwasm_std::Order::Ascending,
+        )
+        .map(|item| item.map(|(_, proposal_id)| proposal_id))
+        .take(limit + 1)
+        .collect::<StdResult<Vec<_>>>()?;
+    let next_key = next_page_key(&mut proposal_ids, limit, String::clone);
+
+    let proposals = proposal_ids
+        .into_iter()
+        .map(|proposal_id| proposal_response(deps, env, proposal_id))
         .collect::<StdResult<Vec<_>>>()?;
 
-    let total_count = proposals.len() as u64;
     Ok(crate::msg::ProposalsResponse {
         proposals,
         total_count,
+        next_key,
     })
 }
 
 fn query_all_proposals(
     deps: Deps,
+    env: &Env,
     start_after: Option<String>,
     limit: Option<u32>,
 ) -> StdResult<crate::msg::ProposalsResponse> {
     let limit = limit.unwrap_or(30).min(100) as usize;
 
-    let proposals: Vec<_> = PROPOSALS
-        .range(deps.storage, None, None, cosmwasm_std::Order::Descending)
-        .skip_while(|item| {
-            if let Some(start_after) = &start_after {
-                if let Ok((key, _)) = item {
-                    key <= start_after
-                } else {
-                    false
-                }
-            } else {
-                false
-            }
-        })
-        .take(limit)
-        .map(|item| {
-            let (_, proposal) = item?;
-            let funding_progress = calculate_funding_progress(&proposal);
-            Ok(crate::msg::ProposalResponse {
-                proposal,
-                funding_progress,
-            })
-        })
+    // Descending by proposal id, so the cursor bounds the top of the range
+    let max = start_after.map(cw_storage_plus::Bound::exclusive);
+    let mut proposal_ids = PROPOSALS
+        .keys(deps.storage, None, max, cosmwasm_std::Order::Descending)
+        .take(limit + 1)
+        .collect::<StdResult<Vec<_>>>()?;
+    let next_key = next_page_key(&mut proposal_ids, limit, String::clone);
+
+    let proposals = proposal_ids
+        .into_iter()
+        .map(|proposal_id| proposal_response(deps, env, proposal_id))
         .collect::<StdResult<Vec<_>>>()?;
 
-    let total_count = proposals.len() as u64;
     Ok(crate::msg::ProposalsResponse {
         proposals,
-        total_count,
+        total_count: PROPOSAL_COUNT.load(deps.storage)?,
+        next_key,
+    })
+}
+
+fn proposal_response(
+    deps: Deps,
+    env: &Env,
+    proposal_id: String,
+) -> StdResult<crate::msg::ProposalResponse> {
+    let proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+    let funding_progress = calculate_funding_progress(deps.storage, env, &proposal)?;
+    let creator_verification = verification::creator_tier(deps.storage, &proposal.creator)?;
+    Ok(crate::msg::ProposalResponse {
+        expected_apy: math::format_percentage(
+            proposal.financial_terms.expected_apy_bps as u128,
+            10_000,
+        ),
+        frozen: freeze::is_frozen(deps.storage, &proposal.id),
+        proposal,
+        funding_progress,
+        creator_verification,
     })
 }
 
+/// Trim a page fetched with one extra item back to `limit`, returning the cursor for the next
+/// page when that extra item was there
+pub(crate) fn next_page_key<T>(
+    items: &mut Vec<T>,
+    limit: usize,
+    key: impl Fn(&T) -> String,
+) -> Option<String> {
+    if items.len() <= limit {
+        return None;
+    }
+    items.truncate(limit);
+    items.last().map(key)
+}
+
 fn query_investment(
     deps: Deps,
     proposal_id: String,
@@ -1320,7 +3414,7 @@ fn query_investment(
     Ok(crate::msg::InvestmentResponse {
         investment: investment.clone(),
         proposal_title: proposal.asset_details.name.clone(),
-        current_value: calculate_current_investment_value(&investment, &proposal),
+        current_value: oracle::mark_to_market(deps.storage, &investment, &proposal)?,
     })
 }
 
@@ -1332,26 +3426,23 @@ fn query_investments_by_proposal(
 ) -> StdResult<crate::msg::InvestmentsResponse> {
     let limit = limit.unwrap_or(30).min(100) as usize;
     let proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+    let start_addr = start_after
+        .map(|s| deps.api.addr_validate(&s))
+        .transpose()?;
+    let start = start_addr.as_ref().map(cw_storage_plus::Bound::exclusive);
 
-    let investments: Vec<_> = INVESTMENTS
-        .prefix(proposal_id.clone())
-        .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
-        .skip_while(|item| {
-            if let Some(start_after) = &start_after {
-                if let Ok((addr, _)) = item {
-                    addr.as_str() <= start_after
-                } else {
-                    false
-                }
-            } else {
-                false
-            }
-        })
-        .take(limit)
-        .map(|item| {
-            let (_, investment) = item?;
+    let mut entries = INVESTMENTS
+        .prefix(proposal_id)
+        .range(deps.storage, start, None, cosmwasm_std::Order::Ascending)
+        .take(limit + 1)
+        .collect::<StdResult<Vec<_>>>()?;
+    let next_key = next_page_key(&mut entries, limit, |(investor, _)| investor.to_string());
+
+    let investments = entries
+        .into_iter()
+        .map(|(_, investment)| {
             Ok(crate::msg::InvestmentResponse {
-                current_value: calculate_current_investment_value(&investment, &proposal),
+                current_value: oracle::mark_to_market(deps.storage, &investment, &proposal)?,
                 proposal_title: proposal.asset_details.name.clone(),
                 investment,
             })
@@ -1363,6 +3454,7 @@ fn query_investments_by_proposal(
     Ok(crate::msg::InvestmentsResponse {
         investments,
         total_invested,
+        next_key,
     })
 }
 
@@ -1373,58 +3465,23 @@ fn query_investments_by_user(
     limit: Option<u32>,
 ) -> StdResult<crate::msg::InvestmentsResponse> {
     let user_addr = deps.api.addr_validate(&user)?;
-    let limit = limit.unwrap_or(30).min(100) as usize;
-
-    // Get user's proposal IDs (this would be more efficient with proper indexing)
-    let user_investments: Vec<_> = INVESTMENTS
-        .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
-        .filter(|item| {
-            if let Ok(((_, addr), _)) = item {
-                addr == &user_addr
-            } else {
-                false
-            }
-        })
-        .skip_while(|item| {
-            if let Some(start_after) = &start_after {
-                if let Ok(((proposal_id, _), _)) = item {
-                    proposal_id <= start_after
-                } else {
-                    false
-                }
-            } else {
-                false
-            }
-        })
-        .take(limit)
-        .map(|item| {
-            let ((proposal_id, _), investment) = item?;
-            let proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
-            Ok(crate::msg::InvestmentResponse {
-                current_value: calculate_current_investment_value(&investment, &proposal),
-                proposal_title: proposal.asset_details.name,
-                investment,
-            })
-        })
-        .collect::<StdResult<Vec<_>>>()?;
-
-    let total_invested: Uint128 = user_investments
-        .iter()
-        .map(|inv| inv.investment.amount)
-        .sum();
-
-    Ok(crate::msg::InvestmentsResponse {
-        investments: user_investments,
-        total_invested,
-    })
+    helpers::get_user_portfolio(deps, &user_addr, start_after, limit)
 }
 
 fn query_creator(deps: Deps, creator: String) -> StdResult<crate::msg::CreatorResponse> {
     let creator_addr = deps.api.addr_validate(&creator)?;
     let creator = CREATORS.load(deps.storage, &creator_addr)?;
     let stats = calculate_creator_stats(&creator);
-
-    Ok(crate::msg::CreatorResponse { creator, stats })
+    let interchain_account =
+        interchain::INTERCHAIN_ACCOUNTS.may_load(deps.storage, &creator_addr)?;
+    let profile = profiles::CREATOR_PROFILES.may_load(deps.storage, &creator_addr)?;
+
+    Ok(crate::msg::CreatorResponse {
+        creator,
+        stats,
+        interchain_account,
+        profile,
+    })
 }
 
 fn query_creator_stats(deps: Deps, creator: String) -> StdResult<crate::msg::CreatorStats> {
@@ -1435,14 +3492,9 @@ fn query_creator_stats(deps: Deps, creator: String) -> StdResult<crate::msg::Cre
 }
 
 fn query_total_value_locked(deps: Deps) -> StdResult<Uint128> {
-    let mut total: Uint128 = Uint128::zero();
-
-    for item in PROPOSALS.range(deps.storage, None, None, cosmwasm_std::Order::Ascending) {
-        let (_, proposal) = item?;
-        total += proposal.funding_status.raised_amount;
-    }
-
-    Ok(total)
+    Ok(crate::state::TOTAL_VALUE_LOCKED
+        .may_load(deps.storage)?
+        .unwrap_or_default())
 }
 
 fn query_platform_stats(deps: Deps) -> StdResult<crate::msg::PlatformStats> {
@@ -1453,13 +3505,42 @@ fn query_platform_stats(deps: Deps) -> StdResult<crate::msg::PlatformStats> {
         total_proposals: efficient_stats.total_proposals,
         active_proposals: efficient_stats.active_proposals,
         total_raised: efficient_stats.total_raised,
-        total_investors: 0, // Calculate separately if needed
+        total_investors: crate::state::AGGREGATE_PLATFORM_STATS
+            .may_load(deps.storage)?
+            .unwrap_or_default()
+            .total_investors, // Each address counts once regardless of how many deals it backs
         successful_proposals: efficient_stats.funded_proposals,
     })
 }
 
+fn query_dashboard_data(
+    deps: Deps,
+    env: &Env,
+    user: Option<String>,
+) -> StdResult<crate::msg::DashboardResponse> {
+    let user = user.map(|user| deps.api.addr_validate(&user)).transpose()?;
+    let (portfolio, rate_limits) = match &user {
+        Some(user) => (
+            Some(helpers::get_portfolio_performance(deps, user, None, None)?),
+            rate_limit::RateLimiter::get_user_statuses(deps.storage, user, env)?,
+        ),
+        None => (None, vec![]),
+    };
+
+    Ok(crate::msg::DashboardResponse {
+        config: query_config(deps)?,
+        platform_stats: query_platform_stats(deps)?,
+        portfolio,
+        rate_limits,
+    })
+}
+
 // Helper functions
-fn calculate_funding_progress(proposal: &Proposal) -> crate::msg::FundingProgress {
+fn calculate_funding_progress(
+    storage: &dyn cosmwasm_std::Storage,
+    env: &Env,
+    proposal: &Proposal,
+) -> StdResult<crate::msg::FundingProgress> {
     let raised_percentage = if proposal.financial_terms.target_amount.is_zero() {
         0u64
     } else {
@@ -1467,39 +3548,66 @@ fn calculate_funding_progress(proposal: &Proposal) -> crate::msg::FundingProgres
             / proposal.financial_terms.target_amount.u128()) as u64
     };
 
-    let shares_sold = if proposal.financial_terms.token_price.is_zero() {
-        0u64
-    } else {
-        (proposal.funding_status.raised_amount.u128() / proposal.financial_terms.token_price.u128())
-            as u64
-    };
+    let shares_sold = shares_sold(storage, proposal)?;
+    let raised = proposal.funding_status.raised_amount;
+    let tier = pricing::active_tier(&proposal.financial_terms, raised);
 
     let shares_remaining = proposal
         .financial_terms
-        .total_shares
+        .sellable_shares()
         .saturating_sub(shares_sold);
 
-    // Calculate days remaining using current time
-    let current_time = 1640995200u64; // Placeholder - in real usage, this would come from env.block.time
-    let days_remaining = if proposal.timestamps.funding_deadline > current_time {
-        ((proposal.timestamps.funding_deadline - current_time) / (24 * 60 * 60)) as i64
-    } else {
-        0i64
-    };
+    // Whole days and hours left before the deadline, at the time of the query
+    let seconds_remaining = proposal
+        .timestamps
+        .funding_deadline
+        .saturating_sub(env.block.time.seconds());
+    let days_remaining = (seconds_remaining / (24 * 60 * 60)) as i64;
+    let hours_remaining = (seconds_remaining / (60 * 60)) as i64;
+    let investor_cap = proposal.compliance.investor_cap();
 
-    crate::msg::FundingProgress {
+    Ok(crate::msg::FundingProgress {
         raised_percentage,
         days_remaining,
+        hours_remaining,
+        funding_deadline_iso: format_iso8601(proposal.timestamps.funding_deadline),
         investors_count: proposal.funding_status.investor_count,
+        investor_cap,
+        investor_slots_remaining: investor_cap
+            .saturating_sub(proposal.funding_status.investor_count),
         shares_sold,
         shares_remaining,
-    }
+        current_tier: tier.index,
+        current_price: tier.token_price,
+        tier_remaining: tier.remaining,
+    })
 }
 
-fn calculate_current_investment_value(investment: &Investment, _proposal: &Proposal) -> Uint128 {
-    // For now, return the original investment amount
-    // In production, this would calculate current market value
-    investment.amount
+/// UTC timestamp such as "2024-03-01T12:00:00Z", for frontends that don't convert unix seconds
+fn format_iso8601(seconds: u64) -> String {
+    let days = (seconds / 86_400) as i64;
+    let secs_of_day = seconds % 86_400;
+
+    // Civil date from days since 1970-01-01 (Howard Hinnant's algorithm)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        secs_of_day / 3_600,
+        secs_of_day % 3_600 / 60,
+        secs_of_day % 60
+    )
 }
 
 fn calculate_creator_stats(creator: &Creator) -> crate::msg::CreatorStats {
@@ -1527,6 +3635,15 @@ fn calculate_creator_stats(creator: &Creator) -> crate::msg::CreatorStats {
 
 // Investment calculation functions
 fn calculate_shares(proposal: &Proposal, investment_amount: Uint128) -> Result<u64, ContractError> {
+    // Tiered raises price each slice of the investment at the tier it falls in
+    if !proposal.financial_terms.pricing_tiers.is_empty() {
+        let raised = proposal.funding_status.raised_amount;
+        let raised_after = MathGuard::safe_add(raised, investment_amount)?;
+        let terms = &proposal.financial_terms;
+        return Ok(pricing::shares_for_raised(terms, raised_after)
+            .saturating_sub(pricing::shares_for_raised(terms, raised)));
+    }
+
     // Validate inputs first
     MathGuard::validate_calculation_inputs(
         investment_amount,
@@ -1544,7 +3661,59 @@ fn calculate_shares(proposal: &Proposal, investment_amount: Uint128) -> Result<u
     )
 }
 
+/// Shares an investment buys, the part of it accepted and the change handed back
+fn fill_investment(
+    proposal: &Proposal,
+    current_shares_sold: u64,
+    investment_amount: Uint128,
+) -> Result<(u64, Uint128, Uint128), ContractError> {
+    let shares = calculate_shares(proposal, investment_amount)?;
+
+    // Check if investment exceeds available shares
+    let total_shares_after_investment = current_shares_sold.saturating_add(shares);
+    if total_shares_after_investment <= proposal.financial_terms.sellable_shares() {
+        return Ok((shares, investment_amount, Uint128::zero()));
+    }
+    let remaining_shares = calculate_remaining_shares(proposal, current_shares_sold);
+    if !proposal.financial_terms.allow_partial_fill || remaining_shares == 0 {
+        return Err(ContractError::InvestmentExceedsAvailable {});
+    }
+
+    // Fill what is left and hand the excess back to the investor
+    let accepted_amount = if proposal.financial_terms.pricing_tiers.is_empty() {
+        MathGuard::safe_mul(
+            Uint128::from(remaining_shares),
+            proposal.financial_terms.token_price,
+        )?
+    } else {
+        pricing::cost_of_shares(
+            &proposal.financial_terms,
+            proposal.funding_status.raised_amount,
+            remaining_shares,
+        )?
+    };
+    let change = MathGuard::safe_sub(investment_amount, accepted_amount)?;
+    Ok((remaining_shares, accepted_amount, change))
+}
+
+/// Shares committed to investors so far, as recorded in the escrow ledger
+pub(crate) fn shares_sold(
+    storage: &dyn cosmwasm_std::Storage,
+    proposal: &Proposal,
+) -> StdResult<u64> {
+    // Proposals funded before the ledger existed fall back to deriving it from the raise
+    Ok(ESCROWED_SHARES
+        .may_load(storage, proposal.id.clone())?
+        .unwrap_or_else(|| calculate_current_shares_sold(proposal)))
+}
+
 fn calculate_current_shares_sold(proposal: &Proposal) -> u64 {
+    if !proposal.financial_terms.pricing_tiers.is_empty() {
+        return pricing::shares_for_raised(
+            &proposal.financial_terms,
+            proposal.funding_status.raised_amount,
+        );
+    }
     if proposal.financial_terms.token_price.is_zero() {
         return 0;
     }
@@ -1565,25 +3734,13 @@ fn calculate_current_shares_sold(proposal: &Proposal) -> u64 {
     }
 }
 
-fn calculate_remaining_shares(proposal: &Proposal) -> u64 {
-    let shares_sold = calculate_current_shares_sold(proposal);
+fn calculate_remaining_shares(proposal: &Proposal, shares_sold: u64) -> u64 {
     proposal
         .financial_terms
-        .total_shares
+        .sellable_shares()
         .saturating_sub(shares_sold)
 }
 
-fn calculate_maximum_investment(proposal: &Proposal) -> Uint128 {
-    let remaining_shares = calculate_remaining_shares(proposal);
-    let remaining_shares_u128 = Uint128::from(remaining_shares as u128);
-
-    // Use safe multiplication to prevent overflow attacks
-    match MathGuard::safe_mul(remaining_shares_u128, proposal.financial_terms.token_price) {
-        Ok(max_investment) => max_investment,
-        Err(_) => Uint128::MAX, // Return maximum if overflow would occur
-    }
-}
-
 // Portfolio query functions
 fn query_user_portfolio(
     deps: Deps,
@@ -1598,9 +3755,11 @@ fn query_user_portfolio(
 fn query_portfolio_performance(
     deps: Deps,
     user: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
 ) -> StdResult<helpers::PortfolioPerformance> {
     let user_addr = deps.api.addr_validate(&user)?;
-    helpers::get_portfolio_performance(deps, &user_addr)
+    helpers::get_portfolio_performance(deps, &user_addr, start_after, limit)
 }
 
 // Lockup query functions
@@ -1683,12 +3842,26 @@ fn validate_proposal_state_transition(
                     message: "Cannot mark as failed before deadline".to_string(),
                 });
             }
+            // Meeting the soft cap makes the raise a success, not a failure
+            if proposal.funding_status.raised_amount >= proposal.financial_terms.minimum_raise() {
+                return Err(ContractError::InvalidInput {
+                    field: "funding_status".to_string(),
+                    message: "Proposal reached its soft cap and cannot fail".to_string(),
+                });
+            }
         }
-        (ProposalStatus::Active, ProposalStatus::Cancelled) => {
+        (ProposalStatus::Active, ProposalStatus::Cancelled)
+        | (ProposalStatus::Draft, ProposalStatus::Cancelled)
+        | (ProposalStatus::UnderReview, ProposalStatus::Cancelled) => {
             // Can be cancelled by admin or creator
             // Additional validation would happen in access control
         }
 
+        // Listing review: drafts are submitted, then approved or sent back
+        (ProposalStatus::Draft, ProposalStatus::UnderReview)
+        | (ProposalStatus::UnderReview, ProposalStatus::Active)
+        | (ProposalStatus::UnderReview, ProposalStatus::Draft) => {}
+
         // Funded proposals can only transition to Completed
         (ProposalStatus::Funded, ProposalStatus::Completed) => {
             // Must have tokens minted and distributed
@@ -1700,6 +3873,18 @@ fn validate_proposal_state_transition(
             }
         }
 
+        // A material change can drop a funded raise below target during reconfirmation
+        (ProposalStatus::Funded, ProposalStatus::Active)
+        | (ProposalStatus::Funded, ProposalStatus::Failed) => {
+            if proposal.funding_status.is_funded {
+                return Err(ContractError::InvalidInput {
+                    field: "funding_status".to_string(),
+                    message: "Funded proposals only reopen or fail after falling below target"
+                        .to_string(),
+                });
+            }
+        }
+
         // Failed and Cancelled proposals cannot transition to other states
         (ProposalStatus::Failed, _) | (ProposalStatus::Cancelled, _) => {
             return Err(ContractError::InvalidInput {
@@ -1708,6 +3893,9 @@ fn validate_proposal_state_transition(
             });
         }
 
+        // Completed assets can be wound down; the holder vote is checked by the caller
+        (ProposalStatus::Completed, ProposalStatus::Retired) => {}
+
         // Completed proposals cannot transition to other states
         (ProposalStatus::Completed, _) => {
             return Err(ContractError::InvalidInput {
@@ -1736,12 +3924,8 @@ fn execute_update_rate_limit_config(
     max_operations: Option<u32>,
     enabled: Option<bool>,
 ) -> Result<Response, ContractError> {
-    let config = CONFIG.load(deps.storage)?;
-
-    // Only admin can update rate limit config
-    if config.admin != info.sender {
-        return Err(ContractError::Unauthorized {});
-    }
+    // Only an operator can update rate limit config
+    roles::ensure_role(deps.storage, &info.sender, Role::Operator)?;
 
     rate_limit::RateLimiter::update_config(deps.storage, window_seconds, max_operations, enabled)?;
 
@@ -1757,12 +3941,8 @@ fn execute_update_operation_limit(
     max_per_window: u32,
     window_seconds: u64,
 ) -> Result<Response, ContractError> {
-    let config = CONFIG.load(deps.storage)?;
-
-    // Only admin can update operation limits
-    if config.admin != info.sender {
-        return Err(ContractError::Unauthorized {});
-    }
+    // Only an operator can update operation limits
+    roles::ensure_role(deps.storage, &info.sender, Role::Operator)?;
 
     rate_limit::RateLimiter::update_operation_limit(
         deps.storage,
@@ -1777,3 +3957,56 @@ fn execute_update_operation_limit(
         .add_attribute("max_per_window", max_per_window.to_string())
         .add_attribute("window_seconds", window_seconds.to_string()))
 }
+
+fn execute_add_rate_limit_exemption(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+    multiplier: Option<u32>,
+) -> Result<Response, ContractError> {
+    // Only an operator can grant rate limit exemptions
+    roles::ensure_role(deps.storage, &info.sender, Role::Operator)?;
+
+    let address = deps.api.addr_validate(&address)?;
+    rate_limit::RateLimiter::add_exemption(deps.storage, address.clone(), multiplier)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "add_rate_limit_exemption")
+        .add_attribute("address", address)
+        .add_attribute(
+            "multiplier",
+            multiplier.map_or("unlimited".to_string(), |multiplier| multiplier.to_string()),
+        ))
+}
+
+fn execute_remove_rate_limit_exemption(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    // Only an operator can revoke rate limit exemptions
+    roles::ensure_role(deps.storage, &info.sender, Role::Operator)?;
+
+    let address = deps.api.addr_validate(&address)?;
+    rate_limit::RateLimiter::remove_exemption(deps.storage, &address)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "remove_rate_limit_exemption")
+        .add_attribute("address", address))
+}
+
+fn execute_prune_rate_limit_data(
+    deps: DepsMut,
+    env: Env,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let limit = limit
+        .unwrap_or(rate_limit::DEFAULT_PRUNE_LIMIT)
+        .clamp(1, rate_limit::MAX_PRUNE_LIMIT);
+    let pruned = rate_limit::RateLimiter::cleanup_old_data(deps.storage, &env, limit as usize)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "prune_rate_limit_data")
+        .add_attribute("pruned_count", pruned.to_string())
+        .add_attribute("batch_size", limit.to_string()))
+}
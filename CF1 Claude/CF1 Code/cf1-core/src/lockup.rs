@@ -1,10 +1,167 @@
-use cosmwasm_std::{Addr, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Uint128};
+use cosmwasm_std::{Addr, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Storage};
 use cw20::Cw20ExecuteMsg;
+use cw_storage_plus::Map;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::error::ContractError;
-use crate::state::{Proposal, ProposalStatus, PROPOSALS, TOKEN_CONTRACTS};
+use crate::gas_optimization::{GasOptimizedStorage, GasOptimizer};
+use crate::state::{save_proposal, Proposal, ProposalStatus, PROPOSALS, TOKEN_CONTRACTS};
+
+/// Most early-release tranches a proposal can define
+pub const MAX_LOCKUP_TRANCHES: usize = 12;
+/// Longest extension one governance vote can add to a lockup
+pub const MAX_LOCKUP_EXTENSION_SECONDS: u64 = 365 * 24 * 60 * 60;
+
+/// Share of each holder's position released `after_seconds` into the lockup, before lockup_end
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LockupTranche {
+    pub after_seconds: u64,
+    pub release_bps: u16, // On top of earlier tranches; whatever is left unlocks at lockup_end
+}
+
+/// Tranche bookkeeping for a proposal with a staged lockup
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LockupProgress {
+    pub start: u64,
+    pub tranches_released: u32,
+    pub released_bps: u16,
+}
+
+// Storage keys
+pub const LOCKUP_PROGRESS: Map<String, LockupProgress> = Map::new("lockup_progress");
+pub const LOCKUP_TRANSFERRED: Map<(&str, &Addr), u64> = Map::new("lockup_transferred"); // Shares moved out before lockup_end
+
+/// Tranches must come in order and release at most the whole position
+pub fn validate_tranches(tranches: &[LockupTranche]) -> Result<(), ContractError> {
+    let invalid = |message: &str| ContractError::InvalidInput {
+        field: "lockup_tranches".to_string(),
+        message: message.to_string(),
+    };
+    if tranches.len() > MAX_LOCKUP_TRANCHES {
+        return Err(invalid(&format!(
+            "At most {} lockup tranches",
+            MAX_LOCKUP_TRANCHES
+        )));
+    }
+
+    let mut previous = 0u64;
+    let mut total_bps = 0u32;
+    for tranche in tranches {
+        if tranche.after_seconds <= previous || tranche.release_bps == 0 {
+            return Err(invalid(
+                "Tranches need a release and strictly increasing unlock times",
+            ));
+        }
+        previous = tranche.after_seconds;
+        total_bps += tranche.release_bps as u32;
+    }
+    if total_bps > 10_000 {
+        return Err(invalid(
+            "Tranches cannot release more than the whole position",
+        ));
+    }
+    Ok(())
+}
+
+/// (Re)start the tranche clock whenever the lockup itself starts
+pub fn start_schedule(storage: &mut dyn Storage, proposal: &Proposal, now: u64) -> StdResult<()> {
+    if proposal.financial_terms.lockup_tranches.is_empty() {
+        return Ok(());
+    }
+    LOCKUP_PROGRESS.save(
+        storage,
+        proposal.id.clone(),
+        &LockupProgress {
+            start: now,
+            tranches_released: 0,
+            released_bps: 0,
+        },
+    )
+}
+
+/// Tranches due by `now` and the share of each position they release
+fn released_at(proposal: &Proposal, progress: Option<&LockupProgress>, now: u64) -> (u32, u16) {
+    if proposal
+        .timestamps
+        .lockup_end
+        .is_some_and(|lockup_end| now >= lockup_end)
+    {
+        return (
+            proposal.financial_terms.lockup_tranches.len() as u32,
+            10_000,
+        );
+    }
+    let Some(progress) = progress else {
+        return (0, 0);
+    };
+
+    proposal
+        .financial_terms
+        .lockup_tranches
+        .iter()
+        .take_while(|tranche| progress.start.saturating_add(tranche.after_seconds) <= now)
+        .fold((0, 0), |(count, bps), tranche| {
+            (count + 1, bps + tranche.release_bps)
+        })
+}
+
+/// Share of every position that can move right now, in basis points
+pub fn unlocked_bps(storage: &dyn Storage, env: &Env, proposal: &Proposal) -> StdResult<u16> {
+    let progress = LOCKUP_PROGRESS.may_load(storage, proposal.id.clone())?;
+    Ok(released_at(proposal, progress.as_ref(), env.block.time.seconds()).1)
+}
+
+/// Let a holder move only the released part of their position while the lockup runs
+pub fn enforce_transfer_allowance(
+    storage: &mut dyn Storage,
+    env: &Env,
+    proposal: &Proposal,
+    holder: &Addr,
+    holding: u64,
+    shares: u64,
+) -> Result<(), ContractError> {
+    let bps = unlocked_bps(storage, env, proposal)?;
+    if bps >= 10_000 {
+        return Ok(());
+    }
+
+    let key = (proposal.id.as_str(), holder);
+    let moved = LOCKUP_TRANSFERRED
+        .may_load(storage, key)?
+        .unwrap_or_default();
+    let released = (holding.saturating_add(moved) as u128 * bps as u128 / 10_000) as u64;
+    let available = released.saturating_sub(moved);
+    if shares > available {
+        return Err(ContractError::TransferExceedsUnlocked { available });
+    }
+    LOCKUP_TRANSFERRED.save(storage, key, &moved.saturating_add(shares))?;
+    Ok(())
+}
+
+/// Push lockup_end back by `additional_seconds`, counting from now if the lockup already ended.
+/// Returns the new lockup_end.
+pub fn extend(
+    storage: &mut dyn Storage,
+    proposal_id: &str,
+    now: u64,
+    additional_seconds: u64,
+) -> Result<u64, ContractError> {
+    let mut proposal = PROPOSALS.load(storage, proposal_id.to_string())?;
+    let lockup_end = proposal
+        .timestamps
+        .lockup_end
+        .ok_or(ContractError::TokensNotMinted {})?
+        .max(now)
+        .checked_add(additional_seconds)
+        .ok_or(ContractError::Overflow {
+            operation: "lockup extension".to_string(),
+        })?;
+    proposal.timestamps.lockup_end = Some(lockup_end);
+    proposal.timestamps.updated_at = now;
+    save_proposal(storage, &proposal)?;
+    Ok(lockup_end)
+}
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct LockupInfo {
@@ -44,14 +201,17 @@ pub fn get_lockup_info(deps: Deps, env: &Env, proposal_id: &str) -> StdResult<Lo
     })
 }
 
-/// Validate that a transfer is allowed (not in lockup)
+/// Validate that a transfer is allowed (lockup over or a tranche released)
 pub fn validate_transfer_allowed(
     deps: Deps,
     env: &Env,
     proposal_id: &str,
 ) -> Result<(), ContractError> {
     if is_in_lockup_period(deps, env, proposal_id)? {
-        return Err(ContractError::TokensInLockup {});
+        let proposal = PROPOSALS.load(deps.storage, proposal_id.to_string())?;
+        if unlocked_bps(deps.storage, env, &proposal)? == 0 {
+            return Err(ContractError::TokensInLockup {});
+        }
     }
     Ok(())
 }
@@ -73,30 +233,52 @@ pub fn get_remaining_lockup_time(deps: Deps, env: &Env, proposal_id: &str) -> St
     }
 }
 
+/// Lockup changes made by one scan
+pub struct LockupBatch {
+    pub unlocked: Vec<String>,
+    pub tranches_released: Vec<String>, // Proposals where another early-release tranche came due
+    pub next_start_after: Option<String>,
+}
+
 /// Check if lockup period has expired for all proposals and update status
-pub fn process_expired_lockups(
-    deps: DepsMut,
+/// Complete funded proposals whose lockup has ended within one batch and record newly due
+/// tranches; also returns the cursor for the next batch
+pub fn unlock_expired(
+    storage: &mut dyn Storage,
     env: &Env,
-    _info: MessageInfo,
-) -> Result<Response, ContractError> {
+    start_after: Option<String>,
+    limit: usize,
+) -> StdResult<LockupBatch> {
     let current_time = env.block.time.seconds();
-    let mut processed_count = 0u64;
     let mut unlocked_proposals = Vec::new();
+    let mut tranches_released = Vec::new();
+
+    let batch = GasOptimizedStorage::load_batch(storage, start_after, limit)?;
+    let next_start_after = GasOptimizedStorage::next_batch_start(&batch, limit);
+
+    for (proposal_id, proposal) in &batch {
+        crate::notifications::lockup_expired(storage, env, proposal)?;
+        let Some(mut progress) = LOCKUP_PROGRESS.may_load(storage, proposal_id.clone())? else {
+            continue;
+        };
+        let (count, bps) = released_at(proposal, Some(&progress), current_time);
+        if count > progress.tranches_released {
+            progress.tranches_released = count;
+            progress.released_bps = bps;
+            LOCKUP_PROGRESS.save(storage, proposal_id.clone(), &progress)?;
+            tranches_released.push(proposal_id.clone());
+        }
+    }
 
     // First, collect all proposals that need processing
-    let proposals_to_process: Vec<(String, Proposal)> = PROPOSALS
-        .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
-        .filter_map(|item| {
-            if let Ok((proposal_id, proposal)) = item {
-                if proposal.status == ProposalStatus::Funded {
-                    if let Some(lockup_end) = proposal.timestamps.lockup_end {
-                        if current_time >= lockup_end {
-                            return Some((proposal_id, proposal));
-                        }
-                    }
-                }
-            }
-            None
+    let proposals_to_process: Vec<(String, Proposal)> = batch
+        .into_iter()
+        .filter(|(_, proposal)| {
+            proposal.status == ProposalStatus::Funded
+                && proposal
+                    .timestamps
+                    .lockup_end
+                    .is_some_and(|lockup_end| current_time >= lockup_end)
         })
         .collect();
 
@@ -105,18 +287,45 @@ pub fn process_expired_lockups(
         // Lockup period has expired, mark as completed
         proposal.status = ProposalStatus::Completed;
         proposal.timestamps.updated_at = current_time;
-        PROPOSALS.save(deps.storage, proposal_id.clone(), &proposal)?;
+        save_proposal(storage, &proposal)?;
 
         unlocked_proposals.push(proposal_id);
-        processed_count += 1;
     }
 
+    Ok(LockupBatch {
+        unlocked: unlocked_proposals,
+        tranches_released,
+        next_start_after,
+    })
+}
+
+pub fn process_expired_lockups(
+    deps: DepsMut,
+    env: &Env,
+    _info: MessageInfo,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    // Scan one batch of proposals, sized from gas estimates unless the caller overrides it
+    let limit = limit
+        .unwrap_or_else(|| GasOptimizer::maintenance_batch_size("process_expired_lockup"))
+        .max(1) as usize;
+    let batch = unlock_expired(deps.storage, env, start_after, limit)?;
+    let processed_count = batch.unlocked.len() as u64;
+
     let mut response = Response::new()
         .add_attribute("method", "process_expired_lockups")
-        .add_attribute("processed_count", processed_count.to_string());
+        .add_attribute("processed_count", processed_count.to_string())
+        .add_attribute("batch_size", limit.to_string());
 
-    if !unlocked_proposals.is_empty() {
-        response = response.add_attribute("unlocked_proposals", unlocked_proposals.join(","));
+    if !batch.unlocked.is_empty() {
+        response = response.add_attribute("unlocked_proposals", batch.unlocked.join(","));
+    }
+    if !batch.tranches_released.is_empty() {
+        response = response.add_attribute("tranches_released", batch.tranches_released.join(","));
+    }
+    if let Some(next) = batch.next_start_after {
+        response = response.add_attribute("next_start_after", next);
     }
 
     Ok(response)
@@ -154,6 +363,8 @@ pub struct LockupStatus {
     pub lockup_end: Option<u64>,
     pub remaining_seconds: u64,
     pub progress_percentage: u64,
+    pub unlocked_percentage: u64, // Share of each position free to move, counting early tranches
+    pub tranches_released: u32,   // Tranches recorded by lockup processing
 }
 
 /// Get comprehensive lockup status for a proposal
@@ -163,11 +374,17 @@ pub fn get_lockup_status(deps: Deps, env: &Env, proposal_id: &str) -> StdResult<
     let progress_percentage = get_lockup_progress(deps, &env, proposal_id)?;
 
     let proposal = PROPOSALS.load(deps.storage, proposal_id.to_string())?;
+    let unlocked_percentage = unlocked_bps(deps.storage, env, &proposal)? as u64 / 100;
+    let tranches_released = LOCKUP_PROGRESS
+        .may_load(deps.storage, proposal_id.to_string())?
+        .map_or(0, |progress| progress.tranches_released);
 
     Ok(LockupStatus {
         is_locked,
         lockup_end: proposal.timestamps.lockup_end,
         remaining_seconds,
         progress_percentage,
+        unlocked_percentage,
+        tranches_released,
     })
 }
@@ -0,0 +1,75 @@
+//! Fixed-point helpers for values shown to users.
+//!
+//! Floats are non-deterministic across wasm runtimes and some chains reject contracts that use
+//! them, so percentages are computed in integers and formatted from there.
+
+use cosmwasm_std::Uint128;
+
+/// `part / whole` in hundredths of a percent, rounded half up. Zero when `whole` is zero.
+pub fn percentage_hundredths(part: u128, whole: u128) -> u128 {
+    if whole == 0 {
+        return 0;
+    }
+    // Twice the precision, then halve, to round the last digit half up
+    let doubled = Uint128::new(part)
+        .checked_multiply_ratio(20_000u128, whole)
+        .unwrap_or(Uint128::MAX)
+        .u128();
+    doubled / 2 + doubled % 2
+}
+
+/// `part / whole` as a percentage with two decimals, e.g. `"12.50%"`
+pub fn format_percentage(part: u128, whole: u128) -> String {
+    let hundredths = percentage_hundredths(part, whole);
+    format!("{}.{:02}%", hundredths / 100, hundredths % 100)
+}
+
+/// A percentage such as `"12.5%"` or `"8"` in basis points; None if it is not a plain
+/// non-negative number with at most two decimals, or does not fit
+pub fn parse_percentage_bps(value: &str) -> Option<u16> {
+    let value = value.trim();
+    let value = value.strip_suffix('%').unwrap_or(value).trim_end();
+    let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+    let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if whole.is_empty() || fraction.len() > 2 || !is_digits(whole) || !is_digits(fraction) {
+        return None;
+    }
+    let hundredths = format!("{:0<2}", fraction).parse::<u32>().ok()?;
+    let bps = whole
+        .parse::<u32>()
+        .ok()?
+        .checked_mul(100)?
+        .checked_add(hundredths)?;
+    u16::try_from(bps).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_percentage_rounds_half_up() {
+        assert_eq!(format_percentage(1, 8), "12.50%");
+        assert_eq!(format_percentage(1, 3), "33.33%");
+        assert_eq!(format_percentage(2, 3), "66.67%");
+        assert_eq!(format_percentage(1, 20_000), "0.01%");
+        assert_eq!(format_percentage(1, 20_001), "0.00%");
+        assert_eq!(format_percentage(5_000, 5_000), "100.00%");
+        assert_eq!(format_percentage(7, 0), "0.00%");
+        assert_eq!(format_percentage(u128::MAX, u128::MAX), "100.00%");
+    }
+
+    #[test]
+    fn test_parse_percentage_bps() {
+        assert_eq!(parse_percentage_bps("12.5%"), Some(1_250));
+        assert_eq!(parse_percentage_bps(" 8 % "), Some(800));
+        assert_eq!(parse_percentage_bps("0.05"), Some(5));
+        assert_eq!(parse_percentage_bps("655.35%"), Some(u16::MAX));
+        assert_eq!(parse_percentage_bps("655.36%"), None);
+        assert_eq!(parse_percentage_bps("8.125%"), None);
+        assert_eq!(parse_percentage_bps("-3%"), None);
+        assert_eq!(parse_percentage_bps("8-10%"), None);
+        assert_eq!(parse_percentage_bps(".5%"), None);
+        assert_eq!(parse_percentage_bps(""), None);
+    }
+}
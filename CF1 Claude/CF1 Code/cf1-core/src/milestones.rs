@@ -0,0 +1,385 @@
+use cosmwasm_std::{
+    Addr, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult, Storage, Uint128,
+};
+use cw_storage_plus::Map;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+use crate::roles::{self, Role};
+use crate::state::{InvestmentStatus, Proposal, INVESTMENTS, PROPOSALS};
+use crate::{ledger, treasury};
+
+/// Upper bound on tranches so releases and queries stay cheap
+pub const MAX_MILESTONES: usize = 20;
+
+/// Tranche of the creator payout, declared in the proposal's financial terms
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Milestone {
+    pub description: String,
+    pub payout_bps: u16, // Share of the creator payout; all milestones must sum to 10000
+}
+
+/// Creator payout held back after distribution
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MilestoneEscrow {
+    pub total_escrowed: Uint128,
+    pub released: Uint128,
+    pub released_milestones: Vec<u32>,
+    pub total_voting_shares: u64, // Shares distributed to holders, the base for approval votes
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MilestoneState {
+    pub index: u32,
+    pub description: String,
+    pub payout_bps: u16,
+    pub amount: Uint128,
+    pub released: bool,
+    pub approving_shares: u64, // Current holdings of investors that approved the release
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MilestoneEscrowResponse {
+    pub proposal_id: String,
+    pub total_escrowed: Uint128,
+    pub released: Uint128,
+    pub remaining: Uint128,
+    pub milestones: Vec<MilestoneState>,
+}
+
+// Storage keys
+pub const MILESTONE_ESCROWS: Map<String, MilestoneEscrow> = Map::new("milestone_escrows");
+pub const MILESTONE_APPROVALS: Map<(String, u32, &Addr), u64> = Map::new("milestone_approvals"); // -> approved_at
+
+/// Validate a milestone schedule at proposal creation
+pub fn validate_schedule(milestones: &[Milestone]) -> Result<(), ContractError> {
+    if milestones.is_empty() {
+        return Ok(());
+    }
+
+    if milestones.len() > MAX_MILESTONES {
+        return Err(ContractError::InvalidInput {
+            field: "milestones".to_string(),
+            message: format!("At most {} milestones are allowed", MAX_MILESTONES),
+        });
+    }
+    if milestones
+        .iter()
+        .any(|m| m.payout_bps == 0 || m.description.trim().is_empty())
+    {
+        return Err(ContractError::InvalidInput {
+            field: "milestones".to_string(),
+            message: "Each milestone needs a description and a non-zero payout".to_string(),
+        });
+    }
+    let total_bps: u32 = milestones.iter().map(|m| m.payout_bps as u32).sum();
+    if total_bps != 10_000 {
+        return Err(ContractError::InvalidInput {
+            field: "milestones".to_string(),
+            message: format!("Milestone payouts must sum to 10000 bps, got {}", total_bps),
+        });
+    }
+    Ok(())
+}
+
+/// Hold the creator payout in escrow instead of paying it out on distribution
+pub fn open_escrow(
+    storage: &mut dyn Storage,
+    proposal_id: &str,
+    creator_amount: Uint128,
+    total_voting_shares: u64,
+) -> StdResult<()> {
+    MILESTONE_ESCROWS.save(
+        storage,
+        proposal_id.to_string(),
+        &MilestoneEscrow {
+            total_escrowed: creator_amount,
+            released: Uint128::zero(),
+            released_milestones: vec![],
+            total_voting_shares,
+        },
+    )
+}
+
+/// The last tranche absorbs rounding so the escrow always empties exactly
+fn tranche_amount(escrow: &MilestoneEscrow, milestones: &[Milestone], index: usize) -> Uint128 {
+    let is_last_outstanding = escrow.released_milestones.len() + 1 == milestones.len();
+    if is_last_outstanding {
+        escrow.total_escrowed - escrow.released
+    } else {
+        escrow
+            .total_escrowed
+            .multiply_ratio(milestones[index].payout_bps as u128, 10_000u128)
+    }
+}
+
+/// Sum of the current holdings of every investor that approved the milestone
+fn approving_shares(storage: &dyn Storage, proposal_id: &str, index: u32) -> StdResult<u64> {
+    let approvers = MILESTONE_APPROVALS
+        .prefix((proposal_id.to_string(), index))
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<Addr>>>()?;
+
+    let mut total = 0u64;
+    for approver in approvers {
+        // Holdings are read at tally time, so shares transferred after voting count only once
+        if let Some(investment) =
+            INVESTMENTS.may_load(storage, (proposal_id.to_string(), &approver))?
+        {
+            if investment.status == InvestmentStatus::Completed {
+                total = total.saturating_add(investment.shares);
+            }
+        }
+    }
+    Ok(total)
+}
+
+/// Token holders approve the release of a tranche
+pub fn approve_milestone(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: String,
+    milestone_index: u32,
+) -> Result<Response, ContractError> {
+    let proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+    if milestone_index as usize >= proposal.financial_terms.milestones.len() {
+        return Err(ContractError::MilestoneNotFound {
+            index: milestone_index,
+        });
+    }
+    let escrow = MILESTONE_ESCROWS
+        .may_load(deps.storage, proposal_id.clone())?
+        .ok_or(ContractError::TokensNotMinted {})?;
+    if escrow.released_milestones.contains(&milestone_index) {
+        return Err(ContractError::MilestoneAlreadyReleased {
+            index: milestone_index,
+        });
+    }
+
+    let holds_tokens = INVESTMENTS
+        .may_load(deps.storage, (proposal_id.clone(), &info.sender))?
+        .is_some_and(|inv| inv.status == InvestmentStatus::Completed && inv.shares > 0);
+    if !holds_tokens {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    MILESTONE_APPROVALS.save(
+        deps.storage,
+        (proposal_id.clone(), milestone_index, &info.sender),
+        &env.block.time.seconds(),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "approve_milestone")
+        .add_attribute("proposal_id", &proposal_id)
+        .add_attribute("milestone", milestone_index.to_string())
+        .add_attribute("voter", info.sender))
+}
+
+/// Release a tranche to the creator once the admin calls it or holders of a majority approve
+pub fn release_milestone(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: String,
+    milestone_index: u32,
+) -> Result<Response, ContractError> {
+    let proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+    let milestones = &proposal.financial_terms.milestones;
+    let index = milestone_index as usize;
+    if index >= milestones.len() {
+        return Err(ContractError::MilestoneNotFound {
+            index: milestone_index,
+        });
+    }
+
+    let mut escrow = MILESTONE_ESCROWS
+        .may_load(deps.storage, proposal_id.clone())?
+        .ok_or(ContractError::TokensNotMinted {})?;
+    if escrow.released_milestones.contains(&milestone_index) {
+        return Err(ContractError::MilestoneAlreadyReleased {
+            index: milestone_index,
+        });
+    }
+
+    let approving = approving_shares(deps.storage, &proposal_id, milestone_index)?;
+    let holder_majority = approving.saturating_mul(2) > escrow.total_voting_shares;
+    if !holder_majority && !roles::has_role(deps.storage, &info.sender, Role::Operator)? {
+        return Err(ContractError::MilestoneNotApproved {
+            index: milestone_index,
+        });
+    }
+
+    let response = pay_out(deps.storage, &env, &proposal, &mut escrow, milestone_index)?;
+    Ok(response
+        .add_attribute("action", "release_milestone")
+        .add_attribute("proposal_id", &proposal_id)
+        .add_attribute("milestone", milestone_index.to_string())
+        .add_attribute(
+            "approved_by",
+            if holder_majority { "holders" } else { "admin" },
+        ))
+}
+
+/// Release a tranche after a passed `ReleaseMilestone` governance vote.
+///
+/// A tranche released in the meantime is left alone, since the holders' decision already holds.
+pub fn release_by_governance(
+    storage: &mut dyn Storage,
+    env: &Env,
+    proposal_id: &str,
+    milestone_index: u32,
+) -> Result<Response, ContractError> {
+    let proposal = PROPOSALS.load(storage, proposal_id.to_string())?;
+    let mut escrow = MILESTONE_ESCROWS
+        .may_load(storage, proposal_id.to_string())?
+        .ok_or(ContractError::TokensNotMinted {})?;
+    if escrow.released_milestones.contains(&milestone_index) {
+        return Ok(Response::new().add_attribute("amount", "0"));
+    }
+    pay_out(storage, env, &proposal, &mut escrow, milestone_index)
+}
+
+/// Check a tranche exists and is still held, before holders vote on releasing it
+pub fn ensure_releasable(
+    storage: &dyn Storage,
+    proposal: &Proposal,
+    milestone_index: u32,
+) -> Result<(), ContractError> {
+    if milestone_index as usize >= proposal.financial_terms.milestones.len() {
+        return Err(ContractError::MilestoneNotFound {
+            index: milestone_index,
+        });
+    }
+    let escrow = MILESTONE_ESCROWS
+        .may_load(storage, proposal.id.clone())?
+        .ok_or(ContractError::TokensNotMinted {})?;
+    if escrow.released_milestones.contains(&milestone_index) {
+        return Err(ContractError::MilestoneAlreadyReleased {
+            index: milestone_index,
+        });
+    }
+    Ok(())
+}
+
+/// Pay a tranche to the creator and mark it released
+fn pay_out(
+    storage: &mut dyn Storage,
+    env: &Env,
+    proposal: &Proposal,
+    escrow: &mut MilestoneEscrow,
+    milestone_index: u32,
+) -> Result<Response, ContractError> {
+    crate::blacklist::ensure_not_blacklisted(storage, &proposal.creator)?;
+    crate::freeze::ensure_not_frozen(storage, &proposal.id)?;
+    let amount = tranche_amount(
+        escrow,
+        &proposal.financial_terms.milestones,
+        milestone_index as usize,
+    );
+    escrow.released += amount;
+    escrow.released_milestones.push(milestone_index);
+    MILESTONE_ESCROWS.save(storage, proposal.id.clone(), escrow)?;
+
+    ledger::record_entry(
+        storage,
+        env,
+        &proposal.id,
+        ledger::LedgerEntryKind::CreatorPayout,
+        &proposal.creator,
+        amount,
+    )?;
+
+    let mut response = Response::new();
+    if !amount.is_zero() {
+        response = response.add_messages(crate::escrow::payout_messages(
+            storage,
+            env,
+            &proposal.id,
+            &proposal.creator,
+            amount,
+        )?);
+    }
+    treasury::debit(storage, treasury::TreasuryAccount::Escrow, amount)?;
+    Ok(response.add_attribute("amount", amount.to_string()))
+}
+
+// Query functions
+pub fn query_milestone_escrow(
+    deps: Deps,
+    proposal_id: String,
+) -> StdResult<MilestoneEscrowResponse> {
+    let proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+    let milestones = &proposal.financial_terms.milestones;
+    let escrow = MILESTONE_ESCROWS
+        .may_load(deps.storage, proposal_id.clone())?
+        .unwrap_or(MilestoneEscrow {
+            total_escrowed: Uint128::zero(),
+            released: Uint128::zero(),
+            released_milestones: vec![],
+            total_voting_shares: 0,
+        });
+
+    let milestones = milestones
+        .iter()
+        .enumerate()
+        .map(|(index, milestone)| {
+            let released = escrow.released_milestones.contains(&(index as u32));
+            Ok(MilestoneState {
+                index: index as u32,
+                description: milestone.description.clone(),
+                payout_bps: milestone.payout_bps,
+                amount: escrow
+                    .total_escrowed
+                    .multiply_ratio(milestone.payout_bps as u128, 10_000u128),
+                released,
+                approving_shares: approving_shares(deps.storage, &proposal_id, index as u32)?,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(MilestoneEscrowResponse {
+        proposal_id,
+        total_escrowed: escrow.total_escrowed,
+        released: escrow.released,
+        remaining: escrow.total_escrowed - escrow.released,
+        milestones,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn milestone(payout_bps: u16) -> Milestone {
+        Milestone {
+            description: "Phase".to_string(),
+            payout_bps,
+        }
+    }
+
+    #[test]
+    fn test_schedule_validation_and_rounding() {
+        assert!(validate_schedule(&[]).is_ok());
+        assert!(validate_schedule(&[milestone(5_000), milestone(5_000)]).is_ok());
+        assert!(validate_schedule(&[milestone(5_000), milestone(4_999)]).is_err());
+        assert!(validate_schedule(&[milestone(10_000), milestone(0)]).is_err());
+
+        // 3 equal-ish tranches of 100 leave the remainder to the last release
+        let milestones = [milestone(3_333), milestone(3_333), milestone(3_334)];
+        let mut escrow = MilestoneEscrow {
+            total_escrowed: Uint128::new(100),
+            released: Uint128::zero(),
+            released_milestones: vec![],
+            total_voting_shares: 10,
+        };
+        for index in 0..3 {
+            let amount = tranche_amount(&escrow, &milestones, index);
+            escrow.released += amount;
+            escrow.released_milestones.push(index as u32);
+        }
+        assert_eq!(escrow.released, escrow.total_escrowed);
+    }
+}
@@ -1,5 +1,5 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Binary, Uint128};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -17,6 +17,7 @@ pub struct InstantiateMsg {
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
+#[allow(clippy::large_enum_variant)] // Deserialized once per call, not worth boxing
 pub enum ExecuteMsg {
     // Proposal Management
     CreateProposal {
@@ -33,22 +34,185 @@ pub enum ExecuteMsg {
     CancelProposal {
         proposal_id: String,
     },
+    // Listing review, when the config requires it: the creator submits a draft, then a
+    // compliance officer opens it or sends it back to draft
+    SubmitProposal {
+        proposal_id: String,
+    },
+    ApproveProposal {
+        proposal_id: String,
+    },
+    RejectProposal {
+        proposal_id: String,
+        reason: String,
+    },
+    // Close a draft, in-review or open proposal as fraudulent: investors are refunded and the
+    // creation bond is slashed (compliance officer only)
+    RejectProposalForFraud {
+        proposal_id: String,
+        reason: String,
+    },
+    // Stop new investments and distribution for a proposal under investigation, leaving its
+    // status and refunds untouched (compliance officer only)
+    FreezeProposal {
+        proposal_id: String,
+        reason: String,
+    },
+    UnfreezeProposal {
+        proposal_id: String,
+    },
+    // Block a creator from creating proposals and freeze their pending payouts (owner only)
+    BlacklistCreator {
+        creator: String,
+        reason: String,
+    },
+    // Lift a blacklist and unfreeze the creator's payouts (owner only)
+    UnblacklistCreator {
+        creator: String,
+    },
+    // A blacklisted creator's one appeal against their blacklisting
+    AppealBlacklist {
+        statement: String,
+    },
+    // Turn down a pending appeal, leaving the blacklist in place (owner only)
+    DismissBlacklistAppeal {
+        creator: String,
+    },
+    // Creator sends a rejected proposal back for review with edits, a bounded number of times
+    ResubmitProposal {
+        proposal_id: String,
+        asset_details: Option<AssetDetails>,
+        documents: Option<Vec<Document>>,
+    },
+    // Creator may push the deadline back once, within the maximum funding period
+    ExtendFundingDeadline {
+        proposal_id: String,
+        new_deadline: u64,
+    },
+    // Creator may close the raise as Funded once the soft cap is met
+    FinalizeFunding {
+        proposal_id: String,
+    },
 
     // Investment Management
+    // Optional client-supplied `operation_id` makes a retried broadcast a rejected duplicate
     Invest {
         proposal_id: String,
+        #[serde(default)]
+        operation_id: Option<String>,
+        #[serde(default)]
+        referrer: Option<String>, // Attributed on the investor's first position in the proposal
+    },
+    // Allowlisted custodian invests its attached funds for a customer, who owns the position
+    InvestFor {
+        proposal_id: String,
+        beneficiary: String,
+    },
+    // Invest the attached funds for `beneficiary`; called by the ibc-hooks middleware with the
+    // NTRN from an ICS-20 transfer
+    InvestFromIbc {
+        proposal_id: String,
+        beneficiary: String,
     },
     RefundInvestors {
         proposal_id: String,
     },
+    ClaimRefund {
+        proposal_id: String,
+    },
+
+    // Co-signature for large tickets
+    ConfirmInvestment {
+        ticket_id: u64,
+    },
+    ConfirmInvestmentSigned {
+        ticket_id: u64,
+        authorization: crate::signatures::SignedAuthorization,
+    },
+    ReclaimPendingInvestment {
+        ticket_id: u64,
+        #[serde(default)]
+        operation_id: Option<String>,
+    },
+    RegisterSecondaryKey {
+        key: String,
+    },
+
+    // Off-chain authorizations
+    RegisterSigningKey {
+        pubkey: Binary,
+    },
+
+    // Document attestations: the creator, or the auditor they name, signs the current version's
+    // CID with their registered signing key
+    SetDocumentAuditor {
+        proposal_id: String,
+        auditor: Option<String>,
+    },
+    AttestDocument {
+        proposal_id: String,
+        document_name: String,
+        signature: Binary,
+    },
 
     // Token Management (post-funding)
     MintTokens {
         proposal_id: String,
     },
+    // Mint again after the share token failed to instantiate (creator or operator)
+    RetryMintTokens {
+        proposal_id: String,
+    },
     DistributeTokens {
         proposal_id: String,
     },
+    // Distribute to the next `limit` pending investors; the batch reaching the end completes it
+    DistributeTokensBatch {
+        proposal_id: String,
+        limit: Option<u32>,
+    },
+    // Pay out the raise once distribution has minted for every investor
+    FinalizeDistribution {
+        proposal_id: String,
+    },
+    // Send the mint again to up to `limit` investors whose mint failed
+    RetryFailedMints {
+        proposal_id: String,
+        limit: Option<u32>,
+    },
+    // Creator mints whatever of their allocation has vested
+    ClaimVestedTokens {
+        proposal_id: String,
+    },
+
+    // Material changes after funding (investors reconfirm or withdraw before distribution)
+    PostMaterialChange {
+        proposal_id: String,
+        description: String,
+        asset_details: Option<AssetDetails>,
+        documents: Option<Vec<Document>>,
+    },
+    ReconfirmInvestment {
+        proposal_id: String,
+    },
+    WithdrawInvestment {
+        proposal_id: String,
+        #[serde(default)]
+        operation_id: Option<String>,
+    },
+    ResolveReconfirmation {
+        proposal_id: String,
+    },
+
+    // Milestone payouts (token holders approve, admin or holder majority releases)
+    ApproveMilestone {
+        proposal_id: String,
+        milestone_index: u32,
+    },
+    ReleaseMilestone {
+        proposal_id: String,
+        milestone_index: u32,
+    },
 
     // Admin Functions
     UpdateConfig {
@@ -58,8 +222,85 @@ pub enum ExecuteMsg {
         max_funding_period_days: Option<u64>,
         cw20_code_id: Option<u64>,
     },
-    ProcessExpiredProposals {},
-    ProcessExpiredLockups {},
+    // Platform-wide bounds on a single investment; None removes a bound (owner only)
+    SetInvestmentBounds {
+        min_investment_amount: Option<Uint128>,
+        max_investment_amount: Option<Uint128>,
+    },
+    // untrn every CreateProposal must attach as an anti-spam bond; None removes it (owner only)
+    SetCreationBond {
+        amount: Option<Uint128>,
+    },
+    // Add an `estimated_gas_class` attribute to every execute response (owner only)
+    SetEmitGasClass {
+        enabled: bool,
+    },
+    // Emergency circuit breaker (guardian only); refunds and queries keep working while paused
+    Pause {
+        reason: String,
+    },
+    Unpause {},
+    // Role-based access control (owner only)
+    GrantRole {
+        address: String,
+        role: crate::roles::Role,
+    },
+    RevokeRole {
+        address: String,
+        role: crate::roles::Role,
+    },
+    // Raise-size fee brackets replacing the flat fee below the top bracket (fee manager only)
+    SetFeeSchedule {
+        brackets: Vec<crate::fees::FeeBracket>,
+    },
+    // Negotiated fee for one raise, replacing the schedule (owner only, while the raise is open)
+    SetProposalFeeOverride {
+        proposal_id: String,
+        fee_bps: u16,
+    },
+    // New proposals start as drafts that need approval; false lists them immediately (owner only)
+    SetProposalReviewRequired {
+        required: bool,
+    },
+    // Contract that holds investor funds for new investments; None keeps them here (owner only)
+    SetEscrowContract {
+        address: Option<String>,
+    },
+    // Contract notified of funding, distribution and lockup expiry; None stops notifications (owner only)
+    SetGovernanceContract {
+        address: Option<String>,
+    },
+    // Send a notification the governance contract rejected again, with the same key (anyone)
+    RetryGovernanceHook {
+        idempotency_key: String,
+    },
+    // Maintenance (paginated; omit limit for a gas-safe default batch)
+    ProcessExpiredProposals {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    ProcessExpiredLockups {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    // Keeper reward for permissionless expiry processing (admin only)
+    UpdateKeeperConfig {
+        reward_per_proposal: Uint128,
+    },
+    // Referrer share of the platform fee on referred investments (admin only)
+    UpdateReferralConfig {
+        fee_bps: u16,
+    },
+    ClaimReferralRewards {},
+    // CronCat-compatible entry for the scheduled maintenance pass (permissionless)
+    RunScheduledMaintenance {
+        limit: Option<u32>,
+    },
+    // Drop rate limit counters whose window has ended, resuming where the last call stopped
+    // (permissionless)
+    PruneRateLimitData {
+        limit: Option<u32>,
+    },
 
     // Rate limit management (admin only)
     UpdateRateLimitConfig {
@@ -72,6 +313,206 @@ pub enum ExecuteMsg {
         max_per_window: u32,
         window_seconds: u64,
     },
+    // Scale an address's limits by `multiplier`, or lift them entirely when it is omitted
+    AddRateLimitExemption {
+        address: String,
+        multiplier: Option<u32>,
+    },
+    RemoveRateLimitExemption {
+        address: String,
+    },
+
+    // Co-signature policy management (admin only)
+    UpdateCosignConfig {
+        threshold: Option<Uint128>,
+        approval_window_seconds: Option<u64>,
+    },
+
+    // KYC registry (admin or compliance officer)
+    AddVerifiedInvestor {
+        investor: String,
+        tier: crate::kyc::VerificationTier,
+        expires_at: Option<u64>,
+    },
+    RemoveVerifiedInvestor {
+        investor: String,
+    },
+    SetComplianceOfficer {
+        officer: Option<String>,
+    },
+    // Reg CF annual investor limits: the investor declares income and net worth, or the
+    // registry sets them from verified records, which the investor cannot then override
+    DeclareInvestorLimits {
+        annual_income: Uint128,
+        net_worth: Uint128,
+    },
+    SetInvestorLimits {
+        investor: String,
+        annual_income: Uint128,
+        net_worth: Uint128,
+    },
+    ClearInvestorLimits {
+        investor: String,
+    },
+    // Brokerage custodians allowed to use InvestFor (compliance officer only)
+    AddCustodian {
+        address: String,
+    },
+    RemoveCustodian {
+        address: String,
+    },
+    // Sent by a Neutron Interchain Account; payouts route to the owner once compliance verifies it
+    RegisterInterchainAccount {
+        controller_connection_id: String,
+        controller_owner: String,
+        transfer_channel_id: String,
+    },
+    // Compliance officer's off-chain attestation; the contract does not check it on chain
+    VerifyInterchainAccount {
+        address: String,
+    },
+    // The account itself or a compliance officer
+    RemoveInterchainAccount {
+        address: String,
+    },
+    // Issuer trust level of a creator (compliance officer only)
+    SetCreatorVerification {
+        creator: String,
+        tier: crate::verification::VerificationTier,
+    },
+    // Targets above this need a Verified or Institutional creator; None lifts it (owner only)
+    SetVerificationThreshold {
+        threshold: Option<Uint128>,
+    },
+    // Sender's public profile; replaces the previous one
+    UpdateCreatorProfile {
+        name: String,
+        bio: Option<String>,
+        website: Option<String>,
+        logo_hash: Option<String>,
+    },
+
+    // Private placement: creator or admin replaces the allowlist; empty reopens the round
+    SetProposalWhitelist {
+        proposal_id: String,
+        investors: Vec<String>,
+    },
+    RevokeAndReissueShares {
+        proposal_id: String,
+        holder: String,
+        reissue_to: String,
+        reason: String,
+    },
+    // Secondary market (after lockup)
+    TransferShares {
+        proposal_id: String,
+        recipient: String,
+        shares: u64,
+    },
+    // Transfer-approval hook, called by a proposal's share token before a holder moves tokens
+    // (same shape as cf1_types::TransferHookMsg)
+    ApproveTransfer {
+        owner: String,
+        recipient: String,
+        amount: Uint128,
+    },
+
+    // Asset wind-down (after a passed RetireAsset vote)
+    RetireAsset {
+        proposal_id: String,
+    },
+    RedeemShares {
+        proposal_id: String,
+    },
+
+    // Financial terms amendments (investors vote once anyone has invested)
+    AmendFinancialTerms {
+        proposal_id: String,
+        terms: crate::amendments::TermsAmendment,
+        reason: String,
+    },
+    VoteOnAmendment {
+        proposal_id: String,
+        vote: crate::governance::VoteOption,
+    },
+    ResolveAmendment {
+        proposal_id: String,
+    },
+
+    // Asset-level governance (token holders)
+    CreateGovernanceProposal {
+        proposal_id: String,
+        title: String,
+        description: String,
+        action: crate::governance::GovernanceAction,
+    },
+    CastVote {
+        governance_id: u64,
+        vote: crate::governance::VoteOption,
+    },
+    ExecuteGovernanceProposal {
+        governance_id: u64,
+    },
+    // Records the next batch of holders when an asset has too many to snapshot at once (anyone)
+    ContinueGovernanceSnapshot {
+        governance_id: u64,
+    },
+    // Sent by the launchpad to itself to apply a passed proposal's action
+    ApplyGovernanceAction {
+        governance_id: u64,
+    },
+    UpdateGovernanceConfig {
+        quorum_bps: Option<u16>,
+        threshold_bps: Option<u16>,
+        voting_period_seconds: Option<u64>,
+    },
+    // Creator names the first property manager; later changes go through ReplacePropertyManager votes
+    SetPropertyManager {
+        proposal_id: String,
+        manager: String,
+    },
+
+    // Asset valuation oracles (admin manages the whitelist)
+    AddOracle {
+        oracle: String,
+    },
+    RemoveOracle {
+        oracle: String,
+    },
+    UpdateAssetValuation {
+        proposal_id: String,
+        valuation: Uint128,
+        source: String,
+    },
+    SetPriceFeed {
+        contract: String,
+        provider: crate::oracle::PriceFeedProvider,
+        max_staleness_seconds: u64,
+    },
+
+    // Treasury sub-accounts
+    TreasuryTransfer {
+        from: crate::treasury::TreasuryAccount,
+        to: crate::treasury::TreasuryAccount,
+        amount: Uint128,
+        memo: String,
+    },
+    FundTreasury {
+        account: crate::treasury::TreasuryAccount,
+    },
+    // Pay out accrued platform fees (fee manager only)
+    WithdrawTreasury {
+        amount: Uint128,
+        recipient: String,
+    },
+}
+
+/// Privileged calls from the chain itself, e.g. a block-end or cron module schedule
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SudoMsg {
+    // Process the next batch of expired proposals and lockups from the stored cursors
+    ScheduledMaintenance { limit: Option<u32> },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, QueryResponses)]
@@ -80,10 +521,73 @@ pub enum QueryMsg {
     // Configuration
     #[returns(crate::state::Config)]
     Config {},
+    #[returns(crate::fees::FeeQuoteResponse)]
+    FeeQuote { proposal_id: String },
+    #[returns(crate::pause::PauseStatusResponse)]
+    PauseStatus {},
+    // Estimated gas per execute operation, by operation name
+    #[returns(crate::gas_monitor::GasStatsResponse)]
+    GasStats {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(crate::roles::RoleAssignmentsResponse)]
+    RoleAssignments {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(crate::custody::CustodiansResponse)]
+    Custodians {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(crate::interchain::InterchainAccountResponse)]
+    InterchainAccount { address: String },
 
     // Proposal queries
     #[returns(ProposalResponse)]
     Proposal { proposal_id: String },
+    #[returns(crate::state::ProposalHotData)]
+    ProposalSummary { proposal_id: String },
+    #[returns(crate::review::ProposalReviewResponse)]
+    ProposalReview { proposal_id: String },
+    #[returns(crate::review::ProposalHistoryResponse)]
+    ProposalHistory { proposal_id: String },
+    // Creation bond still held for a proposal
+    #[returns(Option<crate::bonds::CreationBond>)]
+    CreationBond { proposal_id: String },
+    // Why and since when a proposal is frozen; None if it is not
+    #[returns(Option<crate::freeze::ProposalFreeze>)]
+    ProposalFreeze { proposal_id: String },
+    // Blacklist entry for a creator, with any appeal; None if not blacklisted
+    #[returns(Option<crate::blacklist::BlacklistEntry>)]
+    BlacklistedCreator { creator: String },
+    #[returns(crate::blacklist::BlacklistedCreatorsResponse)]
+    BlacklistedCreators {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    // Every published version of the proposal's documents, oldest first
+    #[returns(crate::documents::DocumentHistoryResponse)]
+    DocumentHistory {
+        proposal_id: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    // Who attested one published version; sequence comes from DocumentHistory
+    #[returns(crate::documents::DocumentAttestationsResponse)]
+    DocumentAttestations { proposal_id: String, sequence: u64 },
+    // Status transitions and admin actions, oldest first; config changes and other platform-wide
+    // entries when proposal_id is omitted
+    #[returns(crate::audit::AuditTrailResponse)]
+    ProposalAuditTrail {
+        proposal_id: Option<String>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    // What the creator may still raise under the Reg CF 12-month issuer limit
+    #[returns(crate::compliance::RegCfCapacityResponse)]
+    CreatorRegCfCapacity { creator: String },
     #[returns(ProposalsResponse)]
     ProposalsByCreator {
         creator: String,
@@ -96,6 +600,43 @@ pub enum QueryMsg {
         start_after: Option<String>,
         limit: Option<u32>,
     },
+    // Newest first; the category is matched ignoring case and surrounding spaces
+    #[returns(ProposalsResponse)]
+    ProposalsByCategory {
+        category: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    // Newest first; the asset type is matched ignoring case and surrounding spaces
+    #[returns(ProposalsResponse)]
+    ProposalsByAssetType {
+        asset_type: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    // Proposals whose target and expected APY fall in range, optionally of one asset type. Ordered
+    // by target amount, or by APY when only min_apy_bps is given. The total counts matches among
+    // the first MAX_COUNT proposals in range.
+    #[returns(ProposalsResponse)]
+    ProposalsByTerms {
+        min_target: Option<Uint128>,
+        max_target: Option<Uint128>,
+        min_apy_bps: Option<u16>,
+        asset_type: Option<String>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    // Active proposals whose funding deadline falls in the next `seconds`, soonest first. The
+    // total counts at most MAX_COUNT of them.
+    #[returns(ProposalsResponse)]
+    ProposalsExpiringWithin {
+        seconds: u64,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    // Active proposals with the most invested over the last day
+    #[returns(crate::trending::TrendingProposalsResponse)]
+    TrendingProposals { limit: Option<u32> },
     #[returns(ProposalsResponse)]
     AllProposals {
         start_after: Option<String>,
@@ -120,6 +661,20 @@ pub enum QueryMsg {
         start_after: Option<String>,
         limit: Option<u32>,
     },
+    // Dry run of an investment: what it would buy and why it would be rejected, if at all
+    #[returns(crate::simulation::SimulateInvestmentResponse)]
+    SimulateInvestment {
+        proposal_id: String,
+        amount: Uint128,
+        denom: String,
+        investor: Option<String>, // Enables the investor-specific checks
+    },
+    #[returns(crate::ledger::ProposalLedgerResponse)]
+    ProposalLedger {
+        proposal_id: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
 
     // Creator queries
     #[returns(CreatorResponse)]
@@ -132,6 +687,9 @@ pub enum QueryMsg {
     TotalValueLocked {},
     #[returns(PlatformStats)]
     PlatformStats {},
+    // Config, platform stats and, for a user, their portfolio and rate limits in one round trip
+    #[returns(DashboardResponse)]
+    DashboardData { user: Option<String> },
 
     // Portfolio queries
     #[returns(InvestmentsResponse)]
@@ -140,36 +698,239 @@ pub enum QueryMsg {
         start_after: Option<String>,
         limit: Option<u32>,
     },
-    #[returns(InvestmentResponse)]
-    PortfolioPerformance { user: String },
+    // Open lots behind the user's position in one proposal, oldest first
+    #[returns(crate::tax_lots::CostBasisResponse)]
+    CostBasis { proposal_id: String, user: String },
+    // One receipt per accepted investment, across proposals, oldest first
+    #[returns(crate::receipts::ReceiptsResponse)]
+    ReceiptsByUser {
+        user: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    // Investments, refunds, distribution claims and votes, newest first
+    #[returns(crate::activity::ActivityResponse)]
+    ActivityByUser {
+        user: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    // Invested, marked-to-market and realized totals with a per-proposal breakdown
+    #[returns(crate::helpers::PortfolioPerformance)]
+    PortfolioPerformance {
+        user: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
 
     // Rate limit queries
-    #[returns(String)]
+    #[returns(crate::rate_limit::RateLimitStatusResponse)]
     RateLimitStatus { user: String, operation: String },
-    #[returns(String)]
+    #[returns(crate::rate_limit::RateLimitConfig)]
     RateLimitConfig {},
+    #[returns(crate::rate_limit::RateLimitExemptionsResponse)]
+    RateLimitExemptions {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    // Keeper queries
+    #[returns(crate::keeper::KeeperConfig)]
+    KeeperConfig {},
+    #[returns(crate::keeper::KeeperStats)]
+    KeeperStats { keeper: String },
+    #[returns(crate::referral::ReferralConfig)]
+    ReferralConfig {},
+    #[returns(crate::referral::ReferrerRewardsResponse)]
+    ReferrerRewards { referrer: String },
+    #[returns(crate::scheduler::SchedulerState)]
+    SchedulerState {},
+    #[returns(crate::vesting::VestingStatusResponse)]
+    VestingStatus { proposal_id: String },
+
+    // Co-signature queries
+    #[returns(crate::cosign::PendingInvestment)]
+    PendingInvestment { ticket_id: u64 },
+    #[returns(crate::cosign::CosignConfig)]
+    CosignConfig {},
+    #[returns(crate::signatures::SignerInfoResponse)]
+    SignerInfo { signer: String },
+
+    // KYC registry queries
+    #[returns(crate::kyc::VerificationStatusResponse)]
+    VerificationStatus { investor: String },
+    #[returns(crate::kyc::VerifiedInvestorsResponse)]
+    VerifiedInvestors {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(crate::kyc::KycConfig)]
+    KycConfig {},
+    #[returns(crate::investor_limits::InvestorLimitResponse)]
+    InvestorRegCfLimit { investor: String },
+    #[returns(crate::kyc::InvestorEligibilityResponse)]
+    InvestorEligibility {
+        proposal_id: String,
+        investor: String,
+    },
+
+    // Private placement queries
+    #[returns(crate::whitelist::WhitelistStatusResponse)]
+    WhitelistStatus {
+        proposal_id: String,
+        investor: String,
+    },
+    #[returns(crate::whitelist::ProposalWhitelistResponse)]
+    ProposalWhitelist {
+        proposal_id: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(crate::revocation::ShareRevocationsResponse)]
+    ShareRevocations {
+        proposal_id: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+
+    // Cap table concentration
+    #[returns(crate::concentration::HolderMetrics)]
+    HolderMetrics { proposal_id: String },
+
+    // Milestone payouts
+    #[returns(crate::milestones::MilestoneEscrowResponse)]
+    MilestoneEscrow { proposal_id: String },
+
+    // Material change reconfirmation
+    #[returns(crate::reconfirmation::ReconfirmationStatusResponse)]
+    ReconfirmationStatus { proposal_id: String },
+
+    // Asset valuations
+    #[returns(crate::oracle::AssetValuationResponse)]
+    AssetValuation { proposal_id: String },
+    #[returns(crate::oracle::ValuationHistoryResponse)]
+    ValuationHistory {
+        proposal_id: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(crate::oracle::OraclesResponse)]
+    Oracles {},
+    #[returns(crate::oracle::PriceFeedResponse)]
+    PriceFeed {},
+    #[returns(crate::oracle::NtrnUsdPriceResponse)]
+    NtrnUsdPrice {},
+
+    // Asset retirement
+    #[returns(crate::retirement::RetirementReportResponse)]
+    RetirementReport { proposal_id: String },
+
+    // Idempotent execution
+    #[returns(crate::idempotency::OperationStatusResponse)]
+    OperationStatus {
+        sender: String,
+        operation_id: String,
+    },
+
+    // Treasury queries
+    #[returns(crate::treasury::TreasuryBalancesResponse)]
+    TreasuryBalances {},
+    #[returns(crate::treasury::TreasuryTransfersResponse)]
+    TreasuryTransfers {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(crate::treasury::TreasuryWithdrawalsResponse)]
+    TreasuryWithdrawals {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+
+    // Last failed share token instantiation, until a mint succeeds; None if there is none
+    #[returns(Option<crate::state::MintFailure>)]
+    MintFailure { proposal_id: String },
+
+    // Cursor of a distribution in progress; None before the first batch and once finalized
+    #[returns(Option<crate::state::DistributionProgress>)]
+    DistributionProgress { proposal_id: String },
+
+    // Investors whose share mint failed, until a retry succeeds
+    #[returns(crate::distribution::FailedMintsResponse)]
+    FailedMints {
+        proposal_id: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
 
     // Lockup queries
-    #[returns(String)]
+    #[returns(crate::lockup::LockupInfo)]
     LockupInfo { proposal_id: String },
-    #[returns(String)]
+    #[returns(crate::lockup::LockupStatus)]
     LockupStatus { proposal_id: String },
 
     // Compliance queries
-    #[returns(String)]
+    #[returns(crate::compliance::ComplianceReport)]
     ComplianceReport { proposal_id: String },
-    #[returns(String)]
+    #[returns(crate::compliance::PlatformComplianceReport)]
     PlatformComplianceReport {},
+    // Paginated report for regulators: proposal status rows, then each proposal's investments.
+    // `format` defaults to JSON rows; CSV returns one encoded line per row
+    #[returns(crate::compliance::ProposalCompliancePage)]
+    ComplianceReportPage {
+        start_after: Option<String>,
+        limit: Option<u32>,
+        format: Option<crate::compliance::ReportFormat>,
+    },
+    #[returns(crate::compliance::InvestmentCompliancePage)]
+    ComplianceInvestmentsPage {
+        proposal_id: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+        format: Option<crate::compliance::ReportFormat>,
+    },
+
+    // Amendment queries
+    #[returns(crate::amendments::AmendmentResponse)]
+    Amendment {
+        proposal_id: String,
+        voter: Option<String>,
+    },
 
     // Governance queries
-    #[returns(String)]
+    #[returns(crate::governance::GovernanceInfo)]
     GovernanceInfo { proposal_id: String },
-    #[returns(String)]
+    #[returns(crate::governance::VotingPowerInfo)]
     UserVotingPower { proposal_id: String, user: String },
-    #[returns(String)]
+    #[returns(Vec<crate::governance::GovernanceInfo>)]
     UserGovernanceProposals { user: String },
-    #[returns(String)]
+    #[returns(crate::governance::GovernanceSetupData)]
     GovernanceSetupData { proposal_id: String },
+    // Notifications the governance contract rejected, by idempotency key
+    #[returns(crate::governance_hooks::FailedGovernanceHooksResponse)]
+    FailedGovernanceHooks {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(crate::governance::GovernanceConfig)]
+    GovernanceConfig {},
+    #[returns(crate::governance::GovernanceProposal)]
+    GovernanceProposal { governance_id: u64 },
+    #[returns(Option<crate::governance::PropertyManager>)]
+    PropertyManager { proposal_id: String },
+    #[returns(crate::governance::GovernanceProposalsResponse)]
+    OpenGovernanceProposals {
+        proposal_id: Option<String>,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(crate::governance::SnapshotVotingPowerResponse)]
+    GovernanceVotingPower { governance_id: u64, voter: String },
+    #[returns(crate::governance::GovernanceVotesResponse)]
+    GovernanceVotes {
+        governance_id: u64,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
 }
 
 // Response types
@@ -177,21 +938,32 @@ pub enum QueryMsg {
 pub struct ProposalResponse {
     pub proposal: Proposal,
     pub funding_progress: FundingProgress,
+    pub creator_verification: crate::verification::VerificationTier, // Issuer trust level, for filtering
+    pub expected_apy: String, // expected_apy_bps for display, e.g. "12.50%"
+    pub frozen: bool,         // Under investigation: no investments or distribution until unfrozen
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct FundingProgress {
     pub raised_percentage: u64,
     pub days_remaining: i64,
+    pub hours_remaining: i64, // Whole hours left, for raises in their final day
+    pub funding_deadline_iso: String, // Deadline in UTC, e.g. "2024-03-01T12:00:00Z"
     pub investors_count: u64,
+    pub investor_cap: u64, // compliance.max_investors, never above the platform ceiling of 500
+    pub investor_slots_remaining: u64, // New investors that can still join
     pub shares_sold: u64,
     pub shares_remaining: u64,
+    pub current_tier: Option<u32>, // Index into pricing_tiers; None when the base price applies
+    pub current_price: Uint128,
+    pub tier_remaining: Option<Uint128>, // Left to raise before the current tier sells out
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct ProposalsResponse {
     pub proposals: Vec<ProposalResponse>,
-    pub total_count: u64,
+    pub total_count: u64,         // Matching proposals across all pages
+    pub next_key: Option<String>, // Pass as start_after for the next page; None on the last page
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -204,13 +976,16 @@ pub struct InvestmentResponse {
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct InvestmentsResponse {
     pub investments: Vec<InvestmentResponse>,
-    pub total_invested: Uint128,
+    pub total_invested: Uint128,  // Across this page
+    pub next_key: Option<String>, // Pass as start_after for the next page; None on the last page
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct CreatorResponse {
     pub creator: Creator,
     pub stats: CreatorStats,
+    pub interchain_account: Option<crate::interchain::InterchainAccount>, // Set when the creator is a registered ICA
+    pub profile: Option<crate::profiles::CreatorProfile>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -225,10 +1000,18 @@ pub struct PlatformStats {
     pub total_proposals: u64,
     pub active_proposals: u64,
     pub total_raised: Uint128,
-    pub total_investors: u64,
+    pub total_investors: u64, // Distinct addresses that have ever invested
     pub successful_proposals: u64,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DashboardResponse {
+    pub config: crate::state::Config,
+    pub platform_stats: PlatformStats,
+    pub portfolio: Option<crate::helpers::PortfolioPerformance>, // None without a user
+    pub rate_limits: Vec<crate::rate_limit::RateLimitStatusResponse>, // Empty without a user
+}
+
 // Migration message
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct MigrateMsg {}
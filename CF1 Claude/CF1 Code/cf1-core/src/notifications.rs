@@ -0,0 +1,122 @@
+//! Notification events for off-chain services.
+//!
+//! Indexers watch for a fixed set of events to send emails and push notifications: a raise
+//! funded, share tokens distributed, a lockup expired and a refund issued. Each carries a stable
+//! set of attributes and a "schema_version". Many of these moments are reached deep inside
+//! storage updates, so handlers queue the events here and the entry point adds them to the
+//! response once the handler succeeds. The same moments are passed on to a registered
+//! governance contract.
+
+use cosmwasm_std::{Addr, Env, Event, Response, StdResult, Storage, Uint128};
+use cw_storage_plus::{Item, Map};
+
+use crate::events;
+use crate::governance_hooks;
+use crate::state::Proposal;
+
+/// Why escrowed funds went back to an investor
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RefundReason {
+    ProposalCancelled,
+    ProposalFailed,
+    Withdrawn,
+    NotReconfirmed,
+}
+
+impl RefundReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RefundReason::ProposalCancelled => "proposal_cancelled",
+            RefundReason::ProposalFailed => "proposal_failed",
+            RefundReason::Withdrawn => "withdrawn",
+            RefundReason::NotReconfirmed => "not_reconfirmed",
+        }
+    }
+}
+
+// Storage keys
+const OUTBOX: Item<Vec<Event>> = Item::new("notification_outbox"); // Queued this transaction
+pub const NOTIFIED_LOCKUPS: Map<String, u64> = Map::new("gov_hooks_lockups"); // proposal_id -> lockup_end announced
+
+fn emit(storage: &mut dyn Storage, event: Event) -> StdResult<()> {
+    let mut outbox = OUTBOX.may_load(storage)?.unwrap_or_default();
+    outbox.push(event);
+    OUTBOX.save(storage, &outbox)
+}
+
+/// A raise just closed as funded
+pub fn proposal_funded(storage: &mut dyn Storage, env: &Env, proposal: &Proposal) -> StdResult<()> {
+    emit(
+        storage,
+        events::funding_complete(
+            &proposal.id,
+            &proposal.creator,
+            proposal.funding_status.raised_amount,
+            proposal.funding_status.investor_count,
+            proposal.timestamps.lockup_end.unwrap_or_default(),
+        ),
+    )?;
+    governance_hooks::proposal_funded(storage, env, proposal)
+}
+
+/// Every investor received their shares
+pub fn tokens_distributed(
+    storage: &mut dyn Storage,
+    env: &Env,
+    proposal: &Proposal,
+    token_contract: &Addr,
+) -> StdResult<()> {
+    emit(
+        storage,
+        events::tokens_distributed(
+            &proposal.id,
+            token_contract,
+            proposal.financial_terms.total_shares,
+            proposal.funding_status.investor_count,
+        ),
+    )?;
+    governance_hooks::tokens_distributed(storage, env, proposal, token_contract)
+}
+
+/// A lockup has ended; announced once per lockup end, so a lockup extended by a holder vote is
+/// announced again when the new end passes
+pub fn lockup_expired(storage: &mut dyn Storage, env: &Env, proposal: &Proposal) -> StdResult<()> {
+    let Some(lockup_end) = proposal.timestamps.lockup_end else {
+        return Ok(());
+    };
+    if env.block.time.seconds() < lockup_end
+        || NOTIFIED_LOCKUPS.may_load(storage, proposal.id.clone())? == Some(lockup_end)
+    {
+        return Ok(());
+    }
+    NOTIFIED_LOCKUPS.save(storage, proposal.id.clone(), &lockup_end)?;
+    emit(storage, events::lockup_expired(&proposal.id, lockup_end))?;
+    governance_hooks::lockup_expired(storage, &proposal.id, lockup_end)
+}
+
+/// Escrowed funds went back to an investor
+pub fn refund_issued(
+    storage: &mut dyn Storage,
+    proposal_id: &str,
+    investor: &Addr,
+    amount: Uint128,
+    reason: RefundReason,
+) -> StdResult<()> {
+    emit(
+        storage,
+        events::refund_issued(proposal_id, investor, amount, reason.as_str()),
+    )
+}
+
+/// Add everything queued during the transaction to the response, then send the governance
+/// notifications (called by the entry points)
+pub fn flush(storage: &mut dyn Storage, response: Response) -> StdResult<Response> {
+    let response = match OUTBOX.may_load(storage)? {
+        Some(outbox) => {
+            OUTBOX.remove(storage);
+            response.add_events(outbox)
+        }
+        None => response,
+    };
+    governance_hooks::flush(storage, response)
+}
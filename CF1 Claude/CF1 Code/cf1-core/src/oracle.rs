@@ -0,0 +1,443 @@
+use cosmwasm_std::{
+    Addr, Decimal, Deps, DepsMut, Env, Int64, MessageInfo, Order, Response, StdResult, Storage,
+    Uint128, Uint64,
+};
+use cw_storage_plus::{Bound, Item, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+use crate::gas_optimization::{DEFAULT_LIMIT, MAX_LIMIT};
+use crate::roles::{self, Role};
+use crate::state::{
+    save_proposal, FinancialTerms, Investment, InvestmentStatus, Proposal, ProposalStatus,
+    PROPOSALS,
+};
+
+/// Appraisal of a whole asset reported by a whitelisted oracle
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AssetValuation {
+    pub sequence: u64,
+    pub valuation: Uint128, // Value of the entire asset in untrn
+    pub source: String,     // e.g. appraisal firm or feed identifier
+    pub reported_by: Addr,
+    pub block_height: u64,
+    pub timestamp: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AssetValuationResponse {
+    pub proposal_id: String,
+    pub latest: Option<AssetValuation>,
+    pub value_per_share: Option<Uint128>, // None until an oracle has reported
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ValuationHistoryResponse {
+    pub proposal_id: String,
+    pub valuations: Vec<AssetValuation>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct OraclesResponse {
+    pub oracles: Vec<Addr>,
+}
+
+/// External contract serving the NTRN/USD rate
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PriceFeedProvider {
+    Pyth {
+        price_feed_id: String,
+    }, // Hex id of the NTRN/USD feed
+    Band {
+        base_symbol: String,
+        quote_symbol: String,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PriceFeedConfig {
+    pub contract: Addr,
+    pub provider: PriceFeedProvider,
+    pub max_staleness_seconds: u64, // Older prices are rejected
+}
+
+/// Funding terms quoted in micro-USD (6 decimals); untrn amounts are derived from them
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct UsdTerms {
+    pub target_amount_usd: Uint128,
+    pub minimum_investment_usd: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct NtrnUsdPriceResponse {
+    pub price: Decimal, // USD per NTRN, which is also micro-USD per untrn
+    pub publish_time: u64,
+    pub age_seconds: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PriceFeedResponse {
+    pub config: Option<PriceFeedConfig>,
+}
+
+// Pyth and Band query interfaces, mirrored to avoid pulling in their SDKs
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+enum PythQueryMsg {
+    PriceFeed { id: String },
+}
+
+#[derive(Deserialize)]
+struct PythPriceFeedResponse {
+    price_feed: PythPriceFeed,
+}
+
+#[derive(Deserialize)]
+struct PythPriceFeed {
+    price: PythPrice,
+}
+
+#[derive(Deserialize)]
+struct PythPrice {
+    price: Int64,
+    #[allow(dead_code)]
+    conf: Uint64,
+    expo: i32,
+    publish_time: i64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+enum BandQueryMsg {
+    GetReferenceData {
+        base_symbol: String,
+        quote_symbol: String,
+    },
+}
+
+#[derive(Deserialize)]
+struct BandReferenceData {
+    rate: Uint128, // 18 decimals
+    last_updated_base: u64,
+    last_updated_quote: u64,
+}
+
+// Storage keys
+pub const ORACLE_WHITELIST: Map<&Addr, u64> = Map::new("oracle_whitelist"); // oracle -> added_at
+pub const ASSET_VALUATIONS: Map<(String, u64), AssetValuation> = Map::new("asset_valuations"); // (proposal_id, seq) -> valuation
+pub const LATEST_VALUATIONS: Map<String, AssetValuation> = Map::new("latest_valuations");
+pub const PRICE_FEED: Item<PriceFeedConfig> = Item::new("price_feed");
+
+/// Allow an address to report valuations (operator only)
+pub fn add_oracle(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    oracle: String,
+) -> Result<Response, ContractError> {
+    roles::ensure_role(deps.storage, &info.sender, Role::Operator)?;
+
+    let oracle_addr = deps.api.addr_validate(&oracle)?;
+    ORACLE_WHITELIST.save(deps.storage, &oracle_addr, &env.block.time.seconds())?;
+
+    Ok(Response::new()
+        .add_attribute("method", "add_oracle")
+        .add_attribute("oracle", oracle_addr))
+}
+
+/// Revoke an oracle; valuations it already reported are kept (operator only)
+pub fn remove_oracle(
+    deps: DepsMut,
+    info: MessageInfo,
+    oracle: String,
+) -> Result<Response, ContractError> {
+    roles::ensure_role(deps.storage, &info.sender, Role::Operator)?;
+
+    let oracle_addr = deps.api.addr_validate(&oracle)?;
+    ORACLE_WHITELIST.remove(deps.storage, &oracle_addr);
+
+    Ok(Response::new()
+        .add_attribute("method", "remove_oracle")
+        .add_attribute("oracle", oracle_addr))
+}
+
+/// Point the contract at an external NTRN/USD feed (operator only)
+pub fn set_price_feed(
+    deps: DepsMut,
+    info: MessageInfo,
+    contract: String,
+    provider: PriceFeedProvider,
+    max_staleness_seconds: u64,
+) -> Result<Response, ContractError> {
+    roles::ensure_role(deps.storage, &info.sender, Role::Operator)?;
+    if max_staleness_seconds == 0 {
+        return Err(ContractError::InvalidInput {
+            field: "max_staleness_seconds".to_string(),
+            message: "Staleness window must be greater than zero".to_string(),
+        });
+    }
+
+    let contract = deps.api.addr_validate(&contract)?;
+    PRICE_FEED.save(
+        deps.storage,
+        &PriceFeedConfig {
+            contract: contract.clone(),
+            provider,
+            max_staleness_seconds,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "set_price_feed")
+        .add_attribute("contract", contract)
+        .add_attribute("max_staleness_seconds", max_staleness_seconds.to_string()))
+}
+
+/// Query the configured feed for NTRN/USD and reject stale or non-positive prices
+pub fn fetch_ntrn_usd_price(deps: Deps, env: &Env) -> Result<NtrnUsdPriceResponse, ContractError> {
+    let config = PRICE_FEED
+        .may_load(deps.storage)?
+        .ok_or(ContractError::PriceFeedNotConfigured {})?;
+
+    let (price, publish_time) = match &config.provider {
+        PriceFeedProvider::Pyth { price_feed_id } => {
+            let response: PythPriceFeedResponse = deps.querier.query_wasm_smart(
+                &config.contract,
+                &PythQueryMsg::PriceFeed {
+                    id: price_feed_id.clone(),
+                },
+            )?;
+            let feed = response.price_feed.price;
+            if feed.price.i64() <= 0 || feed.publish_time < 0 {
+                return Err(ContractError::InvalidOraclePrice {});
+            }
+            let atomics = Uint128::from(feed.price.i64() as u128);
+            let price = if feed.expo <= 0 {
+                Decimal::from_atomics(atomics, feed.expo.unsigned_abs())
+            } else {
+                Decimal::from_atomics(atomics * Uint128::new(10).pow(feed.expo as u32), 0)
+            }
+            .map_err(|_| ContractError::InvalidOraclePrice {})?;
+            (price, feed.publish_time as u64)
+        }
+        PriceFeedProvider::Band {
+            base_symbol,
+            quote_symbol,
+        } => {
+            let data: BandReferenceData = deps.querier.query_wasm_smart(
+                &config.contract,
+                &BandQueryMsg::GetReferenceData {
+                    base_symbol: base_symbol.clone(),
+                    quote_symbol: quote_symbol.clone(),
+                },
+            )?;
+            let price = Decimal::from_atomics(data.rate, 18)
+                .map_err(|_| ContractError::InvalidOraclePrice {})?;
+            (price, data.last_updated_base.min(data.last_updated_quote))
+        }
+    };
+    if price.is_zero() {
+        return Err(ContractError::InvalidOraclePrice {});
+    }
+
+    let age_seconds = env.block.time.seconds().saturating_sub(publish_time);
+    if age_seconds > config.max_staleness_seconds {
+        return Err(ContractError::StalePriceFeed {
+            age_seconds,
+            max_staleness_seconds: config.max_staleness_seconds,
+        });
+    }
+
+    Ok(NtrnUsdPriceResponse {
+        price,
+        publish_time,
+        age_seconds,
+    })
+}
+
+/// Convert USD-denominated terms to untrn at the current rate.
+///
+/// Target and minimum follow the rate on every call. The share price is only set while nothing
+/// has been raised, because shares sold are derived from the untrn raised at that price.
+pub fn apply_usd_terms(
+    deps: Deps,
+    env: &Env,
+    terms: &mut FinancialTerms,
+    raised_amount: Uint128,
+) -> Result<(), ContractError> {
+    let Some(usd_terms) = terms.usd_terms.clone() else {
+        return Ok(());
+    };
+    let price = fetch_ntrn_usd_price(deps, env)?.price;
+    let to_untrn = |usd: Uint128| {
+        usd.checked_div_ceil(price)
+            .map_err(|_| ContractError::InvalidOraclePrice {})
+    };
+
+    terms.target_amount = to_untrn(usd_terms.target_amount_usd)?;
+    terms.minimum_investment = to_untrn(usd_terms.minimum_investment_usd)?;
+    if raised_amount.is_zero() {
+        terms.token_price = to_untrn(
+            usd_terms
+                .target_amount_usd
+                .multiply_ratio(1u128, terms.total_shares.max(1)),
+        )?;
+    }
+    Ok(())
+}
+
+/// Re-derive a USD-denominated proposal's untrn terms before accepting an investment
+pub fn refresh_usd_terms(deps: DepsMut, env: &Env, proposal_id: &str) -> Result<(), ContractError> {
+    let Some(mut proposal) = PROPOSALS.may_load(deps.storage, proposal_id.to_string())? else {
+        return Ok(());
+    };
+    if proposal.financial_terms.usd_terms.is_none() || proposal.status != ProposalStatus::Active {
+        return Ok(());
+    }
+
+    let raised_amount = proposal.funding_status.raised_amount;
+    apply_usd_terms(
+        deps.as_ref(),
+        env,
+        &mut proposal.financial_terms,
+        raised_amount,
+    )?;
+    save_proposal(deps.storage, &proposal)?;
+    Ok(())
+}
+
+/// Record a new valuation for a funded asset (whitelisted oracles only)
+pub fn update_asset_valuation(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: String,
+    valuation: Uint128,
+    source: String,
+) -> Result<Response, ContractError> {
+    if !ORACLE_WHITELIST.has(deps.storage, &info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+    if valuation.is_zero() {
+        return Err(ContractError::InvalidInput {
+            field: "valuation".to_string(),
+            message: "Valuation must be greater than zero".to_string(),
+        });
+    }
+
+    // Only assets with issued shares have a value to mark
+    let proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+    if !matches!(
+        proposal.status,
+        ProposalStatus::Funded | ProposalStatus::Completed
+    ) {
+        return Err(ContractError::ProposalNotFunded {});
+    }
+
+    let sequence = LATEST_VALUATIONS
+        .may_load(deps.storage, proposal_id.clone())?
+        .map_or(0, |latest| latest.sequence + 1);
+    let record = AssetValuation {
+        sequence,
+        valuation,
+        source,
+        reported_by: info.sender.clone(),
+        block_height: env.block.height,
+        timestamp: env.block.time.seconds(),
+    };
+    ASSET_VALUATIONS.save(deps.storage, (proposal_id.clone(), sequence), &record)?;
+    LATEST_VALUATIONS.save(deps.storage, proposal_id.clone(), &record)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_asset_valuation")
+        .add_attribute("proposal_id", &proposal_id)
+        .add_attribute("valuation", valuation.to_string())
+        .add_attribute("sequence", sequence.to_string())
+        .add_attribute("oracle", info.sender))
+}
+
+/// Current value of a position: pro rata share of the latest valuation, or cost basis until
+/// an oracle has reported. Refunded and redeemed positions, and failed raises, are worth nothing.
+pub fn mark_to_market(
+    storage: &dyn Storage,
+    investment: &Investment,
+    proposal: &Proposal,
+) -> StdResult<Uint128> {
+    if matches!(
+        investment.status,
+        InvestmentStatus::Refunded | InvestmentStatus::Redeemed
+    ) || matches!(
+        proposal.status,
+        ProposalStatus::Failed | ProposalStatus::Cancelled
+    ) {
+        return Ok(Uint128::zero());
+    }
+
+    match LATEST_VALUATIONS.may_load(storage, proposal.id.clone())? {
+        Some(latest) if proposal.financial_terms.total_shares > 0 => Ok(latest
+            .valuation
+            .multiply_ratio(investment.shares, proposal.financial_terms.total_shares)),
+        _ => Ok(investment.amount),
+    }
+}
+
+// Query functions
+pub fn query_asset_valuation(deps: Deps, proposal_id: String) -> StdResult<AssetValuationResponse> {
+    let proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+    let latest = LATEST_VALUATIONS.may_load(deps.storage, proposal_id.clone())?;
+    let value_per_share = latest.as_ref().and_then(|latest| {
+        latest
+            .valuation
+            .checked_div(Uint128::from(proposal.financial_terms.total_shares))
+            .ok()
+    });
+
+    Ok(AssetValuationResponse {
+        proposal_id,
+        latest,
+        value_per_share,
+    })
+}
+
+pub fn query_valuation_history(
+    deps: Deps,
+    proposal_id: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<ValuationHistoryResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let valuations = ASSET_VALUATIONS
+        .prefix(proposal_id.clone())
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, valuation)| valuation))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(ValuationHistoryResponse {
+        proposal_id,
+        valuations,
+    })
+}
+
+pub fn query_price_feed(deps: Deps) -> StdResult<PriceFeedResponse> {
+    Ok(PriceFeedResponse {
+        config: PRICE_FEED.may_load(deps.storage)?,
+    })
+}
+
+pub fn query_ntrn_usd_price(deps: Deps, env: Env) -> StdResult<NtrnUsdPriceResponse> {
+    fetch_ntrn_usd_price(deps, &env)
+        .map_err(|err| cosmwasm_std::StdError::generic_err(err.to_string()))
+}
+
+pub fn query_oracles(deps: Deps) -> StdResult<OraclesResponse> {
+    let oracles = ORACLE_WHITELIST
+        .keys(deps.storage, None, None, Order::Ascending)
+        .take(MAX_LIMIT as usize)
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(OraclesResponse { oracles })
+}
@@ -0,0 +1,115 @@
+use cosmwasm_std::{Deps, DepsMut, Env, MessageInfo, Response, StdResult, Storage};
+use cw_storage_plus::Item;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+use crate::msg::ExecuteMsg;
+use crate::roles::{self, Role};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PauseState {
+    pub reason: String,
+    pub timestamp: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PauseStatusResponse {
+    pub paused: bool,
+    pub state: Option<PauseState>,
+}
+
+// Storage keys
+pub const PAUSE_STATE: Item<PauseState> = Item::new("pause_state"); // Present only while paused
+
+/// Reject messages that move money into or out of a raise while the contract is paused.
+///
+/// That covers new investments and co-signed confirmations, share distribution, and payouts to
+/// creators and holders: milestone tranches (including ones released by a holder vote), vested
+/// creator tokens and redemptions. Refunds, withdrawals and admin actions stay open so funds are
+/// never trapped by a pause.
+pub fn ensure_not_paused(storage: &dyn Storage, msg: &ExecuteMsg) -> Result<(), ContractError> {
+    let blocked = matches!(
+        msg,
+        ExecuteMsg::Invest { .. }
+            | ExecuteMsg::InvestFor { .. }
+            | ExecuteMsg::InvestFromIbc { .. }
+            | ExecuteMsg::ConfirmInvestment { .. }
+            | ExecuteMsg::ConfirmInvestmentSigned { .. }
+            | ExecuteMsg::CreateProposal { .. }
+            | ExecuteMsg::MintTokens { .. }
+            | ExecuteMsg::RetryMintTokens { .. }
+            | ExecuteMsg::DistributeTokens { .. }
+            | ExecuteMsg::DistributeTokensBatch { .. }
+            | ExecuteMsg::FinalizeDistribution { .. }
+            | ExecuteMsg::RetryFailedMints { .. }
+            | ExecuteMsg::ReleaseMilestone { .. }
+            | ExecuteMsg::ClaimVestedTokens { .. }
+            | ExecuteMsg::RedeemShares { .. }
+            | ExecuteMsg::ExecuteGovernanceProposal { .. }
+    );
+    if !blocked {
+        return Ok(());
+    }
+    if let Some(state) = PAUSE_STATE.may_load(storage)? {
+        return Err(ContractError::ContractPaused {
+            reason: state.reason,
+        });
+    }
+    Ok(())
+}
+
+/// Trip the circuit breaker (guardian only)
+pub fn pause(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    reason: String,
+) -> Result<Response, ContractError> {
+    roles::ensure_role(deps.storage, &info.sender, Role::Guardian)?;
+    if PAUSE_STATE.exists(deps.storage) {
+        return Err(ContractError::InvalidInput {
+            field: "pause".to_string(),
+            message: "Contract is already paused".to_string(),
+        });
+    }
+
+    PAUSE_STATE.save(
+        deps.storage,
+        &PauseState {
+            reason: reason.clone(),
+            timestamp: env.block.time.seconds(),
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "pause")
+        .add_attribute("reason", reason)
+        .add_attribute("paused_by", info.sender))
+}
+
+/// Resume normal operation (guardian only)
+pub fn unpause(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    roles::ensure_role(deps.storage, &info.sender, Role::Guardian)?;
+    if !PAUSE_STATE.exists(deps.storage) {
+        return Err(ContractError::InvalidInput {
+            field: "pause".to_string(),
+            message: "Contract is not paused".to_string(),
+        });
+    }
+
+    PAUSE_STATE.remove(deps.storage);
+
+    Ok(Response::new()
+        .add_attribute("method", "unpause")
+        .add_attribute("unpaused_by", info.sender))
+}
+
+// Query functions
+pub fn query_pause_status(deps: Deps) -> StdResult<PauseStatusResponse> {
+    let state = PAUSE_STATE.may_load(deps.storage)?;
+    Ok(PauseStatusResponse {
+        paused: state.is_some(),
+        state,
+    })
+}
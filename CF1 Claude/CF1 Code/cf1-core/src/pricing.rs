@@ -0,0 +1,192 @@
+use cosmwasm_std::Uint128;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+use crate::state::FinancialTerms;
+
+/// Most tranches a single proposal can define
+pub const MAX_PRICING_TIERS: usize = 10;
+
+/// Discounted tranche that applies until `up_to` has been raised (e.g. an early-bird price)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PricingTier {
+    pub up_to: Uint128, // Cumulative raised amount at which the tier sells out
+    pub token_price: Uint128,
+}
+
+/// Tier in effect at a given raised amount
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ActiveTier {
+    pub index: Option<u32>, // None once every tier has sold out and the base price applies
+    pub token_price: Uint128,
+    pub remaining: Option<Uint128>, // Amount left before the next price applies
+}
+
+/// Tiers must be ordered by `up_to`, priced, and sell out before the funding cap
+pub fn validate_tiers(terms: &FinancialTerms) -> Result<(), ContractError> {
+    if terms.pricing_tiers.len() > MAX_PRICING_TIERS {
+        return Err(ContractError::InvalidInput {
+            field: "pricing_tiers".to_string(),
+            message: format!("At most {} pricing tiers", MAX_PRICING_TIERS),
+        });
+    }
+
+    let mut previous = Uint128::zero();
+    for tier in &terms.pricing_tiers {
+        if tier.token_price.is_zero() || tier.up_to <= previous || tier.up_to > terms.funding_cap()
+        {
+            return Err(ContractError::InvalidInput {
+                field: "pricing_tiers".to_string(),
+                message: "Tiers need a price and strictly increasing limits within the funding cap"
+                    .to_string(),
+            });
+        }
+        previous = tier.up_to;
+    }
+    Ok(())
+}
+
+/// Price segments as (start, end, price); the base price runs unbounded after the last tier
+fn segments(
+    terms: &FinancialTerms,
+) -> impl Iterator<Item = (Uint128, Option<Uint128>, Uint128)> + '_ {
+    let starts =
+        std::iter::once(Uint128::zero()).chain(terms.pricing_tiers.iter().map(|t| t.up_to));
+    let tiers = terms
+        .pricing_tiers
+        .iter()
+        .map(|t| (Some(t.up_to), t.token_price))
+        .chain(std::iter::once((None, terms.token_price)));
+    starts
+        .zip(tiers)
+        .map(|(start, (end, price))| (start, end, price))
+}
+
+/// Shares bought by the first `raised` of the raise, each segment priced at its own tier
+pub fn shares_for_raised(terms: &FinancialTerms, raised: Uint128) -> u64 {
+    let mut shares = 0u128;
+    for (start, end, price) in segments(terms) {
+        if raised <= start || price.is_zero() {
+            break;
+        }
+        let filled = end.map_or(raised, |end| raised.min(end)) - start;
+        shares += (filled / price).u128();
+    }
+    shares.min(u64::MAX as u128) as u64
+}
+
+/// Amount needed on top of `raised` to buy exactly `shares` more
+pub fn cost_of_shares(
+    terms: &FinancialTerms,
+    raised: Uint128,
+    shares: u64,
+) -> Result<Uint128, ContractError> {
+    let mut needed = Uint128::from(shares);
+    for (start, end, price) in segments(terms) {
+        if end.is_some_and(|end| raised >= end) {
+            continue;
+        }
+        if price.is_zero() {
+            return Err(ContractError::DivisionByZero {});
+        }
+        // Shares already bought in this segment, then where buying `needed` more would land
+        let from = raised.max(start);
+        let bought = (from - start) / price;
+        let target = (bought + needed)
+            .checked_mul(price)
+            .and_then(|cost| start.checked_add(cost))
+            .map_err(|_| ContractError::Overflow {
+                operation: "tier pricing".to_string(),
+            })?;
+        match end {
+            Some(end) if target > end => {
+                needed -= (end - start) / price - bought;
+            }
+            _ => return Ok(target - raised),
+        }
+    }
+    Ok(Uint128::zero())
+}
+
+/// Tier in effect once `raised` has been collected
+pub fn active_tier(terms: &FinancialTerms, raised: Uint128) -> ActiveTier {
+    terms
+        .pricing_tiers
+        .iter()
+        .enumerate()
+        .find(|(_, tier)| raised < tier.up_to)
+        .map_or(
+            ActiveTier {
+                index: None,
+                token_price: terms.token_price,
+                remaining: None,
+            },
+            |(index, tier)| ActiveTier {
+                index: Some(index as u32),
+                token_price: tier.token_price,
+                remaining: Some(tier.up_to - raised),
+            },
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn terms(tiers: &[(u128, u128)]) -> FinancialTerms {
+        FinancialTerms {
+            target_amount: Uint128::new(10_000),
+            token_price: Uint128::new(100),
+            total_shares: 1_000,
+            minimum_investment: Uint128::new(1),
+            expected_apy_bps: 1_000,
+            funding_deadline: 0,
+            allow_partial_fill: false,
+            milestones: vec![],
+            usd_terms: None,
+            soft_cap: None,
+            max_raise: None,
+            pricing_tiers: tiers
+                .iter()
+                .map(|(up_to, price)| PricingTier {
+                    up_to: Uint128::new(*up_to),
+                    token_price: Uint128::new(*price),
+                })
+                .collect(),
+            creator_vesting: None,
+            lockup_tranches: vec![],
+            token_name: None,
+            token_symbol: None,
+            withdrawal_terms: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_investments_span_tiers() {
+        // First 1000 at 50, next 1000 at 80, then the base price of 100
+        let terms = terms(&[(1_000, 50), (2_000, 80)]);
+        assert_eq!(shares_for_raised(&terms, Uint128::new(1_000)), 20);
+        assert_eq!(shares_for_raised(&terms, Uint128::new(2_000)), 32);
+        assert_eq!(shares_for_raised(&terms, Uint128::new(3_000)), 42);
+
+        assert_eq!(
+            cost_of_shares(&terms, Uint128::zero(), 20).unwrap(),
+            Uint128::new(1_000)
+        );
+        // From 900 raised: 2 shares left at 50, then 10 at 80
+        assert_eq!(
+            cost_of_shares(&terms, Uint128::new(900), 12).unwrap(),
+            Uint128::new(900)
+        );
+        assert_eq!(
+            cost_of_shares(&terms, Uint128::new(2_500), 3).unwrap(),
+            Uint128::new(300)
+        );
+
+        let tier = active_tier(&terms, Uint128::new(1_200));
+        assert_eq!(tier.index, Some(1));
+        assert_eq!(tier.remaining, Some(Uint128::new(800)));
+        assert_eq!(active_tier(&terms, Uint128::new(2_000)).index, None);
+    }
+}
@@ -0,0 +1,91 @@
+//! Public creator profiles, kept on-chain so frontends can show who is behind a proposal
+//! without an off-chain database.
+
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response, StdResult, Storage};
+use cw_storage_plus::Map;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+use crate::security::InputValidator;
+use crate::state::CREATORS;
+
+pub const MAX_NAME_LENGTH: usize = 100;
+pub const MAX_BIO_LENGTH: usize = 1_000;
+pub const MAX_WEBSITE_LENGTH: usize = 256;
+/// Fits a hex SHA-256 digest or an IPFS CID
+pub const MAX_LOGO_HASH_LENGTH: usize = 128;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CreatorProfile {
+    pub name: String,
+    pub bio: Option<String>,
+    pub website: Option<String>,
+    pub logo_hash: Option<String>, // Content hash of the logo, resolved by the frontend
+    pub updated_at: u64,
+}
+
+// Storage keys
+pub const CREATOR_PROFILES: Map<&Addr, CreatorProfile> = Map::new("creator_profiles");
+
+fn validate_optional(
+    field: &str,
+    value: &Option<String>,
+    max_length: usize,
+) -> Result<(), ContractError> {
+    match value {
+        Some(value) => InputValidator::validate_length(field, value, 1, max_length),
+        None => Ok(()),
+    }
+}
+
+/// Set the sender's public profile, replacing any previous one
+pub fn update_creator_profile(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name: String,
+    bio: Option<String>,
+    website: Option<String>,
+    logo_hash: Option<String>,
+) -> Result<Response, ContractError> {
+    InputValidator::validate_length("name", &name, 1, MAX_NAME_LENGTH)?;
+    validate_optional("bio", &bio, MAX_BIO_LENGTH)?;
+    validate_optional("website", &website, MAX_WEBSITE_LENGTH)?;
+    validate_optional("logo_hash", &logo_hash, MAX_LOGO_HASH_LENGTH)?;
+
+    let name = InputValidator::sanitize_string_input(&name, MAX_NAME_LENGTH)?;
+    let bio = bio
+        .map(|bio| InputValidator::sanitize_string_input(&bio, MAX_BIO_LENGTH))
+        .transpose()?;
+
+    CREATOR_PROFILES.save(
+        deps.storage,
+        &info.sender,
+        &CreatorProfile {
+            name: name.clone(),
+            bio,
+            website,
+            logo_hash,
+            updated_at: env.block.time.seconds(),
+        },
+    )?;
+    // Keep the name on the creator record in step for existing readers
+    if let Some(mut creator) = CREATORS.may_load(deps.storage, &info.sender)? {
+        creator.name = name.clone();
+        CREATORS.save(deps.storage, &info.sender, &creator)?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "update_creator_profile")
+        .add_attribute("creator", info.sender)
+        .add_attribute("name", name))
+}
+
+/// Name a new creator record starts with; empty until the creator sets a profile
+pub fn display_name(storage: &dyn Storage, creator: &Addr) -> StdResult<String> {
+    Ok(CREATOR_PROFILES
+        .may_load(storage, creator)?
+        .map(|profile| profile.name)
+        .unwrap_or_default())
+}
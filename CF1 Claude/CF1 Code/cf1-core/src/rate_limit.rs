@@ -1,10 +1,12 @@
 use crate::error::ContractError;
-use cosmwasm_std::{Addr, Env, StdError, StdResult, Storage};
-use cw_storage_plus::{Item, Map};
+use crate::gas_optimization::{DEFAULT_LIMIT, MAX_LIMIT};
+use cosmwasm_std::{Addr, Env, Order, StdResult, Storage};
+use cw_storage_plus::{Bound, Item, Map};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Rate limit configuration
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct RateLimitConfig {
     /// Time window in seconds
     pub window_seconds: u64,
@@ -38,6 +40,28 @@ pub struct UserOperations {
     pub last_operation: u64,
 }
 
+/// Where a user stands against one operation's limit
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RateLimitStatusResponse {
+    pub operation: String,
+    pub used: u32,
+    pub remaining: u32,
+    pub window_end: u64, // When the count resets
+}
+
+/// Higher limits for an address such as a market maker or custodian
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RateLimitExemption {
+    pub address: Addr,
+    pub multiplier: Option<u32>, // Scales every limit for the address; None = not rate limited
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RateLimitExemptionsResponse {
+    pub exemptions: Vec<RateLimitExemption>,
+    pub next_key: Option<String>, // Pass as start_after for the next page; None on the last page
+}
+
 /// Operation-specific rate limits
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct OperationLimit {
@@ -50,6 +74,14 @@ pub struct OperationLimit {
 pub const RATE_LIMIT_CONFIG: Item<RateLimitConfig> = Item::new("rate_limit_config");
 pub const USER_OPERATIONS: Map<(&Addr, &str), UserOperations> = Map::new("user_operations");
 pub const OPERATION_LIMITS: Map<&str, OperationLimit> = Map::new("operation_limits");
+pub const RATE_LIMIT_EXEMPTIONS: Map<&Addr, RateLimitExemption> = Map::new("rate_limit_exemptions");
+const PRUNE_CURSOR: Item<(Addr, String)> = Item::new("rate_limit_prune_cursor"); // Absent = start from the first entry
+
+/// Entries checked by a `PruneRateLimitData` call that omits its limit
+pub const DEFAULT_PRUNE_LIMIT: u32 = 100;
+pub const MAX_PRUNE_LIMIT: u32 = 500;
+/// Entries checked on the side of every recorded operation
+pub const AUTO_PRUNE_LIMIT: usize = 3;
 
 /// Rate limiter implementation
 pub struct RateLimiter;
@@ -111,31 +143,75 @@ impl RateLimiter {
             return Ok(());
         }
 
-        let current_time = env.block.time.seconds();
-
-        // Check operation-specific limits first
-        if let Ok(op_limit) = OPERATION_LIMITS.load(storage, operation) {
-            return Self::check_operation_limit(
-                storage,
-                user,
-                operation,
-                current_time,
-                op_limit.max_per_window,
-                op_limit.window_seconds,
-            );
+        // Skip exempt addresses
+        if RATE_LIMIT_EXEMPTIONS
+            .may_load(storage, user)?
+            .is_some_and(|exemption| exemption.multiplier.is_none())
+        {
+            return Ok(());
         }
 
-        // Fall back to global limits
+        let current_time = env.block.time.seconds();
+        let (max_operations, window_seconds) =
+            Self::applicable_limit(storage, &config, user, operation)?;
+
         Self::check_operation_limit(
             storage,
             user,
             operation,
             current_time,
-            config.max_operations,
-            config.window_seconds,
+            max_operations,
+            window_seconds,
         )
     }
 
+    /// The operation's own limit, or the global one, scaled by any exemption for the user.
+    ///
+    /// A full exemption reads as an unlimited count.
+    fn applicable_limit(
+        storage: &dyn Storage,
+        config: &RateLimitConfig,
+        user: &Addr,
+        operation: &str,
+    ) -> StdResult<(u32, u64)> {
+        let (max_operations, window_seconds) =
+            match OPERATION_LIMITS.may_load(storage, operation)? {
+                Some(op_limit) => (op_limit.max_per_window, op_limit.window_seconds),
+                None => (config.max_operations, config.window_seconds),
+            };
+        let max_operations = match RATE_LIMIT_EXEMPTIONS.may_load(storage, user)? {
+            Some(exemption) => exemption.multiplier.map_or(u32::MAX, |multiplier| {
+                max_operations.saturating_mul(multiplier)
+            }),
+            None => max_operations,
+        };
+        Ok((max_operations, window_seconds))
+    }
+
+    /// Check whether the user could perform the operation now, without counting it
+    pub fn ensure_within_limit(
+        storage: &dyn Storage,
+        user: &Addr,
+        operation: &str,
+        env: &Env,
+    ) -> Result<(), ContractError> {
+        let config = RATE_LIMIT_CONFIG.load(storage)?;
+        if !config.enabled || config.excluded_operations.contains(&operation.to_string()) {
+            return Ok(());
+        }
+
+        let (limit, window_seconds) = Self::applicable_limit(storage, &config, user, operation)?;
+        let (_, remaining, _) = Self::get_user_status(storage, user, operation, env)?;
+        if remaining == 0 {
+            return Err(ContractError::RateLimitExceeded {
+                operation: operation.to_string(),
+                limit,
+                window_seconds,
+            });
+        }
+        Ok(())
+    }
+
     /// Check operation-specific rate limit
     fn check_operation_limit(
         storage: &mut dyn Storage,
@@ -186,6 +262,9 @@ impl RateLimiter {
     }
 
     /// Record an operation (updates rate limit counters)
+    ///
+    /// Handlers check `ensure_within_limit` before doing any work and call this only once the
+    /// operation has succeeded, so a rejected call does not use up the caller's allowance.
     pub fn record_operation(
         storage: &mut dyn Storage,
         user: &Addr,
@@ -195,7 +274,8 @@ impl RateLimiter {
         // Check rate limit first
         Self::check_rate_limit(storage, user, operation, env)?;
 
-        // Operation is allowed and has been recorded
+        // Operation is allowed and has been recorded; sweep a few entries so storage stays bounded
+        Self::cleanup_old_data(storage, env, AUTO_PRUNE_LIMIT)?;
         Ok(())
     }
 
@@ -239,6 +319,43 @@ impl RateLimiter {
         Ok(())
     }
 
+    /// Raise an address's limits by `multiplier`, or lift them entirely when it is None
+    pub fn add_exemption(
+        storage: &mut dyn Storage,
+        address: Addr,
+        multiplier: Option<u32>,
+    ) -> Result<(), ContractError> {
+        if multiplier == Some(0) {
+            return Err(ContractError::InvalidInput {
+                field: "multiplier".to_string(),
+                message: "Multiplier must be at least 1".to_string(),
+            });
+        }
+        RATE_LIMIT_EXEMPTIONS.save(
+            storage,
+            &address,
+            &RateLimitExemption {
+                address: address.clone(),
+                multiplier,
+            },
+        )?;
+        Ok(())
+    }
+
+    pub fn remove_exemption(
+        storage: &mut dyn Storage,
+        address: &Addr,
+    ) -> Result<(), ContractError> {
+        if !RATE_LIMIT_EXEMPTIONS.has(storage, address) {
+            return Err(ContractError::InvalidInput {
+                field: "address".to_string(),
+                message: "Address has no rate limit exemption".to_string(),
+            });
+        }
+        RATE_LIMIT_EXEMPTIONS.remove(storage, address);
+        Ok(())
+    }
+
     /// Get user's current rate limit status
     pub fn get_user_status(
         storage: &dyn Storage,
@@ -250,13 +367,9 @@ impl RateLimiter {
         let key = (user, operation);
 
         // Get the applicable limit
+        let config = RATE_LIMIT_CONFIG.load(storage)?;
         let (max_operations, window_seconds) =
-            if let Ok(op_limit) = OPERATION_LIMITS.load(storage, operation) {
-                (op_limit.max_per_window, op_limit.window_seconds)
-            } else {
-                let config = RATE_LIMIT_CONFIG.load(storage)?;
-                (config.max_operations, config.window_seconds)
-            };
+            Self::applicable_limit(storage, &config, user, operation)?;
 
         match USER_OPERATIONS.may_load(storage, key)? {
             Some(user_ops) => {
@@ -277,19 +390,81 @@ impl RateLimiter {
         }
     }
 
-    /// Clean up old rate limit data (maintenance function)
-    pub fn cleanup_old_data(
-        storage: &mut dyn Storage,
+    /// Get user's status for every operation with its own limit
+    pub fn get_user_statuses(
+        storage: &dyn Storage,
+        user: &Addr,
         env: &Env,
-        max_age_seconds: u64,
-    ) -> StdResult<u32> {
+    ) -> StdResult<Vec<RateLimitStatusResponse>> {
+        let operations = OPERATION_LIMITS
+            .keys(storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        operations
+            .into_iter()
+            .map(|operation| {
+                let (used, remaining, window_end) =
+                    Self::get_user_status(storage, user, &operation, env)?;
+                Ok(RateLimitStatusResponse {
+                    operation,
+                    used,
+                    remaining,
+                    window_end,
+                })
+            })
+            .collect()
+    }
+
+    /// Remove entries whose window has ended, checking up to `limit` entries.
+    ///
+    /// Resumes after the last entry the previous call checked and wraps back to the first once it
+    /// reaches the end, so repeated calls sweep the whole map. An expired entry counts the same as
+    /// no entry, so removing one never changes a user's allowance. Returns the number removed.
+    pub fn cleanup_old_data(storage: &mut dyn Storage, env: &Env, limit: usize) -> StdResult<u32> {
+        let config = RATE_LIMIT_CONFIG.load(storage)?;
         let current_time = env.block.time.seconds();
-        let cutoff_time = current_time.saturating_sub(max_age_seconds);
-        let mut cleaned = 0u32;
+        let cursor = PRUNE_CURSOR.may_load(storage)?;
+
+        let mut entries = USER_OPERATIONS
+            .range(
+                storage,
+                cursor
+                    .as_ref()
+                    .map(|(user, operation)| Bound::exclusive((user, operation.as_str()))),
+                None,
+                Order::Ascending,
+            )
+            .take(limit)
+            .collect::<StdResult<Vec<_>>>()?;
+        // Past the end, carry on from the first entry up to where this call started
+        if let Some((user, operation)) = cursor.as_ref().filter(|_| entries.len() < limit) {
+            let wrapped = USER_OPERATIONS
+                .range(
+                    storage,
+                    None,
+                    Some(Bound::inclusive((user, operation.as_str()))),
+                    Order::Ascending,
+                )
+                .take(limit - entries.len())
+                .collect::<StdResult<Vec<_>>>()?;
+            entries.extend(wrapped);
+        }
 
-        // This would require iterating through all USER_OPERATIONS entries
-        // In a real implementation, you'd use pagination and batch processing
-        // For now, we'll return 0 as a placeholder
+        match entries.last() {
+            Some((key, _)) if entries.len() == limit => PRUNE_CURSOR.save(storage, key)?,
+            _ => PRUNE_CURSOR.remove(storage),
+        }
+
+        let mut cleaned = 0u32;
+        for ((user, operation), user_ops) in entries {
+            let window_seconds = match OPERATION_LIMITS.may_load(storage, &operation)? {
+                Some(op_limit) => op_limit.window_seconds,
+                None => config.window_seconds,
+            };
+            if current_time >= user_ops.window_start.saturating_add(window_seconds) {
+                USER_OPERATIONS.remove(storage, (&user, &operation));
+                cleaned += 1;
+            }
+        }
 
         Ok(cleaned)
     }
@@ -298,20 +473,54 @@ impl RateLimiter {
 // Query functions
 pub fn query_rate_limit_status(
     deps: cosmwasm_std::Deps,
+    env: Env,
     user: String,
     operation: String,
-) -> cosmwasm_std::StdResult<bool> {
-    let _user_addr = deps.api.addr_validate(&user)?;
-    // For now, return true (rate limits are okay)
-    // In a real implementation, we'd check the current usage
-    Ok(true)
+) -> cosmwasm_std::StdResult<RateLimitStatusResponse> {
+    let user_addr = deps.api.addr_validate(&user)?;
+    let (used, remaining, window_end) =
+        RateLimiter::get_user_status(deps.storage, &user_addr, &operation, &env)?;
+    Ok(RateLimitStatusResponse {
+        operation,
+        used,
+        remaining,
+        window_end,
+    })
 }
-
 pub fn query_rate_limit_config(
     deps: cosmwasm_std::Deps,
 ) -> cosmwasm_std::StdResult<RateLimitConfig> {
     RATE_LIMIT_CONFIG.load(deps.storage)
 }
+pub fn query_rate_limit_exemptions(
+    deps: cosmwasm_std::Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> cosmwasm_std::StdResult<RateLimitExemptionsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_after = start_after
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+
+    let mut exemptions = RATE_LIMIT_EXEMPTIONS
+        .range(
+            deps.storage,
+            start_after.as_ref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit + 1)
+        .map(|item| item.map(|(_, exemption)| exemption))
+        .collect::<StdResult<Vec<_>>>()?;
+    let next_key = crate::next_page_key(&mut exemptions, limit, |exemption| {
+        exemption.address.to_string()
+    });
+
+    Ok(RateLimitExemptionsResponse {
+        exemptions,
+        next_key,
+    })
+}
 
 #[cfg(test)]
 mod tests {
@@ -420,4 +629,87 @@ mod tests {
         assert_eq!(used, 2);
         assert_eq!(remaining, 3);
     }
+
+    #[test]
+    fn test_cleanup_old_data_prunes_expired_windows() {
+        let mut storage = MockStorage::new();
+        let mut env = mock_env();
+
+        RateLimiter::initialize(&mut storage).unwrap();
+        RateLimiter::update_operation_limit(&mut storage, "short_op", 5, 100).unwrap();
+        for user in ["user1", "user2", "user3"] {
+            let user = Addr::unchecked(user);
+            RateLimiter::check_rate_limit(&mut storage, &user, "short_op", &env).unwrap();
+            RateLimiter::check_rate_limit(&mut storage, &user, "invest", &env).unwrap();
+        }
+
+        // Nothing has expired yet
+        assert_eq!(
+            RateLimiter::cleanup_old_data(&mut storage, &env, 10).unwrap(),
+            0
+        );
+
+        // The short windows have ended, the hour-long invest windows have not
+        env.block.time = env.block.time.plus_seconds(101);
+        assert_eq!(
+            RateLimiter::cleanup_old_data(&mut storage, &env, 4).unwrap(),
+            2
+        );
+        assert_eq!(
+            RateLimiter::cleanup_old_data(&mut storage, &env, 4).unwrap(),
+            1
+        );
+        let remaining = USER_OPERATIONS
+            .keys(&storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(remaining.len(), 3);
+        assert!(remaining.iter().all(|(_, operation)| operation == "invest"));
+
+        // Recording an operation sweeps a few entries along the way
+        env.block.time = env.block.time.plus_seconds(3600);
+        let user = Addr::unchecked("user4");
+        RateLimiter::record_operation(&mut storage, &user, "invest", &env).unwrap();
+        RateLimiter::record_operation(&mut storage, &user, "invest", &env).unwrap();
+        let remaining = USER_OPERATIONS
+            .keys(&storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(remaining, vec![(user, "invest".to_string())]);
+    }
+
+    #[test]
+    fn test_exemptions_scale_or_lift_limits() {
+        let mut storage = MockStorage::new();
+        let env = mock_env();
+        let market_maker = Addr::unchecked("market_maker");
+        let custodian = Addr::unchecked("custodian");
+
+        RateLimiter::initialize(&mut storage).unwrap();
+        RateLimiter::update_operation_limit(&mut storage, "invest", 2, 3600).unwrap();
+        RateLimiter::add_exemption(&mut storage, market_maker.clone(), Some(3)).unwrap();
+        RateLimiter::add_exemption(&mut storage, custodian.clone(), None).unwrap();
+        assert!(RateLimiter::add_exemption(&mut storage, custodian.clone(), Some(0)).is_err());
+
+        for _ in 0..6 {
+            RateLimiter::record_operation(&mut storage, &market_maker, "invest", &env).unwrap();
+        }
+        assert!(
+            RateLimiter::record_operation(&mut storage, &market_maker, "invest", &env).is_err()
+        );
+
+        for _ in 0..10 {
+            RateLimiter::record_operation(&mut storage, &custodian, "invest", &env).unwrap();
+        }
+        let (used, remaining, _) =
+            RateLimiter::get_user_status(&storage, &custodian, "invest", &env).unwrap();
+        assert_eq!((used, remaining), (0, u32::MAX));
+
+        // Back to the normal limit once the exemption is removed
+        RateLimiter::remove_exemption(&mut storage, &market_maker).unwrap();
+        let (used, remaining, _) =
+            RateLimiter::get_user_status(&storage, &market_maker, "invest", &env).unwrap();
+        assert_eq!((used, remaining), (6, 0));
+        assert!(RateLimiter::remove_exemption(&mut storage, &market_maker).is_err());
+    }
 }
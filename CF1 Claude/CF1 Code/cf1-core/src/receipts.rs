@@ -0,0 +1,99 @@
+//! Immutable investment receipts.
+//!
+//! `Investment` accumulates repeat purchases into one position, so it can't show what each
+//! purchase cost. Every accepted investment also gets a receipt, written once and never
+//! changed, recording what was paid, for how many shares, at what price and in which block.
+
+use cosmwasm_std::{Addr, Deps, Env, Order, StdResult, Storage, Uint128};
+use cw_storage_plus::{Bound, Item, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct InvestmentReceipt {
+    pub receipt_id: u64, // Platform-wide, increasing
+    pub proposal_id: String,
+    pub investor: Addr,
+    pub amount: Uint128, // Accepted amount, after any partial-fill change was returned
+    pub shares: u64,
+    pub token_price: Uint128, // Price per share in effect when the purchase started
+    pub denom: String,
+    pub block_height: u64,
+    pub transaction_index: Option<u32>,
+    pub timestamp: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ReceiptsResponse {
+    pub receipts: Vec<InvestmentReceipt>,
+    pub next_key: Option<String>, // Pass as start_after for the next page; None on the last page
+}
+
+// Storage keys
+pub const INVESTMENT_RECEIPTS: Map<(&Addr, u64), InvestmentReceipt> = Map::new("receipts"); // (investor, receipt_id)
+const RECEIPT_COUNT: Item<u64> = Item::new("receipt_count");
+
+/// Write the receipt for an accepted investment, returning its id
+pub fn issue(
+    storage: &mut dyn Storage,
+    env: &Env,
+    investor: &Addr,
+    proposal_id: &str,
+    amount: Uint128,
+    shares: u64,
+    token_price: Uint128,
+) -> StdResult<u64> {
+    let receipt_id = RECEIPT_COUNT.may_load(storage)?.unwrap_or_default() + 1;
+    RECEIPT_COUNT.save(storage, &receipt_id)?;
+    INVESTMENT_RECEIPTS.save(
+        storage,
+        (investor, receipt_id),
+        &InvestmentReceipt {
+            receipt_id,
+            proposal_id: proposal_id.to_string(),
+            investor: investor.clone(),
+            amount,
+            shares,
+            token_price,
+            denom: "untrn".to_string(),
+            block_height: env.block.height,
+            transaction_index: env.transaction.as_ref().map(|tx| tx.index),
+            timestamp: env.block.time.seconds(),
+        },
+    )?;
+    Ok(receipt_id)
+}
+
+// Query functions
+/// The user's receipts across all proposals, oldest first
+pub fn query_receipts_by_user(
+    deps: Deps,
+    user: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<ReceiptsResponse> {
+    let user = deps.api.addr_validate(&user)?;
+    let limit = limit
+        .unwrap_or(crate::gas_optimization::DEFAULT_LIMIT)
+        .min(crate::gas_optimization::MAX_LIMIT) as usize;
+    let min = start_after
+        .map(|s| {
+            s.parse::<u64>().map_err(|_| {
+                cosmwasm_std::StdError::generic_err("start_after must be a receipt id")
+            })
+        })
+        .transpose()?
+        .map(Bound::exclusive);
+
+    let mut receipts = INVESTMENT_RECEIPTS
+        .prefix(&user)
+        .range(deps.storage, min, None, Order::Ascending)
+        .take(limit + 1)
+        .map(|item| item.map(|(_, receipt)| receipt))
+        .collect::<StdResult<Vec<_>>>()?;
+    let next_key = crate::next_page_key(&mut receipts, limit, |receipt| {
+        receipt.receipt_id.to_string()
+    });
+
+    Ok(ReceiptsResponse { receipts, next_key })
+}
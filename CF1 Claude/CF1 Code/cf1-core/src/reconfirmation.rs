@@ -0,0 +1,632 @@
+use cosmwasm_std::{Addr, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Storage, Uint128};
+use cw_storage_plus::Map;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+use crate::escrow;
+use crate::notifications::{self, RefundReason};
+use crate::security::MathGuard;
+use crate::state::{
+    remove_user_investment, save_proposal, AssetDetails, Document, InvestmentStatus, Proposal,
+    ProposalStatus, CREATORS, CREATOR_STATS, ESCROWED_SHARES, INVESTMENTS, PROPOSALS,
+    PROPOSAL_INVESTMENTS,
+};
+use crate::{idempotency, investor_limits, ledger, treasury};
+
+/// Reg CF 227.304(c) gives investors five business days to reconfirm; rounded up to a calendar week
+pub const RECONFIRMATION_PERIOD_SECONDS: u64 = 7 * 24 * 60 * 60;
+/// Highest withdrawal fee a creator can set
+pub const MAX_WITHDRAWAL_FEE_BPS: u16 = 500;
+
+/// How a reconfirmation window was settled
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReconfirmationOutcome {
+    Funded,   // Reconfirmed commitments still meet the target
+    Reopened, // Below target before the deadline; funding resumes
+    Failed,   // Below target after the deadline; everyone is refunded
+}
+
+/// Window opened by a material change between funding and distribution
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ReconfirmationWindow {
+    pub round: u32, // Increments with every material change on the proposal
+    pub description: String,
+    pub opened_at: u64,
+    pub closes_at: u64,
+    pub raised_at_open: Uint128,
+    pub reconfirmed_count: u64,
+    pub withdrawn_amount: Uint128, // Withdrawn during the window plus cancelled at resolution
+    pub outcome: Option<ReconfirmationOutcome>, // None while the window is open
+}
+
+/// How investors may leave a raise that is still active; the default is a free exit at any time
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct WithdrawalTerms {
+    pub fee_bps: u16,          // Kept from the refund as a platform fee
+    pub cooldown_seconds: u64, // Minimum time since the investor's last investment
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ReconfirmationStatusResponse {
+    pub proposal_id: String,
+    pub window: Option<ReconfirmationWindow>,
+    pub reconfirmed_amount: Uint128,
+    pub unconfirmed_amount: Uint128, // Cancelled and refunded if not reconfirmed in time
+}
+
+// Storage keys
+pub const RECONFIRMATION_WINDOWS: Map<String, ReconfirmationWindow> =
+    Map::new("reconfirmation_windows");
+pub const RECONFIRMATIONS: Map<(String, u32, &Addr), u64> = Map::new("reconfirmations"); // (proposal_id, round, investor) -> reconfirmed_at
+
+pub fn validate_withdrawal_terms(terms: &WithdrawalTerms) -> Result<(), ContractError> {
+    if terms.fee_bps > MAX_WITHDRAWAL_FEE_BPS {
+        return Err(ContractError::InvalidInput {
+            field: "withdrawal_terms".to_string(),
+            message: format!(
+                "Withdrawal fee cannot exceed {} bps",
+                MAX_WITHDRAWAL_FEE_BPS
+            ),
+        });
+    }
+    Ok(())
+}
+
+fn open_window_for(
+    storage: &dyn Storage,
+    proposal_id: &str,
+) -> StdResult<Option<ReconfirmationWindow>> {
+    Ok(RECONFIRMATION_WINDOWS
+        .may_load(storage, proposal_id.to_string())?
+        .filter(|window| window.outcome.is_none()))
+}
+
+/// Distribution waits until investors have had their chance to walk away
+pub fn ensure_no_open_window(
+    storage: &dyn Storage,
+    proposal_id: &str,
+) -> Result<(), ContractError> {
+    if open_window_for(storage, proposal_id)?.is_some() {
+        return Err(ContractError::ReconfirmationPending {
+            proposal_id: proposal_id.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Start a reconfirmation window for a funded proposal
+pub fn open_window(
+    storage: &mut dyn Storage,
+    env: &Env,
+    proposal: &Proposal,
+    description: &str,
+) -> Result<ReconfirmationWindow, ContractError> {
+    if proposal.status != ProposalStatus::Funded {
+        return Err(ContractError::ProposalNotFunded {});
+    }
+    ensure_no_open_window(storage, &proposal.id)?;
+
+    let round = RECONFIRMATION_WINDOWS
+        .may_load(storage, proposal.id.clone())?
+        .map_or(0, |previous| previous.round + 1);
+    let now = env.block.time.seconds();
+    let window = ReconfirmationWindow {
+        round,
+        description: description.to_string(),
+        opened_at: now,
+        closes_at: now + RECONFIRMATION_PERIOD_SECONDS,
+        raised_at_open: proposal.funding_status.raised_amount,
+        reconfirmed_count: 0,
+        withdrawn_amount: Uint128::zero(),
+        outcome: None,
+    };
+    RECONFIRMATION_WINDOWS.save(storage, proposal.id.clone(), &window)?;
+    Ok(window)
+}
+
+/// Creator posts a material change to a funded raise, e.g. a corrected offering document
+pub fn post_material_change(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: String,
+    description: String,
+    asset_details: Option<AssetDetails>,
+    documents: Option<Vec<Document>>,
+) -> Result<Response, ContractError> {
+    let mut proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+    if proposal.creator != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    if description.trim().is_empty() {
+        return Err(ContractError::InvalidInput {
+            field: "description".to_string(),
+            message: "Material changes must be described to investors".to_string(),
+        });
+    }
+
+    let window = open_window(deps.storage, &env, &proposal, &description)?;
+
+    if let Some(details) = asset_details {
+        proposal.asset_details = details;
+    }
+    if let Some(docs) = documents {
+        crate::documents::publish(
+            deps.storage,
+            &env,
+            &info.sender,
+            &proposal_id,
+            &mut proposal.documents,
+            docs,
+        )?;
+    }
+    proposal.timestamps.updated_at = env.block.time.seconds();
+    save_proposal(deps.storage, &proposal)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "post_material_change")
+        .add_attribute("proposal_id", &proposal_id)
+        .add_attribute("round", window.round.to_string())
+        .add_attribute("closes_at", window.closes_at.to_string()))
+}
+
+/// Load the open window, failing once its deadline has passed
+fn load_active_window(
+    storage: &dyn Storage,
+    env: &Env,
+    proposal_id: &str,
+) -> Result<ReconfirmationWindow, ContractError> {
+    let window =
+        open_window_for(storage, proposal_id)?.ok_or(ContractError::NoReconfirmationWindow {
+            proposal_id: proposal_id.to_string(),
+        })?;
+    if env.block.time.seconds() > window.closes_at {
+        return Err(ContractError::ReconfirmationWindowClosed {
+            proposal_id: proposal_id.to_string(),
+        });
+    }
+    Ok(window)
+}
+
+/// Investor keeps their commitment under the amended terms
+pub fn reconfirm_investment(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: String,
+) -> Result<Response, ContractError> {
+    let mut window = load_active_window(deps.storage, &env, &proposal_id)?;
+
+    let investment = INVESTMENTS
+        .may_load(deps.storage, (proposal_id.clone(), &info.sender))?
+        .ok_or(ContractError::InvestmentNotFound {})?;
+    if investment.status != InvestmentStatus::Pending {
+        return Err(ContractError::InvestmentNotFound {});
+    }
+
+    let key = (proposal_id.clone(), window.round, &info.sender);
+    if !RECONFIRMATIONS.has(deps.storage, key.clone()) {
+        RECONFIRMATIONS.save(deps.storage, key, &env.block.time.seconds())?;
+        window.reconfirmed_count += 1;
+        RECONFIRMATION_WINDOWS.save(deps.storage, proposal_id.clone(), &window)?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "reconfirm_investment")
+        .add_attribute("proposal_id", &proposal_id)
+        .add_attribute("investor", info.sender))
+}
+
+/// Cancel a pending investment and return the escrowed amount it released.
+///
+/// The position is removed outright so the investor can invest again if funding reopens.
+/// Callers prune `PROPOSAL_INVESTMENTS`, debit treasury escrow and send the refund.
+fn cancel_investment(
+    storage: &mut dyn Storage,
+    env: &Env,
+    proposal: &mut Proposal,
+    investor: &Addr,
+) -> Result<Option<Uint128>, ContractError> {
+    let investment = match INVESTMENTS.may_load(storage, (proposal.id.clone(), investor))? {
+        Some(investment) if investment.status == InvestmentStatus::Pending => investment,
+        _ => return Ok(None),
+    };
+
+    INVESTMENTS.remove(storage, (proposal.id.clone(), investor));
+    // Each purchase stops counting towards the Reg CF limit on the day it was made
+    let lots = crate::tax_lots::TAX_LOTS
+        .may_load(storage, (proposal.id.clone(), investor))?
+        .unwrap_or_default();
+    if lots.is_empty() {
+        investor_limits::release_spend(storage, investor, investment.timestamp, investment.amount)?;
+    }
+    for lot in lots {
+        investor_limits::release_spend(storage, investor, lot.acquired_at, lot.cost)?;
+    }
+    crate::tax_lots::close(storage, &proposal.id, investor);
+    remove_user_investment(storage, investor, &proposal.id)?;
+    let escrowed = crate::shares_sold(storage, proposal)?;
+    ESCROWED_SHARES.save(
+        storage,
+        proposal.id.clone(),
+        &escrowed.saturating_sub(investment.shares),
+    )?;
+    proposal.funding_status.raised_amount =
+        MathGuard::safe_sub(proposal.funding_status.raised_amount, investment.amount)?;
+    proposal.funding_status.investor_count =
+        proposal.funding_status.investor_count.saturating_sub(1);
+
+    ledger::record_entry(
+        storage,
+        env,
+        &proposal.id,
+        ledger::LedgerEntryKind::Refund,
+        investor,
+        investment.amount,
+    )?;
+
+    Ok(Some(investment.amount))
+}
+
+fn prune_investors(
+    storage: &mut dyn Storage,
+    proposal_id: &str,
+    removed: &[Addr],
+) -> StdResult<()> {
+    PROPOSAL_INVESTMENTS.update(
+        storage,
+        proposal_id.to_string(),
+        |investors| -> StdResult<_> {
+            let mut investors = investors.unwrap_or_default();
+            investors.retain(|addr| !removed.contains(addr));
+            Ok(investors)
+        },
+    )?;
+    Ok(())
+}
+
+/// Investor walks away and is refunded immediately.
+///
+/// During a reconfirmation window the exit is free. Otherwise the raise must still be active, the
+/// proposal's cooldown since the investor's last investment must have passed, and its withdrawal
+/// fee is kept as a platform fee.
+pub fn withdraw_investment(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: String,
+    operation_id: Option<String>,
+) -> Result<Response, ContractError> {
+    idempotency::claim(
+        deps.storage,
+        &env,
+        &info.sender,
+        "withdraw_investment",
+        operation_id.as_deref(),
+    )?;
+
+    if open_window_for(deps.storage, &proposal_id)?.is_none() {
+        return withdraw_from_active_raise(deps, env, info.sender, proposal_id);
+    }
+    let mut window = load_active_window(deps.storage, &env, &proposal_id)?;
+    let mut proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+
+    let amount = cancel_investment(deps.storage, &env, &mut proposal, &info.sender)?
+        .ok_or(ContractError::InvestmentNotFound {})?;
+    prune_investors(
+        deps.storage,
+        &proposal_id,
+        std::slice::from_ref(&info.sender),
+    )?;
+
+    // A withdrawal after reconfirming supersedes it
+    if RECONFIRMATIONS.has(
+        deps.storage,
+        (proposal_id.clone(), window.round, &info.sender),
+    ) {
+        RECONFIRMATIONS.remove(
+            deps.storage,
+            (proposal_id.clone(), window.round, &info.sender),
+        );
+        window.reconfirmed_count = window.reconfirmed_count.saturating_sub(1);
+    }
+    window.withdrawn_amount = MathGuard::safe_add(window.withdrawn_amount, amount)?;
+    RECONFIRMATION_WINDOWS.save(deps.storage, proposal_id.clone(), &window)?;
+
+    proposal.timestamps.updated_at = env.block.time.seconds();
+    save_proposal(deps.storage, &proposal)?;
+
+    let refund = escrow::payout_messages(deps.storage, &env, &proposal_id, &info.sender, amount)?;
+    treasury::debit(deps.storage, treasury::TreasuryAccount::Escrow, amount)?;
+    notifications::refund_issued(
+        deps.storage,
+        &proposal_id,
+        &info.sender,
+        amount,
+        RefundReason::Withdrawn,
+    )?;
+
+    Ok(Response::new()
+        .add_messages(refund)
+        .add_attribute("action", "withdraw_investment")
+        .add_attribute("proposal_id", &proposal_id)
+        .add_attribute("investor", &info.sender)
+        .add_attribute("amount", amount.to_string()))
+}
+
+fn withdraw_from_active_raise(
+    deps: DepsMut,
+    env: Env,
+    investor: Addr,
+    proposal_id: String,
+) -> Result<Response, ContractError> {
+    let mut proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+    if proposal.status != ProposalStatus::Active {
+        return Err(ContractError::ProposalNotActive {});
+    }
+    // Past the deadline the outcome is settled by expiry or finalization
+    if env.block.time.seconds() > proposal.financial_terms.funding_deadline {
+        return Err(ContractError::FundingDeadlineExpired {});
+    }
+    let terms = proposal.financial_terms.withdrawal_terms.clone();
+
+    let investment = INVESTMENTS
+        .may_load(deps.storage, (proposal_id.clone(), &investor))?
+        .ok_or(ContractError::InvestmentNotFound {})?;
+    let available_at = investment.timestamp.saturating_add(terms.cooldown_seconds);
+    if env.block.time.seconds() < available_at {
+        return Err(ContractError::WithdrawalCooldown { available_at });
+    }
+
+    let amount = cancel_investment(deps.storage, &env, &mut proposal, &investor)?
+        .ok_or(ContractError::InvestmentNotFound {})?;
+    prune_investors(deps.storage, &proposal_id, std::slice::from_ref(&investor))?;
+    let fee = MathGuard::calculate_percentage(amount, terms.fee_bps)?;
+    let refund = MathGuard::safe_sub(amount, fee)?;
+    let mut messages =
+        escrow::payout_messages(deps.storage, &env, &proposal_id, &investor, refund)?;
+    notifications::refund_issued(
+        deps.storage,
+        &proposal_id,
+        &investor,
+        refund,
+        RefundReason::Withdrawn,
+    )?;
+    messages.extend(escrow::reclaim_message(
+        deps.storage,
+        &env,
+        &proposal_id,
+        fee,
+    )?);
+    treasury::debit(deps.storage, treasury::TreasuryAccount::Escrow, amount)?;
+    if !fee.is_zero() {
+        treasury::credit(deps.storage, treasury::TreasuryAccount::Fees, fee)?;
+    }
+
+    proposal.timestamps.updated_at = env.block.time.seconds();
+    save_proposal(deps.storage, &proposal)?;
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attribute("action", "withdraw_investment")
+        .add_attribute("proposal_id", &proposal_id)
+        .add_attribute("investor", investor)
+        .add_attribute("amount", refund.to_string())
+        .add_attribute("withdrawal_fee", fee.to_string()))
+}
+
+/// Undo the creator success recorded when the raise first reached its target
+fn revert_creator_success(
+    storage: &mut dyn Storage,
+    creator: &Addr,
+    raised: Uint128,
+) -> StdResult<()> {
+    if let Some(mut stats) = CREATOR_STATS.may_load(storage, creator)? {
+        stats.total_raised = stats.total_raised.saturating_sub(raised);
+        stats.success_count = stats.success_count.saturating_sub(1);
+        CREATOR_STATS.save(storage, creator, &stats)?;
+    }
+    if let Some(mut profile) = CREATORS.may_load(storage, creator)? {
+        profile.total_raised = profile.total_raised.saturating_sub(raised);
+        profile.successful_proposals = profile.successful_proposals.saturating_sub(1);
+        CREATORS.save(storage, creator, &profile)?;
+    }
+    Ok(())
+}
+
+/// Settle a closed window (callable by anyone).
+///
+/// Investments that were not reconfirmed are cancelled and refunded. If what remains still
+/// meets the target the raise stays funded; otherwise funding reopens until the original
+/// deadline, or the deal fails and every remaining investor is refunded.
+pub fn resolve_reconfirmation(
+    deps: DepsMut,
+    env: Env,
+    proposal_id: String,
+) -> Result<Response, ContractError> {
+    let mut window = open_window_for(deps.storage, &proposal_id)?.ok_or(
+        ContractError::NoReconfirmationWindow {
+            proposal_id: proposal_id.clone(),
+        },
+    )?;
+    if env.block.time.seconds() <= window.closes_at {
+        return Err(ContractError::ReconfirmationPending {
+            proposal_id: proposal_id.clone(),
+        });
+    }
+
+    let mut proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+    let investors = PROPOSAL_INVESTMENTS
+        .may_load(deps.storage, proposal_id.clone())?
+        .unwrap_or_default();
+
+    let mut refunds = vec![];
+    let mut cancelled = vec![];
+    let mut cancelled_amount = Uint128::zero();
+    for investor in &investors {
+        if RECONFIRMATIONS.has(deps.storage, (proposal_id.clone(), window.round, investor)) {
+            continue;
+        }
+        if let Some(amount) = cancel_investment(deps.storage, &env, &mut proposal, investor)? {
+            refunds.extend(escrow::payout_messages(
+                deps.storage,
+                &env,
+                &proposal_id,
+                investor,
+                amount,
+            )?);
+            notifications::refund_issued(
+                deps.storage,
+                &proposal_id,
+                investor,
+                amount,
+                RefundReason::NotReconfirmed,
+            )?;
+            cancelled.push(investor.clone());
+            cancelled_amount = MathGuard::safe_add(cancelled_amount, amount)?;
+        }
+    }
+    window.withdrawn_amount = MathGuard::safe_add(window.withdrawn_amount, cancelled_amount)?;
+
+    let now = env.block.time.seconds();
+    let raised = proposal.funding_status.raised_amount;
+    let outcome = if raised >= proposal.financial_terms.target_amount
+        || (now > proposal.financial_terms.funding_deadline
+            && raised >= proposal.financial_terms.minimum_raise())
+    {
+        ReconfirmationOutcome::Funded
+    } else if now <= proposal.financial_terms.funding_deadline {
+        ReconfirmationOutcome::Reopened
+    } else {
+        ReconfirmationOutcome::Failed
+    };
+
+    if outcome == ReconfirmationOutcome::Failed {
+        crate::compliance::release_issuer_raise(deps.storage, &proposal.creator, &proposal_id)?;
+    } else {
+        // Withdrawals shrank the raise; a reopened one reserves more as investments come back in
+        crate::compliance::record_issuer_raise(
+            deps.storage,
+            &proposal.creator,
+            &proposal_id,
+            now,
+            raised,
+        )?;
+    }
+    if outcome != ReconfirmationOutcome::Funded {
+        proposal.funding_status.is_funded = false;
+        proposal.timestamps.lockup_end = None;
+        revert_creator_success(deps.storage, &proposal.creator, window.raised_at_open)?;
+    }
+    match outcome {
+        ReconfirmationOutcome::Funded => {}
+        ReconfirmationOutcome::Reopened => {
+            crate::validate_proposal_state_transition(
+                proposal.status,
+                ProposalStatus::Active,
+                &proposal,
+                &env,
+            )?;
+            proposal.status = ProposalStatus::Active;
+        }
+        ReconfirmationOutcome::Failed => {
+            crate::validate_proposal_state_transition(
+                proposal.status,
+                ProposalStatus::Failed,
+                &proposal,
+                &env,
+            )?;
+            proposal.status = ProposalStatus::Failed;
+            let bond_refund = crate::bonds::settle_failed_raise(deps.storage, &env, &proposal)?;
+            if let Some(refund) = bond_refund {
+                refunds.push(refund.into());
+            }
+
+            // Reconfirmed investors are refunded too since the deal is off
+            for investor in &investors {
+                if let Some(amount) =
+                    cancel_investment(deps.storage, &env, &mut proposal, investor)?
+                {
+                    refunds.extend(escrow::payout_messages(
+                        deps.storage,
+                        &env,
+                        &proposal_id,
+                        investor,
+                        amount,
+                    )?);
+                    notifications::refund_issued(
+                        deps.storage,
+                        &proposal_id,
+                        investor,
+                        amount,
+                        RefundReason::ProposalFailed,
+                    )?;
+                    cancelled.push(investor.clone());
+                    cancelled_amount = MathGuard::safe_add(cancelled_amount, amount)?;
+                }
+            }
+        }
+    }
+
+    prune_investors(deps.storage, &proposal_id, &cancelled)?;
+    treasury::debit(
+        deps.storage,
+        treasury::TreasuryAccount::Escrow,
+        cancelled_amount,
+    )?;
+
+    window.outcome = Some(outcome);
+    RECONFIRMATION_WINDOWS.save(deps.storage, proposal_id.clone(), &window)?;
+    proposal.timestamps.updated_at = now;
+    save_proposal(deps.storage, &proposal)?;
+
+    let outcome_label = match outcome {
+        ReconfirmationOutcome::Funded => "funded",
+        ReconfirmationOutcome::Reopened => "reopened",
+        ReconfirmationOutcome::Failed => "failed",
+    };
+    Ok(Response::new()
+        .add_messages(refunds)
+        .add_attribute("action", "resolve_reconfirmation")
+        .add_attribute("proposal_id", &proposal_id)
+        .add_attribute("outcome", outcome_label)
+        .add_attribute("investors_refunded", cancelled.len().to_string())
+        .add_attribute("amount_refunded", cancelled_amount.to_string()))
+}
+
+// Query functions
+pub fn query_reconfirmation_status(
+    deps: Deps,
+    proposal_id: String,
+) -> StdResult<ReconfirmationStatusResponse> {
+    let window = RECONFIRMATION_WINDOWS.may_load(deps.storage, proposal_id.clone())?;
+
+    let mut reconfirmed_amount = Uint128::zero();
+    let mut unconfirmed_amount = Uint128::zero();
+    if let Some(window) = window.as_ref().filter(|window| window.outcome.is_none()) {
+        let investors = PROPOSAL_INVESTMENTS
+            .may_load(deps.storage, proposal_id.clone())?
+            .unwrap_or_default();
+        for investor in &investors {
+            let Some(investment) =
+                INVESTMENTS.may_load(deps.storage, (proposal_id.clone(), investor))?
+            else {
+                continue;
+            };
+            if RECONFIRMATIONS.has(deps.storage, (proposal_id.clone(), window.round, investor)) {
+                reconfirmed_amount = reconfirmed_amount.checked_add(investment.amount)?;
+            } else {
+                unconfirmed_amount = unconfirmed_amount.checked_add(investment.amount)?;
+            }
+        }
+    }
+
+    Ok(ReconfirmationStatusResponse {
+        proposal_id,
+        window,
+        reconfirmed_amount,
+        unconfirmed_amount,
+    })
+}
@@ -0,0 +1,167 @@
+use cosmwasm_std::{
+    Addr, BankMsg, Coin, Deps, DepsMut, MessageInfo, Response, StdResult, Storage, Uint128,
+};
+use cw_storage_plus::{Item, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+use crate::roles::{self, Role};
+use crate::security::MathGuard;
+use crate::treasury;
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct ReferralConfig {
+    pub fee_bps: u16, // Share of the platform fee on referred investments paid to the referrer
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct ReferrerRewards {
+    pub referred_investments: u64,
+    pub referred_amount: Uint128, // Referred capital in raises that completed
+    pub accrued: Uint128,
+    pub claimed: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ReferrerRewardsResponse {
+    pub referrer: Addr,
+    pub rewards: ReferrerRewards,
+    pub claimable: Uint128,
+}
+
+// Storage keys
+pub const REFERRAL_CONFIG: Item<ReferralConfig> = Item::new("referral_config");
+pub const REFERRAL_ATTRIBUTIONS: Map<(&str, &Addr), Addr> = Map::new("referral_attributions"); // (proposal_id, investor) -> referrer
+pub const REFERRER_REWARDS: Map<&Addr, ReferrerRewards> = Map::new("referrer_rewards");
+
+/// Attribute an investor's position in a proposal to a referrer; the first referrer sticks
+pub fn record_referral(
+    deps: DepsMut,
+    proposal_id: &str,
+    investor: &Addr,
+    referrer: Option<String>,
+) -> Result<Option<Addr>, ContractError> {
+    if let Some(existing) = REFERRAL_ATTRIBUTIONS.may_load(deps.storage, (proposal_id, investor))? {
+        return Ok(Some(existing));
+    }
+    let Some(referrer) = referrer else {
+        return Ok(None);
+    };
+
+    let referrer = deps.api.addr_validate(&referrer)?;
+    if referrer == *investor {
+        return Err(ContractError::InvalidInput {
+            field: "referrer".to_string(),
+            message: "Investors cannot refer themselves".to_string(),
+        });
+    }
+    REFERRAL_ATTRIBUTIONS.save(deps.storage, (proposal_id, investor), &referrer)?;
+    Ok(Some(referrer))
+}
+
+/// Accrue referrer rewards for a completed raise, out of its platform fee.
+///
+/// Rewards are computed on each holder's final investment, so positions refunded or withdrawn
+/// before distribution earn nothing. Returns the total carved out of the fee.
+pub fn accrue_rewards(
+    storage: &mut dyn Storage,
+    proposal_id: &str,
+    investments: &[(Addr, Uint128)],
+    platform_fee_bps: u16,
+) -> Result<Uint128, ContractError> {
+    let config = REFERRAL_CONFIG.may_load(storage)?.unwrap_or_default();
+    if config.fee_bps == 0 {
+        return Ok(Uint128::zero());
+    }
+
+    let mut total = Uint128::zero();
+    for (investor, amount) in investments {
+        let Some(referrer) = REFERRAL_ATTRIBUTIONS.may_load(storage, (proposal_id, investor))?
+        else {
+            continue;
+        };
+        let fee = MathGuard::calculate_percentage(*amount, platform_fee_bps)?;
+        let reward = MathGuard::calculate_percentage(fee, config.fee_bps)?;
+
+        let mut rewards = REFERRER_REWARDS
+            .may_load(storage, &referrer)?
+            .unwrap_or_default();
+        rewards.referred_investments += 1;
+        rewards.referred_amount = MathGuard::safe_add(rewards.referred_amount, *amount)?;
+        rewards.accrued = MathGuard::safe_add(rewards.accrued, reward)?;
+        REFERRER_REWARDS.save(storage, &referrer, &rewards)?;
+        total = MathGuard::safe_add(total, reward)?;
+    }
+    Ok(total)
+}
+
+/// Pay out everything the caller has accrued as a referrer
+pub fn claim_referral_rewards(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let mut rewards = REFERRER_REWARDS
+        .may_load(deps.storage, &info.sender)?
+        .unwrap_or_default();
+    let claimable = MathGuard::safe_sub(rewards.accrued, rewards.claimed)?;
+    if claimable.is_zero() {
+        return Err(ContractError::NoReferralRewards {});
+    }
+
+    rewards.claimed = rewards.accrued;
+    REFERRER_REWARDS.save(deps.storage, &info.sender, &rewards)?;
+    treasury::debit(
+        deps.storage,
+        treasury::TreasuryAccount::Referrals,
+        claimable,
+    )?;
+
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![Coin {
+                denom: "untrn".to_string(),
+                amount: claimable,
+            }],
+        })
+        .add_attribute("action", "claim_referral_rewards")
+        .add_attribute("referrer", info.sender)
+        .add_attribute("amount", claimable.to_string()))
+}
+
+/// Set the referrer share of the platform fee (fee manager only)
+pub fn update_referral_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    fee_bps: u16,
+) -> Result<Response, ContractError> {
+    roles::ensure_role(deps.storage, &info.sender, Role::FeeManager)?;
+    if fee_bps > 10_000 {
+        return Err(ContractError::InvalidInput {
+            field: "fee_bps".to_string(),
+            message: "Referral fee cannot exceed the whole platform fee".to_string(),
+        });
+    }
+
+    REFERRAL_CONFIG.save(deps.storage, &ReferralConfig { fee_bps })?;
+
+    Ok(Response::new()
+        .add_attribute("method", "update_referral_config")
+        .add_attribute("fee_bps", fee_bps.to_string()))
+}
+
+// Query functions
+pub fn query_referral_config(deps: Deps) -> StdResult<ReferralConfig> {
+    Ok(REFERRAL_CONFIG.may_load(deps.storage)?.unwrap_or_default())
+}
+
+pub fn query_referrer_rewards(deps: Deps, referrer: String) -> StdResult<ReferrerRewardsResponse> {
+    let referrer = deps.api.addr_validate(&referrer)?;
+    let rewards = REFERRER_REWARDS
+        .may_load(deps.storage, &referrer)?
+        .unwrap_or_default();
+
+    Ok(ReferrerRewardsResponse {
+        referrer,
+        claimable: rewards.accrued - rewards.claimed,
+        rewards,
+    })
+}
@@ -0,0 +1,258 @@
+use cosmwasm_std::{
+    to_json_binary, Addr, BankMsg, Coin, Deps, DepsMut, Env, MessageInfo, Response, StdResult,
+    Storage, Uint128, WasmMsg,
+};
+use cw20::Cw20ExecuteMsg;
+use cw_storage_plus::Map;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+use crate::roles::{self, Role};
+use crate::state::{
+    save_proposal, InvestmentStatus, ProposalStatus, INVESTMENTS, PROPOSALS, PROPOSAL_INVESTMENTS,
+    TOKEN_CONTRACTS,
+};
+use crate::{ledger, treasury};
+
+/// Final state of an asset that has been wound down
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AssetRetirement {
+    pub proposal_id: String,
+    pub governance_id: u64, // Holder vote that approved the wind-down
+    pub retired_by: Addr,
+    pub retired_at: u64,
+    pub final_proceeds: Uint128, // Paid in at retirement, shared pro rata by outstanding shares
+    pub outstanding_shares: u64, // Holder shares when the asset was retired
+    pub redeemed_shares: u64,
+    pub paid_out: Uint128,
+    pub holders_redeemed: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RetirementReportResponse {
+    pub retirement: AssetRetirement,
+    pub total_raised: Uint128,
+    pub unredeemed_shares: u64,
+    pub unclaimed_proceeds: Uint128,
+    pub fully_redeemed: bool,
+}
+
+// Storage keys
+pub const RETIREMENT_APPROVALS: Map<String, u64> = Map::new("retirement_approvals"); // proposal_id -> governance_id
+pub const ASSET_RETIREMENTS: Map<String, AssetRetirement> = Map::new("asset_retirements");
+
+/// Record a passed `RetireAsset` governance vote
+pub fn record_approval(
+    storage: &mut dyn Storage,
+    proposal_id: &str,
+    governance_id: u64,
+) -> StdResult<()> {
+    RETIREMENT_APPROVALS.save(storage, proposal_id.to_string(), &governance_id)
+}
+
+/// Refuse cap table changes once an asset is retired
+pub fn ensure_not_retired(status: ProposalStatus, proposal_id: &str) -> Result<(), ContractError> {
+    if status == ProposalStatus::Retired {
+        return Err(ContractError::AssetRetired {
+            proposal_id: proposal_id.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Wind down a completed asset after holders approved it (creator or operator).
+///
+/// Any `untrn` attached is the final proceeds, held in escrow and paid out as holders redeem.
+/// Transfers stop from this point, so the outstanding share count is fixed.
+pub fn retire_asset(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: String,
+) -> Result<Response, ContractError> {
+    let mut proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+    if info.sender != proposal.creator
+        && !roles::has_role(deps.storage, &info.sender, Role::Operator)?
+    {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let governance_id = RETIREMENT_APPROVALS
+        .may_load(deps.storage, proposal_id.clone())?
+        .ok_or(ContractError::RetirementNotApproved {
+            proposal_id: proposal_id.clone(),
+        })?;
+
+    crate::validate_proposal_state_transition(
+        proposal.status,
+        ProposalStatus::Retired,
+        &proposal,
+        &env,
+    )?;
+
+    let final_proceeds = info
+        .funds
+        .iter()
+        .find(|coin| coin.denom == "untrn")
+        .map(|coin| coin.amount)
+        .unwrap_or_default();
+
+    let mut outstanding_shares = 0u64;
+    for holder in PROPOSAL_INVESTMENTS
+        .may_load(deps.storage, proposal_id.clone())?
+        .unwrap_or_default()
+    {
+        if let Some(investment) =
+            INVESTMENTS.may_load(deps.storage, (proposal_id.clone(), &holder))?
+        {
+            if investment.status == InvestmentStatus::Completed {
+                outstanding_shares = outstanding_shares.saturating_add(investment.shares);
+            }
+        }
+    }
+
+    let now = env.block.time.seconds();
+    proposal.status = ProposalStatus::Retired;
+    proposal.timestamps.updated_at = now;
+    save_proposal(deps.storage, &proposal)?;
+
+    treasury::credit(
+        deps.storage,
+        treasury::TreasuryAccount::Escrow,
+        final_proceeds,
+    )?;
+    ledger::record_entry(
+        deps.storage,
+        &env,
+        &proposal_id,
+        ledger::LedgerEntryKind::RetirementProceeds,
+        &info.sender,
+        final_proceeds,
+    )?;
+
+    ASSET_RETIREMENTS.save(
+        deps.storage,
+        proposal_id.clone(),
+        &AssetRetirement {
+            proposal_id: proposal_id.clone(),
+            governance_id,
+            retired_by: info.sender.clone(),
+            retired_at: now,
+            final_proceeds,
+            outstanding_shares,
+            redeemed_shares: 0,
+            paid_out: Uint128::zero(),
+            holders_redeemed: 0,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "retire_asset")
+        .add_attribute("proposal_id", &proposal_id)
+        .add_attribute("governance_id", governance_id.to_string())
+        .add_attribute("final_proceeds", final_proceeds.to_string())
+        .add_attribute("outstanding_shares", outstanding_shares.to_string()))
+}
+
+/// Burn the caller's tokens for their share of the final proceeds.
+///
+/// Tokens are burned with `BurnFrom`, so the holder must have granted the launchpad an
+/// allowance for their full position. The last redemption receives any rounding remainder.
+pub fn redeem_shares(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: String,
+) -> Result<Response, ContractError> {
+    let proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+    if proposal.status != ProposalStatus::Retired {
+        return Err(ContractError::InvalidInput {
+            field: "proposal_id".to_string(),
+            message: "Shares can only be redeemed after the asset is retired".to_string(),
+        });
+    }
+    crate::governance::ensure_snapshot_settled(deps.storage, &proposal_id)?;
+
+    let mut investment = INVESTMENTS
+        .may_load(deps.storage, (proposal_id.clone(), &info.sender))?
+        .filter(|investment| investment.status == InvestmentStatus::Completed)
+        .ok_or(ContractError::InvestmentNotFound {})?;
+    let shares = investment.shares;
+
+    let mut retirement = ASSET_RETIREMENTS.load(deps.storage, proposal_id.clone())?;
+    retirement.redeemed_shares = retirement.redeemed_shares.saturating_add(shares);
+    let payout = if retirement.redeemed_shares >= retirement.outstanding_shares {
+        retirement.final_proceeds - retirement.paid_out
+    } else {
+        retirement
+            .final_proceeds
+            .multiply_ratio(shares, retirement.outstanding_shares)
+    };
+    retirement.paid_out += payout;
+    retirement.holders_redeemed += 1;
+    ASSET_RETIREMENTS.save(deps.storage, proposal_id.clone(), &retirement)?;
+
+    investment.status = InvestmentStatus::Redeemed;
+    INVESTMENTS.save(
+        deps.storage,
+        (proposal_id.clone(), &info.sender),
+        &investment,
+    )?;
+
+    treasury::debit(deps.storage, treasury::TreasuryAccount::Escrow, payout)?;
+    ledger::record_entry(
+        deps.storage,
+        &env,
+        &proposal_id,
+        ledger::LedgerEntryKind::Redemption,
+        &info.sender,
+        payout,
+    )?;
+
+    let token_address = TOKEN_CONTRACTS.load(deps.storage, proposal_id.clone())?;
+    let mut response = Response::new().add_message(WasmMsg::Execute {
+        contract_addr: token_address.to_string(),
+        msg: to_json_binary(&Cw20ExecuteMsg::BurnFrom {
+            owner: info.sender.to_string(),
+            amount: Uint128::from(shares),
+        })?,
+        funds: vec![],
+    });
+    if !payout.is_zero() {
+        response = response.add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![Coin {
+                denom: "untrn".to_string(),
+                amount: payout,
+            }],
+        });
+    }
+
+    Ok(response
+        .add_attribute("action", "redeem_shares")
+        .add_attribute("proposal_id", &proposal_id)
+        .add_attribute("holder", info.sender)
+        .add_attribute("shares_burned", shares.to_string())
+        .add_attribute("payout", payout.to_string()))
+}
+
+// Query functions
+pub fn query_retirement_report(
+    deps: Deps,
+    proposal_id: String,
+) -> StdResult<RetirementReportResponse> {
+    let retirement = ASSET_RETIREMENTS.load(deps.storage, proposal_id.clone())?;
+    let proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+
+    let unredeemed_shares = retirement
+        .outstanding_shares
+        .saturating_sub(retirement.redeemed_shares);
+    Ok(RetirementReportResponse {
+        total_raised: proposal.funding_status.raised_amount,
+        unclaimed_proceeds: retirement.final_proceeds - retirement.paid_out,
+        fully_redeemed: unredeemed_shares == 0,
+        unredeemed_shares,
+        retirement,
+    })
+}
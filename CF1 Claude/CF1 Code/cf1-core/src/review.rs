@@ -0,0 +1,384 @@
+//! Optional listing review.
+//!
+//! With review required, new proposals start as drafts. The creator submits a draft for review
+//! and a compliance officer either approves it, opening the raise, or rejects it with a reason,
+//! sending it back to draft. The creator can then resubmit it with edits a limited number of
+//! times. Every submission and its outcome is kept as a revision. Without review, proposals go
+//! live as soon as they are created.
+
+use cosmwasm_std::{Addr, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Storage};
+use cw_storage_plus::Map;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+use crate::roles::{self, Role};
+use crate::security::InputValidator;
+use crate::state::{
+    save_proposal, AssetDetails, Config, Document, ProposalStatus, CONFIG, PROPOSALS,
+};
+
+pub const MAX_REASON_LENGTH: usize = 500;
+/// Times a rejected proposal can go back for review before it has to be withdrawn
+pub const MAX_RESUBMISSIONS: u32 = 3;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ReviewDecision {
+    pub approved: bool,
+    pub reviewer: Addr,
+    pub reason: Option<String>, // Why a proposal was sent back
+    pub decided_at: u64,
+}
+
+/// One submission for review and what came of it
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ProposalRevision {
+    pub revision: u32, // 0 for the first submission
+    pub submitted_at: u64,
+    pub changed: Vec<String>, // Parts edited with the resubmission: "asset_details", "documents"
+    pub decision: Option<ReviewDecision>, // None while under review
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ProposalReviewResponse {
+    pub proposal_id: String,
+    pub status: ProposalStatus,
+    pub last_decision: Option<ReviewDecision>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ProposalHistoryResponse {
+    pub proposal_id: String,
+    pub revisions: Vec<ProposalRevision>,
+    pub resubmissions_left: u32,
+}
+
+// Storage keys
+pub const PROPOSAL_REVISIONS: Map<String, Vec<ProposalRevision>> = Map::new("proposal_revisions"); // Oldest first
+
+/// Status a new proposal starts in
+pub fn initial_status(config: &Config) -> ProposalStatus {
+    if config.require_proposal_review {
+        ProposalStatus::Draft
+    } else {
+        ProposalStatus::Active
+    }
+}
+
+/// Turn listing review on or off (owner only). Proposals already in review keep going through it.
+pub fn set_review_required(
+    deps: DepsMut,
+    info: MessageInfo,
+    required: bool,
+) -> Result<Response, ContractError> {
+    roles::ensure_role(deps.storage, &info.sender, Role::Owner)?;
+
+    let mut config = CONFIG.load(deps.storage)?;
+    config.require_proposal_review = required;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "set_review_required")
+        .add_attribute("required", required.to_string()))
+}
+
+/// Hand a draft to the reviewers for the first time (creator only)
+pub fn submit_proposal(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: String,
+) -> Result<Response, ContractError> {
+    let mut proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+    if proposal.creator != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    if PROPOSAL_REVISIONS.has(deps.storage, proposal_id.clone()) {
+        return Err(ContractError::InvalidInput {
+            field: "proposal_id".to_string(),
+            message: "Proposal was already reviewed; use ResubmitProposal".to_string(),
+        });
+    }
+    crate::validate_proposal_state_transition(
+        proposal.status,
+        ProposalStatus::UnderReview,
+        &proposal,
+        &env,
+    )?;
+
+    let now = env.block.time.seconds();
+    proposal.status = ProposalStatus::UnderReview;
+    proposal.timestamps.updated_at = now;
+    save_proposal(deps.storage, &proposal)?;
+    PROPOSAL_REVISIONS.save(
+        deps.storage,
+        proposal_id.clone(),
+        &vec![ProposalRevision {
+            revision: 0,
+            submitted_at: now,
+            changed: vec![],
+            decision: None,
+        }],
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "submit_proposal")
+        .add_attribute("proposal_id", proposal_id))
+}
+
+/// Send a rejected proposal back for review with the requested edits (creator only)
+pub fn resubmit_proposal(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: String,
+    asset_details: Option<AssetDetails>,
+    documents: Option<Vec<Document>>,
+) -> Result<Response, ContractError> {
+    let mut proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+    if proposal.creator != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    let mut revisions = PROPOSAL_REVISIONS
+        .may_load(deps.storage, proposal_id.clone())?
+        .unwrap_or_default();
+    let rejected = revisions
+        .last()
+        .and_then(|revision| revision.decision.as_ref())
+        .is_some_and(|decision| !decision.approved);
+    if !rejected {
+        return Err(ContractError::InvalidInput {
+            field: "proposal_id".to_string(),
+            message: "Only a rejected proposal can be resubmitted".to_string(),
+        });
+    }
+    let revision = revisions.len() as u32;
+    if revision > MAX_RESUBMISSIONS {
+        return Err(ContractError::InvalidInput {
+            field: "proposal_id".to_string(),
+            message: format!("Proposal has used all {} resubmissions", MAX_RESUBMISSIONS),
+        });
+    }
+    crate::validate_proposal_state_transition(
+        proposal.status,
+        ProposalStatus::UnderReview,
+        &proposal,
+        &env,
+    )?;
+
+    let mut changed = vec![];
+    if let Some(asset_details) = asset_details {
+        crate::token_metadata::validate(&asset_details.token_metadata)?;
+        proposal.asset_details = asset_details;
+        changed.push("asset_details".to_string());
+    }
+    if let Some(documents) = documents {
+        let published = crate::documents::publish(
+            deps.storage,
+            &env,
+            &info.sender,
+            &proposal_id,
+            &mut proposal.documents,
+            documents,
+        )?;
+        if !published.is_empty() {
+            changed.push("documents".to_string());
+        }
+    }
+
+    let now = env.block.time.seconds();
+    proposal.status = ProposalStatus::UnderReview;
+    proposal.timestamps.updated_at = now;
+    save_proposal(deps.storage, &proposal)?;
+    revisions.push(ProposalRevision {
+        revision,
+        submitted_at: now,
+        changed: changed.clone(),
+        decision: None,
+    });
+    PROPOSAL_REVISIONS.save(deps.storage, proposal_id.clone(), &revisions)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "resubmit_proposal")
+        .add_attribute("proposal_id", proposal_id)
+        .add_attribute("revision", revision.to_string())
+        .add_attribute("changed", changed.join(",")))
+}
+
+/// Record the reviewer's decision on the submission under review
+fn record_decision(
+    storage: &mut dyn Storage,
+    proposal_id: &str,
+    decision: ReviewDecision,
+) -> StdResult<()> {
+    let mut revisions = PROPOSAL_REVISIONS
+        .may_load(storage, proposal_id.to_string())?
+        .unwrap_or_default();
+    if let Some(revision) = revisions.last_mut() {
+        revision.decision = Some(decision);
+    }
+    PROPOSAL_REVISIONS.save(storage, proposal_id.to_string(), &revisions)
+}
+
+/// Open the raise for investment (compliance officer only)
+pub fn approve_proposal(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: String,
+) -> Result<Response, ContractError> {
+    roles::ensure_role(deps.storage, &info.sender, Role::ComplianceOfficer)?;
+    let mut proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+    crate::validate_proposal_state_transition(
+        proposal.status,
+        ProposalStatus::Active,
+        &proposal,
+        &env,
+    )?;
+
+    // Time spent in review comes out of the funding window, which must still be long enough
+    let config = CONFIG.load(deps.storage)?;
+    let now = env.block.time.seconds();
+    let remaining_days = proposal
+        .financial_terms
+        .funding_deadline
+        .saturating_sub(now)
+        / (24 * 60 * 60);
+    if remaining_days < config.min_funding_period_days {
+        return Err(ContractError::FundingPeriodTooShort {});
+    }
+
+    proposal.status = ProposalStatus::Active;
+    proposal.timestamps.updated_at = now;
+    save_proposal(deps.storage, &proposal)?;
+    record_decision(
+        deps.storage,
+        &proposal_id,
+        ReviewDecision {
+            approved: true,
+            reviewer: info.sender.clone(),
+            reason: None,
+            decided_at: now,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "approve_proposal")
+        .add_attribute("proposal_id", proposal_id)
+        .add_attribute("reviewer", info.sender))
+}
+
+/// Send a proposal back to draft with the changes it needs (compliance officer only). The reason
+/// stays in the proposal's history.
+pub fn reject_proposal(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: String,
+    reason: String,
+) -> Result<Response, ContractError> {
+    roles::ensure_role(deps.storage, &info.sender, Role::ComplianceOfficer)?;
+    InputValidator::validate_length("reason", &reason, 1, MAX_REASON_LENGTH)?;
+    let mut proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+    crate::validate_proposal_state_transition(
+        proposal.status,
+        ProposalStatus::Draft,
+        &proposal,
+        &env,
+    )?;
+
+    let now = env.block.time.seconds();
+    proposal.status = ProposalStatus::Draft;
+    proposal.timestamps.updated_at = now;
+    save_proposal(deps.storage, &proposal)?;
+    record_decision(
+        deps.storage,
+        &proposal_id,
+        ReviewDecision {
+            approved: false,
+            reviewer: info.sender.clone(),
+            reason: Some(reason.clone()),
+            decided_at: now,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "reject_proposal")
+        .add_attribute("proposal_id", proposal_id)
+        .add_attribute("reviewer", info.sender)
+        .add_attribute("reason", reason))
+}
+
+/// Close a proposal found to be fraudulent (compliance officer only). Everyone who invested is
+/// refunded and the creator's bond is slashed to the treasury.
+pub fn reject_proposal_for_fraud(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: String,
+    reason: String,
+) -> Result<Response, ContractError> {
+    roles::ensure_role(deps.storage, &info.sender, Role::ComplianceOfficer)?;
+    InputValidator::validate_length("reason", &reason, 1, MAX_REASON_LENGTH)?;
+    let mut proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+    let under_review = proposal.status == ProposalStatus::UnderReview;
+
+    let (refund_messages, total_refunded, refunded_count) =
+        crate::cancel_proposal(deps.storage, &env, &mut proposal)?;
+    if under_review {
+        record_decision(
+            deps.storage,
+            &proposal_id,
+            ReviewDecision {
+                approved: false,
+                reviewer: info.sender.clone(),
+                reason: Some(reason.clone()),
+                decided_at: env.block.time.seconds(),
+            },
+        )?;
+    }
+    let slashed = crate::bonds::slash_bond(deps.storage, &env, &proposal_id, "fraud")?;
+
+    Ok(Response::new()
+        .add_messages(refund_messages)
+        .add_attribute("method", "reject_proposal_for_fraud")
+        .add_attribute("proposal_id", proposal_id)
+        .add_attribute("reviewer", info.sender)
+        .add_attribute("reason", reason)
+        .add_attribute("total_refunded", total_refunded.to_string())
+        .add_attribute("investors_refunded", refunded_count.to_string())
+        .add_attribute("bond_slashed", slashed.to_string()))
+}
+
+// Query functions
+pub fn query_proposal_review(deps: Deps, proposal_id: String) -> StdResult<ProposalReviewResponse> {
+    let proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+    let last_decision = PROPOSAL_REVISIONS
+        .may_load(deps.storage, proposal_id.clone())?
+        .unwrap_or_default()
+        .into_iter()
+        .rev()
+        .find_map(|revision| revision.decision);
+    Ok(ProposalReviewResponse {
+        proposal_id,
+        status: proposal.status,
+        last_decision,
+    })
+}
+
+pub fn query_proposal_history(
+    deps: Deps,
+    proposal_id: String,
+) -> StdResult<ProposalHistoryResponse> {
+    PROPOSALS.load(deps.storage, proposal_id.clone())?;
+    let revisions = PROPOSAL_REVISIONS
+        .may_load(deps.storage, proposal_id.clone())?
+        .unwrap_or_default();
+    // The first submission is not a resubmission
+    let used = (revisions.len() as u32).saturating_sub(1);
+    Ok(ProposalHistoryResponse {
+        proposal_id,
+        revisions,
+        resubmissions_left: MAX_RESUBMISSIONS.saturating_sub(used),
+    })
+}
@@ -0,0 +1,223 @@
+use cosmwasm_std::{
+    to_json_binary, Addr, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult,
+    Uint128, WasmMsg,
+};
+use cw20::Cw20ExecuteMsg;
+use cw_storage_plus::{Bound, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+use crate::gas_optimization::{DEFAULT_LIMIT, MAX_LIMIT};
+use crate::security::MathGuard;
+use crate::state::{
+    add_user_investment, remove_user_investment, save_proposal, InvestmentStatus, INVESTMENTS,
+    PROPOSALS, PROPOSAL_INVESTMENTS, TOKEN_CONTRACTS,
+};
+
+/// Audit record of a compliance-driven revocation and reissue
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ShareRevocation {
+    pub sequence: u64,
+    pub proposal_id: String,
+    pub revoked_from: Addr,
+    pub reissued_to: Addr,
+    pub shares: u64,
+    pub amount: Uint128,
+    pub tokens_reissued: bool, // true if on-chain tokens were burned and re-minted
+    pub reason: String,
+    pub authorized_by: Addr,
+    pub timestamp: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ShareRevocationsResponse {
+    pub revocations: Vec<ShareRevocation>,
+}
+
+// Storage keys
+pub const SHARE_REVOCATIONS: Map<(String, u64), ShareRevocation> = Map::new("share_revocations"); // (proposal_id, seq) -> record
+pub const SHARE_REVOCATION_COUNT: Map<String, u64> = Map::new("share_revocation_count");
+
+/// Move a holder's entire position to a corrected address (admin or compliance officer).
+///
+/// The cap table entry is transferred, so governance power and any entitlement derived from
+/// `INVESTMENTS` follow it in the same transaction. If tokens were already distributed, the
+/// holder's balance is burned with `BurnFrom` and re-minted to the new address; the holder must
+/// have granted the launchpad an allowance, otherwise the whole operation reverts.
+pub fn revoke_and_reissue_shares(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: String,
+    holder: String,
+    reissue_to: String,
+    reason: String,
+) -> Result<Response, ContractError> {
+    crate::kyc::ensure_registry_manager(deps.storage, &info.sender)?;
+
+    if reason.trim().is_empty() {
+        return Err(ContractError::InvalidInput {
+            field: "reason".to_string(),
+            message: "A revocation reason is required".to_string(),
+        });
+    }
+
+    let holder_addr = deps.api.addr_validate(&holder)?;
+    let new_addr = deps.api.addr_validate(&reissue_to)?;
+    if holder_addr == new_addr {
+        return Err(ContractError::InvalidInput {
+            field: "reissue_to".to_string(),
+            message: "Reissue address must differ from the current holder".to_string(),
+        });
+    }
+
+    let mut proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+    crate::governance::ensure_snapshot_settled(deps.storage, &proposal_id)?;
+    let revoked = INVESTMENTS
+        .may_load(deps.storage, (proposal_id.clone(), &holder_addr))?
+        .ok_or(ContractError::InvestmentNotFound {})?;
+    if matches!(
+        revoked.status,
+        InvestmentStatus::Refunded | InvestmentStatus::Redeemed
+    ) {
+        return Err(ContractError::InvalidInput {
+            field: "holder".to_string(),
+            message: "Refunded or redeemed investments cannot be reissued".to_string(),
+        });
+    }
+
+    // The corrected address must satisfy the deal's investor requirements
+    crate::kyc::enforce_verification(deps.storage, &env, &proposal, &new_addr)?;
+
+    // Merge into an existing position of the new holder, if any
+    let existing = INVESTMENTS.may_load(deps.storage, (proposal_id.clone(), &new_addr))?;
+    let reissued = match existing {
+        Some(mut existing) => {
+            if existing.status != revoked.status {
+                return Err(ContractError::InvalidInput {
+                    field: "reissue_to".to_string(),
+                    message: "Existing position of the new holder is in a different state"
+                        .to_string(),
+                });
+            }
+            existing.shares = existing.shares.saturating_add(revoked.shares);
+            existing.amount = MathGuard::safe_add(existing.amount, revoked.amount)?;
+            proposal.funding_status.investor_count =
+                proposal.funding_status.investor_count.saturating_sub(1);
+            existing
+        }
+        None => {
+            let mut moved = revoked.clone();
+            moved.investor = new_addr.clone();
+            moved
+        }
+    };
+
+    INVESTMENTS.remove(deps.storage, (proposal_id.clone(), &holder_addr));
+    INVESTMENTS.save(deps.storage, (proposal_id.clone(), &new_addr), &reissued)?;
+    crate::tax_lots::transfer(
+        deps.storage,
+        &proposal_id,
+        &holder_addr,
+        &new_addr,
+        revoked.shares,
+    )?;
+
+    PROPOSAL_INVESTMENTS.update(
+        deps.storage,
+        proposal_id.clone(),
+        |investors| -> StdResult<_> {
+            let mut investors = investors.unwrap_or_default();
+            investors.retain(|addr| addr != holder_addr);
+            if !investors.contains(&new_addr) {
+                investors.push(new_addr.clone());
+            }
+            Ok(investors)
+        },
+    )?;
+    remove_user_investment(deps.storage, &holder_addr, &proposal_id)?;
+    add_user_investment(deps.storage, &new_addr, &proposal_id)?;
+
+    proposal.timestamps.updated_at = env.block.time.seconds();
+    save_proposal(deps.storage, &proposal)?;
+    crate::concentration::refresh(deps.storage, &env, &proposal_id)?;
+
+    // Distributed tokens are burned from the old holder and re-minted to the new one
+    let mut messages: Vec<CosmosMsg> = Vec::new();
+    let tokens_reissued = revoked.status == InvestmentStatus::Completed;
+    if tokens_reissued {
+        let token_address = TOKEN_CONTRACTS.load(deps.storage, proposal_id.clone())?;
+        let shares = Uint128::from(revoked.shares);
+        for msg in [
+            Cw20ExecuteMsg::BurnFrom {
+                owner: holder_addr.to_string(),
+                amount: shares,
+            },
+            Cw20ExecuteMsg::Mint {
+                recipient: new_addr.to_string(),
+                amount: shares,
+            },
+        ] {
+            messages.push(
+                WasmMsg::Execute {
+                    contract_addr: token_address.to_string(),
+                    msg: to_json_binary(&msg)?,
+                    funds: vec![],
+                }
+                .into(),
+            );
+        }
+    }
+
+    let sequence = SHARE_REVOCATION_COUNT
+        .may_load(deps.storage, proposal_id.clone())?
+        .unwrap_or(0);
+    SHARE_REVOCATION_COUNT.save(deps.storage, proposal_id.clone(), &(sequence + 1))?;
+    SHARE_REVOCATIONS.save(
+        deps.storage,
+        (proposal_id.clone(), sequence),
+        &ShareRevocation {
+            sequence,
+            proposal_id: proposal_id.clone(),
+            revoked_from: holder_addr.clone(),
+            reissued_to: new_addr.clone(),
+            shares: revoked.shares,
+            amount: revoked.amount,
+            tokens_reissued,
+            reason: reason.clone(),
+            authorized_by: info.sender.clone(),
+            timestamp: env.block.time.seconds(),
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attribute("action", "revoke_and_reissue_shares")
+        .add_attribute("proposal_id", &proposal_id)
+        .add_attribute("revoked_from", holder_addr)
+        .add_attribute("reissued_to", new_addr)
+        .add_attribute("shares", revoked.shares.to_string())
+        .add_attribute("reason", reason)
+        .add_attribute("authorized_by", info.sender))
+}
+
+// Query functions
+pub fn query_share_revocations(
+    deps: Deps,
+    proposal_id: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<ShareRevocationsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let revocations = SHARE_REVOCATIONS
+        .prefix(proposal_id)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, record)| record))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(ShareRevocationsResponse { revocations })
+}
@@ -0,0 +1,140 @@
+use cosmwasm_std::{Addr, Deps, DepsMut, MessageInfo, Order, Response, StdResult, Storage};
+use cw_storage_plus::{Bound, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+use crate::gas_optimization::{DEFAULT_LIMIT, MAX_LIMIT};
+use crate::state::CONFIG;
+
+/// Administrative capabilities that can be spread across several addresses
+#[derive(
+    Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    Owner,             // Grants and revokes roles, changes core config; holds every other role
+    Operator,          // Day-to-day platform settings and proposal interventions
+    ComplianceOfficer, // KYC registry and co-signing of large investments
+    FeeManager,        // Fee schedules and treasury withdrawals
+    Guardian,          // Emergency pause and unpause
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RoleAssignment {
+    pub address: Addr,
+    pub roles: Vec<Role>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RoleAssignmentsResponse {
+    pub admin: Addr, // Config admin, always treated as an owner
+    pub assignments: Vec<RoleAssignment>,
+}
+
+// Storage keys
+pub const ROLE_ASSIGNMENTS: Map<&Addr, Vec<Role>> = Map::new("role_assignments");
+
+/// Whether `address` may act as `role`. The config admin and owners hold every role.
+pub fn has_role(storage: &dyn Storage, address: &Addr, role: Role) -> StdResult<bool> {
+    let config = CONFIG.load(storage)?;
+    if config.admin == *address {
+        return Ok(true);
+    }
+    let roles = ROLE_ASSIGNMENTS
+        .may_load(storage, address)?
+        .unwrap_or_default();
+    Ok(roles.contains(&Role::Owner) || roles.contains(&role))
+}
+
+pub fn ensure_role(storage: &dyn Storage, sender: &Addr, role: Role) -> Result<(), ContractError> {
+    if !has_role(storage, sender, role)? {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(())
+}
+
+/// Give an address a role (owner only)
+pub fn grant_role(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+    role: Role,
+) -> Result<Response, ContractError> {
+    ensure_role(deps.storage, &info.sender, Role::Owner)?;
+    let address = deps.api.addr_validate(&address)?;
+
+    let mut roles = ROLE_ASSIGNMENTS
+        .may_load(deps.storage, &address)?
+        .unwrap_or_default();
+    if roles.contains(&role) {
+        return Err(ContractError::InvalidInput {
+            field: "role".to_string(),
+            message: "Address already holds this role".to_string(),
+        });
+    }
+    roles.push(role);
+    roles.sort();
+    ROLE_ASSIGNMENTS.save(deps.storage, &address, &roles)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "grant_role")
+        .add_attribute("address", address)
+        .add_attribute("role", format!("{:?}", role)))
+}
+
+/// Take a role away from an address (owner only). The config admin cannot be revoked this way.
+pub fn revoke_role(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+    role: Role,
+) -> Result<Response, ContractError> {
+    ensure_role(deps.storage, &info.sender, Role::Owner)?;
+    let address = deps.api.addr_validate(&address)?;
+
+    let mut roles = ROLE_ASSIGNMENTS
+        .may_load(deps.storage, &address)?
+        .unwrap_or_default();
+    if !roles.contains(&role) {
+        return Err(ContractError::InvalidInput {
+            field: "role".to_string(),
+            message: "Address does not hold this role".to_string(),
+        });
+    }
+    roles.retain(|held| *held != role);
+    if roles.is_empty() {
+        ROLE_ASSIGNMENTS.remove(deps.storage, &address);
+    } else {
+        ROLE_ASSIGNMENTS.save(deps.storage, &address, &roles)?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("method", "revoke_role")
+        .add_attribute("address", address)
+        .add_attribute("role", format!("{:?}", role)))
+}
+
+// Query functions
+pub fn query_role_assignments(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<RoleAssignmentsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_addr = start_after
+        .map(|s| deps.api.addr_validate(&s))
+        .transpose()?;
+    let start = start_addr.as_ref().map(Bound::exclusive);
+
+    let assignments = ROLE_ASSIGNMENTS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(address, roles)| RoleAssignment { address, roles }))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(RoleAssignmentsResponse {
+        admin: CONFIG.load(deps.storage)?.admin,
+        assignments,
+    })
+}
@@ -0,0 +1,80 @@
+use cosmwasm_std::{Deps, DepsMut, Env, Response, StdResult};
+use cw_storage_plus::Item;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+use crate::gas_optimization::GasOptimizer;
+use crate::lockup;
+
+/// Where the next scheduled run resumes each maintenance scan
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct SchedulerState {
+    pub expiry_cursor: Option<String>, // None = start from the first proposal
+    pub lockup_cursor: Option<String>,
+    pub runs: u64,
+    pub last_run_height: u64,
+    pub last_run_time: u64,
+}
+
+// Storage keys
+pub const SCHEDULER_STATE: Item<SchedulerState> = Item::new("scheduler_state");
+
+/// Process the next batch of expired proposals and lockups, resuming where the last run stopped.
+///
+/// Called from the chain's block-end/cron sudo hook or a CronCat task. Each scan wraps back to
+/// the first proposal once it reaches the end, so repeated runs cover every proposal.
+pub fn run_scheduled_maintenance(
+    deps: DepsMut,
+    env: &Env,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let mut state = SCHEDULER_STATE.may_load(deps.storage)?.unwrap_or_default();
+
+    let expiry_limit = limit
+        .unwrap_or_else(|| GasOptimizer::maintenance_batch_size("process_expired_proposal"))
+        .max(1) as usize;
+    let expired =
+        crate::expire_proposals(deps.storage, env, state.expiry_cursor.take(), expiry_limit)?;
+
+    let lockup_limit = limit
+        .unwrap_or_else(|| GasOptimizer::maintenance_batch_size("process_expired_lockup"))
+        .max(1) as usize;
+    let lockups =
+        lockup::unlock_expired(deps.storage, env, state.lockup_cursor.take(), lockup_limit)?;
+
+    state.expiry_cursor = expired.next_start_after;
+    state.lockup_cursor = lockups.next_start_after;
+    state.runs += 1;
+    state.last_run_height = env.block.height;
+    state.last_run_time = env.block.time.seconds();
+    SCHEDULER_STATE.save(deps.storage, &state)?;
+
+    let mut response = Response::new()
+        .add_messages(expired.bond_refunds)
+        .add_attribute("method", "scheduled_maintenance")
+        .add_attribute("expired_count", expired.failed.len().to_string())
+        .add_attribute("soft_cap_funded_count", expired.funded.len().to_string())
+        .add_attribute("unlocked_count", lockups.unlocked.len().to_string())
+        .add_attribute("run", state.runs.to_string());
+
+    if !expired.failed.is_empty() {
+        response = response.add_attribute("failed_proposals", expired.failed.join(","));
+    }
+    if !expired.funded.is_empty() {
+        response = response.add_attribute("funded_proposals", expired.funded.join(","));
+    }
+    if !lockups.unlocked.is_empty() {
+        response = response.add_attribute("unlocked_proposals", lockups.unlocked.join(","));
+    }
+    if !lockups.tranches_released.is_empty() {
+        response = response.add_attribute("tranches_released", lockups.tranches_released.join(","));
+    }
+
+    Ok(response)
+}
+
+// Query functions
+pub fn query_scheduler_state(deps: Deps) -> StdResult<SchedulerState> {
+    Ok(SCHEDULER_STATE.may_load(deps.storage)?.unwrap_or_default())
+}
@@ -3,13 +3,12 @@
 
 use crate::error::ContractError;
 use crate::state::{Config, Investment, Proposal, ProposalStatus, CONFIG, PROPOSALS};
-use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, StdResult, Storage, Uint128};
+use cosmwasm_std::{Addr, CosmosMsg, DepsMut, Env, MessageInfo, Storage, SubMsg, Uint128};
+use cw_storage_plus::Item;
 
 /// Security configuration and limits
 pub struct SecurityLimits {
     pub max_proposals_per_creator: u32,
-    pub min_investment_amount: Uint128,
-    pub max_investment_amount: Uint128,
     pub max_investors_per_proposal: u32,
     pub rate_limit_window_seconds: u64,
     pub max_operations_per_window: u32,
@@ -19,8 +18,6 @@ impl Default for SecurityLimits {
     fn default() -> Self {
         Self {
             max_proposals_per_creator: 10,
-            min_investment_amount: Uint128::new(1_000_000), // 1 NTRN
-            max_investment_amount: Uint128::new(1_000_000_000_000), // 1M NTRN
             max_investors_per_proposal: 500,
             rate_limit_window_seconds: 3600, // 1 hour
             max_operations_per_window: 100,
@@ -128,31 +125,37 @@ impl InputValidator {
         Ok(())
     }
 
+    /// Validate an investment against the platform-wide bounds in config
+    pub fn validate_investment_bounds(
+        amount: Uint128,
+        config: &Config,
+    ) -> Result<(), ContractError> {
+        if let Some(minimum) = config.min_investment_amount {
+            if amount < minimum {
+                return Err(ContractError::InvestmentTooSmall { minimum });
+            }
+        }
+        if let Some(maximum) = config.max_investment_amount {
+            if amount > maximum {
+                return Err(ContractError::InvestmentTooLarge { maximum });
+            }
+        }
+        Ok(())
+    }
+
     /// Validate investment amount
     pub fn validate_investment_amount(
         amount: Uint128,
         proposal: &Proposal,
-        limits: &SecurityLimits,
+        config: &Config,
     ) -> Result<(), ContractError> {
-        // Minimum investment check
-        if amount < limits.min_investment_amount {
-            return Err(ContractError::InvestmentTooSmall {
-                minimum: limits.min_investment_amount,
-            });
-        }
+        Self::validate_investment_bounds(amount, config)?;
 
-        // Maximum investment check
-        if amount > limits.max_investment_amount {
-            return Err(ContractError::InvestmentTooLarge {
-                maximum: limits.max_investment_amount,
-            });
-        }
-
-        // Check if investment would exceed target
+        // Check if investment would exceed the funding cap (target unless over-subscription is on)
         let new_total = proposal.funding_status.raised_amount + amount;
-        if new_total > proposal.financial_terms.target_amount {
+        if new_total > proposal.financial_terms.funding_cap() {
             return Err(ContractError::InvestmentExceedsTarget {
-                target: proposal.financial_terms.target_amount,
+                target: proposal.financial_terms.funding_cap(),
                 current: proposal.funding_status.raised_amount,
                 investment: amount,
             });
@@ -161,6 +164,22 @@ impl InputValidator {
         Ok(())
     }
 
+    /// Validate that a text field is between `min_length` and `max_length` bytes
+    pub fn validate_length(
+        field: &str,
+        input: &str,
+        min_length: usize,
+        max_length: usize,
+    ) -> Result<(), ContractError> {
+        if input.trim().len() < min_length || input.len() > max_length {
+            return Err(ContractError::InvalidInput {
+                field: field.to_string(),
+                message: format!("Must be {}-{} characters", min_length, max_length),
+            });
+        }
+        Ok(())
+    }
+
     /// Validate string inputs for XSS/injection protection
     pub fn sanitize_string_input(input: &str, max_length: usize) -> Result<String, ContractError> {
         if input.len() > max_length {
@@ -202,16 +221,59 @@ impl RateLimiter {
     }
 }
 
+/// Operation whose outbound calls are still in flight; set only while a lock is held
+pub const REENTRANCY_LOCK: Item<String> = Item::new("reentrancy_lock");
+
 /// Reentrancy protection
+///
+/// Messages a handler returns run after it has committed its state, so a token contract called
+/// from there could call straight back in. Handlers that call out take the lock and keep it
+/// until the reply for their last message releases it; if any of those messages fail the whole
+/// transaction, lock included, is reverted.
 pub struct ReentrancyGuard;
 
 impl ReentrancyGuard {
-    /// Check for potential reentrancy attacks
+    /// Reject the call if another operation's outbound messages have not finished
     pub fn check_reentrancy(deps: &DepsMut, operation: &str) -> Result<(), ContractError> {
-        // CosmWasm has built-in reentrancy protection through its execution model
-        // Additional checks can be implemented here if needed for specific operations
+        Self::ensure_unlocked(deps.storage, operation)
+    }
+
+    fn ensure_unlocked(storage: &dyn Storage, operation: &str) -> Result<(), ContractError> {
+        if let Some(held_by) = REENTRANCY_LOCK.may_load(storage)? {
+            return Err(ContractError::ReentrancyDetected {
+                operation: format!("{} during {}", operation, held_by),
+            });
+        }
         Ok(())
     }
+
+    /// Take the lock for an operation that is about to call other contracts
+    pub fn enter(storage: &mut dyn Storage, operation: &str) -> Result<(), ContractError> {
+        Self::ensure_unlocked(storage, operation)?;
+        REENTRANCY_LOCK.save(storage, &operation.to_string())?;
+        Ok(())
+    }
+
+    /// Release the lock once the outbound calls have completed
+    pub fn exit(storage: &mut dyn Storage) {
+        REENTRANCY_LOCK.remove(storage);
+    }
+
+    /// Wrap outbound messages so the last one reports back to `reply_id`, where the lock is released
+    pub fn guarded_messages(messages: Vec<CosmosMsg>, reply_id: u64) -> Vec<SubMsg> {
+        let count = messages.len();
+        messages
+            .into_iter()
+            .enumerate()
+            .map(|(index, msg)| {
+                if index + 1 == count {
+                    SubMsg::reply_on_success(msg, reply_id)
+                } else {
+                    SubMsg::new(msg)
+                }
+            })
+            .collect()
+    }
 }
 
 /// Mathematical overflow protection with enhanced precision handling
@@ -537,6 +599,15 @@ mod tests {
             min_funding_period_days: 7,
             max_funding_period_days: 120,
             lockup_period_seconds: 31536000, // 1 year
+            cw20_code_id: 1,
+            fee_schedule: vec![],
+            escrow_contract: None,
+            verification_required_above: None,
+            require_proposal_review: false,
+            governance_contract: None,
+            min_investment_amount: None,
+            max_investment_amount: None,
+            creation_bond: None,
         };
 
         let proposal = Proposal {
@@ -549,6 +620,7 @@ mod tests {
                 location: "Test".to_string(),
                 description: "Test".to_string(),
                 full_description: "Test".to_string(),
+                token_metadata: None,
                 risk_factors: vec!["Test".to_string()],
                 highlights: vec!["Test".to_string()],
             },
@@ -557,27 +629,39 @@ mod tests {
                 token_price: Uint128::new(1000),
                 total_shares: 1000,
                 minimum_investment: Uint128::new(1000),
-                expected_apy: "10%".to_string(),
+                expected_apy_bps: 1_000,
                 funding_deadline: 0,
+                allow_partial_fill: false,
+                milestones: vec![],
+                usd_terms: None,
+                soft_cap: None,
+                max_raise: None,
+                pricing_tiers: vec![],
+                creator_vesting: None,
+                lockup_tranches: vec![],
+                token_name: None,
+                token_symbol: None,
+                withdrawal_terms: Default::default(),
             },
             funding_status: FundingStatus {
-                total_raised: Uint128::zero(),
+                raised_amount: Uint128::zero(),
                 investor_count: 0,
                 is_funded: false,
+                tokens_minted: false,
             },
             documents: vec![],
             compliance: crate::state::ComplianceInfo {
                 kyc_required: true,
                 accredited_only: false,
-                max_investors: 100,
+                max_investors: Some(100),
                 compliance_notes: vec![],
+                max_investment_per_user: None,
             },
             timestamps: crate::state::Timestamps {
                 created_at: 0,
                 updated_at: 0,
-                funded_at: None,
-                token_minted_at: None,
-                lockup_end_at: None,
+                funding_deadline: 0,
+                lockup_end: None,
             },
             status: ProposalStatus::Active,
         };
@@ -0,0 +1,212 @@
+use cosmwasm_std::{
+    to_json_vec, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult,
+};
+use cw_storage_plus::Map;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::error::ContractError;
+
+/// Off-chain authorization relayed by a third party on behalf of `signer`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SignedAuthorization {
+    pub signer: String,
+    pub nonce: u64,
+    pub signature: Binary, // 64-byte secp256k1 signature over sha256(SignDoc)
+}
+
+/// Canonical document the signer signs.
+///
+/// Chain id and contract address come from the executing environment, never from the relayer,
+/// so a signature is only valid on the deployment it was produced for.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SignDoc {
+    pub chain_id: String,
+    pub contract_address: String,
+    pub signer: String,
+    pub nonce: u64,
+    pub action: String,
+    pub data: Binary, // Action-specific parameters
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SignerInfoResponse {
+    pub signer: Addr,
+    pub pubkey: Option<Binary>,
+    pub next_nonce: u64,
+}
+
+// Storage keys
+pub const SIGNING_KEYS: Map<&Addr, Binary> = Map::new("signing_keys"); // address -> secp256k1 pubkey
+pub const SIGNER_NONCES: Map<&Addr, u64> = Map::new("signer_nonces"); // address -> next expected nonce
+
+impl SignDoc {
+    pub fn new(env: &Env, signer: &Addr, nonce: u64, action: &str, data: Binary) -> Self {
+        Self {
+            chain_id: env.block.chain_id.clone(),
+            contract_address: env.contract.address.to_string(),
+            signer: signer.to_string(),
+            nonce,
+            action: action.to_string(),
+            data,
+        }
+    }
+
+    /// Hash that is actually signed
+    pub fn digest(&self) -> StdResult<Vec<u8>> {
+        Ok(Sha256::digest(to_json_vec(self)?).to_vec())
+    }
+}
+
+/// Verify a relayed authorization for `action` and consume its nonce.
+///
+/// Nonces are strictly sequential per signer, so each signature can be used exactly once.
+pub fn verify_and_consume(
+    deps: DepsMut,
+    env: &Env,
+    authorization: &SignedAuthorization,
+    action: &str,
+    data: Binary,
+) -> Result<Addr, ContractError> {
+    let signer = deps.api.addr_validate(&authorization.signer)?;
+    let pubkey = SIGNING_KEYS.may_load(deps.storage, &signer)?.ok_or(
+        ContractError::SigningKeyNotRegistered {
+            signer: signer.to_string(),
+        },
+    )?;
+
+    let expected = SIGNER_NONCES.may_load(deps.storage, &signer)?.unwrap_or(0);
+    if authorization.nonce != expected {
+        return Err(ContractError::InvalidNonce {
+            expected,
+            got: authorization.nonce,
+        });
+    }
+
+    let doc = SignDoc::new(env, &signer, authorization.nonce, action, data);
+    let valid = deps
+        .api
+        .secp256k1_verify(&doc.digest()?, &authorization.signature, &pubkey)
+        .unwrap_or(false);
+    if !valid {
+        return Err(ContractError::InvalidSignature {});
+    }
+
+    SIGNER_NONCES.save(deps.storage, &signer, &(expected + 1))?;
+    Ok(signer)
+}
+
+/// Register (or rotate) the caller's secp256k1 public key for off-chain authorizations
+pub fn register_signing_key(
+    deps: DepsMut,
+    info: MessageInfo,
+    pubkey: Binary,
+) -> Result<Response, ContractError> {
+    // Compressed (33 bytes) or uncompressed (65 bytes) SEC1 encoding
+    if pubkey.len() != 33 && pubkey.len() != 65 {
+        return Err(ContractError::InvalidInput {
+            field: "pubkey".to_string(),
+            message: "Expected a 33 or 65 byte secp256k1 public key".to_string(),
+        });
+    }
+
+    SIGNING_KEYS.save(deps.storage, &info.sender, &pubkey)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "register_signing_key")
+        .add_attribute("signer", info.sender))
+}
+
+// Query functions
+pub fn query_signer_info(deps: Deps, signer: String) -> StdResult<SignerInfoResponse> {
+    let signer_addr = deps.api.addr_validate(&signer)?;
+
+    Ok(SignerInfoResponse {
+        pubkey: SIGNING_KEYS.may_load(deps.storage, &signer_addr)?,
+        next_nonce: SIGNER_NONCES
+            .may_load(deps.storage, &signer_addr)?
+            .unwrap_or(0),
+        signer: signer_addr,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+    use k256::ecdsa::{signature::hazmat::PrehashSigner, Signature, SigningKey};
+
+    fn sign(key: &SigningKey, doc: &SignDoc) -> Binary {
+        let signature: Signature = key.sign_prehash(&doc.digest().unwrap()).unwrap();
+        Binary::from(signature.to_bytes().to_vec())
+    }
+
+    #[test]
+    fn test_signature_scoped_to_deployment_and_single_use() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let signer = deps.api.addr_make("signer");
+        let key = SigningKey::from_slice(&[7u8; 32]).unwrap();
+        let pubkey = Binary::from(
+            key.verifying_key()
+                .to_encoded_point(true)
+                .as_bytes()
+                .to_vec(),
+        );
+
+        register_signing_key(deps.as_mut(), message_info(&signer, &[]), pubkey).unwrap();
+
+        let data = Binary::from(b"1".to_vec());
+        let authorization = |doc: &SignDoc| SignedAuthorization {
+            signer: signer.to_string(),
+            nonce: doc.nonce,
+            signature: sign(&key, doc),
+        };
+
+        // Signed for another chain
+        let mut foreign_env = mock_env();
+        foreign_env.block.chain_id = "other-chain".to_string();
+        let foreign = SignDoc::new(&foreign_env, &signer, 0, "confirm", data.clone());
+        assert!(matches!(
+            verify_and_consume(
+                deps.as_mut(),
+                &env,
+                &authorization(&foreign),
+                "confirm",
+                data.clone()
+            ),
+            Err(ContractError::InvalidSignature {})
+        ));
+
+        // Signed for another contract
+        let mut other_contract = mock_env();
+        other_contract.contract.address = deps.api.addr_make("other_contract");
+        let cross = SignDoc::new(&other_contract, &signer, 0, "confirm", data.clone());
+        assert!(matches!(
+            verify_and_consume(
+                deps.as_mut(),
+                &env,
+                &authorization(&cross),
+                "confirm",
+                data.clone()
+            ),
+            Err(ContractError::InvalidSignature {})
+        ));
+
+        // Valid once, then replay is rejected by the nonce
+        let doc = SignDoc::new(&env, &signer, 0, "confirm", data.clone());
+        let auth = authorization(&doc);
+        assert_eq!(
+            verify_and_consume(deps.as_mut(), &env, &auth, "confirm", data.clone()).unwrap(),
+            signer
+        );
+        assert!(matches!(
+            verify_and_consume(deps.as_mut(), &env, &auth, "confirm", data),
+            Err(ContractError::InvalidNonce {
+                expected: 1,
+                got: 0
+            })
+        ));
+    }
+}
@@ -0,0 +1,150 @@
+//! Dry run of an investment.
+//!
+//! Wallets call `SimulateInvestment` before broadcasting to show what an amount would buy and
+//! whether the launchpad would accept it. Every check `Invest` makes is run against current
+//! state without writing anything, and each one that fails is reported rather than stopping at
+//! the first.
+
+use cosmwasm_std::{Decimal, Deps, Env, StdResult, Uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+use crate::rate_limit::RateLimiter;
+use crate::state::{ProposalStatus, CONFIG, INVESTMENTS, PROPOSALS};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SimulateInvestmentResponse {
+    pub shares: u64,              // Shares the investment would buy; 0 if it cannot fill
+    pub accepted_amount: Uint128, // Part of the amount taken into escrow
+    pub change: Uint128,          // Excess handed back on a partial fill
+    pub effective_price: Option<Decimal>, // Accepted amount per share; None when no shares
+    pub completes_funding: bool,  // Whether this investment would close the raise
+    pub remaining_capacity: Uint128, // Amount still open before the funding cap
+    pub remaining_shares: u64,
+    pub requires_cosign: bool, // The investment would wait for a second approval
+    pub rejections: Vec<String>, // Why Invest would fail; empty if it would go through
+}
+
+/// Run the checks `Invest` makes for `amount` of `denom` without recording anything.
+///
+/// Investor-specific checks (KYC, whitelist, per-investor and annual limits, rate limit) only
+/// run when an `investor` is given.
+pub fn query_simulate_investment(
+    deps: Deps,
+    env: Env,
+    proposal_id: String,
+    amount: Uint128,
+    denom: String,
+    investor: Option<String>,
+) -> StdResult<SimulateInvestmentResponse> {
+    let mut proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+    let config = CONFIG.load(deps.storage)?;
+    let investor = investor
+        .map(|investor| deps.api.addr_validate(&investor))
+        .transpose()?;
+    let mut rejections = Vec::new();
+    let mut reject = |result: Result<(), ContractError>| {
+        if let Err(err) = result {
+            rejections.push(err.to_string());
+        }
+    };
+
+    // Same terms Invest would price at, converted at the current rate for USD raises
+    if proposal.financial_terms.usd_terms.is_some() && proposal.status == ProposalStatus::Active {
+        let raised_amount = proposal.funding_status.raised_amount;
+        reject(crate::oracle::apply_usd_terms(
+            deps,
+            &env,
+            &mut proposal.financial_terms,
+            raised_amount,
+        ));
+    }
+
+    if denom != "untrn" {
+        reject(Err(ContractError::InvalidInput {
+            field: "denom".to_string(),
+            message: "Investments are only accepted in untrn".to_string(),
+        }));
+    }
+    if amount.is_zero() {
+        reject(Err(ContractError::InsufficientFunds {}));
+    }
+    reject(crate::validate_investment(&config, &proposal, &env, amount));
+    reject(crate::freeze::ensure_not_frozen(deps.storage, &proposal_id));
+
+    let shares_sold = crate::shares_sold(deps.storage, &proposal)?;
+    let (shares, accepted_amount, change) = if amount.is_zero() {
+        (0, Uint128::zero(), Uint128::zero())
+    } else {
+        match crate::fill_investment(&proposal, shares_sold, amount) {
+            Ok(fill) => fill,
+            Err(err) => {
+                reject(Err(err));
+                (0, Uint128::zero(), Uint128::zero())
+            }
+        }
+    };
+    let funding_cap = proposal.financial_terms.funding_cap();
+    let remaining_capacity = funding_cap.saturating_sub(proposal.funding_status.raised_amount);
+
+    if let Some(investor) = &investor {
+        reject(crate::kyc::enforce_verification(
+            deps.storage,
+            &env,
+            &proposal,
+            investor,
+        ));
+        reject(crate::whitelist::enforce_whitelist(
+            deps.storage,
+            &proposal_id,
+            investor,
+        ));
+
+        let existing = INVESTMENTS.may_load(deps.storage, (proposal_id.clone(), investor))?;
+        let investor_cap = proposal.compliance.investor_cap();
+        if existing.is_none() && proposal.funding_status.investor_count >= investor_cap {
+            reject(Err(ContractError::MaxInvestorsExceeded {
+                max: investor_cap as u32,
+            }));
+        }
+        let already_invested = existing
+            .map(|investment| investment.amount)
+            .unwrap_or_default();
+        reject(crate::compliance::enforce_investor_cap(
+            &proposal,
+            already_invested,
+            accepted_amount,
+        ));
+        reject(crate::investor_limits::enforce_annual_limit(
+            deps.storage,
+            &env,
+            investor,
+            accepted_amount,
+        ));
+        reject(RateLimiter::ensure_within_limit(
+            deps.storage,
+            investor,
+            "invest",
+            &env,
+        ));
+    }
+
+    Ok(SimulateInvestmentResponse {
+        shares,
+        accepted_amount,
+        change,
+        effective_price: (shares > 0).then(|| Decimal::from_ratio(accepted_amount, shares)),
+        completes_funding: rejections.is_empty()
+            && shares > 0
+            && proposal
+                .funding_status
+                .raised_amount
+                .saturating_add(accepted_amount)
+                >= funding_cap,
+        remaining_capacity,
+        remaining_shares: crate::calculate_remaining_shares(&proposal, shares_sold),
+        requires_cosign: crate::cosign::requires_cosign(deps.storage, amount)?,
+        rejections,
+    })
+}
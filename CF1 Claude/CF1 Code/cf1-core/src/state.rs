@@ -1,4 +1,4 @@
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Empty, Uint128};
 use cw_storage_plus::{Item, Map};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -10,7 +10,23 @@ pub struct Config {
     pub min_funding_period_days: u64,
     pub max_funding_period_days: u64,
     pub lockup_period_seconds: u64, // 12 months in seconds
-    pub cw20_code_id: u64,          // Code ID for CW20 token instantiation
+    pub cw20_code_id: u64,          // Code ID of cf1-restricted-cw20, used for share tokens
+    #[serde(default)]
+    pub fee_schedule: Vec<crate::fees::FeeBracket>, // Raise-size brackets; platform_fee_bps applies above the last
+    #[serde(default)]
+    pub escrow_contract: Option<Addr>, // External custody for new investments; None keeps funds here
+    #[serde(default)]
+    pub verification_required_above: Option<Uint128>, // Larger targets need a verified creator
+    #[serde(default)]
+    pub require_proposal_review: bool, // New proposals wait in draft for approval
+    #[serde(default)]
+    pub governance_contract: Option<Addr>, // Notified of funding, distribution and lockup expiry
+    #[serde(default)]
+    pub min_investment_amount: Option<Uint128>, // Platform floor per investment, on top of each proposal's minimum
+    #[serde(default)]
+    pub max_investment_amount: Option<Uint128>, // Platform ceiling per investment; None leaves it unbounded
+    #[serde(default)]
+    pub creation_bond: Option<Uint128>, // untrn each CreateProposal must carry; None = no bond
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -36,6 +52,23 @@ pub struct AssetDetails {
     pub full_description: String,
     pub risk_factors: Vec<String>,
     pub highlights: Vec<String>,
+    #[serde(default)]
+    pub token_metadata: Option<crate::token_metadata::TokenMetadata>, // Marketing info for the share token
+}
+
+/// Reads `expected_apy_bps`, or the `expected_apy` string that proposals stored before it (e.g.
+/// "12.5%"). An old string that isn't a plain percentage reads as 0.
+fn deserialize_apy_bps<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<u16, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Apy {
+        Bps(u16),
+        Legacy(String),
+    }
+    Ok(match Apy::deserialize(deserializer)? {
+        Apy::Bps(bps) => bps,
+        Apy::Legacy(apy) => crate::math::parse_percentage_bps(&apy).unwrap_or_default(),
+    })
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -44,8 +77,52 @@ pub struct FinancialTerms {
     pub token_price: Uint128,
     pub total_shares: u64,
     pub minimum_investment: Uint128,
-    pub expected_apy: String,
+    #[serde(alias = "expected_apy", deserialize_with = "deserialize_apy_bps")]
+    pub expected_apy_bps: u16, // Expected annual yield in basis points, e.g. 1250 = 12.5%
     pub funding_deadline: u64, // Unix timestamp
+    #[serde(default)]
+    pub allow_partial_fill: bool, // Accept oversubscribed tickets up to remaining shares, refund the rest
+    #[serde(default)]
+    pub milestones: Vec<crate::milestones::Milestone>, // Empty = creator is paid in full on distribution
+    #[serde(default)]
+    pub usd_terms: Option<crate::oracle::UsdTerms>, // Target and minimum in USD, converted to untrn at invest time
+    #[serde(default)]
+    pub soft_cap: Option<Uint128>, // Raise succeeds at the deadline once this much is in; None = target
+    #[serde(default)]
+    pub max_raise: Option<Uint128>, // Keep accepting past target up to this cap; None = close at target
+    #[serde(default)]
+    pub pricing_tiers: Vec<crate::pricing::PricingTier>, // Discounted tranches sold before token_price applies
+    #[serde(default)]
+    pub creator_vesting: Option<crate::vesting::CreatorVesting>, // Creator's share of total_shares, minted as it vests
+    #[serde(default)]
+    pub lockup_tranches: Vec<crate::lockup::LockupTranche>, // Early partial releases; empty = all-or-nothing lockup
+    #[serde(default)]
+    pub token_name: Option<String>, // None = "<asset name> Token"
+    #[serde(default)]
+    pub token_symbol: Option<String>, // None = generated from the proposal id
+    #[serde(default)]
+    pub withdrawal_terms: crate::reconfirmation::WithdrawalTerms, // Fee and cooldown for leaving an active raise
+}
+
+impl FinancialTerms {
+    /// Amount that makes the raise successful once the deadline passes
+    pub fn minimum_raise(&self) -> Uint128 {
+        self.soft_cap.unwrap_or(self.target_amount)
+    }
+
+    /// Amount at which the raise closes immediately and stops accepting investment
+    pub fn funding_cap(&self) -> Uint128 {
+        self.max_raise.unwrap_or(self.target_amount)
+    }
+
+    /// Shares open to investors once the creator's vesting allocation is set aside
+    pub fn sellable_shares(&self) -> u64 {
+        let reserved = self
+            .creator_vesting
+            .as_ref()
+            .map_or(0, |vesting| vesting.allocation_shares(self.total_shares));
+        self.total_shares.saturating_sub(reserved)
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -70,6 +147,34 @@ pub struct ComplianceInfo {
     pub accredited_only: bool,
     pub max_investors: Option<u64>,
     pub compliance_notes: Vec<String>,
+    pub max_investment_per_user: Option<Uint128>, // Cumulative cap per investor address
+}
+
+impl ComplianceInfo {
+    /// Most investors the proposal may have: its own limit, never above the platform ceiling
+    pub fn investor_cap(&self) -> u64 {
+        self.max_investors
+            .map_or(MAX_INVESTORS_PER_PROPOSAL, |max| {
+                max.min(MAX_INVESTORS_PER_PROPOSAL)
+            })
+    }
+}
+
+/// A share token instantiation that failed; kept until a mint for the proposal succeeds
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MintFailure {
+    pub error: String,
+    pub attempts: u32, // Failed instantiations so far
+    pub failed_at: u64,
+}
+
+/// How far a token distribution has got; removed once the raise is paid out
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct DistributionProgress {
+    pub next_index: u64, // Position in the proposal's investor list the next batch starts from
+    pub distributed_count: u64,
+    pub total_distributed: u64, // Shares minted so far
+    pub complete: bool,         // Every investor minted; awaiting FinalizeDistribution
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -82,11 +187,30 @@ pub struct Timestamps {
 
 #[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, JsonSchema)]
 pub enum ProposalStatus {
-    Active,    // Accepting investments
-    Funded,    // Goal reached, tokens can be minted
-    Completed, // Tokens minted and distributed
-    Failed,    // Deadline passed without reaching goal
-    Cancelled, // Cancelled by creator before funding
+    Draft,       // Awaiting submission for review; editable by the creator
+    UnderReview, // Submitted, waiting for approval
+    Active,      // Accepting investments
+    Funded,      // Goal reached, tokens can be minted
+    Completed,   // Tokens minted and distributed
+    Failed,      // Deadline passed without reaching goal
+    Cancelled,   // Cancelled by creator before funding
+    Retired,     // Wound down by holder vote; tokens redeemable for final proceeds
+}
+
+impl ProposalStatus {
+    /// Prefix of the status index
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProposalStatus::Draft => "draft",
+            ProposalStatus::UnderReview => "under_review",
+            ProposalStatus::Active => "active",
+            ProposalStatus::Funded => "funded",
+            ProposalStatus::Completed => "completed",
+            ProposalStatus::Failed => "failed",
+            ProposalStatus::Cancelled => "cancelled",
+            ProposalStatus::Retired => "retired",
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -104,6 +228,7 @@ pub enum InvestmentStatus {
     Pending,   // In escrow, awaiting funding completion
     Completed, // Tokens distributed
     Refunded,  // Investment refunded due to funding failure
+    Redeemed,  // Tokens burned for a share of retirement proceeds
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -114,6 +239,8 @@ pub struct Creator {
     pub successful_proposals: u64,
     pub total_proposals: u64,
     pub established: u64, // Unix timestamp
+    #[serde(default)]
+    pub verification: crate::verification::VerificationTier, // Set by compliance
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -130,11 +257,13 @@ pub const CONFIG: Item<Config> = Item::new("config");
 pub const PROPOSALS: Map<String, Proposal> = Map::new("proposals");
 pub const PROPOSAL_COUNT: Item<u64> = Item::new("proposal_count");
 pub const PROPOSAL_HOT_DATA: Map<String, ProposalHotData> = Map::new("proposal_hot"); // Gas-optimized hot data
+pub const DEADLINE_EXTENSIONS: Map<String, u64> = Map::new("deadline_ext"); // proposal_id -> deadline before its one extension
 
 // Investment data - optimized storage pattern
 pub const INVESTMENTS: Map<(String, &Addr), Investment> = Map::new("investments");
 pub const INVESTMENT_INDEX: Map<&Addr, Vec<String>> = Map::new("inv_idx"); // User -> Proposal IDs (compressed)
 pub const PROPOSAL_STATS: Map<String, ProposalStats> = Map::new("prop_stats"); // Aggregated data
+pub const ESCROWED_SHARES: Map<String, u64> = Map::new("escrowed_shares"); // proposal_id -> shares committed to investors
 
 // Creator data
 pub const CREATORS: Map<&Addr, Creator> = Map::new("creators");
@@ -142,16 +271,35 @@ pub const CREATOR_STATS: Map<&Addr, CreatorCompactStats> = Map::new("creator_sta
 
 // Token and contract management
 pub const TOKEN_CONTRACTS: Map<String, Addr> = Map::new("token_contracts");
-pub const PENDING_TOKEN_REPLY: Item<String> = Item::new("pending_token_reply");
+pub const TOKEN_PROPOSALS: Map<&Addr, String> = Map::new("token_proposals"); // token -> proposal_id, for transfer hooks
+pub const MINT_FAILURES: Map<String, MintFailure> = Map::new("mint_failures"); // proposal_id -> last failed instantiation
+pub const DISTRIBUTION_PROGRESS: Map<String, DistributionProgress> =
+    Map::new("distribution_progress");
 
 // Indexed data for efficient queries
-pub const ACTIVE_PROPOSALS: Map<u64, String> = Map::new("active_idx"); // timestamp -> proposal_id
-pub const FUNDED_PROPOSALS: Map<u64, String> = Map::new("funded_idx"); // timestamp -> proposal_id
-pub const CREATOR_PROPOSAL_INDEX: Map<(&Addr, u64), String> = Map::new("creator_idx"); // (creator, timestamp) -> proposal_id
+pub const STATUS_INDEX: Map<(&str, u64, &str), String> = Map::new("status_idx"); // (status, created_at, proposal_id) -> proposal_id
+pub const STATUS_COUNTS: Map<&str, u64> = Map::new("status_count"); // status -> proposals currently in it
+pub const ACTIVE_PROPOSALS: Map<(u64, &str), String> = Map::new("active_deadline_idx"); // (funding_deadline, proposal_id) -> proposal_id, Active proposals only
+pub const CATEGORY_INDEX: Map<(&str, u64, &str), String> = Map::new("category_idx"); // (category, created_at, proposal_id) -> proposal_id
+pub const CATEGORY_COUNTS: Map<&str, u64> = Map::new("category_count"); // category -> proposals in it
+pub const ASSET_TYPE_INDEX: Map<(&str, u64, &str), String> = Map::new("asset_type_idx"); // (asset_type, created_at, proposal_id) -> proposal_id
+pub const ASSET_TYPE_COUNTS: Map<&str, u64> = Map::new("asset_type_count"); // asset_type -> proposals of it
+pub const TARGET_INDEX: Map<(u128, &str), (u16, String)> = Map::new("target_idx"); // (target_amount, proposal_id) -> (expected APY in bps, asset type listing key)
+pub const APY_INDEX: Map<(u16, &str), (u128, String)> = Map::new("apy_idx"); // (expected APY in bps, proposal_id) -> (target_amount, asset type listing key)
+pub const CREATOR_PROPOSAL_INDEX: Map<(&Addr, u64), String> = Map::new("creator_idx"); // (creator, proposal sequence) -> proposal_id
 pub const CREATOR_PROPOSAL_COUNT: Map<&Addr, u32> = Map::new("creator_count"); // creator -> proposal count
 pub const USER_INVESTMENTS: Map<&Addr, Vec<String>> = Map::new("user_inv"); // user -> proposal_ids
 pub const PROPOSAL_INVESTMENTS: Map<String, Vec<Addr>> = Map::new("prop_inv"); // proposal_id -> investors
-pub const PROPOSAL_INVESTOR_COUNT: Map<String, u32> = Map::new("prop_inv_count"); // proposal_id -> count
+
+// Running platform aggregates, so stats queries never iterate proposals or investors
+pub const TOTAL_VALUE_LOCKED: Item<Uint128> = Item::new("tvl"); // Sum of raised_amount over all proposals
+pub const AGGREGATE_PLATFORM_STATS: Item<AggregatePlatformStats> = Item::new("platform_stats");
+pub const UNIQUE_INVESTORS: Map<&Addr, Empty> = Map::new("unique_investors"); // Every address that has ever held a position
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct AggregatePlatformStats {
+    pub total_investors: u64, // Size of UNIQUE_INVESTORS; each address counts once across all proposals
+}
 
 // Gas-optimized data structures for hot path operations
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -185,6 +333,173 @@ pub fn generate_proposal_id(count: u64) -> String {
     format!("p{}", count) // Shorter IDs for gas efficiency
 }
 
+/// Sequence number a proposal id was generated from ("p12" -> 12)
+pub fn proposal_sequence(proposal_id: &str) -> Option<u64> {
+    proposal_id.strip_prefix('p')?.parse().ok()
+}
+
+impl From<&Proposal> for ProposalHotData {
+    fn from(proposal: &Proposal) -> Self {
+        ProposalHotData {
+            status: proposal.status,
+            target_amount: proposal.financial_terms.target_amount,
+            raised_amount: proposal.funding_status.raised_amount,
+            investor_count: proposal.funding_status.investor_count as u32,
+            funding_deadline: proposal.financial_terms.funding_deadline,
+            creator: proposal.creator.clone(),
+        }
+    }
+}
+
+/// Persist a proposal together with its hot-data summary, status index entry, status count and
+/// contribution to TVL, so indexed queries never go stale
+pub fn save_proposal(storage: &mut dyn cosmwasm_std::Storage, proposal: &Proposal) -> cosmwasm_std::StdResult<()> {
+    let previous = PROPOSALS.may_load(storage, proposal.id.clone())?;
+    let previous_raised = previous.as_ref().map(|p| p.funding_status.raised_amount).unwrap_or_default();
+    if previous_raised != proposal.funding_status.raised_amount {
+        let tvl = TOTAL_VALUE_LOCKED.may_load(storage)?.unwrap_or_default();
+        TOTAL_VALUE_LOCKED.save(storage, &(tvl + proposal.funding_status.raised_amount).saturating_sub(previous_raised))?;
+    }
+    let previous_details = previous.as_ref().map(|p| &p.asset_details);
+    move_listing(storage, CATEGORY_INDEX, CATEGORY_COUNTS, previous_details.map(|d| d.category.as_str()), &proposal.asset_details.category, proposal)?;
+    move_listing(storage, ASSET_TYPE_INDEX, ASSET_TYPE_COUNTS, previous_details.map(|d| d.asset_type.as_str()), &proposal.asset_details.asset_type, proposal)?;
+    index_terms(storage, previous.as_ref().map(terms_index_key), proposal)?;
+    let previous_deadline = previous.as_ref().and_then(active_index_deadline);
+    if previous_deadline != active_index_deadline(proposal) {
+        if let Some(deadline) = previous_deadline {
+            ACTIVE_PROPOSALS.remove(storage, (deadline, proposal.id.as_str()));
+        }
+        if let Some(deadline) = active_index_deadline(proposal) {
+            ACTIVE_PROPOSALS.save(storage, (deadline, proposal.id.as_str()), &proposal.id)?;
+        }
+    }
+    let previous = previous.map(|p| p.status);
+    if previous != Some(proposal.status) {
+        if let Some(previous) = previous {
+            STATUS_INDEX.remove(storage, status_index_key(previous, proposal));
+            STATUS_COUNTS.update(storage, previous.as_str(), |count| -> cosmwasm_std::StdResult<_> {
+                Ok(count.unwrap_or_default().saturating_sub(1))
+            })?;
+        }
+        STATUS_INDEX.save(storage, status_index_key(proposal.status, proposal), &proposal.id)?;
+        STATUS_COUNTS.update(storage, proposal.status.as_str(), |count| -> cosmwasm_std::StdResult<_> {
+            Ok(count.unwrap_or_default() + 1)
+        })?;
+        crate::audit::note_transition(storage, &proposal.id, previous, proposal.status)?;
+    }
+    PROPOSALS.save(storage, proposal.id.clone(), proposal)?;
+    PROPOSAL_HOT_DATA.save(storage, proposal.id.clone(), &ProposalHotData::from(proposal))
+}
+
+/// Record that `user` holds a position in the proposal, counting them as a platform investor the
+/// first time they hold any
+pub fn add_user_investment(storage: &mut dyn cosmwasm_std::Storage, user: &Addr, proposal_id: &str) -> cosmwasm_std::StdResult<()> {
+    let mut proposals = USER_INVESTMENTS.may_load(storage, user)?.unwrap_or_default();
+    if proposals.iter().any(|id| id == proposal_id) {
+        return Ok(());
+    }
+    if !UNIQUE_INVESTORS.has(storage, user) {
+        UNIQUE_INVESTORS.save(storage, user, &Empty {})?;
+        let mut stats = AGGREGATE_PLATFORM_STATS.may_load(storage)?.unwrap_or_default();
+        stats.total_investors += 1;
+        AGGREGATE_PLATFORM_STATS.save(storage, &stats)?;
+    }
+    proposals.push(proposal_id.to_string());
+    USER_INVESTMENTS.save(storage, user, &proposals)
+}
+
+/// Forget the user's position in the proposal. They stay counted as a platform investor.
+pub fn remove_user_investment(storage: &mut dyn cosmwasm_std::Storage, user: &Addr, proposal_id: &str) -> cosmwasm_std::StdResult<()> {
+    let Some(mut proposals) = USER_INVESTMENTS.may_load(storage, user)? else {
+        return Ok(());
+    };
+    let held = proposals.len();
+    proposals.retain(|id| id != proposal_id);
+    if proposals.len() == held {
+        return Ok(());
+    }
+    if proposals.is_empty() {
+        USER_INVESTMENTS.remove(storage, user);
+        Ok(())
+    } else {
+        USER_INVESTMENTS.save(storage, user, &proposals)
+    }
+}
+
+/// Creation time breaks ties within a status; the id keeps same-block proposals apart
+pub fn status_index_key(status: ProposalStatus, proposal: &Proposal) -> (&'static str, u64, &str) {
+    (status.as_str(), proposal.timestamps.created_at, proposal.id.as_str())
+}
+
+/// Categories and asset types are indexed trimmed and lowercased, so browsing ignores case
+pub fn listing_key(value: &str) -> String {
+    value.trim().to_lowercase()
+}
+
+/// Keep a category or asset type index in step with the proposal's current value
+pub fn move_listing(
+    storage: &mut dyn cosmwasm_std::Storage,
+    index: Map<(&str, u64, &str), String>,
+    counts: Map<&str, u64>,
+    previous: Option<&str>,
+    current: &str,
+    proposal: &Proposal,
+) -> cosmwasm_std::StdResult<()> {
+    let previous = previous.map(listing_key);
+    let current = listing_key(current);
+    if previous.as_ref() == Some(&current) {
+        return Ok(());
+    }
+    if let Some(previous) = previous {
+        index.remove(storage, (&previous, proposal.timestamps.created_at, &proposal.id));
+        counts.update(storage, &previous, |count| -> cosmwasm_std::StdResult<_> {
+            Ok(count.unwrap_or_default().saturating_sub(1))
+        })?;
+    }
+    index.save(storage, (&current, proposal.timestamps.created_at, &proposal.id), &proposal.id)?;
+    counts.update(storage, &current, |count| -> cosmwasm_std::StdResult<_> {
+        Ok(count.unwrap_or_default() + 1)
+    })?;
+    Ok(())
+}
+
+/// Target amount, expected APY in basis points and asset type listing key, as `TARGET_INDEX`
+/// and `APY_INDEX` hold them
+pub fn terms_index_key(proposal: &Proposal) -> (u128, u16, String) {
+    (
+        proposal.financial_terms.target_amount.u128(),
+        proposal.financial_terms.expected_apy_bps,
+        listing_key(&proposal.asset_details.asset_type),
+    )
+}
+
+/// Keep the target and APY indexes in step with the proposal's current terms and asset type
+pub fn index_terms(
+    storage: &mut dyn cosmwasm_std::Storage,
+    previous: Option<(u128, u16, String)>,
+    proposal: &Proposal,
+) -> cosmwasm_std::StdResult<()> {
+    let (target, apy_bps, asset_type) = terms_index_key(proposal);
+    if previous.as_ref() == Some(&(target, apy_bps, asset_type.clone())) {
+        return Ok(());
+    }
+    if let Some((previous_target, previous_apy, _)) = previous {
+        TARGET_INDEX.remove(storage, (previous_target, &proposal.id));
+        APY_INDEX.remove(storage, (previous_apy, &proposal.id));
+    }
+    TARGET_INDEX.save(
+        storage,
+        (target, &proposal.id),
+        &(apy_bps, asset_type.clone()),
+    )?;
+    APY_INDEX.save(storage, (apy_bps, &proposal.id), &(target, asset_type))
+}
+
+/// Where an Active proposal sits in `ACTIVE_PROPOSALS`; None for any other status
+pub fn active_index_deadline(proposal: &Proposal) -> Option<u64> {
+    (proposal.status == ProposalStatus::Active).then_some(proposal.financial_terms.funding_deadline)
+}
+
 // Gas-efficient data access patterns
 pub fn get_proposal_hot_data(storage: &dyn cosmwasm_std::Storage, proposal_id: &str) -> cosmwasm_std::StdResult<ProposalHotData> {
     PROPOSAL_HOT_DATA.load(storage, proposal_id.to_string())
@@ -210,3 +525,8 @@ pub const MIN_FUNDING_PERIOD_DAYS: u64 = 7;
 pub const MAX_FUNDING_PERIOD_DAYS: u64 = 120;
 pub const LOCKUP_PERIOD_SECONDS: u64 = 365 * 24 * 60 * 60; // 12 months
 pub const DEFAULT_PLATFORM_FEE_BPS: u16 = 250; // 2.5%
+pub const DEFAULT_MIN_INVESTMENT_AMOUNT: Uint128 = Uint128::new(1_000_000); // 1 NTRN
+pub const MAX_INVESTORS_PER_PROPOSAL: u64 = 500; // Platform-wide holder ceiling for any one proposal
+pub const DEFAULT_DISTRIBUTION_BATCH: u32 = 50; // Investors minted per DistributeTokensBatch call
+pub const MAX_DISTRIBUTION_BATCH: u32 = 100;
+pub const MAX_EXPECTED_APY_BPS: u16 = 5_000; // 50%; anything higher is a typo or not credible
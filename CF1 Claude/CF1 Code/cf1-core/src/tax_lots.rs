@@ -0,0 +1,188 @@
+//! Cost-basis lots behind each position.
+//!
+//! A position merges every purchase into one amount and share count. Lots keep each purchase
+//! apart with its own date, price and cost, and follow the shares: a transfer or reissue moves
+//! lots oldest first, and a withdrawal closes them with the position.
+
+use cosmwasm_std::{Addr, Deps, StdResult, Storage, Uint128};
+use cw_storage_plus::Map;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::state::{Investment, InvestmentStatus, INVESTMENTS};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TaxLot {
+    pub receipt_id: Option<u64>, // Purchase that opened the lot; None for positions opened before lots
+    pub acquired_at: u64,
+    pub shares: u64,
+    pub cost: Uint128,
+    pub token_price: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CostBasisResponse {
+    pub proposal_id: String,
+    pub investor: Addr,
+    pub status: Option<InvestmentStatus>, // None when the user holds no position
+    pub lots: Vec<TaxLot>,                // Oldest first
+    pub total_shares: u64,
+    pub total_cost: Uint128,
+}
+
+// Storage keys
+pub const TAX_LOTS: Map<(String, &Addr), Vec<TaxLot>> = Map::new("tax_lots"); // (proposal_id, holder) -> lots
+
+/// Add a purchase to the holder's lots
+pub fn open(
+    storage: &mut dyn Storage,
+    proposal_id: &str,
+    holder: &Addr,
+    lot: TaxLot,
+) -> StdResult<()> {
+    let mut lots = TAX_LOTS
+        .may_load(storage, (proposal_id.to_string(), holder))?
+        .unwrap_or_default();
+    lots.push(lot);
+    TAX_LOTS.save(storage, (proposal_id.to_string(), holder), &lots)
+}
+
+/// Drop every lot of a position that no longer exists
+pub fn close(storage: &mut dyn Storage, proposal_id: &str, holder: &Addr) {
+    TAX_LOTS.remove(storage, (proposal_id.to_string(), holder));
+}
+
+/// Move `shares` from one holder to another, oldest lots first.
+///
+/// A partly moved lot is split, with its cost shared in proportion to the shares. The moved
+/// lots keep their acquisition date and price.
+pub fn transfer(
+    storage: &mut dyn Storage,
+    proposal_id: &str,
+    from: &Addr,
+    to: &Addr,
+    shares: u64,
+) -> StdResult<()> {
+    let mut remaining = TAX_LOTS
+        .may_load(storage, (proposal_id.to_string(), from))?
+        .unwrap_or_default();
+    let mut moved = vec![];
+    let mut left = shares;
+    while left > 0 && !remaining.is_empty() {
+        let lot = &mut remaining[0];
+        if lot.shares <= left {
+            left -= lot.shares;
+            moved.push(remaining.remove(0));
+        } else {
+            let cost = lot.cost.multiply_ratio(left, lot.shares);
+            lot.shares -= left;
+            lot.cost -= cost;
+            moved.push(TaxLot {
+                shares: left,
+                cost,
+                ..lot.clone()
+            });
+            left = 0;
+        }
+    }
+
+    if remaining.is_empty() {
+        TAX_LOTS.remove(storage, (proposal_id.to_string(), from));
+    } else {
+        TAX_LOTS.save(storage, (proposal_id.to_string(), from), &remaining)?;
+    }
+    let mut received = TAX_LOTS
+        .may_load(storage, (proposal_id.to_string(), to))?
+        .unwrap_or_default();
+    received.extend(moved);
+    received.sort_by_key(|lot| lot.acquired_at);
+    TAX_LOTS.save(storage, (proposal_id.to_string(), to), &received)
+}
+
+/// Lot for a position that predates lot tracking, at its average price
+pub fn legacy_lot(investment: &Investment) -> TaxLot {
+    TaxLot {
+        receipt_id: None,
+        acquired_at: investment.timestamp,
+        shares: investment.shares,
+        cost: investment.amount,
+        token_price: if investment.shares == 0 {
+            Uint128::zero()
+        } else {
+            investment.amount / Uint128::from(investment.shares)
+        },
+    }
+}
+
+// Query functions
+pub fn query_cost_basis(
+    deps: Deps,
+    proposal_id: String,
+    user: String,
+) -> StdResult<CostBasisResponse> {
+    let investor = deps.api.addr_validate(&user)?;
+    let lots = TAX_LOTS
+        .may_load(deps.storage, (proposal_id.clone(), &investor))?
+        .unwrap_or_default();
+    let status = INVESTMENTS
+        .may_load(deps.storage, (proposal_id.clone(), &investor))?
+        .map(|investment| investment.status);
+
+    Ok(CostBasisResponse {
+        total_shares: lots.iter().map(|lot| lot.shares).sum(),
+        total_cost: lots.iter().map(|lot| lot.cost).sum(),
+        proposal_id,
+        investor,
+        status,
+        lots,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::MockStorage;
+
+    fn lot(receipt_id: u64, acquired_at: u64, shares: u64, token_price: u128) -> TaxLot {
+        TaxLot {
+            receipt_id: Some(receipt_id),
+            acquired_at,
+            shares,
+            cost: Uint128::new(shares as u128 * token_price),
+            token_price: Uint128::new(token_price),
+        }
+    }
+
+    #[test]
+    fn test_transfer_moves_oldest_lots_first() {
+        let mut storage = MockStorage::new();
+        let alice = Addr::unchecked("alice");
+        let bob = Addr::unchecked("bob");
+        open(&mut storage, "p1", &alice, lot(1, 100, 3, 10)).unwrap();
+        open(&mut storage, "p1", &alice, lot(2, 200, 4, 15)).unwrap();
+        open(&mut storage, "p1", &bob, lot(3, 150, 1, 12)).unwrap();
+
+        // The whole first lot and one share of the second
+        transfer(&mut storage, "p1", &alice, &bob, 4).unwrap();
+
+        let lots = |storage: &MockStorage, holder: &Addr| {
+            TAX_LOTS.load(storage, ("p1".to_string(), holder)).unwrap()
+        };
+        assert_eq!(lots(&storage, &alice), vec![lot(2, 200, 3, 15)]);
+        assert_eq!(
+            lots(&storage, &bob),
+            vec![lot(1, 100, 3, 10), lot(3, 150, 1, 12), lot(2, 200, 1, 15)]
+        );
+
+        // Moving everything leaves no lots behind
+        transfer(&mut storage, "p1", &alice, &bob, 3).unwrap();
+        assert!(!TAX_LOTS.has(&storage, ("p1".to_string(), &alice)));
+        assert_eq!(
+            lots(&storage, &bob)
+                .iter()
+                .map(|lot| lot.shares)
+                .sum::<u64>(),
+            8
+        );
+    }
+}
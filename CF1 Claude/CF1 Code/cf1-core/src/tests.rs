@@ -1,8 +1,7 @@
 #[cfg(test)]
 mod tests {
-    use super::*;
-    use crate::{execute, instantiate, query, reply};
-    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use crate::{execute, instantiate, query};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
     use cosmwasm_std::{coins, from_json, Addr, Uint128};
     use cw_multi_test::{App, AppBuilder, Contract, ContractWrapper, Executor};
 
@@ -16,31 +15,37 @@ mod tests {
         Box::new(contract)
     }
 
+    fn contract_cw20() -> Box<dyn Contract<cosmwasm_std::Empty>> {
+        let contract = ContractWrapper::new(
+            cf1_restricted_cw20::contract::execute,
+            cf1_restricted_cw20::contract::instantiate,
+            cf1_restricted_cw20::contract::query,
+        );
+        Box::new(contract)
+    }
+
     // Test helper functions
+    fn addr(name: &str) -> Addr {
+        // Same derivation as App::api(), so these match multitest senders
+        cosmwasm_std::testing::MockApi::default().addr_make(name)
+    }
+
     fn mock_app() -> App {
         AppBuilder::new().build(|router, _, storage| {
             router
                 .bank
-                .init_balance(
-                    storage,
-                    &Addr::unchecked("user"),
-                    coins(1_000_000_000, "untrn"),
-                )
+                .init_balance(storage, &addr("user"), coins(2_000_000_000_000, "untrn"))
                 .unwrap();
             router
                 .bank
-                .init_balance(
-                    storage,
-                    &Addr::unchecked("creator"),
-                    coins(1_000_000_000, "untrn"),
-                )
+                .init_balance(storage, &addr("creator"), coins(1_000_000_000, "untrn"))
                 .unwrap();
         })
     }
 
-    fn proper_instantiate() -> (App, u64) {
+    fn proper_instantiate() -> (App, Addr) {
         let mut app = mock_app();
-        let cw20_id = app.store_code(contract_launchpad());
+        let cw20_id = app.store_code(contract_cw20());
         let launchpad_id = app.store_code(contract_launchpad());
 
         let msg = InstantiateMsg {
@@ -52,7 +57,7 @@ mod tests {
         let launchpad_addr = app
             .instantiate_contract(
                 launchpad_id,
-                Addr::unchecked("admin"),
+                addr("admin"),
                 &msg,
                 &[],
                 "CF1-Launchpad",
@@ -60,7 +65,20 @@ mod tests {
             )
             .unwrap();
 
-        (app, launchpad_id)
+        // Fixture proposals require KYC
+        app.execute_contract(
+            addr("admin"),
+            launchpad_addr.clone(),
+            &ExecuteMsg::AddVerifiedInvestor {
+                investor: addr("user").to_string(),
+                tier: crate::kyc::VerificationTier::Retail,
+                expires_at: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+        (app, launchpad_addr)
     }
 
     fn create_test_proposal() -> (AssetDetails, FinancialTerms, Vec<Document>, ComplianceInfo) {
@@ -71,25 +89,37 @@ mod tests {
             location: "Seattle, WA".to_string(),
             description: "Premium office building".to_string(),
             full_description: "A premium office building in downtown Seattle with excellent rental yield potential.".to_string(),
+            token_metadata: None,
             risk_factors: vec!["Market volatility".to_string(), "Interest rate changes".to_string()],
             highlights: vec!["Prime location".to_string(), "Stable tenants".to_string()],
         };
 
-        let current_time = 1640995200u64; // Mock timestamp
+        let current_time = mock_env().block.time.seconds(); // Same as the multitest genesis block
         let financial_terms = FinancialTerms {
             target_amount: Uint128::from(1_000_000_000_000u128), // $1M in micro units
             token_price: Uint128::from(100_000_000u128),         // $100 in micro units
             total_shares: 10_000u64,
             minimum_investment: Uint128::from(500_000_000u128), // $500 in micro units
-            expected_apy: "8.5%".to_string(),
+            expected_apy_bps: 850,
             funding_deadline: current_time + (30 * 24 * 60 * 60), // 30 days from now
+            allow_partial_fill: false,
+            milestones: vec![],
+            usd_terms: None,
+            soft_cap: None,
+            max_raise: None,
+            pricing_tiers: vec![],
+            creator_vesting: None,
+            lockup_tranches: vec![],
+            token_name: None,
+            token_symbol: None,
+            withdrawal_terms: Default::default(),
         };
 
         let documents = vec![Document {
             name: "Business Plan".to_string(),
             doc_type: "PDF".to_string(),
             size: "2.5MB".to_string(),
-            hash: Some("QmTest123".to_string()),
+            hash: Some("QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_string()),
         }];
 
         let compliance = ComplianceInfo {
@@ -97,6 +127,7 @@ mod tests {
             accredited_only: false,
             max_investors: Some(500),
             compliance_notes: vec!["SEC Regulation CF compliant".to_string()],
+            max_investment_per_user: None,
         };
 
         (asset_details, financial_terms, documents, compliance)
@@ -106,7 +137,7 @@ mod tests {
     fn test_instantiate() {
         let mut deps = mock_dependencies();
         let env = mock_env();
-        let info = mock_info("admin", &[]);
+        let info = cosmwasm_std::testing::message_info(&addr("admin"), &[]);
 
         let msg = InstantiateMsg {
             admin: None,
@@ -115,15 +146,16 @@ mod tests {
         };
 
         let res = instantiate(deps.as_mut(), env, info, msg).unwrap();
-        assert_eq!(res.attributes.len(), 2);
-        assert_eq!(res.attributes[0].key, "method");
-        assert_eq!(res.attributes[0].value, "instantiate");
+        assert!(res.attributes.is_empty());
+        assert_eq!(res.events[0].ty, crate::events::event_type("init"));
+        assert_eq!(res.events[0].attributes.len(), 2);
+        assert_eq!(res.events[0].attributes[0].key, "action");
+        assert_eq!(res.events[0].attributes[0].value, "init");
     }
 
     #[test]
     fn test_create_proposal() {
-        let (mut app, launchpad_id) = proper_instantiate();
-        let launchpad_addr = Addr::unchecked("contract0");
+        let (mut app, launchpad_addr) = proper_instantiate();
 
         let (asset_details, financial_terms, documents, compliance) = create_test_proposal();
 
@@ -135,28 +167,22 @@ mod tests {
         };
 
         let res = app
-            .execute_contract(
-                Addr::unchecked("creator"),
-                launchpad_addr.clone(),
-                &msg,
-                &[],
-            )
+            .execute_contract(addr("creator"), launchpad_addr.clone(), &msg, &[])
             .unwrap();
 
         assert!(res.events.iter().any(|e| e
             .attributes
             .iter()
-            .any(|a| a.key == "method" && a.value == "create_proposal")));
+            .any(|a| a.key == "action" && a.value == "create")));
         assert!(res.events.iter().any(|e| e
             .attributes
             .iter()
-            .any(|a| a.key == "proposal_id" && a.value == "proposal_1")));
+            .any(|a| a.key == "proposal_id" && a.value == "p1")));
     }
 
     #[test]
     fn test_query_proposal() {
-        let (mut app, _) = proper_instantiate();
-        let launchpad_addr = Addr::unchecked("contract0");
+        let (mut app, launchpad_addr) = proper_instantiate();
 
         // Create a proposal first
         let (asset_details, financial_terms, documents, compliance) = create_test_proposal();
@@ -167,17 +193,12 @@ mod tests {
             compliance,
         };
 
-        app.execute_contract(
-            Addr::unchecked("creator"),
-            launchpad_addr.clone(),
-            &create_msg,
-            &[],
-        )
-        .unwrap();
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &create_msg, &[])
+            .unwrap();
 
         // Query the proposal
         let query_msg = QueryMsg::Proposal {
-            proposal_id: "proposal_1".to_string(),
+            proposal_id: "p1".to_string(),
         };
 
         let res: crate::msg::ProposalResponse = app
@@ -185,15 +206,51 @@ mod tests {
             .query_wasm_smart(launchpad_addr, &query_msg)
             .unwrap();
 
-        assert_eq!(res.proposal.id, "proposal_1");
+        assert_eq!(res.proposal.id, "p1");
         assert_eq!(res.proposal.asset_details.name, "Test Real Estate");
-        assert_eq!(res.proposal.creator, Addr::unchecked("creator"));
+        assert_eq!(res.proposal.creator, addr("creator"));
+        assert_eq!(res.expected_apy, "8.50%");
+    }
+
+    #[test]
+    fn test_expected_apy_bps_validated_and_read_from_legacy_strings() {
+        let (mut app, launchpad_addr) = proper_instantiate();
+        let (asset_details, mut financial_terms, documents, compliance) = create_test_proposal();
+
+        // Proposals stored before basis points carried a free-form string
+        let json = String::from_utf8(cosmwasm_std::to_json_vec(&financial_terms).unwrap()).unwrap();
+        for (legacy, bps) in [("\"7.25%\"", 725), ("\"Variable\"", 0)] {
+            let legacy_json = json.replace(
+                "\"expected_apy_bps\":850",
+                &format!("\"expected_apy\":{}", legacy),
+            );
+            let terms: FinancialTerms = from_json(legacy_json.as_bytes()).unwrap();
+            assert_eq!(terms.expected_apy_bps, bps);
+        }
+
+        financial_terms.expected_apy_bps = 5_001;
+        let err = app
+            .execute_contract(
+                addr("creator"),
+                launchpad_addr,
+                &ExecuteMsg::CreateProposal {
+                    asset_details,
+                    financial_terms,
+                    documents,
+                    compliance,
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert!(err
+            .root_cause()
+            .to_string()
+            .contains("cannot exceed 50.00%"));
     }
 
     #[test]
     fn test_invest_in_proposal() {
-        let (mut app, _) = proper_instantiate();
-        let launchpad_addr = Addr::unchecked("contract0");
+        let (mut app, launchpad_addr) = proper_instantiate();
 
         // Create a proposal first
         let (asset_details, financial_terms, documents, compliance) = create_test_proposal();
@@ -204,24 +261,21 @@ mod tests {
             compliance,
         };
 
-        app.execute_contract(
-            Addr::unchecked("creator"),
-            launchpad_addr.clone(),
-            &create_msg,
-            &[],
-        )
-        .unwrap();
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &create_msg, &[])
+            .unwrap();
 
         // Invest in the proposal
         let invest_msg = ExecuteMsg::Invest {
-            proposal_id: "proposal_1".to_string(),
+            proposal_id: "p1".to_string(),
+            operation_id: None,
+            referrer: None,
         };
 
         let investment_amount = coins(1_000_000_000, "untrn"); // $1000 investment
 
         let res = app
             .execute_contract(
-                Addr::unchecked("user"),
+                addr("user"),
                 launchpad_addr.clone(),
                 &invest_msg,
                 &investment_amount,
@@ -231,7 +285,7 @@ mod tests {
         assert!(res.events.iter().any(|e| e
             .attributes
             .iter()
-            .any(|a| a.key == "method" && a.value == "invest")));
+            .any(|a| a.key == "action" && a.value == "invest")));
         assert!(res.events.iter().any(|e| e
             .attributes
             .iter()
@@ -240,8 +294,7 @@ mod tests {
 
     #[test]
     fn test_funding_completion() {
-        let (mut app, _) = proper_instantiate();
-        let launchpad_addr = Addr::unchecked("contract0");
+        let (mut app, launchpad_addr) = proper_instantiate();
 
         // Create a proposal
         let (asset_details, financial_terms, documents, compliance) = create_test_proposal();
@@ -252,24 +305,21 @@ mod tests {
             compliance,
         };
 
-        app.execute_contract(
-            Addr::unchecked("creator"),
-            launchpad_addr.clone(),
-            &create_msg,
-            &[],
-        )
-        .unwrap();
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &create_msg, &[])
+            .unwrap();
 
         // Invest the full target amount
         let invest_msg = ExecuteMsg::Invest {
-            proposal_id: "proposal_1".to_string(),
+            proposal_id: "p1".to_string(),
+            operation_id: None,
+            referrer: None,
         };
 
         let full_investment = coins(1_000_000_000_000, "untrn"); // Full $1M target
 
         let res = app
             .execute_contract(
-                Addr::unchecked("user"),
+                addr("user"),
                 launchpad_addr.clone(),
                 &invest_msg,
                 &full_investment,
@@ -285,8 +335,7 @@ mod tests {
 
     #[test]
     fn test_investment_below_minimum() {
-        let (mut app, _) = proper_instantiate();
-        let launchpad_addr = Addr::unchecked("contract0");
+        let (mut app, launchpad_addr) = proper_instantiate();
 
         // Create a proposal
         let (asset_details, financial_terms, documents, compliance) = create_test_proposal();
@@ -297,43 +346,41 @@ mod tests {
             compliance,
         };
 
-        app.execute_contract(
-            Addr::unchecked("creator"),
-            launchpad_addr.clone(),
-            &create_msg,
-            &[],
-        )
-        .unwrap();
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &create_msg, &[])
+            .unwrap();
 
         // Try to invest below minimum
         let invest_msg = ExecuteMsg::Invest {
-            proposal_id: "proposal_1".to_string(),
+            proposal_id: "p1".to_string(),
+            operation_id: None,
+            referrer: None,
         };
 
         let low_investment = coins(100_000_000, "untrn"); // $100, below $500 minimum
 
         let err = app
             .execute_contract(
-                Addr::unchecked("user"),
+                addr("user"),
                 launchpad_addr.clone(),
                 &invest_msg,
                 &low_investment,
             )
             .unwrap_err();
 
-        assert!(err.to_string().contains("Investment amount below minimum"));
+        assert!(err
+            .root_cause()
+            .to_string()
+            .contains("Investment amount below minimum"));
     }
 
     #[test]
     fn test_invalid_funding_period() {
-        let (mut app, _) = proper_instantiate();
-        let launchpad_addr = Addr::unchecked("contract0");
+        let (mut app, launchpad_addr) = proper_instantiate();
 
-        let (mut asset_details, mut financial_terms, documents, compliance) =
-            create_test_proposal();
+        let (asset_details, mut financial_terms, documents, compliance) = create_test_proposal();
 
         // Set funding deadline too short (less than 7 days)
-        financial_terms.funding_deadline = 1640995200u64 + (5 * 24 * 60 * 60); // 5 days
+        financial_terms.funding_deadline = mock_env().block.time.seconds() + (5 * 24 * 60 * 60); // 5 days
 
         let create_msg = ExecuteMsg::CreateProposal {
             asset_details,
@@ -343,21 +390,18 @@ mod tests {
         };
 
         let err = app
-            .execute_contract(
-                Addr::unchecked("creator"),
-                launchpad_addr.clone(),
-                &create_msg,
-                &[],
-            )
+            .execute_contract(addr("creator"), launchpad_addr.clone(), &create_msg, &[])
             .unwrap_err();
 
-        assert!(err.to_string().contains("Funding period too short"));
+        assert!(err
+            .root_cause()
+            .to_string()
+            .contains("Funding period too short"));
     }
 
     #[test]
     fn test_update_proposal() {
-        let (mut app, _) = proper_instantiate();
-        let launchpad_addr = Addr::unchecked("contract0");
+        let (mut app, launchpad_addr) = proper_instantiate();
 
         // Create a proposal first
         let (asset_details, financial_terms, documents, compliance) = create_test_proposal();
@@ -368,41 +412,31 @@ mod tests {
             compliance,
         };
 
-        app.execute_contract(
-            Addr::unchecked("creator"),
-            launchpad_addr.clone(),
-            &create_msg,
-            &[],
-        )
-        .unwrap();
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &create_msg, &[])
+            .unwrap();
 
         // Update the proposal
         let mut updated_asset_details = asset_details.clone();
         updated_asset_details.description = "Updated description".to_string();
 
         let update_msg = ExecuteMsg::UpdateProposal {
-            proposal_id: "proposal_1".to_string(),
+            proposal_id: "p1".to_string(),
             asset_details: Some(updated_asset_details),
             documents: None,
         };
 
         let res = app
-            .execute_contract(
-                Addr::unchecked("creator"),
-                launchpad_addr.clone(),
-                &update_msg,
-                &[],
-            )
+            .execute_contract(addr("creator"), launchpad_addr.clone(), &update_msg, &[])
             .unwrap();
 
         assert!(res.events.iter().any(|e| e
             .attributes
             .iter()
-            .any(|a| a.key == "method" && a.value == "update_proposal")));
+            .any(|a| a.key == "action" && a.value == "update")));
 
         // Verify the update
         let query_msg = QueryMsg::Proposal {
-            proposal_id: "proposal_1".to_string(),
+            proposal_id: "p1".to_string(),
         };
 
         let res: crate::msg::ProposalResponse = app
@@ -418,8 +452,7 @@ mod tests {
 
     #[test]
     fn test_unauthorized_update() {
-        let (mut app, _) = proper_instantiate();
-        let launchpad_addr = Addr::unchecked("contract0");
+        let (mut app, launchpad_addr) = proper_instantiate();
 
         // Create a proposal first
         let (asset_details, financial_terms, documents, compliance) = create_test_proposal();
@@ -430,37 +463,26 @@ mod tests {
             compliance,
         };
 
-        app.execute_contract(
-            Addr::unchecked("creator"),
-            launchpad_addr.clone(),
-            &create_msg,
-            &[],
-        )
-        .unwrap();
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &create_msg, &[])
+            .unwrap();
 
         // Try to update as non-creator
         let update_msg = ExecuteMsg::UpdateProposal {
-            proposal_id: "proposal_1".to_string(),
+            proposal_id: "p1".to_string(),
             asset_details: None,
             documents: None,
         };
 
         let err = app
-            .execute_contract(
-                Addr::unchecked("user"),
-                launchpad_addr.clone(),
-                &update_msg,
-                &[],
-            )
+            .execute_contract(addr("user"), launchpad_addr.clone(), &update_msg, &[])
             .unwrap_err();
 
-        assert!(err.to_string().contains("Unauthorized"));
+        assert!(err.root_cause().to_string().contains("Unauthorized"));
     }
 
     #[test]
     fn test_query_proposals_by_creator() {
-        let (mut app, _) = proper_instantiate();
-        let launchpad_addr = Addr::unchecked("contract0");
+        let (mut app, launchpad_addr) = proper_instantiate();
 
         // Create multiple proposals
         for i in 0..3 {
@@ -475,18 +497,13 @@ mod tests {
                 compliance,
             };
 
-            app.execute_contract(
-                Addr::unchecked("creator"),
-                launchpad_addr.clone(),
-                &create_msg,
-                &[],
-            )
-            .unwrap();
+            app.execute_contract(addr("creator"), launchpad_addr.clone(), &create_msg, &[])
+                .unwrap();
         }
 
         // Query proposals by creator
         let query_msg = QueryMsg::ProposalsByCreator {
-            creator: "creator".to_string(),
+            creator: addr("creator").to_string(),
             start_after: None,
             limit: None,
         };
@@ -502,8 +519,7 @@ mod tests {
 
     #[test]
     fn test_platform_stats() {
-        let (mut app, _) = proper_instantiate();
-        let launchpad_addr = Addr::unchecked("contract0");
+        let (mut app, launchpad_addr) = proper_instantiate();
 
         // Create and fund a proposal
         let (asset_details, financial_terms, documents, compliance) = create_test_proposal();
@@ -514,21 +530,18 @@ mod tests {
             compliance,
         };
 
-        app.execute_contract(
-            Addr::unchecked("creator"),
-            launchpad_addr.clone(),
-            &create_msg,
-            &[],
-        )
-        .unwrap();
+        app.execute_contract(addr("creator"), launchpad_addr.clone(), &create_msg, &[])
+            .unwrap();
 
         // Invest in the proposal
         let invest_msg = ExecuteMsg::Invest {
-            proposal_id: "proposal_1".to_string(),
+            proposal_id: "p1".to_string(),
+            operation_id: None,
+            referrer: None,
         };
 
         app.execute_contract(
-            Addr::unchecked("user"),
+            addr("user"),
             launchpad_addr.clone(),
             &invest_msg,
             &coins(500_000_000_000, "untrn"),
@@ -548,4 +561,1091 @@ mod tests {
         assert_eq!(res.total_raised, Uint128::from(500_000_000_000u128));
         assert_eq!(res.total_investors, 1);
     }
+
+    // Helpers driving the entry points directly against mock storage
+    fn setup_mock_launchpad() -> (
+        cosmwasm_std::OwnedDeps<
+            cosmwasm_std::testing::MockStorage,
+            cosmwasm_std::testing::MockApi,
+            cosmwasm_std::testing::MockQuerier,
+        >,
+        cosmwasm_std::Env,
+    ) {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let admin = deps.api.addr_make("admin");
+
+        let msg = InstantiateMsg {
+            admin: None,
+            platform_fee_bps: Some(250),
+            cw20_code_id: 1,
+        };
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            cosmwasm_std::testing::message_info(&admin, &[]),
+            msg,
+        )
+        .unwrap();
+
+        (deps, env)
+    }
+
+    fn verify_investor(
+        deps: cosmwasm_std::DepsMut,
+        env: &cosmwasm_std::Env,
+        investor: &Addr,
+        tier: crate::kyc::VerificationTier,
+    ) {
+        let admin = cosmwasm_std::testing::MockApi::default().addr_make("admin");
+        execute(
+            deps,
+            env.clone(),
+            cosmwasm_std::testing::message_info(&admin, &[]),
+            ExecuteMsg::AddVerifiedInvestor {
+                investor: investor.to_string(),
+                tier,
+                expires_at: None,
+            },
+        )
+        .unwrap();
+    }
+
+    fn mock_create_proposal_msg(env: &cosmwasm_std::Env) -> ExecuteMsg {
+        let (asset_details, mut financial_terms, documents, compliance) = create_test_proposal();
+        financial_terms.funding_deadline = env.block.time.seconds() + (30 * 24 * 60 * 60);
+
+        ExecuteMsg::CreateProposal {
+            asset_details,
+            financial_terms,
+            documents,
+            compliance,
+        }
+    }
+
+    #[test]
+    fn test_large_investment_requires_cosign() {
+        let (mut deps, mut env) = setup_mock_launchpad();
+        let admin = deps.api.addr_make("admin");
+        let creator = deps.api.addr_make("creator");
+        let investor = deps.api.addr_make("investor");
+        verify_investor(
+            deps.as_mut(),
+            &env,
+            &investor,
+            crate::kyc::VerificationTier::Retail,
+        );
+        let secondary = deps.api.addr_make("secondary");
+        let info = cosmwasm_std::testing::message_info;
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info(&creator, &[]),
+            mock_create_proposal_msg(&env),
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info(&admin, &[]),
+            ExecuteMsg::UpdateCosignConfig {
+                threshold: Some(Uint128::new(10_000_000_000)),
+                approval_window_seconds: Some(3600),
+            },
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info(&investor, &[]),
+            ExecuteMsg::RegisterSecondaryKey {
+                key: secondary.to_string(),
+            },
+        )
+        .unwrap();
+
+        // Large ticket is parked instead of entering escrow
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            info(&investor, &coins(20_000_000_000, "untrn")),
+            ExecuteMsg::Invest {
+                proposal_id: "p1".to_string(),
+                operation_id: None,
+                referrer: None,
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            res.events[0].ty,
+            crate::events::event_type("invest_pending_cosign")
+        );
+        assert!(crate::state::INVESTMENTS
+            .may_load(&deps.storage, ("p1".to_string(), &investor))
+            .unwrap()
+            .is_none());
+
+        // Only the admin or the registered secondary key may confirm
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info(&creator, &[]),
+            ExecuteMsg::ConfirmInvestment { ticket_id: 1 },
+        )
+        .unwrap_err();
+        assert!(matches!(err, crate::error::ContractError::Unauthorized {}));
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info(&secondary, &[]),
+            ExecuteMsg::ConfirmInvestment { ticket_id: 1 },
+        )
+        .unwrap();
+        let investment = crate::state::INVESTMENTS
+            .load(&deps.storage, ("p1".to_string(), &investor))
+            .unwrap();
+        assert_eq!(investment.amount, Uint128::new(20_000_000_000));
+
+        // An unconfirmed ticket can be reclaimed once its window lapses
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info(&investor, &coins(15_000_000_000, "untrn")),
+            ExecuteMsg::Invest {
+                proposal_id: "p1".to_string(),
+                operation_id: None,
+                referrer: None,
+            },
+        )
+        .unwrap();
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info(&investor, &[]),
+            ExecuteMsg::ReclaimPendingInvestment {
+                ticket_id: 2,
+                operation_id: None,
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            crate::error::ContractError::CosignPending { ticket_id: 2 }
+        ));
+
+        env.block.time = env.block.time.plus_seconds(3601);
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            info(&investor, &[]),
+            ExecuteMsg::ReclaimPendingInvestment {
+                ticket_id: 2,
+                operation_id: None,
+            },
+        )
+        .unwrap();
+        assert_eq!(res.messages.len(), 1);
+    }
+
+    #[test]
+    fn test_relayed_cosign_signature() {
+        use crate::signatures::{SignDoc, SignedAuthorization};
+        use k256::ecdsa::{signature::hazmat::PrehashSigner, Signature, SigningKey};
+
+        let (mut deps, env) = setup_mock_launchpad();
+        let admin = deps.api.addr_make("admin");
+        let creator = deps.api.addr_make("creator");
+        let investor = deps.api.addr_make("investor");
+        let secondary = deps.api.addr_make("secondary");
+        let relayer = deps.api.addr_make("relayer");
+        verify_investor(
+            deps.as_mut(),
+            &env,
+            &investor,
+            crate::kyc::VerificationTier::Retail,
+        );
+        let info = cosmwasm_std::testing::message_info;
+        let key = SigningKey::from_slice(&[9u8; 32]).unwrap();
+
+        for (sender, msg) in [
+            (&creator, mock_create_proposal_msg(&env)),
+            (
+                &admin,
+                ExecuteMsg::UpdateCosignConfig {
+                    threshold: Some(Uint128::new(10_000_000_000)),
+                    approval_window_seconds: None,
+                },
+            ),
+            (
+                &investor,
+                ExecuteMsg::RegisterSecondaryKey {
+                    key: secondary.to_string(),
+                },
+            ),
+            (
+                &secondary,
+                ExecuteMsg::RegisterSigningKey {
+                    pubkey: cosmwasm_std::Binary::from(
+                        key.verifying_key().to_encoded_point(true).as_bytes(),
+                    ),
+                },
+            ),
+        ] {
+            execute(deps.as_mut(), env.clone(), info(sender, &[]), msg).unwrap();
+        }
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info(&investor, &coins(20_000_000_000, "untrn")),
+            ExecuteMsg::Invest {
+                proposal_id: "p1".to_string(),
+                operation_id: None,
+                referrer: None,
+            },
+        )
+        .unwrap();
+
+        // The secondary key signs offline; anyone can relay it
+        let doc = SignDoc::new(
+            &env,
+            &secondary,
+            0,
+            "confirm_investment",
+            cosmwasm_std::to_json_binary(&1u64).unwrap(),
+        );
+        let signature: Signature = key.sign_prehash(&doc.digest().unwrap()).unwrap();
+        let authorization = SignedAuthorization {
+            signer: secondary.to_string(),
+            nonce: 0,
+            signature: cosmwasm_std::Binary::from(signature.to_bytes().as_slice()),
+        };
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info(&relayer, &[]),
+            ExecuteMsg::ConfirmInvestmentSigned {
+                ticket_id: 1,
+                authorization: authorization.clone(),
+            },
+        )
+        .unwrap();
+        assert!(crate::state::INVESTMENTS.has(&deps.storage, ("p1".to_string(), &investor)));
+
+        // The same signature cannot be replayed
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info(&relayer, &[]),
+            ExecuteMsg::ConfirmInvestmentSigned {
+                ticket_id: 1,
+                authorization,
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            crate::error::ContractError::InvalidNonce {
+                expected: 1,
+                got: 0
+            }
+        ));
+    }
+
+    #[test]
+    fn test_partial_fill_refunds_change() {
+        let (mut deps, env) = setup_mock_launchpad();
+        let creator = deps.api.addr_make("creator");
+        let investor = deps.api.addr_make("investor");
+        verify_investor(
+            deps.as_mut(),
+            &env,
+            &investor,
+            crate::kyc::VerificationTier::Retail,
+        );
+        let info = cosmwasm_std::testing::message_info;
+
+        let (asset_details, mut financial_terms, documents, compliance) = create_test_proposal();
+        financial_terms.funding_deadline = env.block.time.seconds() + (30 * 24 * 60 * 60);
+        financial_terms.allow_partial_fill = true;
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info(&creator, &[]),
+            ExecuteMsg::CreateProposal {
+                asset_details,
+                financial_terms,
+                documents,
+                compliance,
+            },
+        )
+        .unwrap();
+
+        // 9,000 of 10,000 shares sold
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info(&investor, &coins(900_000_000_000, "untrn")),
+            ExecuteMsg::Invest {
+                proposal_id: "p1".to_string(),
+                operation_id: None,
+                referrer: None,
+            },
+        )
+        .unwrap();
+
+        // Asking for 2,000 more only fills the last 1,000 and refunds the rest
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            info(&investor, &coins(200_000_000_000, "untrn")),
+            ExecuteMsg::Invest {
+                proposal_id: "p1".to_string(),
+                operation_id: None,
+                referrer: None,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            res.messages[0].msg,
+            cosmwasm_std::CosmosMsg::Bank(cosmwasm_std::BankMsg::Send {
+                to_address: investor.to_string(),
+                amount: coins(100_000_000_000, "untrn"),
+            })
+        );
+        let investment = crate::state::INVESTMENTS
+            .load(&deps.storage, ("p1".to_string(), &investor))
+            .unwrap();
+        assert_eq!(investment.shares, 10_000);
+        assert_eq!(investment.amount, Uint128::new(1_000_000_000_000));
+    }
+
+    #[test]
+    fn test_investor_cap_is_cumulative() {
+        let (mut deps, env) = setup_mock_launchpad();
+        let creator = deps.api.addr_make("creator");
+        let investor = deps.api.addr_make("investor");
+        verify_investor(
+            deps.as_mut(),
+            &env,
+            &investor,
+            crate::kyc::VerificationTier::Retail,
+        );
+        let info = cosmwasm_std::testing::message_info;
+
+        let (asset_details, mut financial_terms, documents, mut compliance) =
+            create_test_proposal();
+        financial_terms.funding_deadline = env.block.time.seconds() + (30 * 24 * 60 * 60);
+        compliance.max_investment_per_user = Some(Uint128::new(500_000_000_000));
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info(&creator, &[]),
+            ExecuteMsg::CreateProposal {
+                asset_details,
+                financial_terms,
+                documents,
+                compliance,
+            },
+        )
+        .unwrap();
+
+        let invest = ExecuteMsg::Invest {
+            proposal_id: "p1".to_string(),
+            operation_id: None,
+            referrer: None,
+        };
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info(&investor, &coins(300_000_000_000, "untrn")),
+            invest.clone(),
+        )
+        .unwrap();
+
+        // A second ticket that would cross the cap is rejected
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info(&investor, &coins(300_000_000_000, "untrn")),
+            invest.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            crate::error::ContractError::InvestorCapExceeded { .. }
+        ));
+
+        // Topping up exactly to the cap is fine
+        execute(
+            deps.as_mut(),
+            env,
+            info(&investor, &coins(200_000_000_000, "untrn")),
+            invest,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_proposal_ledger_records_investments() {
+        let (mut deps, env) = setup_mock_launchpad();
+        let creator = deps.api.addr_make("creator");
+        let investor = deps.api.addr_make("investor");
+        verify_investor(
+            deps.as_mut(),
+            &env,
+            &investor,
+            crate::kyc::VerificationTier::Retail,
+        );
+        let info = cosmwasm_std::testing::message_info;
+
+        let (asset_details, mut financial_terms, documents, compliance) = create_test_proposal();
+        financial_terms.funding_deadline = env.block.time.seconds() + (30 * 24 * 60 * 60);
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info(&creator, &[]),
+            ExecuteMsg::CreateProposal {
+                asset_details,
+                financial_terms,
+                documents,
+                compliance,
+            },
+        )
+        .unwrap();
+
+        for amount in [100_000_000_000u128, 200_000_000_000] {
+            execute(
+                deps.as_mut(),
+                env.clone(),
+                info(&investor, &coins(amount, "untrn")),
+                ExecuteMsg::Invest {
+                    proposal_id: "p1".to_string(),
+                    operation_id: None,
+                    referrer: None,
+                },
+            )
+            .unwrap();
+        }
+
+        let res = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::ProposalLedger {
+                proposal_id: "p1".to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+        let ledger: crate::ledger::ProposalLedgerResponse = from_json(&res).unwrap();
+        assert_eq!(ledger.entries.len(), 2);
+        assert_eq!(ledger.entries[1].counterparty, investor);
+        assert_eq!(ledger.escrow_balance, Uint128::new(300_000_000_000));
+
+        // Pagination resumes after the given sequence number
+        let res = query(
+            deps.as_ref(),
+            env,
+            QueryMsg::ProposalLedger {
+                proposal_id: "p1".to_string(),
+                start_after: Some(0),
+                limit: None,
+            },
+        )
+        .unwrap();
+        let page: crate::ledger::ProposalLedgerResponse = from_json(&res).unwrap();
+        assert_eq!(page.entries.len(), 1);
+        assert_eq!(page.entries[0].sequence, 1);
+    }
+
+    #[test]
+    fn test_kyc_required_blocks_unverified_investors() {
+        let (mut deps, mut env) = setup_mock_launchpad();
+        let admin = deps.api.addr_make("admin");
+        let officer = deps.api.addr_make("officer");
+        let creator = deps.api.addr_make("creator");
+        let investor = deps.api.addr_make("investor");
+        let info = cosmwasm_std::testing::message_info;
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info(&creator, &[]),
+            mock_create_proposal_msg(&env),
+        )
+        .unwrap();
+
+        let invest = ExecuteMsg::Invest {
+            proposal_id: "p1".to_string(),
+            operation_id: None,
+            referrer: None,
+        };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info(&investor, &coins(100_000_000_000, "untrn")),
+            invest.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            crate::error::ContractError::InvestorNotVerified { .. }
+        ));
+
+        // Only the admin can designate a compliance officer, who may then verify investors
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info(&admin, &[]),
+            ExecuteMsg::SetComplianceOfficer {
+                officer: Some(officer.to_string()),
+            },
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info(&officer, &[]),
+            ExecuteMsg::AddVerifiedInvestor {
+                investor: investor.to_string(),
+                tier: crate::kyc::VerificationTier::Retail,
+                expires_at: Some(env.block.time.seconds() + 60),
+            },
+        )
+        .unwrap();
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info(&investor, &coins(100_000_000_000, "untrn")),
+            invest.clone(),
+        )
+        .unwrap();
+
+        // Lapsed verification blocks further investment
+        env.block.time = env.block.time.plus_seconds(61);
+        let res = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::VerificationStatus {
+                investor: investor.to_string(),
+            },
+        )
+        .unwrap();
+        let status: crate::kyc::VerificationStatusResponse = from_json(&res).unwrap();
+        assert!(!status.is_verified);
+
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info(&investor, &coins(100_000_000_000, "untrn")),
+            invest,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            crate::error::ContractError::VerificationExpired { .. }
+        ));
+
+        let res = query(
+            deps.as_ref(),
+            env,
+            QueryMsg::VerifiedInvestors {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+        let list: crate::kyc::VerifiedInvestorsResponse = from_json(&res).unwrap();
+        assert_eq!(list.investors.len(), 1);
+        assert_eq!(list.investors[0].verified_by, officer);
+    }
+
+    #[test]
+    fn test_revoke_and_reissue_moves_position() {
+        let (mut deps, env) = setup_mock_launchpad();
+        let admin = deps.api.addr_make("admin");
+        let creator = deps.api.addr_make("creator");
+        let investor = deps.api.addr_make("investor");
+        let corrected = deps.api.addr_make("corrected");
+        let info = cosmwasm_std::testing::message_info;
+        for addr in [&investor, &corrected] {
+            verify_investor(
+                deps.as_mut(),
+                &env,
+                addr,
+                crate::kyc::VerificationTier::Retail,
+            );
+        }
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info(&creator, &[]),
+            mock_create_proposal_msg(&env),
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info(&investor, &coins(100_000_000_000, "untrn")),
+            ExecuteMsg::Invest {
+                proposal_id: "p1".to_string(),
+                operation_id: None,
+                referrer: None,
+            },
+        )
+        .unwrap();
+
+        let revoke = ExecuteMsg::RevokeAndReissueShares {
+            proposal_id: "p1".to_string(),
+            holder: investor.to_string(),
+            reissue_to: corrected.to_string(),
+            reason: "Wrong wallet supplied at subscription".to_string(),
+        };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info(&creator, &[]),
+            revoke.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, crate::error::ContractError::Unauthorized {}));
+
+        execute(deps.as_mut(), env.clone(), info(&admin, &[]), revoke).unwrap();
+
+        assert!(crate::state::INVESTMENTS
+            .may_load(&deps.storage, ("p1".to_string(), &investor))
+            .unwrap()
+            .is_none());
+        let moved = crate::state::INVESTMENTS
+            .load(&deps.storage, ("p1".to_string(), &corrected))
+            .unwrap();
+        assert_eq!(moved.investor, corrected);
+        assert_eq!(moved.shares, 1_000);
+
+        let power =
+            crate::governance::get_user_voting_power(deps.as_ref(), "p1", &corrected).unwrap();
+        assert_eq!(power.voting_power, 1_000);
+
+        let res = query(
+            deps.as_ref(),
+            env,
+            QueryMsg::ShareRevocations {
+                proposal_id: "p1".to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+        let history: crate::revocation::ShareRevocationsResponse = from_json(&res).unwrap();
+        assert_eq!(history.revocations.len(), 1);
+        assert_eq!(history.revocations[0].revoked_from, investor);
+        assert!(!history.revocations[0].tokens_reissued);
+    }
+
+    #[test]
+    fn test_accredited_only_proposal_gating() {
+        let (mut deps, env) = setup_mock_launchpad();
+        let creator = deps.api.addr_make("creator");
+        let retail = deps.api.addr_make("retail");
+        let accredited = deps.api.addr_make("accredited");
+        let info = cosmwasm_std::testing::message_info;
+        verify_investor(
+            deps.as_mut(),
+            &env,
+            &retail,
+            crate::kyc::VerificationTier::Retail,
+        );
+        verify_investor(
+            deps.as_mut(),
+            &env,
+            &accredited,
+            crate::kyc::VerificationTier::Accredited,
+        );
+
+        let (asset_details, mut financial_terms, documents, mut compliance) =
+            create_test_proposal();
+        financial_terms.funding_deadline = env.block.time.seconds() + (30 * 24 * 60 * 60);
+        compliance.accredited_only = true;
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info(&creator, &[]),
+            ExecuteMsg::CreateProposal {
+                asset_details,
+                financial_terms,
+                documents,
+                compliance,
+            },
+        )
+        .unwrap();
+
+        let res = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::InvestorEligibility {
+                proposal_id: "p1".to_string(),
+                investor: retail.to_string(),
+            },
+        )
+        .unwrap();
+        let eligibility: crate::kyc::InvestorEligibilityResponse = from_json(&res).unwrap();
+        assert!(!eligibility.eligible);
+        assert!(!eligibility.is_accredited);
+        assert!(eligibility.reason.is_some());
+
+        let invest = ExecuteMsg::Invest {
+            proposal_id: "p1".to_string(),
+            operation_id: None,
+            referrer: None,
+        };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info(&retail, &coins(100_000_000_000, "untrn")),
+            invest.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            crate::error::ContractError::AccreditationRequired { .. }
+        ));
+
+        execute(
+            deps.as_mut(),
+            env,
+            info(&accredited, &coins(100_000_000_000, "untrn")),
+            invest,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_transfer_hook_msg_parses_as_approve_transfer() {
+        let hook = cf1_types::TransferHookMsg::ApproveTransfer {
+            owner: "holder".to_string(),
+            recipient: "buyer".to_string(),
+            amount: Uint128::new(5),
+        };
+        let msg: ExecuteMsg = from_json(cosmwasm_std::to_json_binary(&hook).unwrap()).unwrap();
+        assert_eq!(
+            msg,
+            ExecuteMsg::ApproveTransfer {
+                owner: "holder".to_string(),
+                recipient: "buyer".to_string(),
+                amount: Uint128::new(5),
+            }
+        );
+    }
+
+    #[test]
+    fn test_interchain_account_payouts_route_to_the_controller() {
+        use crate::ibc::{Ics20Ack, Ics20Packet};
+        use crate::interchain::{self, InterchainAccountResponse};
+        use cosmwasm_std::{
+            BankMsg, CosmosMsg, IbcAckCallbackMsg, IbcAcknowledgement, IbcEndpoint, IbcMsg,
+            IbcPacket, IbcSourceCallbackMsg, IbcTimeout, IbcTimeoutCallbackMsg,
+        };
+
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            cosmwasm_std::testing::message_info(&addr("admin"), &[]),
+            InstantiateMsg {
+                admin: None,
+                platform_fee_bps: Some(250),
+                cw20_code_id: 1,
+            },
+        )
+        .unwrap();
+        let ica = addr("ica");
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            cosmwasm_std::testing::message_info(&ica, &[]),
+            ExecuteMsg::RegisterInterchainAccount {
+                controller_connection_id: "connection-3".to_string(),
+                controller_owner: "osmo1owner".to_string(),
+                transfer_channel_id: "channel-10".to_string(),
+            },
+        )
+        .unwrap();
+
+        // Unverified registrations are still paid on Neutron
+        let amount = Uint128::new(500);
+        assert_eq!(
+            interchain::payout_message(&deps.storage, &env, &ica, amount).unwrap(),
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: ica.to_string(),
+                amount: coins(500, "untrn"),
+            })
+        );
+
+        let verify = ExecuteMsg::VerifyInterchainAccount {
+            address: ica.to_string(),
+        };
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            cosmwasm_std::testing::message_info(&addr("creator"), &[]),
+            verify.clone(),
+        )
+        .unwrap_err();
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            cosmwasm_std::testing::message_info(&addr("admin"), &[]),
+            verify,
+        )
+        .unwrap();
+        let res: InterchainAccountResponse = from_json(
+            query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::InterchainAccount {
+                    address: ica.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert!(res.account.unwrap().verified);
+
+        // Verified accounts are paid over the registered channel with a callback on the result
+        let CosmosMsg::Ibc(IbcMsg::Transfer {
+            channel_id,
+            to_address,
+            amount: sent,
+            timeout,
+            memo,
+        }) = interchain::payout_message(&deps.storage, &env, &ica, amount).unwrap()
+        else {
+            panic!("expected an IBC transfer");
+        };
+        assert_eq!(channel_id, "channel-10");
+        assert_eq!(to_address, "osmo1owner");
+        assert_eq!(sent, cosmwasm_std::Coin::new(500u128, "untrn"));
+        assert_eq!(
+            timeout,
+            IbcTimeout::with_timestamp(
+                env.block
+                    .time
+                    .plus_seconds(interchain::PAYOUT_TIMEOUT_SECONDS)
+            )
+        );
+        assert!(memo.unwrap().contains(env.contract.address.as_str()));
+
+        // A payout that fails or times out goes to the host account instead
+        let packet = IbcPacket::new(
+            cosmwasm_std::to_json_binary(&Ics20Packet {
+                denom: "untrn".to_string(),
+                amount,
+                sender: env.contract.address.to_string(),
+                receiver: "osmo1owner".to_string(),
+                memo: String::new(),
+            })
+            .unwrap(),
+            IbcEndpoint {
+                port_id: "transfer".to_string(),
+                channel_id: "channel-10".to_string(),
+            },
+            IbcEndpoint {
+                port_id: "transfer".to_string(),
+                channel_id: "channel-0".to_string(),
+            },
+            1,
+            timeout,
+        );
+        let returned = vec![cosmwasm_std::SubMsg::new(BankMsg::Send {
+            to_address: ica.to_string(),
+            amount: coins(500, "untrn"),
+        })];
+        let ack = |ack: Ics20Ack| {
+            IbcSourceCallbackMsg::Acknowledgement(IbcAckCallbackMsg::new(
+                IbcAcknowledgement::encode_json(&ack).unwrap(),
+                packet.clone(),
+                addr("relayer"),
+            ))
+        };
+        let res = interchain::ibc_source_callback(
+            deps.as_mut(),
+            env.clone(),
+            ack(Ics20Ack::Result(cosmwasm_std::Binary::from(vec![1]))),
+        )
+        .unwrap();
+        assert!(res.messages.is_empty());
+        let res = interchain::ibc_source_callback(
+            deps.as_mut(),
+            env.clone(),
+            ack(Ics20Ack::Error("receiver rejected".to_string())),
+        )
+        .unwrap();
+        assert_eq!(res.messages, returned);
+        let res = interchain::ibc_source_callback(
+            deps.as_mut(),
+            env,
+            IbcSourceCallbackMsg::Timeout(IbcTimeoutCallbackMsg::new(
+                packet.clone(),
+                addr("relayer"),
+            )),
+        )
+        .unwrap();
+        assert_eq!(res.messages, returned);
+    }
+
+    #[test]
+    fn test_interchain_account_verification_is_rejected_without_role_or_registration() {
+        use crate::interchain;
+        use cosmwasm_std::{BankMsg, CosmosMsg};
+
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            cosmwasm_std::testing::message_info(&addr("admin"), &[]),
+            InstantiateMsg {
+                admin: None,
+                platform_fee_bps: Some(250),
+                cw20_code_id: 1,
+            },
+        )
+        .unwrap();
+        let ica = addr("ica");
+        let verify = ExecuteMsg::VerifyInterchainAccount {
+            address: ica.to_string(),
+        };
+
+        // Nothing to attest before the account registers
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            cosmwasm_std::testing::message_info(&addr("admin"), &[]),
+            verify.clone(),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("has not registered"));
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            cosmwasm_std::testing::message_info(&ica, &[]),
+            ExecuteMsg::RegisterInterchainAccount {
+                controller_connection_id: "connection-3".to_string(),
+                controller_owner: "osmo1owner".to_string(),
+                transfer_channel_id: "channel-10".to_string(),
+            },
+        )
+        .unwrap();
+
+        // The account cannot vouch for itself, so its payouts stay on Neutron
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            cosmwasm_std::testing::message_info(&ica, &[]),
+            verify.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, crate::error::ContractError::Unauthorized {}));
+        assert_eq!(
+            interchain::payout_message(&deps.storage, &env, &ica, Uint128::new(500)).unwrap(),
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: ica.to_string(),
+                amount: coins(500, "untrn"),
+            })
+        );
+
+        // An accepted verification is recorded as the officer's attestation
+        let res = execute(
+            deps.as_mut(),
+            env,
+            cosmwasm_std::testing::message_info(&addr("admin"), &[]),
+            verify,
+        )
+        .unwrap();
+        let attribute = |key: &str| {
+            res.events[0]
+                .attributes
+                .iter()
+                .find(|attr| attr.key == key)
+                .map(|attr| attr.value.clone())
+        };
+        assert_eq!(
+            attribute("verification").as_deref(),
+            Some("unverified_attestation")
+        );
+        assert_eq!(attribute("controller_owner").as_deref(), Some("osmo1owner"));
+    }
+
+    #[test]
+    fn test_rejected_investments_do_not_use_rate_limit_quota() {
+        use crate::rate_limit::RateLimitStatusResponse;
+
+        let (mut deps, env) = setup_mock_launchpad();
+        let creator = deps.api.addr_make("creator");
+        let investor = deps.api.addr_make("investor");
+        let info = cosmwasm_std::testing::message_info;
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info(&creator, &[]),
+            mock_create_proposal_msg(&env),
+        )
+        .unwrap();
+        let invest = ExecuteMsg::Invest {
+            proposal_id: "p1".to_string(),
+            operation_id: None,
+            referrer: None,
+        };
+        let used = |deps: cosmwasm_std::Deps| -> u32 {
+            let status: RateLimitStatusResponse = from_json(
+                query(
+                    deps,
+                    env.clone(),
+                    QueryMsg::RateLimitStatus {
+                        user: investor.to_string(),
+                        operation: "invest".to_string(),
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap();
+            status.used
+        };
+
+        // Handler state is not rolled back here, so a counter written up front would stick
+        for _ in 0..3 {
+            execute(
+                deps.as_mut(),
+                env.clone(),
+                info(&investor, &coins(100_000_000_000, "untrn")),
+                invest.clone(),
+            )
+            .unwrap_err();
+        }
+        assert_eq!(used(deps.as_ref()), 0);
+
+        verify_investor(
+            deps.as_mut(),
+            &env,
+            &investor,
+            crate::kyc::VerificationTier::Retail,
+        );
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info(&investor, &coins(100_000_000_000, "untrn")),
+            invest,
+        )
+        .unwrap();
+        assert_eq!(used(deps.as_ref()), 1);
+    }
 }
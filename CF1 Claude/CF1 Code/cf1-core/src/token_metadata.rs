@@ -0,0 +1,203 @@
+use cosmwasm_std::Storage;
+use cw20::Logo;
+use cw20_base::msg::InstantiateMarketingInfo;
+use cw_storage_plus::Map;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+use crate::state::{FinancialTerms, Proposal, ProposalStatus, PROPOSALS};
+
+/// Longest logo or project URL accepted
+pub const MAX_URL_LENGTH: usize = 256;
+/// Longest token description accepted
+pub const MAX_TOKEN_DESCRIPTION_LENGTH: usize = 1_024;
+
+/// Prefix of generated symbols, kept off-limits for custom ones so the two never collide
+pub const GENERATED_SYMBOL_PREFIX: &str = "CF";
+
+// CW20 limits enforced by cw20-base at instantiation
+const MIN_TOKEN_NAME_BYTES: usize = 3;
+const MAX_TOKEN_NAME_BYTES: usize = 50;
+const MIN_SYMBOL_LENGTH: usize = 3;
+const MAX_SYMBOL_LENGTH: usize = 12;
+const TOKEN_NAME_SUFFIX: &str = " Token";
+
+// Storage keys
+pub const TOKEN_SYMBOLS: Map<&str, String> = Map::new("token_symbols"); // upper-cased custom symbol -> proposal_id
+
+/// Marketing details shown by wallets and explorers for the proposal's share token
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct TokenMetadata {
+    pub logo_url: Option<String>,
+    pub project_url: Option<String>,
+    pub description: Option<String>,
+}
+
+fn validate_url(field: &str, url: &Option<String>) -> Result<(), ContractError> {
+    let Some(url) = url else {
+        return Ok(());
+    };
+    let scheme_ok = ["https://", "ipfs://"]
+        .iter()
+        .any(|scheme| url.starts_with(scheme) && url.len() > scheme.len());
+    if !scheme_ok || url.len() > MAX_URL_LENGTH {
+        return Err(ContractError::InvalidInput {
+            field: field.to_string(),
+            message: format!(
+                "Must be an https:// or ipfs:// URL of at most {} characters",
+                MAX_URL_LENGTH
+            ),
+        });
+    }
+    Ok(())
+}
+
+pub fn validate(metadata: &Option<TokenMetadata>) -> Result<(), ContractError> {
+    let Some(metadata) = metadata else {
+        return Ok(());
+    };
+
+    validate_url("logo_url", &metadata.logo_url)?;
+    validate_url("project_url", &metadata.project_url)?;
+    if metadata
+        .description
+        .as_ref()
+        .is_some_and(|description| description.len() > MAX_TOKEN_DESCRIPTION_LENGTH)
+    {
+        return Err(ContractError::InvalidInput {
+            field: "token_description".to_string(),
+            message: format!(
+                "Description cannot exceed {} characters",
+                MAX_TOKEN_DESCRIPTION_LENGTH
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// CW20 symbols are 3-12 ASCII letters or dashes
+pub fn validate_symbol(symbol: &str) -> Result<(), ContractError> {
+    if symbol.len() < MIN_SYMBOL_LENGTH
+        || symbol.len() > MAX_SYMBOL_LENGTH
+        || !symbol
+            .bytes()
+            .all(|byte| byte.is_ascii_alphabetic() || byte == b'-')
+    {
+        return Err(ContractError::InvalidInput {
+            field: "token_symbol".to_string(),
+            message: format!(
+                "Symbol must be {}-{} letters or dashes",
+                MIN_SYMBOL_LENGTH, MAX_SYMBOL_LENGTH
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Check a creator's custom token name and symbol, and reserve the symbol for this proposal.
+///
+/// Symbols are unique case-insensitively. A symbol held by a proposal that failed or was cancelled
+/// never reached a token, so it can be taken over.
+pub fn reserve_symbol(
+    storage: &mut dyn Storage,
+    terms: &FinancialTerms,
+    proposal_id: &str,
+) -> Result<(), ContractError> {
+    if let Some(name) = &terms.token_name {
+        if name.len() < MIN_TOKEN_NAME_BYTES || name.len() > MAX_TOKEN_NAME_BYTES {
+            return Err(ContractError::InvalidInput {
+                field: "token_name".to_string(),
+                message: format!(
+                    "Name must be {}-{} bytes",
+                    MIN_TOKEN_NAME_BYTES, MAX_TOKEN_NAME_BYTES
+                ),
+            });
+        }
+    }
+    let Some(symbol) = &terms.token_symbol else {
+        return validate_symbol(&generated_symbol(proposal_id));
+    };
+
+    validate_symbol(symbol)?;
+    let key = symbol.to_ascii_uppercase();
+    if key.starts_with(GENERATED_SYMBOL_PREFIX) {
+        return Err(ContractError::InvalidInput {
+            field: "token_symbol".to_string(),
+            message: format!(
+                "Symbols starting with {} are reserved for generated symbols",
+                GENERATED_SYMBOL_PREFIX
+            ),
+        });
+    }
+    if let Some(holder) = TOKEN_SYMBOLS.may_load(storage, &key)? {
+        let released = match PROPOSALS.may_load(storage, holder.clone())? {
+            Some(proposal) => matches!(
+                proposal.status,
+                ProposalStatus::Failed | ProposalStatus::Cancelled
+            ),
+            None => true,
+        };
+        if !released {
+            return Err(ContractError::InvalidInput {
+                field: "token_symbol".to_string(),
+                message: format!("Symbol {} is already used by proposal {}", key, holder),
+            });
+        }
+    }
+    TOKEN_SYMBOLS.save(storage, &key, &proposal_id.to_string())?;
+    Ok(())
+}
+
+/// "<asset name> Token", with the asset name cut down to fit the 50-byte CW20 limit
+fn generated_name(asset_name: &str) -> String {
+    let mut end = asset_name
+        .len()
+        .min(MAX_TOKEN_NAME_BYTES - TOKEN_NAME_SUFFIX.len());
+    while !asset_name.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}{}", asset_name[..end].trim_end(), TOKEN_NAME_SUFFIX)
+}
+
+/// CW20 symbols only allow letters, so digits of the id are mapped to A-J (p12 -> CFBC)
+fn generated_symbol(proposal_id: &str) -> String {
+    GENERATED_SYMBOL_PREFIX
+        .chars()
+        .chain(
+            proposal_id
+                .chars()
+                .filter_map(|c| c.to_digit(10))
+                .map(|d| (b'A' + d as u8) as char),
+        )
+        .collect()
+}
+
+/// The creator's token name, or one derived from the asset name
+pub fn token_name(proposal: &Proposal) -> String {
+    proposal
+        .financial_terms
+        .token_name
+        .clone()
+        .unwrap_or_else(|| generated_name(&proposal.asset_details.name))
+}
+
+/// The creator's token symbol, or one derived from the proposal id
+pub fn token_symbol(proposal: &Proposal) -> String {
+    proposal
+        .financial_terms
+        .token_symbol
+        .clone()
+        .unwrap_or_else(|| generated_symbol(&proposal.id))
+}
+
+/// Marketing block for the token's instantiate message; the creator can update it later
+pub fn marketing_info(proposal: &Proposal) -> Option<InstantiateMarketingInfo> {
+    let metadata = proposal.asset_details.token_metadata.clone()?;
+    Some(InstantiateMarketingInfo {
+        project: metadata.project_url,
+        description: metadata.description,
+        marketing: Some(proposal.creator.to_string()),
+        logo: metadata.logo_url.map(Logo::Url),
+    })
+}
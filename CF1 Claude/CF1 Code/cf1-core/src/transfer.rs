@@ -0,0 +1,237 @@
+use cosmwasm_std::{
+    to_json_binary, Addr, DepsMut, Env, MessageInfo, Response, StdResult, Uint128, WasmMsg,
+};
+use cw20::Cw20ExecuteMsg;
+
+use crate::error::ContractError;
+use crate::security::MathGuard;
+use crate::state::{
+    add_user_investment, remove_user_investment, save_proposal, Investment, InvestmentStatus,
+    ProposalStatus, INVESTMENTS, PROPOSALS, PROPOSAL_INVESTMENTS, TOKEN_CONTRACTS, TOKEN_PROPOSALS,
+};
+
+/// Transfer part or all of a position to another investor once the lockup has expired.
+///
+/// The recipient must pass the deal's KYC / accreditation checks and may not push the holder
+/// count past `compliance.max_investors`. The escrowed amount moves pro rata with the shares. If
+/// tokens were already distributed, the CW20 balance is moved with `TransferFrom`, so the sender
+/// must have granted the launchpad an allowance.
+pub fn transfer_shares(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: String,
+    recipient: String,
+    shares: u64,
+) -> Result<Response, ContractError> {
+    let sender = info.sender;
+    let recipient_addr = deps.api.addr_validate(&recipient)?;
+    let (moved_amount, tokens_moved) = move_position(
+        deps.branch(),
+        &env,
+        &proposal_id,
+        &sender,
+        &recipient_addr,
+        shares,
+    )?;
+
+    // Distributed tokens move with the cap table entry
+    let mut response = Response::new();
+    if tokens_moved {
+        let token_address = TOKEN_CONTRACTS.load(deps.storage, proposal_id.clone())?;
+        response = response.add_message(WasmMsg::Execute {
+            contract_addr: token_address.to_string(),
+            msg: to_json_binary(&Cw20ExecuteMsg::TransferFrom {
+                owner: sender.to_string(),
+                recipient: recipient_addr.to_string(),
+                amount: Uint128::from(shares),
+            })?,
+            funds: vec![],
+        });
+    }
+
+    Ok(response.add_event(crate::events::transfer_shares(
+        &proposal_id,
+        &sender,
+        &recipient_addr,
+        shares,
+        moved_amount,
+        tokens_moved,
+    )))
+}
+
+/// Approve a transfer made directly on a proposal's share token.
+///
+/// Only the token itself may call this. The move goes through the same lockup, KYC and holder
+/// limit checks as `TransferShares` and updates the cap table to match the token balances. Tokens
+/// outside the cap table (the creator's vested allocation) only need an eligible recipient.
+pub fn approve_token_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    owner: String,
+    recipient: String,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    let proposal_id = TOKEN_PROPOSALS
+        .may_load(deps.storage, &info.sender)?
+        .ok_or(ContractError::Unauthorized {})?;
+    let owner = deps.api.addr_validate(&owner)?;
+    let recipient_addr = deps.api.addr_validate(&recipient)?;
+    let shares = u64::try_from(amount.u128()).map_err(|_| ContractError::InvalidInput {
+        field: "amount".to_string(),
+        message: "Transfer exceeds the share supply".to_string(),
+    })?;
+
+    if INVESTMENTS.has(deps.storage, (proposal_id.clone(), &owner)) {
+        move_position(deps, &env, &proposal_id, &owner, &recipient_addr, shares)?;
+    } else {
+        let proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+        crate::retirement::ensure_not_retired(proposal.status, &proposal_id)?;
+        crate::kyc::enforce_verification(deps.storage, &env, &proposal, &recipient_addr)?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "approve_transfer")
+        .add_attribute("proposal_id", &proposal_id)
+        .add_attribute("from", owner)
+        .add_attribute("to", recipient_addr)
+        .add_attribute("shares", shares.to_string()))
+}
+
+/// Move `shares` of `sender`'s position to `recipient` in the cap table.
+///
+/// Returns the escrowed amount that moved with the shares and whether the position had already
+/// been paid out in tokens.
+fn move_position(
+    deps: DepsMut,
+    env: &Env,
+    proposal_id: &str,
+    sender: &Addr,
+    recipient_addr: &Addr,
+    shares: u64,
+) -> Result<(Uint128, bool), ContractError> {
+    if shares == 0 {
+        return Err(ContractError::InvalidInput {
+            field: "shares".to_string(),
+            message: "Must transfer at least one share".to_string(),
+        });
+    }
+    if recipient_addr == sender {
+        return Err(ContractError::InvalidInput {
+            field: "recipient".to_string(),
+            message: "Cannot transfer shares to yourself".to_string(),
+        });
+    }
+    let proposal_id = proposal_id.to_string();
+
+    let mut proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+    crate::retirement::ensure_not_retired(proposal.status, &proposal_id)?;
+    crate::governance::ensure_snapshot_settled(deps.storage, &proposal_id)?;
+    if proposal.status != ProposalStatus::Funded && proposal.status != ProposalStatus::Completed {
+        return Err(ContractError::ProposalNotFunded {});
+    }
+    crate::lockup::validate_transfer_allowed(deps.as_ref(), env, &proposal_id)?;
+
+    let mut from = INVESTMENTS
+        .may_load(deps.storage, (proposal_id.clone(), sender))?
+        .ok_or(ContractError::InvestmentNotFound {})?;
+    if from.status == InvestmentStatus::Refunded {
+        return Err(ContractError::InvalidInput {
+            field: "proposal_id".to_string(),
+            message: "Refunded investments cannot be transferred".to_string(),
+        });
+    }
+    if shares > from.shares {
+        return Err(ContractError::InvalidInput {
+            field: "shares".to_string(),
+            message: format!("Holder only owns {} shares", from.shares),
+        });
+    }
+    crate::lockup::enforce_transfer_allowance(
+        deps.storage,
+        env,
+        &proposal,
+        sender,
+        from.shares,
+        shares,
+    )?;
+
+    crate::kyc::enforce_verification(deps.storage, env, &proposal, recipient_addr)?;
+
+    // The escrowed amount follows the shares; the last share carries any rounding remainder
+    let moved_amount = if shares == from.shares {
+        from.amount
+    } else {
+        from.amount.multiply_ratio(shares, from.shares)
+    };
+
+    let existing = INVESTMENTS.may_load(deps.storage, (proposal_id.clone(), recipient_addr))?;
+    let to = match existing {
+        Some(mut existing) => {
+            if existing.status != from.status {
+                return Err(ContractError::InvalidInput {
+                    field: "recipient".to_string(),
+                    message: "Recipient position is in a different state".to_string(),
+                });
+            }
+            existing.shares = existing.shares.saturating_add(shares);
+            existing.amount = MathGuard::safe_add(existing.amount, moved_amount)?;
+            existing
+        }
+        None => {
+            let max = proposal.compliance.investor_cap();
+            // A full transfer to a new holder leaves the count unchanged
+            if shares < from.shares && proposal.funding_status.investor_count >= max {
+                return Err(ContractError::MaxInvestorsExceeded { max: max as u32 });
+            }
+            proposal.funding_status.investor_count += 1;
+            Investment {
+                investor: recipient_addr.clone(),
+                proposal_id: proposal_id.clone(),
+                amount: moved_amount,
+                shares,
+                timestamp: env.block.time.seconds(),
+                status: from.status.clone(),
+            }
+        }
+    };
+
+    from.shares -= shares;
+    from.amount = MathGuard::safe_sub(from.amount, moved_amount)?;
+    let sender_exits = from.shares == 0;
+    if sender_exits {
+        INVESTMENTS.remove(deps.storage, (proposal_id.clone(), sender));
+        proposal.funding_status.investor_count =
+            proposal.funding_status.investor_count.saturating_sub(1);
+    } else {
+        INVESTMENTS.save(deps.storage, (proposal_id.clone(), sender), &from)?;
+    }
+    INVESTMENTS.save(deps.storage, (proposal_id.clone(), recipient_addr), &to)?;
+    crate::tax_lots::transfer(deps.storage, &proposal_id, sender, recipient_addr, shares)?;
+
+    PROPOSAL_INVESTMENTS.update(
+        deps.storage,
+        proposal_id.clone(),
+        |investors| -> StdResult<_> {
+            let mut investors = investors.unwrap_or_default();
+            if sender_exits {
+                investors.retain(|addr| addr != sender);
+            }
+            if !investors.contains(recipient_addr) {
+                investors.push(recipient_addr.clone());
+            }
+            Ok(investors)
+        },
+    )?;
+    if sender_exits {
+        remove_user_investment(deps.storage, sender, &proposal_id)?;
+    }
+    add_user_investment(deps.storage, recipient_addr, &proposal_id)?;
+
+    proposal.timestamps.updated_at = env.block.time.seconds();
+    save_proposal(deps.storage, &proposal)?;
+    crate::concentration::refresh(deps.storage, env, &proposal_id)?;
+
+    Ok((moved_amount, from.status == InvestmentStatus::Completed))
+}
@@ -0,0 +1,346 @@
+use cosmwasm_std::{
+    Addr, BankMsg, Coin, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult, Storage,
+    Uint128,
+};
+use cw_storage_plus::{Bound, Item, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+use crate::gas_optimization::{DEFAULT_LIMIT, MAX_LIMIT};
+use crate::roles::{self, Role};
+
+/// Internal bucket of the contract's single `untrn` bank balance
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TreasuryAccount {
+    Escrow,        // Investor funds held for proposals and co-sign tickets
+    Fees,          // Platform fees from completed raises and early withdrawals, and slashed bonds
+    InsurancePool, // Reserve for investor protection claims
+    Bonds,         // Deposits posted by creators
+    Escheatment,   // Unclaimed funds awaiting handover
+    Referrals,     // Referrer rewards carved out of platform fees, awaiting claim
+}
+
+impl TreasuryAccount {
+    pub const ALL: [TreasuryAccount; 6] = [
+        TreasuryAccount::Escrow,
+        TreasuryAccount::Fees,
+        TreasuryAccount::InsurancePool,
+        TreasuryAccount::Bonds,
+        TreasuryAccount::Escheatment,
+        TreasuryAccount::Referrals,
+    ];
+
+    fn key(&self) -> &'static str {
+        match self {
+            TreasuryAccount::Escrow => "escrow",
+            TreasuryAccount::Fees => "fees",
+            TreasuryAccount::InsurancePool => "insurance_pool",
+            TreasuryAccount::Bonds => "bonds",
+            TreasuryAccount::Escheatment => "escheatment",
+            TreasuryAccount::Referrals => "referrals",
+        }
+    }
+}
+
+/// Record of funds moved between two buckets without leaving the contract
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TreasuryTransfer {
+    pub sequence: u64,
+    pub from: TreasuryAccount,
+    pub to: TreasuryAccount,
+    pub amount: Uint128,
+    pub memo: String,
+    pub block_height: u64,
+    pub timestamp: u64,
+}
+
+/// Accrued platform fees paid out of the contract
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TreasuryWithdrawal {
+    pub sequence: u64,
+    pub amount: Uint128,
+    pub recipient: Addr,
+    pub withdrawn_by: Addr,
+    pub block_height: u64,
+    pub timestamp: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TreasuryBalance {
+    pub account: TreasuryAccount,
+    pub amount: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TreasuryBalancesResponse {
+    pub balances: Vec<TreasuryBalance>,
+    pub total: Uint128,        // Sum of all buckets
+    pub bank_balance: Uint128, // Actual untrn held by the contract
+    pub unallocated: Uint128,  // Bank balance not attributed to any bucket
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TreasuryTransfersResponse {
+    pub transfers: Vec<TreasuryTransfer>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TreasuryWithdrawalsResponse {
+    pub withdrawals: Vec<TreasuryWithdrawal>,
+}
+
+// Storage keys
+pub const TREASURY_BALANCES: Map<&str, Uint128> = Map::new("treasury_balances");
+pub const TREASURY_TRANSFERS: Map<u64, TreasuryTransfer> = Map::new("treasury_transfers");
+pub const TREASURY_TRANSFER_COUNT: Item<u64> = Item::new("treasury_transfer_count");
+pub const TREASURY_WITHDRAWALS: Map<u64, TreasuryWithdrawal> = Map::new("treasury_withdrawals");
+pub const TREASURY_WITHDRAWAL_COUNT: Item<u64> = Item::new("treasury_withdrawal_count");
+
+/// Funds entered the contract into `account`
+pub fn credit(
+    storage: &mut dyn Storage,
+    account: TreasuryAccount,
+    amount: Uint128,
+) -> StdResult<()> {
+    TREASURY_BALANCES.update(storage, account.key(), |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default().checked_add(amount)?)
+    })?;
+    Ok(())
+}
+
+/// Funds left the contract from `account`; fails rather than letting a bucket go negative
+pub fn debit(
+    storage: &mut dyn Storage,
+    account: TreasuryAccount,
+    amount: Uint128,
+) -> StdResult<()> {
+    TREASURY_BALANCES.update(storage, account.key(), |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default().checked_sub(amount)?)
+    })?;
+    Ok(())
+}
+
+/// Current balance of `account`
+pub fn balance(storage: &dyn Storage, account: TreasuryAccount) -> StdResult<Uint128> {
+    Ok(TREASURY_BALANCES
+        .may_load(storage, account.key())?
+        .unwrap_or_default())
+}
+
+/// Move funds between buckets and keep a record of it
+pub fn transfer(
+    storage: &mut dyn Storage,
+    env: &Env,
+    from: TreasuryAccount,
+    to: TreasuryAccount,
+    amount: Uint128,
+    memo: &str,
+) -> StdResult<()> {
+    if amount.is_zero() {
+        return Ok(());
+    }
+
+    debit(storage, from, amount)?;
+    credit(storage, to, amount)?;
+
+    let sequence = TREASURY_TRANSFER_COUNT.may_load(storage)?.unwrap_or(0);
+    TREASURY_TRANSFER_COUNT.save(storage, &(sequence + 1))?;
+    TREASURY_TRANSFERS.save(
+        storage,
+        sequence,
+        &TreasuryTransfer {
+            sequence,
+            from,
+            to,
+            amount,
+            memo: memo.to_string(),
+            block_height: env.block.height,
+            timestamp: env.block.time.seconds(),
+        },
+    )
+}
+
+/// Escrow belongs to investors and only moves through the investment lifecycle
+fn ensure_not_escrow(account: TreasuryAccount, field: &str) -> Result<(), ContractError> {
+    if account == TreasuryAccount::Escrow {
+        return Err(ContractError::InvalidInput {
+            field: field.to_string(),
+            message: "Escrow can only move through investments, refunds and payouts".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Reallocate funds between non-escrow buckets (fee manager only)
+pub fn execute_internal_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    from: TreasuryAccount,
+    to: TreasuryAccount,
+    amount: Uint128,
+    memo: String,
+) -> Result<Response, ContractError> {
+    roles::ensure_role(deps.storage, &info.sender, Role::FeeManager)?;
+
+    ensure_not_escrow(from, "from")?;
+    ensure_not_escrow(to, "to")?;
+    if from == to || amount.is_zero() {
+        return Err(ContractError::InvalidInput {
+            field: "amount".to_string(),
+            message: "Transfer must move a non-zero amount between different accounts".to_string(),
+        });
+    }
+
+    transfer(deps.storage, &env, from, to, amount, &memo)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "treasury_transfer")
+        .add_attribute("from", from.key())
+        .add_attribute("to", to.key())
+        .add_attribute("amount", amount.to_string()))
+}
+
+/// Deposit untrn into a non-escrow bucket, e.g. to seed the insurance pool
+pub fn execute_fund_account(
+    deps: DepsMut,
+    info: MessageInfo,
+    account: TreasuryAccount,
+) -> Result<Response, ContractError> {
+    ensure_not_escrow(account, "account")?;
+
+    let amount = info
+        .funds
+        .iter()
+        .find(|coin| coin.denom == "untrn")
+        .map(|coin| coin.amount)
+        .unwrap_or_default();
+    if amount.is_zero() {
+        return Err(ContractError::InsufficientFunds {});
+    }
+
+    credit(deps.storage, account, amount)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "fund_treasury")
+        .add_attribute("account", account.key())
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("sender", info.sender))
+}
+
+/// Pay accrued platform fees out of the fee bucket (fee manager only).
+///
+/// A fee manager may itself be a multisig contract, in which case every withdrawal needs its signers.
+pub fn execute_withdraw(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    amount: Uint128,
+    recipient: String,
+) -> Result<Response, ContractError> {
+    roles::ensure_role(deps.storage, &info.sender, Role::FeeManager)?;
+    let recipient = deps.api.addr_validate(&recipient)?;
+
+    let available = balance(deps.storage, TreasuryAccount::Fees)?;
+    if amount.is_zero() || amount > available {
+        return Err(ContractError::InvalidInput {
+            field: "amount".to_string(),
+            message: format!(
+                "Must be between 1 and the {} untrn of accrued fees",
+                available
+            ),
+        });
+    }
+    debit(deps.storage, TreasuryAccount::Fees, amount)?;
+
+    let sequence = TREASURY_WITHDRAWAL_COUNT
+        .may_load(deps.storage)?
+        .unwrap_or(0);
+    TREASURY_WITHDRAWAL_COUNT.save(deps.storage, &(sequence + 1))?;
+    TREASURY_WITHDRAWALS.save(
+        deps.storage,
+        sequence,
+        &TreasuryWithdrawal {
+            sequence,
+            amount,
+            recipient: recipient.clone(),
+            withdrawn_by: info.sender.clone(),
+            block_height: env.block.height,
+            timestamp: env.block.time.seconds(),
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: vec![Coin {
+                denom: "untrn".to_string(),
+                amount,
+            }],
+        })
+        .add_attribute("method", "withdraw_treasury")
+        .add_attribute("sequence", sequence.to_string())
+        .add_attribute("recipient", recipient)
+        .add_attribute("amount", amount.to_string()))
+}
+
+// Query functions
+pub fn query_treasury_balances(deps: Deps, env: Env) -> StdResult<TreasuryBalancesResponse> {
+    let mut balances = Vec::with_capacity(TreasuryAccount::ALL.len());
+    let mut total = Uint128::zero();
+    for account in TreasuryAccount::ALL {
+        let amount = TREASURY_BALANCES
+            .may_load(deps.storage, account.key())?
+            .unwrap_or_default();
+        total = total.checked_add(amount)?;
+        balances.push(TreasuryBalance { account, amount });
+    }
+
+    let bank_balance = deps
+        .querier
+        .query_balance(&env.contract.address, "untrn")?
+        .amount;
+
+    Ok(TreasuryBalancesResponse {
+        balances,
+        total,
+        bank_balance,
+        unallocated: bank_balance.saturating_sub(total),
+    })
+}
+
+pub fn query_treasury_transfers(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<TreasuryTransfersResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let transfers = TREASURY_TRANSFERS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, transfer)| transfer))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(TreasuryTransfersResponse { transfers })
+}
+
+pub fn query_treasury_withdrawals(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<TreasuryWithdrawalsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let withdrawals = TREASURY_WITHDRAWALS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, withdrawal)| withdrawal))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(TreasuryWithdrawalsResponse { withdrawals })
+}
@@ -0,0 +1,120 @@
+//! Recent investment velocity per proposal.
+//!
+//! Every accepted investment adds to an hourly bucket for its proposal. Summing the buckets of
+//! the last day ranks the open raises by momentum, so the UI can show what is trending without
+//! an indexer. Only buckets inside the window are read, so the query cost follows recent
+//! activity rather than the number of proposals.
+
+use cosmwasm_std::{Deps, Env, Order, StdResult, Storage, Uint128};
+use cw_storage_plus::{Bound, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::state::{ProposalStatus, PROPOSAL_HOT_DATA};
+
+/// Width of one velocity bucket (an hour)
+const BUCKET_SECONDS: u64 = 60 * 60;
+/// How many of the latest buckets count as recent
+pub const TRENDING_WINDOW_HOURS: u64 = 24;
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct VelocityBucket {
+    pub investment_count: u32,
+    pub amount: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TrendingProposal {
+    pub proposal_id: String,
+    pub investment_count: u32, // Investments within the window
+    pub amount: Uint128,       // Invested within the window
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TrendingProposalsResponse {
+    pub proposals: Vec<TrendingProposal>, // Most invested first
+    pub window_start: u64,                // Start of the oldest bucket counted
+}
+
+// Storage keys
+pub const INVESTMENT_VELOCITY: Map<(u64, &str), VelocityBucket> = Map::new("velocity"); // (hour, proposal_id) -> invested that hour
+
+fn bucket_hour(now: u64) -> u64 {
+    now / BUCKET_SECONDS
+}
+
+/// Count an accepted investment towards the proposal's velocity
+pub fn record(
+    storage: &mut dyn Storage,
+    proposal_id: &str,
+    now: u64,
+    amount: Uint128,
+) -> StdResult<()> {
+    INVESTMENT_VELOCITY.update(
+        storage,
+        (bucket_hour(now), proposal_id),
+        |bucket| -> StdResult<_> {
+            let mut bucket = bucket.unwrap_or_default();
+            bucket.investment_count = bucket.investment_count.saturating_add(1);
+            bucket.amount = bucket.amount.checked_add(amount)?;
+            Ok(bucket)
+        },
+    )?;
+    Ok(())
+}
+
+// Query functions
+/// Active proposals ranked by what was invested over the last day, then by investment count
+pub fn query_trending_proposals(
+    deps: Deps,
+    env: Env,
+    limit: Option<u32>,
+) -> StdResult<TrendingProposalsResponse> {
+    let limit = limit
+        .unwrap_or(crate::gas_optimization::DEFAULT_LIMIT)
+        .min(crate::gas_optimization::MAX_LIMIT) as usize;
+    let first_hour =
+        bucket_hour(env.block.time.seconds()).saturating_sub(TRENDING_WINDOW_HOURS - 1);
+
+    let mut totals: BTreeMap<String, VelocityBucket> = BTreeMap::new();
+    for item in INVESTMENT_VELOCITY.range(
+        deps.storage,
+        Some(Bound::inclusive((first_hour, ""))),
+        None,
+        Order::Ascending,
+    ) {
+        let ((_, proposal_id), bucket) = item?;
+        let total = totals.entry(proposal_id).or_default();
+        total.investment_count = total
+            .investment_count
+            .saturating_add(bucket.investment_count);
+        total.amount = total.amount.checked_add(bucket.amount)?;
+    }
+
+    let mut proposals = vec![];
+    for (proposal_id, total) in totals {
+        let status = PROPOSAL_HOT_DATA
+            .may_load(deps.storage, proposal_id.clone())?
+            .map(|hot| hot.status);
+        if status == Some(ProposalStatus::Active) {
+            proposals.push(TrendingProposal {
+                proposal_id,
+                investment_count: total.investment_count,
+                amount: total.amount,
+            });
+        }
+    }
+    // Ties keep proposal id order, since the sort is stable
+    proposals.sort_by(|a, b| {
+        b.amount
+            .cmp(&a.amount)
+            .then(b.investment_count.cmp(&a.investment_count))
+    });
+    proposals.truncate(limit);
+
+    Ok(TrendingProposalsResponse {
+        proposals,
+        window_start: first_hour * BUCKET_SECONDS,
+    })
+}
@@ -0,0 +1,104 @@
+//! Issuer trust levels for creators.
+//!
+//! A compliance officer vets creators and records their tier on the creator record. The owner
+//! can require a verified creator for raises above a threshold; proposals carry their creator's
+//! tier so investors can filter by it.
+
+use cosmwasm_std::{Addr, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Storage, Uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+use crate::roles::{self, Role};
+use crate::state::{Config, Creator, CONFIG, CREATORS};
+
+/// Ordered from least to most trusted
+#[derive(
+    Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum VerificationTier {
+    #[default]
+    Unverified,
+    Verified,      // Identity and track record checked
+    Institutional, // Regulated institution acting as issuer
+}
+
+/// Record a creator's tier (compliance officer only). Creators can be vetted before their first
+/// proposal, in which case their record is opened here.
+pub fn set_creator_verification(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    creator: String,
+    tier: VerificationTier,
+) -> Result<Response, ContractError> {
+    roles::ensure_role(deps.storage, &info.sender, Role::ComplianceOfficer)?;
+    let creator_addr = deps.api.addr_validate(&creator)?;
+
+    let mut record = match CREATORS.may_load(deps.storage, &creator_addr)? {
+        Some(record) => record,
+        None => Creator {
+            addr: creator_addr.clone(),
+            name: crate::profiles::display_name(deps.storage, &creator_addr)?,
+            total_raised: Uint128::zero(),
+            successful_proposals: 0,
+            total_proposals: 0,
+            established: env.block.time.seconds(),
+            verification: VerificationTier::Unverified,
+        },
+    };
+    record.verification = tier;
+    CREATORS.save(deps.storage, &creator_addr, &record)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "set_creator_verification")
+        .add_attribute("creator", creator_addr)
+        .add_attribute("tier", format!("{:?}", tier))
+        .add_attribute("verified_by", info.sender))
+}
+
+/// Raise size above which creators must be at least Verified; None lets anyone raise (owner only)
+pub fn set_verification_threshold(
+    deps: DepsMut,
+    info: MessageInfo,
+    threshold: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    roles::ensure_role(deps.storage, &info.sender, Role::Owner)?;
+
+    let mut config = CONFIG.load(deps.storage)?;
+    config.verification_required_above = threshold;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "set_verification_threshold")
+        .add_attribute(
+            "threshold",
+            threshold.map_or_else(|| "none".to_string(), |threshold| threshold.to_string()),
+        ))
+}
+
+pub fn creator_tier(storage: &dyn Storage, creator: &Addr) -> StdResult<VerificationTier> {
+    Ok(CREATORS
+        .may_load(storage, creator)?
+        .map(|record| record.verification)
+        .unwrap_or_default())
+}
+
+/// Reject a raise above the configured threshold from an unverified creator
+pub fn ensure_verified_for(
+    deps: Deps,
+    config: &Config,
+    creator: &Addr,
+    target_amount: Uint128,
+) -> Result<(), ContractError> {
+    let Some(threshold) = config.verification_required_above else {
+        return Ok(());
+    };
+    if target_amount > threshold
+        && creator_tier(deps.storage, creator)? < VerificationTier::Verified
+    {
+        return Err(ContractError::CreatorNotVerified { threshold });
+    }
+    Ok(())
+}
@@ -0,0 +1,202 @@
+use cosmwasm_std::{
+    to_json_binary, Addr, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Storage, Uint128,
+    WasmMsg,
+};
+use cw20::Cw20ExecuteMsg;
+use cw_storage_plus::Map;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+use crate::security::ReentrancyGuard;
+use crate::state::{FinancialTerms, Proposal, TOKEN_CONTRACTS};
+
+/// Largest share of the supply a creator can reserve for themselves
+pub const MAX_CREATOR_ALLOCATION_BPS: u16 = 5_000;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum VestingSchedule {
+    // Nothing before the cliff, then pro rata over the whole duration
+    Linear {
+        cliff_seconds: u64,
+        duration_seconds: u64,
+    },
+    // Everything unlocks at once when the cliff passes
+    Cliff {
+        cliff_seconds: u64,
+    },
+}
+
+/// Creator share allocation set aside from total_shares and minted as it vests
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CreatorVesting {
+    pub allocation_bps: u16, // Share of total_shares, e.g. 500 = 5%
+    pub schedule: VestingSchedule,
+}
+
+impl CreatorVesting {
+    pub fn allocation_shares(&self, total_shares: u64) -> u64 {
+        (total_shares as u128 * self.allocation_bps as u128 / 10_000) as u64
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct VestingRecord {
+    pub beneficiary: Addr,
+    pub total: u64,
+    pub claimed: u64,
+    pub start: u64, // Distribution time
+    pub schedule: VestingSchedule,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct VestingStatusResponse {
+    pub proposal_id: String,
+    pub beneficiary: Addr,
+    pub total: u64,
+    pub vested: u64,
+    pub unvested: u64,
+    pub claimed: u64,
+    pub claimable: u64,
+    pub start: u64,
+    pub schedule: VestingSchedule,
+}
+
+// Storage keys
+pub const VESTING_SCHEDULES: Map<String, VestingRecord> = Map::new("vesting_schedules");
+
+/// The allocation must be a real, bounded slice of the supply on a schedule that ends
+pub fn validate(terms: &FinancialTerms) -> Result<(), ContractError> {
+    let Some(vesting) = &terms.creator_vesting else {
+        return Ok(());
+    };
+
+    let schedule_ok = match vesting.schedule {
+        VestingSchedule::Linear {
+            cliff_seconds,
+            duration_seconds,
+        } => duration_seconds > 0 && cliff_seconds <= duration_seconds,
+        VestingSchedule::Cliff { .. } => true,
+    };
+    if vesting.allocation_bps > MAX_CREATOR_ALLOCATION_BPS
+        || vesting.allocation_shares(terms.total_shares) == 0
+        || !schedule_ok
+    {
+        return Err(ContractError::InvalidInput {
+            field: "creator_vesting".to_string(),
+            message: format!(
+                "Allocation must be between one share and {} bps, with the cliff inside the duration",
+                MAX_CREATOR_ALLOCATION_BPS
+            ),
+        });
+    }
+    Ok(())
+}
+
+fn vested_at(record: &VestingRecord, now: u64) -> u64 {
+    let elapsed = now.saturating_sub(record.start);
+    match record.schedule {
+        VestingSchedule::Linear {
+            cliff_seconds,
+            duration_seconds,
+        } => {
+            if elapsed < cliff_seconds {
+                0
+            } else if elapsed >= duration_seconds {
+                record.total
+            } else {
+                (record.total as u128 * elapsed as u128 / duration_seconds as u128) as u64
+            }
+        }
+        VestingSchedule::Cliff { cliff_seconds } => {
+            if elapsed < cliff_seconds {
+                0
+            } else {
+                record.total
+            }
+        }
+    }
+}
+
+/// Start the creator's vesting clock once investor tokens are distributed
+pub fn start_vesting(storage: &mut dyn Storage, env: &Env, proposal: &Proposal) -> StdResult<()> {
+    let Some(vesting) = &proposal.financial_terms.creator_vesting else {
+        return Ok(());
+    };
+
+    VESTING_SCHEDULES.save(
+        storage,
+        proposal.id.clone(),
+        &VestingRecord {
+            beneficiary: proposal.creator.clone(),
+            total: vesting.allocation_shares(proposal.financial_terms.total_shares),
+            claimed: 0,
+            start: env.block.time.seconds(),
+            schedule: vesting.schedule.clone(),
+        },
+    )
+}
+
+/// Mint whatever has vested since the creator's last claim
+pub fn claim_vested_tokens(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: String,
+) -> Result<Response, ContractError> {
+    ReentrancyGuard::check_reentrancy(&deps, "claim_vested_tokens")?;
+
+    let mut record = VESTING_SCHEDULES.load(deps.storage, proposal_id.clone())?;
+    if record.beneficiary != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    crate::blacklist::ensure_not_blacklisted(deps.storage, &record.beneficiary)?;
+    crate::freeze::ensure_not_frozen(deps.storage, &proposal_id)?;
+    let claimable = vested_at(&record, env.block.time.seconds()).saturating_sub(record.claimed);
+    if claimable == 0 {
+        return Err(ContractError::NoVestedTokens {});
+    }
+
+    record.claimed = record.claimed.saturating_add(claimable);
+    VESTING_SCHEDULES.save(deps.storage, proposal_id.clone(), &record)?;
+
+    let token_address = TOKEN_CONTRACTS.load(deps.storage, proposal_id.clone())?;
+    let mint = WasmMsg::Execute {
+        contract_addr: token_address.to_string(),
+        msg: to_json_binary(&Cw20ExecuteMsg::Mint {
+            recipient: info.sender.to_string(),
+            amount: Uint128::from(claimable),
+        })?,
+        funds: vec![],
+    };
+
+    Ok(Response::new()
+        .add_message(mint)
+        .add_attribute("action", "claim_vested_tokens")
+        .add_attribute("proposal_id", &proposal_id)
+        .add_attribute("amount", claimable.to_string())
+        .add_attribute("claimed_total", record.claimed.to_string()))
+}
+
+// Query functions
+pub fn query_vesting_status(
+    deps: Deps,
+    env: Env,
+    proposal_id: String,
+) -> StdResult<VestingStatusResponse> {
+    let record = VESTING_SCHEDULES.load(deps.storage, proposal_id.clone())?;
+    let vested = vested_at(&record, env.block.time.seconds());
+
+    Ok(VestingStatusResponse {
+        proposal_id,
+        beneficiary: record.beneficiary,
+        total: record.total,
+        vested,
+        unvested: record.total - vested,
+        claimed: record.claimed,
+        claimable: vested.saturating_sub(record.claimed),
+        start: record.start,
+        schedule: record.schedule,
+    })
+}
@@ -0,0 +1,149 @@
+use cosmwasm_std::{Addr, Deps, DepsMut, Empty, MessageInfo, Order, Response, StdResult, Storage};
+use cw_storage_plus::{Bound, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+use crate::gas_optimization::{DEFAULT_LIMIT, MAX_LIMIT};
+use crate::roles::{self, Role};
+use crate::state::{ProposalStatus, PROPOSALS};
+
+/// Largest allowlist a creator can set in one message
+pub const MAX_WHITELIST_SIZE: usize = 500;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct WhitelistStatusResponse {
+    pub proposal_id: String,
+    pub investor: Addr,
+    pub private_round: bool, // false = open to every investor
+    pub eligible: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ProposalWhitelistResponse {
+    pub proposal_id: String,
+    pub size: u32,
+    pub investors: Vec<Addr>,
+}
+
+// Storage keys
+pub const WHITELIST_SIZES: Map<String, u32> = Map::new("whitelist_sizes"); // Present only for private rounds
+pub const WHITELISTED_INVESTORS: Map<(&str, &Addr), Empty> = Map::new("whitelisted_investors");
+
+fn is_whitelisted(storage: &dyn Storage, proposal_id: &str, investor: &Addr) -> bool {
+    !WHITELIST_SIZES.has(storage, proposal_id.to_string())
+        || WHITELISTED_INVESTORS.has(storage, (proposal_id, investor))
+}
+
+/// Reject investors left off a private round's allowlist
+pub fn enforce_whitelist(
+    storage: &dyn Storage,
+    proposal_id: &str,
+    investor: &Addr,
+) -> Result<(), ContractError> {
+    if !is_whitelisted(storage, proposal_id, investor) {
+        return Err(ContractError::NotWhitelisted {
+            proposal_id: proposal_id.to_string(),
+            investor: investor.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Replace a proposal's allowlist (creator or operator). An empty list reopens the round to everyone.
+///
+/// Investors dropped from the list keep what they already invested; they just cannot add to it.
+pub fn set_proposal_whitelist(
+    deps: DepsMut,
+    info: MessageInfo,
+    proposal_id: String,
+    investors: Vec<String>,
+) -> Result<Response, ContractError> {
+    let proposal = PROPOSALS.load(deps.storage, proposal_id.clone())?;
+    if info.sender != proposal.creator
+        && !roles::has_role(deps.storage, &info.sender, Role::Operator)?
+    {
+        return Err(ContractError::Unauthorized {});
+    }
+    if proposal.status != ProposalStatus::Active {
+        return Err(ContractError::ProposalNotActive {});
+    }
+    if investors.len() > MAX_WHITELIST_SIZE {
+        return Err(ContractError::InvalidInput {
+            field: "investors".to_string(),
+            message: format!("At most {} investors per whitelist", MAX_WHITELIST_SIZE),
+        });
+    }
+
+    let mut addrs = investors
+        .iter()
+        .map(|investor| deps.api.addr_validate(investor))
+        .collect::<StdResult<Vec<_>>>()?;
+    addrs.sort();
+    addrs.dedup();
+
+    let previous = WHITELISTED_INVESTORS
+        .prefix(&proposal_id)
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for investor in &previous {
+        WHITELISTED_INVESTORS.remove(deps.storage, (&proposal_id, investor));
+    }
+    for investor in &addrs {
+        WHITELISTED_INVESTORS.save(deps.storage, (&proposal_id, investor), &Empty {})?;
+    }
+
+    if addrs.is_empty() {
+        WHITELIST_SIZES.remove(deps.storage, proposal_id.clone());
+    } else {
+        WHITELIST_SIZES.save(deps.storage, proposal_id.clone(), &(addrs.len() as u32))?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "set_proposal_whitelist")
+        .add_attribute("proposal_id", &proposal_id)
+        .add_attribute("private_round", (!addrs.is_empty()).to_string())
+        .add_attribute("whitelist_size", addrs.len().to_string()))
+}
+
+// Query functions
+pub fn query_whitelist_status(
+    deps: Deps,
+    proposal_id: String,
+    investor: String,
+) -> StdResult<WhitelistStatusResponse> {
+    let investor_addr = deps.api.addr_validate(&investor)?;
+    Ok(WhitelistStatusResponse {
+        private_round: WHITELIST_SIZES.has(deps.storage, proposal_id.clone()),
+        eligible: is_whitelisted(deps.storage, &proposal_id, &investor_addr),
+        proposal_id,
+        investor: investor_addr,
+    })
+}
+
+pub fn query_proposal_whitelist(
+    deps: Deps,
+    proposal_id: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<ProposalWhitelistResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_addr = start_after
+        .map(|s| deps.api.addr_validate(&s))
+        .transpose()?;
+    let start = start_addr.as_ref().map(Bound::exclusive);
+
+    let investors = WHITELISTED_INVESTORS
+        .prefix(&proposal_id)
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(ProposalWhitelistResponse {
+        size: WHITELIST_SIZES
+            .may_load(deps.storage, proposal_id.clone())?
+            .unwrap_or_default(),
+        proposal_id,
+        investors,
+    })
+}